@@ -30,9 +30,17 @@ struct Args {
     #[arg(short = 'c', long)]
     cdrom: Option<String>,
 
+    /// Path to a PSX-EXE to run directly, bypassing the BIOS boot
+    #[arg(short = 'e', long)]
+    exe: Option<String>,
+
     /// Number of instructions to execute
     #[arg(short = 'n', long, default_value = "100000")]
     instructions: usize,
+
+    /// Print text the BIOS writes to its TTY (putchar/puts/printf)
+    #[arg(long)]
+    tty: bool,
 }
 
 fn main() -> Result<()> {
@@ -79,6 +87,18 @@ fn main() -> Result<()> {
     info!("Starting emulation...");
     system.reset();
 
+    if args.tty {
+        system.enable_bios_hle();
+    }
+
+    // Sideload executable if provided
+    if let Some(exe_path) = &args.exe {
+        if let Err(e) = system.load_exe(exe_path) {
+            error!("Failed to load executable: {}", e);
+            return Err(e);
+        }
+    }
+
     // Run for specified number of instructions
     let total_instructions = args.instructions;
     let log_interval = (total_instructions / 10).max(1); // Log ~10 times during execution
@@ -95,6 +115,10 @@ fn main() -> Result<()> {
             );
         }
 
+        if args.tty && i % 1024 == 0 {
+            print!("{}", system.take_tty_output());
+        }
+
         // Execute one instruction
         if let Err(e) = system.step() {
             error!("Error at PC=0x{:08X}: {}", system.pc(), e);
@@ -104,6 +128,8 @@ fn main() -> Result<()> {
         }
     }
 
+    print!("{}", system.take_tty_output());
+
     // Final status
     info!("Emulation completed successfully!");
     info!("Total instructions: {}", total_instructions);
@@ -18,10 +18,16 @@
 //! Handles CD audio track playback for music in PSX games.
 //! CD audio is 44.1kHz, 16-bit stereo PCM audio stored in 2352-byte sectors.
 //! Each sector contains 588 stereo samples (2352 bytes / 4 bytes per sample).
+//!
+//! Decoded XA-ADPCM audio (37.8kHz/18.9kHz) is also queued here and
+//! resampled to 44.1kHz so the SPU can mix both sources through one input.
 
+use std::collections::VecDeque;
 use std::fs::File;
 use std::io::{Read, Seek, SeekFrom};
 
+use crate::core::save_state::{CDAudioState, StateSave};
+
 /// CD-DA audio player
 ///
 /// Handles playback of CD audio tracks from disc image files.
@@ -47,9 +53,28 @@ pub struct CDAudio {
     pub(crate) volume_left: i16,
     pub(crate) volume_right: i16,
 
+    /// CD-to-SPU volume matrix (L→L, L→R, R→R, R→L), 0x80 = 100%
+    volume_matrix: [u8; 4],
+
     /// Sample buffer (2352 bytes per sector = 588 stereo samples)
     buffer: Vec<i16>,
     buffer_position: usize,
+
+    /// Decoded XA-ADPCM samples waiting to be mixed
+    xa_buffer: VecDeque<(i16, i16)>,
+
+    /// Sample rate of the queued XA audio (37800 or 18900 Hz)
+    xa_sample_rate: u32,
+
+    /// Resampling phase accumulator for XA audio (in units of 1/44100 s)
+    xa_phase: u32,
+
+    /// Current XA sample being output
+    xa_current: (i16, i16),
+
+    /// Highest CD-DA sample magnitude (left, right) since the last
+    /// [`CDAudio::take_peak`]
+    peak: (u16, u16),
 }
 
 impl CDAudio {
@@ -76,8 +101,14 @@ impl CDAudio {
             looping: false,
             volume_left: 0x80,
             volume_right: 0x80,
+            volume_matrix: [0x80, 0x00, 0x80, 0x00],
             buffer: Vec::new(),
             buffer_position: 0,
+            xa_buffer: VecDeque::new(),
+            xa_sample_rate: 37_800,
+            xa_phase: 0,
+            xa_current: (0, 0),
+            peak: (0, 0),
         }
     }
 
@@ -163,6 +194,84 @@ impl CDAudio {
         self.volume_right = right as i16;
     }
 
+    /// Set the CD-to-SPU volume matrix
+    ///
+    /// Applied by the CD-ROM controller when a game latches new values
+    /// through the Audio Volume Apply register.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - Volumes for CD-L→SPU-L, CD-L→SPU-R, CD-R→SPU-R and
+    ///   CD-R→SPU-L (0x80 = 100%)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::cdrom::cd_audio::CDAudio;
+    ///
+    /// let mut cd_audio = CDAudio::new();
+    /// // Swap left and right channels
+    /// cd_audio.set_volume_matrix([0x00, 0x80, 0x00, 0x80]);
+    /// ```
+    pub fn set_volume_matrix(&mut self, matrix: [u8; 4]) {
+        self.volume_matrix = matrix;
+    }
+
+    /// Get the current CD-to-SPU volume matrix
+    ///
+    /// # Returns
+    ///
+    /// Volumes for CD-L→SPU-L, CD-L→SPU-R, CD-R→SPU-R and CD-R→SPU-L
+    pub fn volume_matrix(&self) -> [u8; 4] {
+        self.volume_matrix
+    }
+
+    /// Queue decoded XA-ADPCM samples for mixing
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - Stereo samples decoded from one XA sector
+    /// * `sample_rate` - Sample rate of the samples (37800 or 18900 Hz)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::cdrom::cd_audio::CDAudio;
+    ///
+    /// let mut cd_audio = CDAudio::new();
+    /// cd_audio.push_xa_samples(&[(100, -100); 4], 37_800);
+    /// assert_eq!(cd_audio.xa_samples_queued(), 4);
+    /// ```
+    pub fn push_xa_samples(&mut self, samples: &[(i16, i16)], sample_rate: u32) {
+        self.xa_sample_rate = sample_rate;
+        self.xa_buffer.extend(samples.iter().copied());
+    }
+
+    /// Get the number of queued XA samples not yet mixed
+    pub fn xa_samples_queued(&self) -> usize {
+        self.xa_buffer.len()
+    }
+
+    /// Get the next XA sample resampled to 44.1kHz
+    ///
+    /// Uses sample-and-hold resampling: each XA sample lasts 44100 phase units
+    /// and each output sample consumes `xa_sample_rate` units. Outputs silence
+    /// once the queue runs dry.
+    fn next_xa_sample(&mut self) -> (i16, i16) {
+        while self.xa_phase < self.xa_sample_rate {
+            let Some(sample) = self.xa_buffer.pop_front() else {
+                self.xa_phase = 0;
+                self.xa_current = (0, 0);
+                return (0, 0);
+            };
+            self.xa_current = sample;
+            self.xa_phase += 44_100;
+        }
+
+        self.xa_phase -= self.xa_sample_rate;
+        self.xa_current
+    }
+
     /// Check if CD audio is currently playing
     ///
     /// # Returns
@@ -174,7 +283,8 @@ impl CDAudio {
 
     /// Get next stereo sample
     ///
-    /// Returns the next stereo sample from the CD audio stream.
+    /// Returns the next stereo sample from the CD audio stream, mixed with
+    /// any queued XA-ADPCM audio.
     /// Automatically handles sector reading and looping.
     ///
     /// # Returns
@@ -191,6 +301,29 @@ impl CDAudio {
     /// ```
     #[inline(always)]
     pub fn get_sample(&mut self) -> (i16, i16) {
+        let (xa_left, xa_right) = self.next_xa_sample();
+        let (cd_left, cd_right) = self.next_cdda_sample();
+
+        let left = cd_left as i32 + xa_left as i32;
+        let right = cd_right as i32 + xa_right as i32;
+
+        // Route through the CD-to-SPU volume matrix
+        let [ll, lr, rr, rl] = self.volume_matrix.map(|v| v as i32);
+        let (left, right) = ((left * ll + right * rl) >> 7, (right * rr + left * lr) >> 7);
+
+        // Apply volume (scale by volume/128)
+        let left = (left * self.volume_left as i32) >> 7;
+        let right = (right * self.volume_right as i32) >> 7;
+
+        // Clamp to i16 range to avoid wrap-around
+        let left = left.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        let right = right.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+
+        (left, right)
+    }
+
+    /// Get the next raw CD-DA sample, or silence if not playing
+    fn next_cdda_sample(&mut self) -> (i16, i16) {
         if !self.playing {
             return (0, 0);
         }
@@ -205,22 +338,28 @@ impl CDAudio {
             self.buffer_position = 0;
         }
 
-        // Get stereo sample
         let left = self.buffer[self.buffer_position];
         let right = self.buffer[self.buffer_position + 1];
         self.buffer_position += 2;
 
-        // Apply volume (scale by volume/128)
-        let left = (left as i32 * self.volume_left as i32) >> 7;
-        let right = (right as i32 * self.volume_right as i32) >> 7;
-
-        // Clamp to i16 range to avoid wrap-around
-        let left = left.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
-        let right = right.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        self.peak.0 = self.peak.0.max(left.unsigned_abs().min(0x7FFF));
+        self.peak.1 = self.peak.1.max(right.unsigned_abs().min(0x7FFF));
 
         (left, right)
     }
 
+    /// Take the peak CD-DA levels played since the last call
+    ///
+    /// Used for the drive's report interrupts. Levels are measured on the
+    /// raw disc samples, before the volume matrix.
+    ///
+    /// # Returns
+    ///
+    /// Peak magnitude (left, right), 0..=7FFFh
+    pub fn take_peak(&mut self) -> (u16, u16) {
+        std::mem::take(&mut self.peak)
+    }
+
     /// Read a sector from disc and convert to PCM samples
     ///
     /// Reads raw sector data and converts it to 16-bit stereo samples.
@@ -276,6 +415,52 @@ impl Default for CDAudio {
     }
 }
 
+impl StateSave for CDAudio {
+    type State = CDAudioState;
+
+    fn to_state(&self) -> CDAudioState {
+        CDAudioState {
+            current_sector: self.current_sector,
+            play_start: self.play_start,
+            play_end: self.play_end,
+            playing: self.playing,
+            looping: self.looping,
+            volume_left: self.volume_left,
+            volume_right: self.volume_right,
+            volume_matrix: self.volume_matrix,
+            buffer: self.buffer.clone(),
+            buffer_position: self.buffer_position as u32,
+            xa_buffer: self.xa_buffer.iter().copied().collect(),
+            xa_sample_rate: self.xa_sample_rate,
+            xa_phase: self.xa_phase,
+            xa_current: self.xa_current,
+            peak: self.peak,
+        }
+    }
+
+    /// Restore playback state
+    ///
+    /// The opened audio file is kept; playback resumes from the saved
+    /// sector on the next buffer refill.
+    fn restore_from_state(&mut self, state: &CDAudioState) {
+        self.current_sector = state.current_sector;
+        self.play_start = state.play_start;
+        self.play_end = state.play_end;
+        self.playing = state.playing;
+        self.looping = state.looping;
+        self.volume_left = state.volume_left;
+        self.volume_right = state.volume_right;
+        self.volume_matrix = state.volume_matrix;
+        self.buffer = state.buffer.clone();
+        self.buffer_position = state.buffer_position as usize;
+        self.xa_buffer = state.xa_buffer.iter().copied().collect();
+        self.xa_sample_rate = state.xa_sample_rate;
+        self.xa_phase = state.xa_phase;
+        self.xa_current = state.xa_current;
+        self.peak = state.peak;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -420,6 +605,29 @@ mod tests {
         assert!(!audio.is_playing());
     }
 
+    #[test]
+    fn test_volume_matrix_swaps_channels() {
+        let mut audio = CDAudio::new();
+        audio.push_xa_samples(&[(1000, -2000); 4], 44_100);
+
+        let (left, right) = audio.get_sample();
+        assert_eq!((left, right), (1000, -2000));
+
+        audio.set_volume_matrix([0x00, 0x80, 0x00, 0x80]);
+        let (left, right) = audio.get_sample();
+        assert_eq!((left, right), (-2000, 1000));
+    }
+
+    #[test]
+    fn test_volume_matrix_mixes_to_mono() {
+        let mut audio = CDAudio::new();
+        audio.set_volume_matrix([0x40, 0x40, 0x40, 0x40]);
+        audio.push_xa_samples(&[(1000, 3000); 2], 44_100);
+
+        let (left, right) = audio.get_sample();
+        assert_eq!((left, right), (2000, 2000));
+    }
+
     #[test]
     fn test_get_sample_with_zero_volume() {
         let mut audio = CDAudio::new();
@@ -704,6 +912,73 @@ mod tests {
         assert_eq!(audio.volume_right, 0x40);
     }
 
+    #[test]
+    fn test_xa_samples_mixed_without_cdda() {
+        let mut audio = CDAudio::new();
+        audio.push_xa_samples(&[(1000, -1000); 8], 37_800);
+
+        let (left, right) = audio.get_sample();
+
+        assert_eq!(left, 1000);
+        assert_eq!(right, -1000);
+    }
+
+    #[test]
+    fn test_xa_resampling_consumes_at_source_rate() {
+        let mut audio = CDAudio::new();
+        audio.push_xa_samples(&vec![(1, 1); 1890], 18_900);
+
+        // 4410 output samples at 44.1kHz = 0.1s = 1890 samples at 18.9kHz
+        for _ in 0..4410 {
+            audio.get_sample();
+        }
+
+        assert_eq!(audio.xa_samples_queued(), 0);
+    }
+
+    #[test]
+    fn test_xa_silence_when_queue_empty() {
+        let mut audio = CDAudio::new();
+        audio.push_xa_samples(&[(500, 500)], 37_800);
+
+        audio.get_sample();
+        audio.get_sample();
+
+        assert_eq!(audio.get_sample(), (0, 0));
+    }
+
+    #[test]
+    fn test_take_peak_tracks_highest_magnitude() {
+        let file = create_test_audio_file(1);
+        let mut cd_audio = CDAudio::new();
+        cd_audio.load_disc(file.path().to_str().unwrap()).unwrap();
+        cd_audio.play(0, 1, false);
+
+        for _ in 0..10 {
+            cd_audio.get_sample();
+        }
+
+        // Samples 0..9: left = n * 100, right = -(n * 100)
+        assert_eq!(cd_audio.take_peak(), (900, 900));
+        assert_eq!(cd_audio.take_peak(), (0, 0));
+    }
+
+    #[test]
+    fn test_state_round_trip_keeps_peak() {
+        let file = create_test_audio_file(1);
+        let mut cd_audio = CDAudio::new();
+        cd_audio.load_disc(file.path().to_str().unwrap()).unwrap();
+        cd_audio.play(0, 1, false);
+        for _ in 0..10 {
+            cd_audio.get_sample();
+        }
+
+        let mut restored = CDAudio::new();
+        restored.restore_from_state(&cd_audio.to_state());
+
+        assert_eq!(restored.take_peak(), (900, 900));
+    }
+
     #[test]
     fn test_default_implementation() {
         let audio = CDAudio::default();
@@ -64,6 +64,90 @@ pub struct Track {
 
     /// Byte offset in .bin file
     pub file_offset: u64,
+
+    /// Length of the pregap in sectors (INDEX 00 to INDEX 01, plus `gap_sectors`)
+    pub pregap_sectors: u32,
+
+    /// Pregap sectors not stored in the .bin file (CUE `PREGAP` directive)
+    pub gap_sectors: u32,
+}
+
+/// Subchannel Q data for a disc position
+///
+/// The Q channel carries the current track/index and timing information
+/// for every sector. Times are stored as decimal `CDPosition`s; the CD-ROM
+/// controller converts them to BCD when reporting them.
+///
+/// # Raw Layout (10 bytes, without CRC)
+///
+/// ```text
+/// ctrl/adr, track, index, mm, ss, ff, 0, amm, ass, aff
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubchannelQ {
+    /// Control (upper nibble) and ADR (lower nibble)
+    pub control_adr: u8,
+
+    /// Track number (1-99)
+    pub track: u8,
+
+    /// Index (0 = pregap, 1 = track data)
+    pub index: u8,
+
+    /// Time relative to the track start (counts down inside the pregap)
+    pub relative: CDPosition,
+
+    /// Absolute disc time
+    pub absolute: CDPosition,
+}
+
+impl SubchannelQ {
+    /// Encode as the 10 raw Q bytes (BCD), excluding the CRC
+    ///
+    /// # Returns
+    ///
+    /// Raw subchannel Q bytes
+    pub fn to_bytes(&self) -> [u8; 10] {
+        use super::dec_to_bcd;
+
+        [
+            self.control_adr,
+            dec_to_bcd(self.track),
+            dec_to_bcd(self.index),
+            dec_to_bcd(self.relative.minute),
+            dec_to_bcd(self.relative.second),
+            dec_to_bcd(self.relative.sector),
+            0x00,
+            dec_to_bcd(self.absolute.minute),
+            dec_to_bcd(self.absolute.second),
+            dec_to_bcd(self.absolute.sector),
+        ]
+    }
+}
+
+/// Region of a licensed PlayStation disc
+///
+/// Read from the license text in sector 4 of the system area and reported
+/// by GetID as the fourth letter of the `SCEx` string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscRegion {
+    /// NTSC-J (`SCEI`)
+    Japan,
+    /// NTSC-U (`SCEA`)
+    America,
+    /// PAL (`SCEE`)
+    Europe,
+}
+
+impl DiscRegion {
+    /// Last letter of the `SCEx` string reported by GetID
+    pub fn scex_letter(self) -> u8 {
+        match self {
+            DiscRegion::Japan => b'I',
+            DiscRegion::America => b'A',
+            DiscRegion::Europe => b'E',
+        }
+    }
 }
 
 /// CD-ROM track type
@@ -83,7 +167,8 @@ impl DiscImage {
     /// Load a disc image from a .cue file
     ///
     /// Parses the .cue file to extract track information and loads
-    /// the corresponding .bin file containing raw sector data.
+    /// the corresponding .bin file containing raw sector data. The sheet is
+    /// validated first so that a bad dump reports the first problem found.
     ///
     /// # Arguments
     ///
@@ -92,7 +177,10 @@ impl DiscImage {
     /// # Returns
     ///
     /// - `Ok(DiscImage)` if loading succeeded
-    /// - `Err(CdRomError)` if loading failed
+    /// - `Err(CdRomError::MissingBinFile)` if a `FILE` does not exist
+    /// - `Err(CdRomError::InvalidBinSize)` if a `FILE` is not whole sectors
+    /// - `Err(CdRomError::IndexOutOfOrder)` if `INDEX` positions go backwards
+    /// - `Err(CdRomError)` for other loading failures
     ///
     /// # Example
     ///
@@ -103,6 +191,7 @@ impl DiscImage {
     /// ```
     pub fn load(cue_path: &str) -> Result<Self, CdRomError> {
         let cue_data = std::fs::read_to_string(cue_path)?;
+        Self::validate_cue(cue_path, &cue_data)?;
         let bin_path = Self::get_bin_path_from_cue(cue_path, &cue_data)?;
 
         let mut tracks = Self::parse_cue(&cue_data)?;
@@ -112,6 +201,7 @@ impl DiscImage {
 
         // Calculate track lengths based on file size and positions
         Self::calculate_track_lengths(&mut tracks, data.len());
+        Self::resolve_disc_positions(&mut tracks);
 
         log::info!(
             "Loaded disc image: {} tracks, {} MB",
@@ -139,21 +229,8 @@ impl DiscImage {
         for line in cue_data.lines() {
             let line = line.trim();
             if line.starts_with("FILE") {
-                // Extract filename from quotes
-                if let Some(start) = line.find('"') {
-                    if let Some(end) = line[start + 1..].find('"') {
-                        let bin_filename = &line[start + 1..start + 1 + end];
-
-                        // Construct full path by replacing .cue filename with .bin filename
-                        let cue_path_obj = std::path::Path::new(cue_path);
-                        let bin_path = if let Some(parent) = cue_path_obj.parent() {
-                            parent.join(bin_filename)
-                        } else {
-                            std::path::PathBuf::from(bin_filename)
-                        };
-
-                        return Ok(bin_path.to_string_lossy().to_string());
-                    }
+                if let Some(bin_path) = Self::resolve_file_path(cue_path, line) {
+                    return Ok(bin_path.to_string_lossy().to_string());
                 }
             }
         }
@@ -163,6 +240,93 @@ impl DiscImage {
         ))
     }
 
+    /// Resolve the quoted filename of a FILE directive
+    ///
+    /// # Arguments
+    ///
+    /// * `cue_path` - Path to the .cue file (filenames are relative to it)
+    /// * `line` - Trimmed FILE line
+    ///
+    /// # Returns
+    ///
+    /// Path of the referenced file, or None if the filename is not quoted
+    fn resolve_file_path(cue_path: &str, line: &str) -> Option<std::path::PathBuf> {
+        let start = line.find('"')?;
+        let end = line[start + 1..].find('"')?;
+        let filename = &line[start + 1..start + 1 + end];
+
+        Some(match std::path::Path::new(cue_path).parent() {
+            Some(parent) => parent.join(filename),
+            None => std::path::PathBuf::from(filename),
+        })
+    }
+
+    /// Check a .cue sheet against the files it references
+    ///
+    /// Every FILE must exist and hold a whole number of 2352-byte sectors,
+    /// and INDEX positions within a FILE must never go backwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `cue_path` - Path to the .cue file
+    /// * `cue_data` - Content of the .cue file
+    ///
+    /// # Returns
+    ///
+    /// The first problem found, if any
+    fn validate_cue(cue_path: &str, cue_data: &str) -> Result<(), CdRomError> {
+        let mut track = 0u8;
+        let mut previous_lba: Option<i32> = None;
+
+        for (line_index, line) in cue_data.lines().enumerate() {
+            let line = line.trim();
+            let parts: Vec<&str> = line.split_whitespace().collect();
+
+            match parts.first().copied() {
+                Some("FILE") => {
+                    if let Some(path) = Self::resolve_file_path(cue_path, line) {
+                        let path_str = path.to_string_lossy().to_string();
+                        let size = std::fs::metadata(&path)
+                            .map_err(|_| CdRomError::MissingBinFile {
+                                path: path_str.clone(),
+                            })?
+                            .len();
+                        if size % 2352 != 0 {
+                            return Err(CdRomError::InvalidBinSize {
+                                path: path_str,
+                                size,
+                            });
+                        }
+                    }
+                    // INDEX times restart at 0 for each file
+                    previous_lba = None;
+                }
+                Some("TRACK") => {
+                    track = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(track);
+                }
+                Some("INDEX") => {
+                    let (Some(index), Some(time_str)) = (parts.get(1), parts.get(2)) else {
+                        continue;
+                    };
+                    let index = index.parse().unwrap_or(0);
+                    let lba = Self::parse_msf(time_str)?.to_lba();
+
+                    if previous_lba.is_some_and(|previous| lba < previous) {
+                        return Err(CdRomError::IndexOutOfOrder {
+                            track,
+                            index,
+                            line: line_index + 1,
+                        });
+                    }
+                    previous_lba = Some(lba);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
     /// Parse .cue file content to extract track information
     ///
     /// # Arguments
@@ -175,6 +339,7 @@ impl DiscImage {
     pub(super) fn parse_cue(cue_data: &str) -> Result<Vec<Track>, CdRomError> {
         let mut tracks = Vec::new();
         let mut current_track: Option<Track> = None;
+        let mut index0: Option<CDPosition> = None;
 
         for line in cue_data.lines() {
             let line = line.trim();
@@ -185,6 +350,8 @@ impl DiscImage {
                     tracks.push(track);
                 }
 
+                index0 = None;
+
                 let parts: Vec<&str> = line.split_whitespace().collect();
                 let track_num = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(1);
                 let track_type_str = parts.get(2).unwrap_or(&"MODE2/2352");
@@ -195,15 +362,36 @@ impl DiscImage {
                     start_position: CDPosition::new(0, 0, 0),
                     length_sectors: 0,
                     file_offset: 0,
+                    pregap_sectors: 0,
+                    gap_sectors: 0,
                 });
+            } else if line.starts_with("PREGAP") {
+                if let Some(ref mut track) = current_track {
+                    let parts: Vec<&str> = line.split_whitespace().collect();
+                    if let Some(time_str) = parts.get(1) {
+                        let frames = (Self::parse_msf(time_str)?.to_lba() + 150) as u32;
+                        track.gap_sectors = frames;
+                        track.pregap_sectors = frames;
+                    }
+                }
+            } else if line.starts_with("INDEX 00") {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                if let Some(time_str) = parts.get(2) {
+                    index0 = Some(Self::parse_msf(time_str)?);
+                }
             } else if line.starts_with("INDEX 01") {
                 if let Some(ref mut track) = current_track {
                     let parts: Vec<&str> = line.split_whitespace().collect();
                     if let Some(time_str) = parts.get(2) {
+                        // CUE times are relative to the start of the .bin file
                         track.start_position = Self::parse_msf(time_str)?;
-                        // Calculate file offset from MSF position
-                        track.file_offset =
-                            Self::msf_to_sector(&track.start_position) as u64 * 2352;
+                        track.file_offset = (track.start_position.to_lba() + 150) as u64 * 2352;
+
+                        if let Some(pregap_start) = index0 {
+                            let in_file = (track.start_position.to_lba() - pregap_start.to_lba())
+                                .max(0) as u32;
+                            track.pregap_sectors = track.gap_sectors + in_file;
+                        }
                     }
                 }
             }
@@ -280,9 +468,14 @@ impl DiscImage {
         for i in 0..tracks.len() {
             if i + 1 < tracks.len() {
                 // Calculate length as difference between this track and next track
+                // The next track's INDEX 00 pregap is not part of this track
                 let next_offset = tracks[i + 1].file_offset;
                 let this_offset = tracks[i].file_offset;
-                tracks[i].length_sectors = ((next_offset - this_offset) / 2352) as u32;
+                let next_pregap = tracks[i + 1]
+                    .pregap_sectors
+                    .saturating_sub(tracks[i + 1].gap_sectors);
+                tracks[i].length_sectors =
+                    (((next_offset - this_offset) / 2352) as u32).saturating_sub(next_pregap);
             } else {
                 // Last track: calculate from remaining file size
                 let this_offset = tracks[i].file_offset;
@@ -291,8 +484,72 @@ impl DiscImage {
         }
     }
 
+    /// Convert track start positions from .bin file time to absolute disc time
+    ///
+    /// CUE sheets give INDEX times relative to the start of the .bin file.
+    /// On the disc, data starts after the 2-second lead-in pregap (00:02:00 =
+    /// LBA 0) and every `PREGAP` that is not stored in the file shifts all
+    /// following tracks.
+    ///
+    /// # Arguments
+    ///
+    /// * `tracks` - Mutable vector of tracks to update
+    pub(super) fn resolve_disc_positions(tracks: &mut [Track]) {
+        let mut gap_total = 0;
+
+        for track in tracks.iter_mut() {
+            gap_total += track.gap_sectors;
+            let file_sector = (track.file_offset / 2352) as u32;
+            track.start_position = CDPosition::from_lba((file_sector + gap_total) as i32);
+        }
+    }
+
+    /// Get the track owning an absolute LBA, including its pregap
+    ///
+    /// # Arguments
+    ///
+    /// * `lba` - Logical block address (0 = 00:02:00)
+    ///
+    /// # Returns
+    ///
+    /// Optional reference to the owning track
+    pub fn track_for_lba(&self, lba: i32) -> Option<&Track> {
+        self.tracks.iter().find(|t| {
+            let start = t.start_position.to_lba();
+            lba >= start - t.pregap_sectors as i32 && lba < start + t.length_sectors as i32
+        })
+    }
+
+    /// Map an absolute LBA to its byte offset in the .bin file
+    ///
+    /// # Arguments
+    ///
+    /// * `lba` - Logical block address (0 = 00:02:00)
+    ///
+    /// # Returns
+    ///
+    /// - `Some(Some(offset))` - Sector is stored in the file at `offset`
+    /// - `Some(None)` - Sector belongs to a pregap that is not stored in the file
+    /// - `None` - LBA is outside all tracks
+    pub fn file_offset_for_lba(&self, lba: i32) -> Option<Option<u64>> {
+        let track = self.track_for_lba(lba)?;
+        let start = track.start_position.to_lba();
+        let in_file_pregap = track.pregap_sectors.saturating_sub(track.gap_sectors) as i32;
+
+        if lba < start - in_file_pregap {
+            return Some(None);
+        }
+
+        let sector = (track.file_offset / 2352) as i64 + (lba - start) as i64;
+        Some(Some(sector as u64 * 2352))
+    }
+
     /// Read a sector from the disc at the specified MSF position
     ///
+    /// The position is mapped to its owning track so data and audio tracks,
+    /// INDEX 00 pregaps and `PREGAP` gaps resolve to the right file offset.
+    /// Both data and audio sectors are returned as raw 2352-byte frames.
+    ///
     /// # Arguments
     ///
     /// * `position` - MSF position to read from
@@ -313,8 +570,17 @@ impl DiscImage {
     /// }
     /// ```
     pub fn read_sector(&self, position: &CDPosition) -> Option<&[u8]> {
-        let sector_num = Self::msf_to_sector(position);
-        let offset = sector_num * 2352;
+        /// Pregap sectors that are not stored in the image read as silence
+        static SILENCE: [u8; 2352] = [0; 2352];
+
+        let offset = if self.tracks.is_empty() {
+            Self::msf_to_sector(position)
+        } else {
+            match self.file_offset_for_lba(position.to_lba())? {
+                Some(offset) => offset as usize,
+                None => return Some(&SILENCE),
+            }
+        };
 
         if offset + 2352 <= self.data.len() {
             Some(&self.data[offset..offset + 2352])
@@ -346,6 +612,46 @@ impl DiscImage {
         self.tracks.len()
     }
 
+    /// Check whether the disc has no data track
+    ///
+    /// # Returns
+    ///
+    /// true for a CD-DA disc (first track is audio)
+    pub fn is_audio_disc(&self) -> bool {
+        self.tracks
+            .first()
+            .is_some_and(|track| track.track_type == TrackType::Audio)
+    }
+
+    /// Detect the disc region from the license text
+    ///
+    /// Licensed discs carry `Licensed by Sony Computer Entertainment Inc.`
+    /// (or `Amer`/`Euro` variants) in sector 4 (00:02:04).
+    ///
+    /// # Returns
+    ///
+    /// The region, or None for unlicensed and audio discs
+    pub fn region(&self) -> Option<DiscRegion> {
+        const LICENSE: &[u8] = b"Sony Computer Entertainment ";
+
+        if self.is_audio_disc() {
+            return None;
+        }
+
+        let sector = self.read_sector(&CDPosition::new(0, 2, 4))?;
+        let start = sector
+            .windows(LICENSE.len())
+            .position(|window| window == LICENSE)?
+            + LICENSE.len();
+
+        match sector.get(start..start + 4)? {
+            b"Inc." => Some(DiscRegion::Japan),
+            b"Amer" => Some(DiscRegion::America),
+            b"Euro" => Some(DiscRegion::Europe),
+            _ => None,
+        }
+    }
+
     /// Get track information by track number
     ///
     /// # Arguments
@@ -359,6 +665,111 @@ impl DiscImage {
         self.tracks.iter().find(|t| t.number == track_num)
     }
 
+    /// Get the first and last track numbers on the disc
+    ///
+    /// # Returns
+    ///
+    /// `(first, last)` track numbers, or `None` if the disc has no tracks
+    pub fn track_range(&self) -> Option<(u8, u8)> {
+        let first = self.tracks.iter().map(|t| t.number).min()?;
+        let last = self.tracks.iter().map(|t| t.number).max()?;
+        Some((first, last))
+    }
+
+    /// Get the start position of a track
+    ///
+    /// Track 0 refers to the lead-out area, i.e. the position immediately
+    /// after the last track.
+    ///
+    /// # Arguments
+    ///
+    /// * `track_num` - Track number (0 for lead-out, 1-99)
+    ///
+    /// # Returns
+    ///
+    /// Start position (MSF) of the track, or `None` if the track does not exist
+    pub fn track_start(&self, track_num: u8) -> Option<CDPosition> {
+        if track_num == 0 {
+            let last = self
+                .tracks
+                .iter()
+                .max_by_key(|t| t.start_position.to_lba())?;
+            let end = last.start_position.to_lba() + last.length_sectors as i32;
+            return Some(CDPosition::from_lba(end));
+        }
+
+        self.get_track(track_num).map(|t| t.start_position)
+    }
+
+    /// Synthesize subchannel Q for a disc position
+    ///
+    /// Positions inside a track's pregap (INDEX 00) report index 0 with the
+    /// relative time counting down to the track start. Everything before the
+    /// first track belongs to its 2-second pregap.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Absolute MSF position
+    ///
+    /// # Returns
+    ///
+    /// Subchannel Q data, or `None` if the position lies outside all tracks
+    pub fn subchannel_q(&self, position: &CDPosition) -> Option<SubchannelQ> {
+        let lba = position.to_lba();
+        let first = self
+            .tracks
+            .iter()
+            .min_by_key(|t| t.start_position.to_lba())?;
+
+        let pregap_track = self.tracks.iter().find(|t| {
+            let start = t.start_position.to_lba();
+            let pregap_start = if t.number == first.number {
+                i32::MIN
+            } else {
+                start - t.pregap_sectors as i32
+            };
+            lba >= pregap_start && lba < start
+        });
+
+        let (track, index, relative) = match pregap_track {
+            Some(track) => (track, 0, track.start_position.to_lba() - lba),
+            None => {
+                let track = self.track_at(position)?;
+                (track, 1, lba - track.start_position.to_lba())
+            }
+        };
+
+        let control = match track.track_type {
+            TrackType::Audio => 0x00,
+            TrackType::Mode1_2352 | TrackType::Mode2_2352 => 0x40,
+        };
+
+        Some(SubchannelQ {
+            control_adr: control | 0x01,
+            track: track.number,
+            index,
+            relative: CDPosition::from_lba(relative - 150),
+            absolute: *position,
+        })
+    }
+
+    /// Get the track containing a disc position
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Absolute MSF position
+    ///
+    /// # Returns
+    ///
+    /// Optional reference to the track whose sector range contains `position`
+    pub fn track_at(&self, position: &CDPosition) -> Option<&Track> {
+        let lba = position.to_lba();
+        self.tracks.iter().find(|t| {
+            let start = t.start_position.to_lba();
+            lba >= start && lba < start + t.length_sectors as i32
+        })
+    }
+
     /// Create a dummy disc image for testing
     ///
     /// Creates a minimal valid disc image with a single data track.
@@ -376,6 +787,8 @@ impl DiscImage {
             start_position: CDPosition::new(0, 2, 0),
             length_sectors: 100,
             file_offset: 0,
+            pregap_sectors: 0,
+            gap_sectors: 0,
         };
 
         // Create minimal dummy data (100 sectors * 2352 bytes)
@@ -386,6 +799,37 @@ impl DiscImage {
             data,
         }
     }
+
+    /// Create a dummy single-track CD-DA disc image for testing
+    #[cfg(test)]
+    pub(super) fn new_audio_dummy() -> Self {
+        let mut disc = Self::new_dummy();
+        disc.tracks[0].track_type = TrackType::Audio;
+        disc
+    }
+
+    /// Create a single-track disc image from raw 2352-byte sectors for testing
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Raw sector data, starting at 00:02:00
+    #[cfg(test)]
+    pub(super) fn from_raw(data: Vec<u8>) -> Self {
+        let track = Track {
+            number: 1,
+            track_type: TrackType::Mode2_2352,
+            start_position: CDPosition::new(0, 2, 0),
+            length_sectors: (data.len() / 2352) as u32,
+            file_offset: 0,
+            pregap_sectors: 0,
+            gap_sectors: 0,
+        };
+
+        Self {
+            tracks: vec![track],
+            data,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -559,6 +1003,8 @@ mod tests {
             start_position: CDPosition::new(0, 0, 0),
             length_sectors: 0,
             file_offset: 0,
+            pregap_sectors: 0,
+            gap_sectors: 0,
         }];
 
         let file_size = 100 * 2352; // 100 sectors
@@ -578,6 +1024,8 @@ mod tests {
                 start_position: CDPosition::new(0, 0, 0),
                 length_sectors: 0,
                 file_offset: 0,
+                pregap_sectors: 0,
+                gap_sectors: 0,
             },
             Track {
                 number: 2,
@@ -585,6 +1033,8 @@ mod tests {
                 start_position: CDPosition::new(0, 10, 0),
                 length_sectors: 0,
                 file_offset: track2_sector as u64 * 2352,
+                pregap_sectors: 0,
+                gap_sectors: 0,
             },
         ];
 
@@ -683,6 +1133,134 @@ mod tests {
         assert!(track.is_none());
     }
 
+    #[test]
+    fn test_track_range_and_start() {
+        let disc = DiscImage::new_dummy();
+
+        assert_eq!(disc.track_range(), Some((1, 1)));
+        assert_eq!(disc.track_start(1), Some(CDPosition::new(0, 2, 0)));
+        assert_eq!(disc.track_start(2), None);
+
+        // Lead-out follows the 100 sectors of track 1
+        assert_eq!(disc.track_start(0), Some(CDPosition::new(0, 3, 25)));
+    }
+
+    #[test]
+    fn test_parse_cue_index_00_sets_pregap() {
+        let cue_content = r#"FILE "game.bin" BINARY
+  TRACK 01 MODE2/2352
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    INDEX 00 10:00:00
+    INDEX 01 10:02:00
+"#;
+
+        let tracks = DiscImage::parse_cue(cue_content).unwrap();
+
+        assert_eq!(tracks[0].pregap_sectors, 0);
+        assert_eq!(tracks[1].pregap_sectors, 150);
+    }
+
+    /// Disc with a data track and an audio track with a 2-second pregap
+    fn two_track_disc() -> DiscImage {
+        let tracks = vec![
+            Track {
+                number: 1,
+                track_type: TrackType::Mode2_2352,
+                start_position: CDPosition::new(0, 2, 0),
+                length_sectors: 300,
+                file_offset: 0,
+                pregap_sectors: 0,
+                gap_sectors: 0,
+            },
+            Track {
+                number: 2,
+                track_type: TrackType::Audio,
+                start_position: CDPosition::new(0, 8, 0),
+                length_sectors: 100,
+                file_offset: 450 * 2352,
+                pregap_sectors: 150,
+                gap_sectors: 0,
+            },
+        ];
+
+        DiscImage {
+            tracks,
+            data: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_subchannel_q_data_track() {
+        let disc = two_track_disc();
+
+        let q = disc.subchannel_q(&CDPosition::new(0, 3, 10)).unwrap();
+
+        assert_eq!(q.control_adr, 0x41);
+        assert_eq!(q.track, 1);
+        assert_eq!(q.index, 1);
+        assert_eq!(q.relative, CDPosition::new(0, 1, 10));
+        assert_eq!(q.absolute, CDPosition::new(0, 3, 10));
+    }
+
+    #[test]
+    fn test_subchannel_q_pregap_is_index_0() {
+        let disc = two_track_disc();
+
+        // 00:07:74 is the last pregap sector before track 2
+        let q = disc.subchannel_q(&CDPosition::new(0, 7, 74)).unwrap();
+        assert_eq!(q.control_adr, 0x01);
+        assert_eq!(q.track, 2);
+        assert_eq!(q.index, 0);
+        assert_eq!(q.relative, CDPosition::new(0, 0, 1));
+
+        // Transition to index 1 at the track start
+        let q = disc.subchannel_q(&CDPosition::new(0, 8, 0)).unwrap();
+        assert_eq!(q.track, 2);
+        assert_eq!(q.index, 1);
+        assert_eq!(q.relative, CDPosition::new(0, 0, 0));
+    }
+
+    #[test]
+    fn test_subchannel_q_first_track_pregap() {
+        let disc = two_track_disc();
+
+        let q = disc.subchannel_q(&CDPosition::new(0, 0, 0)).unwrap();
+
+        assert_eq!(q.track, 1);
+        assert_eq!(q.index, 0);
+        assert_eq!(q.relative, CDPosition::new(0, 2, 0));
+    }
+
+    #[test]
+    fn test_subchannel_q_to_bytes() {
+        let disc = two_track_disc();
+
+        let q = disc.subchannel_q(&CDPosition::new(0, 8, 12)).unwrap();
+
+        assert_eq!(
+            q.to_bytes(),
+            [0x01, 0x02, 0x01, 0x00, 0x00, 0x12, 0x00, 0x00, 0x08, 0x12]
+        );
+    }
+
+    #[test]
+    fn test_subchannel_q_outside_disc() {
+        let disc = two_track_disc();
+
+        assert!(disc.subchannel_q(&CDPosition::new(10, 0, 0)).is_none());
+    }
+
+    #[test]
+    fn test_track_at_position() {
+        let disc = DiscImage::new_dummy();
+
+        // Dummy track 1 covers 00:02:00 - 00:03:24 (100 sectors)
+        assert_eq!(disc.track_at(&CDPosition::new(0, 2, 0)).unwrap().number, 1);
+        assert_eq!(disc.track_at(&CDPosition::new(0, 3, 24)).unwrap().number, 1);
+        assert!(disc.track_at(&CDPosition::new(0, 3, 25)).is_none());
+    }
+
     #[test]
     fn test_load_disc_invalid_cue_path() {
         let result = DiscImage::load("nonexistent.cue");
@@ -717,8 +1295,55 @@ mod tests {
 "#;
         let (cue_file, _dir) = create_test_cue_file(cue_content);
 
-        let result = DiscImage::load(cue_file.path().to_str().unwrap());
-        assert!(result.is_err());
+        match DiscImage::load(cue_file.path().to_str().unwrap()) {
+            Err(CdRomError::MissingBinFile { path }) => assert!(path.ends_with("missing.bin")),
+            other => panic!("Expected MissingBinFile, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn test_load_disc_truncated_bin() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("short.bin"), vec![0u8; 10 * 2352 - 100]).unwrap();
+
+        let cue_file = NamedTempFile::new_in(dir.path()).unwrap();
+        std::fs::write(
+            cue_file.path(),
+            "FILE \"short.bin\" BINARY\n  TRACK 01 MODE2/2352\n    INDEX 01 00:00:00\n",
+        )
+        .unwrap();
+
+        match DiscImage::load(cue_file.path().to_str().unwrap()) {
+            Err(CdRomError::InvalidBinSize { size, .. }) => assert_eq!(size, 10 * 2352 - 100),
+            other => panic!("Expected InvalidBinSize, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn test_load_disc_out_of_order_indices() {
+        let dir = TempDir::new().unwrap();
+        let bin_path = create_test_bin_file(&dir, "order.bin", 1500);
+
+        let cue_content = format!(
+            r#"FILE "{}" BINARY
+  TRACK 01 MODE2/2352
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    INDEX 00 00:12:00
+    INDEX 01 00:10:00
+"#,
+            bin_path.file_name().unwrap().to_str().unwrap()
+        );
+        let cue_file = NamedTempFile::new_in(dir.path()).unwrap();
+        std::fs::write(cue_file.path(), cue_content).unwrap();
+
+        let err = DiscImage::load(cue_file.path().to_str().unwrap()).unwrap_err();
+        match err {
+            CdRomError::IndexOutOfOrder { track, index, line } => {
+                assert_eq!((track, index, line), (2, 1, 6));
+            }
+            other => panic!("Expected IndexOutOfOrder, got {:?}", other),
+        }
     }
 
     #[test]
@@ -800,8 +1425,8 @@ mod tests {
         // Track 1 starts at 00:00:00
         assert_eq!(tracks[0].file_offset, 0);
 
-        // Track 2 starts at 00:10:00 (10 seconds = 750 frames, minus 150 pregap = 600 sectors)
-        let expected_offset = (10 * 75 - 150) as u64 * 2352;
+        // Track 2 starts at 00:10:00 in the file (10 seconds = 750 frames)
+        let expected_offset = (10 * 75) as u64 * 2352;
         assert_eq!(tracks[1].file_offset, expected_offset);
     }
 
@@ -846,6 +1471,112 @@ mod tests {
         assert_eq!(sector_data[2351], 5);
     }
 
+    /// Write a .bin where every sector is filled with its file sector index
+    /// and load it with the given track layout
+    fn load_mixed_disc(sectors: usize, tracks: &str) -> DiscImage {
+        let dir = TempDir::new().unwrap();
+        let bin_path = dir.path().join("mixed.bin");
+
+        let mut data = Vec::new();
+        for sector in 0..sectors {
+            data.extend(std::iter::repeat_n(sector as u8, 2352));
+        }
+        std::fs::write(&bin_path, data).unwrap();
+
+        let cue_path = dir.path().join("mixed.cue");
+        std::fs::write(&cue_path, format!("FILE \"mixed.bin\" BINARY\n{}", tracks)).unwrap();
+
+        DiscImage::load(cue_path.to_str().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_load_converts_to_disc_time() {
+        let disc = load_mixed_disc(
+            20,
+            r#"  TRACK 01 MODE2/2352
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    INDEX 00 00:00:10
+    INDEX 01 00:00:12
+"#,
+        );
+
+        let track1 = disc.get_track(1).unwrap();
+        let track2 = disc.get_track(2).unwrap();
+
+        // Data starts after the 2-second lead-in
+        assert_eq!(track1.start_position, CDPosition::new(0, 2, 0));
+        assert_eq!(track1.length_sectors, 10);
+
+        assert_eq!(track2.start_position, CDPosition::new(0, 2, 12));
+        assert_eq!(track2.pregap_sectors, 2);
+        assert_eq!(track2.length_sectors, 8);
+    }
+
+    #[test]
+    fn test_read_sector_multi_track_with_index_00() {
+        let disc = load_mixed_disc(
+            20,
+            r#"  TRACK 01 MODE2/2352
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    INDEX 00 00:00:10
+    INDEX 01 00:00:12
+"#,
+        );
+
+        // Data track
+        assert_eq!(disc.read_sector(&CDPosition::new(0, 2, 3)).unwrap()[0], 3);
+
+        // In-file pregap of the audio track
+        assert_eq!(disc.track_for_lba(10).unwrap().number, 2);
+        assert_eq!(disc.read_sector(&CDPosition::new(0, 2, 10)).unwrap()[0], 10);
+
+        // Audio frames are returned raw
+        let frame = disc.read_sector(&CDPosition::new(0, 2, 15)).unwrap();
+        assert_eq!(frame.len(), 2352);
+        assert_eq!(frame[0], 15);
+    }
+
+    #[test]
+    fn test_read_sector_with_pregap_directive() {
+        let disc = load_mixed_disc(
+            20,
+            r#"  TRACK 01 MODE2/2352
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    PREGAP 00:02:00
+    INDEX 01 00:00:10
+"#,
+        );
+
+        let track2 = disc.get_track(2).unwrap();
+
+        // The 150-sector gap is not in the file but shifts the track
+        assert_eq!(track2.start_position, CDPosition::from_lba(160));
+        assert_eq!(track2.pregap_sectors, 150);
+        assert_eq!(track2.file_offset, 10 * 2352);
+        assert_eq!(disc.get_track(1).unwrap().length_sectors, 10);
+
+        // Gap reads as silence
+        assert_eq!(disc.file_offset_for_lba(12), Some(None));
+        let gap = disc.read_sector(&CDPosition::from_lba(12)).unwrap();
+        assert!(gap.iter().all(|&b| b == 0));
+
+        // Track 2 data comes from file sector 10 onward
+        assert_eq!(disc.file_offset_for_lba(161), Some(Some(11 * 2352)));
+        assert_eq!(disc.read_sector(&CDPosition::from_lba(161)).unwrap()[0], 11);
+    }
+
+    #[test]
+    fn test_file_offset_for_lba_outside_disc() {
+        let disc = DiscImage::new_dummy();
+
+        assert_eq!(disc.file_offset_for_lba(-1), None);
+        assert_eq!(disc.file_offset_for_lba(100), None);
+        assert_eq!(disc.file_offset_for_lba(0), Some(Some(0)));
+    }
+
     #[test]
     fn test_new_dummy() {
         let disc = DiscImage::new_dummy();
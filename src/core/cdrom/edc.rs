@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sector error detection (EDC)
+//!
+//! Data sectors carry a 32-bit EDC (a CRC-32 with polynomial 0x8001801B,
+//! processed LSB first) which the drive checks before delivering the
+//! sector. The checked range depends on the sector mode:
+//!
+//! ```text
+//! Mode 1:        EDC over 0x000-0x80F, stored at 0x810
+//! Mode 2 Form 1: EDC over 0x010-0x817, stored at 0x818
+//! Mode 2 Form 2: EDC optional, not checked
+//! ```
+
+use super::xa_adpcm::{SUBHEADER_OFFSET, SUBMODE_FORM2};
+
+/// Sync pattern at the start of every raw data sector
+const SYNC_PATTERN: [u8; 12] = [
+    0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00,
+];
+
+/// Reversed EDC polynomial
+const EDC_POLY: u32 = 0xD801_8001;
+
+/// CRC lookup table, built at compile time
+const EDC_TABLE: [u32; 256] = build_table();
+
+const fn build_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut edc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            edc = (edc >> 1) ^ if edc & 1 != 0 { EDC_POLY } else { 0 };
+            bit += 1;
+        }
+        table[i] = edc;
+        i += 1;
+    }
+    table
+}
+
+/// Compute the EDC of a byte range
+///
+/// # Arguments
+///
+/// * `data` - Bytes covered by the EDC
+///
+/// # Returns
+///
+/// 32-bit EDC value
+pub(super) fn compute_edc(data: &[u8]) -> u32 {
+    data.iter().fold(0u32, |edc, &byte| {
+        (edc >> 8) ^ EDC_TABLE[((edc ^ byte as u32) & 0xFF) as usize]
+    })
+}
+
+/// Verify the EDC of a raw 2352-byte sector
+///
+/// Only Mode 1 and Mode 2 Form 1 sectors are checked. Audio sectors,
+/// Form 2 sectors and sectors without a sync pattern (e.g. CD-DA or
+/// synthesized silence) are always accepted.
+///
+/// # Arguments
+///
+/// * `sector` - Raw sector data
+///
+/// # Returns
+///
+/// false if the sector is a data sector with a mismatching EDC
+pub(super) fn verify_sector(sector: &[u8]) -> bool {
+    if sector.len() < 2352 || sector[..12] != SYNC_PATTERN {
+        return true;
+    }
+
+    let (range, stored_at) = match sector[15] {
+        1 => (0..0x810, 0x810),
+        2 if sector[SUBHEADER_OFFSET + 2] & SUBMODE_FORM2 == 0 => (0x10..0x818, 0x818),
+        _ => return true,
+    };
+
+    let stored = u32::from_le_bytes([
+        sector[stored_at],
+        sector[stored_at + 1],
+        sector[stored_at + 2],
+        sector[stored_at + 3],
+    ]);
+
+    compute_edc(&sector[range]) == stored
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    /// Build a raw Mode 1 sector with a valid EDC
+    pub(in crate::core::cdrom) fn mode1_sector(fill: u8) -> Vec<u8> {
+        let mut sector = vec![0u8; 2352];
+        sector[..12].copy_from_slice(&SYNC_PATTERN);
+        sector[15] = 1;
+        sector[16..0x810].fill(fill);
+        let edc = compute_edc(&sector[..0x810]);
+        sector[0x810..0x814].copy_from_slice(&edc.to_le_bytes());
+        sector
+    }
+
+    #[test]
+    fn test_compute_edc_known_value() {
+        assert_eq!(compute_edc(&[]), 0);
+        // Single set bit in the first byte yields the table entry
+        assert_eq!(compute_edc(&[0x01]), EDC_TABLE[1]);
+        assert_eq!(EDC_TABLE[0x80], EDC_POLY);
+    }
+
+    #[test]
+    fn test_verify_mode1_sector() {
+        let mut sector = mode1_sector(0x5A);
+        assert!(verify_sector(&sector));
+
+        sector[0x100] ^= 0x01;
+        assert!(!verify_sector(&sector));
+    }
+
+    #[test]
+    fn test_verify_mode2_form1_sector() {
+        let mut sector = vec![0u8; 2352];
+        sector[..12].copy_from_slice(&SYNC_PATTERN);
+        sector[15] = 2;
+        sector[24..0x818].fill(0xA5);
+        let edc = compute_edc(&sector[0x10..0x818]);
+        sector[0x818..0x81C].copy_from_slice(&edc.to_le_bytes());
+        assert!(verify_sector(&sector));
+
+        sector[0x200] = 0;
+        assert!(!verify_sector(&sector));
+    }
+
+    #[test]
+    fn test_verify_skips_form2_and_audio() {
+        // Form 2 sectors are not checked
+        let mut sector = vec![0u8; 2352];
+        sector[..12].copy_from_slice(&SYNC_PATTERN);
+        sector[15] = 2;
+        sector[SUBHEADER_OFFSET + 2] = SUBMODE_FORM2;
+        sector[100] = 0xFF;
+        assert!(verify_sector(&sector));
+
+        // No sync pattern (CD-DA)
+        assert!(verify_sector(&[0x12u8; 2352]));
+    }
+}
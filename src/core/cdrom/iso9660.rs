@@ -0,0 +1,406 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! ISO9660 file system support
+//!
+//! PlayStation discs use a standard ISO9660 file system on the data track.
+//! Files are located by reading the Primary Volume Descriptor (PVD) and
+//! walking directory records from the root directory.
+//!
+//! # Layout
+//!
+//! ```text
+//! Sector 16:      Primary Volume Descriptor ("\x01CD001")
+//!   +156:         Root directory record (34 bytes)
+//! Directory:      Sequence of variable-length directory records
+//!   +0:           Record length (0 = padding until next sector)
+//!   +2:           Extent LBA (little-endian u32)
+//!   +10:          Data length (little-endian u32)
+//!   +25:          Flags (bit 1 = directory)
+//!   +32:          Name length
+//!   +33:          Name ("FILE.EXT;1")
+//! ```
+
+use super::{CDPosition, DiscImage};
+use crate::core::error::CdRomError;
+
+/// Size of the user data area of a Mode 1 / Mode 2 Form 1 sector
+const LOGICAL_SECTOR_SIZE: usize = 2048;
+
+/// Sector containing the Primary Volume Descriptor
+const PVD_SECTOR: u32 = 16;
+
+/// Offset of the root directory record inside the PVD
+const ROOT_RECORD_OFFSET: usize = 156;
+
+/// A parsed ISO9660 directory record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct DirectoryRecord {
+    /// Name without the ";1" version suffix
+    pub(super) name: String,
+
+    /// First sector of the extent
+    pub(super) lba: u32,
+
+    /// Extent length in bytes
+    pub(super) size: u32,
+
+    /// Whether this record describes a directory
+    pub(super) is_dir: bool,
+}
+
+impl DirectoryRecord {
+    /// Parse a single directory record
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Record bytes, starting at the length byte
+    ///
+    /// # Returns
+    ///
+    /// The parsed record, or `None` if the record is truncated
+    fn parse(data: &[u8]) -> Option<Self> {
+        let name_len = *data.get(32)? as usize;
+        let raw_name = data.get(33..33 + name_len)?;
+
+        let name = match raw_name {
+            [0x00] => ".".to_string(),
+            [0x01] => "..".to_string(),
+            _ => strip_version(&String::from_utf8_lossy(raw_name)).to_string(),
+        };
+
+        Some(Self {
+            name,
+            lba: u32::from_le_bytes(data.get(2..6)?.try_into().ok()?),
+            size: u32::from_le_bytes(data.get(10..14)?.try_into().ok()?),
+            is_dir: data.get(25)? & 0x02 != 0,
+        })
+    }
+}
+
+/// Strip the ";N" version suffix from an ISO9660 file name
+fn strip_version(name: &str) -> &str {
+    name.split(';').next().unwrap_or(name)
+}
+
+impl DiscImage {
+    /// Read the 2048-byte user data area of a sector
+    ///
+    /// # Arguments
+    ///
+    /// * `lba` - Logical block address (0 = 00:02:00)
+    ///
+    /// # Returns
+    ///
+    /// User data of a Mode 1 or Mode 2 Form 1 sector
+    fn read_user_data(&self, lba: u32) -> Result<&[u8], CdRomError> {
+        let sector = self
+            .read_sector(&CDPosition::from_lba(lba as i32))
+            .ok_or(CdRomError::InvalidSector { sector: lba })?;
+
+        // Mode byte follows the 12-byte sync pattern and 3-byte MSF header
+        let offset = match sector[15] {
+            1 => 16,
+            2 => 24, // Skip the 8-byte subheader
+            mode => {
+                return Err(CdRomError::ReadError {
+                    sector: lba,
+                    reason: format!("Unsupported sector mode {}", mode),
+                })
+            }
+        };
+
+        Ok(&sector[offset..offset + LOGICAL_SECTOR_SIZE])
+    }
+
+    /// Read a contiguous extent, trimmed to its length in bytes
+    fn read_extent(&self, lba: u32, size: u32) -> Result<Vec<u8>, CdRomError> {
+        let size = size as usize;
+        let sectors = size.div_ceil(LOGICAL_SECTOR_SIZE) as u32;
+
+        let mut data = Vec::with_capacity(sectors as usize * LOGICAL_SECTOR_SIZE);
+        for i in 0..sectors {
+            data.extend_from_slice(self.read_user_data(lba + i)?);
+        }
+
+        data.truncate(size);
+        Ok(data)
+    }
+
+    /// Read the root directory record from the Primary Volume Descriptor
+    fn root_directory(&self) -> Result<DirectoryRecord, CdRomError> {
+        let pvd = self.read_user_data(PVD_SECTOR)?;
+
+        if pvd[0] != 0x01 || &pvd[1..6] != b"CD001" {
+            return Err(CdRomError::ReadError {
+                sector: PVD_SECTOR,
+                reason: "Missing ISO9660 Primary Volume Descriptor".to_string(),
+            });
+        }
+
+        DirectoryRecord::parse(&pvd[ROOT_RECORD_OFFSET..]).ok_or(CdRomError::ReadError {
+            sector: PVD_SECTOR,
+            reason: "Invalid root directory record".to_string(),
+        })
+    }
+
+    /// List the records of a directory extent
+    ///
+    /// Records never cross sector boundaries; a zero length byte marks
+    /// padding up to the next sector.
+    pub(super) fn read_directory(
+        &self,
+        dir: &DirectoryRecord,
+    ) -> Result<Vec<DirectoryRecord>, CdRomError> {
+        let data = self.read_extent(dir.lba, dir.size)?;
+        let mut records = Vec::new();
+
+        for block in data.chunks(LOGICAL_SECTOR_SIZE) {
+            let mut offset = 0;
+            while offset < block.len() {
+                let len = block[offset] as usize;
+                if len == 0 {
+                    break;
+                }
+
+                if let Some(record) = DirectoryRecord::parse(&block[offset..]) {
+                    records.push(record);
+                }
+                offset += len;
+            }
+        }
+
+        Ok(records)
+    }
+
+    /// Locate a file by path
+    ///
+    /// Path components may be separated by `\` or `/`, an optional `cdrom:`
+    /// prefix is ignored, and names are matched case-insensitively with or
+    /// without the ";1" version suffix.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - File path (e.g., "cdrom:\\SLUS_000.01;1")
+    ///
+    /// # Returns
+    ///
+    /// Directory record of the file
+    pub(super) fn find_file(&self, path: &str) -> Result<DirectoryRecord, CdRomError> {
+        let not_found = || CdRomError::DiscLoadError(format!("File not found: {}", path));
+
+        let trimmed = path.trim();
+        let trimmed = trimmed
+            .get(..6)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("cdrom:"))
+            .map_or(trimmed, |_| &trimmed[6..]);
+
+        let mut current = self.root_directory()?;
+        let mut components = trimmed
+            .split(['\\', '/'])
+            .filter(|c| !c.is_empty())
+            .peekable();
+
+        while let Some(component) = components.next() {
+            let name = strip_version(component);
+            let is_last = components.peek().is_none();
+
+            current = self
+                .read_directory(&current)?
+                .into_iter()
+                .find(|r| r.name.eq_ignore_ascii_case(name) && (is_last || r.is_dir))
+                .ok_or_else(not_found)?;
+        }
+
+        if current.is_dir {
+            return Err(not_found());
+        }
+
+        Ok(current)
+    }
+
+    /// Read a file by path from the ISO9660 file system
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - File path (e.g., "SYSTEM.CNF;1" or "cdrom:\\DATA\\FILE.BIN;1")
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<u8>)` - File contents, trimmed to the file length
+    /// - `Err(CdRomError)` - If the file system is invalid or the file is missing
+    pub fn read_file(&self, path: &str) -> Result<Vec<u8>, CdRomError> {
+        let record = self.find_file(path)?;
+
+        log::debug!(
+            "ISO9660: {} at LBA {} ({} bytes)",
+            path,
+            record.lba,
+            record.size
+        );
+
+        self.read_extent(record.lba, record.size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cdrom::CDROM;
+
+    const SECTOR_SIZE: usize = 2352;
+
+    /// Write a directory record into `buf` and return its length
+    fn write_record(buf: &mut [u8], name: &[u8], lba: u32, size: u32, is_dir: bool) -> usize {
+        let len = 33 + name.len() + (name.len() + 1) % 2;
+        buf[0] = len as u8;
+        buf[2..6].copy_from_slice(&lba.to_le_bytes());
+        buf[6..10].copy_from_slice(&lba.to_be_bytes());
+        buf[10..14].copy_from_slice(&size.to_le_bytes());
+        buf[14..18].copy_from_slice(&size.to_be_bytes());
+        buf[25] = if is_dir { 0x02 } else { 0x00 };
+        buf[32] = name.len() as u8;
+        buf[33..33 + name.len()].copy_from_slice(name);
+        len
+    }
+
+    /// Byte range of the user data area of a Mode 2 Form 1 sector
+    fn user_data_range(lba: usize) -> std::ops::Range<usize> {
+        let start = lba * SECTOR_SIZE + 24;
+        start..start + LOGICAL_SECTOR_SIZE
+    }
+
+    /// Build a small Mode 2 ISO9660 image:
+    ///
+    /// ```text
+    /// /SYSTEM.CNF;1      (LBA 20, 30 bytes)
+    /// /DATA/FILE.BIN;1   (LBA 21, 3000 bytes)
+    /// ```
+    fn synthetic_iso() -> DiscImage {
+        let sectors = 24;
+        let mut data = vec![0u8; sectors * SECTOR_SIZE];
+
+        for lba in 0..sectors {
+            data[lba * SECTOR_SIZE + 15] = 2; // Mode 2
+        }
+
+        // Primary Volume Descriptor
+        let range = user_data_range(16);
+        let pvd = &mut data[range];
+        pvd[0] = 0x01;
+        pvd[1..6].copy_from_slice(b"CD001");
+        write_record(&mut pvd[156..], &[0x00], 18, 2048, true);
+
+        // Root directory
+        let range = user_data_range(18);
+        let root = &mut data[range];
+        let mut offset = write_record(root, &[0x00], 18, 2048, true);
+        offset += write_record(&mut root[offset..], &[0x01], 18, 2048, true);
+        offset += write_record(&mut root[offset..], b"DATA", 19, 2048, true);
+        write_record(&mut root[offset..], b"SYSTEM.CNF;1", 20, 30, false);
+
+        // DATA directory
+        let range = user_data_range(19);
+        let dir = &mut data[range];
+        let mut offset = write_record(dir, &[0x00], 19, 2048, true);
+        offset += write_record(&mut dir[offset..], &[0x01], 18, 2048, true);
+        write_record(&mut dir[offset..], b"FILE.BIN;1", 21, 3000, false);
+
+        // SYSTEM.CNF contents
+        let range = user_data_range(20);
+        data[range.start..range.start + 30].copy_from_slice(b"BOOT = cdrom:\\SLUS_000.01;1\r\n\0");
+
+        // FILE.BIN contents spanning two sectors
+        for i in 0..3000 {
+            let lba = 21 + i / 2048;
+            let start = user_data_range(lba).start;
+            data[start + i % 2048] = (i % 251) as u8;
+        }
+
+        DiscImage::from_raw(data)
+    }
+
+    #[test]
+    fn test_read_file_in_root() {
+        let disc = synthetic_iso();
+
+        let data = disc.read_file("SYSTEM.CNF;1").unwrap();
+
+        assert_eq!(data.len(), 30);
+        assert!(data.starts_with(b"BOOT = cdrom:\\SLUS_000.01;1"));
+    }
+
+    #[test]
+    fn test_read_file_in_subdirectory_spanning_sectors() {
+        let disc = synthetic_iso();
+
+        let data = disc.read_file("cdrom:\\DATA\\FILE.BIN;1").unwrap();
+
+        assert_eq!(data.len(), 3000);
+        for (i, &byte) in data.iter().enumerate() {
+            assert_eq!(byte, (i % 251) as u8);
+        }
+    }
+
+    #[test]
+    fn test_read_file_path_variants() {
+        let disc = synthetic_iso();
+
+        assert!(disc.read_file("data/file.bin").is_ok());
+        assert!(disc.read_file("\\SYSTEM.CNF").is_ok());
+    }
+
+    #[test]
+    fn test_read_file_not_found() {
+        let disc = synthetic_iso();
+
+        assert!(disc.read_file("MISSING.EXE;1").is_err());
+        assert!(disc.read_file("DATA\\MISSING.BIN;1").is_err());
+        // Directories are not files
+        assert!(disc.read_file("DATA").is_err());
+    }
+
+    #[test]
+    fn test_read_file_without_pvd() {
+        let disc = DiscImage::new_dummy();
+
+        assert!(disc.read_file("SYSTEM.CNF;1").is_err());
+    }
+
+    #[test]
+    fn test_read_directory_lists_records() {
+        let disc = synthetic_iso();
+        let root = disc.root_directory().unwrap();
+
+        let names: Vec<_> = disc
+            .read_directory(&root)
+            .unwrap()
+            .into_iter()
+            .map(|r| r.name)
+            .collect();
+
+        assert_eq!(names, vec![".", "..", "DATA", "SYSTEM.CNF"]);
+    }
+
+    #[test]
+    fn test_cdrom_read_file_uses_iso9660() {
+        let mut cdrom = CDROM::new();
+        cdrom.disc = Some(synthetic_iso());
+
+        let data = cdrom.read_file("SYSTEM.CNF;1").unwrap();
+
+        assert_eq!(data.len(), 30);
+    }
+}
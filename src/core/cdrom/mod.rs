@@ -26,19 +26,27 @@
 //!
 //! The CD-ROM controller supports various commands sent via the command register:
 //!
-//! | Command | Name    | Description                              |
-//! |---------|---------|------------------------------------------|
-//! | 0x01    | GetStat | Get current drive status                 |
-//! | 0x02    | SetLoc  | Set seek target position (MSF format)    |
-//! | 0x06    | ReadN   | Start reading data sectors               |
-//! | 0x09    | Pause   | Pause reading or audio playback          |
-//! | 0x0A    | Init    | Initialize drive                         |
-//! | 0x0E    | SetMode | Set drive mode (speed, sector size, etc) |
-//! | 0x15    | SeekL   | Seek to target position (data)           |
-//! | 0x19    | Test    | Test/diagnostic commands                 |
-//! | 0x1A    | GetID   | Get disc identification                  |
-//! | 0x1B    | ReadS   | Start reading sectors with retry         |
-//! | 0x1E    | ReadTOC | Read table of contents                   |
+//! | Command | Name      | Description                              |
+//! |---------|-----------|------------------------------------------|
+//! | 0x01    | GetStat   | Get current drive status                 |
+//! | 0x02    | SetLoc    | Set seek target position (MSF format)    |
+//! | 0x03    | Play      | Start CD-DA audio playback               |
+//! | 0x06    | ReadN     | Start reading data sectors               |
+//! | 0x08    | Stop      | Stop reading/playback and motor          |
+//! | 0x09    | Pause     | Pause reading or audio playback          |
+//! | 0x0A    | Init      | Initialize drive                         |
+//! | 0x0D    | SetFilter | Set XA-ADPCM file/channel filter         |
+//! | 0x0E    | SetMode   | Set drive mode (speed, sector size, etc) |
+//! | 0x10    | GetlocL   | Get header of last read sector           |
+//! | 0x11    | GetlocP   | Get subchannel Q position                |
+//! | 0x13    | GetTN     | Get first and last track numbers         |
+//! | 0x14    | GetTD     | Get track start position                 |
+//! | 0x15    | SeekL     | Seek to target position (data)           |
+//! | 0x16    | SeekP     | Seek to target position (audio)          |
+//! | 0x19    | Test      | Test/diagnostic commands                 |
+//! | 0x1A    | GetID     | Get disc identification                  |
+//! | 0x1B    | ReadS     | Start reading sectors with retry         |
+//! | 0x1E    | ReadTOC   | Read table of contents                   |
 //!
 //! # MSF Addressing
 //!
@@ -75,14 +83,19 @@
 
 use std::collections::VecDeque;
 
+use super::save_state::{CDROMState, StateSave};
 use super::timing::{EventHandle, TickCount};
 
 pub mod cd_audio;
 mod commands;
 mod disc;
+mod edc;
+mod iso9660;
+pub mod xa_adpcm;
 
 pub use cd_audio::CDAudio;
-pub use disc::{DiscImage, Track, TrackType};
+pub use disc::{DiscImage, DiscRegion, SubchannelQ, Track, TrackType};
+pub use xa_adpcm::XaDecoder;
 
 /// Second response types for command completion
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -99,6 +112,8 @@ enum SecondResponseType {
     Pause,
     /// Seek command second response
     Seek,
+    /// Stop command second response
+    Stop,
 }
 
 /// CD-ROM drive controller
@@ -124,6 +139,11 @@ pub struct CDROM {
     /// Current index in data buffer for byte-by-byte reading
     pub(super) data_index: usize,
 
+    /// Header and subheader of the last sector read (for GetlocL)
+    ///
+    /// MM, SS, FF, Mode, File, Channel, Submode, Codinginfo
+    pub(super) last_sector_header: Option<[u8; 8]>,
+
     /// Cycle counter for sector reading timing
     pub(super) read_ticks: u32,
 
@@ -139,12 +159,34 @@ pub struct CDROM {
     /// Target seek position
     pub(super) seek_target: Option<CDPosition>,
 
+    /// Addressing of the seek in progress (SeekL or SeekP)
+    pub(super) seek_kind: SeekKind,
+
+    /// Track number currently being played by the Play command (0 = none)
+    pub(super) play_track: u8,
+
+    /// Channel of the peak level in the next CD-DA report (false = left)
+    pub(super) report_peak_right: bool,
+
+    /// Audio volume values written by the CPU, applied on the next latch
+    /// (L→L, L→R, R→R, R→L)
+    pub(super) pending_volume: [u8; 4],
+
+    /// Remaining cycles until the motor has spun up after closing the shell
+    pub(super) spin_up_ticks: u32,
+
     /// Interrupt flag (5 levels: bit 0-4 for INT1-INT5)
     pub(super) interrupt_flag: u8,
 
     /// Interrupt enable mask
     interrupt_enable: u8,
 
+    /// Level of the IRQ output (any enabled interrupt flag set)
+    irq_line: bool,
+
+    /// IRQ output rose since the system last polled it
+    irq_raised: bool,
+
     /// Status register
     pub(super) status: CDStatus,
 
@@ -154,9 +196,23 @@ pub struct CDROM {
     /// CD audio player
     pub(crate) cd_audio: CDAudio,
 
+    /// XA-ADPCM decoder for streamed audio sectors
+    xa_decoder: XaDecoder,
+
+    /// XA filter file number (sectors must match when `xa_filter` is set)
+    pub(super) xa_filter_file: u8,
+
+    /// XA filter channel number (sectors must match when `xa_filter` is set)
+    pub(super) xa_filter_channel: u8,
+
     /// Drive mode settings (speed, sector size, etc)
     pub(super) mode: CDMode,
 
+    /// Region reported by GetID for data discs without license text
+    ///
+    /// None reports such discs as unlicensed.
+    pub(super) region: Option<DiscRegion>,
+
     /// Current index/status register select
     index: u8,
 
@@ -219,8 +275,40 @@ pub(super) struct CDMode {
     /// Auto pause at end of track
     pub(super) auto_pause: bool,
 
-    /// Report CD-DA audio sectors
-    pub(super) cdda_report: bool,
+    /// Allow reading CD-DA sectors (SeekL and reads may land on audio)
+    pub(super) cdda_enabled: bool,
+}
+
+impl CDMode {
+    /// Decode a SetMode parameter byte
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Mode byte (see [`CDROM::cmd_setmode`])
+    pub(super) fn from_byte(mode: u8) -> Self {
+        Self {
+            cdda_enabled: mode & 0x01 != 0,
+            auto_pause: mode & 0x02 != 0,
+            report_all: mode & 0x04 != 0,
+            xa_filter: mode & 0x08 != 0,
+            ignore_bit: mode & 0x10 != 0,
+            size_2340: mode & 0x20 != 0,
+            xa_adpcm: mode & 0x40 != 0,
+            double_speed: mode & 0x80 != 0,
+        }
+    }
+
+    /// Encode as a SetMode parameter byte
+    pub(super) fn to_byte(self) -> u8 {
+        (self.cdda_enabled as u8)
+            | (self.auto_pause as u8) << 1
+            | (self.report_all as u8) << 2
+            | (self.xa_filter as u8) << 3
+            | (self.ignore_bit as u8) << 4
+            | (self.size_2340 as u8) << 5
+            | (self.xa_adpcm as u8) << 6
+            | (self.double_speed as u8) << 7
+    }
 }
 
 /// CD-ROM drive state
@@ -233,10 +321,19 @@ pub(super) enum CDState {
     /// Seeking to target position
     Seeking,
     /// Playing audio CD
-    #[allow(dead_code)]
     Playing,
 }
 
+/// Addressing used by the pending seek
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) enum SeekKind {
+    /// SeekL: lock onto the data sector header at the target
+    #[default]
+    Logical,
+    /// SeekP: lock onto the subchannel Q position at the target
+    Physical,
+}
+
 /// CD-ROM position in MSF (Minute:Second:Frame) format
 ///
 /// All values are stored as decimal (not BCD).
@@ -296,7 +393,6 @@ pub(super) struct CDStatus {
     /// Currently seeking
     pub(super) seeking: bool,
     /// Currently playing audio
-    #[allow(dead_code)]
     pub(super) playing: bool,
 }
 
@@ -340,8 +436,24 @@ impl CDROM {
     /// Init second response delay (~2ms)
     const INIT_SECOND_RESPONSE_DELAY: TickCount = 70_000;
 
-    /// Seek second response delay (simplified, varies by distance)
-    const SEEK_SECOND_RESPONSE_DELAY: TickCount = 100_000;
+    /// Motor spin-up time after closing the shell (~1s)
+    const SPIN_UP_CYCLES: u32 = 33_868_800;
+
+    /// Stop second response delay (~2ms, motor spin-down)
+    const STOP_SECOND_RESPONSE_DELAY: TickCount = 70_000;
+
+    // Seek timing constants
+    /// Minimum seek time for short seeks within the same region (~1ms)
+    const SEEK_MIN_CYCLES: u32 = 33_868;
+
+    /// Seek time for a full-disc seek (~300ms)
+    const SEEK_MAX_CYCLES: u32 = 10_160_640;
+
+    /// Seeks up to this many sectors only cost the minimum seek time
+    const SEEK_SHORT_DISTANCE: u32 = 75;
+
+    /// Number of sectors on a full 74-minute disc
+    const DISC_MAX_SECTORS: u32 = 74 * 60 * 75;
 
     /// Create a new CD-ROM controller
     ///
@@ -361,17 +473,29 @@ impl CDROM {
             response_fifo: VecDeque::new(),
             data_buffer: Vec::new(),
             data_index: 0,
+            last_sector_header: None,
             read_ticks: 0,
             seek_ticks: 0,
             state: CDState::Idle,
             position: CDPosition::new(0, 2, 0),
             seek_target: None,
+            seek_kind: SeekKind::Logical,
+            play_track: 0,
+            report_peak_right: false,
+            pending_volume: [0x80, 0x00, 0x80, 0x00],
+            spin_up_ticks: 0,
             interrupt_flag: 0,
+            irq_line: false,
+            irq_raised: false,
             interrupt_enable: 0,
             status: CDStatus::default(),
             disc: None,
             cd_audio: CDAudio::new(),
+            xa_decoder: XaDecoder::new(),
+            xa_filter_file: 0,
+            xa_filter_channel: 0,
             mode: CDMode::default(),
+            region: None,
             index: 0,
             command_event: None,
             command_second_response_event: None,
@@ -438,6 +562,7 @@ impl CDROM {
     /// When INT5 is acknowledged, also clears latched error status flags.
     pub fn acknowledge_interrupt(&mut self, value: u8) {
         self.interrupt_flag &= !value;
+        self.update_irq_line();
         if value & 0x10 != 0 {
             self.status.error = false;
             self.status.seek_error = false;
@@ -449,6 +574,7 @@ impl CDROM {
     /// Set interrupt enable mask
     pub fn set_interrupt_enable(&mut self, value: u8) {
         self.interrupt_enable = value & 0x1F;
+        self.update_irq_line();
         log::trace!(
             "CD-ROM: Set interrupt enable 0x{:02X}",
             self.interrupt_enable
@@ -460,6 +586,28 @@ impl CDROM {
         self.interrupt_enable
     }
 
+    /// Take a pending rising edge of the IRQ output
+    ///
+    /// The output is the OR of the enabled interrupt flags. Each time it
+    /// goes active the interrupt controller must see a new request, even if
+    /// the flag was acknowledged and raised again between two polls.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the IRQ output went active since the last call
+    pub fn take_irq(&mut self) -> bool {
+        std::mem::take(&mut self.irq_raised)
+    }
+
+    /// Recompute the IRQ output after the flag or enable mask changed
+    fn update_irq_line(&mut self) {
+        let active = self.interrupt_flag & self.interrupt_enable & 0x1F != 0;
+        if active && !self.irq_line {
+            self.irq_raised = true;
+        }
+        self.irq_line = active;
+    }
+
     /// Set index register (for register selection)
     ///
     /// Bits 0-1: Register select (0-3)
@@ -608,6 +756,7 @@ impl CDROM {
         }
 
         self.interrupt_flag |= 1 << (level - 1);
+        self.update_irq_line();
         log::trace!("CD-ROM: Triggered INT{}", level);
     }
 
@@ -634,6 +783,28 @@ impl CDROM {
         self.trigger_interrupt(5); // INT5 (error)
     }
 
+    /// Report a sector that failed EDC verification
+    ///
+    /// Stops reading, latches the error flags and generates INT5 in place
+    /// of the INT1 that would have delivered the sector.
+    pub(super) fn sector_read_error(&mut self) {
+        log::warn!(
+            "CD-ROM: EDC mismatch at {:02}:{:02}:{:02}",
+            self.position.minute,
+            self.position.second,
+            self.position.sector
+        );
+
+        self.state = CDState::Idle;
+        self.status.reading = false;
+        self.read_ticks = 0;
+        self.status.error = true;
+        self.status.seek_error = true;
+        self.response_fifo.push_back(self.get_status_byte());
+        self.response_fifo.push_back(0x04); // Error code: Read error
+        self.trigger_interrupt(5); // INT5 (error)
+    }
+
     /// Load a disc image from a .cue file
     ///
     /// Loads the disc image and updates the drive state to reflect
@@ -673,6 +844,107 @@ impl CDROM {
         Ok(())
     }
 
+    /// Set the region reported for discs without license text
+    ///
+    /// Licensed discs always report their own region. With a region set,
+    /// unlicensed data discs (homebrew, patched images) are reported as
+    /// licensed for that region instead of failing the BIOS license check.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - Fallback region, or None to report unlicensed discs as such
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::cdrom::{DiscRegion, CDROM};
+    ///
+    /// let mut cdrom = CDROM::new();
+    /// cdrom.set_region(Some(DiscRegion::Europe));
+    /// ```
+    pub fn set_region(&mut self, region: Option<DiscRegion>) {
+        self.region = region;
+    }
+
+    /// Open the drive shell (eject the disc)
+    ///
+    /// Stops any reading or playback, removes the disc and spins down the
+    /// motor. GetStat reports the open shell until a new disc is inserted
+    /// with [`close_shell_with`](Self::close_shell_with).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::cdrom::CDROM;
+    ///
+    /// let mut cdrom = CDROM::new();
+    /// cdrom.open_shell();
+    /// assert!(cdrom.is_shell_open());
+    /// assert!(!cdrom.has_disc());
+    /// ```
+    pub fn open_shell(&mut self) {
+        log::info!("CD-ROM: Shell opened");
+
+        self.state = CDState::Idle;
+        self.status.reading = false;
+        self.status.seeking = false;
+        self.status.playing = false;
+        self.status.motor_on = false;
+        self.status.shell_open = true;
+        self.cd_audio.stop();
+        self.disc = None;
+        self.last_sector_header = None;
+        self.spin_up_ticks = 0;
+    }
+
+    /// Close the drive shell with a new disc inserted
+    ///
+    /// Loads the disc image immediately, but the drive keeps reporting the
+    /// open shell until the motor has spun up (~1 second of emulated time).
+    /// After that the shell-open and ID-error flags are cleared, so the next
+    /// GetID reports the new disc.
+    ///
+    /// # Arguments
+    ///
+    /// * `cue_path` - Path to the .cue file of the new disc
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the disc was loaded
+    /// - `Err(CdRomError)` if loading failed (the shell stays open)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use psrx::core::cdrom::CDROM;
+    ///
+    /// let mut cdrom = CDROM::new();
+    /// cdrom.open_shell();
+    /// cdrom.close_shell_with("disc2.cue").unwrap();
+    /// ```
+    pub fn close_shell_with(
+        &mut self,
+        cue_path: &str,
+    ) -> Result<(), crate::core::error::CdRomError> {
+        self.load_disc(cue_path)?;
+
+        // Shell stays reported open until the motor is up to speed
+        self.status.shell_open = true;
+        self.spin_up_ticks = Self::SPIN_UP_CYCLES;
+
+        log::info!("CD-ROM: Shell closed with {}, spinning up", cue_path);
+        Ok(())
+    }
+
+    /// Check if the drive shell is open
+    ///
+    /// # Returns
+    ///
+    /// true while the shell is open or the motor is still spinning up
+    pub fn is_shell_open(&self) -> bool {
+        self.status.shell_open
+    }
+
     /// Helper function to extract .bin file path from .cue file
     fn get_bin_path_from_cue(
         &self,
@@ -728,11 +1000,14 @@ impl CDROM {
     /// }
     /// ```
     pub fn read_current_sector(&mut self) -> Option<Vec<u8>> {
-        if let Some(ref disc) = self.disc {
-            disc.read_sector(&self.position).map(|data| data.to_vec())
-        } else {
-            None
-        }
+        let data = self.disc.as_ref()?.read_sector(&self.position)?.to_vec();
+
+        // Remember the header (12-15) and subheader (16-19) for GetlocL
+        let mut header = [0u8; 8];
+        header.copy_from_slice(&data[12..20]);
+        self.last_sector_header = Some(header);
+
+        Some(data)
     }
 
     /// Check if a disc is loaded
@@ -762,30 +1037,20 @@ impl CDROM {
         self.position = position;
     }
 
-    /// Read a file from the disc (simplified ISO9660 implementation)
+    /// Read a file from the disc's ISO9660 file system
     ///
-    /// This is a simplified implementation that can read specific files
-    /// from the PlayStation disc. It handles the special case of SYSTEM.CNF
-    /// which is typically located at a known sector.
+    /// Reads the Primary Volume Descriptor at sector 16, walks the directory
+    /// tree from the root directory and returns the file's contents.
     ///
     /// # Arguments
     ///
-    /// * `filename` - File name to read (e.g., "SYSTEM.CNF;1")
+    /// * `filename` - File path (e.g., "SYSTEM.CNF;1" or "cdrom:\\SLUS_000.01;1")
     ///
     /// # Returns
     ///
-    /// - `Ok(Vec<u8>)` - File data
+    /// - `Ok(Vec<u8>)` - File data, trimmed to the file length
     /// - `Err(CdRomError)` - If file not found or disc not loaded
     ///
-    /// # Implementation Note
-    ///
-    /// This is a simplified implementation. A full ISO9660 parser would:
-    /// 1. Read the Primary Volume Descriptor at sector 16
-    /// 2. Parse the root directory
-    /// 3. Search for the file in the directory tree
-    ///
-    /// For now, we use known sector locations for common files.
-    ///
     /// # Example
     ///
     /// ```no_run
@@ -796,41 +1061,11 @@ impl CDROM {
     /// // let system_cnf = cdrom.read_file("SYSTEM.CNF;1").unwrap();
     /// ```
     pub fn read_file(&mut self, filename: &str) -> Result<Vec<u8>, super::error::CdRomError> {
-        if !self.has_disc() {
+        let Some(ref disc) = self.disc else {
             return Err(super::error::CdRomError::NoDisc);
-        }
-
-        // Special case: SYSTEM.CNF is typically at sector 00:00:22 (LBA 22)
-        if filename.to_uppercase().starts_with("SYSTEM.CNF") {
-            let old_position = self.position;
-
-            // Seek to sector 00:00:22
-            self.position = CDPosition::from_lba(22);
-
-            if let Some(sector_data) = self.read_current_sector() {
-                // Restore original position
-                self.position = old_position;
-
-                // Extract text data (skip 24-byte header for Mode 2 sectors)
-                let text_start = 24;
-                let text_data = &sector_data[text_start..];
-
-                // Find null terminator or end of sector
-                let text_end = text_data
-                    .iter()
-                    .position(|&b| b == 0)
-                    .unwrap_or(text_data.len());
-
-                return Ok(text_data[..text_end].to_vec());
-            }
-        }
+        };
 
-        // For executables and other files, we would need full ISO9660 parsing
-        // For now, return an error
-        Err(super::error::CdRomError::DiscLoadError(format!(
-            "File not found: {}",
-            filename
-        )))
+        disc.read_file(filename)
     }
 
     /// Advance execution by the specified number of CPU cycles
@@ -863,33 +1098,62 @@ impl CDROM {
     /// }
     /// ```
     pub fn tick(&mut self, cycles: u32) {
+        // Handle motor spin-up after a disc change
+        if self.spin_up_ticks > 0 {
+            self.spin_up_ticks = self.spin_up_ticks.saturating_sub(cycles);
+            if self.spin_up_ticks == 0 {
+                self.status.shell_open = false;
+                self.status.id_error = false;
+                self.status.motor_on = true;
+                log::info!("CD-ROM: Motor spun up, disc ready");
+            }
+        }
+
         // Handle sector reading
         if self.state == CDState::Reading {
             self.read_ticks += cycles;
 
-            // Read one sector every ~13,300 cycles (at 1x speed)
-            // 75 sectors/second at ~33.8688 MHz CPU = ~451,584 cycles/second / 75 = ~6,021 cycles
-            // However, PSX-SPX documents that actual timing is closer to 13,300 cycles
-            const CYCLES_PER_SECTOR: u32 = 13_300;
+            // Read one sector every ~13,300 cycles at 1x speed (half at 2x)
+            let cycles_per_sector = self.cycles_per_sector() as u32;
 
-            if self.read_ticks >= CYCLES_PER_SECTOR {
-                self.read_ticks -= CYCLES_PER_SECTOR;
+            if self.read_ticks >= cycles_per_sector {
+                self.read_ticks -= cycles_per_sector;
 
                 if let Some(data) = self.read_current_sector() {
-                    self.data_buffer = data;
-                    self.data_index = 0;
-                    self.trigger_interrupt(1); // INT1 (data ready)
+                    if !edc::verify_sector(&data) {
+                        self.sector_read_error();
+                    } else {
+                        // XA-ADPCM audio sectors go to the mixer instead of the CPU
+                        if !self.process_xa_sector(&data) {
+                            self.data_buffer = data;
+                            self.data_index = 0;
+                            self.trigger_interrupt(1); // INT1 (data ready)
+                        }
+
+                        log::trace!(
+                            "CD-ROM: Read sector at {:02}:{:02}:{:02}",
+                            self.position.minute,
+                            self.position.second,
+                            self.position.sector
+                        );
+
+                        // Advance to next sector
+                        self.advance_position();
+                    }
+                }
+            }
+        }
 
-                    log::trace!(
-                        "CD-ROM: Read sector at {:02}:{:02}:{:02}",
-                        self.position.minute,
-                        self.position.second,
-                        self.position.sector
-                    );
+        // Handle CD-DA playback (audio is streamed by CDAudio, we only track position)
+        if self.state == CDState::Playing {
+            self.read_ticks += cycles;
 
-                    // Advance to next sector
-                    self.advance_position();
-                }
+            // Audio plays at 1x speed unless the mode selects 2x
+            let cycles_per_sector = self.cycles_per_sector() as u32;
+
+            while self.state == CDState::Playing && self.read_ticks >= cycles_per_sector {
+                self.read_ticks -= cycles_per_sector;
+                self.advance_play_position();
             }
         }
 
@@ -897,29 +1161,86 @@ impl CDROM {
         if self.state == CDState::Seeking {
             self.seek_ticks += cycles;
 
-            let seek_time = self.calculate_seek_time();
+            let seek_time = self.seek_duration();
             if self.seek_ticks >= seek_time {
                 self.seek_ticks = 0;
-                self.state = CDState::Idle;
-                self.status.seeking = false;
-
-                if let Some(target) = self.seek_target {
-                    self.position = target;
 
-                    log::debug!(
-                        "CD-ROM: Seek complete to {:02}:{:02}:{:02}",
-                        self.position.minute,
-                        self.position.second,
-                        self.position.sector
-                    );
-
-                    self.response_fifo.push_back(self.get_status_byte());
-                    self.trigger_interrupt(2); // INT2 (seek complete)
+                if self.seek_target.is_some() {
+                    // INT2 (seek complete) or INT5 (seek error)
+                    let (int_level, response) = self.do_seek_complete();
+                    self.response_fifo.extend(response);
+                    self.trigger_interrupt(int_level);
+                } else {
+                    self.state = CDState::Idle;
+                    self.status.seeking = false;
                 }
             }
         }
     }
 
+    /// Get the XA filter file number set by SetFilter
+    ///
+    /// # Returns
+    ///
+    /// File number that XA sectors must match when the XA filter is enabled
+    pub fn xa_filter_file(&self) -> u8 {
+        self.xa_filter_file
+    }
+
+    /// Get the XA filter channel number set by SetFilter
+    ///
+    /// # Returns
+    ///
+    /// Channel number that XA sectors must match when the XA filter is enabled
+    pub fn xa_filter_channel(&self) -> u8 {
+        self.xa_filter_channel
+    }
+
+    /// Route an XA-ADPCM audio sector to the CD audio mixer
+    ///
+    /// Audio sectors (Mode 2 Form 2 with the audio submode bit) are decoded
+    /// and queued on `CDAudio` when XA-ADPCM is enabled in the mode byte.
+    /// With the XA filter enabled, sectors whose file/channel do not match
+    /// the filter are dropped silently.
+    ///
+    /// # Arguments
+    ///
+    /// * `sector` - Raw 2352-byte sector
+    ///
+    /// # Returns
+    ///
+    /// `true` if the sector was consumed as audio and must not be delivered
+    /// to the CPU
+    pub(super) fn process_xa_sector(&mut self, sector: &[u8]) -> bool {
+        const XA_AUDIO: u8 = xa_adpcm::SUBMODE_AUDIO | xa_adpcm::SUBMODE_FORM2;
+
+        if !self.mode.xa_adpcm || sector[15] != 2 {
+            return false;
+        }
+
+        let subheader = &sector[xa_adpcm::SUBHEADER_OFFSET..];
+        let (file, channel, submode) = (subheader[0], subheader[1], subheader[2]);
+
+        if submode & XA_AUDIO != XA_AUDIO {
+            return false;
+        }
+
+        if self.mode.xa_filter && (file != self.xa_filter_file || channel != self.xa_filter_channel)
+        {
+            log::trace!(
+                "CD-ROM: Dropping XA sector file={} channel={} (filter mismatch)",
+                file,
+                channel
+            );
+            return true;
+        }
+
+        let audio = self.xa_decoder.decode_sector(sector);
+        self.cd_audio
+            .push_xa_samples(&audio.samples, audio.sample_rate);
+        true
+    }
+
     /// Advance MSF position by one sector
     ///
     /// Handles wraparound for sectors (75 per second) and seconds (60 per minute).
@@ -935,6 +1256,127 @@ impl CDROM {
         }
     }
 
+    /// Get subchannel Q for the current drive position
+    ///
+    /// Falls back to track 1, index 1 when no disc is loaded or the
+    /// position lies outside the disc's track layout.
+    pub(super) fn current_subchannel_q(&self) -> SubchannelQ {
+        self.disc
+            .as_ref()
+            .and_then(|disc| disc.subchannel_q(&self.position))
+            .unwrap_or_else(|| SubchannelQ {
+                control_adr: 0x41,
+                track: 1,
+                index: 1,
+                relative: CDPosition::from_lba(self.position.to_lba().max(0) - 150),
+                absolute: self.position,
+            })
+    }
+
+    /// Start the CD audio player at the current position within `track`
+    ///
+    /// CDAudio addresses sectors relative to the start of the .bin file.
+    /// Inside a pregap that is not stored in the file, playback starts at
+    /// the beginning of the track.
+    ///
+    /// # Arguments
+    ///
+    /// * `track` - Track being played
+    pub(super) fn play_track_audio(&mut self, track: &Track) {
+        let track_sector = (track.file_offset / 2352) as u32;
+        let start_sector = self
+            .disc
+            .as_ref()
+            .and_then(|disc| disc.file_offset_for_lba(self.position.to_lba()))
+            .flatten()
+            .map_or(track_sector, |offset| (offset / 2352) as u32);
+        let end_sector = track_sector + track.length_sectors.saturating_sub(1);
+        self.cd_audio.play(start_sector, end_sector, false);
+    }
+
+    /// Advance the CD-DA play position by one sector
+    ///
+    /// The end of the current track is detected from the disc's track map
+    /// (the subchannel track number changes at the next track's pregap).
+    /// With Auto Pause the drive pauses there with INT4; otherwise playback
+    /// continues into the next track. Running off the last track always
+    /// stops playback with INT4. Report interrupts (INT1) are generated
+    /// when reporting is enabled.
+    ///
+    /// # Report format
+    ///
+    /// ```text
+    /// stat, track, index, amm, ass, asect, peak_lo, peak_hi   (asect 00, 20, 40, 60)
+    /// stat, track, index, mm, ss+80h, sect, peak_lo, peak_hi  (asect 10, 30, 50, 70)
+    /// ```
+    ///
+    /// Reports are sent every 10 sectors from the subchannel Q data, in BCD,
+    /// alternating between absolute and track-relative time (bit 7 of the
+    /// seconds byte marks relative time). The peak is the highest sample
+    /// magnitude played since the last report; bit 15 selects the channel
+    /// (0 = left, 1 = right), which alternates between reports.
+    fn advance_play_position(&mut self) {
+        self.advance_position();
+
+        let Some(disc) = self.disc.as_ref() else {
+            return;
+        };
+        let track = disc.track_for_lba(self.position.to_lba()).cloned();
+
+        if track.as_ref().map(|t| t.number) != Some(self.play_track) {
+            match track {
+                Some(next) if !self.mode.auto_pause => {
+                    log::debug!("CD-ROM: Playing on into track {}", next.number);
+                    self.play_track = next.number;
+                    self.play_track_audio(&next);
+                }
+                _ => {
+                    log::debug!(
+                        "CD-ROM: Auto pause at end of track {} ({:02}:{:02}:{:02})",
+                        self.play_track,
+                        self.position.minute,
+                        self.position.second,
+                        self.position.sector
+                    );
+                    self.state = CDState::Idle;
+                    self.status.playing = false;
+                    self.cd_audio.stop();
+
+                    self.response_fifo.push_back(self.get_status_byte());
+                    self.trigger_interrupt(4); // INT4 (data end)
+                    return;
+                }
+            }
+        }
+
+        if self.mode.report_all && self.position.sector.is_multiple_of(10) {
+            let q = self.current_subchannel_q();
+            let (time, second_flag) = if (self.position.sector / 10).is_multiple_of(2) {
+                (q.absolute, 0x00)
+            } else {
+                (q.relative, 0x80)
+            };
+
+            let (left, right) = self.cd_audio.take_peak();
+            let peak = if self.report_peak_right {
+                right | 0x8000
+            } else {
+                left
+            };
+            self.report_peak_right = !self.report_peak_right;
+
+            self.response_fifo.push_back(self.get_status_byte());
+            self.response_fifo.push_back(dec_to_bcd(q.track));
+            self.response_fifo.push_back(dec_to_bcd(q.index));
+            self.response_fifo.push_back(dec_to_bcd(time.minute));
+            self.response_fifo
+                .push_back(dec_to_bcd(time.second) | second_flag);
+            self.response_fifo.push_back(dec_to_bcd(time.sector));
+            self.response_fifo.extend(peak.to_le_bytes());
+            self.trigger_interrupt(1); // INT1 (report)
+        }
+    }
+
     /// Calculate seek time in CPU cycles based on seek distance
     ///
     /// # Returns
@@ -943,17 +1385,101 @@ impl CDROM {
     ///
     /// # Implementation Note
     ///
-    /// This is a simplified implementation using a fixed seek time.
-    /// Real hardware varies seek time based on distance:
-    /// - Short seeks (same track): ~1ms
-    /// - Medium seeks (nearby): ~20-50ms
-    /// - Long seeks (opposite sides): ~200-500ms
+    /// Seek time scales with the LBA distance between `position` and
+    /// `seek_target`:
+    /// - Short seeks (within ~1 second of data): ~1ms
+    /// - Medium seeks: linear in distance (~30ms per 10% of the disc)
+    /// - Full-disc seeks: ~300ms
     ///
-    /// For now, we use a fixed time of ~3ms (100,000 cycles).
-    fn calculate_seek_time(&self) -> u32 {
-        // TODO: Calculate actual seek time based on distance
-        // For now, use fixed seek time of approximately 3ms
-        100_000 // ~3ms at 33.8688 MHz
+    /// Without a seek target, the minimum seek time is used.
+    pub(super) fn calculate_seek_time(&self) -> u32 {
+        let Some(target) = self.seek_target else {
+            return Self::SEEK_MIN_CYCLES;
+        };
+
+        let distance = (target.to_lba() - self.position.to_lba()).unsigned_abs();
+        if distance <= Self::SEEK_SHORT_DISTANCE {
+            return Self::SEEK_MIN_CYCLES;
+        }
+
+        let range = (Self::SEEK_MAX_CYCLES - Self::SEEK_MIN_CYCLES) as u64;
+        let scaled =
+            range * distance.min(Self::DISC_MAX_SECTORS) as u64 / Self::DISC_MAX_SECTORS as u64;
+
+        Self::SEEK_MIN_CYCLES + scaled as u32
+    }
+
+    /// Total time until the pending seek delivers its second response
+    ///
+    /// SeekL has to read one sector header at the target to confirm the
+    /// data position, so it takes one sector period longer than SeekP,
+    /// which only needs the subchannel Q position.
+    ///
+    /// # Returns
+    ///
+    /// Number of CPU cycles for the seek operation
+    pub(super) fn seek_duration(&self) -> u32 {
+        match self.seek_kind {
+            SeekKind::Logical => self.calculate_seek_time() + self.cycles_per_sector() as u32,
+            SeekKind::Physical => self.calculate_seek_time(),
+        }
+    }
+
+    /// Move the head to the seek target and settle the drive
+    ///
+    /// SeekL locks onto the data sector header at the target and latches it
+    /// for GetlocL. On an audio track there is no header, so unless CD-DA
+    /// sectors are enabled in the mode the seek fails and the data position
+    /// stays where it was. SeekP only follows subchannel Q, lands on audio
+    /// and data tracks alike and leaves the last sector header untouched.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the seek succeeded, `false` if a seek error was latched
+    pub(super) fn finish_seek(&mut self) -> bool {
+        self.state = CDState::Idle;
+        self.status.seeking = false;
+
+        let Some(target) = self.seek_target else {
+            return true;
+        };
+
+        if self.seek_kind == SeekKind::Logical {
+            let on_audio = self
+                .disc
+                .as_ref()
+                .and_then(|disc| disc.track_at(&target))
+                .is_some_and(|track| track.track_type == TrackType::Audio);
+
+            if on_audio && !self.mode.cdda_enabled {
+                log::warn!(
+                    "CD-ROM: SeekL to audio sector {:02}:{:02}:{:02}",
+                    target.minute,
+                    target.second,
+                    target.sector
+                );
+                self.last_sector_header = None;
+                self.status.error = true;
+                self.status.seek_error = true;
+                return false;
+            }
+
+            self.position = target;
+            if !on_audio {
+                self.read_current_sector();
+            }
+        } else {
+            self.position = target;
+        }
+
+        log::debug!(
+            "CD-ROM: {:?} seek complete to {:02}:{:02}:{:02}",
+            self.seek_kind,
+            self.position.minute,
+            self.position.second,
+            self.position.sector
+        );
+        true
     }
 
     /// Read a single byte from the data buffer
@@ -984,6 +1510,52 @@ impl CDROM {
         }
     }
 
+    /// Write the Request Register (0x1F801803, index 0)
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Register value
+    ///
+    /// # Register Format
+    ///
+    /// ```text
+    /// Bit 5: SMADPCLR (sound map ADPCM buffer clear) - ignored
+    /// Bit 6: SMEN (sound map enable) - ignored
+    /// Bit 7: BFRD (0=Clear data FIFO, 1=Load sector into data FIFO)
+    /// ```
+    pub(super) fn write_request_register(&mut self, value: u8) {
+        log::trace!("CD-ROM: Request Register write: 0x{:02X}", value);
+
+        if value & 0x80 != 0 {
+            // Transfer the active sector into the data FIFO from the start
+            self.data_index = 0;
+        } else {
+            // Empty the data FIFO; the sector stays buffered for the next request
+            self.data_index = self.data_buffer.len();
+        }
+    }
+
+    /// Write the Audio Volume Apply register (0x1F801803, index 3)
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Register value
+    ///
+    /// # Register Format
+    ///
+    /// ```text
+    /// Bit 0: Mute XA-ADPCM - ignored
+    /// Bit 5: Apply volume changes (latch the pending volume values)
+    /// ```
+    pub(super) fn apply_audio_volume(&mut self, value: u8) {
+        log::trace!("CD-ROM: Audio Volume Apply write: 0x{:02X}", value);
+
+        if value & 0x20 != 0 {
+            self.cd_audio.set_volume_matrix(self.pending_volume);
+            log::debug!("CD-ROM: Audio volume matrix {:02X?}", self.pending_volume);
+        }
+    }
+
     /// Push a byte to the data buffer (for testing)
     ///
     /// This is a test helper method to populate the CD-ROM data buffer
@@ -1098,9 +1670,10 @@ impl CDROM {
     ///
     /// ```text
     /// 0x1F801800: Index/Status register (all indices)
-    /// 0x1F801801: Command register (index 0) / Sound Map Data (index 1-3)
+    /// 0x1F801801: Command register (index 0) / Sound Map Data (index 1-2) / Audio Volume (index 3)
     /// 0x1F801802: Parameter FIFO (index 0) / Interrupt Enable (index 1) / Audio Volume (index 2-3)
-    /// 0x1F801803: Request Register (index 0) / Interrupt Flag (index 1) / Audio Volume (index 2-3)
+    /// 0x1F801803: Request Register (index 0) / Interrupt Flag (index 1) / Audio Volume (index 2)
+    ///             / Audio Volume Apply (index 3)
     /// ```
     ///
     /// # Note
@@ -1120,34 +1693,37 @@ impl CDROM {
                 self.command_to_schedule = Some(value);
             }
 
-            // 0x1F801801: Sound Map Data Out (index 1-3) - not implemented
-            (Self::REG_DATA, 1..=3) => {
+            // 0x1F801801: Sound Map Data Out (index 1-2) - not implemented
+            (Self::REG_DATA, 1) | (Self::REG_DATA, 2) => {
                 log::trace!("CD-ROM: Sound Map Data Out write: 0x{:02X}", value);
             }
 
+            // 0x1F801801: Audio Volume CD-R → SPU-R (index 3)
+            (Self::REG_DATA, 3) => self.pending_volume[2] = value,
+
             // 0x1F801802: Parameter FIFO (index 0)
             (Self::REG_INT_FLAG, 0) => self.push_param(value),
 
             // 0x1F801802: Interrupt Enable (index 1)
             (Self::REG_INT_FLAG, 1) => self.set_interrupt_enable(value),
 
-            // 0x1F801802: Audio Volume (index 2-3) - not implemented
-            (Self::REG_INT_FLAG, 2) | (Self::REG_INT_FLAG, 3) => {
-                log::trace!("CD-ROM: Audio Volume write: 0x{:02X}", value);
-            }
+            // 0x1F801802: Audio Volume CD-L → SPU-L (index 2)
+            (Self::REG_INT_FLAG, 2) => self.pending_volume[0] = value,
 
-            // 0x1F801803: Request Register (index 0) - not implemented
-            (Self::REG_INT_ENABLE, 0) => {
-                log::trace!("CD-ROM: Request Register write: 0x{:02X}", value);
-            }
+            // 0x1F801802: Audio Volume CD-R → SPU-L (index 3)
+            (Self::REG_INT_FLAG, 3) => self.pending_volume[3] = value,
+
+            // 0x1F801803: Request Register (index 0)
+            (Self::REG_INT_ENABLE, 0) => self.write_request_register(value),
 
             // 0x1F801803: Interrupt Flag (index 1)
             (Self::REG_INT_ENABLE, 1) => self.acknowledge_interrupt(value),
 
-            // 0x1F801803: Audio Volume (index 2-3) - not implemented
-            (Self::REG_INT_ENABLE, 2) | (Self::REG_INT_ENABLE, 3) => {
-                log::trace!("CD-ROM: Audio Volume write: 0x{:02X}", value);
-            }
+            // 0x1F801803: Audio Volume CD-L → SPU-R (index 2)
+            (Self::REG_INT_ENABLE, 2) => self.pending_volume[1] = value,
+
+            // 0x1F801803: Audio Volume Apply Changes (index 3)
+            (Self::REG_INT_ENABLE, 3) => self.apply_audio_volume(value),
 
             _ => {
                 log::warn!(
@@ -1214,8 +1790,9 @@ impl CDROM {
             SecondResponseType::GetID => Self::GETID_SECOND_RESPONSE_DELAY,
             SecondResponseType::ReadTOC => Self::READTOC_SECOND_RESPONSE_DELAY,
             SecondResponseType::Init => Self::INIT_SECOND_RESPONSE_DELAY,
-            SecondResponseType::Seek => Self::SEEK_SECOND_RESPONSE_DELAY,
+            SecondResponseType::Seek => self.seek_duration() as TickCount,
             SecondResponseType::Pause => 10_000, // ~300μs
+            SecondResponseType::Stop => Self::STOP_SECOND_RESPONSE_DELAY,
             SecondResponseType::None => 0,
         }
     }
@@ -1328,6 +1905,38 @@ impl CDROM {
         }
     }
 
+    /// CPU cycles between sectors at the current drive speed
+    pub(super) fn cycles_per_sector(&self) -> TickCount {
+        if self.mode.double_speed {
+            Self::CYCLES_PER_SECTOR_2X
+        } else {
+            Self::CYCLES_PER_SECTOR_1X
+        }
+    }
+
+    /// Apply the current drive speed to the sector read event
+    ///
+    /// Called when SetMode or Init may have changed the speed. A running
+    /// event is restarted so the next sector arrives at the new cadence.
+    ///
+    /// # Arguments
+    ///
+    /// * `timing` - Timing event manager
+    pub(super) fn update_sector_read_period(
+        &mut self,
+        timing: &mut super::timing::TimingEventManager,
+    ) {
+        let Some(handle) = self.sector_read_event else {
+            return;
+        };
+
+        let period = self.cycles_per_sector();
+        timing.set_interval(handle, period);
+        if timing.is_active(handle) {
+            timing.schedule(handle, period);
+        }
+    }
+
     /// Register timing events for CD-ROM operations
     ///
     /// This should be called during system initialization to register all
@@ -1347,14 +1956,8 @@ impl CDROM {
         self.async_interrupt_event = Some(timing.register_event("CDROM Async Interrupt"));
 
         // Register sector read event (periodic, activated when reading starts)
-        let cycles_per_sector = if self.mode.double_speed {
-            Self::CYCLES_PER_SECTOR_2X
-        } else {
-            Self::CYCLES_PER_SECTOR_1X
-        };
-
         self.sector_read_event =
-            Some(timing.register_periodic_event("CDROM Sector Read", cycles_per_sector));
+            Some(timing.register_periodic_event("CDROM Sector Read", self.cycles_per_sector()));
 
         log::info!("CD-ROM: Timing events registered successfully");
     }
@@ -1366,6 +1969,131 @@ impl Default for CDROM {
     }
 }
 
+impl StateSave for CDROM {
+    type State = CDROMState;
+
+    fn to_state(&self) -> CDROMState {
+        let msf = |p: CDPosition| (p.minute, p.second, p.sector);
+
+        CDROMState {
+            status: self.get_status_byte(),
+            index: self.index,
+            param_fifo: self.param_fifo.iter().copied().collect(),
+            response_fifo: self.response_fifo.iter().copied().collect(),
+            data_buffer: self.data_buffer.clone(),
+            data_index: self.data_index as u32,
+            last_sector_header: self.last_sector_header,
+            drive_state: match self.state {
+                CDState::Idle => 0,
+                CDState::Reading => 1,
+                CDState::Seeking => 2,
+                CDState::Playing => 3,
+            },
+            seek_target: self.seek_target.map(msf),
+            seek_physical: self.seek_kind == SeekKind::Physical,
+            read_position: msf(self.position),
+            read_ticks: self.read_ticks,
+            seek_ticks: self.seek_ticks,
+            spin_up_ticks: self.spin_up_ticks,
+            play_track: self.play_track,
+            report_peak_right: self.report_peak_right,
+            pending_volume: self.pending_volume,
+            mode: self.mode.to_byte(),
+            interrupt_enable: self.interrupt_enable,
+            interrupt_flag: self.interrupt_flag,
+            xa_filter_file: self.xa_filter_file,
+            xa_filter_channel: self.xa_filter_channel,
+            xa_history: self.xa_decoder.history,
+            pending_command: self.pending_command,
+            pending_second_response: self.pending_second_response.map(|r| match r {
+                SecondResponseType::None => 0,
+                SecondResponseType::GetID => 1,
+                SecondResponseType::ReadTOC => 2,
+                SecondResponseType::Init => 3,
+                SecondResponseType::Pause => 4,
+                SecondResponseType::Seek => 5,
+                SecondResponseType::Stop => 6,
+            }),
+            pending_async_interrupt: self.pending_async_interrupt,
+            last_interrupt_time: self.last_interrupt_time,
+            async_response_fifo: self.async_response_fifo.iter().copied().collect(),
+            command_to_schedule: self.command_to_schedule,
+            audio: self.cd_audio.to_state(),
+        }
+    }
+
+    /// Restore the drive state
+    ///
+    /// The inserted disc and timing event handles are kept from this
+    /// instance.
+    fn restore_from_state(&mut self, state: &CDROMState) {
+        let msf = |(m, s, f): (u8, u8, u8)| CDPosition::new(m, s, f);
+        let bit = |n: u8| state.status & (1 << n) != 0;
+
+        self.status = CDStatus {
+            error: bit(0),
+            motor_on: bit(1),
+            seek_error: bit(2),
+            id_error: bit(3),
+            shell_open: bit(4),
+            reading: bit(5),
+            seeking: bit(6),
+            playing: bit(7),
+        };
+        self.index = state.index & 0x03;
+        self.param_fifo = state.param_fifo.iter().copied().collect();
+        self.response_fifo = state.response_fifo.iter().copied().collect();
+        self.data_buffer = state.data_buffer.clone();
+        self.data_index = state.data_index as usize;
+        self.last_sector_header = state.last_sector_header;
+        self.state = match state.drive_state {
+            1 => CDState::Reading,
+            2 => CDState::Seeking,
+            3 => CDState::Playing,
+            _ => CDState::Idle,
+        };
+        self.seek_target = state.seek_target.map(msf);
+        self.seek_kind = if state.seek_physical {
+            SeekKind::Physical
+        } else {
+            SeekKind::Logical
+        };
+        self.position = msf(state.read_position);
+        self.read_ticks = state.read_ticks;
+        self.seek_ticks = state.seek_ticks;
+        self.spin_up_ticks = state.spin_up_ticks;
+        self.play_track = state.play_track;
+        self.report_peak_right = state.report_peak_right;
+        self.pending_volume = state.pending_volume;
+
+        self.mode = CDMode::from_byte(state.mode);
+        self.interrupt_enable = state.interrupt_enable;
+        self.interrupt_flag = state.interrupt_flag;
+        self.irq_line = self.interrupt_flag & self.interrupt_enable & 0x1F != 0;
+        self.irq_raised = false;
+        self.xa_filter_file = state.xa_filter_file;
+        self.xa_filter_channel = state.xa_filter_channel;
+        self.xa_decoder.history = state.xa_history;
+
+        self.pending_command = state.pending_command;
+        self.pending_second_response = state.pending_second_response.map(|r| match r {
+            1 => SecondResponseType::GetID,
+            2 => SecondResponseType::ReadTOC,
+            3 => SecondResponseType::Init,
+            4 => SecondResponseType::Pause,
+            5 => SecondResponseType::Seek,
+            6 => SecondResponseType::Stop,
+            _ => SecondResponseType::None,
+        });
+        self.pending_async_interrupt = state.pending_async_interrupt;
+        self.last_interrupt_time = state.last_interrupt_time;
+        self.async_response_fifo = state.async_response_fifo.iter().copied().collect();
+        self.command_to_schedule = state.command_to_schedule;
+
+        self.cd_audio.restore_from_state(&state.audio);
+    }
+}
+
 /// Convert BCD (Binary-Coded Decimal) to decimal
 ///
 /// BCD format: each nibble (4 bits) represents a decimal digit (0-9).
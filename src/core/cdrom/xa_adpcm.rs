@@ -0,0 +1,327 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! XA-ADPCM audio decoder
+//!
+//! XA audio is stored in Mode 2 Form 2 sectors and streamed by the CD-ROM
+//! controller directly to the SPU. Each sector holds 18 sound groups of
+//! 128 bytes, starting right after the 8-byte subheader.
+//!
+//! # Sound Group Layout
+//!
+//! ```text
+//! 0x00-0x0F: Sound parameters (shift/filter per sound unit, duplicated)
+//! 0x10-0x7F: 28 words of sample data, interleaved across sound units
+//! ```
+//!
+//! 4-bit groups hold 8 sound units, 8-bit groups hold 4. In stereo mode
+//! even units belong to the left channel and odd units to the right.
+//!
+//! # Coding Info (subheader byte 3)
+//!
+//! ```text
+//! Bit 0-1: Channels (0=Mono, 1=Stereo)
+//! Bit 2-3: Sample rate (0=37800Hz, 1=18900Hz)
+//! Bit 4-5: Bits per sample (0=4bit, 1=8bit)
+//! Bit 6:   Emphasis
+//! ```
+
+/// Offset of the subheader in a raw 2352-byte sector
+pub(super) const SUBHEADER_OFFSET: usize = 16;
+
+/// Offset of the first sound group in a raw 2352-byte sector
+const SOUND_GROUP_OFFSET: usize = 24;
+
+/// Number of sound groups per sector
+const SOUND_GROUPS: usize = 18;
+
+/// Size of one sound group in bytes
+const SOUND_GROUP_SIZE: usize = 128;
+
+/// Samples per sound unit
+const SAMPLES_PER_UNIT: usize = 28;
+
+/// Submode bit: Audio sector
+pub(super) const SUBMODE_AUDIO: u8 = 1 << 2;
+
+/// Submode bit: Form 2 sector
+pub(super) const SUBMODE_FORM2: u8 = 1 << 5;
+
+/// ADPCM prediction filter coefficients (positive)
+const POS_TABLE: [i32; 4] = [0, 60, 115, 98];
+
+/// ADPCM prediction filter coefficients (negative)
+const NEG_TABLE: [i32; 4] = [0, 0, -52, -55];
+
+/// Decoded audio from one XA sector
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XaSectorAudio {
+    /// Stereo samples (mono sectors are duplicated to both channels)
+    pub samples: Vec<(i16, i16)>,
+
+    /// Sample rate in Hz (37800 or 18900)
+    pub sample_rate: u32,
+}
+
+/// XA-ADPCM decoder
+///
+/// Keeps the ADPCM prediction history for the left and right channels
+/// across sectors, as a continuous stream is split over many sectors.
+#[derive(Debug, Clone, Default)]
+pub struct XaDecoder {
+    /// Previous two samples per channel (old, older)
+    pub(super) history: [(i32, i32); 2],
+}
+
+impl XaDecoder {
+    /// Create a new decoder with cleared history
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::cdrom::xa_adpcm::XaDecoder;
+    ///
+    /// let decoder = XaDecoder::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clear the prediction history (e.g. when a new stream starts)
+    pub fn reset(&mut self) {
+        self.history = [(0, 0); 2];
+    }
+
+    /// Decode a raw 2352-byte XA sector
+    ///
+    /// # Arguments
+    ///
+    /// * `sector` - Raw sector data including sync, header and subheader
+    ///
+    /// # Returns
+    ///
+    /// Decoded stereo samples and their sample rate
+    pub fn decode_sector(&mut self, sector: &[u8]) -> XaSectorAudio {
+        let coding_info = sector[SUBHEADER_OFFSET + 3];
+        let stereo = coding_info & 0x03 == 0x01;
+        let sample_rate = if coding_info & 0x0C == 0x04 {
+            18_900
+        } else {
+            37_800
+        };
+        let eight_bit = coding_info & 0x30 == 0x10;
+
+        let units = if eight_bit { 4 } else { 8 };
+        let mut samples = Vec::with_capacity(SOUND_GROUPS * units * SAMPLES_PER_UNIT);
+
+        for group in 0..SOUND_GROUPS {
+            let start = SOUND_GROUP_OFFSET + group * SOUND_GROUP_SIZE;
+            let group_data = &sector[start..start + SOUND_GROUP_SIZE];
+
+            if stereo {
+                for pair in 0..units / 2 {
+                    let left = self.decode_unit(group_data, pair * 2, 0, eight_bit);
+                    let right = self.decode_unit(group_data, pair * 2 + 1, 1, eight_bit);
+                    samples.extend(left.into_iter().zip(right));
+                }
+            } else {
+                for unit in 0..units {
+                    let mono = self.decode_unit(group_data, unit, 0, eight_bit);
+                    samples.extend(mono.into_iter().map(|s| (s, s)));
+                }
+            }
+        }
+
+        XaSectorAudio {
+            samples,
+            sample_rate,
+        }
+    }
+
+    /// Decode the 28 samples of one sound unit
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - 128-byte sound group
+    /// * `unit` - Sound unit index (0-7 for 4-bit, 0-3 for 8-bit)
+    /// * `channel` - History slot to use (0=left/mono, 1=right)
+    /// * `eight_bit` - Whether samples are 8-bit
+    fn decode_unit(
+        &mut self,
+        group: &[u8],
+        unit: usize,
+        channel: usize,
+        eight_bit: bool,
+    ) -> [i16; SAMPLES_PER_UNIT] {
+        let params = group[4 + unit];
+        let filter = ((params >> 4) & 0x03) as usize;
+        let (f0, f1) = (POS_TABLE[filter], NEG_TABLE[filter]);
+
+        // Ranges above the valid maximum behave like range 9 on hardware
+        let shift = if eight_bit {
+            8 - (params & 0x0F).min(8) as i32
+        } else {
+            let range = params & 0x0F;
+            12 - if range > 12 { 9 } else { range } as i32
+        };
+
+        let (mut old, mut older) = self.history[channel];
+        let mut out = [0i16; SAMPLES_PER_UNIT];
+
+        for (i, sample) in out.iter_mut().enumerate() {
+            let t = if eight_bit {
+                group[16 + unit + i * 4] as i8 as i32
+            } else {
+                let byte = group[16 + unit / 2 + i * 4];
+                let nibble = (byte >> ((unit & 1) * 4)) & 0x0F;
+                ((nibble << 4) as i8 as i32) >> 4
+            };
+
+            let s = (t << shift) + ((old * f0 + older * f1 + 32) >> 6);
+            let s = s.clamp(i16::MIN as i32, i16::MAX as i32);
+
+            older = old;
+            old = s;
+            *sample = s as i16;
+        }
+
+        self.history[channel] = (old, older);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a raw XA sector with the given coding info and sound groups
+    fn make_sector(coding_info: u8, fill: impl Fn(&mut [u8])) -> Vec<u8> {
+        let mut sector = vec![0u8; 2352];
+        sector[15] = 2;
+        sector[SUBHEADER_OFFSET + 2] = SUBMODE_AUDIO | SUBMODE_FORM2;
+        sector[SUBHEADER_OFFSET + 3] = coding_info;
+        for group in 0..SOUND_GROUPS {
+            let start = SOUND_GROUP_OFFSET + group * SOUND_GROUP_SIZE;
+            fill(&mut sector[start..start + SOUND_GROUP_SIZE]);
+        }
+        sector
+    }
+
+    #[test]
+    fn test_decode_silent_sector_lengths() {
+        let mut decoder = XaDecoder::new();
+
+        // 4-bit mono, 37800Hz: 18 groups * 8 units * 28 samples
+        let audio = decoder.decode_sector(&make_sector(0x00, |_| {}));
+        assert_eq!(audio.samples.len(), 4032);
+        assert_eq!(audio.sample_rate, 37_800);
+
+        // 4-bit stereo, 18900Hz: half as many sample pairs
+        let audio = decoder.decode_sector(&make_sector(0x05, |_| {}));
+        assert_eq!(audio.samples.len(), 2016);
+        assert_eq!(audio.sample_rate, 18_900);
+
+        // 8-bit mono: 4 units per group
+        let audio = decoder.decode_sector(&make_sector(0x10, |_| {}));
+        assert_eq!(audio.samples.len(), 2016);
+
+        // 8-bit stereo
+        let audio = decoder.decode_sector(&make_sector(0x11, |_| {}));
+        assert_eq!(audio.samples.len(), 1008);
+        assert!(audio.samples.iter().all(|&s| s == (0, 0)));
+    }
+
+    #[test]
+    fn test_decode_4bit_shift_without_filter() {
+        let mut decoder = XaDecoder::new();
+
+        // Range 8 => shift 4, filter 0; every nibble = 0x3 (low) / 0xD (high, -3)
+        let sector = make_sector(0x00, |g| {
+            g[4..12].fill(0x08);
+            g[16..].fill(0xD3);
+        });
+        let audio = decoder.decode_sector(&sector);
+
+        // Unit 0 uses the low nibble of byte 0, unit 1 the high nibble
+        assert_eq!(audio.samples[0], (3 << 4, 3 << 4));
+        assert_eq!(audio.samples[28], (-3 << 4, -3 << 4));
+    }
+
+    #[test]
+    fn test_decode_4bit_stereo_splits_channels() {
+        let mut decoder = XaDecoder::new();
+
+        let sector = make_sector(0x01, |g| {
+            g[4..12].fill(0x0C); // shift 0
+            g[16..].fill(0xF1); // left = 1, right = -1
+        });
+        let audio = decoder.decode_sector(&sector);
+
+        assert_eq!(audio.samples[0], (1, -1));
+    }
+
+    #[test]
+    fn test_decode_8bit_samples() {
+        let mut decoder = XaDecoder::new();
+
+        let sector = make_sector(0x10, |g| {
+            g[4..8].fill(0x08); // shift 0
+            g[16..].fill(0x80); // -128
+        });
+        let audio = decoder.decode_sector(&sector);
+
+        assert_eq!(audio.samples[0], (-128, -128));
+    }
+
+    #[test]
+    fn test_decode_filter_uses_history() {
+        let mut decoder = XaDecoder::new();
+
+        // Filter 1 (60/64 of previous sample), shift 0, all samples = 7
+        let sector = make_sector(0x00, |g| {
+            g[4..12].fill(0x1C);
+            g[16..].fill(0x77);
+        });
+        let audio = decoder.decode_sector(&sector);
+
+        // s0 = 7, s1 = 7 + (7*60 + 32) >> 6 = 14
+        assert_eq!(audio.samples[0].0, 7);
+        assert_eq!(audio.samples[1].0, 14);
+    }
+
+    #[test]
+    fn test_decode_clamps_output() {
+        let mut decoder = XaDecoder::new();
+
+        // Filter 1 accumulates toward saturation with large positive samples
+        let sector = make_sector(0x00, |g| {
+            g[4..12].fill(0x10); // range 0 => shift 12
+            g[16..].fill(0x77);
+        });
+        let audio = decoder.decode_sector(&sector);
+
+        assert!(audio.samples.iter().any(|&(l, _)| l == i16::MAX));
+    }
+
+    #[test]
+    fn test_reset_clears_history() {
+        let mut decoder = XaDecoder::new();
+        decoder.history = [(100, 50), (-100, -50)];
+
+        decoder.reset();
+
+        assert_eq!(decoder.history, [(0, 0); 2]);
+    }
+}
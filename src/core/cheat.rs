@@ -0,0 +1,371 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GameShark-style cheat codes
+//!
+//! Cheats are applied to main RAM once per frame, after VBlank.
+//!
+//! # Code Format
+//!
+//! Each line is `TTAAAAAA VVVV`: an 8-digit type + RAM offset followed by a
+//! 4-digit value. The offset is relative to 0x80000000.
+//!
+//! | Type | Effect |
+//! |------|--------|
+//! | 80 | Write 16-bit value |
+//! | 30 | Write 8-bit value (low byte of VVVV) |
+//! | 10 / 11 | Add / subtract 16-bit value |
+//! | 20 / 21 | Add / subtract 8-bit value |
+//! | D0 / D1 | Run next line if 16-bit value is equal / not equal |
+//! | E0 / E1 | Run next line if 8-bit value is equal / not equal |
+//!
+//! # Example
+//!
+//! ```
+//! use psrx::core::cheat::Cheat;
+//!
+//! // Keep the byte at 0x80012345 at 99 while the flag at 0x80010000 is set
+//! let cheat = Cheat::parse("D0010000 0001\n30012345 0063").unwrap();
+//! assert_eq!(cheat.len(), 2);
+//! ```
+
+use super::error::{EmulatorError, Result};
+use super::memory::Bus;
+
+/// Base address that code offsets are relative to (KSEG0 RAM)
+const RAM_BASE: u32 = 0x8000_0000;
+
+/// A single decoded code line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheatOp {
+    /// 80: write 16-bit value
+    Write16 { address: u32, value: u16 },
+    /// 30: write 8-bit value
+    Write8 { address: u32, value: u8 },
+    /// 10: add to 16-bit value
+    Add16 { address: u32, value: u16 },
+    /// 11: subtract from 16-bit value
+    Sub16 { address: u32, value: u16 },
+    /// 20: add to 8-bit value
+    Add8 { address: u32, value: u8 },
+    /// 21: subtract from 8-bit value
+    Sub8 { address: u32, value: u8 },
+    /// D0/D1: gate next line on 16-bit comparison
+    If16 {
+        address: u32,
+        value: u16,
+        equal: bool,
+    },
+    /// E0/E1: gate next line on 8-bit comparison
+    If8 {
+        address: u32,
+        value: u8,
+        equal: bool,
+    },
+}
+
+impl CheatOp {
+    /// Parse one `TTAAAAAA VVVV` line
+    fn parse(line: &str) -> Result<Self> {
+        let invalid = || EmulatorError::Parse(format!("invalid cheat code line: {}", line));
+
+        let mut parts = line.split_whitespace();
+        let (head, tail) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(head), Some(tail), None) if head.len() == 8 && tail.len() == 4 => (head, tail),
+            _ => return Err(invalid()),
+        };
+        let head = u32::from_str_radix(head, 16).map_err(|_| invalid())?;
+        let value = u16::from_str_radix(tail, 16).map_err(|_| invalid())?;
+
+        let address = RAM_BASE | (head & 0x00FF_FFFF);
+        let byte = value as u8;
+
+        Ok(match head >> 24 {
+            0x80 => CheatOp::Write16 { address, value },
+            0x30 => CheatOp::Write8 {
+                address,
+                value: byte,
+            },
+            0x10 => CheatOp::Add16 { address, value },
+            0x11 => CheatOp::Sub16 { address, value },
+            0x20 => CheatOp::Add8 {
+                address,
+                value: byte,
+            },
+            0x21 => CheatOp::Sub8 {
+                address,
+                value: byte,
+            },
+            0xD0 | 0xD1 => CheatOp::If16 {
+                address,
+                value,
+                equal: head >> 24 == 0xD0,
+            },
+            0xE0 | 0xE1 => CheatOp::If8 {
+                address,
+                value: byte,
+                equal: head >> 24 == 0xE0,
+            },
+            _ => return Err(invalid()),
+        })
+    }
+
+    /// Execute the line
+    ///
+    /// # Returns
+    ///
+    /// false if this is a conditional whose condition failed
+    fn apply(&self, bus: &mut Bus) -> Result<bool> {
+        match *self {
+            CheatOp::Write16 { address, value } => bus.write16(address, value)?,
+            CheatOp::Write8 { address, value } => bus.write8(address, value)?,
+            CheatOp::Add16 { address, value } => {
+                let old = bus.read16(address)?;
+                bus.write16(address, old.wrapping_add(value))?;
+            }
+            CheatOp::Sub16 { address, value } => {
+                let old = bus.read16(address)?;
+                bus.write16(address, old.wrapping_sub(value))?;
+            }
+            CheatOp::Add8 { address, value } => {
+                let old = bus.read8(address)?;
+                bus.write8(address, old.wrapping_add(value))?;
+            }
+            CheatOp::Sub8 { address, value } => {
+                let old = bus.read8(address)?;
+                bus.write8(address, old.wrapping_sub(value))?;
+            }
+            CheatOp::If16 {
+                address,
+                value,
+                equal,
+            } => return Ok((bus.read16(address)? == value) == equal),
+            CheatOp::If8 {
+                address,
+                value,
+                equal,
+            } => return Ok((bus.read8(address)? == value) == equal),
+        }
+        Ok(true)
+    }
+}
+
+/// A cheat made of one or more code lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cheat {
+    /// Decoded lines in order
+    ops: Vec<CheatOp>,
+}
+
+impl Cheat {
+    /// Parse a cheat from GameShark code text
+    ///
+    /// Lines are separated by newlines; blank lines are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - Code text such as `"80012345 0063"`
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Cheat)` if every line is a supported code
+    /// - `Err(EmulatorError::Parse)` for malformed or unsupported lines
+    pub fn parse(code: &str) -> Result<Self> {
+        let ops = code
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(CheatOp::parse)
+            .collect::<Result<Vec<_>>>()?;
+
+        if ops.is_empty() {
+            return Err(EmulatorError::Parse("empty cheat code".to_string()));
+        }
+
+        Ok(Self { ops })
+    }
+
+    /// Number of code lines
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Check whether the cheat has no lines
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Apply the cheat to memory
+    ///
+    /// A failed conditional skips the line that follows it.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - Memory bus to patch
+    pub fn apply(&self, bus: &mut Bus) -> Result<()> {
+        let mut ops = self.ops.iter();
+        while let Some(op) = ops.next() {
+            if !op.apply(bus)? {
+                ops.next();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Set of active cheats
+#[derive(Debug, Default)]
+pub struct CheatEngine {
+    /// Active cheats in the order they were added
+    cheats: Vec<Cheat>,
+}
+
+impl CheatEngine {
+    /// Create an engine with no cheats
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse and add a cheat
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - GameShark code text
+    ///
+    /// # Returns
+    ///
+    /// `Err(EmulatorError::Parse)` if the code is invalid
+    pub fn add(&mut self, code: &str) -> Result<()> {
+        let cheat = Cheat::parse(code)?;
+        self.cheats.push(cheat);
+        Ok(())
+    }
+
+    /// Remove a previously added cheat
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - Code text of the cheat (whitespace and case may differ)
+    ///
+    /// # Returns
+    ///
+    /// true if a matching cheat was removed
+    pub fn remove(&mut self, code: &str) -> bool {
+        let Ok(cheat) = Cheat::parse(code) else {
+            return false;
+        };
+
+        match self.cheats.iter().position(|c| *c == cheat) {
+            Some(index) => {
+                self.cheats.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Number of active cheats
+    pub fn len(&self) -> usize {
+        self.cheats.len()
+    }
+
+    /// Check whether no cheats are active
+    pub fn is_empty(&self) -> bool {
+        self.cheats.is_empty()
+    }
+
+    /// Apply every active cheat
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - Memory bus to patch
+    pub fn apply(&self, bus: &mut Bus) {
+        for cheat in &self.cheats {
+            if let Err(e) = cheat.apply(bus) {
+                log::warn!("Cheat: failed to apply code: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_malformed_codes() {
+        assert!(Cheat::parse("").is_err());
+        assert!(Cheat::parse("8001234 0063").is_err());
+        assert!(Cheat::parse("80012345 00G3").is_err());
+        assert!(Cheat::parse("F0012345 0063").is_err());
+    }
+
+    #[test]
+    fn test_constant_write_16_and_8() {
+        let mut bus = Bus::new();
+        let mut engine = CheatEngine::new();
+        engine.add("80012344 BEEF").unwrap();
+        engine.add("30012346 0063").unwrap();
+
+        engine.apply(&mut bus);
+
+        assert_eq!(bus.read16(0x80012344).unwrap(), 0xBEEF);
+        assert_eq!(bus.read8(0x80012346).unwrap(), 0x63);
+    }
+
+    #[test]
+    fn test_conditional_gates_next_line() {
+        let mut bus = Bus::new();
+        let mut engine = CheatEngine::new();
+        engine.add("D0010000 0001\n80010010 1234").unwrap();
+        engine.add("E1010000 0001\n30010020 0055").unwrap();
+
+        // Condition false: nothing written
+        engine.apply(&mut bus);
+        assert_eq!(bus.read16(0x80010010).unwrap(), 0);
+        assert_eq!(bus.read8(0x80010020).unwrap(), 0x55, "not-equal passes");
+
+        // Condition true
+        bus.write16(0x80010000, 1).unwrap();
+        bus.write8(0x80010020, 0).unwrap();
+        engine.apply(&mut bus);
+        assert_eq!(bus.read16(0x80010010).unwrap(), 0x1234);
+        assert_eq!(bus.read8(0x80010020).unwrap(), 0, "not-equal fails");
+    }
+
+    #[test]
+    fn test_increment_and_decrement() {
+        let mut bus = Bus::new();
+        let mut engine = CheatEngine::new();
+        engine.add("10010000 0002").unwrap();
+        engine.add("21010004 0001").unwrap();
+
+        engine.apply(&mut bus);
+        engine.apply(&mut bus);
+
+        assert_eq!(bus.read16(0x80010000).unwrap(), 4);
+        assert_eq!(bus.read8(0x80010004).unwrap(), 0xFE);
+    }
+
+    #[test]
+    fn test_remove_matches_normalized_code() {
+        let mut engine = CheatEngine::new();
+        engine.add("80012344 beef").unwrap();
+
+        assert!(!engine.remove("80012344 0000"));
+        assert!(engine.remove("  80012344   BEEF "));
+        assert!(engine.is_empty());
+    }
+}
@@ -17,11 +17,19 @@
 //! - Byte 3: Button state low byte
 //! - Byte 4: Button state high byte
 //!
+//! In analog mode (DualShock) the ID is 0x73 and four stick bytes follow:
+//! - Bytes 5-6: Right stick X, Y
+//! - Bytes 7-8: Left stick X, Y
+//!
+//! Stick axes range from 0x00 to 0xFF with 0x80 at center.
+//!
 //! # Button Encoding
 //!
 //! Buttons use active-low encoding (0 = pressed, 1 = released).
 //! This matches the PlayStation hardware behavior.
 
+use super::save_state::ControllerData;
+
 /// Button bit definitions for PlayStation controller
 ///
 /// All buttons use active-low logic:
@@ -73,10 +81,14 @@ pub(crate) enum SerialState {
     Transferring,
 }
 
-/// PlayStation digital controller (gamepad)
+/// Stick axis value at rest
+const STICK_CENTER: u8 = 0x80;
+
+/// PlayStation controller (gamepad)
 ///
 /// Implements the standard PSX digital controller with 14 buttons
-/// and serial communication protocol.
+/// and serial communication protocol. In analog mode it reports as a
+/// DualShock with two analog sticks.
 ///
 /// # Examples
 ///
@@ -109,6 +121,15 @@ pub struct Controller {
 
     /// Current byte index being transferred
     transfer_index: usize,
+
+    /// Analog (DualShock) mode enabled
+    analog_mode: bool,
+
+    /// Left stick position (X, Y)
+    left_stick: (u8, u8),
+
+    /// Right stick position (X, Y)
+    right_stick: (u8, u8),
 }
 
 impl Controller {
@@ -129,6 +150,9 @@ impl Controller {
             tx_buffer: Vec::new(),
             rx_buffer: Vec::new(),
             transfer_index: 0,
+            analog_mode: false,
+            left_stick: (STICK_CENTER, STICK_CENTER),
+            right_stick: (STICK_CENTER, STICK_CENTER),
         }
     }
 
@@ -217,6 +241,83 @@ impl Controller {
         self.buttons
     }
 
+    /// Replace the whole button state
+    ///
+    /// # Arguments
+    ///
+    /// * `buttons` - 16-bit button state (active low: 0 = pressed)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::controller::{Controller, buttons};
+    ///
+    /// let mut controller = Controller::new();
+    /// controller.set_buttons(!buttons::CROSS);
+    /// assert_eq!(controller.get_buttons() & buttons::CROSS, 0);
+    /// ```
+    #[inline]
+    pub fn set_buttons(&mut self, buttons: u16) {
+        self.buttons = buttons;
+    }
+
+    /// Set analog stick positions
+    ///
+    /// Only reported while analog mode is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `lx`, `ly` - Left stick X/Y (0x00-0xFF, 0x80 = center)
+    /// * `rx`, `ry` - Right stick X/Y (0x00-0xFF, 0x80 = center)
+    pub fn set_sticks(&mut self, lx: u8, ly: u8, rx: u8, ry: u8) {
+        self.left_stick = (lx, ly);
+        self.right_stick = (rx, ry);
+    }
+
+    /// Get analog stick positions
+    ///
+    /// # Returns
+    ///
+    /// `(lx, ly, rx, ry)`
+    pub fn get_sticks(&self) -> (u8, u8, u8, u8) {
+        (
+            self.left_stick.0,
+            self.left_stick.1,
+            self.right_stick.0,
+            self.right_stick.1,
+        )
+    }
+
+    /// Switch between digital pad and analog (DualShock) mode
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - true for analog mode (ID 0x73), false for digital (ID 0x41)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::controller::Controller;
+    ///
+    /// let mut controller = Controller::new();
+    /// controller.set_analog_mode(true);
+    /// controller.select();
+    /// controller.transfer(0x01);
+    /// assert_eq!(controller.transfer(0x42), 0x73);
+    /// ```
+    pub fn set_analog_mode(&mut self, enabled: bool) {
+        self.analog_mode = enabled;
+    }
+
+    /// Check whether analog mode is enabled
+    ///
+    /// # Returns
+    ///
+    /// true if the controller reports as a DualShock
+    pub fn is_analog_mode(&self) -> bool {
+        self.analog_mode
+    }
+
     /// Select the controller (chip select)
     ///
     /// Prepares the controller for serial communication by setting up
@@ -224,10 +325,11 @@ impl Controller {
     ///
     /// Response format:
     /// - Byte 0: 0xFF (will be overwritten by first transfer)
-    /// - Byte 1: 0x41 (Controller ID - Digital Pad)
+    /// - Byte 1: 0x41 (Digital Pad) or 0x73 (Analog Pad)
     /// - Byte 2: 0x5A (Always 0x5A)
     /// - Byte 3: Button state low byte
     /// - Byte 4: Button state high byte
+    /// - Bytes 5-8: Right X, Right Y, Left X, Left Y (analog mode only)
     pub fn select(&mut self) {
         self.state = SerialState::Selected;
         self.transfer_index = 0;
@@ -241,6 +343,16 @@ impl Controller {
             ((self.buttons >> 8) & 0xFF) as u8, // Button state high byte
         ];
 
+        if self.analog_mode {
+            self.tx_buffer[1] = 0x73; // Controller ID: 0x73 = Analog Pad
+            self.tx_buffer.extend_from_slice(&[
+                self.right_stick.0,
+                self.right_stick.1,
+                self.left_stick.0,
+                self.left_stick.1,
+            ]);
+        }
+
         log::trace!("Controller selected, buttons: 0x{:04X}", self.buttons);
     }
 
@@ -309,6 +421,18 @@ impl Controller {
         rx_byte
     }
 
+    /// Check whether the controller acknowledged the last byte
+    ///
+    /// The controller pulls /ACK after every byte except the last one of
+    /// its response.
+    ///
+    /// # Returns
+    ///
+    /// true if the controller expects another byte
+    pub fn ack(&self) -> bool {
+        self.state == SerialState::Transferring && self.transfer_index < self.tx_buffer.len()
+    }
+
     /// Check if controller acknowledged the command
     ///
     /// The first byte sent from console should be 0x01 to indicate
@@ -320,6 +444,42 @@ impl Controller {
     pub fn is_acknowledged(&self) -> bool {
         self.rx_buffer.first() == Some(&0x01)
     }
+
+    /// Save the button and serial protocol state
+    ///
+    /// # Returns
+    ///
+    /// Saved controller data, including a poll in progress
+    pub(crate) fn save_data(&self) -> ControllerData {
+        ControllerData {
+            buttons: self.buttons,
+            serial_state: match self.state {
+                SerialState::Idle => 0,
+                SerialState::Selected => 1,
+                SerialState::Transferring => 2,
+            },
+            tx_buffer: self.tx_buffer.clone(),
+            rx_buffer: self.rx_buffer.clone(),
+            transfer_index: self.transfer_index as u32,
+        }
+    }
+
+    /// Restore the button and serial protocol state
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Data saved by [`Controller::save_data`]
+    pub(crate) fn restore_data(&mut self, data: &ControllerData) {
+        self.buttons = data.buttons;
+        self.state = match data.serial_state {
+            1 => SerialState::Selected,
+            2 => SerialState::Transferring,
+            _ => SerialState::Idle,
+        };
+        self.tx_buffer = data.tx_buffer.clone();
+        self.rx_buffer = data.rx_buffer.clone();
+        self.transfer_index = data.transfer_index as usize;
+    }
 }
 
 impl Default for Controller {
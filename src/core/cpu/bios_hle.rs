@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! BIOS function call hooks
+//!
+//! Programs call the BIOS by jumping to 0xA0, 0xB0 or 0xC0 with the function
+//! number in $t1 (r9). When hooks are enabled, the CPU reports every call
+//! reaching one of these vectors: calls are logged by name and TTY output
+//! (putchar, puts and writes to stdout) is collected into a console buffer.
+//!
+//! The hooks only observe. The call still runs in the real BIOS, so
+//! enabling them never changes emulation results.
+
+use crate::core::memory::Bus;
+
+/// Register holding the function number ($t1)
+const REG_T1: usize = 9;
+/// First argument register ($a0)
+const REG_A0: usize = 4;
+/// Second argument register ($a1)
+const REG_A1: usize = 5;
+/// Third argument register ($a2)
+const REG_A2: usize = 6;
+
+/// Longest string read for a puts/write call
+const MAX_STRING_LEN: u32 = 4096;
+
+/// BIOS call table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BiosVector {
+    /// A(nn) functions at 0xA0
+    A,
+    /// B(nn) functions at 0xB0
+    B,
+    /// C(nn) functions at 0xC0
+    C,
+}
+
+impl BiosVector {
+    /// Identify a BIOS call vector
+    ///
+    /// Matches 0xA0/0xB0/0xC0 in any of KUSEG, KSEG0 and KSEG1.
+    ///
+    /// # Arguments
+    ///
+    /// * `pc` - Instruction address
+    ///
+    /// # Returns
+    ///
+    /// The call table, or None if `pc` is not a call vector
+    #[inline(always)]
+    pub fn from_pc(pc: u32) -> Option<Self> {
+        match pc & 0x1FFF_FFFF {
+            0xA0 => Some(BiosVector::A),
+            0xB0 => Some(BiosVector::B),
+            0xC0 => Some(BiosVector::C),
+            _ => None,
+        }
+    }
+}
+
+/// Name of a well-known BIOS function
+///
+/// # Arguments
+///
+/// * `vector` - Call table
+/// * `function` - Function number from $t1
+///
+/// # Returns
+///
+/// Function name, or None for functions without a known name
+pub fn function_name(vector: BiosVector, function: u32) -> Option<&'static str> {
+    let name = match (vector, function) {
+        (BiosVector::A, 0x00) => "FileOpen",
+        (BiosVector::A, 0x02) => "FileRead",
+        (BiosVector::A, 0x03) => "FileWrite",
+        (BiosVector::A, 0x04) => "FileClose",
+        (BiosVector::A, 0x17) => "strcmp",
+        (BiosVector::A, 0x1B) => "strlen",
+        (BiosVector::A, 0x2A) => "memcpy",
+        (BiosVector::A, 0x2B) => "memset",
+        (BiosVector::A, 0x33) => "malloc",
+        (BiosVector::A, 0x34) => "free",
+        (BiosVector::A, 0x3C) => "std_out_putchar",
+        (BiosVector::A, 0x3E) => "std_out_puts",
+        (BiosVector::A, 0x3F) => "printf",
+        (BiosVector::A, 0x44) => "FlushCache",
+        (BiosVector::A, 0x96) => "AddCDROMDevice",
+        (BiosVector::A, 0xA0) => "_boot",
+        (BiosVector::B, 0x07) => "DeliverEvent",
+        (BiosVector::B, 0x08) => "OpenEvent",
+        (BiosVector::B, 0x0A) => "WaitEvent",
+        (BiosVector::B, 0x0B) => "TestEvent",
+        (BiosVector::B, 0x0C) => "EnableEvent",
+        (BiosVector::B, 0x12) => "InitPad",
+        (BiosVector::B, 0x13) => "StartPad",
+        (BiosVector::B, 0x14) => "StopPad",
+        (BiosVector::B, 0x17) => "ReturnFromException",
+        (BiosVector::B, 0x3D) => "std_out_putchar",
+        (BiosVector::B, 0x3F) => "std_out_puts",
+        (BiosVector::B, 0x4A) => "InitCard",
+        (BiosVector::B, 0x4B) => "StartCard",
+        (BiosVector::B, 0x4C) => "StopCard",
+        (BiosVector::B, 0x56) => "GetC0Table",
+        (BiosVector::B, 0x57) => "GetB0Table",
+        (BiosVector::C, 0x00) => "EnqueueTimerAndVblankIrqs",
+        (BiosVector::C, 0x01) => "EnqueueSyscallHandler",
+        (BiosVector::C, 0x07) => "InstallExceptionHandlers",
+        (BiosVector::C, 0x0A) => "ChangeClearRCnt",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// BIOS call hooks and TTY console
+#[derive(Debug, Default)]
+pub struct BiosHle {
+    /// Console output collected since the last [`BiosHle::take_tty_output`]
+    tty: String,
+}
+
+impl BiosHle {
+    /// Create hooks with an empty console
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Handle a call reaching a BIOS vector
+    ///
+    /// # Arguments
+    ///
+    /// * `vector` - Call table
+    /// * `regs` - CPU registers at the call
+    /// * `bus` - Memory bus, used to read string arguments
+    pub fn on_call(&mut self, vector: BiosVector, regs: &[u32; 32], bus: &Bus) {
+        let function = regs[REG_T1];
+
+        log::debug!(
+            "BIOS {:?}({:02X}) {} a0=0x{:08X} a1=0x{:08X}",
+            vector,
+            function,
+            function_name(vector, function).unwrap_or("?"),
+            regs[REG_A0],
+            regs[REG_A1]
+        );
+
+        match (vector, function) {
+            (BiosVector::A, 0x3C) | (BiosVector::B, 0x3D) => {
+                self.tty.push(regs[REG_A0] as u8 as char);
+            }
+            (BiosVector::A, 0x3E) | (BiosVector::B, 0x3F) => {
+                self.read_string(bus, regs[REG_A0], MAX_STRING_LEN);
+            }
+            // FileWrite to stdout
+            (BiosVector::A, 0x03) if regs[REG_A0] == 1 => {
+                self.read_string(bus, regs[REG_A1], regs[REG_A2].min(MAX_STRING_LEN));
+            }
+            _ => {}
+        }
+    }
+
+    /// Append a NUL-terminated string from memory to the console
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - Memory bus
+    /// * `address` - Start of the string
+    /// * `max_len` - Maximum number of bytes to read
+    fn read_string(&mut self, bus: &Bus, address: u32, max_len: u32) {
+        for offset in 0..max_len {
+            match bus.read8(address.wrapping_add(offset)) {
+                Ok(0) | Err(_) => break,
+                Ok(byte) => self.tty.push(byte as char),
+            }
+        }
+    }
+
+    /// Get the console output collected so far
+    pub fn tty_output(&self) -> &str {
+        &self.tty
+    }
+
+    /// Take the console output collected so far, leaving the buffer empty
+    ///
+    /// # Returns
+    ///
+    /// Text written through the BIOS since the last call
+    pub fn take_tty_output(&mut self) -> String {
+        std::mem::take(&mut self.tty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cpu::CPU;
+
+    fn regs(function: u32, a0: u32, a1: u32, a2: u32) -> [u32; 32] {
+        let mut regs = [0u32; 32];
+        regs[REG_T1] = function;
+        regs[REG_A0] = a0;
+        regs[REG_A1] = a1;
+        regs[REG_A2] = a2;
+        regs
+    }
+
+    #[test]
+    fn test_vector_from_pc_all_segments() {
+        assert_eq!(BiosVector::from_pc(0x000000A0), Some(BiosVector::A));
+        assert_eq!(BiosVector::from_pc(0x800000B0), Some(BiosVector::B));
+        assert_eq!(BiosVector::from_pc(0xA00000C0), Some(BiosVector::C));
+        assert_eq!(BiosVector::from_pc(0x800000A4), None);
+    }
+
+    #[test]
+    fn test_putchar_and_puts_collect_tty() {
+        let mut bus = Bus::new();
+        bus.write32(0x80001000, u32::from_le_bytes(*b"ok\n\0"))
+            .unwrap();
+        let mut hle = BiosHle::new();
+
+        hle.on_call(BiosVector::A, &regs(0x3C, b'>' as u32, 0, 0), &bus);
+        hle.on_call(BiosVector::B, &regs(0x3F, 0x80001000, 0, 0), &bus);
+
+        assert_eq!(hle.take_tty_output(), ">ok\n");
+        assert_eq!(hle.tty_output(), "");
+    }
+
+    #[test]
+    fn test_file_write_only_captures_stdout() {
+        let mut bus = Bus::new();
+        bus.write32(0x80001000, u32::from_le_bytes(*b"abcd"))
+            .unwrap();
+        let mut hle = BiosHle::new();
+
+        hle.on_call(BiosVector::A, &regs(0x03, 1, 0x80001000, 2), &bus);
+        hle.on_call(BiosVector::A, &regs(0x03, 3, 0x80001000, 4), &bus);
+
+        assert_eq!(hle.tty_output(), "ab");
+    }
+
+    #[test]
+    fn test_cpu_reports_call_at_vector() {
+        let mut bus = Bus::new();
+        let mut cpu = CPU::new();
+        cpu.enable_bios_hle();
+
+        // NOP at the A0 vector; putchar('X')
+        cpu.set_pc(0x000000A0);
+        cpu.set_reg(9, 0x3C);
+        cpu.set_reg(4, b'X' as u32);
+        cpu.step(&mut bus).unwrap();
+
+        assert_eq!(cpu.take_tty_output(), "X");
+    }
+
+    #[test]
+    fn test_cpu_hooks_disabled_by_default() {
+        let mut bus = Bus::new();
+        let mut cpu = CPU::new();
+
+        cpu.set_pc(0x000000A0);
+        cpu.set_reg(9, 0x3C);
+        cpu.set_reg(4, b'X' as u32);
+        cpu.step(&mut bus).unwrap();
+
+        assert_eq!(cpu.take_tty_output(), "");
+    }
+}
@@ -16,6 +16,8 @@
 //! MIPS instruction disassembler for debugging
 //!
 //! Converts binary instruction encodings to human-readable assembly mnemonics.
+//! Branch and jump targets are resolved to absolute addresses, and COP2
+//! instructions decode to their GTE command names.
 
 use super::decode::{decode_i_type, decode_j_type, decode_r_type};
 
@@ -39,7 +41,7 @@ impl Disassembler {
     /// # Arguments
     ///
     /// * `instruction` - The 32-bit instruction to disassemble
-    /// * `pc` - Program counter (used for jump target calculation)
+    /// * `pc` - Address of the instruction (used for branch/jump targets)
     ///
     /// # Returns
     ///
@@ -58,7 +60,7 @@ impl Disassembler {
 
         match opcode {
             0x00 => Self::disasm_special(instruction),
-            0x01 => Self::disasm_regimm(instruction),
+            0x01 => Self::disasm_regimm(instruction, pc),
             0x02 => {
                 let (_, target) = decode_j_type(instruction);
                 let addr = (pc & 0xF000_0000) | (target << 2);
@@ -71,19 +73,29 @@ impl Disassembler {
             }
             0x04 => {
                 let (_, rs, rt, imm) = decode_i_type(instruction);
-                format!("beq r{}, r{}, {}", rs, rt, (imm as i16))
+                format!(
+                    "beq r{}, r{}, 0x{:08X}",
+                    rs,
+                    rt,
+                    Self::branch_target(pc, imm)
+                )
             }
             0x05 => {
                 let (_, rs, rt, imm) = decode_i_type(instruction);
-                format!("bne r{}, r{}, {}", rs, rt, (imm as i16))
+                format!(
+                    "bne r{}, r{}, 0x{:08X}",
+                    rs,
+                    rt,
+                    Self::branch_target(pc, imm)
+                )
             }
             0x06 => {
                 let (_, rs, _, imm) = decode_i_type(instruction);
-                format!("blez r{}, {}", rs, (imm as i16))
+                format!("blez r{}, 0x{:08X}", rs, Self::branch_target(pc, imm))
             }
             0x07 => {
                 let (_, rs, _, imm) = decode_i_type(instruction);
-                format!("bgtz r{}, {}", rs, (imm as i16))
+                format!("bgtz r{}, 0x{:08X}", rs, Self::branch_target(pc, imm))
             }
             0x08 => {
                 let (_, rs, rt, imm) = decode_i_type(instruction);
@@ -118,6 +130,7 @@ impl Disassembler {
                 format!("lui r{}, 0x{:04X}", rt, imm)
             }
             0x10 => Self::disasm_cop0(instruction),
+            0x12 => Self::disasm_cop2(instruction),
             0x20 => {
                 let (_, rs, rt, imm) = decode_i_type(instruction);
                 format!("lb r{}, {}(r{})", rt, (imm as i16), rs)
@@ -166,6 +179,14 @@ impl Disassembler {
                 let (_, rs, rt, imm) = decode_i_type(instruction);
                 format!("swr r{}, {}(r{})", rt, (imm as i16), rs)
             }
+            0x32 => {
+                let (_, rs, rt, imm) = decode_i_type(instruction);
+                format!("lwc2 cop2r{}, {}(r{})", rt, (imm as i16), rs)
+            }
+            0x3A => {
+                let (_, rs, rt, imm) = decode_i_type(instruction);
+                format!("swc2 cop2r{}, {}(r{})", rt, (imm as i16), rs)
+            }
             _ => format!("??? 0x{:08X}", instruction),
         }
     }
@@ -214,15 +235,24 @@ impl Disassembler {
         }
     }
 
+    /// Compute a branch target from the branch address and offset
+    ///
+    /// The offset is relative to the delay slot (pc + 4).
+    fn branch_target(pc: u32, imm: u16) -> u32 {
+        pc.wrapping_add(4)
+            .wrapping_add(((imm as i16 as i32) << 2) as u32)
+    }
+
     /// Disassemble REGIMM (opcode 0x01) instruction
-    fn disasm_regimm(instruction: u32) -> String {
+    fn disasm_regimm(instruction: u32, pc: u32) -> String {
         let (_, rs, rt, imm) = decode_i_type(instruction);
+        let target = Self::branch_target(pc, imm);
 
         match rt {
-            0x00 => format!("bltz r{}, {}", rs, (imm as i16)),
-            0x01 => format!("bgez r{}, {}", rs, (imm as i16)),
-            0x10 => format!("bltzal r{}, {}", rs, (imm as i16)),
-            0x11 => format!("bgezal r{}, {}", rs, (imm as i16)),
+            0x00 => format!("bltz r{}, 0x{:08X}", rs, target),
+            0x01 => format!("bgez r{}, 0x{:08X}", rs, target),
+            0x10 => format!("bltzal r{}, 0x{:08X}", rs, target),
+            0x11 => format!("bgezal r{}, 0x{:08X}", rs, target),
             _ => format!("??? 0x{:08X}", instruction),
         }
     }
@@ -246,6 +276,73 @@ impl Disassembler {
             _ => format!("??? 0x{:08X}", instruction),
         }
     }
+
+    /// Disassemble COP2 (GTE) instruction
+    ///
+    /// Register moves use `cop2rN` for data registers and `cop2cN` for
+    /// control registers. Commands print their GTE mnemonic followed by
+    /// the `sf`/`lm` flags when set.
+    fn disasm_cop2(instruction: u32) -> String {
+        let rs = (instruction >> 21) & 0x1F;
+        let rt = (instruction >> 16) & 0x1F;
+        let rd = (instruction >> 11) & 0x1F;
+
+        if rs & 0x10 != 0 {
+            return Self::disasm_gte_command(instruction);
+        }
+
+        match rs {
+            0x00 => format!("mfc2 r{}, cop2r{}", rt, rd),
+            0x02 => format!("cfc2 r{}, cop2c{}", rt, rd),
+            0x04 => format!("mtc2 r{}, cop2r{}", rt, rd),
+            0x06 => format!("ctc2 r{}, cop2c{}", rt, rd),
+            _ => format!("??? 0x{:08X}", instruction),
+        }
+    }
+
+    /// Disassemble a GTE command (COP2 with bit 25 set)
+    fn disasm_gte_command(instruction: u32) -> String {
+        let name = match instruction & 0x3F {
+            0x01 => "rtps",
+            0x06 => "nclip",
+            0x0C => "op",
+            0x10 => "dpcs",
+            0x11 => "intpl",
+            0x12 => "mvmva",
+            0x13 => "ncds",
+            0x14 => "cdp",
+            0x16 => "ncdt",
+            0x1B => "nccs",
+            0x1C => "cc",
+            0x1E => "ncs",
+            0x20 => "nct",
+            0x28 => "sqr",
+            0x29 => "dcpl",
+            0x2A => "dpct",
+            0x2D => "avsz3",
+            0x2E => "avsz4",
+            0x30 => "rtpt",
+            0x3D => "gpf",
+            0x3E => "gpl",
+            0x3F => "ncct",
+            _ => return format!("??? 0x{:08X}", instruction),
+        };
+
+        let mut text = name.to_string();
+        if instruction & 0x3F == 0x12 {
+            let mx = (instruction >> 17) & 0x3;
+            let v = (instruction >> 15) & 0x3;
+            let cv = (instruction >> 13) & 0x3;
+            text.push_str(&format!(" mx={}, v={}, cv={}", mx, v, cv));
+        }
+        if instruction & (1 << 19) != 0 {
+            text.push_str(" sf");
+        }
+        if instruction & (1 << 10) != 0 {
+            text.push_str(" lm");
+        }
+        text
+    }
 }
 
 #[cfg(test)]
@@ -488,23 +585,23 @@ mod tests {
 
     #[test]
     fn test_disasm_bne() {
-        // BNE r1, r2, 8
-        let result = Disassembler::disassemble(0x14220008, 0);
-        assert_eq!(result, "bne r1, r2, 8");
+        // BNE r1, r2, +8 words from the delay slot
+        let result = Disassembler::disassemble(0x14220008, 0x80010000);
+        assert_eq!(result, "bne r1, r2, 0x80010024");
     }
 
     #[test]
     fn test_disasm_blez() {
-        // BLEZ r3, 4
-        let result = Disassembler::disassemble(0x18600004, 0);
-        assert_eq!(result, "blez r3, 4");
+        // BLEZ r3, +4 words
+        let result = Disassembler::disassemble(0x18600004, 0x80010000);
+        assert_eq!(result, "blez r3, 0x80010014");
     }
 
     #[test]
     fn test_disasm_bgtz() {
-        // BGTZ r4, -2
-        let result = Disassembler::disassemble(0x1C80FFFE, 0);
-        assert_eq!(result, "bgtz r4, -2");
+        // BGTZ r4, -2 words
+        let result = Disassembler::disassemble(0x1C80FFFE, 0x80010000);
+        assert_eq!(result, "bgtz r4, 0x8000FFFC");
     }
 
     #[test]
@@ -619,30 +716,30 @@ mod tests {
 
     #[test]
     fn test_disasm_bltz() {
-        // BLTZ r5, -10
-        let result = Disassembler::disassemble(0x04A0FFF6, 0);
-        assert_eq!(result, "bltz r5, -10");
+        // BLTZ r5, -10 words
+        let result = Disassembler::disassemble(0x04A0FFF6, 0x80010100);
+        assert_eq!(result, "bltz r5, 0x800100DC");
     }
 
     #[test]
     fn test_disasm_bgez() {
-        // BGEZ r6, 20
-        let result = Disassembler::disassemble(0x04C10014, 0);
-        assert_eq!(result, "bgez r6, 20");
+        // BGEZ r6, +20 words
+        let result = Disassembler::disassemble(0x04C10014, 0x80010000);
+        assert_eq!(result, "bgez r6, 0x80010054");
     }
 
     #[test]
     fn test_disasm_bltzal() {
-        // BLTZAL r7, -100
-        let result = Disassembler::disassemble(0x04F0FF9C, 0);
-        assert_eq!(result, "bltzal r7, -100");
+        // BLTZAL r7, -100 words
+        let result = Disassembler::disassemble(0x04F0FF9C, 0x80010000);
+        assert_eq!(result, "bltzal r7, 0x8000FE74");
     }
 
     #[test]
     fn test_disasm_bgezal() {
-        // BGEZAL r8, 100
-        let result = Disassembler::disassemble(0x05110064, 0);
-        assert_eq!(result, "bgezal r8, 100");
+        // BGEZAL r8, +100 words
+        let result = Disassembler::disassemble(0x05110064, 0x80010000);
+        assert_eq!(result, "bgezal r8, 0x80010194");
     }
 
     // ========== COP0 Tests ==========
@@ -716,23 +813,23 @@ mod tests {
 
     #[test]
     fn test_disasm_beq_negative_offset() {
-        // BEQ with negative offset
-        let result = Disassembler::disassemble(0x1022FFFF, 0);
-        assert_eq!(result, "beq r1, r2, -1");
+        // BEQ with negative offset (branch to itself)
+        let result = Disassembler::disassemble(0x1022FFFF, 0x80010000);
+        assert_eq!(result, "beq r1, r2, 0x80010000");
     }
 
     #[test]
     fn test_disasm_bne_max_positive_offset() {
-        // BNE with max positive offset (32767)
-        let result = Disassembler::disassemble(0x14227FFF, 0);
-        assert_eq!(result, "bne r1, r2, 32767");
+        // BNE with max positive offset (32767 words)
+        let result = Disassembler::disassemble(0x14227FFF, 0x80010000);
+        assert_eq!(result, "bne r1, r2, 0x80030000");
     }
 
     #[test]
     fn test_disasm_bne_max_negative_offset() {
-        // BNE with max negative offset (-32768)
-        let result = Disassembler::disassemble(0x14228000, 0);
-        assert_eq!(result, "bne r1, r2, -32768");
+        // BNE with max negative offset (-32768 words)
+        let result = Disassembler::disassemble(0x14228000, 0x80030000);
+        assert_eq!(result, "bne r1, r2, 0x80010004");
     }
 
     #[test]
@@ -802,6 +899,60 @@ mod tests {
         assert!(result.starts_with("???"));
     }
 
+    // ========== COP2 (GTE) Tests ==========
+
+    #[test]
+    fn test_disasm_mfc2() {
+        // MFC2 r2, cop2r24 (MAC0)
+        let result = Disassembler::disassemble(0x4802C000, 0);
+        assert_eq!(result, "mfc2 r2, cop2r24");
+    }
+
+    #[test]
+    fn test_disasm_ctc2() {
+        // CTC2 r3, cop2c26 (H)
+        let result = Disassembler::disassemble(0x48C3D000, 0);
+        assert_eq!(result, "ctc2 r3, cop2c26");
+    }
+
+    #[test]
+    fn test_disasm_gte_rtps() {
+        // RTPS with sf=1, lm=0
+        let result = Disassembler::disassemble(0x4A180001, 0);
+        assert_eq!(result, "rtps sf");
+    }
+
+    #[test]
+    fn test_disasm_gte_mvmva() {
+        // MVMVA sf=1, mx=0 (RT), v=3 (IR), cv=3 (none), lm=1
+        let result = Disassembler::disassemble(0x4A09E412, 0);
+        assert_eq!(result, "mvmva mx=0, v=3, cv=3 sf lm");
+    }
+
+    #[test]
+    fn test_disasm_gte_nclip() {
+        let result = Disassembler::disassemble(0x4A000006, 0);
+        assert_eq!(result, "nclip");
+    }
+
+    #[test]
+    fn test_disasm_lwc2_swc2() {
+        assert_eq!(
+            Disassembler::disassemble(0xC8A00008, 0),
+            "lwc2 cop2r0, 8(r5)"
+        );
+        assert_eq!(
+            Disassembler::disassemble(0xE8B3FFFC, 0),
+            "swc2 cop2r19, -4(r5)"
+        );
+    }
+
+    #[test]
+    fn test_disasm_invalid_gte_command() {
+        let result = Disassembler::disassemble(0x4A000000, 0);
+        assert!(result.starts_with("???"));
+    }
+
     #[test]
     fn test_disasm_unimplemented_opcode() {
         // Unimplemented opcode (e.g., 0x30 - not defined)
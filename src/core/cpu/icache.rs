@@ -21,12 +21,18 @@
 //! # Hardware Specifications
 //!
 //! The PSX CPU (MIPS R3000A) has a 4KB instruction cache with the following characteristics:
-//! - **Size**: 4KB (1024 cache lines × 4 bytes per line)
+//! - **Size**: 4KB (256 cache lines × 16 bytes per line)
 //! - **Organization**: Direct-mapped
-//! - **Line size**: 4 bytes (1 instruction per line)
-//! - **Indexing**: Lower 12 bits (bits [11:2]) select the cache line
+//! - **Line size**: 16 bytes (4 instructions per line, one valid bit per word)
+//! - **Indexing**: Bits [11:4] select the cache line, bits [3:2] the word
 //! - **Tag**: Upper 20 bits (bits [31:12]) identify the cached address
 //!
+//! # Cache Isolation
+//!
+//! While COP0 SR.IsC is set, CPU stores are redirected to the cache
+//! instead of memory (see [`InstructionCache::write_isolated`]). The BIOS
+//! uses this to flush the cache and to size RAM without touching it.
+//!
 //! # Design Rationale
 //!
 //! A direct-mapped cache was chosen for:
@@ -57,16 +63,16 @@
 ///
 /// Each cache line stores:
 /// - **tag**: Upper 20 bits of the address (bits [31:12])
-/// - **data**: The 32-bit instruction word
-/// - **valid**: Whether this cache line contains valid data
+/// - **data**: Four consecutive 32-bit instruction words
+/// - **valid**: One valid bit per word (bit N = word N)
 #[derive(Debug, Clone, Copy)]
 struct CacheLine {
     /// Address tag (upper 20 bits)
     tag: u32,
-    /// Cached instruction word
-    data: u32,
-    /// Valid bit
-    valid: bool,
+    /// Cached instruction words
+    data: [u32; 4],
+    /// Per-word valid bits
+    valid: u8,
 }
 
 impl CacheLine {
@@ -75,15 +81,15 @@ impl CacheLine {
     const fn new() -> Self {
         Self {
             tag: 0,
-            data: 0,
-            valid: false,
+            data: [0; 4],
+            valid: 0,
         }
     }
 }
 
 /// Direct-mapped instruction cache for MIPS R3000A
 ///
-/// Implements a 4KB instruction cache with 1024 cache lines,
+/// Implements a 4KB instruction cache with 256 cache lines of 4 words,
 /// matching the PSX hardware specifications.
 ///
 /// # Cache Organization
@@ -91,43 +97,53 @@ impl CacheLine {
 /// ```text
 /// Address format (32 bits):
 /// [31:12] Tag (20 bits) - Identifies which address is cached
-/// [11:2]  Index (10 bits) - Selects cache line (0-1023)
+/// [11:4]  Index (8 bits) - Selects cache line (0-255)
+/// [3:2]   Word (2 bits) - Selects instruction within the line
 /// [1:0]   Byte offset (always 00 for word-aligned instructions)
 /// ```
 ///
+/// Storing a word whose tag differs from the line's tag replaces the tag
+/// and drops every other word of the line, as on hardware.
+///
 /// # Performance Characteristics
 ///
 /// - **Lookup**: O(1) - Direct indexing, no search required
 /// - **Store**: O(1) - Direct replacement
 /// - **Invalidate**: O(1) - Single entry
-/// - **Invalidate range**: O(n) - Linear scan of affected lines
+/// - **Invalidate range**: O(n) - Linear scan of affected words
 /// - **Clear**: O(1) - Bulk reset
 ///
 /// # Memory Usage
 ///
-/// - 1024 cache lines × 12 bytes per line = 12KB total
-/// - Each line contains: tag (4 bytes) + data (4 bytes) + valid (1 byte) + padding (3 bytes)
+/// - 256 cache lines × 24 bytes per line = 6KB total
+/// - Each line contains: tag (4 bytes) + data (16 bytes) + valid (1 byte) + padding (3 bytes)
 pub struct InstructionCache {
-    /// Cache lines (1024 entries for 4KB cache)
+    /// Cache lines (256 entries for 4KB cache)
     lines: Vec<CacheLine>,
 }
 
 impl InstructionCache {
-    /// Number of cache lines (4KB / 4 bytes per instruction)
-    const LINE_COUNT: usize = 1024;
+    /// Number of cache lines (4KB / 16 bytes per line)
+    const LINE_COUNT: usize = 256;
 
-    /// Bit mask for extracting the cache line index (bits [11:2])
-    const INDEX_MASK: u32 = 0x3FF; // 10 bits for 1024 lines
+    /// Number of instruction words per cache line
+    const WORDS_PER_LINE: usize = 4;
+
+    /// Bit mask for extracting the cache line index (bits [11:4])
+    const INDEX_MASK: u32 = 0xFF; // 8 bits for 256 lines
 
     /// Bit shift for extracting the cache line index from address
-    const INDEX_SHIFT: u32 = 2;
+    const INDEX_SHIFT: u32 = 4;
+
+    /// Bit shift for extracting the word offset within a line
+    const WORD_SHIFT: u32 = 2;
 
     /// Bit shift for extracting the tag from address
     const TAG_SHIFT: u32 = 12;
 
     /// Create a new instruction cache
     ///
-    /// Allocates 1024 cache lines, all initially invalid.
+    /// Allocates 256 cache lines, all initially invalid.
     ///
     /// # Example
     ///
@@ -145,7 +161,7 @@ impl InstructionCache {
 
     /// Extract cache line index from address
     ///
-    /// Takes bits [11:2] of the address to select one of 1024 cache lines.
+    /// Takes bits [11:4] of the address to select one of 256 cache lines.
     ///
     /// # Arguments
     ///
@@ -153,12 +169,26 @@ impl InstructionCache {
     ///
     /// # Returns
     ///
-    /// Cache line index (0-1023)
+    /// Cache line index (0-255)
     #[inline(always)]
     fn index(&self, addr: u32) -> usize {
         ((addr >> Self::INDEX_SHIFT) & Self::INDEX_MASK) as usize
     }
 
+    /// Extract word offset within a cache line from address
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - Instruction address
+    ///
+    /// # Returns
+    ///
+    /// Word offset (0-3)
+    #[inline(always)]
+    fn word(&self, addr: u32) -> usize {
+        ((addr >> Self::WORD_SHIFT) as usize) & (Self::WORDS_PER_LINE - 1)
+    }
+
     /// Extract tag from address
     ///
     /// Takes bits [31:12] of the address for tag comparison.
@@ -179,7 +209,7 @@ impl InstructionCache {
     ///
     /// Performs a cache lookup using direct-mapped addressing.
     /// Returns the cached instruction if:
-    /// - The cache line is valid
+    /// - The word's valid bit is set
     /// - The tag matches
     ///
     /// # Arguments
@@ -207,12 +237,11 @@ impl InstructionCache {
     /// ```
     #[inline(always)]
     pub fn fetch(&self, addr: u32) -> Option<u32> {
-        let index = self.index(addr);
-        let tag = self.tag(addr);
+        let word = self.word(addr);
+        let line = &self.lines[self.index(addr)];
 
-        let line = &self.lines[index];
-        if line.valid && line.tag == tag {
-            Some(line.data)
+        if line.valid & (1 << word) != 0 && line.tag == self.tag(addr) {
+            Some(line.data[word])
         } else {
             None
         }
@@ -221,7 +250,8 @@ impl InstructionCache {
     /// Store instruction in cache
     ///
     /// Stores an instruction in the cache using direct-mapped addressing.
-    /// If another instruction already occupies this cache line, it is evicted.
+    /// If the line currently holds a different tag, the whole line is
+    /// evicted before the new word is stored.
     ///
     /// # Arguments
     ///
@@ -244,18 +274,60 @@ impl InstructionCache {
     #[inline(always)]
     pub fn store(&mut self, addr: u32, instruction: u32) {
         let index = self.index(addr);
+        let word = self.word(addr);
         let tag = self.tag(addr);
 
-        self.lines[index] = CacheLine {
-            tag,
-            data: instruction,
-            valid: true,
-        };
+        let line = &mut self.lines[index];
+        if line.tag != tag {
+            line.tag = tag;
+            line.valid = 0;
+        }
+        line.data[word] = instruction;
+        line.valid |= 1 << word;
+    }
+
+    /// Handle a CPU store while the cache is isolated (COP0 SR.IsC set)
+    ///
+    /// Stores never reach memory during isolation. In tag test mode
+    /// (cache control bit 2) the store writes the line's tag and clears
+    /// its valid bits, which is how the BIOS flushes the cache. Otherwise
+    /// the value is written into the data word of the line.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - Store address
+    /// * `value` - Value being stored
+    /// * `tag_test` - Whether tag test mode is enabled in cache control
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::cpu::icache::InstructionCache;
+    ///
+    /// let mut cache = InstructionCache::new();
+    /// cache.store(0x80000010, 0x3C080000);
+    ///
+    /// // BIOS cache flush: store 0 to each line in tag test mode
+    /// cache.write_isolated(0x00000010, 0, true);
+    /// assert_eq!(cache.fetch(0x80000010), None);
+    /// ```
+    pub fn write_isolated(&mut self, addr: u32, value: u32, tag_test: bool) {
+        let index = self.index(addr);
+        let word = self.word(addr);
+        let tag = self.tag(addr);
+
+        let line = &mut self.lines[index];
+        if tag_test {
+            line.tag = tag;
+            line.valid = 0;
+        } else {
+            line.data[word] = value;
+        }
     }
 
     /// Invalidate cached instruction at given address
     ///
-    /// Marks the cache line as invalid. This is essential for cache coherency
+    /// Clears the word's valid bit. This is essential for cache coherency
     /// when memory is modified after caching (self-modifying code, DMA, etc.).
     ///
     /// # Arguments
@@ -276,11 +348,12 @@ impl InstructionCache {
     #[inline(always)]
     pub fn invalidate(&mut self, addr: u32) {
         let index = self.index(addr);
+        let word = self.word(addr);
         let tag = self.tag(addr);
 
         let line = &mut self.lines[index];
-        if line.valid && line.tag == tag {
-            line.valid = false;
+        if line.tag == tag {
+            line.valid &= !(1 << word);
         }
     }
 
@@ -325,13 +398,7 @@ impl InstructionCache {
                 break;
             }
 
-            let index = self.index(addr);
-            let tag = self.tag(addr);
-
-            let line = &mut self.lines[index];
-            if line.valid && line.tag == tag {
-                line.valid = false;
-            }
+            self.invalidate(addr);
 
             if addr == end_aligned {
                 break;
@@ -388,7 +455,7 @@ impl InstructionCache {
     /// ```
     pub fn clear(&mut self) {
         for line in &mut self.lines {
-            line.valid = false;
+            line.valid = 0;
         }
     }
 
@@ -412,7 +479,7 @@ impl InstructionCache {
     /// assert!(!cache.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.lines.iter().all(|line| !line.valid)
+        self.lines.iter().all(|line| line.valid == 0)
     }
 
     /// Get number of valid cached entries
@@ -421,7 +488,7 @@ impl InstructionCache {
     ///
     /// # Returns
     ///
-    /// Number of valid cache entries (0-1024)
+    /// Number of valid cached words (0-1024)
     ///
     /// # Example
     ///
@@ -436,7 +503,10 @@ impl InstructionCache {
     /// assert_eq!(cache.len(), 2);
     /// ```
     pub fn len(&self) -> usize {
-        self.lines.iter().filter(|line| line.valid).count()
+        self.lines
+            .iter()
+            .map(|line| line.valid.count_ones() as usize)
+            .sum()
     }
 
     /// Get cache hit rate statistics
@@ -460,7 +530,51 @@ impl InstructionCache {
     /// assert!(occupancy > 0.0 && occupancy <= 100.0);
     /// ```
     pub fn occupancy(&self) -> f64 {
-        (self.len() as f64 / Self::LINE_COUNT as f64) * 100.0
+        (self.len() as f64 / (Self::LINE_COUNT * Self::WORDS_PER_LINE) as f64) * 100.0
+    }
+
+    /// Export every cache line for a save state
+    ///
+    /// # Returns
+    ///
+    /// One `(tag, words, valid bits)` entry per line, in index order
+    pub fn save_lines(&self) -> Vec<(u32, [u32; 4], u8)> {
+        self.lines
+            .iter()
+            .map(|line| (line.tag, line.data, line.valid))
+            .collect()
+    }
+
+    /// Restore cache lines exported by [`InstructionCache::save_lines`]
+    ///
+    /// A list of the wrong length (e.g. from an older state) leaves the
+    /// cache empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - One `(tag, words, valid bits)` entry per line
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::cpu::icache::InstructionCache;
+    ///
+    /// let mut cache = InstructionCache::new();
+    /// cache.store(0x80000000, 0x24080001);
+    ///
+    /// let mut restored = InstructionCache::new();
+    /// restored.restore_lines(&cache.save_lines());
+    /// assert_eq!(restored.fetch(0x80000000), Some(0x24080001));
+    /// ```
+    pub fn restore_lines(&mut self, lines: &[(u32, [u32; 4], u8)]) {
+        if lines.len() != Self::LINE_COUNT {
+            self.clear();
+            return;
+        }
+
+        for (line, &(tag, data, valid)) in self.lines.iter_mut().zip(lines) {
+            *line = CacheLine { tag, data, valid };
+        }
     }
 }
 
@@ -608,10 +722,11 @@ mod tests {
     fn test_index_extraction() {
         let cache = InstructionCache::new();
 
-        // Test index extraction
+        // Test index extraction (16-byte lines)
         assert_eq!(cache.index(0x80000000), 0);
-        assert_eq!(cache.index(0x80000004), 1);
-        assert_eq!(cache.index(0x80000008), 2);
+        assert_eq!(cache.index(0x8000000C), 0);
+        assert_eq!(cache.index(0x80000010), 1);
+        assert_eq!(cache.word(0x80000008), 2);
 
         // Test wrapping (address with same lower 12 bits)
         assert_eq!(cache.index(0x80000000), cache.index(0x80001000));
@@ -1050,8 +1165,8 @@ mod tests {
 
         // PSX has RAM mirrors at different addresses
         // 0x00000000 (KUSEG), 0x80000000 (KSEG0 cached), 0xA0000000 (KSEG1 uncached)
-        // All three addresses map to the same cache index (1024)
-        // index = (addr >> 2) & 0x3FF = (0x1000 >> 2) & 0x3FF = 0x400 & 0x3FF = 0x0
+        // All three addresses map to the same cache line
+        // index = (addr >> 4) & 0xFF = (0x1000 >> 4) & 0xFF = 0x100 & 0xFF = 0x0
         // So they all map to index 0 with different tags
         let kuseg_addr = 0x00001000;
         let kseg0_addr = 0x80001000;
@@ -1067,4 +1182,35 @@ mod tests {
         assert_eq!(cache.fetch(kseg0_addr), None); // Evicted
         assert_eq!(cache.fetch(kseg1_addr), Some(0x33333333)); // Last stored
     }
+
+    #[test]
+    fn test_cache_tag_change_evicts_whole_line() {
+        let mut cache = InstructionCache::new();
+
+        cache.store(0x80000000, 0x11111111);
+        cache.store(0x80000004, 0x22222222);
+
+        // Same line, different tag: every word of the line is dropped
+        cache.store(0x80001008, 0x33333333);
+
+        assert_eq!(cache.fetch(0x80000000), None);
+        assert_eq!(cache.fetch(0x80000004), None);
+        assert_eq!(cache.fetch(0x80001008), Some(0x33333333));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_write_isolated_modes() {
+        let mut cache = InstructionCache::new();
+        cache.store(0x80000020, 0xAAAAAAAA);
+
+        // Data mode overwrites the word but keeps the tag and valid bits
+        cache.write_isolated(0x80000020, 0xBBBBBBBB, false);
+        assert_eq!(cache.fetch(0x80000020), Some(0xBBBBBBBB));
+
+        // Tag test mode rewrites the tag and invalidates the line
+        cache.write_isolated(0x00000020, 0, true);
+        assert_eq!(cache.fetch(0x80000020), None);
+        assert!(cache.is_empty());
+    }
 }
@@ -18,8 +18,11 @@
 //! This module implements CPU instructions that interact with Coprocessor 2
 //! (the Geometry Transformation Engine).
 
+use super::super::decode::decode_i_type;
+use super::super::ExceptionCause;
 use super::CPU;
 use crate::core::error::Result;
+use crate::core::memory::Bus;
 
 impl CPU {
     /// MFC2: Move From Coprocessor 2 (data register)
@@ -155,14 +158,211 @@ impl CPU {
     /// - 0x0280030: RTPT (Rotate, Translate, Perspective transform Triple)
     /// - 0x01400006: NCLIP (Normal clipping)
     /// - 0x0400012: MVMVA (Matrix-Vector multiply with vector addition)
+    ///
+    /// The command's cycle cost is added to `stall_cycles` so the caller
+    /// can advance the system clock by the GTE execution time.
     pub(super) fn op_gte_command(&mut self, instruction: u32) -> Result<()> {
         // The lower 25 bits contain the GTE command
         let command = instruction & 0x01FFFFFF;
 
         log::trace!("GTE command: 0x{:08X}", command);
 
-        self.gte.execute(command);
+        let cycles = self.gte.execute(command);
+        self.stall_cycles += cycles;
+
+        Ok(())
+    }
+
+    /// LWC2: Load Word to Coprocessor 2
+    ///
+    /// Loads a 32-bit word from memory into a GTE data register.
+    /// The address must be 4-byte aligned.
+    ///
+    /// Format: lwc2 rt, offset(rs)
+    /// - rt: GTE data register (bits [20:16])
+    ///
+    /// # Arguments
+    ///
+    /// * `instruction` - The full 32-bit instruction
+    /// * `bus` - Memory bus for reading
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) on success, triggers AddressErrorLoad exception on misalignment
+    pub(super) fn op_lwc2(&mut self, instruction: u32, bus: &mut Bus) -> Result<()> {
+        if !self.gte_enabled() {
+            return Ok(());
+        }
+
+        let (_, rs, rt, imm) = decode_i_type(instruction);
+        let offset = (imm as i16) as i32; // Sign extend
+        let addr = self.reg(rs).wrapping_add(offset as u32);
+
+        if addr & 0x3 != 0 {
+            self.address_error(ExceptionCause::AddressErrorLoad, addr);
+            return Ok(());
+        }
+
+        self.stall_cycles += bus.access_latency(addr);
+
+        let value = bus.read32(addr)?;
+        self.gte.write_data(rt as usize, value as i32);
+
+        log::trace!(
+            "LWC2: GTE.data[{}] = [0x{:08X}] (0x{:08X})",
+            rt,
+            addr,
+            value
+        );
 
         Ok(())
     }
+
+    /// SWC2: Store Word from Coprocessor 2
+    ///
+    /// Stores a GTE data register to memory.
+    /// The address must be 4-byte aligned.
+    ///
+    /// Format: swc2 rt, offset(rs)
+    /// - rt: GTE data register (bits [20:16])
+    ///
+    /// # Arguments
+    ///
+    /// * `instruction` - The full 32-bit instruction
+    /// * `bus` - Memory bus for writing
+    ///
+    /// # Returns
+    ///
+    /// Ok(()) on success, triggers AddressErrorStore exception on misalignment
+    pub(super) fn op_swc2(&mut self, instruction: u32, bus: &mut Bus) -> Result<()> {
+        if !self.gte_enabled() {
+            return Ok(());
+        }
+
+        let (_, rs, rt, imm) = decode_i_type(instruction);
+        let offset = (imm as i16) as i32; // Sign extend
+        let addr = self.reg(rs).wrapping_add(offset as u32);
+
+        if addr & 0x3 != 0 {
+            self.address_error(ExceptionCause::AddressErrorStore, addr);
+            return Ok(());
+        }
+
+        let value = self.gte.read_data(rt as usize) as u32;
+        if self.cache_isolated() {
+            self.store_isolated(bus, addr, value);
+            return Ok(());
+        }
+
+        bus.write32(addr, value)?;
+
+        log::trace!(
+            "SWC2: [0x{:08X}] = GTE.data[{}] (0x{:08X})",
+            addr,
+            rt,
+            value
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::COP0;
+    use super::*;
+
+    /// CPU with the GTE enabled (SR.CU2) and `program` at 0x80010000
+    fn setup_program(bus: &mut Bus, program: &[u32]) -> CPU {
+        let mut cpu = CPU::new();
+        cpu.cop0.regs[COP0::SR] |= 1 << 30;
+        for (i, &instruction) in program.iter().enumerate() {
+            bus.write32(0x80010000 + i as u32 * 4, instruction).unwrap();
+        }
+        cpu.set_pc(0x80010000);
+        cpu
+    }
+
+    #[test]
+    fn test_gte_command_adds_stall_cycles() {
+        let mut cpu = CPU::new();
+
+        // COP2 RTPS (sf=1)
+        cpu.op_gte_command(0x4A18_0001).unwrap();
+        assert_eq!(cpu.stall_cycles, 15);
+
+        // COP2 NCLIP accumulates until drained
+        cpu.op_gte_command(0x4A00_0006).unwrap();
+        assert_eq!(cpu.stall_cycles, 23);
+    }
+
+    #[test]
+    fn test_mtc2_command_mfc2_round_trip() {
+        let mut bus = Bus::new();
+        // SXY0=(0,0), SXY1=(10,0), SXY2=(0,10): NCLIP gives 100 in MAC0
+        let mut cpu = setup_program(
+            &mut bus,
+            &[
+                0x48886000, // mtc2 r8, $12 (SXY0)
+                0x48896800, // mtc2 r9, $13 (SXY1)
+                0x488A7000, // mtc2 r10, $14 (SXY2)
+                0x4A000006, // nclip
+                0x480BC000, // mfc2 r11, $24 (MAC0)
+                0x00000000, // nop (load delay)
+            ],
+        );
+        cpu.set_reg(8, 0);
+        cpu.set_reg(9, 10);
+        cpu.set_reg(10, 10 << 16);
+
+        let mut cycles = 0;
+        for _ in 0..6 {
+            cycles += cpu.step(&mut bus).unwrap();
+        }
+
+        assert_eq!(cpu.reg(11), 100);
+        // Each instruction misses the cold instruction cache and pays a RAM read
+        let fetch = 1 + bus.access_latency(0x80010000);
+        assert_eq!(cycles, 6 * fetch + 8, "NCLIP adds its command cycles");
+    }
+
+    #[test]
+    fn test_ctc2_cfc2_round_trip() {
+        let mut bus = Bus::new();
+        let mut cpu = setup_program(
+            &mut bus,
+            &[
+                0x48C8D000, // ctc2 r8, $26 (H)
+                0x4849D000, // cfc2 r9, $26
+                0x00000000, // nop (load delay)
+            ],
+        );
+        cpu.set_reg(8, 0x0155);
+
+        for _ in 0..3 {
+            cpu.step(&mut bus).unwrap();
+        }
+
+        // H reads back sign-extended, as on hardware
+        assert_eq!(cpu.reg(9), 0x0155);
+    }
+
+    #[test]
+    fn test_lwc2_swc2_transfer_data_registers() {
+        let mut bus = Bus::new();
+        bus.write32(0x80020000, 0x00640032).unwrap();
+        let mut cpu = setup_program(
+            &mut bus,
+            &[
+                0xC9000000, // lwc2 $0, 0(r8) (VXY0)
+                0xE9000004, // swc2 $0, 4(r8)
+            ],
+        );
+        cpu.set_reg(8, 0x80020000);
+
+        cpu.step(&mut bus).unwrap();
+        cpu.step(&mut bus).unwrap();
+
+        assert_eq!(bus.read32(0x80020004).unwrap(), 0x00640032);
+    }
 }
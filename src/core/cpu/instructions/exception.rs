@@ -73,6 +73,7 @@ impl CPU {
 mod tests {
     use super::*;
     use crate::core::cpu::cop0::COP0;
+    use crate::core::memory::Bus;
 
     fn create_test_cpu() -> CPU {
         CPU::new()
@@ -104,17 +105,18 @@ mod tests {
     #[test]
     fn test_syscall_saves_epc() {
         let mut cpu = create_test_cpu();
+        // SYSCALL at 0xBFC000FC, PC already advanced past it
+        cpu.current_pc = 0xBFC000FC;
         cpu.pc = 0xBFC00100;
         cpu.next_pc = 0xBFC00104;
 
         cpu.op_syscall(0).unwrap();
 
         // EPC should point to the SYSCALL instruction
-        // Implementation saves (pc - 4) as EPC
         assert_eq!(
             cpu.cop0.regs[COP0::EPC],
             0xBFC000FC,
-            "SYSCALL should save PC-4 to EPC"
+            "SYSCALL should save its own address to EPC"
         );
     }
 
@@ -188,6 +190,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_exception_vector_follows_bev() {
+        let mut cpu = create_test_cpu();
+
+        cpu.cop0.regs[COP0::SR] = 0x00400000;
+        assert_eq!(cpu.exception_vector(), 0xBFC00180);
+
+        cpu.cop0.regs[COP0::SR] = 0x00000000;
+        assert_eq!(cpu.exception_vector(), 0x80000080);
+    }
+
+    #[test]
+    fn test_exception_handler_fetched_from_bios_or_ram() {
+        let mut bus = Bus::new();
+        // addiu r8, r0, 0x0B00 in BIOS at 0xBFC00180
+        bus.write_bios_for_test(0x180, &0x24080B00u32.to_le_bytes());
+        // addiu r8, r0, 0x0A00 in RAM at 0x80000080
+        bus.write32(0x80000080, 0x24080A00).unwrap();
+
+        // BEV=1: handler runs from ROM
+        let mut cpu = create_test_cpu();
+        cpu.cop0.regs[COP0::SR] = 0x00400000;
+        cpu.op_syscall(0).unwrap();
+        cpu.step(&mut bus).unwrap();
+        assert_eq!(cpu.reg(8), 0x0B00);
+
+        // BEV=0: handler installed in RAM runs
+        let mut cpu = create_test_cpu();
+        cpu.cop0.regs[COP0::SR] = 0x00000000;
+        cpu.op_syscall(0).unwrap();
+        cpu.step(&mut bus).unwrap();
+        assert_eq!(cpu.reg(8), 0x0A00);
+    }
+
     // ========== BREAK Tests ==========
 
     #[test]
@@ -211,17 +247,18 @@ mod tests {
     #[test]
     fn test_break_saves_epc() {
         let mut cpu = create_test_cpu();
+        // BREAK at 0xA00001FC, PC already advanced past it
+        cpu.current_pc = 0xA00001FC;
         cpu.pc = 0xA0000200;
         cpu.next_pc = 0xA0000204;
 
         cpu.op_break(0).unwrap();
 
         // EPC should point to the BREAK instruction
-        // Implementation saves (pc - 4) as EPC
         assert_eq!(
             cpu.cop0.regs[COP0::EPC],
             0xA00001FC,
-            "BREAK should save PC-4 to EPC"
+            "BREAK should save its own address to EPC"
         );
     }
 
@@ -315,14 +352,239 @@ mod tests {
         );
     }
 
+    // ========== Exception Dispatch Tests ==========
+
+    /// Execute `instruction` at 0x80010000 through `CPU::step`
+    ///
+    /// Returns CAUSE.ExcCode after the step.
+    fn run_at_80010000(cpu: &mut CPU, bus: &mut Bus, instruction: u32) -> u32 {
+        bus.write32(0x80010000, instruction).unwrap();
+        cpu.invalidate_icache(0x80010000);
+        cpu.set_pc(0x80010000);
+        cpu.step(bus).unwrap();
+        (cpu.cop0.regs[COP0::CAUSE] >> 2) & 0x1F
+    }
+
+    #[test]
+    fn test_unaligned_load_raises_adel_with_badvaddr() {
+        let mut cpu = create_test_cpu();
+        let mut bus = Bus::new();
+        cpu.set_reg(8, 0x80020001);
+
+        // lw r9, 0(r8)
+        let code = run_at_80010000(&mut cpu, &mut bus, 0x8D090000);
+
+        assert_eq!(code, ExceptionCause::AddressErrorLoad as u32);
+        assert_eq!(cpu.cop0.regs[COP0::BADA], 0x80020001);
+        assert_eq!(cpu.cop0.regs[COP0::EPC], 0x80010000);
+        assert_eq!(cpu.pc(), cpu.exception_vector());
+    }
+
+    #[test]
+    fn test_unaligned_store_raises_ades_with_badvaddr() {
+        let mut cpu = create_test_cpu();
+        let mut bus = Bus::new();
+        cpu.set_reg(8, 0x80020002);
+
+        // sw r9, 0(r8)
+        let code = run_at_80010000(&mut cpu, &mut bus, 0xAD090000);
+
+        assert_eq!(code, ExceptionCause::AddressErrorStore as u32);
+        assert_eq!(cpu.cop0.regs[COP0::BADA], 0x80020002);
+    }
+
+    #[test]
+    fn test_unaligned_fetch_raises_adel() {
+        let mut cpu = create_test_cpu();
+        let mut bus = Bus::new();
+        cpu.set_pc(0x80010002);
+
+        cpu.step(&mut bus).unwrap();
+
+        let code = (cpu.cop0.regs[COP0::CAUSE] >> 2) & 0x1F;
+        assert_eq!(code, ExceptionCause::AddressErrorLoad as u32);
+        assert_eq!(cpu.cop0.regs[COP0::BADA], 0x80010002);
+        assert_eq!(cpu.cop0.regs[COP0::EPC], 0x80010002);
+    }
+
+    #[test]
+    fn test_unmapped_fetch_raises_instruction_bus_error() {
+        let mut cpu = create_test_cpu();
+        let mut bus = Bus::new();
+        cpu.set_pc(0xBFFFFFF0);
+
+        cpu.step(&mut bus).unwrap();
+
+        let code = (cpu.cop0.regs[COP0::CAUSE] >> 2) & 0x1F;
+        assert_eq!(code, ExceptionCause::BusErrorInstruction as u32);
+        assert_eq!(cpu.cop0.regs[COP0::EPC], 0xBFFFFFF0);
+    }
+
+    #[test]
+    fn test_unmapped_load_raises_data_bus_error() {
+        let mut cpu = create_test_cpu();
+        let mut bus = Bus::new();
+        cpu.set_reg(8, 0xBFFFFFF0);
+
+        // lw r9, 0(r8)
+        let code = run_at_80010000(&mut cpu, &mut bus, 0x8D090000);
+
+        assert_eq!(code, ExceptionCause::BusErrorData as u32);
+        assert_eq!(cpu.cop0.regs[COP0::EPC], 0x80010000);
+    }
+
+    #[test]
+    fn test_add_overflow_raises_ov() {
+        let mut cpu = create_test_cpu();
+        let mut bus = Bus::new();
+        cpu.set_reg(8, 0x7FFFFFFF);
+        cpu.set_reg(9, 1);
+
+        // add r10, r8, r9
+        let code = run_at_80010000(&mut cpu, &mut bus, 0x01095020);
+
+        assert_eq!(code, ExceptionCause::Overflow as u32);
+        assert_eq!(cpu.reg(10), 0, "rd must not be written on overflow");
+    }
+
+    #[test]
+    fn test_syscall_and_break_through_step() {
+        let mut cpu = create_test_cpu();
+        let mut bus = Bus::new();
+
+        let code = run_at_80010000(&mut cpu, &mut bus, 0x0000000C);
+        assert_eq!(code, ExceptionCause::Syscall as u32);
+        assert_eq!(cpu.cop0.regs[COP0::EPC], 0x80010000);
+
+        let code = run_at_80010000(&mut cpu, &mut bus, 0x0000000D);
+        assert_eq!(code, ExceptionCause::Breakpoint as u32);
+    }
+
+    #[test]
+    fn test_reserved_opcode_raises_ri() {
+        let mut cpu = create_test_cpu();
+        let mut bus = Bus::new();
+
+        // Primary opcode 0x14 is not defined
+        let code = run_at_80010000(&mut cpu, &mut bus, 0x50000000);
+        assert_eq!(code, ExceptionCause::ReservedInstruction as u32);
+
+        // SPECIAL function 0x01 is not defined
+        let code = run_at_80010000(&mut cpu, &mut bus, 0x00000001);
+        assert_eq!(code, ExceptionCause::ReservedInstruction as u32);
+    }
+
+    #[test]
+    fn test_missing_coprocessor_raises_cpu_with_ce() {
+        let mut cpu = create_test_cpu();
+        let mut bus = Bus::new();
+
+        // COP1 instruction
+        let code = run_at_80010000(&mut cpu, &mut bus, 0x44000000);
+
+        assert_eq!(code, ExceptionCause::CoprocessorUnusable as u32);
+        assert_eq!((cpu.cop0.regs[COP0::CAUSE] >> 28) & 0x3, 1);
+    }
+
+    #[test]
+    fn test_gte_disabled_raises_cpu() {
+        let mut cpu = create_test_cpu();
+        let mut bus = Bus::new();
+
+        // SR.CU2 clear: GTE command is refused
+        cpu.cop0.regs[COP0::SR] &= !(1 << 30);
+        let code = run_at_80010000(&mut cpu, &mut bus, 0x4A000001);
+
+        assert_eq!(code, ExceptionCause::CoprocessorUnusable as u32);
+        assert_eq!((cpu.cop0.regs[COP0::CAUSE] >> 28) & 0x3, 2);
+    }
+
+    #[test]
+    fn test_fault_in_branch_delay_slot_sets_bd() {
+        let mut cpu = create_test_cpu();
+        let mut bus = Bus::new();
+        cpu.set_reg(8, 0x80020001);
+
+        // beq r0, r0, +4 ; lw r9, 0(r8) in the delay slot
+        bus.write32(0x80010000, 0x10000004).unwrap();
+        bus.write32(0x80010004, 0x8D090000).unwrap();
+        cpu.set_pc(0x80010000);
+
+        cpu.step(&mut bus).unwrap();
+        cpu.step(&mut bus).unwrap();
+
+        let cause = cpu.cop0.regs[COP0::CAUSE];
+        assert_eq!((cause >> 2) & 0x1F, ExceptionCause::AddressErrorLoad as u32);
+        assert_ne!(cause & (1 << 31), 0, "BD should be set");
+        assert_eq!(cpu.cop0.regs[COP0::EPC], 0x80010000, "EPC is the branch");
+        assert_eq!(cpu.cop0.regs[COP0::BADA], 0x80020001);
+    }
+
+    #[test]
+    fn test_interrupt_saves_next_instruction_in_epc() {
+        let mut cpu = create_test_cpu();
+        cpu.set_pc(0x80010008);
+        cpu.cop0.regs[COP0::SR] = 0x00000101; // IEc=1, IM0=1
+
+        cpu.check_interrupts(0x01);
+
+        let cause = cpu.cop0.regs[COP0::CAUSE];
+        assert_eq!((cause >> 2) & 0x1F, ExceptionCause::Interrupt as u32);
+        assert_eq!(cpu.cop0.regs[COP0::EPC], 0x80010008);
+        assert_eq!(cause & (1 << 31), 0);
+    }
+
+    #[test]
+    fn test_interrupt_check_does_not_trigger_watchpoints() {
+        use crate::core::interrupt::{interrupts, InterruptController};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let mut cpu = create_test_cpu();
+        let mut bus = Bus::new();
+        let ic = Rc::new(RefCell::new(InterruptController::new()));
+        ic.borrow_mut().write_mask(interrupts::VBLANK as u32);
+        ic.borrow_mut().request(interrupts::VBLANK);
+        bus.set_interrupt_controller(ic);
+
+        bus.write32(0x80010000, 0x00000000).unwrap(); // nop
+        bus.add_watchpoint(0x80010000, true, false);
+        cpu.set_pc(0x80010000);
+        cpu.cop0.regs[COP0::SR] = 0x00000401; // IEc=1, IM2=1
+
+        cpu.step(&mut bus).unwrap();
+
+        let cause = cpu.cop0.regs[COP0::CAUSE];
+        assert_eq!((cause >> 2) & 0x1F, ExceptionCause::Interrupt as u32);
+        assert_eq!(cpu.cop0.regs[COP0::EPC], 0x80010000);
+        assert!(
+            bus.drain_watchpoint_hits().is_empty(),
+            "looking ahead for a GTE command is not a fetch"
+        );
+    }
+
+    #[test]
+    fn test_rfe_restores_mode_after_exception() {
+        let mut cpu = create_test_cpu();
+        cpu.cop0.regs[COP0::SR] = 0x00000003; // User mode, interrupts enabled
+
+        cpu.op_syscall(0).unwrap();
+        assert_eq!(cpu.cop0.regs[COP0::SR] & 0x3, 0, "kernel, IE off");
+
+        cpu.op_rfe(0).unwrap();
+        assert_eq!(cpu.cop0.regs[COP0::SR] & 0x3, 0x3, "KU/IE restored");
+    }
+
     // ========== Edge Cases ==========
 
     #[test]
     fn test_exception_in_delay_slot() {
         let mut cpu = create_test_cpu();
-        cpu.pc = 0x80001000;
-        cpu.next_pc = 0x80001004;
-        cpu.in_branch_delay = true; // In delay slot
+        // SYSCALL in the delay slot of a branch at 0x80000FFC
+        cpu.current_pc = 0x80001000;
+        cpu.pc = 0x80002000;
+        cpu.next_pc = 0x80002004;
+        cpu.delay_slot = true;
 
         cpu.op_syscall(0).unwrap();
 
@@ -334,6 +596,11 @@ mod tests {
             bd_bit, 1,
             "BD bit should be set when exception in delay slot"
         );
+        assert_eq!(
+            cpu.cop0.regs[COP0::EPC],
+            0x80000FFC,
+            "EPC should point to the branch in delay slot"
+        );
     }
 
     #[test]
@@ -365,16 +632,17 @@ mod tests {
 
     #[test]
     fn test_exception_from_different_pc_values() {
-        let test_pcs = [0x80000000, 0xBFC00000, 0xA0000100, 0x00001000, 0xFFFFFFFC];
+        let test_pcs: [u32; 5] = [0x80000000, 0xBFC00000, 0xA0000100, 0x00001000, 0xFFFFFFFC];
 
         for &test_pc in &test_pcs {
             let mut cpu = create_test_cpu();
+            cpu.current_pc = test_pc.wrapping_sub(4);
             cpu.pc = test_pc;
             cpu.next_pc = test_pc.wrapping_add(4);
 
             cpu.op_syscall(0).unwrap();
 
-            // EPC = pc - 4
+            // EPC = address of the SYSCALL
             let expected_epc = test_pc.wrapping_sub(4);
             assert_eq!(
                 cpu.cop0.regs[COP0::EPC],
@@ -44,10 +44,12 @@ impl CPU {
 
         // Check alignment
         if addr & 0x3 != 0 {
-            self.exception(ExceptionCause::AddressErrorLoad);
+            self.address_error(ExceptionCause::AddressErrorLoad, addr);
             return Ok(());
         }
 
+        self.stall_cycles += bus.access_latency(addr);
+
         let value = bus.read32(addr)?;
         self.set_reg_delayed(rt, value); // Load delay slot
         Ok(())
@@ -76,10 +78,12 @@ impl CPU {
 
         // Check alignment
         if addr & 0x1 != 0 {
-            self.exception(ExceptionCause::AddressErrorLoad);
+            self.address_error(ExceptionCause::AddressErrorLoad, addr);
             return Ok(());
         }
 
+        self.stall_cycles += bus.access_latency(addr);
+
         let value = bus.read16(addr)? as i16 as i32 as u32; // Sign extend
         self.set_reg_delayed(rt, value); // Load delay slot
         Ok(())
@@ -108,10 +112,12 @@ impl CPU {
 
         // Check alignment
         if addr & 0x1 != 0 {
-            self.exception(ExceptionCause::AddressErrorLoad);
+            self.address_error(ExceptionCause::AddressErrorLoad, addr);
             return Ok(());
         }
 
+        self.stall_cycles += bus.access_latency(addr);
+
         let value = bus.read16(addr)? as u32; // Zero extend
         self.set_reg_delayed(rt, value); // Load delay slot
         Ok(())
@@ -138,6 +144,8 @@ impl CPU {
         let offset = (imm as i16) as i32; // Sign extend
         let addr = self.reg(rs).wrapping_add(offset as u32);
 
+        self.stall_cycles += bus.access_latency(addr);
+
         let value = bus.read8(addr)? as i8 as i32 as u32; // Sign extend
         self.set_reg_delayed(rt, value); // Load delay slot
         Ok(())
@@ -164,6 +172,8 @@ impl CPU {
         let offset = (imm as i16) as i32; // Sign extend
         let addr = self.reg(rs).wrapping_add(offset as u32);
 
+        self.stall_cycles += bus.access_latency(addr);
+
         let value = bus.read8(addr)? as u32; // Zero extend
         self.set_reg_delayed(rt, value); // Load delay slot
         Ok(())
@@ -171,11 +181,19 @@ impl CPU {
 
     /// LWL: Load Word Left (unaligned load support)
     ///
-    /// Stub implementation for Phase 1 Week 2.
-    /// Full implementation will be added in Week 3.
+    /// Loads the bytes from the aligned word base up to `addr` into the
+    /// upper bytes of rt, keeping the remaining lower bytes. Paired with LWR
+    /// to load a word from any address.
     ///
     /// Format: lwl rt, offset(rs)
     ///
+    /// | addr & 3 | Result |
+    /// |----------|--------|
+    /// | 0 | `(rt & 0x00FFFFFF) \| (mem << 24)` |
+    /// | 1 | `(rt & 0x0000FFFF) \| (mem << 16)` |
+    /// | 2 | `(rt & 0x000000FF) \| (mem << 8)` |
+    /// | 3 | `mem` |
+    ///
     /// # Arguments
     ///
     /// * `instruction` - The full 32-bit instruction
@@ -183,23 +201,38 @@ impl CPU {
     ///
     /// # Returns
     ///
-    /// Ok(()) - stub implementation
-    pub(crate) fn op_lwl(&mut self, _instruction: u32, _bus: &mut Bus) -> Result<()> {
-        // TODO: Implement LWL based on PSX-SPX documentation (Week 3)
-        log::warn!(
-            "LWL instruction not yet implemented at PC=0x{:08X}",
-            self.pc
-        );
+    /// Ok(()) on success
+    pub(crate) fn op_lwl(&mut self, instruction: u32, bus: &mut Bus) -> Result<()> {
+        let (_, rs, rt, imm) = decode_i_type(instruction);
+        let offset = (imm as i16) as i32; // Sign extend
+        let addr = self.reg(rs).wrapping_add(offset as u32);
+
+        let shift = addr & 0x3;
+        let base = addr & !0x3;
+        self.stall_cycles += bus.access_latency(addr);
+        let mut bytes = self.unaligned_load_target(rt).to_le_bytes();
+        for i in 0..=shift {
+            bytes[(3 - shift + i) as usize] = bus.read8(base + i)?;
+        }
+
+        self.set_reg_delayed(rt, u32::from_le_bytes(bytes)); // Load delay slot
         Ok(())
     }
 
     /// LWR: Load Word Right (unaligned load support)
     ///
-    /// Stub implementation for Phase 1 Week 2.
-    /// Full implementation will be added in Week 3.
+    /// Loads the bytes from `addr` up to the end of its aligned word into
+    /// the lower bytes of rt, keeping the remaining upper bytes.
     ///
     /// Format: lwr rt, offset(rs)
     ///
+    /// | addr & 3 | Result |
+    /// |----------|--------|
+    /// | 0 | `mem` |
+    /// | 1 | `(rt & 0xFF000000) \| (mem >> 8)` |
+    /// | 2 | `(rt & 0xFFFF0000) \| (mem >> 16)` |
+    /// | 3 | `(rt & 0xFFFFFF00) \| (mem >> 24)` |
+    ///
     /// # Arguments
     ///
     /// * `instruction` - The full 32-bit instruction
@@ -207,13 +240,182 @@ impl CPU {
     ///
     /// # Returns
     ///
-    /// Ok(()) - stub implementation
-    pub(crate) fn op_lwr(&mut self, _instruction: u32, _bus: &mut Bus) -> Result<()> {
-        // TODO: Implement LWR based on PSX-SPX documentation (Week 3)
-        log::warn!(
-            "LWR instruction not yet implemented at PC=0x{:08X}",
-            self.pc
-        );
+    /// Ok(()) on success
+    pub(crate) fn op_lwr(&mut self, instruction: u32, bus: &mut Bus) -> Result<()> {
+        let (_, rs, rt, imm) = decode_i_type(instruction);
+        let offset = (imm as i16) as i32; // Sign extend
+        let addr = self.reg(rs).wrapping_add(offset as u32);
+
+        let shift = addr & 0x3;
+        self.stall_cycles += bus.access_latency(addr);
+        let mut bytes = self.unaligned_load_target(rt).to_le_bytes();
+        for i in 0..(4 - shift) {
+            bytes[i as usize] = bus.read8(addr + i)?;
+        }
+
+        self.set_reg_delayed(rt, u32::from_le_bytes(bytes)); // Load delay slot
         Ok(())
     }
+
+    /// Current value of an LWL/LWR target register
+    ///
+    /// LWL and LWR merge into the value still travelling through the load
+    /// delay slot, so a `lwl`/`lwr` pair targeting the same register
+    /// combines both halves without an intervening instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `rt` - Target register
+    ///
+    /// # Returns
+    ///
+    /// Pending load value for `rt` if there is one, otherwise the register
+    fn unaligned_load_target(&self, rt: u8) -> u32 {
+        match self.pending_load.or(self.load_delay) {
+            Some(delay) if delay.reg == rt => delay.value,
+            _ => self.reg(rt),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Helper to build an I-type load instruction
+    fn make_load_inst(opcode: u8, rs: u8, rt: u8, imm: u16) -> u32 {
+        ((opcode as u32) << 26) | ((rs as u32) << 21) | ((rt as u32) << 16) | imm as u32
+    }
+
+    // Commit the pending load so the result is visible
+    fn flush_load_delay(cpu: &mut CPU) {
+        cpu.set_reg_delayed(0, 0);
+    }
+
+    fn bus_with_pattern() -> Bus {
+        let mut bus = Bus::new();
+        bus.write32(0x80001000, 0x44332211).unwrap();
+        bus.write32(0x80001004, 0x88776655).unwrap();
+        bus
+    }
+
+    #[test]
+    fn test_lwl_each_alignment() {
+        let expected = [0x11AABBCC, 0x2211BBCC, 0x332211CC, 0x44332211];
+
+        for (shift, &value) in expected.iter().enumerate() {
+            let mut cpu = CPU::new();
+            let mut bus = bus_with_pattern();
+            cpu.set_reg(1, 0x80001000 + shift as u32);
+            cpu.set_reg(2, 0xDDAABBCC);
+
+            cpu.op_lwl(make_load_inst(0x22, 1, 2, 0), &mut bus).unwrap();
+            flush_load_delay(&mut cpu);
+
+            assert_eq!(cpu.reg(2), value, "LWL with addr & 3 = {}", shift);
+        }
+    }
+
+    #[test]
+    fn test_lwr_each_alignment() {
+        let expected = [0x44332211, 0xDD443322, 0xDDAA4433, 0xDDAABB44];
+
+        for (shift, &value) in expected.iter().enumerate() {
+            let mut cpu = CPU::new();
+            let mut bus = bus_with_pattern();
+            cpu.set_reg(1, 0x80001000 + shift as u32);
+            cpu.set_reg(2, 0xDDAABBCC);
+
+            cpu.op_lwr(make_load_inst(0x26, 1, 2, 0), &mut bus).unwrap();
+            flush_load_delay(&mut cpu);
+
+            assert_eq!(cpu.reg(2), value, "LWR with addr & 3 = {}", shift);
+        }
+    }
+
+    #[test]
+    fn test_lwl_lwr_pair_loads_across_word_boundary() {
+        let mut cpu = CPU::new();
+        let mut bus = bus_with_pattern();
+        cpu.set_reg(1, 0x80001003);
+
+        // lwr r2, 0(r1) ; lwl r2, 3(r1) loads the word at 0x80001003
+        cpu.op_lwr(make_load_inst(0x26, 1, 2, 0), &mut bus).unwrap();
+        cpu.op_lwl(make_load_inst(0x22, 1, 2, 3), &mut bus).unwrap();
+        flush_load_delay(&mut cpu);
+
+        assert_eq!(cpu.reg(2), 0x77665544);
+    }
+
+    #[test]
+    fn test_lwl_merges_into_pending_load() {
+        let mut cpu = CPU::new();
+        let mut bus = bus_with_pattern();
+        cpu.set_reg(1, 0x80001001);
+        cpu.set_reg(2, 0xFFFFFFFF);
+
+        // The LWR result is still in the load delay slot when LWL runs
+        cpu.op_lwr(make_load_inst(0x26, 1, 2, 0), &mut bus).unwrap();
+        assert_eq!(cpu.reg(2), 0xFFFFFFFF);
+        cpu.op_lwl(make_load_inst(0x22, 1, 2, 3), &mut bus).unwrap();
+        flush_load_delay(&mut cpu);
+
+        assert_eq!(cpu.reg(2), 0x55443322);
+    }
+
+    // ========== Load Delay Slot Tests ==========
+
+    /// Run `program` from 0x80010000 with r1 pointing at 0x80001000
+    fn run_program(program: &[u32]) -> CPU {
+        let mut cpu = CPU::new();
+        let mut bus = bus_with_pattern();
+        for (i, &instruction) in program.iter().enumerate() {
+            bus.write32(0x80010000 + i as u32 * 4, instruction).unwrap();
+        }
+        cpu.set_pc(0x80010000);
+        cpu.set_reg(1, 0x80001000);
+        cpu.set_reg(2, 0xAAAA);
+
+        for _ in program {
+            cpu.step(&mut bus).unwrap();
+        }
+        cpu
+    }
+
+    #[test]
+    fn test_delay_slot_reads_old_value() {
+        let cpu = run_program(&[
+            0x8C220000, // lw r2, 0(r1)
+            0x00401821, // addu r3, r2, r0 (delay slot: old r2)
+            0x00402021, // addu r4, r2, r0 (loaded r2)
+        ]);
+
+        assert_eq!(cpu.reg(3), 0xAAAA);
+        assert_eq!(cpu.reg(4), 0x44332211);
+        assert_eq!(cpu.reg(2), 0x44332211);
+    }
+
+    #[test]
+    fn test_delay_slot_write_discards_load() {
+        let cpu = run_program(&[
+            0x8C220000, // lw r2, 0(r1)
+            0x24020005, // addiu r2, r0, 5 (delay slot writes the load target)
+            0x00000000, // nop
+        ]);
+
+        assert_eq!(cpu.reg(2), 5);
+    }
+
+    #[test]
+    fn test_back_to_back_loads_to_same_register() {
+        let cpu = run_program(&[
+            0x8C220000, // lw r2, 0(r1)
+            0x8C220004, // lw r2, 4(r1)
+            0x00401821, // addu r3, r2, r0 (still neither load visible)
+            0x00000000, // nop
+        ]);
+
+        assert_eq!(cpu.reg(3), 0xAAAA);
+        assert_eq!(cpu.reg(2), 0x88776655);
+    }
 }
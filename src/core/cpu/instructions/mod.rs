@@ -18,7 +18,9 @@
 //! This module contains all MIPS R3000A instruction implementations,
 //! organized by instruction type for better maintainability.
 
+use super::cop0::COP0;
 use super::decode::decode_r_type;
+use super::ExceptionCause;
 use super::CPU;
 use crate::core::error::Result;
 use crate::core::memory::Bus;
@@ -73,6 +75,11 @@ impl CPU {
             0x0E => self.op_xori(instruction),      // XORI
             0x0F => self.op_lui(instruction),       // LUI
             0x10 => self.execute_cop0(instruction), // COP0
+            0x11 | 0x13 => {
+                // COP1/COP3 do not exist on the PSX
+                self.coprocessor_unusable(opcode & 0x3);
+                Ok(())
+            }
             0x12 => self.execute_cop2(instruction), // COP2
             0x20 => self.op_lb(instruction, bus),   // LB
             0x21 => self.op_lh(instruction, bus),   // LH
@@ -87,6 +94,13 @@ impl CPU {
             0x2B => self.op_sw(instruction, bus),   // SW
             0x2E => self.op_swr(instruction, bus),  // SWR
             0x2F => self.op_cache(instruction),     // CACHE (treated as NOP)
+            0x32 => self.op_lwc2(instruction, bus), // LWC2
+            0x3A => self.op_swc2(instruction, bus), // SWC2
+            0x30 | 0x31 | 0x33 | 0x38 | 0x39 | 0x3B => {
+                // LWCz/SWCz for the missing coprocessors 0, 1 and 3
+                self.coprocessor_unusable(opcode & 0x3);
+                Ok(())
+            }
             0x3F => {
                 // Invalid opcode 0x3F (all 1s in opcode field)
                 // This typically appears when reading from unpopulated memory (0xFFFFFFFF)
@@ -99,10 +113,11 @@ impl CPU {
             }
             _ => {
                 log::warn!(
-                    "Unimplemented opcode: 0x{:02X} at PC=0x{:08X}",
+                    "Reserved opcode: 0x{:02X} at PC=0x{:08X}",
                     opcode,
-                    self.pc
+                    self.current_pc
                 );
+                self.exception(ExceptionCause::ReservedInstruction);
                 Ok(())
             }
         }
@@ -165,10 +180,11 @@ impl CPU {
             }
             _ => {
                 log::warn!(
-                    "Unimplemented SPECIAL function: 0x{:02X} at PC=0x{:08X}",
+                    "Reserved SPECIAL function: 0x{:02X} at PC=0x{:08X}",
                     funct,
-                    self.pc
+                    self.current_pc
                 );
+                self.exception(ExceptionCause::ReservedInstruction);
                 Ok(())
             }
         }
@@ -256,6 +272,21 @@ impl CPU {
         }
     }
 
+    /// Check that the GTE is enabled through SR.CU2 (bit 30)
+    ///
+    /// Raises a coprocessor unusable exception when it is not.
+    ///
+    /// # Returns
+    ///
+    /// true if the COP2 instruction may execute
+    fn gte_enabled(&mut self) -> bool {
+        if self.cop0.regs[COP0::SR] & (1 << 30) == 0 {
+            self.coprocessor_unusable(2);
+            return false;
+        }
+        true
+    }
+
     /// Handle COP2 instructions (opcode 0x12)
     ///
     /// COP2 instructions are used to interact with Coprocessor 2 (GTE).
@@ -268,6 +299,10 @@ impl CPU {
     ///
     /// Ok(()) on success
     fn execute_cop2(&mut self, instruction: u32) -> Result<()> {
+        if !self.gte_enabled() {
+            return Ok(());
+        }
+
         // COP2 sub-opcode is in bits [25:21]
         let sub_op = (instruction >> 21) & 0x1F;
 
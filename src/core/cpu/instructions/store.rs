@@ -44,7 +44,12 @@ impl CPU {
 
         // Check alignment
         if addr & 0x3 != 0 {
-            self.exception(ExceptionCause::AddressErrorStore);
+            self.address_error(ExceptionCause::AddressErrorStore, addr);
+            return Ok(());
+        }
+
+        if self.cache_isolated() {
+            self.store_isolated(bus, addr, self.reg(rt));
             return Ok(());
         }
 
@@ -75,7 +80,12 @@ impl CPU {
 
         // Check alignment
         if addr & 0x1 != 0 {
-            self.exception(ExceptionCause::AddressErrorStore);
+            self.address_error(ExceptionCause::AddressErrorStore, addr);
+            return Ok(());
+        }
+
+        if self.cache_isolated() {
+            self.store_isolated(bus, addr, self.reg(rt));
             return Ok(());
         }
 
@@ -104,17 +114,44 @@ impl CPU {
         let offset = (imm as i16) as i32; // Sign extend
         let addr = self.reg(rs).wrapping_add(offset as u32);
 
+        if self.cache_isolated() {
+            self.store_isolated(bus, addr, self.reg(rt));
+            return Ok(());
+        }
+
         bus.write8(addr, self.reg(rt) as u8)?;
         Ok(())
     }
 
+    /// Redirect a store to the instruction cache while it is isolated
+    ///
+    /// With COP0 SR.IsC set, stores never reach memory. Partial stores
+    /// (SH/SB) still write a full word, as on hardware.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - Memory bus, used to read the cache control register
+    /// * `addr` - Store address
+    /// * `value` - Value being stored
+    pub(super) fn store_isolated(&mut self, bus: &Bus, addr: u32, value: u32) {
+        self.icache
+            .write_isolated(addr, value, bus.cache_tag_test());
+    }
+
     /// SWL: Store Word Left (unaligned store support)
     ///
-    /// Stub implementation for Phase 1 Week 2.
-    /// Full implementation will be added in Week 3.
+    /// Stores the upper bytes of rt to the bytes from the aligned word base
+    /// up to `addr`. Paired with SWR to store a word to any address.
     ///
     /// Format: swl rt, offset(rs)
     ///
+    /// | addr & 3 | Memory word after the store |
+    /// |----------|-----------------------------|
+    /// | 0 | `(mem & 0xFFFFFF00) \| (rt >> 24)` |
+    /// | 1 | `(mem & 0xFFFF0000) \| (rt >> 16)` |
+    /// | 2 | `(mem & 0xFF000000) \| (rt >> 8)` |
+    /// | 3 | `rt` |
+    ///
     /// # Arguments
     ///
     /// * `instruction` - The full 32-bit instruction
@@ -122,23 +159,40 @@ impl CPU {
     ///
     /// # Returns
     ///
-    /// Ok(()) - stub implementation
-    pub(crate) fn op_swl(&mut self, _instruction: u32, _bus: &mut Bus) -> Result<()> {
-        // TODO: Implement SWL (Week 3)
-        log::warn!(
-            "SWL instruction not yet implemented at PC=0x{:08X}",
-            self.pc
-        );
+    /// Ok(()) on success
+    pub(crate) fn op_swl(&mut self, instruction: u32, bus: &mut Bus) -> Result<()> {
+        let (_, rs, rt, imm) = decode_i_type(instruction);
+        let offset = (imm as i16) as i32; // Sign extend
+        let addr = self.reg(rs).wrapping_add(offset as u32);
+
+        if self.cache_isolated() {
+            self.store_isolated(bus, addr, self.reg(rt));
+            return Ok(());
+        }
+
+        let shift = addr & 0x3;
+        let base = addr & !0x3;
+        let bytes = self.reg(rt).to_le_bytes();
+        for i in 0..=shift {
+            bus.write8(base + i, bytes[(3 - shift + i) as usize])?;
+        }
         Ok(())
     }
 
     /// SWR: Store Word Right (unaligned store support)
     ///
-    /// Stub implementation for Phase 1 Week 2.
-    /// Full implementation will be added in Week 3.
+    /// Stores the lower bytes of rt to the bytes from `addr` up to the end
+    /// of its aligned word.
     ///
     /// Format: swr rt, offset(rs)
     ///
+    /// | addr & 3 | Memory word after the store |
+    /// |----------|-----------------------------|
+    /// | 0 | `rt` |
+    /// | 1 | `(mem & 0x000000FF) \| (rt << 8)` |
+    /// | 2 | `(mem & 0x0000FFFF) \| (rt << 16)` |
+    /// | 3 | `(mem & 0x00FFFFFF) \| (rt << 24)` |
+    ///
     /// # Arguments
     ///
     /// * `instruction` - The full 32-bit instruction
@@ -146,13 +200,168 @@ impl CPU {
     ///
     /// # Returns
     ///
-    /// Ok(()) - stub implementation
-    pub(crate) fn op_swr(&mut self, _instruction: u32, _bus: &mut Bus) -> Result<()> {
-        // TODO: Implement SWR (Week 3)
-        log::warn!(
-            "SWR instruction not yet implemented at PC=0x{:08X}",
-            self.pc
-        );
+    /// Ok(()) on success
+    pub(crate) fn op_swr(&mut self, instruction: u32, bus: &mut Bus) -> Result<()> {
+        let (_, rs, rt, imm) = decode_i_type(instruction);
+        let offset = (imm as i16) as i32; // Sign extend
+        let addr = self.reg(rs).wrapping_add(offset as u32);
+
+        if self.cache_isolated() {
+            self.store_isolated(bus, addr, self.reg(rt));
+            return Ok(());
+        }
+
+        let shift = addr & 0x3;
+        let bytes = self.reg(rt).to_le_bytes();
+        for i in 0..(4 - shift) {
+            bus.write8(addr + i, bytes[i as usize])?;
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::COP0;
+    use super::*;
+
+    // Helper to build an I-type store instruction
+    fn make_store_inst(opcode: u8, rs: u8, rt: u8, imm: u16) -> u32 {
+        ((opcode as u32) << 26) | ((rs as u32) << 21) | ((rt as u32) << 16) | imm as u32
+    }
+
+    // Set or clear COP0 SR.IsC (bit 16)
+    fn set_isolation(cpu: &mut CPU, isolated: bool) {
+        if isolated {
+            cpu.cop0.regs[COP0::SR] |= 1 << 16;
+        } else {
+            cpu.cop0.regs[COP0::SR] &= !(1 << 16);
+        }
+    }
+
+    #[test]
+    fn test_sw_writes_memory_when_not_isolated() {
+        let mut cpu = CPU::new();
+        let mut bus = Bus::new();
+        cpu.set_reg(1, 0x80000100);
+        cpu.set_reg(2, 0xDEADBEEF);
+
+        cpu.op_sw(make_store_inst(0x2B, 1, 2, 0), &mut bus).unwrap();
+
+        assert_eq!(bus.read32(0x80000100).unwrap(), 0xDEADBEEF);
+    }
+
+    #[test]
+    fn test_sw_isolated_does_not_write_memory() {
+        let mut cpu = CPU::new();
+        let mut bus = Bus::new();
+        bus.write32(0x80000100, 0x12345678).unwrap();
+        cpu.set_reg(1, 0x00000100);
+        set_isolation(&mut cpu, true);
+
+        cpu.op_sw(make_store_inst(0x2B, 1, 0, 0), &mut bus).unwrap();
+        cpu.op_sh(make_store_inst(0x29, 1, 0, 0), &mut bus).unwrap();
+        cpu.op_sb(make_store_inst(0x28, 1, 0, 0), &mut bus).unwrap();
+
+        assert_eq!(bus.read32(0x80000100).unwrap(), 0x12345678);
+    }
+
+    #[test]
+    fn test_sw_isolated_tag_test_invalidates_line() {
+        let mut cpu = CPU::new();
+        let mut bus = Bus::new();
+        cpu.prefill_icache(0x80000100, 0x3C080000);
+        cpu.prefill_icache(0x80000104, 0x24080001);
+
+        // Tag test mode, as used by the BIOS cache flush
+        bus.write32(0xFFFE0130, 0x0000_0804).unwrap();
+        cpu.set_reg(1, 0x00000100);
+        set_isolation(&mut cpu, true);
+
+        cpu.op_sw(make_store_inst(0x2B, 1, 0, 0), &mut bus).unwrap();
+
+        assert_eq!(cpu.icache.fetch(0x80000100), None);
+        assert_eq!(cpu.icache.fetch(0x80000104), None);
+    }
+
+    #[test]
+    fn test_sw_isolated_writes_cache_data() {
+        let mut cpu = CPU::new();
+        let mut bus = Bus::new();
+        cpu.prefill_icache(0x80000200, 0x00000000);
+
+        // Data mode (tag test off): the store replaces the cached word
+        bus.write32(0xFFFE0130, 0x0000_0800).unwrap();
+        cpu.set_reg(1, 0x80000200);
+        cpu.set_reg(2, 0x24080001);
+        set_isolation(&mut cpu, true);
+
+        cpu.op_sw(make_store_inst(0x2B, 1, 2, 0), &mut bus).unwrap();
+
+        assert_eq!(cpu.icache.fetch(0x80000200), Some(0x24080001));
+        assert_eq!(bus.read32(0x80000200).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_swl_each_alignment() {
+        let expected = [0x887766DD, 0x8877DDCC, 0x88DDCCBB, 0xDDCCBBAA];
+
+        for (shift, &value) in expected.iter().enumerate() {
+            let mut cpu = CPU::new();
+            let mut bus = Bus::new();
+            bus.write32(0x80001000, 0x88776655).unwrap();
+            cpu.set_reg(1, 0x80001000 + shift as u32);
+            cpu.set_reg(2, 0xDDCCBBAA);
+
+            cpu.op_swl(make_store_inst(0x2A, 1, 2, 0), &mut bus)
+                .unwrap();
+
+            assert_eq!(
+                bus.read32(0x80001000).unwrap(),
+                value,
+                "SWL with addr & 3 = {}",
+                shift
+            );
+        }
+    }
+
+    #[test]
+    fn test_swr_each_alignment() {
+        let expected = [0xDDCCBBAA, 0xCCBBAA55, 0xBBAA6655, 0xAA776655];
+
+        for (shift, &value) in expected.iter().enumerate() {
+            let mut cpu = CPU::new();
+            let mut bus = Bus::new();
+            bus.write32(0x80001000, 0x88776655).unwrap();
+            cpu.set_reg(1, 0x80001000 + shift as u32);
+            cpu.set_reg(2, 0xDDCCBBAA);
+
+            cpu.op_swr(make_store_inst(0x2E, 1, 2, 0), &mut bus)
+                .unwrap();
+
+            assert_eq!(
+                bus.read32(0x80001000).unwrap(),
+                value,
+                "SWR with addr & 3 = {}",
+                shift
+            );
+        }
+    }
+
+    #[test]
+    fn test_swl_swr_pair_stores_across_word_boundary() {
+        let mut cpu = CPU::new();
+        let mut bus = Bus::new();
+        cpu.set_reg(1, 0x80001002);
+        cpu.set_reg(2, 0xDDCCBBAA);
+
+        // swr r2, 0(r1) ; swl r2, 3(r1) stores the word at 0x80001002
+        cpu.op_swr(make_store_inst(0x2E, 1, 2, 0), &mut bus)
+            .unwrap();
+        cpu.op_swl(make_store_inst(0x2A, 1, 2, 3), &mut bus)
+            .unwrap();
+
+        assert_eq!(bus.read32(0x80001000).unwrap(), 0xBBAA0000);
+        assert_eq!(bus.read32(0x80001004).unwrap(), 0x0000DDCC);
+    }
+}
@@ -13,10 +13,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::core::error::Result;
+use crate::core::error::{EmulatorError, Result};
 use crate::core::gte::GTE;
 use crate::core::memory::Bus;
-use crate::core::timing::TimingEventManager;
+use crate::core::save_state::{CPUState, StateSave};
 
 /// CPU (MIPS R3000A) emulation implementation
 ///
@@ -62,17 +62,73 @@ pub struct CPU {
     /// On PSX, load instruction results cannot be used in the next instruction
     load_delay: Option<LoadDelay>,
 
+    /// Load issued by the previous instruction
+    ///
+    /// Committed once the current instruction completes, unless the current
+    /// instruction writes the same register itself.
+    pending_load: Option<LoadDelay>,
+
     /// Branch delay slot flag
+    ///
+    /// Set by a branch or jump; the next instruction executes in its delay slot.
     in_branch_delay: bool,
 
+    /// Whether the instruction being executed sits in a branch delay slot
+    delay_slot: bool,
+
+    /// Address of the instruction being executed (reported in EPC)
+    current_pc: u32,
+
     /// Current instruction (for debugging)
     current_instruction: u32,
 
+    /// Extra cycles consumed by the last instruction
+    ///
+    /// Set by instructions that take longer than one cycle (e.g., GTE
+    /// commands, memory reads) and drained after each instruction.
+    stall_cycles: u32,
+
+    /// How instruction cycles are counted
+    clock_mode: ClockMode,
+
     /// Instruction cache
     ///
-    /// Caches instructions when COP0 SR.IsC bit (bit 16) is set.
-    /// Essential for BIOS operation which isolates cache before zeroing RAM.
+    /// 4KB direct-mapped cache. While COP0 SR.IsC (bit 16) is set, stores
+    /// are redirected here instead of memory, which the BIOS relies on to
+    /// flush the cache and size RAM.
     icache: InstructionCache,
+
+    /// In-memory trace of recently executed instructions (None when disabled)
+    trace_buffer: Option<TraceBuffer>,
+
+    /// BIOS call hooks (None when disabled)
+    bios_hle: Option<BiosHle>,
+}
+
+/// CPU cycle accounting mode
+///
+/// # Tradeoff
+///
+/// [`ClockMode::Accurate`] charges each instruction for the bus latency of
+/// its instruction fetch (on an instruction cache miss) and data reads, plus
+/// the GTE command time. Games that busy-wait on hardware or rely on code
+/// running at roughly the real speed behave closer to a console.
+///
+/// [`ClockMode::Fast`] counts every instruction as exactly one cycle. More
+/// instructions fit in each emulated frame, so CPU-heavy games run with
+/// less slowdown, but timing-sensitive code may run too fast or break.
+/// The host-side cost of emulating an instruction is the same in both
+/// modes.
+///
+/// Stores are not charged in either mode, since the write queue hides
+/// them on hardware.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClockMode {
+    /// Memory access delays and GTE stalls are added to the cycle count
+    #[default]
+    Accurate,
+    /// Every instruction takes a fixed single cycle
+    Fast,
 }
 
 /// Load delay management structure
@@ -89,18 +145,23 @@ pub struct LoadDelay {
 }
 
 // Module declarations
+pub mod bios_hle;
 mod cop0;
 mod decode;
 mod disassembler;
 pub mod icache;
 mod instructions;
+mod trace_buffer;
 mod tracer;
 
 // Re-exports
+pub use bios_hle::BiosHle;
+use bios_hle::BiosVector;
 pub use cop0::ExceptionCause;
 use cop0::COP0;
 pub use disassembler::Disassembler;
 pub use icache::InstructionCache;
+pub use trace_buffer::{TraceBuffer, TraceEntry};
 pub use tracer::CpuTracer;
 
 impl CPU {
@@ -133,9 +194,16 @@ impl CPU {
             cop0: COP0::new(),
             gte: GTE::new(),
             load_delay: None,
+            pending_load: None,
             in_branch_delay: false,
+            delay_slot: false,
+            current_pc: 0xBFC00000,
             current_instruction: 0,
+            stall_cycles: 0,
+            clock_mode: ClockMode::default(),
             icache: InstructionCache::new(),
+            trace_buffer: None,
+            bios_hle: None,
         }
     }
 
@@ -161,8 +229,12 @@ impl CPU {
         self.cop0.reset();
         self.gte.reset();
         self.load_delay = None;
+        self.pending_load = None;
         self.in_branch_delay = false;
+        self.delay_slot = false;
+        self.current_pc = 0xBFC00000;
         self.current_instruction = 0;
+        self.stall_cycles = 0;
         self.icache.clear();
     }
 
@@ -201,7 +273,8 @@ impl CPU {
     /// - `value`: Value to write
     ///
     /// # Note
-    /// Writes to r0 are ignored (r0 is always 0).
+    /// Writes to r0 are ignored (r0 is always 0). A load still in its delay
+    /// slot for the same register is discarded, as on hardware.
     ///
     /// # Example
     /// ```
@@ -219,6 +292,9 @@ impl CPU {
     pub fn set_reg(&mut self, index: u8, value: u32) {
         if index != 0 {
             self.regs[index as usize] = value;
+            if self.pending_load.is_some_and(|load| load.reg == index) {
+                self.pending_load = None;
+            }
         }
     }
 
@@ -298,7 +374,8 @@ impl CPU {
     ///
     /// # Behavior
     /// 1. Execute current load delay if present
-    /// 2. Set new load delay
+    /// 2. Discard an older load to the same register still in flight
+    /// 3. Set new load delay
     ///
     /// # Arguments
     /// - `index`: Target register number (0-31)
@@ -325,6 +402,9 @@ impl CPU {
 
         // Set new load delay
         if index != 0 {
+            if self.pending_load.is_some_and(|load| load.reg == index) {
+                self.pending_load = None;
+            }
             self.load_delay = Some(LoadDelay { reg: index, value });
         }
     }
@@ -343,7 +423,8 @@ impl CPU {
     ///
     /// # Returns
     ///
-    /// Number of cycles consumed (currently always 1)
+    /// Number of cycles consumed (1, plus any memory and GTE stalls in
+    /// [`ClockMode::Accurate`])
     ///
     /// # Example
     ///
@@ -364,147 +445,352 @@ impl CPU {
             self.handle_interrupt();
         }
 
-        // The instruction fetched below will execute now. If we were in a delay slot,
-        // clear the flag; any branch/jump executed in this step will set it again.
-        let _was_in_delay = self.in_branch_delay;
-        self.in_branch_delay = false;
-        // Resolve load delay from previous instruction
-        if let Some(delay) = self.load_delay.take() {
-            self.set_reg(delay.reg, delay.value);
-        }
+        // The instruction fetched below runs in the delay slot if the previous
+        // one was a branch; any branch/jump executed in this step sets the flag again.
+        self.delay_slot = std::mem::take(&mut self.in_branch_delay);
+        // The load from the previous instruction lands after this one
+        self.pending_load = self.load_delay.take();
 
-        // Instruction fetch with cache support
+        self.fetch_and_execute(bus)?;
+        self.commit_pending_load();
+
+        // Base cost is 1 cycle; add any stall from multi-cycle operations
+        Ok(1 + self.take_stall_cycles())
+    }
+
+    /// Fetch, advance the PC and execute one instruction
+    ///
+    /// Records the instruction in the trace buffer when tracing is enabled
+    /// and its address passes the filters. Unaligned or unmapped accesses
+    /// reported by the bus are turned into CPU exceptions.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - Memory bus for instruction fetch and data access
+    fn fetch_and_execute(&mut self, bus: &mut Bus) -> Result<()> {
         let pc = self.pc;
+        self.current_pc = pc;
 
-        // SIMPLIFIED INSTRUCTION CACHE: Always use cache when available
-        // This solves the BIOS initialization issue where RAM is zeroed
-        // while code is executing. Cache entries are never overwritten.
-        let instruction = if let Some(cached_instr) = self.icache.fetch(pc) {
-            // Cache hit - use cached instruction
-            cached_instr
-        } else {
-            // Cache miss - read from RAM and cache it
-            let instr = bus.read32(pc)?;
-            self.icache.store(pc, instr);
-            instr
-        };
+        if let Some(hle) = self.bios_hle.as_mut() {
+            if let Some(vector) = BiosVector::from_pc(pc) {
+                hle.on_call(vector, &self.regs, bus);
+            }
+        }
 
-        self.current_instruction = instruction;
+        // Instruction fetch with cache support
+        self.current_instruction = match self.fetch_instruction(bus) {
+            Ok(instruction) => instruction,
+            Err(error) => {
+                return self.memory_exception(
+                    error,
+                    ExceptionCause::AddressErrorLoad,
+                    ExceptionCause::BusErrorInstruction,
+                );
+            }
+        };
 
         // Update PC (delay slot handling)
         self.pc = self.next_pc;
         self.next_pc = self.next_pc.wrapping_add(4);
 
-        // Execute instruction
-        self.execute_instruction(bus)?;
+        let result = if !self.trace_buffer.as_ref().is_some_and(|t| t.matches(pc)) {
+            self.execute_instruction(bus)
+        } else {
+            let before = self.regs;
+            let result = self.execute_instruction(bus);
+            let entry = TraceEntry::new(pc, self.current_instruction, &before, &self.regs);
+            if let Some(trace) = self.trace_buffer.as_mut() {
+                trace.push(entry);
+            }
+            result
+        };
+
+        match result {
+            Err(error) if Self::is_store(self.current_instruction) => self.memory_exception(
+                error,
+                ExceptionCause::AddressErrorStore,
+                ExceptionCause::BusErrorData,
+            ),
+            Err(error) => self.memory_exception(
+                error,
+                ExceptionCause::AddressErrorLoad,
+                ExceptionCause::BusErrorData,
+            ),
+            Ok(()) => Ok(()),
+        }
+    }
+
+    /// Write the previous instruction's load to its target register
+    ///
+    /// Called after the instruction in the load delay slot has executed, so
+    /// that it still read the old register value.
+    #[inline(always)]
+    fn commit_pending_load(&mut self) {
+        if let Some(load) = self.pending_load.take() {
+            self.regs[load.reg as usize] = load.value;
+        }
+    }
 
-        // For now, all instructions take 1 cycle
-        Ok(1)
+    /// Check whether an instruction writes to memory
+    ///
+    /// # Arguments
+    ///
+    /// * `instruction` - The full 32-bit instruction
+    ///
+    /// # Returns
+    ///
+    /// true for SB/SH/SWL/SW/SWR and SWCz
+    fn is_store(instruction: u32) -> bool {
+        matches!(instruction >> 26, 0x28..=0x2E | 0x38..=0x3B)
     }
 
-    /// Execute instructions in a loop with timing event integration
+    /// Raise the exception matching a failed memory access
     ///
-    /// This is the main CPU execution loop for event-driven timing.
-    /// Executes instructions until the timing system signals to exit
-    /// (e.g., frame complete).
+    /// Unaligned accesses become address errors with the faulting address in
+    /// BadVAddr; accesses to unmapped memory become bus errors. Any other
+    /// error is returned unchanged.
     ///
-    /// # Execution Model
+    /// # Arguments
     ///
-    /// Based on DuckStation's architecture:
-    /// 1. Check if `pending_ticks >= downcount`
-    /// 2. If yes, run timing events
-    /// 3. Execute one instruction
-    /// 4. Increment `pending_ticks`
-    /// 5. Check for interrupts → set `downcount = 0` for immediate event processing
-    /// 6. Repeat until `should_exit_loop()` returns true
+    /// * `error` - Error reported by the bus
+    /// * `address_error` - AdEL or AdES, depending on the access
+    /// * `bus_error` - IBE for instruction fetches, DBE for data accesses
+    fn memory_exception(
+        &mut self,
+        error: EmulatorError,
+        address_error: ExceptionCause,
+        bus_error: ExceptionCause,
+    ) -> Result<()> {
+        match error {
+            EmulatorError::UnalignedAccess { address, .. } => {
+                self.address_error(address_error, address);
+                Ok(())
+            }
+            EmulatorError::InvalidMemoryAccess { .. } => {
+                self.exception(bus_error);
+                Ok(())
+            }
+            error => Err(error),
+        }
+    }
+
+    /// Start recording executed instructions in memory
+    ///
+    /// Replaces any existing trace buffer.
     ///
     /// # Arguments
     ///
-    /// * `bus` - Memory bus for reading instructions and data
-    /// * `timing` - Timing event manager
+    /// * `capacity` - Number of most recent instructions to keep
+    ///
+    /// # Example
+    /// ```
+    /// use psrx::core::cpu::CPU;
+    ///
+    /// let mut cpu = CPU::new();
+    /// cpu.enable_trace_buffer(4096);
+    /// assert!(cpu.recent_trace().is_empty());
+    /// ```
+    pub fn enable_trace_buffer(&mut self, capacity: usize) {
+        self.trace_buffer = Some(TraceBuffer::new(capacity));
+    }
+
+    /// Stop recording executed instructions and drop the buffer
+    pub fn disable_trace_buffer(&mut self) {
+        self.trace_buffer = None;
+    }
+
+    /// Get the trace buffer for configuring filters
     ///
     /// # Returns
     ///
-    /// Ok(()) when execution completes normally
+    /// Mutable trace buffer, or None if tracing is disabled
+    pub fn trace_buffer_mut(&mut self) -> Option<&mut TraceBuffer> {
+        self.trace_buffer.as_mut()
+    }
+
+    /// Get the most recently executed instructions
     ///
-    /// # Example
+    /// # Returns
     ///
-    /// ```no_run
+    /// Trace entries, oldest first (empty when tracing is disabled)
+    pub fn recent_trace(&self) -> &[TraceEntry] {
+        self.trace_buffer.as_ref().map_or(&[], |t| t.entries())
+    }
+
+    /// Enable the BIOS call hooks
+    ///
+    /// Calls through the 0xA0/0xB0/0xC0 vectors are logged and TTY output
+    /// is collected. The BIOS still executes every call.
+    ///
+    /// # Example
+    /// ```
     /// use psrx::core::cpu::CPU;
-    /// use psrx::core::memory::Bus;
-    /// use psrx::core::timing::TimingEventManager;
     ///
     /// let mut cpu = CPU::new();
-    /// let mut bus = Bus::new();
-    /// let mut timing = TimingEventManager::new();
+    /// cpu.enable_bios_hle();
+    /// assert_eq!(cpu.take_tty_output(), "");
+    /// ```
+    pub fn enable_bios_hle(&mut self) {
+        if self.bios_hle.is_none() {
+            self.bios_hle = Some(BiosHle::new());
+        }
+    }
+
+    /// Disable the BIOS call hooks and drop any unread TTY output
+    pub fn disable_bios_hle(&mut self) {
+        self.bios_hle = None;
+    }
+
+    /// Set how instruction cycles are counted
+    ///
+    /// See [`ClockMode`] for the speed/accuracy tradeoff.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Cycle accounting mode
     ///
-    /// // Set frame target (e.g., 564,480 cycles for one frame at 60 Hz)
-    /// timing.set_frame_target(564_480);
+    /// # Example
     ///
-    /// // Execute until frame complete
-    /// cpu.execute(&mut bus, &mut timing).unwrap();
     /// ```
-    pub fn execute(&mut self, bus: &mut Bus, timing: &mut TimingEventManager) -> Result<()> {
-        loop {
-            // Check if timing events need to run
-            if timing.pending_ticks >= timing.downcount {
-                // Run all pending timing events
-                timing.run_events();
-
-                // Check if we should exit (e.g., frame complete)
-                if timing.should_exit_loop() {
-                    break;
-                }
-            }
+    /// use psrx::core::cpu::{ClockMode, CPU};
+    ///
+    /// let mut cpu = CPU::new();
+    /// cpu.set_clock_mode(ClockMode::Fast);
+    /// assert_eq!(cpu.clock_mode(), ClockMode::Fast);
+    /// ```
+    pub fn set_clock_mode(&mut self, mode: ClockMode) {
+        self.clock_mode = mode;
+    }
 
-            // Increment pending ticks for this CPU cycle
-            timing.pending_ticks += 1;
+    /// Get the cycle accounting mode
+    ///
+    /// # Returns
+    ///
+    /// The current [`ClockMode`]
+    pub fn clock_mode(&self) -> ClockMode {
+        self.clock_mode
+    }
 
-            // Check for interrupts before fetching instruction
-            if self.should_handle_interrupt(bus) {
-                self.handle_interrupt();
-                // Force immediate event processing for interrupt handling
-                timing.downcount = 0;
-            }
+    /// Drain the stall accumulated by the last instruction
+    ///
+    /// # Returns
+    ///
+    /// Extra cycles to charge, always 0 in [`ClockMode::Fast`]
+    #[inline(always)]
+    fn take_stall_cycles(&mut self) -> u32 {
+        let stall = std::mem::take(&mut self.stall_cycles);
+        match self.clock_mode {
+            ClockMode::Accurate => stall,
+            ClockMode::Fast => 0,
+        }
+    }
 
-            // The instruction fetched below will execute now. If we were in a delay slot,
-            // clear the flag; any branch/jump executed in this step will set it again.
-            self.in_branch_delay = false;
+    /// Set the GTE horizontal projection scale (widescreen hack)
+    ///
+    /// See [`GTE::set_widescreen_scale`].
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - Factor applied to projected screen X (1.0 disables it)
+    pub fn set_widescreen_scale(&mut self, scale: f32) {
+        self.gte.set_widescreen_scale(scale);
+    }
 
-            // Resolve load delay from previous instruction
-            if let Some(delay) = self.load_delay.take() {
-                self.set_reg(delay.reg, delay.value);
-            }
+    /// Take the TTY output written through the BIOS since the last call
+    ///
+    /// # Returns
+    ///
+    /// Console text (empty when the hooks are disabled)
+    pub fn take_tty_output(&mut self) -> String {
+        self.bios_hle
+            .as_mut()
+            .map(BiosHle::take_tty_output)
+            .unwrap_or_default()
+    }
 
-            // Instruction fetch with cache support
-            let pc = self.pc;
-
-            // SIMPLIFIED INSTRUCTION CACHE: Always use cache when available
-            // This solves the BIOS initialization issue where RAM is zeroed
-            // while code is executing. Cache entries are never overwritten.
-            let instruction = if let Some(cached_instr) = self.icache.fetch(pc) {
-                // Cache hit - use cached instruction
-                cached_instr
-            } else {
-                // Cache miss - read from RAM and cache it
-                let instr = bus.read32(pc)?;
-                self.icache.store(pc, instr);
-                instr
-            };
-
-            self.current_instruction = instruction;
-
-            // Update PC (delay slot handling)
-            self.pc = self.next_pc;
-            self.next_pc = self.next_pc.wrapping_add(4);
-
-            // Execute instruction
-            self.execute_instruction(bus)?;
+    /// Fetch the instruction at the current PC through the instruction cache
+    ///
+    /// On a miss the word is read from the bus and stored in the cache.
+    /// Fetches are never affected by cache isolation, only stores are.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - Memory bus for reading the instruction on a cache miss
+    ///
+    /// # Returns
+    ///
+    /// The 32-bit instruction word
+    fn fetch_instruction(&mut self, bus: &mut Bus) -> Result<u32> {
+        let pc = self.pc;
+
+        if let Some(instruction) = self.icache.fetch(pc) {
+            return Ok(instruction);
         }
 
-        Ok(())
+        self.stall_cycles += bus.access_latency(pc);
+        let instruction = bus.read32(pc)?;
+        self.icache.store(pc, instruction);
+        Ok(instruction)
     }
 
+    /// Check whether the cache is isolated from memory (COP0 SR.IsC, bit 16)
+    ///
+    /// # Returns
+    ///
+    /// `true` if stores should go to the instruction cache instead of memory
+    #[inline(always)]
+    pub(crate) fn cache_isolated(&self) -> bool {
+        self.cop0.regs[COP0::SR] & (1 << 16) != 0
+    }
+
+    /// Select the general exception vector from COP0 SR.BEV (bit 22)
+    ///
+    /// While BEV is set (as after reset) handlers run from the BIOS ROM
+    /// through uncached KSEG1. Once the BIOS clears BEV, exceptions go to
+    /// the handler installed in RAM through cached KSEG0.
+    ///
+    /// # Returns
+    ///
+    /// 0xBFC00180 if BEV is set, 0x80000080 otherwise
+    pub(crate) fn exception_vector(&self) -> u32 {
+        if self.cop0.regs[COP0::SR] & (1 << 22) != 0 {
+            0xBFC00180 // BEV=1: Bootstrap exception vector
+        } else {
+            0x80000080 // BEV=0: Normal exception vector
+        }
+    }
+
+    /// Raise an address error exception (AdEL/AdES)
+    ///
+    /// # Arguments
+    ///
+    /// * `cause` - AddressErrorLoad or AddressErrorStore
+    /// * `address` - Faulting address, stored in BadVAddr
+    pub(crate) fn address_error(&mut self, cause: ExceptionCause, address: u32) {
+        self.cop0.regs[COP0::BADA] = address;
+        self.exception(cause);
+    }
+
+    /// Raise a coprocessor unusable exception
+    ///
+    /// # Arguments
+    ///
+    /// * `coprocessor` - Coprocessor number, stored in CAUSE.CE (bits 29:28)
+    pub(crate) fn coprocessor_unusable(&mut self, coprocessor: u32) {
+        let cause = self.cop0.regs[COP0::CAUSE];
+        self.cop0.regs[COP0::CAUSE] = (cause & !(0x3 << 28)) | ((coprocessor & 0x3) << 28);
+        self.exception(ExceptionCause::CoprocessorUnusable);
+    }
+
+    /// Enter the exception handler
+    ///
+    /// Pushes the KU/IE mode stack in SR, records the exception code and
+    /// BD flag in CAUSE and stores the address of the faulting instruction
+    /// (or of the branch, for a delay slot) in EPC.
+    ///
+    /// # Arguments
+    ///
+    /// * `cause` - Exception code written to CAUSE.ExcCode
     pub fn exception(&mut self, cause: ExceptionCause) {
         // Save current status (push exception level)
         let sr = self.cop0.regs[COP0::SR];
@@ -519,28 +805,22 @@ impl CPU {
         self.cop0.regs[COP0::CAUSE] = (cause_reg & !0x7C) | ((cause as u32) << 2);
 
         // Save exception PC
-        // self.pc currently points to (faulting_pc + 4). Adjust accordingly.
-        let current_pc = self.pc.wrapping_sub(4);
-        let epc = if self.in_branch_delay {
-            current_pc.wrapping_sub(4) // branch instruction address
+        let epc = if self.delay_slot {
+            self.current_pc.wrapping_sub(4) // branch instruction address
         } else {
-            current_pc // faulting instruction address
+            self.current_pc // faulting instruction address
         };
         self.cop0.regs[COP0::EPC] = epc;
 
         // Set branch delay flag in CAUSE if in delay slot
-        if self.in_branch_delay {
+        if self.delay_slot {
             self.cop0.regs[COP0::CAUSE] |= 1 << 31;
         } else {
             self.cop0.regs[COP0::CAUSE] &= !(1 << 31);
         }
 
         // Jump to exception handler
-        let handler = if (sr & (1 << 22)) != 0 {
-            0xBFC00180 // BEV=1: Bootstrap exception vector
-        } else {
-            0x80000080 // BEV=0: Normal exception vector
-        };
+        let handler = self.exception_vector();
 
         // Log exception details
         log::warn!(
@@ -548,13 +828,14 @@ impl CPU {
             cause,
             epc,
             handler,
-            self.in_branch_delay,
+            self.delay_slot,
             self.current_instruction
         );
 
         self.pc = handler;
         self.next_pc = handler.wrapping_add(4);
         self.in_branch_delay = false;
+        self.delay_slot = false;
         self.load_delay = None;
     }
 
@@ -655,7 +936,7 @@ impl CPU {
         if ie != 0 {
             let masked = pending_all & im;
             if masked != 0 {
-                self.exception(ExceptionCause::Interrupt);
+                self.handle_interrupt();
             }
         }
     }
@@ -700,17 +981,39 @@ impl CPU {
         }
 
         // Bit 10 (0x0400) in IM controls external interrupts
-        irq_pending && (im & 0x04) != 0
+        if !irq_pending || (im & 0x04) == 0 {
+            return false;
+        }
+
+        // The hardware still executes a GTE command that an interrupt lands
+        // on, and the BIOS handler skips it when returning. Take the
+        // interrupt one instruction later so the command is not lost.
+        // Peek without side effects: this is not a real fetch, so it must not
+        // fire watchpoints or show up in the I/O log
+        let next = self
+            .icache
+            .fetch(self.pc)
+            .or_else(|| bus.peek32(self.pc))
+            .unwrap_or(0);
+        next >> 25 != 0x25
     }
 
     /// Handle an interrupt
     ///
     /// Triggers an interrupt exception. This will:
-    /// - Save the current PC to EPC
+    /// - Save the PC of the next (not yet executed) instruction to EPC
     /// - Update the Status Register (disable interrupts, enter kernel mode)
     /// - Jump to the interrupt handler
     fn handle_interrupt(&mut self) {
         log::debug!("Handling interrupt at PC=0x{:08X}", self.pc);
+        // Interrupts are taken between instructions: the instruction at PC
+        // has not run yet and is in a delay slot if the last one branched.
+        // A load issued by the last instruction still completes.
+        if let Some(load) = self.load_delay.take() {
+            self.set_reg(load.reg, load.value);
+        }
+        self.current_pc = self.pc;
+        self.delay_slot = self.in_branch_delay;
         self.exception(ExceptionCause::Interrupt);
     }
 
@@ -759,3 +1062,48 @@ impl Default for CPU {
         Self::new()
     }
 }
+
+impl StateSave for CPU {
+    type State = CPUState;
+
+    fn to_state(&self) -> CPUState {
+        let mut state = CPUState {
+            regs: self.regs,
+            pc: self.pc,
+            next_pc: self.next_pc,
+            hi: self.hi,
+            lo: self.lo,
+            cop0_regs: self.cop0.regs,
+            load_delay: self.load_delay.map(|delay| (delay.reg, delay.value)),
+            in_branch_delay: self.in_branch_delay,
+            current_instruction: self.current_instruction,
+            icache_lines: self.icache.save_lines(),
+            ..Default::default()
+        };
+        self.gte.save_registers(&mut state);
+        state
+    }
+
+    /// Restore the CPU from a saved state
+    ///
+    /// The instruction cache is restored too, so cache misses (and their
+    /// cost in [`ClockMode::Accurate`]) replay exactly as before the save.
+    fn restore_from_state(&mut self, state: &CPUState) {
+        self.regs = state.regs;
+        self.regs[0] = 0;
+        self.pc = state.pc;
+        self.next_pc = state.next_pc;
+        self.hi = state.hi;
+        self.lo = state.lo;
+        self.cop0.regs = state.cop0_regs;
+        self.gte.restore_registers(state);
+        self.load_delay = state
+            .load_delay
+            .map(|(reg, value)| LoadDelay { reg, value });
+        self.pending_load = None;
+        self.in_branch_delay = state.in_branch_delay;
+        self.current_instruction = state.current_instruction;
+        self.stall_cycles = 0;
+        self.icache.restore_lines(&state.icache_lines);
+    }
+}
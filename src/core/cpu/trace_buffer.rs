@@ -0,0 +1,236 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! In-memory execution trace
+//!
+//! Keeps the last N executed instructions together with the registers they
+//! changed. Unlike [`CpuTracer`](super::CpuTracer), nothing is written to
+//! disk, so the buffer can stay enabled while the emulator runs at full speed
+//! and be inspected after a hang or crash.
+
+use std::ops::Range;
+
+use super::Disassembler;
+
+/// One executed instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    /// Address of the instruction
+    pub pc: u32,
+
+    /// Raw instruction word
+    pub instruction: u32,
+
+    /// General purpose registers written by the instruction (index, new value)
+    pub changes: Vec<(u8, u32)>,
+}
+
+impl TraceEntry {
+    /// Build an entry by comparing the registers before and after execution
+    ///
+    /// # Arguments
+    ///
+    /// * `pc` - Address of the instruction
+    /// * `instruction` - Raw instruction word
+    /// * `before` - Registers before execution
+    /// * `after` - Registers after execution
+    pub(crate) fn new(pc: u32, instruction: u32, before: &[u32; 32], after: &[u32; 32]) -> Self {
+        let changes = before
+            .iter()
+            .zip(after)
+            .enumerate()
+            .filter(|(_, (old, new))| old != new)
+            .map(|(index, (_, &new))| (index as u8, new))
+            .collect();
+
+        Self {
+            pc,
+            instruction,
+            changes,
+        }
+    }
+
+    /// Disassemble the traced instruction
+    ///
+    /// # Returns
+    ///
+    /// Assembly text with branch targets resolved against `pc`
+    pub fn disassemble(&self) -> String {
+        Disassembler::disassemble(self.instruction, self.pc)
+    }
+}
+
+/// Ring buffer of recently executed instructions
+///
+/// Entries are stored in a vector of up to twice the capacity; when it
+/// fills up the oldest half is dropped. This keeps the most recent
+/// `capacity` entries contiguous so they can be returned as a slice.
+///
+/// The entry type defaults to [`TraceEntry`]; other debug logs that record
+/// by address (such as the bus I/O log) reuse the buffer with their own
+/// entry type.
+///
+/// # Example
+///
+/// ```
+/// use psrx::core::cpu::TraceBuffer;
+///
+/// let mut buffer: TraceBuffer = TraceBuffer::new(1024);
+/// buffer.add_filter(0x80010000..0x80020000);
+/// assert!(buffer.matches(0x80010010));
+/// assert!(!buffer.matches(0xBFC00000));
+/// ```
+#[derive(Debug)]
+pub struct TraceBuffer<T = TraceEntry> {
+    /// Stored entries, oldest first
+    entries: Vec<T>,
+
+    /// Number of entries returned by [`TraceBuffer::entries`]
+    capacity: usize,
+
+    /// Address ranges to record (all addresses when empty)
+    filters: Vec<Range<u32>>,
+}
+
+impl<T> TraceBuffer<T> {
+    /// Create an empty trace buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of entries to keep (at least 1)
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            entries: Vec::with_capacity(capacity * 2),
+            capacity,
+            filters: Vec::new(),
+        }
+    }
+
+    /// Only record entries whose address is in `range`
+    ///
+    /// Multiple filters may be added; an entry is recorded if it matches
+    /// any of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Half-open address range
+    pub fn add_filter(&mut self, range: Range<u32>) {
+        self.filters.push(range);
+    }
+
+    /// Remove all address filters (record everything)
+    pub fn clear_filters(&mut self) {
+        self.filters.clear();
+    }
+
+    /// Check whether an address passes the filters
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Instruction or register address
+    ///
+    /// # Returns
+    ///
+    /// true if the entry should be recorded
+    #[inline]
+    pub fn matches(&self, address: u32) -> bool {
+        self.filters.is_empty() || self.filters.iter().any(|r| r.contains(&address))
+    }
+
+    /// Record an entry
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - Entry to append
+    pub fn push(&mut self, entry: T) {
+        if self.entries.len() == self.capacity * 2 {
+            self.entries.drain(..self.capacity);
+        }
+        self.entries.push(entry);
+    }
+
+    /// Get the most recent entries
+    ///
+    /// # Returns
+    ///
+    /// Up to `capacity` entries, oldest first
+    pub fn entries(&self) -> &[T] {
+        let start = self.entries.len().saturating_sub(self.capacity);
+        &self.entries[start..]
+    }
+
+    /// Discard all recorded entries
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(pc: u32) -> TraceEntry {
+        TraceEntry::new(pc, 0, &[0; 32], &[0; 32])
+    }
+
+    #[test]
+    fn test_entry_records_changed_registers() {
+        let before = [0u32; 32];
+        let mut after = before;
+        after[8] = 0x1234;
+        after[31] = 0x80010008;
+
+        let entry = TraceEntry::new(0x80010000, 0x0C004000, &before, &after);
+
+        assert_eq!(entry.changes, vec![(8, 0x1234), (31, 0x80010008)]);
+    }
+
+    #[test]
+    fn test_buffer_keeps_most_recent_entries() {
+        let mut buffer = TraceBuffer::new(3);
+        for pc in 0..10 {
+            buffer.push(entry(pc * 4));
+        }
+
+        let pcs: Vec<u32> = buffer.entries().iter().map(|e| e.pc).collect();
+        assert_eq!(pcs, vec![28, 32, 36]);
+    }
+
+    #[test]
+    fn test_buffer_partial_fill() {
+        let mut buffer = TraceBuffer::new(8);
+        buffer.push(entry(4));
+
+        assert_eq!(buffer.entries().len(), 1);
+        buffer.clear();
+        assert!(buffer.entries().is_empty());
+    }
+
+    #[test]
+    fn test_filters_match_any_range() {
+        let mut buffer: TraceBuffer = TraceBuffer::new(4);
+        assert!(buffer.matches(0xBFC00000), "no filter records everything");
+
+        buffer.add_filter(0x1000..0x2000);
+        buffer.add_filter(0x8000..0x8004);
+        assert!(buffer.matches(0x1FFC));
+        assert!(buffer.matches(0x8000));
+        assert!(!buffer.matches(0x2000));
+
+        buffer.clear_filters();
+        assert!(buffer.matches(0x2000));
+    }
+}
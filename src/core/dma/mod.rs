@@ -57,6 +57,9 @@
 
 use crate::core::cdrom::CDROM;
 use crate::core::gpu::GPU;
+use crate::core::mdec::MDEC;
+use crate::core::memory::Bus;
+use crate::core::save_state::{DMAChannelState, DMAState, StateSave};
 use crate::core::spu::SPU;
 
 /// DMA Controller with 7 channels
@@ -86,6 +89,9 @@ pub struct DMA {
     ///
     /// Controls interrupt generation and flags for DMA completion.
     interrupt: u32,
+
+    /// CPU cycles available for moving words in block/linked-list transfers
+    cycle_budget: u32,
 }
 
 /// Single DMA channel
@@ -112,12 +118,29 @@ pub struct DMAChannel {
     /// - Bit 1: Address step (0=forward, 1=backward)
     /// - Bit 8: Chopping enable
     /// - Bits 9-10: Sync mode (0=immediate, 1=block, 2=linked-list)
+    /// - Bits 16-18: Chopping DMA window size (1 << N words)
+    /// - Bits 20-22: Chopping CPU window size (1 << N cycles)
     /// - Bit 24: Start/busy flag
     /// - Bit 28: Manual trigger
     channel_control: u32,
 
     /// Channel ID (0-6)
     channel_id: u8,
+
+    /// Whether a transfer has been started and not yet completed
+    in_progress: bool,
+
+    /// RAM address of the next word (or linked-list node) to transfer
+    current_address: u32,
+
+    /// Words left in the current block-mode transfer
+    words_remaining: u32,
+
+    /// CPU cycles left before the next chopped burst may run
+    chop_wait: u32,
+
+    /// Linked-list nodes processed in the current transfer
+    nodes_visited: u32,
 }
 
 impl DMAChannel {
@@ -138,6 +161,11 @@ impl DMAChannel {
             block_control: 0,
             channel_control: 0,
             channel_id,
+            in_progress: false,
+            current_address: 0,
+            words_remaining: 0,
+            chop_wait: 0,
+            nodes_visited: 0,
         }
     }
 
@@ -171,6 +199,24 @@ impl DMAChannel {
         (self.channel_control & 0x1000_0000) != 0
     }
 
+    /// Check if chopping is enabled (bit 8 of CHCR)
+    #[inline(always)]
+    pub fn chopping(&self) -> bool {
+        (self.channel_control & 0x0100) != 0
+    }
+
+    /// Get the chopping DMA window size in words (bits 16-18 of CHCR)
+    #[inline(always)]
+    pub fn chop_dma_size(&self) -> u32 {
+        1 << ((self.channel_control >> 16) & 7)
+    }
+
+    /// Get the chopping CPU window size in cycles (bits 20-22 of CHCR)
+    #[inline(always)]
+    pub fn chop_cpu_size(&self) -> u32 {
+        1 << ((self.channel_control >> 20) & 7)
+    }
+
     /// Deactivate the channel (clear bit 24 of CHCR)
     fn deactivate(&mut self) {
         log::trace!("DMA channel {} deactivated", self.channel_id);
@@ -179,13 +225,20 @@ impl DMAChannel {
 }
 
 impl DMA {
+    /// CPU cycles needed to move one word
+    const CYCLES_PER_WORD: u32 = 1;
+
+    /// Maximum linked-list nodes per transfer
+    ///
+    /// A valid list cannot have more nodes than there are words in RAM,
+    /// so exceeding this means the list loops.
+    const LINKED_LIST_MAX_NODES: u32 = 0x8_0000;
+
     /// Channel 0: MDEC In (compression input)
-    #[allow(dead_code)]
-    const CH_MDEC_IN: usize = 0;
+    pub const CH_MDEC_IN: usize = 0;
 
     /// Channel 1: MDEC Out (decompression output)
-    #[allow(dead_code)]
-    const CH_MDEC_OUT: usize = 1;
+    pub const CH_MDEC_OUT: usize = 1;
 
     /// Channel 2: GPU (graphics)
     pub const CH_GPU: usize = 2;
@@ -197,8 +250,10 @@ impl DMA {
     pub const CH_SPU: usize = 4;
 
     /// Channel 5: PIO (expansion port)
-    #[allow(dead_code)]
-    const CH_PIO: usize = 5;
+    pub const CH_PIO: usize = 5;
+
+    /// Expansion Region 1 base address used by PIO transfers
+    const PIO_BASE: u32 = 0x1F00_0000;
 
     /// Channel 6: OTC (ordering table clear)
     pub const CH_OTC: usize = 6;
@@ -227,6 +282,7 @@ impl DMA {
             ],
             control: 0x0765_4321, // Default channel priority
             interrupt: 0,
+            cycle_budget: 0,
         }
     }
 
@@ -235,9 +291,15 @@ impl DMA {
     /// Should be called periodically (e.g., once per scanline) to handle
     /// active DMA transfers. Respects DPCR enable bits and priority ordering.
     ///
+    /// Block and linked-list transfers move one word per cycle, so large
+    /// transfers progress over several calls and keep the CHCR busy bit set
+    /// until they complete. Sync mode 0 transfers complete at once.
+    ///
     /// # Arguments
     ///
+    /// * `cycles` - CPU cycles elapsed since the last call
     /// * `ram` - Main system RAM
+    /// * `mdec` - MDEC reference for MDEC In/Out transfers
     /// * `gpu` - GPU reference for GPU transfers
     /// * `cdrom` - CD-ROM reference for CD-ROM transfers
     /// * `spu` - SPU reference for SPU transfers
@@ -247,7 +309,9 @@ impl DMA {
     /// `true` if any transfer generated an interrupt
     pub fn tick(
         &mut self,
+        cycles: u32,
         ram: &mut [u8],
+        mdec: &mut MDEC,
         gpu: &mut GPU,
         cdrom: &mut CDROM,
         spu: &mut SPU,
@@ -267,12 +331,24 @@ impl DMA {
             }
         }
 
+        // Let the CPU window of chopped transfers elapse
+        for channel in self.channels.iter_mut() {
+            channel.chop_wait = channel.chop_wait.saturating_sub(cycles);
+        }
+
+        // Cycles only accumulate while a transfer is waiting to run
+        if active_channels.is_empty() {
+            self.cycle_budget = 0;
+            return false;
+        }
+        self.cycle_budget = self.cycle_budget.saturating_add(cycles);
+
         // Sort by priority (higher priority value = higher priority)
         active_channels.sort_by(|a, b| b.1.cmp(&a.1));
 
         // Execute transfers in priority order
         for (ch_id, _) in active_channels {
-            irq |= self.execute_transfer(ch_id, ram, gpu, cdrom, spu);
+            irq |= self.execute_transfer(ch_id, ram, mdec, gpu, cdrom, spu);
         }
 
         irq
@@ -284,6 +360,7 @@ impl DMA {
     ///
     /// * `ch_id` - Channel ID (0-6)
     /// * `ram` - Main system RAM
+    /// * `mdec` - MDEC reference
     /// * `gpu` - GPU reference
     /// * `cdrom` - CD-ROM reference
     /// * `spu` - SPU reference
@@ -295,6 +372,7 @@ impl DMA {
         &mut self,
         ch_id: usize,
         ram: &mut [u8],
+        mdec: &mut MDEC,
         gpu: &mut GPU,
         cdrom: &mut CDROM,
         spu: &mut SPU,
@@ -308,9 +386,12 @@ impl DMA {
         );
 
         let completed = match ch_id {
+            Self::CH_MDEC_IN => self.transfer_mdec_in(ram, mdec),
+            Self::CH_MDEC_OUT => self.transfer_mdec_out(ram, mdec),
             Self::CH_GPU => self.transfer_gpu(ram, gpu),
             Self::CH_CDROM => self.transfer_cdrom(ram, cdrom),
             Self::CH_SPU => self.transfer_spu(ram, spu),
+            Self::CH_PIO => self.transfer_pio(ram),
             Self::CH_OTC => self.transfer_otc(ram),
             _ => {
                 log::warn!("DMA{} not implemented", ch_id);
@@ -334,29 +415,210 @@ impl DMA {
         }
     }
 
+    /// Get the block size in words from BCR
+    ///
+    /// A size of 0 means 0x10000 words.
+    fn block_size(&self, ch_id: usize) -> u32 {
+        match self.channels[ch_id].block_control & 0xFFFF {
+            0 => 0x10000,
+            size => size,
+        }
+    }
+
+    /// Get the total number of words to transfer in sync mode 0 or 1
+    ///
+    /// Sync mode 0 transfers one block, sync mode 1 transfers
+    /// `block_count` blocks of `block_size` words.
+    fn block_words(&self, ch_id: usize) -> u32 {
+        let block_size = self.block_size(ch_id);
+        let block_count = (self.channels[ch_id].block_control >> 16) & 0xFFFF;
+
+        if self.channels[ch_id].sync_mode() == 0 {
+            block_size
+        } else {
+            block_size * block_count
+        }
+    }
+
+    /// Start or continue a word-based transfer
+    ///
+    /// Sync mode 0 moves everything at once (the CPU is stalled for the
+    /// whole transfer on hardware), unless chopping is enabled: then only
+    /// `chop_dma_size` words move per burst, followed by a `chop_cpu_size`
+    /// cycle window for the CPU. Other modes only move as many words as
+    /// the accumulated cycle budget allows, so the CPU keeps running
+    /// between chunks.
+    ///
+    /// # Arguments
+    ///
+    /// * `ch_id` - Channel ID (0-6)
+    /// * `total_words` - Words in the whole transfer
+    ///
+    /// # Returns
+    ///
+    /// Current RAM address and the number of words to move now
+    fn begin_chunk(&mut self, ch_id: usize, total_words: u32) -> (u32, u32) {
+        let channel = &mut self.channels[ch_id];
+        if !channel.in_progress {
+            channel.in_progress = true;
+            channel.current_address = channel.base_address & 0x001F_FFFC;
+            channel.words_remaining = total_words;
+        }
+
+        let words = if channel.sync_mode() == 0 && channel.chopping() {
+            if channel.chop_wait > 0 {
+                0
+            } else {
+                channel.chop_wait = channel.chop_cpu_size();
+                channel.words_remaining.min(channel.chop_dma_size())
+            }
+        } else if channel.sync_mode() == 0 {
+            channel.words_remaining
+        } else {
+            channel
+                .words_remaining
+                .min(self.cycle_budget / Self::CYCLES_PER_WORD)
+        };
+        self.cycle_budget = self
+            .cycle_budget
+            .saturating_sub(words * Self::CYCLES_PER_WORD);
+
+        (channel.current_address, words)
+    }
+
+    /// Record the progress of a chunk and finish the transfer if done
+    ///
+    /// On completion MADR is written back with the final address and the
+    /// block count in BCR is cleared, as on hardware.
+    ///
+    /// # Arguments
+    ///
+    /// * `ch_id` - Channel ID (0-6)
+    /// * `address` - RAM address after the chunk
+    /// * `words` - Words moved in the chunk
+    ///
+    /// # Returns
+    ///
+    /// `true` if the transfer completed
+    fn end_chunk(&mut self, ch_id: usize, address: u32, words: u32) -> bool {
+        let channel = &mut self.channels[ch_id];
+        channel.current_address = address;
+        channel.words_remaining -= words;
+
+        if channel.words_remaining == 0 {
+            channel.in_progress = false;
+            channel.chop_wait = 0;
+            channel.base_address = address;
+            channel.block_control &= 0xFFFF;
+            channel.deactivate();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Execute MDEC In DMA transfer (channel 0)
+    ///
+    /// Feeds commands and compressed macroblock data from RAM to the MDEC.
+    fn transfer_mdec_in(&mut self, ram: &mut [u8], mdec: &mut MDEC) -> bool {
+        let total_words = self.block_words(Self::CH_MDEC_IN);
+        let (mut addr, words) = self.begin_chunk(Self::CH_MDEC_IN, total_words);
+
+        for _ in 0..words {
+            let word = self.read_ram_u32(ram, addr);
+            mdec.write_command(word);
+            addr = (addr + 4) & 0x001F_FFFC;
+        }
+
+        let completed = self.end_chunk(Self::CH_MDEC_IN, addr, words);
+        if completed {
+            log::debug!("MDEC In DMA transfer complete ({} words)", total_words);
+        }
+        completed
+    }
+
+    /// Execute MDEC Out DMA transfer (channel 1)
+    ///
+    /// Reads decoded pixel data from the MDEC into RAM. Until the MDEC has
+    /// produced enough data the transfer stays pending, as the data-out
+    /// request is not asserted yet.
+    fn transfer_mdec_out(&mut self, ram: &mut [u8], mdec: &mut MDEC) -> bool {
+        let total_words = self.block_words(Self::CH_MDEC_OUT);
+
+        let pending = if self.channels[Self::CH_MDEC_OUT].in_progress {
+            self.channels[Self::CH_MDEC_OUT].words_remaining
+        } else {
+            total_words
+        };
+        if (mdec.output_len() as u32) < pending {
+            return false;
+        }
+
+        let (mut addr, words) = self.begin_chunk(Self::CH_MDEC_OUT, total_words);
+
+        for _ in 0..words {
+            let word = mdec.read_data();
+            self.write_ram_u32(ram, addr, word);
+            addr = (addr + 4) & 0x001F_FFFC;
+        }
+
+        let completed = self.end_chunk(Self::CH_MDEC_OUT, addr, words);
+        if completed {
+            log::debug!("MDEC Out DMA transfer complete ({} words)", total_words);
+        }
+        completed
+    }
+
     /// Execute GPU DMA transfer (channel 2)
     ///
-    /// Supports linked-list mode for command buffer transfers.
+    /// Supports linked-list mode for command buffer transfers, and block
+    /// modes for VRAM image transfers. In sync mode 1 only whole blocks are
+    /// moved; each RAM → GPU block is written into the CPU→VRAM window set
+    /// up by GP0(0xA0), normally one VRAM row per block.
     fn transfer_gpu(&mut self, ram: &mut [u8], gpu: &mut GPU) -> bool {
-        // Extract channel data first to avoid borrow issues
         let sync_mode = self.channels[Self::CH_GPU].sync_mode();
         let direction = self.channels[Self::CH_GPU].direction();
-        let base_address = self.channels[Self::CH_GPU].base_address;
-        let block_control = self.channels[Self::CH_GPU].block_control;
 
         match sync_mode {
             2 => {
                 // Linked-list mode (GPU command lists)
-                let mut addr = base_address & 0x001F_FFFC;
+                if !self.channels[Self::CH_GPU].in_progress {
+                    self.channels[Self::CH_GPU].in_progress = true;
+                    self.channels[Self::CH_GPU].nodes_visited = 0;
+                    self.channels[Self::CH_GPU].current_address =
+                        self.channels[Self::CH_GPU].base_address & 0x001F_FFFC;
+                }
+                let mut addr = self.channels[Self::CH_GPU].current_address;
 
                 loop {
                     // Read linked-list header
                     let header = self.read_ram_u32(ram, addr);
-                    let count = (header >> 24) as usize;
+                    let count = header >> 24;
+
+                    // Each node costs its header plus payload words
+                    let cost = (count + 1) * Self::CYCLES_PER_WORD;
+                    if self.cycle_budget < cost {
+                        self.channels[Self::CH_GPU].current_address = addr;
+                        return false;
+                    }
+                    self.cycle_budget -= cost;
+
+                    // Bail out of malformed or cyclic lists
+                    self.channels[Self::CH_GPU].nodes_visited += 1;
+                    if self.channels[Self::CH_GPU].nodes_visited > Self::LINKED_LIST_MAX_NODES {
+                        log::warn!(
+                            "GPU DMA linked list exceeded {} nodes at 0x{:08X}, aborting",
+                            Self::LINKED_LIST_MAX_NODES,
+                            addr
+                        );
+                        self.channels[Self::CH_GPU].in_progress = false;
+                        self.channels[Self::CH_GPU].deactivate();
+                        return false;
+                    }
 
                     // Send all words in this node to GPU
                     for i in 0..count {
-                        let word = self.read_ram_u32(ram, addr + 4 + (i * 4) as u32);
+                        let word = self.read_ram_u32(ram, addr + 4 + i * 4);
                         gpu.write_gp0(word);
                     }
 
@@ -369,41 +631,58 @@ impl DMA {
                     addr = header & 0x001F_FFFC;
                 }
 
+                // MADR ends up holding the end-of-list marker
+                self.channels[Self::CH_GPU].base_address = 0x00FF_FFFF;
+                self.channels[Self::CH_GPU].in_progress = false;
                 self.channels[Self::CH_GPU].deactivate();
                 log::debug!("GPU DMA linked-list transfer complete");
                 true
             }
             0 | 1 => {
                 // Block mode for VRAM transfers
-                let block_size = (block_control & 0xFFFF) as usize;
-                let block_count = ((block_control >> 16) & 0xFFFF) as usize;
-                let mut addr = base_address & 0x001F_FFFC;
-
-                let total_words = if sync_mode == 0 {
-                    block_size
-                } else {
-                    block_size * block_count
-                };
+                let total_words = self.block_words(Self::CH_GPU);
+                let (mut addr, mut words) = self.begin_chunk(Self::CH_GPU, total_words);
+
+                // Block sync waits until a whole block fits in the budget
+                let block_size = self.block_size(Self::CH_GPU);
+                if sync_mode == 1 && words < self.channels[Self::CH_GPU].words_remaining {
+                    let partial = words % block_size;
+                    words -= partial;
+                    self.cycle_budget += partial * Self::CYCLES_PER_WORD;
+                }
 
-                if direction == DMAChannel::TRANSFER_FROM_RAM {
+                if direction == DMAChannel::TRANSFER_FROM_RAM && sync_mode == 1 {
+                    // RAM → VRAM, one block at a time
+                    let mut block = Vec::with_capacity(block_size as usize);
+                    for _ in 0..words / block_size {
+                        block.clear();
+                        for _ in 0..block_size {
+                            block.push(self.read_ram_u32(ram, addr));
+                            addr = (addr + 4) & 0x001F_FFFC;
+                        }
+                        gpu.write_vram_block(&block);
+                    }
+                } else if direction == DMAChannel::TRANSFER_FROM_RAM {
                     // RAM → GPU
-                    for _ in 0..total_words {
+                    for _ in 0..words {
                         let word = self.read_ram_u32(ram, addr);
                         gpu.write_gp0(word);
                         addr = (addr + 4) & 0x001F_FFFC;
                     }
                 } else if direction == DMAChannel::TRANSFER_TO_RAM {
                     // GPU → RAM (VRAM reads)
-                    for _ in 0..total_words {
+                    for _ in 0..words {
                         let word = gpu.read_gpuread();
                         self.write_ram_u32(ram, addr, word);
                         addr = (addr + 4) & 0x001F_FFFC;
                     }
                 }
 
-                self.channels[Self::CH_GPU].deactivate();
-                log::debug!("GPU DMA block transfer complete ({} words)", total_words);
-                true
+                let completed = self.end_chunk(Self::CH_GPU, addr, words);
+                if completed {
+                    log::debug!("GPU DMA block transfer complete ({} words)", total_words);
+                }
+                completed
             }
             _ => {
                 log::warn!("GPU DMA sync mode {} not supported", sync_mode);
@@ -417,19 +696,12 @@ impl DMA {
     ///
     /// Transfers sector data from CD-ROM to RAM.
     fn transfer_cdrom(&mut self, ram: &mut [u8], cdrom: &mut CDROM) -> bool {
-        // Extract channel data first to avoid borrow issues
-        let block_control = self.channels[Self::CH_CDROM].block_control;
-        let base_address = self.channels[Self::CH_CDROM].base_address;
-
         // CD-ROM only supports device→RAM transfers
-        let block_size = (block_control & 0xFFFF) as usize;
-        let block_count = ((block_control >> 16) & 0xFFFF) as usize;
-
-        let mut addr = base_address & 0x001F_FFFC;
-        let total_words = block_size * block_count;
+        let total_words = self.block_words(Self::CH_CDROM);
+        let (mut addr, words) = self.begin_chunk(Self::CH_CDROM, total_words);
 
         // Transfer data from CD-ROM buffer to RAM (word by word)
-        for _ in 0..total_words {
+        for _ in 0..words {
             // Read 4 bytes (1 word) from CD-ROM
             let byte0 = cdrom.get_data_byte();
             let byte1 = cdrom.get_data_byte();
@@ -442,13 +714,15 @@ impl DMA {
             addr = (addr + 4) & 0x001F_FFFC;
         }
 
-        self.channels[Self::CH_CDROM].deactivate();
-        log::debug!(
-            "CD-ROM DMA transfer complete ({} words = {} bytes)",
-            total_words,
-            total_words * 4
-        );
-        true
+        let completed = self.end_chunk(Self::CH_CDROM, addr, words);
+        if completed {
+            log::debug!(
+                "CD-ROM DMA transfer complete ({} words = {} bytes)",
+                total_words,
+                total_words * 4
+            );
+        }
+        completed
     }
 
     /// Execute SPU DMA transfer (channel 4)
@@ -456,83 +730,83 @@ impl DMA {
     /// Transfers data between RAM and SPU RAM.
     /// Supports sync mode 0 (manual/immediate) and sync mode 1 (block).
     fn transfer_spu(&mut self, ram: &mut [u8], spu: &mut SPU) -> bool {
-        // Extract channel data first to avoid borrow issues
         let sync_mode = self.channels[Self::CH_SPU].sync_mode();
         let direction = self.channels[Self::CH_SPU].direction();
-        let base_address = self.channels[Self::CH_SPU].base_address;
-        let block_control = self.channels[Self::CH_SPU].block_control;
 
-        match sync_mode {
-            // Sync mode 0: Manual/Immediate
-            0 => {
-                let words = if block_control & 0xFFFF > 0 {
-                    block_control & 0xFFFF
-                } else {
-                    0x10000
-                };
-
-                let mut addr = base_address & 0x001F_FFFC;
-
-                for _ in 0..words {
-                    if direction == DMAChannel::TRANSFER_FROM_RAM {
-                        // RAM → SPU
-                        let value = self.read_ram_u32(ram, addr);
-                        spu.dma_write(value);
-                    } else {
-                        // SPU → RAM
-                        let value = spu.dma_read();
-                        self.write_ram_u32(ram, addr, value);
-                    }
+        if sync_mode > 1 {
+            log::warn!("SPU DMA sync mode {} not supported", sync_mode);
+            self.channels[Self::CH_SPU].deactivate();
+            return false;
+        }
 
-                    addr = (addr + 4) & 0x001F_FFFC;
-                }
+        let total_words = self.block_words(Self::CH_SPU);
+        let (mut addr, words) = self.begin_chunk(Self::CH_SPU, total_words);
 
-                spu.flush_dma_fifo();
-                self.channels[Self::CH_SPU].deactivate();
-                log::debug!("SPU DMA sync mode 0 transfer complete ({} words)", words);
-                true
+        for _ in 0..words {
+            if direction == DMAChannel::TRANSFER_FROM_RAM {
+                // RAM → SPU
+                let value = self.read_ram_u32(ram, addr);
+                spu.dma_write(value);
+            } else {
+                // SPU → RAM
+                let value = spu.dma_read();
+                self.write_ram_u32(ram, addr, value);
             }
 
-            // Sync mode 1: Block
-            1 => {
-                let block_size = (block_control & 0xFFFF) as usize;
-                let block_count = ((block_control >> 16) & 0xFFFF) as usize;
-
-                let mut addr = base_address & 0x001F_FFFC;
-
-                for _ in 0..block_count {
-                    for _ in 0..block_size {
-                        if direction == DMAChannel::TRANSFER_FROM_RAM {
-                            // RAM → SPU
-                            let value = self.read_ram_u32(ram, addr);
-                            spu.dma_write(value);
-                        } else {
-                            // SPU → RAM
-                            let value = spu.dma_read();
-                            self.write_ram_u32(ram, addr, value);
-                        }
+            addr = (addr + 4) & 0x001F_FFFC;
+        }
 
-                        addr = (addr + 4) & 0x001F_FFFC;
-                    }
-                }
+        let completed = self.end_chunk(Self::CH_SPU, addr, words);
+        if completed {
+            spu.flush_dma_fifo();
+            log::debug!(
+                "SPU DMA sync mode {} transfer complete ({} words)",
+                sync_mode,
+                total_words
+            );
+        }
+        completed
+    }
 
-                spu.flush_dma_fifo();
-                self.channels[Self::CH_SPU].deactivate();
-                log::debug!(
-                    "SPU DMA block transfer complete ({} blocks × {} words = {} words)",
-                    block_count,
-                    block_size,
-                    block_count * block_size
-                );
-                true
-            }
+    /// Execute PIO DMA transfer (channel 5)
+    ///
+    /// Transfers data between RAM and the expansion port (Expansion
+    /// Region 1), advancing through the region one word at a time.
+    /// Supports sync mode 0 (immediate) and sync mode 1 (block).
+    fn transfer_pio(&mut self, ram: &mut [u8]) -> bool {
+        let sync_mode = self.channels[Self::CH_PIO].sync_mode();
+        let direction = self.channels[Self::CH_PIO].direction();
+
+        if sync_mode > 1 {
+            log::warn!("PIO DMA sync mode {} not supported", sync_mode);
+            self.channels[Self::CH_PIO].deactivate();
+            return false;
+        }
 
-            _ => {
-                log::warn!("SPU DMA sync mode {} not supported", sync_mode);
-                self.channels[Self::CH_SPU].deactivate();
-                false
+        let total_words = self.block_words(Self::CH_PIO);
+        let (mut addr, words) = self.begin_chunk(Self::CH_PIO, total_words);
+        let done = total_words - self.channels[Self::CH_PIO].words_remaining;
+
+        for i in 0..words {
+            let port = Self::PIO_BASE + (done + i) * 4;
+            if direction == DMAChannel::TRANSFER_FROM_RAM {
+                // RAM → Expansion port
+                let value = self.read_ram_u32(ram, addr);
+                Bus::write_expansion32(port, value);
+            } else {
+                // Expansion port → RAM
+                let value = Bus::read_expansion32(port);
+                self.write_ram_u32(ram, addr, value);
             }
+
+            addr = (addr + 4) & 0x001F_FFFC;
+        }
+
+        let completed = self.end_chunk(Self::CH_PIO, addr, words);
+        if completed {
+            log::debug!("PIO DMA transfer complete ({} words)", total_words);
         }
+        completed
     }
 
     /// Execute OTC (Ordering Table Clear) transfer (channel 6)
@@ -540,16 +814,13 @@ impl DMA {
     /// Creates a reverse-linked list in RAM for GPU command ordering.
     /// Used to set up GPU command lists for rendering.
     fn transfer_otc(&mut self, ram: &mut [u8]) -> bool {
-        // Extract channel data first to avoid borrow issues
-        let block_control = self.channels[Self::CH_OTC].block_control;
-        let base_address = self.channels[Self::CH_OTC].base_address;
-
-        let count = block_control & 0xFFFF;
-        let mut addr = base_address & 0x001F_FFFC;
+        let total_words = self.block_words(Self::CH_OTC);
+        let (mut addr, words) = self.begin_chunk(Self::CH_OTC, total_words);
+        let remaining = self.channels[Self::CH_OTC].words_remaining;
 
         // Write reverse-linked list
-        for i in 0..count {
-            if i == count - 1 {
+        for i in 0..words {
+            if remaining - i == 1 {
                 // Last entry: end marker
                 self.write_ram_u32(ram, addr, 0x00FF_FFFF);
             } else {
@@ -560,9 +831,11 @@ impl DMA {
             addr = addr.wrapping_sub(4) & 0x001F_FFFC;
         }
 
-        self.channels[Self::CH_OTC].deactivate();
-        log::debug!("OTC DMA transfer complete ({} entries)", count);
-        true
+        let completed = self.end_chunk(Self::CH_OTC, addr, words);
+        if completed {
+            log::debug!("OTC DMA transfer complete ({} entries)", total_words);
+        }
+        completed
     }
 
     /// Read 32-bit word from RAM
@@ -676,6 +949,10 @@ impl DMA {
 
     /// Write channel CHCR register
     pub fn write_chcr(&mut self, channel: usize, value: u32) {
+        // Starting a channel begins a new transfer from MADR/BCR
+        if !self.channels[channel].is_active() {
+            self.channels[channel].in_progress = false;
+        }
         self.channels[channel].channel_control = value;
         log::trace!("DMA{} CHCR = 0x{:08X}", channel, value);
 
@@ -730,6 +1007,50 @@ impl Default for DMA {
     }
 }
 
+impl StateSave for DMA {
+    type State = DMAState;
+
+    fn to_state(&self) -> DMAState {
+        DMAState {
+            channels: self
+                .channels
+                .iter()
+                .map(|ch| DMAChannelState {
+                    base_address: ch.base_address,
+                    block_control: ch.block_control,
+                    channel_control: ch.channel_control,
+                    channel_id: ch.channel_id,
+                    in_progress: ch.in_progress,
+                    current_address: ch.current_address,
+                    words_remaining: ch.words_remaining,
+                    chop_wait: ch.chop_wait,
+                    nodes_visited: ch.nodes_visited,
+                })
+                .collect(),
+            control: self.control,
+            interrupt: self.interrupt,
+            cycle_budget: self.cycle_budget,
+        }
+    }
+
+    fn restore_from_state(&mut self, state: &DMAState) {
+        for (ch, saved) in self.channels.iter_mut().zip(&state.channels) {
+            ch.base_address = saved.base_address;
+            ch.block_control = saved.block_control;
+            ch.channel_control = saved.channel_control;
+            ch.in_progress = saved.in_progress;
+            ch.current_address = saved.current_address;
+            ch.words_remaining = saved.words_remaining;
+            ch.chop_wait = saved.chop_wait;
+            ch.nodes_visited = saved.nodes_visited;
+        }
+
+        self.control = state.control;
+        self.interrupt = state.interrupt;
+        self.cycle_budget = state.cycle_budget;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1320,6 +1641,349 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mdec_in_out_transfer() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+
+        // Decode one 8-bit block (no tables loaded, so it decodes to 0x80)
+        dma.write_ram_u32(&mut dev.ram, 0x1000, 0x2800_0001);
+        dma.write_ram_u32(&mut dev.ram, 0x1004, 0xFE00_0590);
+
+        dma.write_control(0x0765_43A9); // Enable channels 0 and 1
+        dma.write_madr(DMA::CH_MDEC_IN, 0x1000);
+        dma.write_bcr(DMA::CH_MDEC_IN, 0x0001_0002);
+        dma.write_chcr(DMA::CH_MDEC_IN, 0x1100_0201); // Block mode, RAM→MDEC
+        dma.write_madr(DMA::CH_MDEC_OUT, 0x2000);
+        dma.write_bcr(DMA::CH_MDEC_OUT, 0x0001_0010);
+        dma.write_chcr(DMA::CH_MDEC_OUT, 0x1100_0200); // Block mode, MDEC→RAM
+
+        // MDEC Out has higher priority but waits for decoded data
+        dev.tick(&mut dma, 64);
+        assert!(!dma.channels[DMA::CH_MDEC_IN].is_active());
+        assert!(dma.channels[DMA::CH_MDEC_OUT].is_active());
+        assert_eq!(dev.mdec.output_len(), 16);
+
+        dev.tick(&mut dma, 64);
+        assert!(!dma.channels[DMA::CH_MDEC_OUT].is_active());
+        for i in 0..16 {
+            assert_eq!(dma.read_ram_u32(&dev.ram, 0x2000 + i * 4), 0x8080_8080);
+        }
+    }
+
+    #[test]
+    fn test_chcr_chopping_fields() {
+        let mut dma = create_test_dma();
+
+        dma.write_chcr(DMA::CH_CDROM, 0x0073_0100);
+        let channel = &dma.channels[DMA::CH_CDROM];
+        assert!(channel.chopping());
+        assert_eq!(channel.chop_dma_size(), 8);
+        assert_eq!(channel.chop_cpu_size(), 128);
+
+        dma.write_chcr(DMA::CH_CDROM, 0);
+        assert!(!dma.channels[DMA::CH_CDROM].chopping());
+        assert_eq!(dma.channels[DMA::CH_CDROM].chop_dma_size(), 1);
+    }
+
+    /// Devices needed to tick the DMA controller
+    struct Devices {
+        ram: Vec<u8>,
+        mdec: MDEC,
+        gpu: GPU,
+        cdrom: CDROM,
+        spu: SPU,
+    }
+
+    impl Devices {
+        fn new() -> Self {
+            Self {
+                ram: vec![0u8; 2 * 1024 * 1024],
+                mdec: MDEC::new(),
+                gpu: GPU::new(),
+                cdrom: CDROM::new(),
+                spu: SPU::new(),
+            }
+        }
+
+        fn tick(&mut self, dma: &mut DMA, cycles: u32) -> bool {
+            dma.tick(
+                cycles,
+                &mut self.ram,
+                &mut self.mdec,
+                &mut self.gpu,
+                &mut self.cdrom,
+                &mut self.spu,
+            )
+        }
+    }
+
+    #[test]
+    fn test_block_transfer_takes_time() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+
+        // SPU block transfer: 4 blocks of 16 words, RAM → SPU
+        dma.write_control(0x0009_0000);
+        dma.write_madr(DMA::CH_SPU, 0x1000);
+        dma.write_bcr(DMA::CH_SPU, 0x0004_0010);
+        dma.write_chcr(DMA::CH_SPU, 0x1100_0201);
+
+        // 40 cycles move 40 of 64 words; the busy bit stays set
+        assert!(!dev.tick(&mut dma, 40));
+        assert!(dma.read_chcr(DMA::CH_SPU) & 0x0100_0000 != 0);
+        assert_eq!(dma.channels[DMA::CH_SPU].words_remaining, 24);
+        assert_eq!(dma.channels[DMA::CH_SPU].current_address, 0x1000 + 40 * 4);
+
+        // The rest completes the transfer
+        dev.tick(&mut dma, 24);
+        assert_eq!(dma.read_chcr(DMA::CH_SPU) & 0x0100_0000, 0);
+        assert_ne!(dma.read_interrupt() & (1 << 28), 0);
+    }
+
+    #[test]
+    fn test_immediate_transfer_completes_at_once() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+
+        // OTC sync mode 0 with 8 entries
+        dma.write_control(0x0800_0000);
+        dma.write_madr(DMA::CH_OTC, 0x101C);
+        dma.write_bcr(DMA::CH_OTC, 8);
+        dma.write_chcr(DMA::CH_OTC, 0x1100_0002);
+
+        dev.tick(&mut dma, 1);
+
+        assert!(!dma.channels[DMA::CH_OTC].is_active());
+        assert_eq!(dma.read_ram_u32(&dev.ram, 0x101C), 0x1018);
+        assert_eq!(dma.read_ram_u32(&dev.ram, 0x1000), 0x00FF_FFFF);
+    }
+
+    #[test]
+    fn test_chopped_transfer_progresses_in_bursts() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+        for i in 0..16u32 {
+            dma.write_ram_u32(&mut dev.ram, 0x1000 + i * 4, i);
+        }
+
+        // GPU sync mode 0, 16 words, chopping: 4-word bursts, 8-cycle CPU windows
+        dma.write_control(0x0000_0800);
+        dma.write_madr(DMA::CH_GPU, 0x1000);
+        dma.write_bcr(DMA::CH_GPU, 16);
+        dma.write_chcr(DMA::CH_GPU, 0x1132_0101);
+
+        dev.tick(&mut dma, 1);
+        assert_eq!(dma.channels[DMA::CH_GPU].words_remaining, 12);
+
+        // Still inside the CPU window
+        dev.tick(&mut dma, 4);
+        assert_eq!(dma.channels[DMA::CH_GPU].words_remaining, 12);
+        assert!(dma.channels[DMA::CH_GPU].is_active());
+
+        // Window elapsed: next burst
+        dev.tick(&mut dma, 4);
+        assert_eq!(dma.channels[DMA::CH_GPU].words_remaining, 8);
+        assert_eq!(dma.channels[DMA::CH_GPU].current_address, 0x1000 + 8 * 4);
+
+        dev.tick(&mut dma, 8);
+        dev.tick(&mut dma, 8);
+        assert!(!dma.channels[DMA::CH_GPU].is_active());
+        assert_eq!(dma.channels[DMA::CH_GPU].words_remaining, 0);
+    }
+
+    #[test]
+    fn test_gpu_block_transfer_writes_back_madr_bcr() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+
+        // 2 blocks of 4 words, RAM → GPU
+        dma.write_control(0x0000_0800);
+        dma.write_madr(DMA::CH_GPU, 0x1000);
+        dma.write_bcr(DMA::CH_GPU, 0x0002_0004);
+        dma.write_chcr(DMA::CH_GPU, 0x1100_0201);
+
+        dev.tick(&mut dma, 8);
+
+        assert!(!dma.channels[DMA::CH_GPU].is_active());
+        assert_eq!(dma.read_madr(DMA::CH_GPU), 0x1020);
+        assert_eq!(dma.read_bcr(DMA::CH_GPU), 0x0000_0004);
+    }
+
+    #[test]
+    fn test_gpu_block_sync_writes_image_rows_to_vram() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+
+        // 4×2 image at (16, 32): one 2-word block per VRAM row
+        for i in 0..4u32 {
+            let pixel = (i * 2) as u16;
+            let word = (pixel as u32) | ((pixel as u32 + 1) << 16);
+            dma.write_ram_u32(&mut dev.ram, 0x1000 + i * 4, word | 0x0100_0100);
+        }
+        dev.gpu.write_gp0(0xA000_0000);
+        dev.gpu.write_gp0(0x0020_0010);
+        dev.gpu.write_gp0(0x0002_0004);
+
+        dma.write_control(0x0000_0800);
+        dma.write_madr(DMA::CH_GPU, 0x1000);
+        dma.write_bcr(DMA::CH_GPU, 0x0002_0002);
+        dma.write_chcr(DMA::CH_GPU, 0x1100_0201);
+
+        // Three cycles only fit one whole block: the first row
+        dev.tick(&mut dma, 3);
+        assert_eq!(dma.channels[DMA::CH_GPU].words_remaining, 2);
+        assert_eq!(dev.gpu.read_vram(16, 32), 0x0100);
+        assert_eq!(dev.gpu.read_vram(19, 32), 0x0103);
+        assert_eq!(dev.gpu.read_vram(16, 33), 0);
+
+        dev.tick(&mut dma, 1);
+        assert!(!dma.channels[DMA::CH_GPU].is_active());
+        for x in 0..4u16 {
+            assert_eq!(dev.gpu.read_vram(16 + x, 33), 0x0104 + x);
+        }
+        assert!(dev.gpu.vram_transfer.is_none());
+    }
+
+    #[test]
+    fn test_gpu_block_sync_with_zero_block_size_uses_0x10000_words() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+
+        // BCR block size 0 means 0x10000 words per block
+        dma.write_control(0x0000_0800);
+        dma.write_madr(DMA::CH_GPU, 0x1000);
+        dma.write_bcr(DMA::CH_GPU, 0x0001_0000);
+        dma.write_chcr(DMA::CH_GPU, 0x1100_0201);
+
+        dev.tick(&mut dma, 8);
+        assert_eq!(dma.channels[DMA::CH_GPU].words_remaining, 0x10000);
+
+        dev.tick(&mut dma, 0x10000);
+        assert!(!dma.channels[DMA::CH_GPU].is_active());
+        assert_eq!(dma.read_madr(DMA::CH_GPU), 0x1000 + 0x40000);
+    }
+
+    #[test]
+    fn test_otc_transfer_writes_back_madr_bcr() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+
+        dma.write_control(0x0800_0000);
+        dma.write_madr(DMA::CH_OTC, 0x101C);
+        dma.write_bcr(DMA::CH_OTC, 8);
+        dma.write_chcr(DMA::CH_OTC, 0x1100_0002);
+
+        dev.tick(&mut dma, 1);
+
+        assert_eq!(dma.read_madr(DMA::CH_OTC), 0x0FFC);
+        assert_eq!(dma.read_bcr(DMA::CH_OTC), 8);
+    }
+
+    #[test]
+    fn test_linked_list_transfer_writes_end_marker_to_madr() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+        dma.write_ram_u32(&mut dev.ram, 0x1000, 0x00FF_FFFF);
+
+        dma.write_control(0x0000_0800);
+        dma.write_madr(DMA::CH_GPU, 0x1000);
+        dma.write_chcr(DMA::CH_GPU, 0x1100_0401);
+
+        dev.tick(&mut dma, 1);
+
+        assert!(!dma.channels[DMA::CH_GPU].is_active());
+        assert_eq!(dma.read_madr(DMA::CH_GPU), 0x00FF_FFFF);
+    }
+
+    #[test]
+    fn test_pio_transfer_reads_expansion_region() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+
+        // 66 words from the expansion port into RAM (sync mode 0)
+        dma.write_control(0x0080_0000);
+        dma.write_interrupt((1 << 23) | (1 << 21));
+        dma.write_madr(DMA::CH_PIO, 0x1000);
+        dma.write_bcr(DMA::CH_PIO, 66);
+        dma.write_chcr(DMA::CH_PIO, 0x1100_0000);
+
+        let irq = dev.tick(&mut dma, 1);
+
+        assert!(irq);
+        assert!(!dma.channels[DMA::CH_PIO].is_active());
+        assert_ne!(dma.read_interrupt() & (1 << 29), 0);
+
+        // ROM header reads as 0, the rest of the region as open bus
+        assert_eq!(dma.read_ram_u32(&dev.ram, 0x1000), 0);
+        assert_eq!(dma.read_ram_u32(&dev.ram, 0x1000 + 63 * 4), 0);
+        assert_eq!(dma.read_ram_u32(&dev.ram, 0x1000 + 64 * 4), 0xFFFF_FFFF);
+        assert_eq!(dma.read_ram_u32(&dev.ram, 0x1000 + 66 * 4), 0);
+    }
+
+    #[test]
+    fn test_pio_transfer_from_ram_completes() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+
+        dma.write_control(0x0080_0000);
+        dma.write_madr(DMA::CH_PIO, 0x1000);
+        dma.write_bcr(DMA::CH_PIO, 0x0002_0008);
+        dma.write_chcr(DMA::CH_PIO, 0x1100_0201);
+
+        dev.tick(&mut dma, 16);
+
+        assert!(!dma.channels[DMA::CH_PIO].is_active());
+        assert_eq!(dma.read_madr(DMA::CH_PIO), 0x1040);
+    }
+
+    #[test]
+    fn test_linked_list_self_reference_aborts() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+
+        // Node at 0x1000 with no payload links to itself
+        dma.write_ram_u32(&mut dev.ram, 0x1000, 0x0000_1000);
+
+        dma.write_control(0x0000_0800);
+        dma.write_madr(DMA::CH_GPU, 0x1000);
+        dma.write_chcr(DMA::CH_GPU, 0x1100_0401);
+
+        // Plenty of cycles to loop forever; must return instead
+        let irq = dev.tick(&mut dma, u32::MAX);
+
+        assert!(!irq);
+        assert!(!dma.channels[DMA::CH_GPU].is_active());
+        assert_eq!(dma.read_interrupt() & (1 << 26), 0);
+    }
+
+    #[test]
+    fn test_linked_list_transfer_takes_time() {
+        let mut dma = create_test_dma();
+        let mut dev = Devices::new();
+
+        // Two nodes with 3 NOP words each: 0x1000 → 0x2000 → end
+        dma.write_ram_u32(&mut dev.ram, 0x1000, 0x0300_2000);
+        dma.write_ram_u32(&mut dev.ram, 0x2000, 0x03FF_FFFF);
+
+        dma.write_control(0x0000_0800);
+        dma.write_madr(DMA::CH_GPU, 0x1000);
+        dma.write_chcr(DMA::CH_GPU, 0x1100_0401);
+
+        // Not enough cycles for the first node (4 words)
+        dev.tick(&mut dma, 3);
+        assert!(dma.channels[DMA::CH_GPU].is_active());
+        assert_eq!(dma.channels[DMA::CH_GPU].current_address, 0x1000);
+
+        // First node sent, second still pending
+        dev.tick(&mut dma, 2);
+        assert!(dma.channels[DMA::CH_GPU].is_active());
+        assert_eq!(dma.channels[DMA::CH_GPU].current_address, 0x2000);
+
+        dev.tick(&mut dma, 4);
+        assert!(!dma.channels[DMA::CH_GPU].is_active());
+    }
+
     #[test]
     fn test_complete_transfer_setup() {
         let mut dma = create_test_dma();
@@ -25,8 +25,15 @@ pub enum EmulatorError {
     #[error("BIOS file not found: {0}")]
     BiosNotFound(String),
 
-    #[error("Invalid BIOS size: {got} bytes (expected {expected})")]
-    InvalidBiosSize { expected: usize, got: usize },
+    #[error("Invalid BIOS size: {got} bytes (expected {expected}): {hint}")]
+    InvalidBiosSize {
+        expected: usize,
+        got: usize,
+        hint: &'static str,
+    },
+
+    #[error("Invalid BIOS image: {0}")]
+    InvalidBios(String),
 
     #[error("Invalid memory access at 0x{address:08X}")]
     InvalidMemoryAccess { address: u32 },
@@ -57,6 +64,12 @@ pub enum EmulatorError {
 
     #[error("Loader error: {0}")]
     LoaderError(String),
+
+    #[error("Save state error: {0}")]
+    SaveState(String),
+
+    #[error("Memory card error: {0}")]
+    MemoryCard(String),
 }
 
 /// GPU-specific error types
@@ -102,6 +115,17 @@ pub enum CdRomError {
     #[error("Disc load error: {0}")]
     DiscLoadError(String),
 
+    #[error("BIN file not found: '{path}' (check the FILE line of the .cue sheet)")]
+    MissingBinFile { path: String },
+
+    #[error("BIN file '{path}' is {size} bytes, not a multiple of the 2352-byte sector size (truncated dump?)")]
+    InvalidBinSize { path: String, size: u64 },
+
+    #[error(
+        "INDEX {index:02} of track {track:02} (line {line}) is earlier than the previous INDEX"
+    )]
+    IndexOutOfOrder { track: u8, index: u8, line: usize },
+
     #[error("I/O error: {0}")]
     IoError(#[from] std::io::Error),
 }
@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RGB888 ↔ VRAM 15-bit color conversion
+//!
+//! VRAM stores 5 bits per channel (bits 0-4 red, 5-9 green, 10-14 blue,
+//! bit 15 mask). The GPU itself converts by truncating on the way in and
+//! shifting left by 3 on the way out, so white reads back as 248. Tools that
+//! import textures or export screenshots may prefer rounding to the nearest
+//! value, which uses the full 0-255 range.
+
+/// How 8-bit and 5-bit channel values are mapped onto each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorRounding {
+    /// Drop the low 3 bits, and expand by shifting left 3 (GPU behavior)
+    #[default]
+    Truncate,
+    /// Map to the nearest value, so 31 expands to 255
+    Round,
+}
+
+impl ColorRounding {
+    /// Convert one 8-bit channel to 5 bits
+    #[inline]
+    fn to_5bit(self, value: u8) -> u16 {
+        match self {
+            Self::Truncate => (value >> 3) as u16,
+            Self::Round => (value as u16 * 31 + 127) / 255,
+        }
+    }
+
+    /// Convert one 5-bit channel to 8 bits
+    #[inline]
+    fn to_8bit(self, value: u16) -> u8 {
+        let value = value & 0x1F;
+        match self {
+            Self::Truncate => (value << 3) as u8,
+            Self::Round => ((value * 255 + 15) / 31) as u8,
+        }
+    }
+}
+
+/// Convert an RGB888 color to the VRAM 15-bit format
+///
+/// # Arguments
+///
+/// * `r` - Red channel (0-255)
+/// * `g` - Green channel (0-255)
+/// * `b` - Blue channel (0-255)
+/// * `rounding` - Truncate like the GPU, or round to nearest
+///
+/// # Returns
+///
+/// 16-bit color in 5-5-5 RGB format (mask bit 15 clear)
+///
+/// # Example
+///
+/// ```
+/// use psrx::core::gpu::{rgb888_to_rgb555, ColorRounding};
+///
+/// assert_eq!(rgb888_to_rgb555(0xFF, 0x80, 0x06, ColorRounding::Truncate), 0x021F);
+/// assert_eq!(rgb888_to_rgb555(0xFF, 0x80, 0x06, ColorRounding::Round), 0x061F);
+/// ```
+pub fn rgb888_to_rgb555(r: u8, g: u8, b: u8, rounding: ColorRounding) -> u16 {
+    (rounding.to_5bit(b) << 10) | (rounding.to_5bit(g) << 5) | rounding.to_5bit(r)
+}
+
+/// Convert a VRAM 15-bit color to RGB888
+///
+/// The mask bit (bit 15) is ignored.
+///
+/// # Arguments
+///
+/// * `pixel` - 16-bit color in 5-5-5 RGB format
+/// * `rounding` - Shift like the GPU, or expand to the nearest value
+///
+/// # Returns
+///
+/// Tuple (r, g, b) with 8-bit channels
+///
+/// # Example
+///
+/// ```
+/// use psrx::core::gpu::{rgb555_to_rgb888, ColorRounding};
+///
+/// assert_eq!(rgb555_to_rgb888(0x7FFF, ColorRounding::Truncate), (248, 248, 248));
+/// assert_eq!(rgb555_to_rgb888(0x7FFF, ColorRounding::Round), (255, 255, 255));
+/// ```
+pub fn rgb555_to_rgb888(pixel: u16, rounding: ColorRounding) -> (u8, u8, u8) {
+    (
+        rounding.to_8bit(pixel),
+        rounding.to_8bit(pixel >> 5),
+        rounding.to_8bit(pixel >> 10),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODES: [ColorRounding; 2] = [ColorRounding::Truncate, ColorRounding::Round];
+
+    #[test]
+    fn test_rgb555_round_trip_is_exact_in_both_modes() {
+        for mode in MODES {
+            for pixel in 0..0x8000u16 {
+                let (r, g, b) = rgb555_to_rgb888(pixel, mode);
+                assert_eq!(rgb888_to_rgb555(r, g, b, mode), pixel, "{:?}", mode);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rgb888_round_trip_is_stable_for_representative_colors() {
+        let colors = [
+            (0x00, 0x00, 0x00),
+            (0xFF, 0xFF, 0xFF),
+            (0xFF, 0x00, 0x00),
+            (0x00, 0xFF, 0x00),
+            (0x00, 0x00, 0xFF),
+            (0x80, 0x80, 0x80),
+            (0x12, 0x9A, 0xE7),
+            (0x07, 0x04, 0xFC),
+        ];
+
+        for mode in MODES {
+            for &(r, g, b) in &colors {
+                // The first conversion quantizes; after that it is fixed
+                let once = rgb555_to_rgb888(rgb888_to_rgb555(r, g, b, mode), mode);
+                let twice = rgb555_to_rgb888(rgb888_to_rgb555(once.0, once.1, once.2, mode), mode);
+                assert_eq!(once, twice, "{:?} {:?}", mode, (r, g, b));
+
+                // Quantization error stays within one 5-bit step
+                let max_error = match mode {
+                    ColorRounding::Truncate => 7,
+                    ColorRounding::Round => 4,
+                };
+                for (a, b) in [(r, once.0), (g, once.1), (b, once.2)] {
+                    assert!(a.abs_diff(b) <= max_error, "{:?} {} -> {}", mode, a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_truncate_matches_gpu_shift() {
+        assert_eq!(
+            rgb888_to_rgb555(0x40, 0x80, 0xFF, ColorRounding::Truncate),
+            0x7E08
+        );
+        assert_eq!(
+            rgb555_to_rgb888(0x7E08, ColorRounding::Truncate),
+            (0x40, 0x80, 0xF8)
+        );
+    }
+
+    #[test]
+    fn test_round_uses_full_range() {
+        assert_eq!(
+            rgb888_to_rgb555(0xFC, 0x05, 0x03, ColorRounding::Round),
+            0x003F
+        );
+        assert_eq!(rgb555_to_rgb888(0x001F, ColorRounding::Round), (255, 0, 0));
+        assert_eq!(rgb555_to_rgb888(0x8000, ColorRounding::Round), (0, 0, 0));
+    }
+}
@@ -0,0 +1,255 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Completed display frames
+//!
+//! A [`Frame`] is a snapshot of the display area taken at VBlank, already
+//! cropped and converted to RGBA so a frontend can present it without
+//! touching VRAM or GPU registers.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::core::error::{EmulatorError, Result};
+
+/// A completed RGBA frame of display output
+///
+/// Pixels are stored row-major (left-to-right, top-to-bottom). Each pixel
+/// is packed as `u32::from_le_bytes([r, g, b, a])`, so the bytes are in
+/// R, G, B, A order in memory. Alpha is always 0xFF.
+///
+/// # Examples
+///
+/// ```
+/// use psrx::core::gpu::Frame;
+///
+/// let frame = Frame::from_rgb24(1, 1, &[0x10, 0x20, 0x30]);
+/// assert_eq!(frame.pixels[0].to_le_bytes(), [0x10, 0x20, 0x30, 0xFF]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Frame width in pixels
+    pub width: u32,
+
+    /// Frame height in pixels
+    pub height: u32,
+
+    /// RGBA pixels (width × height entries)
+    pub pixels: Vec<u32>,
+}
+
+impl Frame {
+    /// Build a frame from packed RGB24 data
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Frame width in pixels
+    /// * `height` - Frame height in pixels
+    /// * `rgb` - RGB24 data (width × height × 3 bytes), as produced by
+    ///   `GPU::get_framebuffer()`
+    ///
+    /// # Returns
+    ///
+    /// Frame with every pixel fully opaque
+    pub fn from_rgb24(width: u32, height: u32, rgb: &[u8]) -> Self {
+        let pixels = rgb
+            .chunks_exact(3)
+            .map(|p| u32::from_le_bytes([p[0], p[1], p[2], 0xFF]))
+            .collect();
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Encode the frame as an RGB PNG image
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination for the PNG data
+    ///
+    /// # Returns
+    ///
+    /// `Err(EmulatorError::Io)` if encoding or writing fails
+    pub fn write_png<W: Write>(&self, writer: W) -> Result<()> {
+        let rgb: Vec<u8> = self
+            .pixels
+            .iter()
+            .flat_map(|pixel| {
+                let [r, g, b, _] = pixel.to_le_bytes();
+                [r, g, b]
+            })
+            .collect();
+
+        let mut encoder = png::Encoder::new(writer, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        encoder
+            .write_header()
+            .and_then(|mut png| png.write_image_data(&rgb))
+            .map_err(|e| EmulatorError::Io(std::io::Error::other(e)))
+    }
+
+    /// Save the frame to a PNG file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Output file path
+    ///
+    /// # Returns
+    ///
+    /// `Err(EmulatorError::Io)` if the file cannot be written
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_png(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Hash the frame contents
+    ///
+    /// Uses 64-bit FNV-1a over the dimensions and pixels, so the value is
+    /// stable across runs, platforms and compiler versions and can be
+    /// stored as a golden value in tests.
+    ///
+    /// # Returns
+    ///
+    /// 64-bit hash of the frame
+    pub fn hash(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xCBF2_9CE4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+        let header = [self.width, self.height];
+        header
+            .iter()
+            .chain(&self.pixels)
+            .flat_map(|word| word.to_le_bytes())
+            .fold(FNV_OFFSET, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::GPU;
+    use super::*;
+
+    /// Run the GPU up to the start of the next VBlank
+    fn run_to_vblank(gpu: &mut GPU) {
+        while !gpu.tick(1).0 {}
+    }
+
+    #[test]
+    fn test_from_rgb24_packs_opaque_pixels() {
+        let frame = Frame::from_rgb24(2, 1, &[0xF8, 0x00, 0x00, 0x00, 0x00, 0xF8]);
+
+        assert_eq!(frame.pixels, vec![0xFF0000F8, 0xFFF80000]);
+    }
+
+    #[test]
+    fn test_take_frame_once_per_vblank() {
+        let mut gpu = GPU::new();
+        assert!(gpu.take_frame().is_none());
+
+        run_to_vblank(&mut gpu);
+        assert!(gpu.take_frame().is_some());
+        assert!(gpu.take_frame().is_none());
+
+        run_to_vblank(&mut gpu);
+        assert!(gpu.take_frame().is_some());
+    }
+
+    #[test]
+    fn test_take_frame_crops_display_area() {
+        let mut gpu = GPU::new();
+        gpu.write_gp1(0x08000001); // 320×240
+        gpu.write_gp1(0x05000000 | (8 << 10) | 32); // Display start: (32, 8)
+        gpu.write_gp1(0x07000000 | 0x10 | ((0x10 + 100) << 10)); // 100 lines
+        gpu.write_vram(32, 8, 0x03E0); // Green at the display origin
+
+        run_to_vblank(&mut gpu);
+        let frame = gpu.take_frame().unwrap();
+
+        assert_eq!((frame.width, frame.height), (320, 100));
+        assert_eq!(frame.pixels.len(), 320 * 100);
+        assert_eq!(frame.pixels[0].to_le_bytes(), [0x00, 0xF8, 0x00, 0xFF]);
+    }
+
+    #[test]
+    fn test_write_png_round_trip() {
+        let frame = Frame::from_rgb24(2, 1, &[0x10, 0x20, 0x30, 0x40, 0x50, 0x60]);
+        let mut data = Vec::new();
+        frame.write_png(&mut data).unwrap();
+
+        let decoder = png::Decoder::new(std::io::Cursor::new(data));
+        let mut reader = decoder.read_info().unwrap();
+        let mut buf = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut buf).unwrap();
+
+        assert_eq!((info.width, info.height), (2, 1));
+        assert_eq!(info.color_type, png::ColorType::Rgb);
+        assert_eq!(&buf[..6], &[0x10, 0x20, 0x30, 0x40, 0x50, 0x60]);
+    }
+
+    #[test]
+    fn test_vram_region_reads_back_pattern() {
+        let mut gpu = GPU::new();
+        for y in 0..4u16 {
+            for x in 0..3u16 {
+                gpu.write_vram(1022 + x, 100 + y, (y << 8) | x);
+            }
+        }
+
+        // The region crosses the right edge of VRAM
+        let region = gpu.vram_region(1022, 101, 3, 2);
+        assert_eq!(region, vec![0x0100, 0x0101, 0x0102, 0x0200, 0x0201, 0x0202]);
+        assert!(gpu.vram_region(0, 0, 0, 4).is_empty());
+    }
+
+    #[test]
+    fn test_dump_vram_png_writes_full_vram() {
+        let mut gpu = GPU::new();
+        gpu.write_vram(5, 7, 0x801F); // Red with the mask bit set
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        gpu.dump_vram_png(file.path()).unwrap();
+
+        let data = std::fs::read(file.path()).unwrap();
+        let decoder = png::Decoder::new(std::io::Cursor::new(data));
+        let mut reader = decoder.read_info().unwrap();
+        let mut buf = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut buf).unwrap();
+
+        assert_eq!((info.width, info.height), (1024, 512));
+        let offset = (7 * 1024 + 5) * 3;
+        assert_eq!(&buf[offset..offset + 3], &[0xF8, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn test_hash_depends_on_pixels_and_size() {
+        let a = Frame::from_rgb24(2, 1, &[0, 0, 0, 255, 255, 255]);
+        let b = Frame::from_rgb24(2, 1, &[0, 0, 0, 255, 255, 254]);
+        let c = Frame::from_rgb24(1, 2, &[0, 0, 0, 255, 255, 255]);
+
+        assert_eq!(a.hash(), a.clone().hash());
+        assert_ne!(a.hash(), b.hash());
+        assert_ne!(a.hash(), c.hash());
+    }
+}
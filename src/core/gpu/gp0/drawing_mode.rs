@@ -352,6 +352,8 @@ impl GPU {
 
         self.status.set_mask_bit = set_mask_while_drawing;
         self.status.draw_pixels = !check_mask_before_draw;
+        self.rasterizer
+            .set_mask_settings(set_mask_while_drawing, check_mask_before_draw);
 
         log::debug!(
             "Mask settings: set={} check={}",
@@ -577,6 +579,77 @@ mod tests {
         assert!(!gpu.status.draw_pixels);
     }
 
+    #[test]
+    fn test_mask_settings_status_bits() {
+        let mut gpu = GPU::new();
+        assert_eq!(gpu.status() & 0x1800, 0);
+
+        // GPUSTAT bit 11 = set mask, bit 12 = check mask
+        gpu.write_gp0(0xE6000001);
+        assert_eq!(gpu.status() & 0x1800, 0x0800);
+        gpu.write_gp0(0xE6000002);
+        assert_eq!(gpu.status() & 0x1800, 0x1000);
+    }
+
+    #[test]
+    fn test_check_mask_preserves_masked_pixels() {
+        let mut gpu = GPU::new();
+        gpu.write_vram(11, 10, 0x8123); // Masked pixel
+
+        gpu.write_gp0(0xE6000002); // Check mask
+        gpu.write_gp0(0x600000FF); // Variable size rectangle, red
+        gpu.write_gp0(0x000A000A); // Vertex: (10, 10)
+        gpu.write_gp0(0x00040004); // Size: 4×4
+
+        assert_eq!(gpu.read_vram(10, 10), 0x001F);
+        assert_eq!(gpu.read_vram(11, 10), 0x8123);
+
+        // Without checking, masked pixels are overwritten
+        gpu.write_gp0(0xE6000000);
+        gpu.write_gp0(0x600000FF);
+        gpu.write_gp0(0x000A000A);
+        gpu.write_gp0(0x00040004);
+
+        assert_eq!(gpu.read_vram(11, 10), 0x001F);
+    }
+
+    #[test]
+    fn test_set_mask_marks_drawn_pixels() {
+        let mut gpu = GPU::new();
+
+        gpu.write_gp0(0xE6000001); // Set mask
+        gpu.write_gp0(0x20FF0000); // Monochrome triangle, blue
+        gpu.write_gp0(0x00000000); // V1: (0, 0)
+        gpu.write_gp0(0x00000010); // V2: (16, 0)
+        gpu.write_gp0(0x00100000); // V3: (0, 16)
+
+        assert_eq!(gpu.read_vram(2, 2), 0xFC00);
+
+        // A second primitive with check mask enabled cannot overwrite it
+        gpu.write_gp0(0xE6000002);
+        gpu.write_gp0(0x200000FF); // Red
+        gpu.write_gp0(0x00000000);
+        gpu.write_gp0(0x00000010);
+        gpu.write_gp0(0x00100000);
+
+        assert_eq!(gpu.read_vram(2, 2), 0xFC00);
+    }
+
+    #[test]
+    fn test_mask_settings_apply_to_cpu_transfer() {
+        let mut gpu = GPU::new();
+        gpu.write_vram(1, 0, 0x8000);
+
+        gpu.write_gp0(0xE6000003); // Set and check mask
+        gpu.write_gp0(0xA0000000);
+        gpu.write_gp0(0x00000000); // X=0, Y=0
+        gpu.write_gp0(0x00010002); // Width=2, Height=1
+        gpu.write_gp0(0x7FFF001F);
+
+        assert_eq!(gpu.read_vram(0, 0), 0x801F);
+        assert_eq!(gpu.read_vram(1, 0), 0x8000);
+    }
+
     #[test]
     fn test_draw_mode_combined() {
         let mut gpu = GPU::new();
@@ -41,20 +41,22 @@ impl GPU {
     /// Bits 24-31: Command (0x02)
     ///
     /// Parameter 1: Top-Left Corner (X,Y)
-    /// Bits 0-15:  Y coordinate (in VRAM)
-    /// Bits 16-31: X coordinate (in VRAM)
+    /// Bits 0-15:  X coordinate (in VRAM)
+    /// Bits 16-31: Y coordinate (in VRAM)
     ///
     /// Parameter 2: Width + Height
-    /// Bits 0-15:  Height (in pixels)
-    /// Bits 16-31: Width (in pixels)
+    /// Bits 0-15:  Width (in pixels)
+    /// Bits 16-31: Height (in pixels)
     /// ```
     ///
     /// # Hardware Behavior
     ///
-    /// - Coordinate wrapping: Uses raw VRAM coordinates (0-1023, 0-511)
-    /// - Width alignment: Width is rounded up to multiples of 16 pixels
+    /// - X is rounded down to a multiple of 16 (`X AND 3F0h`)
+    /// - Width is rounded up to a multiple of 16 (`((W AND 3FFh) + 15) AND NOT 15`)
+    /// - Y and height are masked to 9 bits; rows past 511 wrap to the top
+    /// - A zero width or height fills nothing
     /// - Ignores settings: Does NOT respect drawing area, drawing offset, or mask bits
-    /// - Color format: RGB color is converted to 15-bit format (5-5-5)
+    /// - Color format: RGB color is converted to 15-bit format (5-5-5), bit 15 clear
     ///
     /// # Examples
     ///
@@ -67,8 +69,10 @@ impl GPU {
     /// gpu.write_gp0(0x00320032); // X=50, Y=50
     /// gpu.write_gp0(0x00640064); // Width=100, Height=100
     ///
-    /// // Verify the fill - note width is aligned to 16 pixels (100 → 112)
-    /// assert_eq!(gpu.read_vram(50, 50), 0x001F); // Red in 15-bit format
+    /// // X is aligned down (50 → 48) and width up (100 → 112)
+    /// assert_eq!(gpu.read_vram(48, 50), 0x001F); // Red in 15-bit format
+    /// assert_eq!(gpu.read_vram(159, 50), 0x001F);
+    /// assert_eq!(gpu.read_vram(160, 50), 0x0000);
     /// ```
     pub(crate) fn gp0_fill_rectangle(&mut self) {
         // Need 3 words for fill command
@@ -93,36 +97,26 @@ impl GPU {
         let b5 = (b >> 3) as u16;
         let color = r5 | (g5 << 5) | (b5 << 10);
 
-        // Extract coordinates - note the bit layout matches PSX-SPX spec
-        // Parameter 1: YyyyXxxx (16-bit Y, 16-bit X)
-        let x = ((coords >> 16) & 0xFFFF) as u16;
-        let y = (coords & 0xFFFF) as u16;
+        // Parameter 1: YyyyXxxx (Y in the high halfword, X in the low)
+        let x = (coords & 0xFFFF) as u16;
+        let y = (coords >> 16) as u16;
 
-        // Extract size - note the bit layout
-        // Parameter 2: HhhhWwww (16-bit Height, 16-bit Width)
-        let width = ((size >> 16) & 0xFFFF) as u16;
-        let height = (size & 0xFFFF) as u16;
+        // Parameter 2: HhhhWwww (height in the high halfword, width in the low)
+        let width = (size & 0xFFFF) as u16;
+        let height = (size >> 16) as u16;
 
-        // Apply VRAM coordinate wrapping
-        let x = x & 0x3FF; // 10-bit (0-1023)
-        let y = y & 0x1FF; // 9-bit (0-511)
-
-        // Apply width/height masking (zero encodes full span on hardware)
-        let width_raw = width & 0x3FF; // 10-bit max (0 ⇒ 1024 pixels)
-        let width = if width_raw == 0 { 0x400 } else { width_raw };
-        let height_raw = height & 0x1FF; // 9-bit max (0 ⇒ 512 pixels)
-        let height = if height_raw == 0 { 0x200 } else { height_raw };
-
-        // Hardware aligns width to 16-pixel boundaries (rounds up)
-        let aligned_width = (width + 15) & !15;
+        // The fill engine works on 16-pixel strips: X rounds down, width up
+        let x = x & 0x3F0;
+        let y = y & 0x1FF;
+        let width = ((width & 0x3FF) + 15) & !15;
+        let height = height & 0x1FF;
 
         log::debug!(
-            "Fill Rectangle: ({}, {}) size {}×{} (aligned width: {}) color=0x{:04X} (RGB {},{},{})",
+            "Fill Rectangle: ({}, {}) size {}×{} color=0x{:04X} (RGB {},{},{})",
             x,
             y,
             width,
             height,
-            aligned_width,
             color,
             r,
             g,
@@ -130,7 +124,7 @@ impl GPU {
         );
 
         // Perform the fill operation
-        self.fill_vram_rect(x, y, aligned_width, height, color);
+        self.fill_vram_rect(x, y, width, height, color);
     }
 
     /// Fill a rectangular region of VRAM with a solid color
@@ -142,8 +136,8 @@ impl GPU {
     ///
     /// * `x` - Top-left X coordinate in VRAM (will be wrapped to 0-1023)
     /// * `y` - Top-left Y coordinate in VRAM (will be wrapped to 0-511)
-    /// * `width` - Width in pixels (should already be 16-pixel aligned)
-    /// * `height` - Height in pixels
+    /// * `width` - Width in pixels (already 16-pixel aligned)
+    /// * `height` - Height in pixels (0 fills nothing)
     /// * `color` - 16-bit color value in 5-5-5 RGB format
     ///
     /// # Note
@@ -171,16 +165,16 @@ mod tests {
     fn test_fill_rectangle_parsing() {
         let mut gpu = GPU::new();
 
-        // Fill 32×32 region at (100, 100) with white (0xFFFFFF)
+        // Fill 32×32 region at (96, 100) with white (0xFFFFFF)
         gpu.write_gp0(0x02FFFFFF); // Command + White color
-        gpu.write_gp0(0x00640064); // X=100, Y=100
+        gpu.write_gp0(0x00640060); // X=96, Y=100
         gpu.write_gp0(0x00200020); // Width=32, Height=32
 
         // Verify pixels are white (0x7FFF in 15-bit format)
-        assert_eq!(gpu.read_vram(100, 100), 0x7FFF); // Top-left
-        assert_eq!(gpu.read_vram(131, 100), 0x7FFF); // Top-right (100 + 31)
-        assert_eq!(gpu.read_vram(100, 131), 0x7FFF); // Bottom-left
-        assert_eq!(gpu.read_vram(131, 131), 0x7FFF); // Bottom-right
+        assert_eq!(gpu.read_vram(96, 100), 0x7FFF); // Top-left
+        assert_eq!(gpu.read_vram(127, 100), 0x7FFF); // Top-right (96 + 31)
+        assert_eq!(gpu.read_vram(96, 131), 0x7FFF); // Bottom-left
+        assert_eq!(gpu.read_vram(127, 131), 0x7FFF); // Bottom-right
     }
 
     #[test]
@@ -189,11 +183,11 @@ mod tests {
 
         // Fill 100×50 region - width should be aligned to 112 pixels (100 rounded up to next 16)
         // Color format: 0xCCBBGGRR (Command, Blue, Green, Red)
-        // Coordinate format: YyyyXxxx (Y in bits 0-15, X in bits 16-31)
-        // Size format: WwwwHhhh (Width in bits 16-31, Height in bits 0-15)
+        // Coordinate format: YyyyXxxx (X in bits 0-15, Y in bits 16-31)
+        // Size format: HhhhWwww (Width in bits 0-15, Height in bits 16-31)
         gpu.write_gp0(0x02FF0000); // Command + Blue color (0x0000FF in RGB)
         gpu.write_gp0(0x00000000); // X=0, Y=0
-        gpu.write_gp0(0x00640032); // Width=100 (0x64), Height=50 (0x32)
+        gpu.write_gp0(0x00320064); // Width=100 (0x64), Height=50 (0x32)
 
         // Verify that width was aligned to 112 pixels
         // Blue = 0xFF >> 3 = 0x1F in 5-bit format, shifted left by 10 = 0x7C00
@@ -201,6 +195,8 @@ mod tests {
         assert_eq!(gpu.read_vram(99, 0), 0x7C00); // Original width
         assert_eq!(gpu.read_vram(111, 0), 0x7C00); // Aligned width (112 - 1)
         assert_eq!(gpu.read_vram(112, 0), 0x0000); // Beyond aligned width
+        assert_eq!(gpu.read_vram(0, 49), 0x7C00); // Last row
+        assert_eq!(gpu.read_vram(0, 50), 0x0000); // Beyond height
     }
 
     #[test]
@@ -269,13 +265,48 @@ mod tests {
     fn test_fill_rectangle_zero_size() {
         let mut gpu = GPU::new();
 
-        // Fill with zero width/height - hardware treats this as full VRAM (1024×512)
+        // Zero width or height fills nothing
         gpu.write_gp0(0x02FFFFFF); // Command + White color
         gpu.write_gp0(0x00000000); // X=0, Y=0
-        gpu.write_gp0(0x00000000); // Width=0, Height=0
+        gpu.write_gp0(0x00100000); // Width=0, Height=16
+        gpu.write_gp0(0x02FFFFFF);
+        gpu.write_gp0(0x00000000);
+        gpu.write_gp0(0x00000010); // Width=16, Height=0
+
+        assert_eq!(gpu.read_vram(0, 0), 0x0000);
+    }
+
+    #[test]
+    fn test_fill_rectangle_hardware_rounding_and_wrap() {
+        let mut gpu = GPU::new();
+        gpu.write_gp0(0xE6000003); // Set and check mask bit
+
+        // X=1013 (→ 1008), Y=510, W=3FFh+10h (→ 16), H=4 (wraps to rows 0-1)
+        gpu.write_gp0(0x021F1F1F);
+        gpu.write_gp0(0x01FE03F5);
+        gpu.write_gp0(0x00040410);
+
+        let color = 0x0C63;
+        for y in [510, 511, 0, 1] {
+            assert_eq!(gpu.read_vram(1008, y), color, "row {}", y);
+            assert_eq!(gpu.read_vram(1023, y), color, "row {}", y);
+            assert_eq!(gpu.read_vram(1007, y), 0, "left of aligned X");
+            assert_eq!(gpu.read_vram(0, y), 0, "X does not wrap past the strip");
+        }
+        assert_eq!(gpu.read_vram(1008, 2), 0);
+        assert_eq!(gpu.read_vram(1008, 509), 0);
+    }
+
+    #[test]
+    fn test_fill_rectangle_ignores_mask_bit() {
+        let mut gpu = GPU::new();
+        gpu.write_vram(32, 8, 0x8000);
+        gpu.write_gp0(0xE6000003); // Set and check mask bit
+
+        gpu.write_gp0(0x02000000); // Black
+        gpu.write_gp0(0x00080020); // X=32, Y=8
+        gpu.write_gp0(0x00010010); // 16×1
 
-        // Zero dimensions map to the full 1024×512 VRAM region
-        assert_eq!(gpu.read_vram(0, 0), 0x7FFF);
-        assert_eq!(gpu.read_vram(1023, 511), 0x7FFF);
+        assert_eq!(gpu.read_vram(32, 8), 0x0000, "masked pixel overwritten");
     }
 }
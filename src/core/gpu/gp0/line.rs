@@ -20,405 +20,101 @@
 use super::super::primitives::{Color, Vertex};
 use super::super::GPU;
 
+/// Check whether a word terminates a poly-line
+///
+/// The GPU stops at any word matching 0x5xxx5xxx, which covers the usual
+/// 0x50005000 and 0x55555555 terminators.
+fn is_polyline_terminator(word: u32) -> bool {
+    word & 0xF000_F000 == 0x5000_5000
+}
+
 impl GPU {
-    /// GP0(0x40): Monochrome Line (Opaque)
+    /// GP0(0x40-0x5F): Line and Poly-line
     ///
-    /// Renders a single line segment with a solid color.
-    /// Requires 3 words: command+color, vertex1, vertex2
+    /// Decodes every line variant from the command bits and renders it with
+    /// Bresenham's algorithm.
     ///
-    /// # Command Format
+    /// # Command Bits
     ///
     /// ```text
-    /// Word 0: 0x40RRGGBB - Command (0x40) + RGB color
-    /// Word 1: YYYYXXXX - Start vertex (X, Y)
-    /// Word 2: YYYYXXXX - End vertex (X, Y)
+    /// Bit 4 (0x10): Gouraud shading (per-vertex colors)
+    /// Bit 3 (0x08): Poly-line (terminated by 0x5xxx5xxx)
+    /// Bit 1 (0x02): Semi-transparent
     /// ```
     ///
-    /// # References
-    ///
-    /// - [PSX-SPX: GPU Line Commands](http://problemkaputt.de/psx-spx.htm#gpurenderlinecommands)
-    pub(crate) fn parse_line_opaque(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return; // Need more words
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let v1 = self.command_fifo.pop_front().unwrap();
-        let v2 = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let vertex1 = Vertex::from_u32(v1);
-        let vertex2 = Vertex::from_u32(v2);
-
-        self.render_line(vertex1, vertex2, color, false);
-    }
-
-    /// GP0(0x42): Monochrome Line (Semi-Transparent)
-    ///
-    /// Renders a single line segment with semi-transparency enabled.
-    /// Requires 3 words: command+color, vertex1, vertex2
-    pub(crate) fn parse_line_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let v1 = self.command_fifo.pop_front().unwrap();
-        let v2 = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let vertex1 = Vertex::from_u32(v1);
-        let vertex2 = Vertex::from_u32(v2);
-
-        self.render_line(vertex1, vertex2, color, true);
-    }
-
-    /// GP0(0x48): Monochrome Polyline (Opaque)
-    ///
-    /// Renders connected line segments (polyline) with a solid color.
-    /// The polyline is terminated by a vertex with coordinate 0x50005000
-    /// or 0x55555555.
+    /// Bits 0 and 2 have no effect on lines.
     ///
     /// # Command Format
     ///
     /// ```text
-    /// Word 0: 0x48RRGGBB - Command (0x48) + RGB color
-    /// Word 1: YYYYXXXX - First vertex (X, Y)
-    /// Word 2: YYYYXXXX - Second vertex (X, Y)
-    /// ...
-    /// Word N: 0x50005000 or 0x55555555 - Terminator
-    /// ```
-    ///
-    /// # Notes
-    ///
-    /// The terminator value signals the end of the polyline.
-    /// We wait for the terminator before processing the polyline.
-    pub(crate) fn parse_polyline_opaque(&mut self) {
-        if self.command_fifo.len() < 4 {
-            return; // Need at least 4 words (command + 2 vertices + terminator)
-        }
-
-        // Check if there's a terminator in the FIFO
-        let has_terminator = self
-            .command_fifo
-            .iter()
-            .skip(1) // Skip command word
-            .any(|&word| word == 0x5000_5000 || word == 0x5555_5555);
-
-        if !has_terminator {
-            return; // Wait for terminator
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let color = Color::from_u32(cmd);
-
-        // Collect vertices until terminator
-        let mut vertices = Vec::new();
-        while let Some(&word) = self.command_fifo.front() {
-            // Check for terminator values
-            if word == 0x5000_5000 || word == 0x5555_5555 {
-                self.command_fifo.pop_front();
-                break;
-            }
-
-            let v = self.command_fifo.pop_front().unwrap();
-            vertices.push(Vertex::from_u32(v));
-
-            // Safety limit to prevent infinite loops
-            if vertices.len() >= 256 {
-                log::warn!("Polyline exceeded 256 vertices, discarding remainder");
-                // Drain remaining vertices and terminator to avoid FIFO desync
-                while let Some(word) = self.command_fifo.pop_front() {
-                    if word == 0x5000_5000 || word == 0x5555_5555 {
-                        break;
-                    }
-                }
-                break;
-            }
-        }
-
-        // Need at least 2 vertices to draw
-        if vertices.len() >= 2 {
-            self.render_polyline(&vertices, color, false);
-        }
-    }
-
-    /// GP0(0x4A): Monochrome Polyline (Semi-Transparent)
-    ///
-    /// Renders connected line segments with semi-transparency enabled.
-    /// Format is identical to 0x48 but with semi-transparency.
-    pub(crate) fn parse_polyline_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 4 {
-            return; // Need at least 4 words (command + 2 vertices + terminator)
-        }
-
-        // Check if there's a terminator in the FIFO
-        let has_terminator = self
-            .command_fifo
-            .iter()
-            .skip(1) // Skip command word
-            .any(|&word| word == 0x5000_5000 || word == 0x5555_5555);
-
-        if !has_terminator {
-            return; // Wait for terminator
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let color = Color::from_u32(cmd);
-
-        // Collect vertices until terminator
-        let mut vertices = Vec::new();
-        while let Some(&word) = self.command_fifo.front() {
-            // Check for terminator values
-            if word == 0x5000_5000 || word == 0x5555_5555 {
-                self.command_fifo.pop_front();
-                break;
-            }
-
-            let v = self.command_fifo.pop_front().unwrap();
-            vertices.push(Vertex::from_u32(v));
-
-            // Safety limit
-            if vertices.len() >= 256 {
-                log::warn!("Polyline exceeded 256 vertices, discarding remainder");
-                // Drain remaining vertices and terminator to avoid FIFO desync
-                while let Some(word) = self.command_fifo.pop_front() {
-                    if word == 0x5000_5000 || word == 0x5555_5555 {
-                        break;
-                    }
-                }
-                break;
-            }
-        }
-
-        if vertices.len() >= 2 {
-            self.render_polyline(&vertices, color, true);
-        }
-    }
-
-    /// GP0(0x50): Shaded Line (Opaque)
-    ///
-    /// Renders a single line segment with Gouraud shading (gradient color interpolation).
-    /// Requires 4 words: command+color1, vertex1, color2, vertex2
-    ///
-    /// # Command Format
-    ///
-    /// ```text
-    /// Word 0: 0x50RRGGBB - Command (0x50) + Color1 (RGB)
+    /// Word 0: CCRRGGBB - Command + Color1 (RGB)
     /// Word 1: YYYYXXXX - Vertex1 (X, Y)
-    /// Word 2: 0x00RRGGBB - Color2 (RGB)
-    /// Word 3: YYYYXXXX - Vertex2 (X, Y)
+    /// [Gouraud only: 00RRGGBB - Color2]
+    /// Word 2: YYYYXXXX - Vertex2 (X, Y)
+    /// [Poly-line only: further (Color, Vertex) entries]
+    /// [Poly-line only: 0x5xxx5xxx - Terminator]
     /// ```
     ///
+    /// Single lines take 3 words (4 with Gouraud shading). Poly-lines are
+    /// buffered until the terminator arrives; the terminator is only
+    /// recognized where a new vertex (or its color) would start, after the
+    /// first two vertices.
+    ///
     /// # References
     ///
     /// - [PSX-SPX: GPU Line Commands](http://problemkaputt.de/psx-spx.htm#gpurenderlinecommands)
-    pub(crate) fn parse_shaded_line_opaque(&mut self) {
-        if self.command_fifo.len() < 4 {
-            return; // Need more words
-        }
-
-        let c0v0 = self.command_fifo.pop_front().unwrap();
-        let v1 = self.command_fifo.pop_front().unwrap();
-        let c1v1 = self.command_fifo.pop_front().unwrap();
-        let v2 = self.command_fifo.pop_front().unwrap();
-
-        let color1 = Color::from_u32(c0v0);
-        let vertex1 = Vertex::from_u32(v1);
-        let color2 = Color::from_u32(c1v1);
-        let vertex2 = Vertex::from_u32(v2);
-
-        self.render_shaded_line(vertex1, color1, vertex2, color2, false);
-    }
-
-    /// GP0(0x52): Shaded Line (Semi-Transparent)
-    ///
-    /// Renders a single line segment with Gouraud shading and semi-transparency enabled.
-    /// Requires 4 words: command+color1, vertex1, color2, vertex2
-    pub(crate) fn parse_shaded_line_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 4 {
-            return;
-        }
-
-        let c0v0 = self.command_fifo.pop_front().unwrap();
-        let v1 = self.command_fifo.pop_front().unwrap();
-        let c1v1 = self.command_fifo.pop_front().unwrap();
-        let v2 = self.command_fifo.pop_front().unwrap();
-
-        let color1 = Color::from_u32(c0v0);
-        let vertex1 = Vertex::from_u32(v1);
-        let color2 = Color::from_u32(c1v1);
-        let vertex2 = Vertex::from_u32(v2);
-
-        self.render_shaded_line(vertex1, color1, vertex2, color2, true);
-    }
-
-    /// GP0(0x58): Shaded Polyline (Opaque)
-    ///
-    /// Renders connected line segments with Gouraud shading (per-vertex colors).
-    /// The polyline is terminated by 0x50005000 or 0x55555555.
-    ///
-    /// # Command Format
-    ///
-    /// ```text
-    /// Word 0: 0x58RRGGBB - Command (0x58) + Color1 (RGB)
-    /// Word 1: YYYYXXXX - Vertex1 (X, Y)
-    /// Word 2: 0x00RRGGBB - Color2 (RGB)
-    /// Word 3: YYYYXXXX - Vertex2 (X, Y)
-    /// ...
-    /// Word N: 0x50005000 or 0x55555555 - Terminator
-    /// ```
-    ///
-    /// # Notes
-    ///
-    /// The terminator value signals the end of the polyline.
-    /// We wait for the terminator before processing the polyline.
-    pub(crate) fn parse_shaded_polyline_opaque(&mut self) {
-        if self.command_fifo.len() < 5 {
-            return; // Need at least 5 words (command+color1 + vertex1 + color2 + vertex2 + terminator)
-        }
-
-        // Check if there's a terminator in the FIFO
-        let has_terminator = self
-            .command_fifo
-            .iter()
-            .skip(1) // Skip command word
-            .any(|&word| word == 0x5000_5000 || word == 0x5555_5555);
-
-        if !has_terminator {
-            return; // Wait for terminator
-        }
-
-        let c0v0 = self.command_fifo.pop_front().unwrap();
-        let first_color = Color::from_u32(c0v0);
-        let first_vertex = Vertex::from_u32(self.command_fifo.pop_front().unwrap());
-
-        // Collect color-vertex pairs until terminator
-        let mut vertices = vec![first_vertex];
-        let mut colors = vec![first_color];
-
-        while let Some(&word) = self.command_fifo.front() {
-            // Check for terminator values
-            if word == 0x5000_5000 || word == 0x5555_5555 {
-                self.command_fifo.pop_front();
-                break;
-            }
-
-            // Read color+vertex pair
-            let color_word = self.command_fifo.pop_front().unwrap();
-
-            // Check if next word exists and is a terminator before committing the color
-            if let Some(&vertex_word) = self.command_fifo.front() {
-                if vertex_word == 0x5000_5000 || vertex_word == 0x5555_5555 {
-                    // Terminator follows color - malformed command
-                    // Don't add color without matching vertex to maintain colors.len() == vertices.len()
-                    self.command_fifo.pop_front(); // Pop terminator to maintain FIFO sync
-                    log::warn!(
-                        "Shaded polyline has color without matching vertex before terminator"
-                    );
-                    break;
-                }
-                let vertex_word = self.command_fifo.pop_front().unwrap();
-                colors.push(Color::from_u32(color_word));
-                vertices.push(Vertex::from_u32(vertex_word));
-            } else {
-                // No more words after color - shouldn't happen as we checked for terminator
-                log::warn!("Shaded polyline color without vertex at end of FIFO");
-                break;
-            }
-
-            // Safety limit to prevent infinite loops
-            if vertices.len() >= 256 {
-                log::warn!("Shaded polyline exceeded 256 vertices, discarding remainder");
-                // Drain remaining words and terminator to avoid FIFO desync
-                while let Some(word) = self.command_fifo.pop_front() {
-                    if word == 0x5000_5000 || word == 0x5555_5555 {
-                        break;
-                    }
+    pub(crate) fn parse_line(&mut self) {
+        let command = self.command_fifo[0] >> 24;
+        let gouraud = command & 0x10 != 0;
+        let polyline = command & 0x08 != 0;
+        let semi_transparent = command & 0x02 != 0;
+
+        let words_per_vertex = if gouraud { 2 } else { 1 };
+
+        // Command word plus the first two vertices
+        let mut word_count = 2 + words_per_vertex;
+        if polyline {
+            loop {
+                match self.command_fifo.get(word_count) {
+                    None => return, // Wait for more vertices or the terminator
+                    Some(&word) if is_polyline_terminator(word) => break,
+                    Some(_) => word_count += words_per_vertex,
                 }
-                break;
             }
+        } else if self.command_fifo.len() < word_count {
+            return; // Need more words
         }
 
-        // Need at least 2 vertices to draw
-        if vertices.len() >= 2 && colors.len() >= 2 {
-            self.render_shaded_polyline(&vertices, &colors, false);
-        }
-    }
-
-    /// GP0(0x5A): Shaded Polyline (Semi-Transparent)
-    ///
-    /// Renders connected line segments with Gouraud shading and semi-transparency enabled.
-    /// Format is identical to 0x58 but with semi-transparency.
-    pub(crate) fn parse_shaded_polyline_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 5 {
-            return; // Need at least 5 words (command+color1 + vertex1 + color2 + vertex2 + terminator)
-        }
-
-        // Check if there's a terminator in the FIFO
-        let has_terminator = self
-            .command_fifo
-            .iter()
-            .skip(1) // Skip command word
-            .any(|&word| word == 0x5000_5000 || word == 0x5555_5555);
-
-        if !has_terminator {
-            return; // Wait for terminator
+        let words: Vec<u32> = self.command_fifo.drain(..word_count).collect();
+        if polyline {
+            self.command_fifo.pop_front(); // Terminator
         }
 
-        let c0v0 = self.command_fifo.pop_front().unwrap();
-        let first_color = Color::from_u32(c0v0);
-        let first_vertex = Vertex::from_u32(self.command_fifo.pop_front().unwrap());
-
-        // Collect color-vertex pairs until terminator
-        let mut vertices = vec![first_vertex];
-        let mut colors = vec![first_color];
-
-        while let Some(&word) = self.command_fifo.front() {
-            // Check for terminator values
-            if word == 0x5000_5000 || word == 0x5555_5555 {
-                self.command_fifo.pop_front();
-                break;
-            }
-
-            // Read color+vertex pair
-            let color_word = self.command_fifo.pop_front().unwrap();
-
-            // Check if next word exists and is a terminator before committing the color
-            if let Some(&vertex_word) = self.command_fifo.front() {
-                if vertex_word == 0x5000_5000 || vertex_word == 0x5555_5555 {
-                    // Terminator follows color - malformed command
-                    // Don't add color without matching vertex to maintain colors.len() == vertices.len()
-                    self.command_fifo.pop_front(); // Pop terminator to maintain FIFO sync
-                    log::warn!(
-                        "Shaded polyline has color without matching vertex before terminator"
-                    );
-                    break;
-                }
-                let vertex_word = self.command_fifo.pop_front().unwrap();
-                colors.push(Color::from_u32(color_word));
-                vertices.push(Vertex::from_u32(vertex_word));
+        let mut vertices = vec![Vertex::from_u32(words[1])];
+        let mut colors = vec![Color::from_u32(words[0])];
+        for entry in words[2..].chunks(words_per_vertex) {
+            // Flat lines reuse the command color for every vertex
+            colors.push(if gouraud {
+                Color::from_u32(entry[0])
             } else {
-                // No more words after color - shouldn't happen as we checked for terminator
-                log::warn!("Shaded polyline color without vertex at end of FIFO");
-                break;
-            }
-
-            // Safety limit
-            if vertices.len() >= 256 {
-                log::warn!("Shaded polyline exceeded 256 vertices, discarding remainder");
-                // Drain remaining words and terminator to avoid FIFO desync
-                while let Some(word) = self.command_fifo.pop_front() {
-                    if word == 0x5000_5000 || word == 0x5555_5555 {
-                        break;
-                    }
-                }
-                break;
-            }
+                colors[0]
+            });
+            vertices.push(Vertex::from_u32(entry[words_per_vertex - 1]));
         }
 
-        if vertices.len() >= 2 && colors.len() >= 2 {
-            self.render_shaded_polyline(&vertices, &colors, true);
+        match (polyline, gouraud) {
+            (false, false) => {
+                self.render_line(vertices[0], vertices[1], colors[0], semi_transparent)
+            }
+            (false, true) => self.render_shaded_line(
+                vertices[0],
+                colors[0],
+                vertices[1],
+                colors[1],
+                semi_transparent,
+            ),
+            (true, false) => self.render_polyline(&vertices, colors[0], semi_transparent),
+            (true, true) => self.render_shaded_polyline(&vertices, &colors, semi_transparent),
         }
     }
 }
@@ -666,4 +362,95 @@ mod tests {
 
         assert!(gpu.command_fifo.is_empty());
     }
+
+    #[test]
+    fn test_shaded_line_diagonal_endpoint_colors() {
+        let mut gpu = GPU::new();
+
+        gpu.write_gp0(0x500000FF); // Command + Color1 (Red)
+        gpu.write_gp0(0x000A000A); // V1: (10, 10)
+        gpu.write_gp0(0x00FF0000); // Color2 (Blue)
+        gpu.write_gp0(0x00140014); // V2: (20, 20)
+
+        assert_eq!(gpu.read_vram(10, 10), 0x001F);
+        assert_eq!(gpu.read_vram(20, 20), 0x7C00);
+
+        // Halfway: R = 255 - 255*5/10 = 128, B = 127
+        assert_eq!(gpu.read_vram(15, 15), (15 << 10) | 16);
+    }
+
+    #[test]
+    fn test_raw_bit_ignored_for_lines() {
+        let mut gpu = GPU::new();
+
+        // GP0(0x41) behaves like 0x40
+        gpu.write_gp0(0x41FFFFFF);
+        gpu.write_gp0(0x00050005);
+        gpu.write_gp0(0x00050008);
+
+        assert!(gpu.command_fifo.is_empty());
+        assert_eq!(gpu.read_vram(8, 5), 0x7FFF);
+    }
+
+    #[test]
+    fn test_polyline_terminator_masked() {
+        let mut gpu = GPU::new();
+
+        // Any 0x5xxx5xxx word ends a poly-line
+        gpu.write_gp0(0x4CFFFFFF);
+        gpu.write_gp0(0x00000000); // V1: (0, 0)
+        gpu.write_gp0(0x00000004); // V2: (4, 0)
+        gpu.write_gp0(0x00040004); // V3: (4, 4)
+        assert_eq!(gpu.command_fifo.len(), 4);
+
+        gpu.write_gp0(0x51235456); // Terminator
+
+        assert!(gpu.command_fifo.is_empty());
+        assert_eq!(gpu.read_vram(4, 4), 0x7FFF);
+    }
+
+    #[test]
+    fn test_semi_transparent_line_blends() {
+        let mut gpu = GPU::new();
+        gpu.write_vram(3, 0, 0x7FFF);
+
+        // Mode 0 (average): black over white gives half intensity
+        gpu.write_gp0(0xE1000000);
+        gpu.write_gp0(0x42000000);
+        gpu.write_gp0(0x00000000); // V1: (0, 0)
+        gpu.write_gp0(0x00000003); // V2: (3, 0)
+
+        assert_eq!(gpu.read_vram(3, 0), 0x3DEF);
+    }
+
+    #[test]
+    fn test_shaded_line_dithering() {
+        let mut gpu = GPU::new();
+
+        gpu.write_gp0(0xE1000200); // Dithering enabled
+        gpu.write_gp0(0x50808080); // Gray (128)
+        gpu.write_gp0(0x00000000); // V1: (0, 0)
+        gpu.write_gp0(0x00808080); // Gray (128)
+        gpu.write_gp0(0x00000003); // V2: (3, 0)
+
+        // Dither offsets on row 0: -4, 0, -3, +1
+        let gray = |c: u16| (c << 10) | (c << 5) | c;
+        assert_eq!(gpu.read_vram(0, 0), gray(15));
+        assert_eq!(gpu.read_vram(1, 0), gray(16));
+        assert_eq!(gpu.read_vram(2, 0), gray(15));
+        assert_eq!(gpu.read_vram(3, 0), gray(16));
+    }
+
+    #[test]
+    fn test_line_exceeding_max_width_skipped() {
+        let mut gpu = GPU::new();
+
+        gpu.write_gp0(0xE5000000 | 512); // Offset: (512, 0)
+        gpu.write_gp0(0x40FFFFFF);
+        gpu.write_gp0(0x000A0600); // V1: (-512, 10) => (0, 10)
+        gpu.write_gp0(0x000A0200); // V2: (512, 10) => (1024, 10)
+
+        assert!(gpu.command_fifo.is_empty());
+        assert_eq!(gpu.read_vram(100, 10), 0x0000);
+    }
 }
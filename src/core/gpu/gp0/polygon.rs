@@ -278,23 +278,33 @@ impl GPU {
         self.render_gradient_quad(&vertices, &colors, true);
     }
 
-    /// GP0(0x24): Textured Triangle (Opaque)
+    /// GP0(0x24-0x27, 0x2C-0x2F, 0x34-0x37, 0x3C-0x3F): Textured Polygon
     ///
-    /// Renders a textured triangle with texture mapping.
-    /// Requires 7 words: command+color, vertex1+texcoord1, clut, vertex2+texcoord2, tpage, vertex3+texcoord3
+    /// Renders a textured triangle or quadrilateral. The command byte selects
+    /// the variant:
+    ///
+    /// - Bit 4 (0x10): Gouraud shading (one color per vertex)
+    /// - Bit 3 (0x08): Quadrilateral (4 vertices) instead of triangle
+    /// - Bit 1 (0x02): Semi-transparent
+    /// - Bit 0 (0x01): Raw texture (no color modulation)
     ///
     /// # Command Format
     ///
     /// ```text
-    /// Word 0: 0x24RRGGBB - Command (0x24) + Color (RGB tint)
+    /// Word 0: 0xCCRRGGBB - Command + Color1 (modulation tint)
     /// Word 1: YYYYXXXX - Vertex1 (X, Y)
     /// Word 2: CLUTVVUU - CLUT info (bits 16-31) + TexCoord1 (U, V)
+    /// [Gouraud only: 00RRGGBB - Color2]
     /// Word 3: YYYYXXXX - Vertex2 (X, Y)
     /// Word 4: PAGEVVUU - Texture Page (bits 16-31) + TexCoord2 (U, V)
+    /// [Gouraud only: 00RRGGBB - Color3]
     /// Word 5: YYYYXXXX - Vertex3 (X, Y)
     /// Word 6: ----VVUU - TexCoord3 (U, V)
+    /// [Quad only: Color4 (Gouraud), Vertex4, TexCoord4]
     /// ```
     ///
+    /// Triangles take 7 words (9 with Gouraud shading), quads 9 (12).
+    ///
     /// # CLUT and Texture Page Encoding
     ///
     /// Word 2 (CLUT):
@@ -304,215 +314,100 @@ impl GPU {
     /// Word 4 (Texture Page):
     /// - Bits 16-19: Texture page X base (N×64)
     /// - Bit 20: Texture page Y base (0=Y0-255, 1=Y256-511)
-    /// - Bits 21-22: Semi-transparency mode (ignored for opaque)
+    /// - Bits 21-22: Semi-transparency mode
     /// - Bits 23-24: Texture depth (0=4bit, 1=8bit, 2=15bit)
     ///
+    /// The texture page attribute also updates the draw mode (as GP0(E1h)
+    /// would) for texture page, semi-transparency and depth.
+    ///
     /// # References
     ///
-    /// - [PSX-SPX: GPU Texture Commands](http://problemkaputt.de/psx-spx.htm#gputextureattributes)
-    pub(crate) fn parse_textured_triangle_opaque(&mut self) {
-        if self.command_fifo.len() < 7 {
+    /// - [PSX-SPX: GPU Render Polygon Commands](http://problemkaputt.de/psx-spx.htm#gpurenderpolygoncommands)
+    pub(crate) fn parse_textured_polygon(&mut self) {
+        let command = self.command_fifo[0] >> 24;
+        let gouraud = command & 0x10 != 0;
+        let vertex_count = if command & 0x08 != 0 { 4 } else { 3 };
+        let semi_transparent = command & 0x02 != 0;
+        let raw = command & 0x01 != 0;
+
+        let word_count = 1 + vertex_count * 2 + if gouraud { vertex_count - 1 } else { 0 };
+        if self.command_fifo.len() < word_count {
             return; // Need more words
         }
 
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let v0 = self.command_fifo.pop_front().unwrap();
-        let t0clut = self.command_fifo.pop_front().unwrap();
-        let v1 = self.command_fifo.pop_front().unwrap();
-        let t1page = self.command_fifo.pop_front().unwrap();
-        let v2 = self.command_fifo.pop_front().unwrap();
-        let t2 = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let vertices = [
-            Vertex::from_u32(v0),
-            Vertex::from_u32(v1),
-            Vertex::from_u32(v2),
-        ];
-        let texcoords = [
-            TexCoord::from_u32(t0clut),
-            TexCoord::from_u32(t1page),
-            TexCoord::from_u32(t2),
-        ];
-
-        // Extract CLUT coordinates from word 2
-        let clut_x = ((t0clut >> 16) & 0x3F) * 16;
-        let clut_y = (t0clut >> 22) & 0x1FF;
-
-        // Extract texture page information from word 4
-        let page_x = ((t1page >> 16) & 0xF) * 64;
-        let page_y = ((t1page >> 20) & 1) * 256;
-        let tex_depth = ((t1page >> 23) & 0x3) as u8;
-
-        let texture_info = TextureInfo {
-            page_x: page_x as u16,
-            page_y: page_y as u16,
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            depth: tex_depth.into(),
-        };
-
-        self.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
-    }
-
-    /// GP0(0x26): Textured Triangle (Semi-Transparent)
-    ///
-    /// Renders a textured triangle with semi-transparency enabled.
-    /// Same format as 0x24, but with semi-transparency blending applied.
-    pub(crate) fn parse_textured_triangle_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 7 {
-            return;
+        let mut colors = [Color::from_u32(0); 4];
+        let mut vertices = [Vertex::from_u32(0); 4];
+        let mut texcoords = [TexCoord::from_u32(0); 4];
+        let mut attributes = [0u32; 2];
+
+        for i in 0..vertex_count {
+            // Flat shading reuses the first color for every vertex
+            colors[i] = if i == 0 || gouraud {
+                Color::from_u32(self.command_fifo.pop_front().unwrap())
+            } else {
+                colors[0]
+            };
+            vertices[i] = Vertex::from_u32(self.command_fifo.pop_front().unwrap());
+            let tex_word = self.command_fifo.pop_front().unwrap();
+            texcoords[i] = TexCoord::from_u32(tex_word);
+            if i < 2 {
+                attributes[i] = tex_word >> 16;
+            }
         }
 
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let v0 = self.command_fifo.pop_front().unwrap();
-        let t0clut = self.command_fifo.pop_front().unwrap();
-        let v1 = self.command_fifo.pop_front().unwrap();
-        let t1page = self.command_fifo.pop_front().unwrap();
-        let v2 = self.command_fifo.pop_front().unwrap();
-        let t2 = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let vertices = [
-            Vertex::from_u32(v0),
-            Vertex::from_u32(v1),
-            Vertex::from_u32(v2),
-        ];
-        let texcoords = [
-            TexCoord::from_u32(t0clut),
-            TexCoord::from_u32(t1page),
-            TexCoord::from_u32(t2),
-        ];
-
-        let clut_x = ((t0clut >> 16) & 0x3F) * 16;
-        let clut_y = (t0clut >> 22) & 0x1FF;
-        let page_x = ((t1page >> 16) & 0xF) * 64;
-        let page_y = ((t1page >> 20) & 1) * 256;
-        let tex_depth = ((t1page >> 23) & 0x3) as u8;
+        let [clut, texpage] = attributes;
+        self.apply_texpage_attribute(texpage);
 
         let texture_info = TextureInfo {
-            page_x: page_x as u16,
-            page_y: page_y as u16,
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            depth: tex_depth.into(),
+            page_x: ((texpage & 0xF) * 64) as u16,
+            page_y: (((texpage >> 4) & 1) * 256) as u16,
+            clut_x: ((clut & 0x3F) * 16) as u16,
+            clut_y: ((clut >> 6) & 0x1FF) as u16,
+            depth: (((texpage >> 7) & 0x3) as u8).into(),
         };
 
-        self.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, true);
+        if vertex_count == 4 {
+            self.render_textured_quad(
+                &vertices,
+                &texcoords,
+                &colors,
+                &texture_info,
+                semi_transparent,
+                !raw,
+            );
+        } else {
+            self.render_textured_triangle(
+                &[vertices[0], vertices[1], vertices[2]],
+                &[texcoords[0], texcoords[1], texcoords[2]],
+                &[colors[0], colors[1], colors[2]],
+                &texture_info,
+                semi_transparent,
+                !raw,
+            );
+        }
     }
 
-    /// GP0(0x2C): Textured Quadrilateral (Opaque)
+    /// Apply a polygon's texture page attribute to the draw mode
     ///
-    /// Renders a textured quadrilateral with texture mapping.
-    /// Requires 9 words: command+color, 4×(vertex+texcoord), with CLUT and texture page info
+    /// Textured polygons carry their own texpage, which replaces GPUSTAT
+    /// bits 0-8 the same way GP0(E1h) does.
     ///
-    /// # Command Format
+    /// # Arguments
     ///
-    /// ```text
-    /// Word 0: 0x2CRRGGBB - Command (0x2C) + Color (RGB tint)
-    /// Word 1: YYYYXXXX - Vertex1 (X, Y)
-    /// Word 2: CLUTVVUU - CLUT info + TexCoord1 (U, V)
-    /// Word 3: YYYYXXXX - Vertex2 (X, Y)
-    /// Word 4: PAGEVVUU - Texture Page + TexCoord2 (U, V)
-    /// Word 5: YYYYXXXX - Vertex3 (X, Y)
-    /// Word 6: ----VVUU - TexCoord3 (U, V)
-    /// Word 7: YYYYXXXX - Vertex4 (X, Y)
-    /// Word 8: ----VVUU - TexCoord4 (U, V)
-    /// ```
-    pub(crate) fn parse_textured_quad_opaque(&mut self) {
-        if self.command_fifo.len() < 9 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let v0 = self.command_fifo.pop_front().unwrap();
-        let t0clut = self.command_fifo.pop_front().unwrap();
-        let v1 = self.command_fifo.pop_front().unwrap();
-        let t1page = self.command_fifo.pop_front().unwrap();
-        let v2 = self.command_fifo.pop_front().unwrap();
-        let t2 = self.command_fifo.pop_front().unwrap();
-        let v3 = self.command_fifo.pop_front().unwrap();
-        let t3 = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let vertices = [
-            Vertex::from_u32(v0),
-            Vertex::from_u32(v1),
-            Vertex::from_u32(v2),
-            Vertex::from_u32(v3),
-        ];
-        let texcoords = [
-            TexCoord::from_u32(t0clut),
-            TexCoord::from_u32(t1page),
-            TexCoord::from_u32(t2),
-            TexCoord::from_u32(t3),
-        ];
-
-        let clut_x = ((t0clut >> 16) & 0x3F) * 16;
-        let clut_y = (t0clut >> 22) & 0x1FF;
-        let page_x = ((t1page >> 16) & 0xF) * 64;
-        let page_y = ((t1page >> 20) & 1) * 256;
-        let tex_depth = ((t1page >> 23) & 0x3) as u8;
-
-        let texture_info = TextureInfo {
-            page_x: page_x as u16,
-            page_y: page_y as u16,
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            depth: tex_depth.into(),
-        };
-
-        self.render_textured_quad(&vertices, &texcoords, &texture_info, &color, false);
-    }
-
-    /// GP0(0x2E): Textured Quadrilateral (Semi-Transparent)
-    ///
-    /// Renders a textured quadrilateral with semi-transparency enabled.
-    /// Same format as 0x2C, but with semi-transparency blending applied.
-    pub(crate) fn parse_textured_quad_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 9 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let v0 = self.command_fifo.pop_front().unwrap();
-        let t0clut = self.command_fifo.pop_front().unwrap();
-        let v1 = self.command_fifo.pop_front().unwrap();
-        let t1page = self.command_fifo.pop_front().unwrap();
-        let v2 = self.command_fifo.pop_front().unwrap();
-        let t2 = self.command_fifo.pop_front().unwrap();
-        let v3 = self.command_fifo.pop_front().unwrap();
-        let t3 = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let vertices = [
-            Vertex::from_u32(v0),
-            Vertex::from_u32(v1),
-            Vertex::from_u32(v2),
-            Vertex::from_u32(v3),
-        ];
-        let texcoords = [
-            TexCoord::from_u32(t0clut),
-            TexCoord::from_u32(t1page),
-            TexCoord::from_u32(t2),
-            TexCoord::from_u32(t3),
-        ];
-
-        let clut_x = ((t0clut >> 16) & 0x3F) * 16;
-        let clut_y = (t0clut >> 22) & 0x1FF;
-        let page_x = ((t1page >> 16) & 0xF) * 64;
-        let page_y = ((t1page >> 20) & 1) * 256;
-        let tex_depth = ((t1page >> 23) & 0x3) as u8;
-
-        let texture_info = TextureInfo {
-            page_x: page_x as u16,
-            page_y: page_y as u16,
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            depth: tex_depth.into(),
-        };
-
-        self.render_textured_quad(&vertices, &texcoords, &texture_info, &color, true);
+    /// * `texpage` - Texture page attribute (upper half of the second texcoord word)
+    fn apply_texpage_attribute(&mut self, texpage: u32) {
+        let semi_transparency = ((texpage >> 5) & 3) as u8;
+        let texture_depth = ((texpage >> 7) & 3) as u8;
+
+        self.draw_mode.texture_page_x_base = (texpage & 0xF) as u16 * 64;
+        self.draw_mode.texture_page_y_base = ((texpage >> 4) & 1) as u16 * 256;
+        self.draw_mode.semi_transparency = semi_transparency;
+        self.draw_mode.texture_depth = texture_depth;
+
+        self.status.texture_page_x_base = (texpage & 0xF) as u8;
+        self.status.texture_page_y_base = ((texpage >> 4) & 1) as u8;
+        self.status.semi_transparency = semi_transparency;
+        self.status.texture_depth = texture_depth;
     }
 }
 
@@ -779,4 +674,155 @@ mod tests {
         // Now FIFO should be empty
         assert!(gpu.command_fifo.is_empty());
     }
+
+    /// Set up a 16×16 4-bit texture at page (256, 0) with a CLUT at (0, 480)
+    ///
+    /// Texels with U < 8 use CLUT index 1, the rest index 2.
+    fn setup_4bit_texture(gpu: &mut GPU, clut1: u16, clut2: u16) {
+        for v in 0..16 {
+            for word in 0..4 {
+                let indices = if word < 2 { 0x1111 } else { 0x2222 };
+                gpu.write_vram(256 + word, v, indices);
+            }
+        }
+        gpu.write_vram(1, 480, clut1);
+        gpu.write_vram(2, 480, clut2);
+    }
+
+    /// CLUT attribute (X=0, Y=480) in the upper half of a texcoord word
+    const CLUT_480: u32 = 480 << 22;
+
+    /// Texture page attribute for X=256 (page 4), 4-bit
+    const PAGE_256: u32 = 4 << 16;
+
+    #[test]
+    fn test_textured_quad_samples_4bit_texture() {
+        let mut gpu = GPU::new();
+        setup_4bit_texture(&mut gpu, 0x001F, 0x03E0); // red, green
+
+        gpu.write_gp0(0x2C808080); // Textured quad, neutral modulation
+        gpu.write_gp0(0x000A000A); // V1: (10, 10)
+        gpu.write_gp0(CLUT_480); // CLUT + UV (0, 0)
+        gpu.write_gp0(0x000A001A); // V2: (26, 10)
+        gpu.write_gp0(PAGE_256 | 0x000F); // Page + UV (15, 0)
+        gpu.write_gp0(0x001A000A); // V3: (10, 26)
+        gpu.write_gp0(0x00000F00); // UV (0, 15)
+        gpu.write_gp0(0x001A001A); // V4: (26, 26)
+        gpu.write_gp0(0x00000F0F); // UV (15, 15)
+
+        assert!(gpu.command_fifo.is_empty());
+        assert_eq!(gpu.read_vram(12, 12), 0x001F, "Left half samples index 1");
+        assert_eq!(gpu.read_vram(23, 20), 0x03E0, "Right half samples index 2");
+        assert_eq!(
+            gpu.read_vram(30, 12),
+            0x0000,
+            "Outside the quad is untouched"
+        );
+
+        // The polygon's texpage is applied to the draw mode
+        assert_eq!(gpu.draw_mode.texture_page_x_base, 256);
+    }
+
+    #[test]
+    fn test_textured_polygon_transparent_texels_skipped() {
+        let mut gpu = GPU::new();
+        setup_4bit_texture(&mut gpu, 0x0000, 0x001F); // index 1 transparent
+        gpu.write_vram(12, 12, 0x1234);
+
+        gpu.write_gp0(0x24808080); // Textured triangle
+        gpu.write_gp0(0x000A000A); // V1: (10, 10)
+        gpu.write_gp0(CLUT_480);
+        gpu.write_gp0(0x000A001A); // V2: (26, 10)
+        gpu.write_gp0(PAGE_256 | 0x000F);
+        gpu.write_gp0(0x001A000A); // V3: (10, 26)
+        gpu.write_gp0(0x00000F00);
+
+        assert_eq!(gpu.read_vram(12, 12), 0x1234);
+        assert_eq!(gpu.read_vram(20, 11), 0x001F);
+    }
+
+    #[test]
+    fn test_raw_textured_quad_ignores_color() {
+        let mut gpu = GPU::new();
+        setup_4bit_texture(&mut gpu, 0x001F, 0x001F);
+
+        gpu.write_gp0(0x2D000000); // Raw textured quad with black color
+        gpu.write_gp0(0x000A000A);
+        gpu.write_gp0(CLUT_480);
+        gpu.write_gp0(0x000A001A);
+        gpu.write_gp0(PAGE_256 | 0x000F);
+        gpu.write_gp0(0x001A000A);
+        gpu.write_gp0(0x00000F00);
+        gpu.write_gp0(0x001A001A);
+        gpu.write_gp0(0x00000F0F);
+
+        assert_eq!(gpu.read_vram(15, 15), 0x001F);
+    }
+
+    #[test]
+    fn test_gouraud_textured_quad_interpolates_modulation() {
+        let mut gpu = GPU::new();
+        setup_4bit_texture(&mut gpu, 0x001F, 0x001F);
+
+        // GP0(0x3C): 12 words, left vertices bright, right vertices dark
+        gpu.write_gp0(0x3C0000FF); // Command + Color1 (r=255)
+        gpu.write_gp0(0x000A000A); // V1: (10, 10)
+        gpu.write_gp0(CLUT_480);
+        gpu.write_gp0(0x00000000); // Color2 (black)
+        gpu.write_gp0(0x000A004A); // V2: (74, 10)
+        gpu.write_gp0(PAGE_256 | 0x000F);
+        gpu.write_gp0(0x000000FF); // Color3 (r=255)
+        gpu.write_gp0(0x004A000A); // V3: (10, 74)
+        gpu.write_gp0(0x00000F00);
+        gpu.write_gp0(0x00000000); // Color4 (black)
+        gpu.write_gp0(0x004A004A); // V4: (74, 74)
+        gpu.write_gp0(0x00000F0F);
+
+        assert!(gpu.command_fifo.is_empty());
+
+        // Red texel (248) at double brightness saturates; fades toward the right
+        let left = gpu.read_vram(11, 40) & 0x1F;
+        let right = gpu.read_vram(72, 40) & 0x1F;
+        assert_eq!(left, 0x1F);
+        assert!(
+            right < 4,
+            "right edge should be nearly black, got {}",
+            right
+        );
+    }
+
+    #[test]
+    fn test_semi_transparent_textured_polygon_blends_masked_texels() {
+        let mut gpu = GPU::new();
+        // Index 1 has the semi-transparency bit set, index 2 does not
+        setup_4bit_texture(&mut gpu, 0x8002, 0x0002);
+        for y in 0..32 {
+            for x in 0..32 {
+                gpu.write_vram(x, y, 0x0001);
+            }
+        }
+
+        // Semi-transparent quad, texpage selects additive blending (mode 1)
+        gpu.write_gp0(0x2E808080);
+        gpu.write_gp0(0x000A000A);
+        gpu.write_gp0(CLUT_480);
+        gpu.write_gp0(0x000A001A);
+        gpu.write_gp0(PAGE_256 | (1 << 21) | 0x000F);
+        gpu.write_gp0(0x001A000A);
+        gpu.write_gp0(0x00000F00);
+        gpu.write_gp0(0x001A001A);
+        gpu.write_gp0(0x00000F0F);
+
+        assert_eq!(gpu.draw_mode.semi_transparency, 1);
+        assert_eq!(
+            gpu.read_vram(12, 12) & 0x7FFF,
+            0x0003,
+            "Masked texel is blended"
+        );
+        assert_eq!(
+            gpu.read_vram(23, 20) & 0x7FFF,
+            0x0002,
+            "Unmasked texel is opaque"
+        );
+    }
 }
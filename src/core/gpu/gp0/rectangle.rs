@@ -21,340 +21,91 @@
 //! - Variable size and fixed size (1×1, 8×8, 16×16)
 
 use super::super::primitives::{Color, TexCoord, TextureInfo, Vertex};
+use super::super::render::{rectangle_pixels, DrawCost};
 use super::super::GPU;
 
 impl GPU {
-    // =========================================================================
-    // Monochrome (Solid Color) Rectangles
-    // =========================================================================
-
-    /// GP0(0x60): Monochrome Rectangle (Variable Size, Opaque)
-    ///
-    /// Renders a solid-color rectangle of variable dimensions.
-    /// Requires 3 words: command+color, vertex, width+height
-    pub(crate) fn parse_monochrome_rect_variable_opaque(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return; // Need more words
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let size = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let width = (size >> 16) as u16;
-        let height = (size & 0xFFFF) as u16;
-
-        self.render_monochrome_rect(pos.x, pos.y, width, height, &color, false);
-    }
-
-    /// GP0(0x62): Monochrome Rectangle (Variable Size, Semi-Transparent)
-    ///
-    /// Renders a solid-color rectangle with semi-transparency enabled.
-    /// Requires 3 words: command+color, vertex, width+height
-    pub(crate) fn parse_monochrome_rect_variable_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let size = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let width = (size >> 16) as u16;
-        let height = (size & 0xFFFF) as u16;
-
-        self.render_monochrome_rect(pos.x, pos.y, width, height, &color, true);
-    }
-
-    /// GP0(0x68): Monochrome Rectangle (1×1, Opaque)
+    /// GP0(0x60-0x7F): Rectangle
     ///
-    /// Renders a single pixel in solid color.
-    /// Requires 2 words: command+color, vertex
-    pub(crate) fn parse_monochrome_rect_1x1_opaque(&mut self) {
-        if self.command_fifo.len() < 2 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-
-        self.render_monochrome_rect(pos.x, pos.y, 1, 1, &color, false);
-    }
-
-    /// GP0(0x6A): Monochrome Rectangle (1×1, Semi-Transparent)
+    /// Renders an axis-aligned rectangle (sprite). The command byte selects
+    /// the variant:
     ///
-    /// Renders a single pixel with semi-transparency.
-    /// Requires 2 words: command+color, vertex
-    pub(crate) fn parse_monochrome_rect_1x1_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 2 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-
-        self.render_monochrome_rect(pos.x, pos.y, 1, 1, &color, true);
-    }
-
-    /// GP0(0x70): Monochrome Rectangle (8×8, Opaque)
+    /// - Bits 3-4: Size (0=variable, 1=1×1, 2=8×8, 3=16×16)
+    /// - Bit 2 (0x04): Textured
+    /// - Bit 1 (0x02): Semi-transparent
+    /// - Bit 0 (0x01): Raw texture (no color modulation)
     ///
-    /// Renders an 8×8 pixel rectangle in solid color.
-    /// Requires 2 words: command+color, vertex
-    pub(crate) fn parse_monochrome_rect_8x8_opaque(&mut self) {
-        if self.command_fifo.len() < 2 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-
-        self.render_monochrome_rect(pos.x, pos.y, 8, 8, &color, false);
-    }
-
-    /// GP0(0x72): Monochrome Rectangle (8×8, Semi-Transparent)
+    /// # Command Format
     ///
-    /// Renders an 8×8 pixel rectangle with semi-transparency.
-    /// Requires 2 words: command+color, vertex
-    pub(crate) fn parse_monochrome_rect_8x8_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 2 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-
-        self.render_monochrome_rect(pos.x, pos.y, 8, 8, &color, true);
-    }
-
-    /// GP0(0x78): Monochrome Rectangle (16×16, Opaque)
+    /// ```text
+    /// Word 0: 0xCCRRGGBB - Command + Color
+    /// Word 1: YYYYXXXX - Top-left vertex (X, Y)
+    /// [Textured only: CLUTVVUU - CLUT info (bits 16-31) + TexCoord (U, V)]
+    /// [Variable size only: HHHHWWWW - Height (bits 16-24), Width (bits 0-9)]
+    /// ```
     ///
-    /// Renders a 16×16 pixel rectangle in solid color.
-    /// Requires 2 words: command+color, vertex
-    pub(crate) fn parse_monochrome_rect_16x16_opaque(&mut self) {
-        if self.command_fifo.len() < 2 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-
-        self.render_monochrome_rect(pos.x, pos.y, 16, 16, &color, false);
-    }
-
-    /// GP0(0x7A): Monochrome Rectangle (16×16, Semi-Transparent)
+    /// Textured rectangles use the texture page from the current draw mode
+    /// (GP0(E1h)), and the U/V coordinates advance one texel per pixel.
     ///
-    /// Renders a 16×16 pixel rectangle with semi-transparency.
-    /// Requires 2 words: command+color, vertex
-    pub(crate) fn parse_monochrome_rect_16x16_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 2 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-
-        self.render_monochrome_rect(pos.x, pos.y, 16, 16, &color, true);
-    }
-
-    // =========================================================================
-    // Textured Rectangles
-    // =========================================================================
-
-    /// GP0(0x64): Textured Rectangle (Variable Size, Opaque, Raw Texture)
+    /// # References
     ///
-    /// Renders a textured rectangle with raw texture colors (no modulation).
-    /// Requires 4 words: command+color, vertex, texcoord+clut, width+height
-    pub(crate) fn parse_textured_rect_variable_opaque(&mut self) {
-        if self.command_fifo.len() < 4 {
-            return;
+    /// - [PSX-SPX: GPU Render Rectangle Commands](http://problemkaputt.de/psx-spx.htm#gpurenderrectanglecommands)
+    pub(crate) fn parse_rectangle(&mut self) {
+        let command = self.command_fifo[0] >> 24;
+        let size_mode = (command >> 3) & 0x03;
+        let textured = command & 0x04 != 0;
+        let semi_transparent = command & 0x02 != 0;
+        let raw = command & 0x01 != 0;
+
+        let word_count = 2 + textured as usize + (size_mode == 0) as usize;
+        if self.command_fifo.len() < word_count {
+            return; // Need more words
         }
 
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-        let size = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-        let width = (size >> 16) as u16;
-        let height = (size & 0xFFFF) as u16;
-
-        log::info!(
-            "GP0(0x64) Textured Rect: pos=({}, {}), size={}x{}, texcoord=({}, {}), clut=({}, {})",
-            pos.x,
-            pos.y,
-            width,
-            height,
-            texcoord.u,
-            texcoord.v,
-            clut_x,
-            clut_y
-        );
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
+        let color = Color::from_u32(self.command_fifo.pop_front().unwrap());
+        let pos = Vertex::from_u32(self.command_fifo.pop_front().unwrap());
+        let texcoord_clut = if textured {
+            self.command_fifo.pop_front().unwrap()
+        } else {
+            0
         };
 
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            width,
-            height,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            false,
-            false,
-        );
-    }
-
-    /// GP0(0x65): Textured Rectangle (Variable Size, Opaque, Modulated)
-    ///
-    /// Renders a textured rectangle with color modulation.
-    /// Requires 4 words: command+color, vertex, texcoord+clut, width+height
-    pub(crate) fn parse_textured_rect_variable_opaque_modulated(&mut self) {
-        if self.command_fifo.len() < 4 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-        let size = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-        let width = (size >> 16) as u16;
-        let height = (size & 0xFFFF) as u16;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
+        let (width, height) = match size_mode {
+            0 => {
+                let size = self.command_fifo.pop_front().unwrap();
+                ((size & 0x3FF) as u16, ((size >> 16) & 0x1FF) as u16)
+            }
+            1 => (1, 1),
+            2 => (8, 8),
+            _ => (16, 16),
         };
 
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            width,
-            height,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            false,
-            true,
-        );
-    }
-
-    /// GP0(0x66): Textured Rectangle (Variable Size, Semi-Transparent, Raw Texture)
-    ///
-    /// Renders a textured rectangle with semi-transparency, no modulation.
-    /// Requires 4 words: command+color, vertex, texcoord+clut, width+height
-    pub(crate) fn parse_textured_rect_variable_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 4 {
+        if !textured {
+            self.render_monochrome_rect(pos.x, pos.y, width, height, &color, semi_transparent);
             return;
         }
 
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-        let size = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
         let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-        let width = (size >> 16) as u16;
-        let height = (size & 0xFFFF) as u16;
-
         let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
+            clut_x: (((texcoord_clut >> 16) & 0x3F) * 16) as u16,
+            clut_y: ((texcoord_clut >> 22) & 0x1FF) as u16,
             page_x: self.draw_mode.texture_page_x_base,
             page_y: self.draw_mode.texture_page_y_base,
             depth: self.draw_mode.texture_depth.into(),
         };
 
-        self.render_textured_rect(
+        log::trace!(
+            "GP0(0x{:02X}) Textured Rect: pos=({}, {}), size={}x{}, texcoord=({}, {}), clut=({}, {})",
+            command,
             pos.x,
             pos.y,
             width,
             height,
             texcoord.u,
             texcoord.v,
-            &texture_info,
-            &color,
-            true,
-            false,
+            texture_info.clut_x,
+            texture_info.clut_y
         );
-    }
-
-    /// GP0(0x67): Textured Rectangle (Variable Size, Semi-Transparent, Modulated)
-    ///
-    /// Renders a textured rectangle with semi-transparency and color modulation.
-    /// Requires 4 words: command+color, vertex, texcoord+clut, width+height
-    pub(crate) fn parse_textured_rect_variable_semi_transparent_modulated(&mut self) {
-        if self.command_fifo.len() < 4 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-        let size = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-        let width = (size >> 16) as u16;
-        let height = (size & 0xFFFF) as u16;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
 
         self.render_textured_rect(
             pos.x,
@@ -365,467 +116,8 @@ impl GPU {
             texcoord.v,
             &texture_info,
             &color,
-            true,
-            true,
-        );
-    }
-
-    /// GP0(0x6C): Textured Rectangle (1×1, Opaque, Raw Texture)
-    ///
-    /// Renders a 1×1 textured rectangle (single texel).
-    /// Requires 3 words: command+color, vertex, texcoord+clut
-    pub(crate) fn parse_textured_rect_1x1_opaque(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
-
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            1,
-            1,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            false,
-            false,
-        );
-    }
-
-    /// GP0(0x6D): Textured Rectangle (1×1, Opaque, Modulated)
-    pub(crate) fn parse_textured_rect_1x1_opaque_modulated(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
-
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            1,
-            1,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            false,
-            true,
-        );
-    }
-
-    /// GP0(0x6E): Textured Rectangle (1×1, Semi-Transparent, Raw Texture)
-    pub(crate) fn parse_textured_rect_1x1_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
-
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            1,
-            1,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            true,
-            false,
-        );
-    }
-
-    /// GP0(0x6F): Textured Rectangle (1×1, Semi-Transparent, Modulated)
-    pub(crate) fn parse_textured_rect_1x1_semi_transparent_modulated(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
-
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            1,
-            1,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            true,
-            true,
-        );
-    }
-
-    /// GP0(0x74): Textured Rectangle (8×8, Opaque, Raw Texture)
-    pub(crate) fn parse_textured_rect_8x8_opaque(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
-
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            8,
-            8,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            false,
-            false,
-        );
-    }
-
-    /// GP0(0x75): Textured Rectangle (8×8, Opaque, Modulated)
-    pub(crate) fn parse_textured_rect_8x8_opaque_modulated(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
-
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            8,
-            8,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            false,
-            true,
-        );
-    }
-
-    /// GP0(0x76): Textured Rectangle (8×8, Semi-Transparent, Raw Texture)
-    pub(crate) fn parse_textured_rect_8x8_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
-
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            8,
-            8,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            true,
-            false,
-        );
-    }
-
-    /// GP0(0x77): Textured Rectangle (8×8, Semi-Transparent, Modulated)
-    pub(crate) fn parse_textured_rect_8x8_semi_transparent_modulated(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
-
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            8,
-            8,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            true,
-            true,
-        );
-    }
-
-    /// GP0(0x7C): Textured Rectangle (16×16, Opaque, Raw Texture)
-    pub(crate) fn parse_textured_rect_16x16_opaque(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
-
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            16,
-            16,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            false,
-            false,
-        );
-    }
-
-    /// GP0(0x7D): Textured Rectangle (16×16, Opaque, Modulated)
-    pub(crate) fn parse_textured_rect_16x16_opaque_modulated(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
-
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            16,
-            16,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            false,
-            true,
-        );
-    }
-
-    /// GP0(0x7E): Textured Rectangle (16×16, Semi-Transparent, Raw Texture)
-    pub(crate) fn parse_textured_rect_16x16_semi_transparent(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
-
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            16,
-            16,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            true,
-            false,
-        );
-    }
-
-    /// GP0(0x7F): Textured Rectangle (16×16, Semi-Transparent, Modulated)
-    pub(crate) fn parse_textured_rect_16x16_semi_transparent_modulated(&mut self) {
-        if self.command_fifo.len() < 3 {
-            return;
-        }
-
-        let cmd = self.command_fifo.pop_front().unwrap();
-        let vertex = self.command_fifo.pop_front().unwrap();
-        let texcoord_clut = self.command_fifo.pop_front().unwrap();
-
-        let color = Color::from_u32(cmd);
-        let pos = Vertex::from_u32(vertex);
-        let texcoord = TexCoord::from_u32(texcoord_clut);
-        let clut_x = ((texcoord_clut >> 16) & 0x3F) * 16;
-        let clut_y = (texcoord_clut >> 22) & 0x1FF;
-
-        let texture_info = TextureInfo {
-            clut_x: clut_x as u16,
-            clut_y: clut_y as u16,
-            page_x: self.draw_mode.texture_page_x_base,
-            page_y: self.draw_mode.texture_page_y_base,
-            depth: self.draw_mode.texture_depth.into(),
-        };
-
-        self.render_textured_rect(
-            pos.x,
-            pos.y,
-            16,
-            16,
-            texcoord.u,
-            texcoord.v,
-            &texture_info,
-            &color,
-            true,
-            true,
+            semi_transparent,
+            !raw,
         );
     }
 
@@ -852,6 +144,12 @@ impl GPU {
         color: &Color,
         semi_transparent: bool,
     ) {
+        let cost = DrawCost {
+            blended: semi_transparent,
+            ..DrawCost::default()
+        };
+        self.add_rect_draw_time(x, y, width, height, cost);
+
         self.rasterizer.draw_rectangle(
             &mut self.vram,
             &self.draw_mode,
@@ -866,6 +164,20 @@ impl GPU {
         );
     }
 
+    /// Keep the GPU busy for the estimated time of a rectangle
+    ///
+    /// # Arguments
+    ///
+    /// * `x`, `y` - Top-left corner before the drawing offset
+    /// * `width`, `height` - Size in pixels
+    /// * `cost` - Per-pixel work of the rectangle
+    fn add_rect_draw_time(&mut self, x: i16, y: i16, width: u16, height: u16, cost: DrawCost) {
+        let x = x.wrapping_add(self.draw_offset.0);
+        let y = y.wrapping_add(self.draw_offset.1);
+        let pixels = rectangle_pixels(x, y, width, height, &self.draw_area);
+        self.add_draw_time(pixels, cost);
+    }
+
     /// Render a textured rectangle
     ///
     /// # Arguments
@@ -894,6 +206,13 @@ impl GPU {
         semi_transparent: bool,
         modulated: bool,
     ) {
+        let cost = DrawCost {
+            textured: true,
+            blended: semi_transparent,
+            ..DrawCost::default()
+        };
+        self.add_rect_draw_time(x, y, width, height, cost);
+
         self.rasterizer.draw_textured_rectangle(
             &mut self.vram,
             &self.draw_mode,
@@ -906,6 +225,7 @@ impl GPU {
             tex_u,
             tex_v,
             texture_info,
+            &self.texture_window,
             color,
             semi_transparent,
             modulated,
@@ -997,7 +317,7 @@ mod tests {
     fn test_textured_rect_variable_opaque() {
         let mut gpu = GPU::new();
 
-        // GP0(0x64): Textured Rectangle Variable Size Opaque (Raw)
+        // GP0(0x64): Textured Rectangle Variable Size Opaque (Modulated)
         gpu.write_gp0(0x64808080); // Command + Color
         gpu.write_gp0(0x00000000); // Vertex: (0, 0)
         gpu.write_gp0(0x00000000); // TexCoord + CLUT
@@ -1007,10 +327,10 @@ mod tests {
     }
 
     #[test]
-    fn test_textured_rect_variable_modulated() {
+    fn test_textured_rect_variable_raw() {
         let mut gpu = GPU::new();
 
-        // GP0(0x65): Textured Rectangle Variable Size Opaque (Modulated)
+        // GP0(0x65): Textured Rectangle Variable Size Opaque (Raw)
         gpu.write_gp0(0x65808080); // Command + Color (modulation)
         gpu.write_gp0(0x00320032); // Vertex: (50, 50)
         gpu.write_gp0(0x00200020); // TexCoord: U=32, V=32
@@ -1023,25 +343,25 @@ mod tests {
     fn test_textured_rect_1x1_variants() {
         let mut gpu = GPU::new();
 
-        // GP0(0x6C): 1×1 Opaque Raw
+        // GP0(0x6C): 1×1 Opaque Modulated
         gpu.write_gp0(0x6C808080);
         gpu.write_gp0(0x00000000);
         gpu.write_gp0(0x00000000);
         assert!(gpu.command_fifo.is_empty());
 
-        // GP0(0x6D): 1×1 Opaque Modulated
+        // GP0(0x6D): 1×1 Opaque Raw
         gpu.write_gp0(0x6D808080);
         gpu.write_gp0(0x00000000);
         gpu.write_gp0(0x00000000);
         assert!(gpu.command_fifo.is_empty());
 
-        // GP0(0x6E): 1×1 Semi-Transparent Raw
+        // GP0(0x6E): 1×1 Semi-Transparent Modulated
         gpu.write_gp0(0x6E808080);
         gpu.write_gp0(0x00000000);
         gpu.write_gp0(0x00000000);
         assert!(gpu.command_fifo.is_empty());
 
-        // GP0(0x6F): 1×1 Semi-Transparent Modulated
+        // GP0(0x6F): 1×1 Semi-Transparent Raw
         gpu.write_gp0(0x6F808080);
         gpu.write_gp0(0x00000000);
         gpu.write_gp0(0x00000000);
@@ -1052,25 +372,25 @@ mod tests {
     fn test_textured_rect_8x8_variants() {
         let mut gpu = GPU::new();
 
-        // GP0(0x74): 8×8 Opaque Raw
+        // GP0(0x74): 8×8 Opaque Modulated
         gpu.write_gp0(0x74808080);
         gpu.write_gp0(0x00000000);
         gpu.write_gp0(0x00000000);
         assert!(gpu.command_fifo.is_empty());
 
-        // GP0(0x75): 8×8 Opaque Modulated
+        // GP0(0x75): 8×8 Opaque Raw
         gpu.write_gp0(0x75808080);
         gpu.write_gp0(0x00000000);
         gpu.write_gp0(0x00000000);
         assert!(gpu.command_fifo.is_empty());
 
-        // GP0(0x76): 8×8 Semi-Transparent Raw
+        // GP0(0x76): 8×8 Semi-Transparent Modulated
         gpu.write_gp0(0x76808080);
         gpu.write_gp0(0x00000000);
         gpu.write_gp0(0x00000000);
         assert!(gpu.command_fifo.is_empty());
 
-        // GP0(0x77): 8×8 Semi-Transparent Modulated
+        // GP0(0x77): 8×8 Semi-Transparent Raw
         gpu.write_gp0(0x77808080);
         gpu.write_gp0(0x00000000);
         gpu.write_gp0(0x00000000);
@@ -1177,16 +497,16 @@ mod tests {
     fn test_rect_modulation_vs_raw() {
         let mut gpu = GPU::new();
 
-        // Raw texture (bit 24 = 1): GP0(0x64) - color ignored
-        gpu.write_gp0(0x64FFFFFF);
+        // Raw texture (bit 24 = 1): GP0(0x65) - color ignored
+        gpu.write_gp0(0x65FFFFFF);
         gpu.write_gp0(0x00000000);
         gpu.write_gp0(0x00000000);
         gpu.write_gp0(0x00200020);
         assert!(gpu.command_fifo.is_empty());
 
-        // Modulated (bit 24 = 0): GP0(0x65) - color used for modulation
+        // Modulated (bit 24 = 0): GP0(0x64) - color used for modulation
         // Per PSX-SPX: (texel.rgb * vertexColor.rgb) / 128
-        gpu.write_gp0(0x65808080); // 128,128,128 = brightest for modulation
+        gpu.write_gp0(0x64808080); // 128,128,128 = brightest for modulation
         gpu.write_gp0(0x00000000);
         gpu.write_gp0(0x00000000);
         gpu.write_gp0(0x00200020);
@@ -1254,4 +574,120 @@ mod tests {
 
         assert!(gpu.command_fifo.is_empty());
     }
+
+    #[test]
+    fn test_textured_rect_8x8_samples_texture() {
+        let mut gpu = GPU::new();
+
+        // 4-bit texture at X=256: U 0-7 use index 1, U 8-15 index 2,
+        // U 16+ index 0 (CLUT entry 0x0000, transparent)
+        for v in 0..8 {
+            for word in 0..4 {
+                let indices = if word < 2 { 0x1111 } else { 0x2222 };
+                gpu.write_vram(256 + word, v, indices);
+            }
+        }
+        gpu.write_vram(1, 480, 0x001F); // red
+        gpu.write_vram(2, 480, 0x03E0); // green
+        gpu.write_vram(21, 10, 0x7C00); // background under transparent texels
+
+        gpu.write_gp0(0xE1000004); // Texture page X=256, 4-bit
+        gpu.write_gp0(0x75000000); // 8×8 textured, raw
+        gpu.write_gp0(0x000A0010); // Vertex: (16, 10)
+        gpu.write_gp0((480 << 22) | 0x000C); // CLUT (0, 480) + UV (12, 0)
+
+        assert_eq!(gpu.read_vram(16, 10), 0x03E0);
+        assert_eq!(gpu.read_vram(19, 17), 0x03E0);
+        assert_eq!(gpu.read_vram(20, 10), 0x0000);
+        assert_eq!(gpu.read_vram(21, 10), 0x7C00); // transparent texel kept
+        assert_eq!(gpu.read_vram(24, 10), 0x0000); // past the 8-pixel width
+    }
+
+    #[test]
+    fn test_textured_rect_sees_clut_update_between_draws() {
+        let mut gpu = GPU::new();
+
+        // 4-bit texture at X=256 using palette index 1
+        for v in 0..8 {
+            for word in 0..2 {
+                gpu.write_vram(256 + word, v, 0x1111);
+            }
+        }
+        gpu.write_vram(1, 480, 0x001F); // red
+
+        gpu.write_gp0(0xE1000004); // Texture page X=256, 4-bit
+        let draw = |gpu: &mut GPU| {
+            gpu.write_gp0(0x75000000); // 8×8 textured, raw
+            gpu.write_gp0(0x000A0010); // Vertex: (16, 10)
+            gpu.write_gp0(480 << 22); // CLUT (0, 480) + UV (0, 0)
+            gpu.read_vram(16, 10)
+        };
+
+        assert_eq!(draw(&mut gpu), 0x001F);
+
+        // Rewriting the palette entry must not leave a stale cached CLUT
+        gpu.write_vram(1, 480, 0x03E0);
+        assert_eq!(draw(&mut gpu), 0x03E0);
+
+        // Same through a CPU→VRAM transfer over the CLUT
+        gpu.write_gp0(0xA0000000);
+        gpu.write_gp0((480 << 16) | 1); // Destination (1, 480)
+        gpu.write_gp0(0x0001_0002); // 2×1
+        gpu.write_gp0(0x0000_7C00); // Entry 1: blue, entry 2: black
+        assert_eq!(draw(&mut gpu), 0x7C00);
+    }
+
+    #[test]
+    fn test_textured_rect_upscaled_maps_each_texel_to_a_block() {
+        let mut gpu = GPU::new();
+        gpu.set_upscale(2);
+
+        // 15-bit texture at X=256: red, green
+        gpu.write_vram(256, 0, 0x001F);
+        gpu.write_vram(257, 0, 0x03E0);
+
+        gpu.write_gp0(0xE1000104); // Texture page X=256, 15-bit
+        gpu.write_gp0(0x75000000); // 8×8 textured, raw
+        gpu.write_gp0(0x000A0010); // Vertex: (16, 10)
+        gpu.write_gp0(0x00000000); // UV (0, 0)
+
+        // Native VRAM is unchanged by upscaling
+        assert_eq!(gpu.read_vram(16, 10), 0x001F);
+        assert_eq!(gpu.read_vram(17, 10), 0x03E0);
+
+        let upscaled = gpu.rasterizer.upscaled_vram().unwrap();
+        let at = |x: usize, y: usize| upscaled[y * 2048 + x];
+
+        // The texture upload was mirrored as 2×2 blocks
+        assert_eq!(at(513, 1), 0x001F);
+
+        // Each texel covers a 2×2 block at twice the coordinates
+        for (x, y) in [(32, 20), (33, 20), (32, 21), (33, 21)] {
+            assert_eq!(at(x, y), 0x001F);
+        }
+        for (x, y) in [(34, 20), (35, 20), (34, 21), (35, 21)] {
+            assert_eq!(at(x, y), 0x03E0);
+        }
+    }
+
+    #[test]
+    fn test_rect_clipped_to_drawing_area() {
+        let mut gpu = GPU::new();
+
+        gpu.write_gp0(0xE3000000 | (20 << 10) | 20); // Area top-left: (20, 20)
+        gpu.write_gp0(0xE4000000 | (39 << 10) | 39); // Area bottom-right: (39, 39)
+        gpu.write_gp0(0xE5000000 | (5 << 11) | 5); // Offset: (5, 5)
+
+        gpu.write_gp0(0x600000FF); // Variable size, red
+        gpu.write_gp0(0x000A000A); // Vertex: (10, 10) => (15, 15) after offset
+        gpu.write_gp0(0x0014000A); // Size: Width=10, Height=20
+
+        // Drawn from (20, 20) to (24, 34), clipped at the area's top-left
+        assert_eq!(gpu.read_vram(20, 20), 0x001F);
+        assert_eq!(gpu.read_vram(24, 34), 0x001F);
+        assert_eq!(gpu.read_vram(19, 20), 0x0000);
+        assert_eq!(gpu.read_vram(20, 19), 0x0000);
+        assert_eq!(gpu.read_vram(25, 20), 0x0000);
+        assert_eq!(gpu.read_vram(20, 35), 0x0000);
+    }
 }
@@ -29,6 +29,10 @@ impl GPU {
     /// - Word 2: Size (Width in bits 0-15, Height in bits 16-31)
     ///
     /// After this command, subsequent GP0 writes are treated as VRAM data.
+    /// Rows wrap around at the 1024-pixel VRAM width (and columns at 512
+    /// lines). Once the rectangle is filled the GPU goes back to decoding
+    /// GP0 commands; the unused upper half of the last word of an image
+    /// with an odd pixel count is dropped.
     pub(crate) fn gp0_cpu_to_vram_transfer(&mut self) {
         if self.command_fifo.len() < 3 {
             return; // Need more words
@@ -92,7 +96,7 @@ impl GPU {
         // Write first pixel
         let vram_x = (transfer.x + transfer.current_x) & 0x3FF;
         let vram_y = (transfer.y + transfer.current_y) & 0x1FF;
-        self.write_vram(vram_x, vram_y, pixel1);
+        self.write_vram_masked(vram_x, vram_y, pixel1);
 
         transfer.current_x += 1;
         if transfer.current_x >= transfer.width {
@@ -104,7 +108,7 @@ impl GPU {
         if transfer.current_y < transfer.height {
             let vram_x = (transfer.x + transfer.current_x) & 0x3FF;
             let vram_y = (transfer.y + transfer.current_y) & 0x1FF;
-            self.write_vram(vram_x, vram_y, pixel2);
+            self.write_vram_masked(vram_x, vram_y, pixel2);
 
             transfer.current_x += 1;
             if transfer.current_x >= transfer.width {
@@ -123,6 +127,47 @@ impl GPU {
         }
     }
 
+    /// Write one block of a sync mode 1 (block sync) DMA transfer
+    ///
+    /// While the CPU→VRAM window opened by GP0(0xA0) is active, the words
+    /// are stored straight into VRAM, continuing the window's row-major
+    /// order, without going through the GP0 command FIFO. Words left over
+    /// once the window is filled, or sent with no window open, are handled
+    /// as ordinary GP0 writes.
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - Words of one DMA block (two pixels per word)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::gpu::GPU;
+    ///
+    /// let mut gpu = GPU::new();
+    /// // Open a 2×1 window at (0, 0), then send one block
+    /// gpu.write_gp0(0xA000_0000);
+    /// gpu.write_gp0(0x0000_0000);
+    /// gpu.write_gp0(0x0001_0002);
+    /// gpu.write_vram_block(&[0x1234_5678]);
+    ///
+    /// assert_eq!(gpu.read_vram(0, 0), 0x5678);
+    /// assert_eq!(gpu.read_vram(1, 0), 0x1234);
+    /// ```
+    pub fn write_vram_block(&mut self, block: &[u32]) {
+        for &word in block {
+            let window_open = self
+                .vram_transfer
+                .as_ref()
+                .is_some_and(|t| t.direction == VRAMTransferDirection::CpuToVram);
+            if window_open {
+                self.process_vram_write(word);
+            } else {
+                self.write_gp0(word);
+            }
+        }
+    }
+
     /// GP0(0xC0): VRAM→CPU Transfer
     ///
     /// Initiates a transfer from VRAM to CPU. The transfer requires 3 command words:
@@ -176,7 +221,21 @@ impl GPU {
     /// - Word 2: Destination coordinates (X in bits 0-15, Y in bits 16-31)
     /// - Word 3: Size (Width in bits 0-15, Height in bits 16-31)
     ///
-    /// The copy handles overlapping regions correctly by using a temporary buffer.
+    /// Coordinates wrap at the 1024×512 VRAM boundary.
+    ///
+    /// # Copy Order
+    ///
+    /// Rows are copied top to bottom, one pixel at a time. Within a row the
+    /// hardware copies right to left when the source X is left of the
+    /// destination X, so horizontally overlapping copies behave like
+    /// `memmove`. Vertically overlapping copies read rows that have already
+    /// been written, as on hardware.
+    ///
+    /// # Mask Bit
+    ///
+    /// Destination pixels with bit 15 set are skipped when "check mask" is
+    /// enabled (GP0(E6h) bit 1), and bit 15 is forced on in written pixels
+    /// when "set mask" is enabled (GP0(E6h) bit 0).
     pub(crate) fn gp0_vram_to_vram_transfer(&mut self) {
         if self.command_fifo.len() < 4 {
             return;
@@ -204,29 +263,37 @@ impl GPU {
             height
         );
 
-        // Copy rectangle
-        // Note: Need to handle overlapping regions correctly
-        let mut temp_buffer = vec![0u16; (width as usize) * (height as usize)];
+        let reverse = src_x < dst_x;
 
-        // Read source
         for y in 0..height {
-            for x in 0..width {
-                let sx = (src_x + x) & 0x3FF;
-                let sy = (src_y + y) & 0x1FF;
-                temp_buffer[(y as usize) * (width as usize) + (x as usize)] =
-                    self.read_vram(sx, sy);
+            let sy = (src_y + y) & 0x1FF;
+            let dy = (dst_y + y) & 0x1FF;
+
+            for i in 0..width {
+                let x = if reverse { width - 1 - i } else { i };
+                let pixel = self.read_vram((src_x + x) & 0x3FF, sy);
+                self.write_vram_masked((dst_x + x) & 0x3FF, dy, pixel);
             }
         }
+    }
 
-        // Write destination
-        for y in 0..height {
-            for x in 0..width {
-                let dx = (dst_x + x) & 0x3FF;
-                let dy = (dst_y + y) & 0x1FF;
-                let pixel = temp_buffer[(y as usize) * (width as usize) + (x as usize)];
-                self.write_vram(dx, dy, pixel);
-            }
+    /// Write a transferred pixel to VRAM, honoring the GP0(E6h) mask settings
+    ///
+    /// The pixel is dropped if the destination has bit 15 set and "check
+    /// mask" is enabled; bit 15 is forced on if "set mask" is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X coordinate (0-1023)
+    /// * `y` - Y coordinate (0-511)
+    /// * `value` - 16-bit pixel value
+    fn write_vram_masked(&mut self, x: u16, y: u16, value: u16) {
+        if !self.status.draw_pixels && self.read_vram(x, y) & 0x8000 != 0 {
+            return;
         }
+
+        let mask = if self.status.set_mask_bit { 0x8000 } else { 0 };
+        self.write_vram(x, y, value | mask);
     }
 }
 
@@ -308,6 +375,34 @@ mod tests {
         assert_eq!(gpu.read_vram(1, 0), 0x7FFF);
     }
 
+    #[test]
+    fn test_cpu_to_vram_image_wraps_rows_and_returns_to_commands() {
+        let mut gpu = GPU::new();
+
+        // 3×2 image at (1022, 10): each row crosses the right edge of VRAM
+        gpu.write_gp0(0xA0000000);
+        gpu.write_gp0(0x000A03FE);
+        gpu.write_gp0(0x00020003);
+
+        // 6 pixels in 3 words, row by row
+        gpu.write_gp0(0x0002_0001);
+        gpu.write_gp0(0x0004_0003);
+        gpu.write_gp0(0x0006_0005);
+        assert!(gpu.vram_transfer.is_none());
+
+        assert_eq!(gpu.read_vram(1022, 10), 0x0001);
+        assert_eq!(gpu.read_vram(1023, 10), 0x0002);
+        assert_eq!(gpu.read_vram(0, 10), 0x0003);
+        assert_eq!(gpu.read_vram(1022, 11), 0x0004);
+        assert_eq!(gpu.read_vram(1023, 11), 0x0005);
+        assert_eq!(gpu.read_vram(0, 11), 0x0006);
+
+        // The next word is a command again: GP0(E1h) texture page X = 5 * 64
+        gpu.write_gp0(0xE100_0005);
+        assert_eq!(gpu.draw_mode.texture_page_x_base, 320);
+        assert_eq!(gpu.read_vram(1, 11), 0);
+    }
+
     #[test]
     fn test_vram_to_cpu_transfer() {
         let mut gpu = GPU::new();
@@ -337,6 +432,48 @@ mod tests {
         assert!(!gpu.status.ready_to_send_vram);
     }
 
+    #[test]
+    fn test_vram_to_cpu_readback_wraps_and_latches() {
+        let mut gpu = GPU::new();
+
+        // Upload a 3×2 rect straddling the right VRAM edge
+        gpu.write_gp0(0xA0000000);
+        gpu.write_gp0(0x000A03FF); // X=1023, Y=10
+        gpu.write_gp0(0x00020003); // Width=3, Height=2
+        gpu.write_gp0(0x00020001);
+        gpu.write_gp0(0x00040003);
+        gpu.write_gp0(0x00060005);
+
+        // Read it back through GPUREAD
+        gpu.write_gp0(0xC0000000);
+        gpu.write_gp0(0x000A03FF);
+        gpu.write_gp0(0x00020003);
+        assert_ne!(gpu.status() & (1 << 27), 0);
+
+        assert_eq!(gpu.read_gpuread(), 0x00020001);
+        assert_eq!(gpu.read_gpuread(), 0x00040003);
+        assert_eq!(gpu.read_gpuread(), 0x00060005);
+        assert_eq!(gpu.status() & (1 << 27), 0);
+
+        // GPUREAD holds the last word once the transfer is done
+        assert_eq!(gpu.read_gpuread(), 0x00060005);
+    }
+
+    #[test]
+    fn test_gpuread_ignores_cpu_to_vram_transfer() {
+        let mut gpu = GPU::new();
+
+        gpu.write_gp0(0xA0000000);
+        gpu.write_gp0(0x00000000);
+        gpu.write_gp0(0x00010002); // Width=2, Height=1
+
+        assert_eq!(gpu.read_gpuread(), 0);
+        gpu.write_gp0(0x7FFF001F);
+
+        assert_eq!(gpu.read_vram(0, 0), 0x001F);
+        assert_eq!(gpu.read_vram(1, 0), 0x7FFF);
+    }
+
     #[test]
     fn test_vram_to_vram_transfer() {
         let mut gpu = GPU::new();
@@ -367,8 +504,7 @@ mod tests {
     }
 
     #[test]
-    #[ignore] // TODO: Fix VRAM-to-VRAM transfer implementation or test setup
-    fn test_vram_to_vram_overlapping_regions() {
+    fn test_vram_to_vram_non_overlapping_row() {
         let mut gpu = GPU::new();
 
         // Setup a non-overlapping region first for simpler testing
@@ -385,7 +521,7 @@ mod tests {
         // Copy first 4 pixels to destination 60, which doesn't overlap
         gpu.write_gp0(0x80000000);
         gpu.write_gp0(0x00320032); // Source: X=50, Y=50
-        gpu.write_gp0(0x003C0032); // Dest: X=60, Y=50 (no overlap)
+        gpu.write_gp0(0x0032003C); // Dest: X=60, Y=50 (no overlap)
         gpu.write_gp0(0x00010004); // Width=4, Height=1
 
         // Verify copy worked correctly without overlap issues
@@ -442,4 +578,139 @@ mod tests {
         assert_eq!(gpu.read_vram(1, 0), 0x7FFF); // White
         assert_eq!(gpu.read_vram(2, 0), 0x7C00); // Blue
     }
+
+    #[test]
+    fn test_vram_to_vram_filled_rectangle() {
+        let mut gpu = GPU::new();
+
+        // Fill a red 16×8 rectangle at (100, 20)
+        for y in 0..8 {
+            for x in 0..16 {
+                gpu.write_vram(100 + x, 20 + y, 0x001F);
+            }
+        }
+
+        gpu.write_gp0(0x80000000);
+        gpu.write_gp0(0x00140064); // Source: X=100, Y=20
+        gpu.write_gp0(0x01000200); // Dest: X=512, Y=256
+        gpu.write_gp0(0x00080010); // Width=16, Height=8
+
+        for y in 0..8 {
+            for x in 0..16 {
+                assert_eq!(gpu.read_vram(100 + x, 20 + y), 0x001F);
+                assert_eq!(gpu.read_vram(512 + x, 256 + y), 0x001F);
+            }
+        }
+        assert_eq!(gpu.read_vram(528, 256), 0x0000);
+        assert_eq!(gpu.read_vram(512, 264), 0x0000);
+    }
+
+    #[test]
+    fn test_vram_to_vram_overlapping_shift_right() {
+        let mut gpu = GPU::new();
+
+        for x in 0..4 {
+            gpu.write_vram(10 + x, 0, x + 1);
+        }
+
+        // Shift the row right by one pixel onto itself
+        gpu.write_gp0(0x80000000);
+        gpu.write_gp0(0x0000000A); // Source: X=10, Y=0
+        gpu.write_gp0(0x0000000B); // Dest: X=11, Y=0
+        gpu.write_gp0(0x00010004); // Width=4, Height=1
+
+        // Copied right to left, so the source is not smeared
+        assert_eq!(gpu.read_vram(10, 0), 1);
+        assert_eq!(gpu.read_vram(11, 0), 1);
+        assert_eq!(gpu.read_vram(12, 0), 2);
+        assert_eq!(gpu.read_vram(13, 0), 3);
+        assert_eq!(gpu.read_vram(14, 0), 4);
+    }
+
+    #[test]
+    fn test_vram_to_vram_mask_bit() {
+        let mut gpu = GPU::new();
+
+        gpu.write_vram(0, 0, 0x001F);
+        gpu.write_vram(1, 0, 0x001F);
+        gpu.write_vram(100, 0, 0x8000); // Masked destination pixel
+
+        // Set and check mask
+        gpu.write_gp0(0xE6000003);
+        gpu.write_gp0(0x80000000);
+        gpu.write_gp0(0x00000000); // Source: X=0, Y=0
+        gpu.write_gp0(0x00000064); // Dest: X=100, Y=0
+        gpu.write_gp0(0x00010002); // Width=2, Height=1
+
+        assert_eq!(gpu.read_vram(100, 0), 0x8000);
+        assert_eq!(gpu.read_vram(101, 0), 0x801F);
+    }
+
+    const DMA_REQUEST: u32 = 1 << 25;
+    const READY_CMD: u32 = 1 << 26;
+    const READY_VRAM: u32 = 1 << 27;
+    const READY_DMA: u32 = 1 << 28;
+
+    #[test]
+    fn test_full_fifo_clears_ready_bits() {
+        let mut gpu = GPU::new();
+        gpu.write_gp1(0x04000002); // DMA direction: CPU→GP0
+        assert_eq!(
+            gpu.status() & (DMA_REQUEST | READY_CMD | READY_DMA),
+            DMA_REQUEST | READY_CMD | READY_DMA
+        );
+
+        // Flat polyline: command + 15 vertices, no terminator yet
+        gpu.write_gp0(0x48FFFFFF);
+        assert_eq!(gpu.status() & READY_CMD, 0, "command in progress");
+        for i in 0..14 {
+            gpu.write_gp0(i * 4);
+            assert_ne!(gpu.status() & READY_DMA, 0, "{} words queued", i + 2);
+        }
+        gpu.write_gp0(0x00400040);
+        assert_eq!(gpu.command_fifo.len(), 16);
+        assert_eq!(gpu.status() & (DMA_REQUEST | READY_DMA), 0);
+
+        // Terminator completes the command and drains the FIFO
+        gpu.write_gp0(0x55555555);
+        assert!(gpu.command_fifo.is_empty());
+        assert_eq!(
+            gpu.status() & (DMA_REQUEST | READY_CMD | READY_DMA),
+            DMA_REQUEST | READY_DMA,
+            "still drawing"
+        );
+
+        gpu.tick(GPU::DOTS_PER_SCANLINE as u32);
+        assert_ne!(gpu.status() & READY_CMD, 0);
+    }
+
+    #[test]
+    fn test_cpu_to_vram_transfer_busy_for_commands() {
+        let mut gpu = GPU::new();
+
+        gpu.write_gp0(0xA0000000);
+        gpu.write_gp0(0x00000000);
+        gpu.write_gp0(0x00010002); // 2×1: one data word
+        assert_eq!(gpu.status() & (READY_CMD | READY_DMA), READY_DMA);
+
+        gpu.write_gp0(0x7FFF7FFF);
+        assert_ne!(gpu.status() & READY_CMD, 0);
+    }
+
+    #[test]
+    fn test_dma_request_follows_direction() {
+        let mut gpu = GPU::new();
+        gpu.write_gp0(0xC0000000);
+        gpu.write_gp0(0x00000000);
+        gpu.write_gp0(0x00010002);
+        assert_ne!(gpu.status() & READY_VRAM, 0);
+
+        gpu.write_gp1(0x04000000);
+        assert_eq!(gpu.status() & DMA_REQUEST, 0, "direction off");
+        gpu.write_gp1(0x04000003);
+        assert_ne!(gpu.status() & DMA_REQUEST, 0, "GPUREAD data pending");
+
+        gpu.read_gpuread();
+        assert_eq!(gpu.status() & (DMA_REQUEST | READY_VRAM), 0);
+    }
 }
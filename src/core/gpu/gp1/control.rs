@@ -43,6 +43,7 @@ impl GPU {
 
         // Cancel any ongoing VRAM transfer
         self.vram_transfer = None;
+        self.status.ready_to_send_vram = false;
 
         log::debug!("Command buffer reset");
     }
@@ -79,28 +80,42 @@ impl GPU {
     /// GP1(0x10): GPU Info
     ///
     /// Requests GPU information to be returned via the GPUREAD register.
-    /// Different info types return different GPU state information.
+    /// The environment set by GP0(E2h)-GP0(E5h) is returned in the same
+    /// bit layout as the command parameters.
     ///
     /// # Arguments
     ///
-    /// * `value` - Bits 0-7: Info type
-    ///   - 0x02: Texture window settings
-    ///   - 0x03: Draw area top left
-    ///   - 0x04: Draw area bottom right
-    ///   - 0x05: Draw offset
+    /// * `value` - Bits 0-2: Info type
+    ///   - 0x02: Texture window settings (20 bits)
+    ///   - 0x03: Draw area top left (20 bits)
+    ///   - 0x04: Draw area bottom right (20 bits)
+    ///   - 0x05: Draw offset (22 bits)
     ///   - 0x07: GPU version (returns 2 for PSX)
+    ///   - Other types leave the previous GPUREAD value unchanged
     pub(crate) fn gp1_get_gpu_info(&mut self, value: u32) {
-        let info_type = value & 0xFF;
+        let info_type = value & 0x7;
 
         log::debug!("GPU info request: type {}", info_type);
 
-        // TODO: Implement proper GPU info responses via GPUREAD register
-        // Info types:
-        // 0x02 - Texture window
-        // 0x03 - Draw area top left
-        // 0x04 - Draw area bottom right
-        // 0x05 - Draw offset
-        // 0x07 - GPU version (2 for PSX)
+        let info = match info_type {
+            0x02 => {
+                let window = &self.texture_window;
+                (window.mask_x as u32)
+                    | ((window.mask_y as u32) << 5)
+                    | ((window.offset_x as u32) << 10)
+                    | ((window.offset_y as u32) << 15)
+            }
+            0x03 => (self.draw_area.left as u32) | ((self.draw_area.top as u32) << 10),
+            0x04 => (self.draw_area.right as u32) | ((self.draw_area.bottom as u32) << 10),
+            0x05 => {
+                let (x, y) = self.draw_offset;
+                (x as u32 & 0x7FF) | ((y as u32 & 0x7FF) << 11)
+            }
+            0x07 => 2,
+            _ => return,
+        };
+
+        self.gpuread_latch = info;
     }
 }
 
@@ -108,6 +123,32 @@ impl GPU {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_gp1_get_gpu_info_reads_back_environment() {
+        let mut gpu = GPU::new();
+
+        gpu.write_gp0(0xE2_0F_A5_4A); // Texture window
+        gpu.write_gp0(0xE3_01_90_20); // Draw area top-left (32, 100)
+        gpu.write_gp0(0xE4_03_BE_7F); // Draw area bottom-right (639, 239)
+        gpu.write_gp0(0xE5_3F_F4_00); // Draw offset (-1024, -2)
+        assert_eq!(gpu.draw_offset, (-1024, -2));
+
+        gpu.write_gp1(0x1000_0002);
+        assert_eq!(gpu.read_gpuread(), 0x0F_A5_4A);
+        gpu.write_gp1(0x1000_0003);
+        assert_eq!(gpu.read_gpuread(), 32 | (100 << 10));
+        gpu.write_gp1(0x1000_0004);
+        assert_eq!(gpu.read_gpuread(), 639 | (239 << 10));
+        gpu.write_gp1(0x1000_0005);
+        assert_eq!(gpu.read_gpuread(), 0x3F_F4_00);
+        gpu.write_gp1(0x1000_0007);
+        assert_eq!(gpu.read_gpuread(), 2);
+
+        // Unused info types keep the previous value
+        gpu.write_gp1(0x1000_0000);
+        assert_eq!(gpu.read_gpuread(), 2);
+    }
+
     #[test]
     fn test_gp1_reset_gpu() {
         let mut gpu = GPU::new();
@@ -323,4 +364,75 @@ mod tests {
         gpu.gp1_acknowledge_interrupt();
         assert!(!gpu.status.interrupt_request);
     }
+
+    #[test]
+    fn test_gp1_00_restores_power_on_status() {
+        let mut gpu = GPU::new();
+        gpu.write_gp1(0x03000000); // Display on
+        gpu.write_gp1(0x04000003); // DMA GPUREAD→CPU
+        gpu.write_gp1(0x08000009); // 320x240 PAL
+        gpu.write_gp0(0xE100060F); // Draw mode
+        gpu.write_gp0(0x28000000); // Partial quad
+        gpu.status.interrupt_request = true;
+
+        gpu.write_gp1(0x00000000);
+
+        assert_eq!(gpu.status(), 0x1480_2000);
+        assert!(gpu.command_fifo.is_empty());
+    }
+
+    #[test]
+    fn test_gp1_01_clears_fifo_only() {
+        let mut gpu = GPU::new();
+        gpu.write_gp1(0x03000000);
+        gpu.write_gp0(0x28000000); // Partial quad
+        assert_eq!(gpu.status() & (1 << 26), 0);
+
+        gpu.write_gp1(0x01000000);
+
+        assert!(gpu.command_fifo.is_empty());
+        assert_ne!(gpu.status() & (1 << 26), 0, "ready for commands");
+        assert_eq!(gpu.status() & (1 << 23), 0, "display setting kept");
+
+        // The next word starts a fresh command
+        gpu.write_gp0(0xE1000001);
+        assert!(gpu.command_fifo.is_empty());
+        assert_eq!(gpu.status() & 0xF, 1);
+    }
+
+    #[test]
+    fn test_gp1_02_clears_irq_status_bit() {
+        let mut gpu = GPU::new();
+        gpu.status.interrupt_request = true;
+        assert_ne!(gpu.status() & (1 << 24), 0);
+
+        gpu.write_gp1(0x02000000);
+
+        assert_eq!(gpu.status() & (1 << 24), 0);
+    }
+
+    #[test]
+    fn test_gp1_03_toggles_display_status_bit() {
+        let mut gpu = GPU::new();
+
+        gpu.write_gp1(0x03000000);
+        assert_eq!(gpu.status() & (1 << 23), 0);
+
+        gpu.write_gp1(0x03000001);
+        assert_ne!(gpu.status() & (1 << 23), 0);
+    }
+
+    #[test]
+    fn test_gp1_04_direction_status_bits() {
+        let mut gpu = GPU::new();
+
+        for direction in 0..4u32 {
+            gpu.write_gp1(0x04000000 | direction);
+            assert_eq!((gpu.status() >> 29) & 3, direction);
+
+            // Idle GPU: FIFO has room, no GPUREAD data pending
+            let dma_request = gpu.status() & (1 << 25) != 0;
+            assert_eq!(dma_request, direction == 1 || direction == 2);
+        }
+    }
 }
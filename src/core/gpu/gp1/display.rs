@@ -56,6 +56,9 @@ impl GPU {
     /// GP1(0x06): Horizontal Display Range
     ///
     /// Sets the horizontal display range on screen (scanline timing).
+    /// X1/X2 count video clock cycles, so the visible width in pixels is
+    /// the range divided by the dot clock divider of the current horizontal
+    /// resolution, rounded to a multiple of 4.
     ///
     /// # Arguments
     ///
@@ -64,8 +67,8 @@ impl GPU {
         let x1 = (value & 0xFFF) as u16;
         let x2 = ((value >> 12) & 0xFFF) as u16;
 
-        // Store as width
-        self.display_area.width = x2.saturating_sub(x1);
+        self.display_area.horizontal_range = (x1, x2);
+        self.update_display_size();
 
         log::debug!(
             "Horizontal display range: {} to {} (width: {})",
@@ -78,6 +81,8 @@ impl GPU {
     /// GP1(0x07): Vertical Display Range
     ///
     /// Sets the vertical display range on screen (scanline timing).
+    /// Y1/Y2 count scanlines per field, so the height doubles in 480-line
+    /// interlaced mode.
     ///
     /// # Arguments
     ///
@@ -86,8 +91,8 @@ impl GPU {
         let y1 = (value & 0x3FF) as u16;
         let y2 = ((value >> 10) & 0x3FF) as u16;
 
-        // Store as height
-        self.display_area.height = y2.saturating_sub(y1);
+        self.display_area.vertical_range = (y1, y2);
+        self.update_display_size();
 
         log::debug!(
             "Vertical display range: {} to {} (height: {})",
@@ -141,12 +146,11 @@ impl GPU {
 
         // Video mode (NTSC/PAL)
         let video_mode = ((value >> 3) & 1) != 0;
-        self.display_mode.video_mode = if video_mode {
+        self.set_video_standard(if video_mode {
             VideoMode::PAL
         } else {
             VideoMode::NTSC
-        };
-        self.status.video_mode = video_mode;
+        });
 
         // Color depth
         let color_depth = ((value >> 4) & 1) != 0;
@@ -165,6 +169,8 @@ impl GPU {
         // Reverse flag (rarely used)
         self.status.reverse_flag = ((value >> 7) & 1) != 0;
 
+        self.update_display_size();
+
         log::debug!(
             "Display mode: {:?} {:?} {:?} {:?} interlaced={}",
             self.display_mode.horizontal_res,
@@ -174,6 +180,24 @@ impl GPU {
             interlaced
         );
     }
+
+    /// Recompute the display width and height from the display ranges
+    ///
+    /// Called whenever GP1(06h), GP1(07h) or GP1(08h) changes an input.
+    fn update_display_size(&mut self) {
+        let (x1, x2) = self.display_area.horizontal_range;
+        let (y1, y2) = self.display_area.vertical_range;
+
+        let cycles = x2.saturating_sub(x1) as u32;
+        let divider = self.display_mode.horizontal_res.dot_clock_divider();
+        self.display_area.width = if cycles == 0 {
+            0
+        } else {
+            ((cycles / divider + 2) & !3) as u16
+        };
+
+        self.display_area.height = y2.saturating_sub(y1) * self.field_count() as u16;
+    }
 }
 
 #[cfg(test)]
@@ -254,8 +278,25 @@ mod tests {
         let x2 = 0x260 + (320 * 8); // 608 + 2560 = 3168
         gpu.gp1_horizontal_display_range(x1 | (x2 << 12));
 
-        // Width = X2 - X1
-        assert_eq!(gpu.display_area.width, (x2 - x1) as u16);
+        // Width = (X2 - X1) / 8 cycles per pixel at 320 wide
+        assert_eq!(gpu.display_area.horizontal_range, (0x260, 0xC60));
+        assert_eq!(gpu.display_area.width, 320);
+    }
+
+    #[test]
+    fn test_gp1_horizontal_display_range_follows_dot_clock() {
+        let mut gpu = GPU::new();
+        gpu.gp1_horizontal_display_range(0x260 | ((0x260 + 2560) << 12));
+
+        gpu.gp1_display_mode(0b00000011); // 640 wide: 4 cycles per pixel
+        assert_eq!(gpu.display_area.width, 640);
+
+        gpu.gp1_display_mode(0b00000000); // 256 wide: 10 cycles per pixel
+        assert_eq!(gpu.display_area.width, 256);
+
+        // Odd ranges round to a multiple of 4 pixels
+        gpu.gp1_horizontal_display_range(0x260 | ((0x260 + 2500) << 12));
+        assert_eq!(gpu.display_area.width, 252);
     }
 
     #[test]
@@ -294,6 +335,10 @@ mod tests {
         // Height = Y2 - Y1
         assert_eq!(gpu.display_area.height, (y2 - y1) as u16);
         assert_eq!(gpu.display_area.height, 240);
+
+        // 480-line interlaced output shows both fields
+        gpu.gp1_display_mode(0b00100101);
+        assert_eq!(gpu.display_area.height, 480);
     }
 
     #[test]
@@ -488,4 +533,144 @@ mod tests {
         assert_eq!(gpu.display_mode.horizontal_res, HorizontalRes::R320);
         assert!(!gpu.display_mode.display_disabled);
     }
+
+    #[test]
+    fn test_framebuffer_24bit_unpacks_packed_pixels() {
+        let mut gpu = GPU::new();
+        gpu.write_gp1(0x08000011); // 320×240, 24-bit
+        gpu.write_gp1(0x05000000 | (2 << 10) | 100); // Display start: (100, 2)
+
+        // Two RGB pixels (0x11,0x22,0x33) and (0x44,0x55,0x66) in three words
+        gpu.write_vram(100, 2, 0x2211);
+        gpu.write_vram(101, 2, 0x4433);
+        gpu.write_vram(102, 2, 0x6655);
+
+        let framebuffer = gpu.get_framebuffer();
+        assert_eq!(framebuffer.len(), 320 * 240 * 3);
+        assert_eq!(&framebuffer[..6], &[0x11, 0x22, 0x33, 0x44, 0x55, 0x66]);
+        assert_eq!(&framebuffer[6..9], &[0, 0, 0]);
+    }
+
+    #[test]
+    fn test_framebuffer_15bit_expands_channels() {
+        let mut gpu = GPU::new();
+        gpu.write_vram(0, 0, 0x7C1F); // Magenta
+
+        let framebuffer = gpu.get_framebuffer();
+        assert_eq!(&framebuffer[..3], &[0xF8, 0x00, 0xF8]);
+    }
+
+    #[test]
+    fn test_interlaced_field_alternates_each_frame() {
+        let mut gpu = GPU::new();
+        gpu.write_gp1(0x08000025); // 320×480, interlaced
+
+        let cycles_per_frame = GPU::DOTS_PER_SCANLINE as u32 * GPU::SCANLINES_PER_FRAME as u32;
+
+        // Move onto the first active scanline, then sample once per frame
+        gpu.tick(GPU::DOTS_PER_SCANLINE as u32);
+        let mut fields = Vec::new();
+        for _ in 0..4 {
+            fields.push(gpu.status() >> 31);
+            gpu.tick(cycles_per_frame);
+        }
+
+        assert_eq!(fields, vec![0, 1, 0, 1]);
+        assert_eq!((gpu.status() >> 13) & 1, 0);
+    }
+
+    #[test]
+    fn test_odd_line_flag_clear_during_vblank() {
+        let mut gpu = GPU::new();
+        gpu.write_gp1(0x08000025); // 320×480, interlaced
+
+        gpu.tick(GPU::DOTS_PER_SCANLINE as u32 * GPU::VBLANK_START as u32);
+
+        assert!(gpu.is_in_vblank());
+        assert_eq!(gpu.status() >> 31, 0);
+        assert_eq!((gpu.status() >> 13) & 1, 1);
+    }
+
+    #[test]
+    fn test_progressive_field_bit_reads_one() {
+        let mut gpu = GPU::new();
+        gpu.write_gp1(0x08000001); // 320×240, not interlaced
+
+        assert_eq!((gpu.status() >> 13) & 1, 1);
+
+        // Bit 31 follows scanline parity
+        gpu.tick(GPU::DOTS_PER_SCANLINE as u32);
+        assert_eq!(gpu.status() >> 31, 1);
+        gpu.tick(GPU::DOTS_PER_SCANLINE as u32);
+        assert_eq!(gpu.status() >> 31, 0);
+    }
+
+    #[test]
+    fn test_framebuffer_interlaced_weaves_both_fields() {
+        let mut gpu = GPU::new();
+        gpu.write_gp1(0x08000025); // 320×480, interlaced
+        gpu.write_vram(0, 0, 0x001F); // Even field line 0: red
+        gpu.write_vram(0, 1, 0x7C00); // Odd field line 0: blue
+
+        let framebuffer = gpu.get_framebuffer();
+        assert_eq!(framebuffer.len(), 320 * 480 * 3);
+        assert_eq!(&framebuffer[..3], &[0xF8, 0x00, 0x00]);
+        assert_eq!(&framebuffer[320 * 3..320 * 3 + 3], &[0x00, 0x00, 0xF8]);
+    }
+
+    #[test]
+    fn test_pal_vblank_line_range() {
+        let mut gpu = GPU::new();
+        gpu.set_video_standard(VideoMode::PAL);
+        let line = VideoMode::PAL.dots_per_scanline() as u32;
+
+        // Lines 0-287 are active display
+        let (vblank, _) = gpu.tick(line * 287);
+        assert!(!vblank);
+        assert!(!gpu.is_in_vblank());
+
+        // VBlank starts at line 288
+        let (vblank, _) = gpu.tick(line);
+        assert!(vblank);
+        assert!(gpu.is_in_vblank());
+
+        // ...and lasts through line 313, past the NTSC frame length
+        let (vblank, _) = gpu.tick(line * 25);
+        assert!(!vblank);
+        assert!(gpu.is_in_vblank());
+
+        // Line 314 wraps back to 0
+        gpu.tick(line);
+        assert!(!gpu.is_in_vblank());
+    }
+
+    #[test]
+    fn test_gp1_display_mode_pal_sets_video_standard() {
+        let mut gpu = GPU::new();
+        gpu.write_gp1(0x08000008);
+
+        assert_eq!(gpu.video_mode(), VideoMode::PAL);
+        assert_eq!((gpu.status() >> 20) & 1, 1);
+    }
+
+    #[test]
+    fn test_framebuffer_cropped_to_display_ranges() {
+        let mut gpu = GPU::new();
+        gpu.write_gp1(0x08000001); // 320 wide, NTSC
+        gpu.write_gp1(0x05000000 | (16 << 10) | 64); // Display start: (64, 16)
+        gpu.write_gp1(0x06000000 | 0x260 | ((0x260 + 256 * 8) << 12)); // 256 pixels
+        gpu.write_gp1(0x07000000 | 0x10 | ((0x10 + 200) << 10)); // 200 lines
+
+        gpu.write_vram(64, 16, 0x001F); // Top-left of the display: red
+        gpu.write_vram(64 + 255, 16 + 199, 0x7C00); // Bottom-right: blue
+        gpu.write_vram(64 + 256, 16, 0x7FFF); // Just outside: white
+
+        let framebuffer = gpu.get_framebuffer();
+        assert_eq!(framebuffer.len(), 256 * 200 * 3);
+        assert_eq!(&framebuffer[..3], &[0xF8, 0x00, 0x00]);
+
+        let last = (200 * 256 - 1) * 3;
+        assert_eq!(&framebuffer[last..last + 3], &[0x00, 0x00, 0xF8]);
+        assert!(!framebuffer.chunks(3).any(|p| p == [0xF8, 0xF8, 0xF8]));
+    }
 }
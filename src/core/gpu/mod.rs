@@ -49,9 +49,12 @@
 
 use std::collections::VecDeque;
 
-use super::timing::EventHandle;
+use super::save_state::{GPUState, StateSave, VRAMTransferState};
+use render::DrawCost;
 
 // Module declarations
+mod color;
+mod frame;
 mod gp0;
 mod gp1;
 mod primitives;
@@ -59,9 +62,14 @@ mod registers;
 mod render;
 
 // Public re-exports
+pub use color::{rgb555_to_rgb888, rgb888_to_rgb555, ColorRounding};
+pub use frame::Frame;
 pub use primitives::*;
 pub use registers::*;
-pub use render::Rasterizer;
+pub use render::{Rasterizer, MAX_UPSCALE};
+
+/// Depth of the GP0 command FIFO in words
+const GP0_FIFO_DEPTH: usize = 16;
 
 /// GPU state representing the CXD8561 graphics processor
 ///
@@ -132,6 +140,11 @@ pub struct GPU {
     /// Tracks the state of ongoing VRAM-to-CPU or CPU-to-VRAM transfers.
     pub(crate) vram_transfer: Option<VRAMTransfer>,
 
+    /// Last value presented on the GPUREAD register
+    ///
+    /// GPUREAD keeps returning this value when no VRAM→CPU transfer is active.
+    gpuread_latch: u32,
+
     /// Scanline counter (0-262 for NTSC)
     ///
     /// Tracks the current scanline being rendered. NTSC mode uses 263 scanlines total,
@@ -146,7 +159,8 @@ pub struct GPU {
 
     /// VBlank status flag
     ///
-    /// True when the GPU is in the vertical blanking period (scanlines 243-262 for NTSC).
+    /// True when the GPU is in the vertical blanking period (scanlines 243-262 for NTSC,
+    /// 288-313 for PAL).
     /// During VBlank, no active display output occurs and games typically perform
     /// frame synchronization and VRAM updates.
     in_vblank: bool,
@@ -157,25 +171,29 @@ pub struct GPU {
     /// Can be extended later for proper HBlank timing if needed by games.
     in_hblank: bool,
 
-    // Timing event handles
-    /// VBlank event handle
-    vblank_event: Option<EventHandle>,
-
-    /// HBlank event handle
-    hblank_event: Option<EventHandle>,
-
-    // Interrupt flags
-    /// VBlank interrupt pending flag
-    vblank_interrupt_pending: bool,
+    /// CPU cycles not yet converted to dots, scaled by the dots per scanline
+    ///
+    /// Carries the remainder of [`GPU::tick_cycles`] so that one scanline
+    /// takes exactly `cycles_per_scanline()` CPU cycles over time.
+    dot_remainder: u64,
 
-    /// HBlank interrupt pending flag
-    hblank_interrupt_pending: bool,
+    /// GPU clock cycles left before the last primitive finishes drawing
+    ///
+    /// Primitives are rasterized immediately; this only delays GPUSTAT's
+    /// ready-to-receive-command bit by the estimated drawing time.
+    draw_busy_cycles: u32,
 
     /// VRAM dirty flag
     ///
     /// Set to true when VRAM is modified. Used by the frontend to optimize
     /// texture uploads - only upload VRAM to GPU when this flag is set.
     vram_dirty: bool,
+
+    /// Frame ready flag
+    ///
+    /// Set at the start of each VBlank and cleared by `take_frame()`, so each
+    /// completed frame is handed to the frontend exactly once.
+    frame_ready: bool,
 }
 
 impl GPU {
@@ -242,15 +260,15 @@ impl GPU {
             command_fifo: VecDeque::new(),
             status: GPUStatus::default(),
             vram_transfer: None,
+            gpuread_latch: 0,
             scanline: 0,
             dots: 0,
             in_vblank: false,
             in_hblank: false,
-            vblank_event: None,
-            hblank_event: None,
-            vblank_interrupt_pending: false,
-            hblank_interrupt_pending: false,
+            dot_remainder: 0,
+            draw_busy_cycles: 0,
             vram_dirty: false,
+            frame_ready: false,
         };
 
         // Initialize rasterizer with default clip rect
@@ -279,6 +297,8 @@ impl GPU {
 
         // Clear VRAM to black (separate from state reset)
         self.vram.fill(0x0000);
+        self.rasterizer.clear_clut_cache();
+        self.rasterizer.reload_upscaled_vram(&self.vram);
 
         // Mark VRAM as dirty so frontend uploads cleared texture
         self.vram_dirty = true;
@@ -298,11 +318,16 @@ impl GPU {
         self.display_mode = DisplayMode::default();
         self.command_fifo.clear();
         self.status = GPUStatus::default();
+        self.rasterizer.set_mask_settings(false, false);
+        self.update_rasterizer_clip_rect();
         self.vram_transfer = None;
         self.scanline = 0;
         self.dots = 0;
         self.in_vblank = false;
         self.in_hblank = false;
+        self.dot_remainder = 0;
+        self.draw_busy_cycles = 0;
+        self.frame_ready = false;
     }
 
     /// Read a 16-bit pixel from VRAM
@@ -362,6 +387,9 @@ impl GPU {
         let index = self.vram_index(x, y);
         self.vram[index] = value;
         self.vram_dirty = true;
+        self.rasterizer.invalidate_clut_pixel(x & 0x3FF, y & 0x1FF);
+        self.rasterizer
+            .sync_upscaled_pixel(x & 0x3FF, y & 0x1FF, value);
     }
 
     /// Get VRAM index from coordinates
@@ -449,8 +477,18 @@ impl GPU {
     /// Generate RGB24 framebuffer for display
     ///
     /// Extracts the display area from VRAM and converts it to 24-bit RGB
-    /// format suitable for display. Each pixel is converted from 15-bit
-    /// (5-5-5 RGB) to 24-bit (8-8-8 RGB) by left-shifting each channel.
+    /// format suitable for display.
+    ///
+    /// In 15-bit mode each pixel is converted from 5-5-5 RGB to 8-8-8 RGB by
+    /// left-shifting each channel. In 24-bit mode (GP1(08h) bit 4, used for
+    /// MDEC video) VRAM rows hold packed 3-byte RGB pixels, so each display
+    /// pixel spans one and a half 16-bit words starting at the display area X.
+    ///
+    /// The output is cropped to the display area: it starts at the display
+    /// start (GP1(05h)) and its size follows the display ranges (GP1(06h)/
+    /// GP1(07h)). In 480-line interlaced mode the frame is twice as tall as the
+    /// vertical range: even output lines belong to the even field and odd lines
+    /// to the odd field, both woven from consecutive VRAM rows.
     ///
     /// # Returns
     ///
@@ -473,20 +511,28 @@ impl GPU {
         let width = self.display_area.width as usize;
         let height = self.display_area.height as usize;
         let mut framebuffer = vec![0u8; width * height * 3];
+        let is_24bit = self.display_mode.display_area_color_depth == ColorDepth::C24Bit;
 
         for y in 0..height {
+            let vram_y = (self.display_area.y as usize + y) % 512;
+            let row = &self.vram[vram_y * 1024..(vram_y + 1) * 1024];
+
+            // Byte N of a 24-bit row lives in word N/2 (low byte first)
+            let row_byte = |n: usize| {
+                let word = row[(self.display_area.x as usize + n / 2) % 1024];
+                (word >> ((n & 1) * 8)) as u8
+            };
+
             for x in 0..width {
-                // Calculate VRAM coordinates with wrapping
-                let vram_x = (self.display_area.x as usize + x) % 1024;
-                let vram_y = (self.display_area.y as usize + y) % 512;
-                let vram_index = vram_y * 1024 + vram_x;
-                let pixel = self.vram[vram_index];
-
-                // Convert 15-bit (5-5-5) to 24-bit (8-8-8) RGB
-                // Left-shift by 3 to expand from 5-bit to 8-bit
-                let r = ((pixel & 0x1F) << 3) as u8;
-                let g = (((pixel >> 5) & 0x1F) << 3) as u8;
-                let b = (((pixel >> 10) & 0x1F) << 3) as u8;
+                let (r, g, b) = if is_24bit {
+                    (row_byte(x * 3), row_byte(x * 3 + 1), row_byte(x * 3 + 2))
+                } else {
+                    // Calculate VRAM X coordinate with wrapping
+                    let vram_x = (self.display_area.x as usize + x) % 1024;
+
+                    // Expand 15-bit (5-5-5) to 24-bit the way the video DAC does
+                    rgb555_to_rgb888(row[vram_x], ColorRounding::Truncate)
+                };
 
                 let fb_index = (y * width + x) * 3;
                 framebuffer[fb_index] = r;
@@ -498,6 +544,259 @@ impl GPU {
         framebuffer
     }
 
+    /// Take the most recently completed frame
+    ///
+    /// Returns the display area as an RGBA frame once per VBlank; further
+    /// calls return `None` until the next VBlank. The frame is cropped to the
+    /// display area and converted from 15-bit or 24-bit color like
+    /// `get_framebuffer()`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(Frame)` if a VBlank occurred since the last call
+    /// - `None` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::GPU;
+    ///
+    /// let mut gpu = GPU::new();
+    /// assert!(gpu.take_frame().is_none()); // No VBlank yet
+    ///
+    /// gpu.tick(GPU::DOTS_PER_SCANLINE as u32 * GPU::VBLANK_START as u32);
+    /// let frame = gpu.take_frame().unwrap();
+    /// assert_eq!((frame.width, frame.height), (320, 240));
+    /// assert!(gpu.take_frame().is_none()); // Already taken
+    /// ```
+    pub fn take_frame(&mut self) -> Option<Frame> {
+        if !self.frame_ready {
+            return None;
+        }
+        self.frame_ready = false;
+
+        Some(self.current_frame())
+    }
+
+    /// Capture the display area as it is right now
+    ///
+    /// Unlike `take_frame()`, this does not wait for VBlank and does not
+    /// consume the pending frame.
+    ///
+    /// # Returns
+    ///
+    /// RGBA frame cropped to the display area
+    pub fn current_frame(&self) -> Frame {
+        Frame::from_rgb24(
+            self.display_area.width as u32,
+            self.display_area.height as u32,
+            &self.get_framebuffer(),
+        )
+    }
+
+    /// Set the internal-resolution upscale factor
+    ///
+    /// Polygons, rectangles and lines are additionally rendered at
+    /// `factor`× resolution; read the result with
+    /// [`GPU::upscaled_frame`]. VRAM itself, and everything derived from it
+    /// such as `current_frame()`, stays at native resolution.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - Scale factor, clamped to 1..=`MAX_UPSCALE` (1 disables)
+    pub fn set_upscale(&mut self, factor: u16) {
+        self.rasterizer.set_upscale(factor, &self.vram);
+    }
+
+    /// Current internal-resolution scale factor (1 when disabled)
+    pub fn upscale_factor(&self) -> u16 {
+        self.rasterizer.upscale_factor()
+    }
+
+    /// Capture the display area from the upscaled framebuffer
+    ///
+    /// # Returns
+    ///
+    /// RGBA frame of `factor`× the display area size, or `None` if
+    /// upscaling is disabled or the display is in 24-bit mode (which shows
+    /// raw VRAM bytes and is never upscaled)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::GPU;
+    ///
+    /// let mut gpu = GPU::new();
+    /// assert!(gpu.upscaled_frame().is_none());
+    ///
+    /// gpu.set_upscale(2);
+    /// let frame = gpu.upscaled_frame().unwrap();
+    /// assert_eq!((frame.width, frame.height), (640, 480));
+    /// ```
+    pub fn upscaled_frame(&self) -> Option<Frame> {
+        if self.display_mode.display_area_color_depth == ColorDepth::C24Bit {
+            return None;
+        }
+        let pixels = self.rasterizer.upscaled_vram()?;
+
+        let factor = self.rasterizer.upscale_factor() as usize;
+        let (vram_width, vram_height) = (Self::VRAM_WIDTH * factor, Self::VRAM_HEIGHT * factor);
+        let width = self.display_area.width as usize * factor;
+        let height = self.display_area.height as usize * factor;
+        let left = self.display_area.x as usize * factor;
+        let top = self.display_area.y as usize * factor;
+
+        let mut rgb = Vec::with_capacity(width * height * 3);
+        for y in 0..height {
+            let row = (top + y) % vram_height * vram_width;
+            for x in 0..width {
+                let pixel = pixels[row + (left + x) % vram_width];
+                let (r, g, b) = rgb555_to_rgb888(pixel, ColorRounding::Truncate);
+                rgb.extend_from_slice(&[r, g, b]);
+            }
+        }
+
+        Some(Frame::from_rgb24(width as u32, height as u32, &rgb))
+    }
+
+    /// Copy a rectangle of raw VRAM pixels
+    ///
+    /// Coordinates wrap around the VRAM edges like GPU transfers do, so a
+    /// region may cross the right or bottom edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Left edge (0-1023)
+    /// * `y` - Top edge (0-511)
+    /// * `width` - Width in pixels
+    /// * `height` - Height in pixels
+    ///
+    /// # Returns
+    ///
+    /// `width × height` 16-bit pixels, row-major
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::GPU;
+    ///
+    /// let mut gpu = GPU::new();
+    /// gpu.write_vram(10, 20, 0x7FFF);
+    /// assert_eq!(gpu.vram_region(10, 20, 2, 1), vec![0x7FFF, 0x0000]);
+    /// ```
+    pub fn vram_region(&self, x: u16, y: u16, width: u16, height: u16) -> Vec<u16> {
+        let mut pixels = Vec::with_capacity(width as usize * height as usize);
+        for row in 0..height {
+            for col in 0..width {
+                pixels.push(self.read_vram(x.wrapping_add(col), y.wrapping_add(row)));
+            }
+        }
+        pixels
+    }
+
+    /// Render the whole 1024×512 VRAM as an image
+    ///
+    /// Every pixel is decoded as 15-bit color, including texture and CLUT
+    /// data, and the mask bit is ignored.
+    ///
+    /// # Returns
+    ///
+    /// RGBA frame of the full VRAM
+    pub fn vram_frame(&self) -> Frame {
+        let rgb: Vec<u8> = self
+            .vram
+            .iter()
+            .flat_map(|&pixel| {
+                let (r, g, b) = rgb555_to_rgb888(pixel, ColorRounding::Truncate);
+                [r, g, b]
+            })
+            .collect();
+
+        Frame::from_rgb24(Self::VRAM_WIDTH as u32, Self::VRAM_HEIGHT as u32, &rgb)
+    }
+
+    /// Save the whole VRAM to a PNG file for debugging
+    ///
+    /// Useful for checking what textures, CLUTs and framebuffers actually
+    /// ended up in VRAM. See [`GPU::vram_frame`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Output file path
+    ///
+    /// # Returns
+    ///
+    /// `Err(EmulatorError::Io)` if the file cannot be written
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use psrx::core::GPU;
+    ///
+    /// let gpu = GPU::new();
+    /// gpu.dump_vram_png("vram.png").unwrap();
+    /// ```
+    pub fn dump_vram_png<P: AsRef<std::path::Path>>(
+        &self,
+        path: P,
+    ) -> crate::core::error::Result<()> {
+        self.vram_frame().save_png(path)
+    }
+
+    /// Get the dot clock divider for the current horizontal resolution
+    ///
+    /// Used by Timer 0 when it counts dots instead of system clock cycles.
+    ///
+    /// # Returns
+    ///
+    /// Video clock cycles per dot (4-10)
+    pub fn dot_clock_divider(&self) -> u32 {
+        self.display_mode.horizontal_res.dot_clock_divider()
+    }
+
+    /// Get the current video standard
+    ///
+    /// Used by Timer 1 to derive the scanline length of its hblank source.
+    ///
+    /// # Returns
+    ///
+    /// NTSC or PAL, as selected by GP1(08h)
+    pub fn video_mode(&self) -> VideoMode {
+        self.display_mode.video_mode
+    }
+
+    /// Set the video standard driving the scanline timing
+    ///
+    /// Selects between NTSC (263 lines, 60Hz) and PAL (314 lines, 50Hz).
+    /// The scanline and dot counters wrap, and VBlank starts, according to
+    /// the selected standard. GP1(08h) bit 3 updates this as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Video standard to use
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::gpu::{VideoMode, GPU};
+    ///
+    /// let mut gpu = GPU::new();
+    /// gpu.set_video_standard(VideoMode::PAL);
+    /// assert_eq!(gpu.video_mode(), VideoMode::PAL);
+    /// ```
+    pub fn set_video_standard(&mut self, mode: VideoMode) {
+        self.display_mode.video_mode = mode;
+        self.status.video_mode = mode == VideoMode::PAL;
+
+        // Keep the counters inside the new frame geometry
+        if self.scanline >= mode.scanlines_per_frame() {
+            self.scanline = 0;
+        }
+        if self.dots >= mode.dots_per_scanline() {
+            self.dots = 0;
+        }
+    }
+
     /// Get current GPU status register value
     ///
     /// Packs all GPU status flags into a 32-bit GPUSTAT register value
@@ -516,7 +815,7 @@ impl GPU {
         status |= (self.status.dithering as u32) << 9;
         status |= (self.status.draw_to_display as u32) << 10;
         status |= (self.status.set_mask_bit as u32) << 11;
-        status |= (self.status.draw_pixels as u32) << 12;
+        status |= (!self.status.draw_pixels as u32) << 12;
         status |= (self.status.interlace_field as u32) << 13;
         status |= (self.status.reverse_flag as u32) << 14;
         status |= (self.status.texture_disable as u32) << 15;
@@ -528,21 +827,116 @@ impl GPU {
         status |= (self.status.vertical_interlace as u32) << 22;
         status |= (self.status.display_disabled as u32) << 23;
         status |= (self.status.interrupt_request as u32) << 24;
-        status |= (self.status.dma_request as u32) << 25;
-        status |= (self.status.ready_to_receive_cmd as u32) << 26;
-        status |= (self.status.ready_to_send_vram as u32) << 27;
-        status |= (self.status.ready_to_receive_dma as u32) << 28;
+        status |= self.transfer_status_bits();
         status |= ((self.status.dma_direction as u32) & 0x03) << 29;
 
-        // Bit 31: VBlank flag
-        // Set to 1 when in VBlank period (scanlines 243-262 for NTSC)
-        if self.in_vblank {
-            status |= 1 << 31;
+        // Bit 13 always reads 1 when interlace is off
+        if !self.display_mode.interlaced {
+            status |= 1 << 13;
         }
 
+        // Bit 31: Drawing even/odd lines (0 = even or VBlank, 1 = odd)
+        status |= (self.status.drawing_odd_line as u32) << 31;
+
         status
     }
 
+    /// Compute the DMA/ready bits of GPUSTAT (bits 25-28)
+    ///
+    /// The bits follow the live command FIFO and VRAM transfer state:
+    ///
+    /// - Bit 26 (ready for command): no partial command is buffered, no
+    ///   CPU→VRAM transfer is in progress and the last primitive has had
+    ///   time to finish drawing
+    /// - Bit 27 (ready to send VRAM): a VRAM→CPU transfer has data pending
+    /// - Bit 28 (ready for DMA block): the FIFO is not full
+    /// - Bit 25 (DMA request): depends on the GP1(04h) direction; off, FIFO
+    ///   not full, bit 28 or bit 27 for directions 0-3
+    ///
+    /// # Returns
+    ///
+    /// GPUSTAT bits 25-28 in place
+    fn transfer_status_bits(&self) -> u32 {
+        let direction = self.vram_transfer.as_ref().map(|t| t.direction);
+
+        let fifo_ready = self.command_fifo.len() < GP0_FIFO_DEPTH;
+        let command_ready = self.command_fifo.is_empty()
+            && direction != Some(VRAMTransferDirection::CpuToVram)
+            && !self.is_drawing();
+        let vram_ready = direction == Some(VRAMTransferDirection::VramToCpu);
+
+        let dma_request = match self.status.dma_direction & 0x03 {
+            0 => false,
+            1 | 2 => fifo_ready,
+            _ => vram_ready,
+        };
+
+        (dma_request as u32) << 25
+            | (command_ready as u32) << 26
+            | (vram_ready as u32) << 27
+            | (fifo_ready as u32) << 28
+    }
+
+    /// Keep the GPU busy for the estimated time of a primitive
+    ///
+    /// # Arguments
+    ///
+    /// * `pixels` - Number of pixels the primitive fills
+    /// * `cost` - Per-pixel work of the primitive
+    pub(crate) fn add_draw_time(&mut self, pixels: u32, cost: DrawCost) {
+        self.draw_busy_cycles = self.draw_busy_cycles.saturating_add(cost.cycles(pixels));
+    }
+
+    /// Check whether the GPU is still drawing a primitive
+    ///
+    /// # Returns
+    ///
+    /// `true` while the estimated drawing time of the last primitives has
+    /// not elapsed; GPUSTAT bit 26 reads 0 meanwhile
+    pub fn is_drawing(&self) -> bool {
+        self.draw_busy_cycles > 0
+    }
+
+    /// Number of fields woven into one displayed frame
+    ///
+    /// # Returns
+    ///
+    /// 2 in 480-line interlaced mode, 1 otherwise
+    fn field_count(&self) -> usize {
+        if self.display_mode.interlaced && self.display_mode.vertical_res == VerticalRes::R480 {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Handle VBlank entry
+    ///
+    /// Marks the current frame as complete and switches to the next
+    /// interlace field. The field only alternates while interlace is
+    /// enabled in GP1(08h).
+    fn start_vblank(&mut self) {
+        self.frame_ready = true;
+
+        if self.display_mode.interlaced {
+            self.status.interlace_field = !self.status.interlace_field;
+        }
+    }
+
+    /// Update the even/odd line flag (GPUSTAT bit 31) for the current scanline
+    ///
+    /// In 480-line interlaced mode the flag follows the field being output;
+    /// otherwise it follows the scanline parity. It always reads 0 in VBlank.
+    fn update_odd_line_flag(&mut self) {
+        self.status.drawing_odd_line = if self.in_vblank {
+            false
+        } else if self.field_count() == 2 {
+            self.status.interlace_field
+        } else {
+            self.scanline & 1 != 0
+        };
+    }
+
     /// Get the display area configuration
     ///
     /// Returns the current display area settings which define the region of VRAM
@@ -607,9 +1001,47 @@ impl GPU {
         self.scanline
     }
 
+    /// Advance the GPU by CPU cycles
+    ///
+    /// Converts CPU cycles to GPU dots so that one scanline lasts
+    /// `cycles_per_scanline()` CPU cycles of the current video standard,
+    /// keeping the scanline counter in step with the timers, then runs
+    /// [`GPU::tick`].
+    ///
+    /// # Arguments
+    ///
+    /// * `cycles` - Number of CPU cycles to advance
+    ///
+    /// # Returns
+    ///
+    /// `(vblank_interrupt, hblank_interrupt)` as returned by [`GPU::tick`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::{VideoMode, GPU};
+    ///
+    /// let mut gpu = GPU::new();
+    /// let line = VideoMode::NTSC.cycles_per_scanline();
+    ///
+    /// assert_eq!(gpu.tick_cycles(line - 1), (false, false));
+    /// assert_eq!(gpu.tick_cycles(1), (false, true));
+    /// assert_eq!(gpu.get_scanline(), 1);
+    /// ```
+    pub fn tick_cycles(&mut self, cycles: u32) -> (bool, bool) {
+        let mode = self.display_mode.video_mode;
+        let cycles_per_scanline = mode.cycles_per_scanline() as u64;
+
+        self.dot_remainder += cycles as u64 * mode.dots_per_scanline() as u64;
+        let dots = self.dot_remainder / cycles_per_scanline;
+        self.dot_remainder %= cycles_per_scanline;
+
+        self.tick(dots as u32)
+    }
+
     /// Tick GPU and update scanline/VBlank/HBlank state
     ///
-    /// Advances the GPU state by the specified number of cycles, updating the scanline
+    /// Advances the GPU state by the specified number of dots, updating the scanline
     /// counter and generating VBlank/HBlank interrupt signals when appropriate.
     ///
     /// The PlayStation GPU operates on a scanline-based timing model:
@@ -620,7 +1052,8 @@ impl GPU {
     ///
     /// # Arguments
     ///
-    /// * `cycles` - Number of CPU cycles to advance
+    /// * `dots` - Number of GPU dots to advance (see [`GPU::tick_cycles`] to
+    ///   advance by CPU cycles)
     ///
     /// # Returns
     ///
@@ -636,7 +1069,7 @@ impl GPU {
     ///
     /// let mut gpu = GPU::new();
     ///
-    /// // Tick for one CPU cycle
+    /// // Tick for one dot
     /// let (vblank, hblank) = gpu.tick(1);
     ///
     /// // Process interrupts
@@ -647,33 +1080,39 @@ impl GPU {
     ///     // Handle HBlank signal (for timers)
     /// }
     /// ```
-    pub fn tick(&mut self, cycles: u32) -> (bool, bool) {
+    pub fn tick(&mut self, dots: u32) -> (bool, bool) {
         let mut vblank_interrupt = false;
         let mut hblank_interrupt = false;
+        let mode = self.display_mode.video_mode;
 
-        for _ in 0..cycles {
+        // Dots are GPU clock cycles, the unit of the drawing time
+        self.draw_busy_cycles = self.draw_busy_cycles.saturating_sub(dots);
+
+        for _ in 0..dots {
             self.dots += 1;
 
-            if self.dots >= Self::DOTS_PER_SCANLINE {
+            if self.dots >= mode.dots_per_scanline() {
                 self.dots = 0;
                 self.scanline += 1;
 
                 // HBlank occurs at end of each scanline
                 hblank_interrupt = true;
 
-                if self.scanline >= Self::SCANLINES_PER_FRAME {
+                if self.scanline >= mode.scanlines_per_frame() {
                     self.scanline = 0;
                 }
 
                 // Check VBlank region
                 let was_in_vblank = self.in_vblank;
-                self.in_vblank =
-                    self.scanline >= Self::VBLANK_START && self.scanline < Self::VBLANK_END;
+                self.in_vblank = self.scanline >= mode.vblank_start();
 
                 // VBlank interrupt at start of VBlank
                 if self.in_vblank && !was_in_vblank {
                     vblank_interrupt = true;
+                    self.start_vblank();
                 }
+
+                self.update_odd_line_flag();
             }
 
             // HBlank during horizontal blanking period
@@ -747,18 +1186,13 @@ impl GPU {
             0x20 => self.parse_monochrome_triangle_opaque(),
             0x22 => self.parse_monochrome_triangle_semi_transparent(),
 
-            // Textured triangles
-            0x24 => self.parse_textured_triangle_opaque(),
-            0x26 => self.parse_textured_triangle_semi_transparent(),
+            // Textured polygons (flat/Gouraud, triangle/quad, raw/modulated)
+            0x24..=0x27 | 0x2C..=0x2F | 0x34..=0x37 | 0x3C..=0x3F => self.parse_textured_polygon(),
 
             // Monochrome quadrilaterals
             0x28 => self.parse_monochrome_quad_opaque(),
             0x2A => self.parse_monochrome_quad_semi_transparent(),
 
-            // Textured quadrilaterals
-            0x2C => self.parse_textured_quad_opaque(),
-            0x2E => self.parse_textured_quad_semi_transparent(),
-
             // Shaded triangles
             0x30 => self.parse_shaded_triangle_opaque(),
             0x32 => self.parse_shaded_triangle_semi_transparent(),
@@ -767,55 +1201,11 @@ impl GPU {
             0x38 => self.parse_shaded_quad_opaque(),
             0x3A => self.parse_shaded_quad_semi_transparent(),
 
-            // Lines (monochrome)
-            0x40 => self.parse_line_opaque(),
-            0x42 => self.parse_line_semi_transparent(),
-
-            // Polylines (monochrome)
-            0x48 => self.parse_polyline_opaque(),
-            0x4A => self.parse_polyline_semi_transparent(),
-
-            // Lines (shaded/Gouraud)
-            0x50 => self.parse_shaded_line_opaque(),
-            0x52 => self.parse_shaded_line_semi_transparent(),
-
-            // Polylines (shaded/Gouraud)
-            0x58 => self.parse_shaded_polyline_opaque(),
-            0x5A => self.parse_shaded_polyline_semi_transparent(),
-
-            // Monochrome rectangles
-            0x60 => self.parse_monochrome_rect_variable_opaque(),
-            0x62 => self.parse_monochrome_rect_variable_semi_transparent(),
-            0x68 => self.parse_monochrome_rect_1x1_opaque(),
-            0x6A => self.parse_monochrome_rect_1x1_semi_transparent(),
-            0x70 => self.parse_monochrome_rect_8x8_opaque(),
-            0x72 => self.parse_monochrome_rect_8x8_semi_transparent(),
-            0x78 => self.parse_monochrome_rect_16x16_opaque(),
-            0x7A => self.parse_monochrome_rect_16x16_semi_transparent(),
-
-            // Textured rectangles (variable size)
-            0x64 => self.parse_textured_rect_variable_opaque(),
-            0x65 => self.parse_textured_rect_variable_opaque_modulated(),
-            0x66 => self.parse_textured_rect_variable_semi_transparent(),
-            0x67 => self.parse_textured_rect_variable_semi_transparent_modulated(),
-
-            // Textured rectangles (1×1)
-            0x6C => self.parse_textured_rect_1x1_opaque(),
-            0x6D => self.parse_textured_rect_1x1_opaque_modulated(),
-            0x6E => self.parse_textured_rect_1x1_semi_transparent(),
-            0x6F => self.parse_textured_rect_1x1_semi_transparent_modulated(),
-
-            // Textured rectangles (8×8)
-            0x74 => self.parse_textured_rect_8x8_opaque(),
-            0x75 => self.parse_textured_rect_8x8_opaque_modulated(),
-            0x76 => self.parse_textured_rect_8x8_semi_transparent(),
-            0x77 => self.parse_textured_rect_8x8_semi_transparent_modulated(),
-
-            // Textured rectangles (16×16)
-            0x7C => self.parse_textured_rect_16x16_opaque(),
-            0x7D => self.parse_textured_rect_16x16_opaque_modulated(),
-            0x7E => self.parse_textured_rect_16x16_semi_transparent(),
-            0x7F => self.parse_textured_rect_16x16_semi_transparent_modulated(),
+            // Lines and poly-lines (flat/Gouraud)
+            0x40..=0x5F => self.parse_line(),
+
+            // Rectangles (monochrome/textured, variable/fixed size)
+            0x60..=0x7F => self.parse_rectangle(),
 
             // VRAM transfer commands
             0xA0 => self.gp0_cpu_to_vram_transfer(),
@@ -844,38 +1234,29 @@ impl GPU {
 
     /// Read from GPUREAD register (0x1F801810)
     ///
-    /// Returns pixel data during VRAM→CPU transfers. Each read returns
-    /// two 16-bit pixels packed into a 32-bit word.
+    /// Returns pixel data during VRAM→CPU transfers (GP0(C0h)). Each read
+    /// returns two 16-bit pixels packed into a 32-bit word, walking the
+    /// source rectangle row by row and wrapping at the VRAM edges. Once the
+    /// last pixel is read, GPUSTAT bit 27 is cleared and further reads return
+    /// the last value again.
+    ///
+    /// # Returns
+    ///
+    /// Packed pixel pair (first pixel in the low halfword)
     pub fn read_gpuread(&mut self) -> u32 {
         // Extract transfer state to avoid borrowing issues
         let mut transfer = match self.vram_transfer.take() {
-            Some(t) => t,
-            None => return 0,
+            Some(t) if t.direction == VRAMTransferDirection::VramToCpu => t,
+            other => {
+                self.vram_transfer = other;
+                return self.gpuread_latch;
+            }
         };
 
         // Read two pixels and pack into u32
-        let vram_x1 = (transfer.x + transfer.current_x) & 0x3FF;
-        let vram_y1 = (transfer.y + transfer.current_y) & 0x1FF;
-        let pixel1 = self.read_vram(vram_x1, vram_y1);
-
-        transfer.current_x += 1;
-        if transfer.current_x >= transfer.width {
-            transfer.current_x = 0;
-            transfer.current_y += 1;
-        }
-
+        let pixel1 = self.next_transfer_pixel(&mut transfer);
         let pixel2 = if transfer.current_y < transfer.height {
-            let vram_x2 = (transfer.x + transfer.current_x) & 0x3FF;
-            let vram_y2 = (transfer.y + transfer.current_y) & 0x1FF;
-            let p = self.read_vram(vram_x2, vram_y2);
-
-            transfer.current_x += 1;
-            if transfer.current_x >= transfer.width {
-                transfer.current_x = 0;
-                transfer.current_y += 1;
-            }
-
-            p
+            self.next_transfer_pixel(&mut transfer)
         } else {
             0
         };
@@ -888,7 +1269,31 @@ impl GPU {
             self.vram_transfer = Some(transfer);
         }
 
-        (pixel1 as u32) | ((pixel2 as u32) << 16)
+        self.gpuread_latch = (pixel1 as u32) | ((pixel2 as u32) << 16);
+        self.gpuread_latch
+    }
+
+    /// Read the next pixel of a VRAM→CPU transfer and advance its position
+    ///
+    /// # Arguments
+    ///
+    /// * `transfer` - Active transfer state
+    ///
+    /// # Returns
+    ///
+    /// The 16-bit pixel at the current position
+    fn next_transfer_pixel(&self, transfer: &mut VRAMTransfer) -> u16 {
+        let vram_x = (transfer.x + transfer.current_x) & 0x3FF;
+        let vram_y = (transfer.y + transfer.current_y) & 0x1FF;
+        let pixel = self.read_vram(vram_x, vram_y);
+
+        transfer.current_x += 1;
+        if transfer.current_x >= transfer.width {
+            transfer.current_x = 0;
+            transfer.current_y += 1;
+        }
+
+        pixel
     }
 
     /// Process GP1 command (control commands)
@@ -911,184 +1316,236 @@ impl GPU {
             }
         }
     }
+}
 
-    /// Register timing events for GPU VBlank and HBlank
-    ///
-    /// This should be called during system initialization to register GPU
-    /// timing events with the timing manager.
-    ///
-    /// # Arguments
-    ///
-    /// * `timing` - Timing event manager
-    pub fn register_events(&mut self, timing: &mut super::timing::TimingEventManager) {
-        // VBlank event: fires every frame (~16.67ms for NTSC 60Hz)
-        // Total cycles per frame = DOTS_PER_SCANLINE * SCANLINES_PER_FRAME
-        // = 3413 * 263 = 897,619 cycles
-        // But PSX CPU runs at 33.8688 MHz, and at 60fps that's 564,480 cycles/frame
-        // Using the standard PSX timing
-        const CYCLES_PER_FRAME: i32 = 564_480;
-
-        self.vblank_event = Some(timing.register_periodic_event("GPU VBlank", CYCLES_PER_FRAME));
-
-        // Activate VBlank event
-        if let Some(handle) = self.vblank_event {
-            timing.schedule(handle, CYCLES_PER_FRAME);
-        }
-
-        // HBlank event: fires every scanline
-        // = CYCLES_PER_FRAME / SCANLINES_PER_FRAME = 564_480 / 263 ≈ 2146 cycles
-        const CYCLES_PER_SCANLINE: i32 = 2_146;
-
-        self.hblank_event = Some(timing.register_periodic_event("GPU HBlank", CYCLES_PER_SCANLINE));
-
-        // Activate HBlank event
-        if let Some(handle) = self.hblank_event {
-            timing.schedule(handle, CYCLES_PER_SCANLINE);
-        }
-
-        log::info!(
-            "GPU: Timing events registered and activated (VBlank={} cycles, HBlank={} cycles)",
-            CYCLES_PER_FRAME,
-            CYCLES_PER_SCANLINE
-        );
-    }
-
-    /// Process GPU timing events
-    ///
-    /// This should be called by System when timing events fire.
-    ///
-    /// # Arguments
-    ///
-    /// * `timing` - Timing event manager
-    /// * `triggered_events` - List of event handles that have fired
-    pub fn process_events(
-        &mut self,
-        _timing: &mut super::timing::TimingEventManager,
-        triggered_events: &[EventHandle],
-    ) {
-        // Check if VBlank event fired
-        if let Some(handle) = self.vblank_event {
-            if triggered_events.contains(&handle) {
-                self.vblank_callback();
-            }
-        }
-
-        // Check if HBlank event fired
-        if let Some(handle) = self.hblank_event {
-            if triggered_events.contains(&handle) {
-                self.hblank_callback();
-            }
-        }
-    }
-
-    /// VBlank callback (called when vblank_event fires)
-    ///
-    /// Triggered at the start of vertical blanking period (scanline 243).
-    fn vblank_callback(&mut self) {
-        // Reset scanline counter at start of VBlank
-        self.scanline = Self::VBLANK_START;
-        self.in_vblank = true;
-        self.dots = 0;
-
-        // Set VBlank interrupt pending
-        self.vblank_interrupt_pending = true;
-
-        log::trace!("GPU: VBlank");
+impl Default for GPU {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    /// HBlank callback (called when hblank_event fires)
-    ///
-    /// Triggered at the end of each scanline.
-    fn hblank_callback(&mut self) {
-        self.scanline += 1;
-        self.dots = 0;
-
-        // Wrap scanline counter
-        if self.scanline >= Self::SCANLINES_PER_FRAME {
-            self.scanline = 0;
+impl StateSave for GPU {
+    type State = GPUState;
+
+    fn to_state(&self) -> GPUState {
+        GPUState {
+            vram: self.vram.clone(),
+            draw_area_left: self.draw_area.left,
+            draw_area_top: self.draw_area.top,
+            draw_area_right: self.draw_area.right,
+            draw_area_bottom: self.draw_area.bottom,
+            draw_offset_x: self.draw_offset.0,
+            draw_offset_y: self.draw_offset.1,
+            display_area_x: self.display_area.x,
+            display_area_y: self.display_area.y,
+            display_width: self.display_area.width,
+            display_height: self.display_area.height,
+            display_horiz_start: self.display_area.horizontal_range.0,
+            display_horiz_end: self.display_area.horizontal_range.1,
+            display_vert_start: self.display_area.vertical_range.0,
+            display_vert_end: self.display_area.vertical_range.1,
+            display_enabled: !self.display_mode.display_disabled,
+            display_depth_24bit: self.display_mode.display_area_color_depth == ColorDepth::C24Bit,
+            vertical_interlace: self.display_mode.interlaced,
+            horizontal_res: match self.display_mode.horizontal_res {
+                HorizontalRes::R256 => 0,
+                HorizontalRes::R320 => 1,
+                HorizontalRes::R512 => 2,
+                HorizontalRes::R640 => 3,
+                HorizontalRes::R368 => 4,
+                HorizontalRes::R384 => 5,
+            },
+            vertical_res: self.display_mode.vertical_res == VerticalRes::R480,
+            video_mode: self.display_mode.video_mode == VideoMode::PAL,
+            texture_window_mask_x: self.texture_window.mask_x,
+            texture_window_mask_y: self.texture_window.mask_y,
+            texture_window_offset_x: self.texture_window.offset_x,
+            texture_window_offset_y: self.texture_window.offset_y,
+            draw_mode_texture_page_x: (self.draw_mode.texture_page_x_base / 64) as u8,
+            draw_mode_texture_page_y: (self.draw_mode.texture_page_y_base / 256) as u8,
+            draw_mode_semi_transparency: self.draw_mode.semi_transparency,
+            draw_mode_texture_depth: self.draw_mode.texture_depth,
+            draw_mode_dithering: self.draw_mode.dithering,
+            draw_mode_draw_to_display: self.draw_mode.draw_to_display,
+            draw_mode_texture_disable: self.draw_mode.texture_disable,
+            draw_mode_rectangle_flip_x: self.draw_mode.texture_x_flip,
+            draw_mode_rectangle_flip_y: self.draw_mode.texture_y_flip,
+            mask_bit_force: self.status.set_mask_bit,
+            mask_bit_check: !self.status.draw_pixels,
+            status: self.status(),
+            interlace_field: self.status.interlace_field,
+            scanline: self.scanline,
+            dots: self.dots,
+            in_vblank: self.in_vblank,
+            in_hblank: self.in_hblank,
+            dot_remainder: self.dot_remainder,
+            draw_busy_cycles: self.draw_busy_cycles,
+            command_fifo: self.command_fifo.iter().copied().collect(),
+            vram_transfer: self.vram_transfer.as_ref().map(|t| VRAMTransferState {
+                x: t.x,
+                y: t.y,
+                width: t.width,
+                height: t.height,
+                current_x: t.current_x,
+                current_y: t.current_y,
+                vram_to_cpu: t.direction == VRAMTransferDirection::VramToCpu,
+            }),
+            gpuread_latch: self.gpuread_latch,
+            frame_ready: self.frame_ready,
         }
-
-        // Update VBlank status
-        self.in_vblank = self.scanline >= Self::VBLANK_START && self.scanline < Self::VBLANK_END;
-
-        // Set HBlank interrupt pending (always signal for timer synchronization)
-        self.hblank_interrupt_pending = true;
-
-        log::trace!("GPU: HBlank (scanline {})", self.scanline);
     }
 
-    /// Poll GPU interrupt flags
-    ///
-    /// Returns interrupt flags and clears them.
-    /// Replaces the return value of tick() for event-driven timing.
+    /// Restore the GPU from a saved state
     ///
-    /// # Returns
-    ///
-    /// Tuple of (vblank_interrupt, hblank_interrupt)
-    pub fn poll_interrupts(&mut self) -> (bool, bool) {
-        let vblank = self.vblank_interrupt_pending;
-        let hblank = self.hblank_interrupt_pending;
-
-        self.vblank_interrupt_pending = false;
-        self.hblank_interrupt_pending = false;
-
-        (vblank, hblank)
-    }
-}
+    /// Timing event handles are kept from this instance; their schedule is
+    /// restored separately with the timing state.
+    fn restore_from_state(&mut self, state: &GPUState) {
+        let len = self.vram.len().min(state.vram.len());
+        self.vram[..len].copy_from_slice(&state.vram[..len]);
+        self.vram_dirty = true;
+        self.rasterizer.clear_clut_cache();
+        self.rasterizer.reload_upscaled_vram(&self.vram);
+
+        self.draw_area = DrawingArea {
+            left: state.draw_area_left,
+            top: state.draw_area_top,
+            right: state.draw_area_right,
+            bottom: state.draw_area_bottom,
+        };
+        self.draw_offset = (state.draw_offset_x, state.draw_offset_y);
+        self.display_area = DisplayArea {
+            x: state.display_area_x,
+            y: state.display_area_y,
+            width: state.display_width,
+            height: state.display_height,
+            horizontal_range: (state.display_horiz_start, state.display_horiz_end),
+            vertical_range: (state.display_vert_start, state.display_vert_end),
+        };
+        self.display_mode = DisplayMode {
+            horizontal_res: match state.horizontal_res {
+                0 => HorizontalRes::R256,
+                2 => HorizontalRes::R512,
+                3 => HorizontalRes::R640,
+                4 => HorizontalRes::R368,
+                5 => HorizontalRes::R384,
+                _ => HorizontalRes::R320,
+            },
+            vertical_res: if state.vertical_res {
+                VerticalRes::R480
+            } else {
+                VerticalRes::R240
+            },
+            video_mode: if state.video_mode {
+                VideoMode::PAL
+            } else {
+                VideoMode::NTSC
+            },
+            display_area_color_depth: if state.display_depth_24bit {
+                ColorDepth::C24Bit
+            } else {
+                ColorDepth::C15Bit
+            },
+            interlaced: state.vertical_interlace,
+            display_disabled: !state.display_enabled,
+        };
+        self.texture_window = TextureWindow {
+            mask_x: state.texture_window_mask_x,
+            mask_y: state.texture_window_mask_y,
+            offset_x: state.texture_window_offset_x,
+            offset_y: state.texture_window_offset_y,
+        };
+        self.draw_mode = DrawMode {
+            texture_page_x_base: state.draw_mode_texture_page_x as u16 * 64,
+            texture_page_y_base: state.draw_mode_texture_page_y as u16 * 256,
+            semi_transparency: state.draw_mode_semi_transparency,
+            texture_depth: state.draw_mode_texture_depth,
+            dithering: state.draw_mode_dithering,
+            draw_to_display: state.draw_mode_draw_to_display,
+            texture_disable: state.draw_mode_texture_disable,
+            texture_x_flip: state.draw_mode_rectangle_flip_x,
+            texture_y_flip: state.draw_mode_rectangle_flip_y,
+        };
 
-impl Default for GPU {
-    fn default() -> Self {
-        Self::new()
+        let bits = state.status;
+        let bit = |n: u32| bits & (1 << n) != 0;
+        self.status = GPUStatus {
+            texture_page_x_base: (bits & 0x0F) as u8,
+            texture_page_y_base: ((bits >> 4) & 0x01) as u8,
+            semi_transparency: ((bits >> 5) & 0x03) as u8,
+            texture_depth: ((bits >> 7) & 0x03) as u8,
+            dithering: bit(9),
+            draw_to_display: bit(10),
+            set_mask_bit: state.mask_bit_force,
+            draw_pixels: !state.mask_bit_check,
+            interlace_field: state.interlace_field,
+            reverse_flag: bit(14),
+            texture_disable: bit(15),
+            horizontal_res_2: ((bits >> 16) & 0x01) as u8,
+            horizontal_res_1: ((bits >> 17) & 0x03) as u8,
+            vertical_res: bit(19),
+            video_mode: bit(20),
+            display_area_color_depth: bit(21),
+            vertical_interlace: bit(22),
+            display_disabled: bit(23),
+            interrupt_request: bit(24),
+            dma_request: bit(25),
+            ready_to_receive_cmd: bit(26),
+            ready_to_send_vram: bit(27),
+            ready_to_receive_dma: bit(28),
+            dma_direction: ((bits >> 29) & 0x03) as u8,
+            drawing_odd_line: bit(31),
+        };
+        self.rasterizer
+            .set_mask_settings(state.mask_bit_force, state.mask_bit_check);
+        self.update_rasterizer_clip_rect();
+
+        self.command_fifo = state.command_fifo.iter().copied().collect();
+        self.vram_transfer = state.vram_transfer.as_ref().map(|t| VRAMTransfer {
+            x: t.x,
+            y: t.y,
+            width: t.width,
+            height: t.height,
+            current_x: t.current_x,
+            current_y: t.current_y,
+            direction: if t.vram_to_cpu {
+                VRAMTransferDirection::VramToCpu
+            } else {
+                VRAMTransferDirection::CpuToVram
+            },
+        });
+        self.gpuread_latch = state.gpuread_latch;
+
+        self.scanline = state.scanline;
+        self.dots = state.dots;
+        self.in_vblank = state.in_vblank;
+        self.in_hblank = state.in_hblank;
+        self.dot_remainder = state.dot_remainder;
+        self.draw_busy_cycles = state.draw_busy_cycles;
+        self.frame_ready = state.frame_ready;
     }
 }
 
-/// ⚠️ **UNUSED PREPARATORY CODE - NOT CURRENTLY INVOKED** ⚠️
-///
-/// This IODevice trait implementation for GPU is **preparatory code** that exists for
-/// future Phase 2 work. It is NOT used by any current code path.
-///
-/// **Current Implementation**: The Bus directly calls GPU methods via
-/// `gpu.borrow_mut().read_gpuread()` / `write_gp0()` / `write_gp1()`. This provides
-/// proper mutable access and works correctly.
+/// IODevice trait implementation for GPU
 ///
-/// **Future Work (Phase 2+)**: Before switching the Bus to trait-based device routing,
-/// the GPUREAD limitation below MUST be resolved via one of these approaches:
-/// - Change IODevice trait signature to allow `read_register(&mut self)`
-/// - Use interior mutability (RefCell/Mutex) for VRAM transfer state
-/// - Implement a small IO wrapper type around GPU that handles mutability
+/// The Bus currently routes GPU registers directly through
+/// `gpu.borrow_mut().read_gpuread()` / `write_gp0()` / `write_gp1()`; this
+/// implementation provides the same behavior for trait-based device routing.
 ///
 /// ## GPU Register Layout
 ///
 /// The GPU has two 32-bit registers accessible via memory-mapped I/O:
 /// - Offset 0x00 (0x1F801810): GP0 (write) / GPUREAD (read)
 /// - Offset 0x04 (0x1F801814): GP1 (write) / GPUSTAT (read)
-///
-/// ## Known Limitations (To Be Fixed Before Activation)
-///
-/// - **GPUREAD register returns status instead of transfer data**: The `read_gpuread()`
-///   method requires `&mut self` to consume VRAM→CPU transfer data, but the IODevice
-///   trait's `read_register()` only provides `&self`. The current workaround returns
-///   `status()` with a warning. **This MUST be fixed before activating trait-based
-///   routing**, otherwise VRAM-to-CPU transfers will not work.
-/// - **No runtime impact currently**: Since this implementation is unused, the limitation
-///   doesn't affect emulation accuracy or behavior.
-#[allow(dead_code)]
 impl crate::core::memory::IODevice for GPU {
     fn address_range(&self) -> (u32, u32) {
         // GPU registers: 0x1F801810 - 0x1F801817 (8 bytes, 2 registers)
         (0x1F801810, 0x1F801817)
     }
 
-    fn read_register(&self, offset: u32) -> crate::core::error::Result<u32> {
+    fn read_register(&mut self, offset: u32) -> crate::core::error::Result<u32> {
         match offset {
             // GPUREAD register (0x1F801810, offset 0x00)
-            0x00 => {
-                // GPUREAD requires &mut self, but trait uses &self
-                // This is a limitation - we'll need to handle this specially
-                // For now, log a warning and return status
-                log::warn!("GPUREAD access via IODevice trait (requires mutable access)");
-                Ok(self.status())
-            }
+            0x00 => Ok(self.read_gpuread()),
 
             // GPUSTAT register (0x1F801814, offset 0x04)
             0x04 => Ok(self.status()),
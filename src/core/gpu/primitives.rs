@@ -107,10 +107,7 @@ impl Color {
     /// assert_eq!((rgb15 >> 10) & 0x1F, 8); // B: 64 >> 3 = 8
     /// ```
     pub fn to_rgb15(&self) -> u16 {
-        let r = ((self.r as u16) >> 3) & 0x1F;
-        let g = ((self.g as u16) >> 3) & 0x1F;
-        let b = ((self.b as u16) >> 3) & 0x1F;
-        (b << 10) | (g << 5) | r
+        super::rgb888_to_rgb555(self.r, self.g, self.b, super::ColorRounding::Truncate)
     }
 }
 
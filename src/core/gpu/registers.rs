@@ -110,6 +110,10 @@ pub struct TextureWindow {
 /// Display area configuration
 ///
 /// Defines the region of VRAM that is output to the display.
+///
+/// The display start (GP1(05h)) gives the VRAM origin, while the width and
+/// height are derived from the horizontal and vertical display ranges
+/// (GP1(06h)/GP1(07h)) and the current display mode.
 #[derive(Debug, Clone, Copy)]
 pub struct DisplayArea {
     /// Display area X coordinate in VRAM
@@ -123,6 +127,12 @@ pub struct DisplayArea {
 
     /// Display height in pixels
     pub height: u16,
+
+    /// Horizontal display range (X1, X2) in video clock cycles
+    pub horizontal_range: (u16, u16),
+
+    /// Vertical display range (Y1, Y2) in scanlines
+    pub vertical_range: (u16, u16),
 }
 
 impl Default for DisplayArea {
@@ -132,6 +142,8 @@ impl Default for DisplayArea {
             y: 0,
             width: 320,
             height: 240,
+            horizontal_range: (0x200, 0xC00),
+            vertical_range: (0x010, 0x100),
         }
     }
 }
@@ -197,6 +209,25 @@ pub enum HorizontalRes {
     R384,
 }
 
+impl HorizontalRes {
+    /// Video clock cycles per dot for this resolution
+    ///
+    /// The dot clock is the GPU video clock (~53.69MHz) divided by this value.
+    ///
+    /// # Returns
+    ///
+    /// 10 (256), 8 (320), 7 (368/384), 5 (512) or 4 (640)
+    pub fn dot_clock_divider(self) -> u32 {
+        match self {
+            HorizontalRes::R256 => 10,
+            HorizontalRes::R320 => 8,
+            HorizontalRes::R368 | HorizontalRes::R384 => 7,
+            HorizontalRes::R512 => 5,
+            HorizontalRes::R640 => 4,
+        }
+    }
+}
+
 /// Vertical resolution modes
 ///
 /// The GPU supports two vertical resolutions, with different values for NTSC and PAL.
@@ -221,6 +252,73 @@ pub enum VideoMode {
     PAL,
 }
 
+impl VideoMode {
+    /// CPU cycles per scanline for this video standard
+    ///
+    /// PAL lines are 3406 cycles of a slower (~53.20MHz) video clock,
+    /// which works out slightly longer than NTSC lines in CPU cycles.
+    ///
+    /// # Returns
+    ///
+    /// 2146 (NTSC) or 2168 (PAL)
+    pub fn cycles_per_scanline(self) -> u32 {
+        match self {
+            VideoMode::NTSC => 2146,
+            VideoMode::PAL => 2168,
+        }
+    }
+
+    /// Video clock dots per scanline for this video standard
+    ///
+    /// # Returns
+    ///
+    /// 3413 (NTSC) or 3406 (PAL)
+    pub fn dots_per_scanline(self) -> u16 {
+        match self {
+            VideoMode::NTSC => 3413,
+            VideoMode::PAL => 3406,
+        }
+    }
+
+    /// Total scanlines per frame for this video standard
+    ///
+    /// # Returns
+    ///
+    /// 263 (NTSC) or 314 (PAL)
+    pub fn scanlines_per_frame(self) -> u16 {
+        match self {
+            VideoMode::NTSC => 263,
+            VideoMode::PAL => 314,
+        }
+    }
+
+    /// First scanline of the vertical blanking period
+    ///
+    /// VBlank lasts from this line until the scanline counter wraps.
+    ///
+    /// # Returns
+    ///
+    /// 243 (NTSC) or 288 (PAL)
+    pub fn vblank_start(self) -> u16 {
+        match self {
+            VideoMode::NTSC => 243,
+            VideoMode::PAL => 288,
+        }
+    }
+
+    /// CPU cycles per frame for this video standard
+    ///
+    /// # Returns
+    ///
+    /// 564,480 (NTSC, 60Hz) or 677,376 (PAL, 50Hz)
+    pub fn cycles_per_frame(self) -> u32 {
+        match self {
+            VideoMode::NTSC => 33_868_800 / 60,
+            VideoMode::PAL => 33_868_800 / 50,
+        }
+    }
+}
+
 /// Display color depth
 ///
 /// Specifies the color depth used for display output.
@@ -259,7 +357,9 @@ pub struct GPUStatus {
     /// Set mask bit when drawing
     pub set_mask_bit: bool,
 
-    /// Check mask bit before drawing (don't draw if set)
+    /// Draw over masked pixels (false when GP0(E6h) check-mask is enabled)
+    ///
+    /// Reported inverted in GPUSTAT bit 12 (1 = don't draw to masked areas).
     pub draw_pixels: bool,
 
     /// Interlace field (even/odd)
@@ -324,7 +424,7 @@ impl Default for GPUStatus {
             dithering: false,
             draw_to_display: false,
             set_mask_bit: false,
-            draw_pixels: false,
+            draw_pixels: true,
             interlace_field: false,
             reverse_flag: false,
             texture_disable: false,
@@ -636,4 +736,28 @@ mod tests {
 
         assert_ne!(c15bit, c24bit);
     }
+
+    #[test]
+    fn test_horizontal_res_dot_clock_divider() {
+        assert_eq!(HorizontalRes::R256.dot_clock_divider(), 10);
+        assert_eq!(HorizontalRes::R320.dot_clock_divider(), 8);
+        assert_eq!(HorizontalRes::R368.dot_clock_divider(), 7);
+        assert_eq!(HorizontalRes::R512.dot_clock_divider(), 5);
+        assert_eq!(HorizontalRes::R640.dot_clock_divider(), 4);
+    }
+
+    #[test]
+    fn test_video_mode_cycles_per_scanline() {
+        assert_eq!(VideoMode::NTSC.cycles_per_scanline(), 2146);
+        assert_eq!(VideoMode::PAL.cycles_per_scanline(), 2168);
+        assert!(VideoMode::PAL.cycles_per_scanline() > VideoMode::NTSC.cycles_per_scanline());
+    }
+
+    #[test]
+    fn test_video_mode_frame_timing() {
+        assert_eq!(VideoMode::NTSC.scanlines_per_frame(), 263);
+        assert_eq!(VideoMode::PAL.scanlines_per_frame(), 314);
+        assert_eq!(VideoMode::NTSC.cycles_per_frame(), 564_480);
+        assert_eq!(VideoMode::PAL.cycles_per_frame(), 677_376);
+    }
 }
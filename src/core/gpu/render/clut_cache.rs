@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CLUT (palette) cache for indexed textures
+//!
+//! Sprite-heavy scenes draw many 4-bit and 8-bit primitives sharing a few
+//! palettes. Instead of reading the CLUT from VRAM for every texel, the
+//! rasterizer copies the palette once per primitive and keeps it until the
+//! CLUT coordinate changes or a VRAM write lands inside it.
+
+use std::cell::Cell;
+
+/// Palette entries of the CLUT most recently used for drawing
+pub(super) struct ClutCache {
+    /// CLUT X, Y and entry count (16 or 256) of the cached palette
+    key: Option<(u16, u16, u16)>,
+
+    /// Set when a VRAM write overlaps the cached palette
+    ///
+    /// Pixel writes only hold `&self`, so the flag uses interior mutability.
+    stale: Cell<bool>,
+
+    /// Cached palette entries
+    entries: [u16; 256],
+}
+
+impl ClutCache {
+    /// Create an empty cache
+    pub(super) fn new() -> Self {
+        Self {
+            key: None,
+            stale: Cell::new(false),
+            entries: [0; 256],
+        }
+    }
+
+    /// Make sure the palette at (x, y) is cached
+    ///
+    /// Reloads from VRAM only if a different CLUT is cached or the cached
+    /// one was overwritten. Entries past the right edge of VRAM read as 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - VRAM buffer (1024×512)
+    /// * `x` - CLUT X coordinate
+    /// * `y` - CLUT Y coordinate
+    /// * `len` - Number of entries (16 for 4-bit, 256 for 8-bit textures)
+    pub(super) fn load(&mut self, vram: &[u16], x: u16, y: u16, len: u16) {
+        if self.key == Some((x, y, len)) && !self.stale.get() {
+            return;
+        }
+
+        for (i, entry) in self.entries.iter_mut().take(len as usize).enumerate() {
+            let px = x as usize + i;
+            *entry = if px < 1024 && y < 512 {
+                vram[y as usize * 1024 + px]
+            } else {
+                0
+            };
+        }
+
+        self.key = Some((x, y, len));
+        self.stale.set(false);
+    }
+
+    /// Look up a palette entry if the CLUT at (x, y) is cached and current
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - CLUT X coordinate
+    /// * `y` - CLUT Y coordinate
+    /// * `index` - Palette index
+    ///
+    /// # Returns
+    ///
+    /// The cached entry, or `None` if it must be read from VRAM
+    #[inline(always)]
+    pub(super) fn lookup(&self, x: u16, y: u16, index: u16) -> Option<u16> {
+        match self.key {
+            Some((cx, cy, len)) if cx == x && cy == y && index < len && !self.stale.get() => {
+                Some(self.entries[index as usize])
+            }
+            _ => None,
+        }
+    }
+
+    /// Mark the cache stale if (x, y) lies inside the cached palette
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X coordinate of the written pixel
+    /// * `y` - Y coordinate of the written pixel
+    #[inline(always)]
+    pub(super) fn invalidate_pixel(&self, x: u16, y: u16) {
+        if let Some((cx, cy, len)) = self.key {
+            if y == cy && x >= cx && x - cx < len {
+                self.stale.set(true);
+            }
+        }
+    }
+
+    /// Drop the cached palette
+    pub(super) fn clear(&mut self) {
+        self.key = None;
+        self.stale.set(false);
+    }
+}
@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Drawing time estimates for primitives
+//!
+//! The software rasterizer finishes a primitive immediately, but the real
+//! GPU needs time roughly proportional to the number of pixels it fills,
+//! and more for textured, shaded or semi-transparent pixels. While that
+//! time runs, GPUSTAT bit 26 (ready to receive command) reads 0.
+//!
+//! Costs are in GPU clock cycles, the unit of the scanline dot counter,
+//! and are estimates rather than cycle-exact figures.
+
+use super::super::registers::DrawingArea;
+
+/// Fixed cost of starting any primitive
+pub(crate) const SETUP_CYCLES: u32 = 16;
+
+/// What a primitive does per pixel, which sets its drawing cost
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct DrawCost {
+    /// Texels are fetched (and looked up in the CLUT)
+    pub textured: bool,
+
+    /// Colors are interpolated across the primitive
+    pub shaded: bool,
+
+    /// The background is read back for semi-transparency
+    pub blended: bool,
+}
+
+impl DrawCost {
+    /// Estimate the GPU cycles needed to draw `pixels` pixels
+    ///
+    /// Each pixel costs one cycle, plus one for texturing and half a
+    /// cycle each for shading and blending.
+    ///
+    /// # Arguments
+    ///
+    /// * `pixels` - Number of pixels drawn
+    ///
+    /// # Returns
+    ///
+    /// Drawing time in GPU clock cycles, including the setup cost
+    pub(crate) fn cycles(self, pixels: u32) -> u32 {
+        let half_cycles_per_pixel =
+            2 + 2 * self.textured as u32 + self.shaded as u32 + self.blended as u32;
+        SETUP_CYCLES.saturating_add(pixels.saturating_mul(half_cycles_per_pixel) / 2)
+    }
+}
+
+/// Approximate the pixel count of a triangle
+///
+/// Uses the triangle's area, limited to the part of its bounding box that
+/// lies inside the drawing area.
+///
+/// # Arguments
+///
+/// * `v0`, `v1`, `v2` - Vertices with the drawing offset applied
+/// * `area` - Drawing area (clipping rectangle)
+///
+/// # Returns
+///
+/// Estimated number of pixels drawn
+pub(crate) fn triangle_pixels(
+    v0: (i16, i16),
+    v1: (i16, i16),
+    v2: (i16, i16),
+    area: &DrawingArea,
+) -> u32 {
+    let (x0, y0) = (v0.0 as i64, v0.1 as i64);
+    let (x1, y1) = (v1.0 as i64, v1.1 as i64);
+    let (x2, y2) = (v2.0 as i64, v2.1 as i64);
+    let triangle = ((x1 - x0) * (y2 - y0) - (x2 - x0) * (y1 - y0)).unsigned_abs() / 2;
+
+    let left = x0.min(x1).min(x2);
+    let top = y0.min(y1).min(y2);
+    let right = x0.max(x1).max(x2);
+    let bottom = y0.max(y1).max(y2);
+    let visible = clipped_area(left, top, right + 1, bottom + 1, area);
+
+    triangle.min(visible) as u32
+}
+
+/// Pixel count of a rectangle after clipping to the drawing area
+///
+/// # Arguments
+///
+/// * `x`, `y` - Top-left corner with the drawing offset applied
+/// * `width`, `height` - Size in pixels
+/// * `area` - Drawing area (clipping rectangle)
+///
+/// # Returns
+///
+/// Number of pixels drawn
+pub(crate) fn rectangle_pixels(x: i16, y: i16, width: u16, height: u16, area: &DrawingArea) -> u32 {
+    let (x, y) = (x as i64, y as i64);
+    clipped_area(x, y, x + width as i64, y + height as i64, area) as u32
+}
+
+/// Area of the half-open rectangle [left, right) × [top, bottom) inside `area`
+fn clipped_area(left: i64, top: i64, right: i64, bottom: i64, area: &DrawingArea) -> u64 {
+    let width = right.min(area.right as i64 + 1) - left.max(area.left as i64);
+    let height = bottom.min(area.bottom as i64 + 1) - top.max(area.top as i64);
+    (width.max(0) * height.max(0)) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn full_area() -> DrawingArea {
+        DrawingArea {
+            left: 0,
+            top: 0,
+            right: 1023,
+            bottom: 511,
+        }
+    }
+
+    #[test]
+    fn test_draw_cost_grows_with_texturing_and_blending() {
+        let flat = DrawCost::default();
+        let textured = DrawCost {
+            textured: true,
+            ..flat
+        };
+        let blended = DrawCost {
+            textured: true,
+            blended: true,
+            ..flat
+        };
+
+        assert_eq!(flat.cycles(0), SETUP_CYCLES);
+        assert_eq!(flat.cycles(100), SETUP_CYCLES + 100);
+        assert_eq!(textured.cycles(100), SETUP_CYCLES + 200);
+        assert_eq!(blended.cycles(100), SETUP_CYCLES + 250);
+    }
+
+    #[test]
+    fn test_pixel_counts_are_clipped_to_drawing_area() {
+        let area = full_area();
+        assert_eq!(triangle_pixels((0, 0), (100, 0), (0, 100), &area), 5000);
+        assert_eq!(rectangle_pixels(10, 10, 16, 8, &area), 128);
+
+        // Entirely outside the drawing area
+        assert_eq!(triangle_pixels((-300, 0), (-100, 0), (-300, 200), &area), 0);
+        assert_eq!(rectangle_pixels(1020, 0, 16, 8, &area), 32);
+    }
+}
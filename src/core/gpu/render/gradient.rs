@@ -17,8 +17,9 @@
 //!
 //! Implements gradient triangle and quad rasterization with per-vertex colors.
 
-use super::super::primitives::{Color, Vertex};
+use super::super::primitives::{BlendMode, Color, Vertex};
 use super::super::GPU;
+use super::draw_time::{triangle_pixels, DrawCost};
 
 impl GPU {
     /// Render a gradient (Gouraud-shaded) triangle
@@ -40,7 +41,9 @@ impl GPU {
     ///
     /// # Notes
     ///
-    /// Semi-transparency is currently ignored (will be implemented in #36).
+    /// Semi-transparent triangles blend with the background using the
+    /// current draw mode's semi-transparency setting.
+    /// Interpolated colors are dithered when dithering is enabled in the draw mode.
     /// The drawing offset is applied to all vertices before rasterization.
     pub(crate) fn render_gradient_triangle(
         &mut self,
@@ -86,16 +89,29 @@ impl GPU {
             colors[2].b
         );
 
+        let cost = DrawCost {
+            shaded: true,
+            blended: semi_transparent,
+            ..DrawCost::default()
+        };
+        self.add_draw_time(triangle_pixels(v0, v1, v2, &self.draw_area), cost);
+
         let c0 = (colors[0].r, colors[0].g, colors[0].b);
         let c1 = (colors[1].r, colors[1].g, colors[1].b);
         let c2 = (colors[2].r, colors[2].g, colors[2].b);
 
-        // For now, ignore semi_transparent (will be implemented in #36)
-        let _ = semi_transparent;
+        let blend_mode =
+            semi_transparent.then(|| BlendMode::from_bits(self.draw_mode.semi_transparency));
 
         // Rasterize the gradient triangle
-        self.rasterizer
-            .draw_gradient_triangle(&mut self.vram, v0, c0, v1, c1, v2, c2);
+        self.rasterizer.draw_shaded_triangle(
+            &mut self.vram,
+            (v0, c0),
+            (v1, c1),
+            (v2, c2),
+            blend_mode,
+            self.draw_mode.dithering,
+        );
     }
 
     /// Render a gradient (Gouraud-shaded) quadrilateral
@@ -585,4 +601,95 @@ mod tests {
 
         assert_ne!(gpu.read_vram(0, 0), 0x0000);
     }
+
+    #[test]
+    fn test_gradient_triangle_blend_modes() {
+        // Background (16, 16, 16) and foreground (8, 8, 8) in 5-bit channels
+        let background = 0x4210;
+        let gray = |c: u16| (c << 10) | (c << 5) | c;
+        let expected = [
+            (0, gray(12)), // B/2 + F/2
+            (1, gray(24)), // B + F
+            (2, gray(8)),  // B - F
+            (3, gray(18)), // B + F/4
+        ];
+
+        for (mode, result) in expected {
+            let mut gpu = GPU::new();
+            gpu.draw_mode.semi_transparency = mode;
+            for y in 0..32 {
+                for x in 0..32 {
+                    gpu.write_vram(x, y, background);
+                }
+            }
+
+            // GP0(0x32): Shaded triangle, semi-transparent
+            gpu.write_gp0(0x32404040);
+            gpu.write_gp0(0x00000000); // V1: (0, 0)
+            gpu.write_gp0(0x00404040);
+            gpu.write_gp0(0x0000001F); // V2: (31, 0)
+            gpu.write_gp0(0x00404040);
+            gpu.write_gp0(0x001F0000); // V3: (0, 31)
+
+            assert_eq!(gpu.read_vram(4, 4), result, "mode {}", mode);
+            assert_eq!(gpu.read_vram(30, 30), background, "mode {}", mode);
+        }
+    }
+
+    #[test]
+    fn test_gradient_blend_clamps_channels() {
+        let mut gpu = GPU::new();
+        gpu.draw_mode.semi_transparency = 1; // B + F
+        gpu.write_vram(2, 2, 0x7FFF);
+
+        let vertices = [
+            Vertex { x: 0, y: 0 },
+            Vertex { x: 8, y: 0 },
+            Vertex { x: 0, y: 8 },
+        ];
+        let white = Color {
+            r: 255,
+            g: 255,
+            b: 255,
+        };
+        gpu.render_gradient_triangle(&vertices, &[white; 3], true);
+
+        assert_eq!(gpu.read_vram(2, 2), 0x7FFF);
+        assert_eq!(gpu.read_vram(1, 1), 0x7FFF);
+    }
+
+    #[test]
+    fn test_gradient_triangle_dithering_changes_low_bits() {
+        let vertices = [
+            Vertex { x: 0, y: 0 },
+            Vertex { x: 64, y: 0 },
+            Vertex { x: 0, y: 64 },
+        ];
+        let colors = [
+            Color { r: 0, g: 0, b: 0 },
+            Color {
+                r: 128,
+                g: 128,
+                b: 128,
+            },
+            Color { r: 0, g: 0, b: 0 },
+        ];
+
+        let mut plain = GPU::new();
+        plain.render_gradient_triangle(&vertices, &colors, false);
+
+        let mut dithered = GPU::new();
+        dithered.draw_mode.dithering = true;
+        dithered.render_gradient_triangle(&vertices, &colors, false);
+
+        let row: Vec<(u16, u16)> = (0..32)
+            .map(|x| (plain.read_vram(x, 4), dithered.read_vram(x, 4)))
+            .collect();
+
+        // Dithering perturbs some pixels by at most one 5-bit step
+        assert!(row.iter().any(|(a, b)| a != b));
+        assert!(row
+            .iter()
+            .all(|&(a, b)| ((a & 0x1F) as i16 - (b & 0x1F) as i16).abs() <= 1));
+    }
 }
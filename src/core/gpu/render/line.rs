@@ -17,8 +17,19 @@
 //!
 //! Implements line and polyline rasterization using Bresenham's algorithm.
 
-use super::super::primitives::{Color, Vertex};
+use super::super::primitives::{BlendMode, Color, Vertex};
 use super::super::GPU;
+use super::draw_time::DrawCost;
+
+/// Maximum horizontal distance between line endpoints
+///
+/// Lines spanning 1024 or more pixels horizontally are not drawn.
+const MAX_LINE_WIDTH: i16 = 1023;
+
+/// Maximum vertical distance between line endpoints
+///
+/// Lines spanning 512 or more pixels vertically are not drawn.
+const MAX_LINE_HEIGHT: i16 = 511;
 
 impl GPU {
     /// Render a monochrome line
@@ -32,11 +43,6 @@ impl GPU {
     /// * `v1` - End vertex
     /// * `color` - Line color
     /// * `semi_transparent` - Whether semi-transparency is enabled
-    ///
-    /// # Notes
-    ///
-    /// Semi-transparency is currently ignored (will be implemented in #36).
-    /// The drawing offset is applied to both endpoints before rasterization.
     pub(crate) fn render_line(
         &mut self,
         v0: Vertex,
@@ -44,37 +50,7 @@ impl GPU {
         color: Color,
         semi_transparent: bool,
     ) {
-        // Apply drawing offset
-        let x0 = v0.x.wrapping_add(self.draw_offset.0);
-        let y0 = v0.y.wrapping_add(self.draw_offset.1);
-        let x1 = v1.x.wrapping_add(self.draw_offset.0);
-        let y1 = v1.y.wrapping_add(self.draw_offset.1);
-
-        log::trace!(
-            "Rendering {}line: ({}, {}) -> ({}, {}) color=({},{},{})",
-            if semi_transparent {
-                "semi-transparent "
-            } else {
-                ""
-            },
-            x0,
-            y0,
-            x1,
-            y1,
-            color.r,
-            color.g,
-            color.b
-        );
-
-        // Convert color to 15-bit RGB format
-        let color_15bit = color.to_rgb15();
-
-        // For now, ignore semi_transparent (will be implemented in #36)
-        let _ = semi_transparent;
-
-        // Rasterize the line
-        self.rasterizer
-            .draw_line(&mut self.vram, x0, y0, x1, y1, color_15bit);
+        self.render_line_segment((v0, color), (v1, color), false, semi_transparent);
     }
 
     /// Render a polyline (connected line segments)
@@ -98,49 +74,16 @@ impl GPU {
         color: Color,
         semi_transparent: bool,
     ) {
-        if vertices.len() < 2 {
-            return;
+        for pair in vertices.windows(2) {
+            self.render_line_segment((pair[0], color), (pair[1], color), false, semi_transparent);
         }
-
-        log::trace!(
-            "Rendering {}polyline with {} vertices, color=({},{},{})",
-            if semi_transparent {
-                "semi-transparent "
-            } else {
-                ""
-            },
-            vertices.len(),
-            color.r,
-            color.g,
-            color.b
-        );
-
-        // Convert color to 15-bit RGB format
-        let color_15bit = color.to_rgb15();
-
-        // For now, ignore semi_transparent (will be implemented in #36)
-        let _ = semi_transparent;
-
-        // Apply drawing offset to all vertices
-        let points: Vec<(i16, i16)> = vertices
-            .iter()
-            .map(|v| {
-                (
-                    v.x.wrapping_add(self.draw_offset.0),
-                    v.y.wrapping_add(self.draw_offset.1),
-                )
-            })
-            .collect();
-
-        // Rasterize the polyline
-        self.rasterizer
-            .draw_polyline(&mut self.vram, &points, color_15bit);
     }
 
     /// Render a shaded line with Gouraud shading
     ///
     /// Applies the drawing offset to both vertices and rasterizes the line
-    /// with color interpolation between the two endpoints.
+    /// with color interpolation between the two endpoints. The interpolated
+    /// colors are dithered when dithering is enabled in the draw mode.
     ///
     /// # Arguments
     ///
@@ -149,11 +92,6 @@ impl GPU {
     /// * `v1` - End vertex
     /// * `c1` - End vertex color
     /// * `semi_transparent` - Whether semi-transparency is enabled
-    ///
-    /// # Notes
-    ///
-    /// Semi-transparency is currently ignored (will be implemented in #36).
-    /// The drawing offset is applied to both endpoints before rasterization.
     pub(crate) fn render_shaded_line(
         &mut self,
         v0: Vertex,
@@ -162,44 +100,7 @@ impl GPU {
         c1: Color,
         semi_transparent: bool,
     ) {
-        // Apply drawing offset
-        let x0 = v0.x.wrapping_add(self.draw_offset.0);
-        let y0 = v0.y.wrapping_add(self.draw_offset.1);
-        let x1 = v1.x.wrapping_add(self.draw_offset.0);
-        let y1 = v1.y.wrapping_add(self.draw_offset.1);
-
-        log::trace!(
-            "Rendering {}shaded line: ({}, {}) color=({},{},{}) -> ({}, {}) color=({},{},{})",
-            if semi_transparent {
-                "semi-transparent "
-            } else {
-                ""
-            },
-            x0,
-            y0,
-            c0.r,
-            c0.g,
-            c0.b,
-            x1,
-            y1,
-            c1.r,
-            c1.g,
-            c1.b
-        );
-
-        // For now, ignore semi_transparent (will be implemented in #36)
-        let _ = semi_transparent;
-
-        // Rasterize the line with color interpolation
-        self.rasterizer.draw_gradient_line(
-            &mut self.vram,
-            x0,
-            y0,
-            (c0.r, c0.g, c0.b),
-            x1,
-            y1,
-            (c1.r, c1.g, c1.b),
-        );
+        self.render_line_segment((v0, c0), (v1, c1), true, semi_transparent);
     }
 
     /// Render a shaded polyline (connected line segments with per-vertex colors)
@@ -223,40 +124,89 @@ impl GPU {
         colors: &[Color],
         semi_transparent: bool,
     ) {
-        if vertices.len() < 2 || colors.len() < 2 {
-            return;
+        let points: Vec<(Vertex, Color)> = vertices
+            .iter()
+            .copied()
+            .zip(colors.iter().copied())
+            .collect();
+
+        for pair in points.windows(2) {
+            self.render_line_segment(pair[0], pair[1], true, semi_transparent);
         }
+    }
+
+    /// Render one line segment
+    ///
+    /// Applies the drawing offset and rasterizes the segment with the
+    /// current semi-transparency mode. Segments longer than the hardware
+    /// limits (1023 horizontally, 511 vertically) are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Start vertex and color
+    /// * `end` - End vertex and color
+    /// * `gouraud` - Whether colors are interpolated (enables dithering)
+    /// * `semi_transparent` - Whether semi-transparency is enabled
+    fn render_line_segment(
+        &mut self,
+        start: (Vertex, Color),
+        end: (Vertex, Color),
+        gouraud: bool,
+        semi_transparent: bool,
+    ) {
+        let (v0, c0) = start;
+        let (v1, c1) = end;
+
+        // Apply drawing offset
+        let x0 = v0.x.wrapping_add(self.draw_offset.0);
+        let y0 = v0.y.wrapping_add(self.draw_offset.1);
+        let x1 = v1.x.wrapping_add(self.draw_offset.0);
+        let y1 = v1.y.wrapping_add(self.draw_offset.1);
 
         log::trace!(
-            "Rendering {}shaded polyline with {} vertices",
+            "Rendering {}line: ({}, {}) color=({},{},{}) -> ({}, {}) color=({},{},{})",
             if semi_transparent {
                 "semi-transparent "
             } else {
                 ""
             },
-            vertices.len()
+            x0,
+            y0,
+            c0.r,
+            c0.g,
+            c0.b,
+            x1,
+            y1,
+            c1.r,
+            c1.g,
+            c1.b
         );
 
-        // For now, ignore semi_transparent (will be implemented in #36)
-        let _ = semi_transparent;
+        let width = (x1 as i32 - x0 as i32).abs();
+        let height = (y1 as i32 - y0 as i32).abs();
+        if width > MAX_LINE_WIDTH as i32 || height > MAX_LINE_HEIGHT as i32 {
+            log::trace!("Skipping line exceeding {}x{}", width, height);
+            return;
+        }
 
-        // Apply drawing offset to all vertices
-        let points: Vec<(i16, i16)> = vertices
-            .iter()
-            .map(|v| {
-                (
-                    v.x.wrapping_add(self.draw_offset.0),
-                    v.y.wrapping_add(self.draw_offset.1),
-                )
-            })
-            .collect();
+        let cost = DrawCost {
+            shaded: gouraud,
+            blended: semi_transparent,
+            ..DrawCost::default()
+        };
+        self.add_draw_time(width.max(height) as u32 + 1, cost);
 
-        // Convert colors to tuples
-        let color_tuples: Vec<(u8, u8, u8)> = colors.iter().map(|c| (c.r, c.g, c.b)).collect();
+        let blend_mode =
+            semi_transparent.then(|| BlendMode::from_bits(self.draw_mode.semi_transparency));
+        let dither = gouraud && self.draw_mode.dithering;
 
-        // Rasterize the shaded polyline
-        self.rasterizer
-            .draw_gradient_polyline(&mut self.vram, &points, &color_tuples);
+        self.rasterizer.draw_shaded_line(
+            &mut self.vram,
+            ((x0, y0), (c0.r, c0.g, c0.b)),
+            ((x1, y1), (c1.r, c1.g, c1.b)),
+            blend_mode,
+            dither,
+        );
     }
 }
 
@@ -21,12 +21,17 @@
 //! - Line and polyline rendering
 //! - Texture-mapped primitives (4-bit, 8-bit, 15-bit)
 
+mod clut_cache;
+mod draw_time;
 mod gradient;
 mod line;
 mod quad;
 mod rasterizer;
 mod texture;
 mod triangle;
+mod upscale;
 
 // Public re-exports
+pub(crate) use draw_time::{rectangle_pixels, DrawCost};
 pub use rasterizer::Rasterizer;
+pub use upscale::MAX_UPSCALE;
@@ -41,8 +41,22 @@
 //! - [Triangle Rasterization Tutorial](https://www.sunshine2k.de/coding/java/TriangleRasterization/TriangleRasterization.html)
 //! - [Scratchapixel: Rasterization](https://www.scratchapixel.com/lessons/3d-basic-rendering/rasterization-practical-implementation)
 
+use super::super::color::{rgb555_to_rgb888, rgb888_to_rgb555, ColorRounding};
 use super::super::primitives::{Color, TextureDepth, TextureInfo};
 use super::super::registers::{DrawMode, DrawingArea};
+use super::clut_cache::ClutCache;
+use super::upscale::{UpscaleBuffer, MAX_UPSCALE};
+
+/// Dither offsets indexed by `[y & 3][x & 3]`
+///
+/// Added to each 8-bit color channel before truncation to 5 bits when
+/// dithering is enabled (GP0(E1h) bit 9).
+const DITHER_TABLE: [[i16; 4]; 4] = [
+    [-4, 0, -3, 1],
+    [2, -2, 3, -1],
+    [-3, 1, -4, 0],
+    [3, -1, 2, -2],
+];
 
 /// Triangle rasterizer using scanline algorithm
 ///
@@ -73,6 +87,23 @@ pub struct Rasterizer {
     /// All pixels are clipped to this rectangle.
     /// Format: (left, top, right, bottom) - all inclusive
     clip_rect: (i16, i16, i16, i16),
+
+    /// Force bit 15 on in every drawn pixel (GP0(E6h) bit 0)
+    set_mask: bool,
+
+    /// Skip pixels whose bit 15 is already set (GP0(E6h) bit 1)
+    check_mask: bool,
+
+    /// Palette of the last indexed-texture primitive
+    clut_cache: ClutCache,
+
+    /// Oversized framebuffer, present when upscaling is enabled
+    upscale: Option<UpscaleBuffer>,
+
+    /// Scale of the pass being drawn (1 for native VRAM)
+    ///
+    /// Sets the stride and bounds used by pixel writes.
+    pass_scale: i16,
 }
 
 impl Rasterizer {
@@ -84,6 +115,11 @@ impl Rasterizer {
     pub fn new() -> Self {
         Self {
             clip_rect: (0, 0, 1023, 511),
+            set_mask: false,
+            check_mask: false,
+            clut_cache: ClutCache::new(),
+            upscale: None,
+            pass_scale: 1,
         }
     }
 
@@ -102,6 +138,32 @@ impl Rasterizer {
         self.clip_rect = (left, top, right, bottom);
     }
 
+    /// Set the mask bit behavior
+    ///
+    /// Mirrors GP0(E6h). The mask bit is bit 15 of each VRAM pixel.
+    ///
+    /// # Arguments
+    ///
+    /// * `set_mask` - Set bit 15 in every pixel drawn
+    /// * `check_mask` - Leave pixels with bit 15 set untouched
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::gpu::Rasterizer;
+    ///
+    /// let mut vram = vec![0u16; 1024 * 512];
+    /// let mut rasterizer = Rasterizer::new();
+    /// rasterizer.set_mask_settings(true, false);
+    ///
+    /// rasterizer.draw_line(&mut vram, 0, 0, 0, 0, 0x001F);
+    /// assert_eq!(vram[0], 0x801F);
+    /// ```
+    pub fn set_mask_settings(&mut self, set_mask: bool, check_mask: bool) {
+        self.set_mask = set_mask;
+        self.check_mask = check_mask;
+    }
+
     /// Rasterize a solid color triangle
     ///
     /// Uses a scanline algorithm to fill the triangle with the specified color.
@@ -129,6 +191,22 @@ impl Rasterizer {
         v1: (i16, i16),
         v2: (i16, i16),
         color: u16,
+    ) {
+        self.upscaled_pass(|r, target, s| {
+            let scale = |v| Self::scale_point(v, s);
+            r.fill_triangle(target, scale(v0), scale(v1), scale(v2), color);
+        });
+        self.fill_triangle(vram, v0, v1, v2, color);
+    }
+
+    /// Body of [`Rasterizer::draw_triangle`] for a single pass
+    fn fill_triangle(
+        &mut self,
+        vram: &mut [u16],
+        v0: (i16, i16),
+        v1: (i16, i16),
+        v2: (i16, i16),
+        color: u16,
     ) {
         // Sort vertices by Y coordinate (v0.y <= v1.y <= v2.y)
         let (v0, v1, v2) = Self::sort_vertices_by_y(v0, v1, v2);
@@ -289,7 +367,7 @@ impl Rasterizer {
 
         // Draw pixels
         for x in x1..=x2 {
-            Self::write_pixel(vram, x, y, color);
+            self.write_pixel(vram, x, y, color);
         }
     }
 
@@ -359,6 +437,8 @@ impl Rasterizer {
     /// Write a single pixel to VRAM
     ///
     /// Performs bounds checking and writes the pixel if coordinates are valid.
+    /// Honors the mask settings: masked pixels are skipped when checking is
+    /// enabled, and bit 15 is forced on when setting is enabled.
     ///
     /// # Arguments
     ///
@@ -367,17 +447,35 @@ impl Rasterizer {
     /// * `y` - Y coordinate
     /// * `color` - Pixel color
     #[inline(always)]
-    fn write_pixel(vram: &mut [u16], x: i16, y: i16, color: u16) {
+    fn write_pixel(&self, vram: &mut [u16], x: i16, y: i16, color: u16) {
         // Bounds check using range contains
-        if !(0..1024).contains(&x) || !(0..512).contains(&y) {
+        let (width, height) = self.pass_size();
+        if !(0..width).contains(&x) || !(0..height).contains(&y) {
             return;
         }
 
-        let index = (y as usize) * 1024 + (x as usize);
+        let index = (y as usize) * (width as usize) + (x as usize);
+
+        if self.check_mask && vram[index] & 0x8000 != 0 {
+            return;
+        }
 
         // Write pixel to VRAM
         // Bounds are checked above, so this is safe
-        vram[index] = color;
+        vram[index] = color | self.mask_bit();
+        if self.pass_scale == 1 {
+            self.clut_cache.invalidate_pixel(x as u16, y as u16);
+        }
+    }
+
+    /// Bit 15 value forced into drawn pixels by the set-mask setting
+    #[inline(always)]
+    fn mask_bit(&self) -> u16 {
+        if self.set_mask {
+            0x8000
+        } else {
+            0
+        }
     }
 
     /// Write a blended pixel to VRAM with semi-transparency
@@ -426,18 +524,192 @@ impl Rasterizer {
         blend_mode: crate::core::gpu::BlendMode,
     ) {
         // Bounds check
-        if !(0..1024).contains(&x) || !(0..512).contains(&y) {
+        let (width, height) = self.pass_size();
+        if !(0..width).contains(&x) || !(0..height).contains(&y) {
             return;
         }
 
-        let index = (y as usize) * 1024 + (x as usize);
+        let index = (y as usize) * (width as usize) + (x as usize);
 
         // Read background pixel
         let background = vram[index];
+        if self.check_mask && background & 0x8000 != 0 {
+            return;
+        }
 
-        // Blend and write
+        // Blend and write, keeping the foreground's bit 15 (texel STP bit)
         let blended = blend_mode.blend(background, color);
-        vram[index] = blended;
+        vram[index] = blended | (color & 0x8000) | self.mask_bit();
+        if self.pass_scale == 1 {
+            self.clut_cache.invalidate_pixel(x as u16, y as u16);
+        }
+    }
+
+    /// Width and height of the buffer drawn by the current pass
+    #[inline(always)]
+    fn pass_size(&self) -> (i16, i16) {
+        (1024 * self.pass_scale, 512 * self.pass_scale)
+    }
+
+    /// Notify the rasterizer of a VRAM write made outside of drawing
+    ///
+    /// Marks the cached palette stale if the pixel lies inside it. Called by
+    /// the GPU for transfers, fills and copies.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X coordinate (0-1023)
+    /// * `y` - Y coordinate (0-511)
+    #[inline(always)]
+    pub(crate) fn invalidate_clut_pixel(&self, x: u16, y: u16) {
+        self.clut_cache.invalidate_pixel(x, y);
+    }
+
+    /// Drop the cached palette, e.g. after VRAM was replaced wholesale
+    pub(crate) fn clear_clut_cache(&mut self) {
+        self.clut_cache.clear();
+    }
+
+    /// Enable or disable internal-resolution upscaling
+    ///
+    /// With a factor above 1, every primitive is additionally rendered at
+    /// `factor`× resolution into an oversized framebuffer, readable with
+    /// [`Rasterizer::upscaled_vram`]. Native VRAM is still drawn as before,
+    /// so transfers, copies and texture sampling are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - Scale factor, clamped to 1..=`MAX_UPSCALE` (1 disables)
+    /// * `vram` - Native VRAM buffer used to seed the oversized framebuffer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::gpu::Rasterizer;
+    ///
+    /// let mut vram = vec![0u16; 1024 * 512];
+    /// let mut rasterizer = Rasterizer::new();
+    /// rasterizer.set_upscale(2, &vram);
+    ///
+    /// rasterizer.draw_triangle(&mut vram, (0, 0), (8, 0), (0, 8), 0x7FFF);
+    /// assert_eq!(rasterizer.upscaled_vram().unwrap().len(), 2048 * 1024);
+    /// ```
+    pub fn set_upscale(&mut self, factor: u16, vram: &[u16]) {
+        let factor = factor.clamp(1, MAX_UPSCALE);
+        if factor == self.upscale_factor() {
+            return;
+        }
+        self.upscale = (factor > 1).then(|| UpscaleBuffer::new(factor, vram));
+    }
+
+    /// Current internal-resolution scale factor (1 when disabled)
+    pub fn upscale_factor(&self) -> u16 {
+        self.upscale.as_ref().map_or(1, UpscaleBuffer::factor)
+    }
+
+    /// Oversized framebuffer drawn at the upscale factor
+    ///
+    /// # Returns
+    ///
+    /// `1024 * factor` × `512 * factor` pixels, row-major, or `None` if
+    /// upscaling is disabled
+    pub fn upscaled_vram(&self) -> Option<&[u16]> {
+        self.upscale.as_ref().map(UpscaleBuffer::pixels)
+    }
+
+    /// Mirror a VRAM write made outside of drawing into the upscaled buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X coordinate (0-1023)
+    /// * `y` - Y coordinate (0-511)
+    /// * `value` - Pixel value written to VRAM
+    #[inline(always)]
+    pub(crate) fn sync_upscaled_pixel(&mut self, x: u16, y: u16, value: u16) {
+        if let Some(buffer) = &mut self.upscale {
+            buffer.write_native_pixel(x, y, value);
+        }
+    }
+
+    /// Rebuild the upscaled buffer from VRAM, e.g. after a reset or load
+    pub(crate) fn reload_upscaled_vram(&mut self, vram: &[u16]) {
+        if let Some(buffer) = &mut self.upscale {
+            buffer.reload(vram);
+        }
+    }
+
+    /// Run one draw into the upscaled buffer, if upscaling is enabled
+    ///
+    /// The clip rectangle and pixel bounds are scaled for the duration of
+    /// the pass. Callers run this before drawing into native VRAM, so
+    /// textures are sampled from the same VRAM contents in both passes.
+    ///
+    /// # Arguments
+    ///
+    /// * `draw` - Called with the rasterizer, the upscaled buffer and the
+    ///   scale factor
+    fn upscaled_pass(&mut self, draw: impl FnOnce(&mut Self, &mut [u16], i16)) {
+        let Some(mut buffer) = self.upscale.take() else {
+            return;
+        };
+
+        let scale = buffer.factor() as i16;
+        let clip = self.clip_rect;
+        let edge = |v: i16| v.saturating_add(1).saturating_mul(scale) - 1;
+        self.clip_rect = (
+            clip.0.saturating_mul(scale),
+            clip.1.saturating_mul(scale),
+            edge(clip.2),
+            edge(clip.3),
+        );
+        self.pass_scale = scale;
+
+        draw(self, buffer.pixels_mut(), scale);
+
+        self.pass_scale = 1;
+        self.clip_rect = clip;
+        self.upscale = Some(buffer);
+    }
+
+    /// Scale a vertex for the upscaled pass
+    #[inline(always)]
+    fn scale_point(v: (i16, i16), scale: i16) -> (i16, i16) {
+        (v.0.saturating_mul(scale), v.1.saturating_mul(scale))
+    }
+
+    /// Load the CLUT of an indexed texture into the palette cache
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Reference to VRAM buffer
+    /// * `info` - Texture information (CLUT position and depth)
+    fn prepare_clut(&mut self, vram: &[u16], info: &TextureInfo) {
+        let len = match info.depth {
+            TextureDepth::T4Bit => 16,
+            TextureDepth::T8Bit => 256,
+            TextureDepth::T15Bit => return,
+        };
+        self.clut_cache.load(vram, info.clut_x, info.clut_y, len);
+    }
+
+    /// Read a CLUT entry, from the palette cache when possible
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Reference to VRAM buffer
+    /// * `info` - Texture information (CLUT position)
+    /// * `index` - Palette index
+    ///
+    /// # Returns
+    ///
+    /// Raw 16-bit CLUT entry
+    #[inline(always)]
+    fn clut_entry(&self, vram: &[u16], info: &TextureInfo, index: u16) -> u16 {
+        self.clut_cache
+            .lookup(info.clut_x, info.clut_y, index)
+            .unwrap_or_else(|| {
+                Self::read_vram_pixel(vram, (info.clut_x + index) as i16, info.clut_y as i16)
+            })
     }
 
     /// Rasterize a semi-transparent solid color triangle
@@ -493,6 +765,23 @@ impl Rasterizer {
         v2: (i16, i16),
         color: u16,
         blend_mode: crate::core::gpu::BlendMode,
+    ) {
+        self.upscaled_pass(|r, target, s| {
+            let scale = |v| Self::scale_point(v, s);
+            r.fill_triangle_blended(target, scale(v0), scale(v1), scale(v2), color, blend_mode);
+        });
+        self.fill_triangle_blended(vram, v0, v1, v2, color, blend_mode);
+    }
+
+    /// Body of [`Rasterizer::draw_triangle_blended`] for a single pass
+    fn fill_triangle_blended(
+        &mut self,
+        vram: &mut [u16],
+        v0: (i16, i16),
+        v1: (i16, i16),
+        v2: (i16, i16),
+        color: u16,
+        blend_mode: crate::core::gpu::BlendMode,
     ) {
         // Sort vertices by Y coordinate (v0.y <= v1.y <= v2.y)
         let (v0, v1, v2) = Self::sort_vertices_by_y(v0, v1, v2);
@@ -653,6 +942,16 @@ impl Rasterizer {
     /// rasterizer.draw_line(&mut vram, 0, 0, 100, 100, 0x7FFF);
     /// ```
     pub fn draw_line(&mut self, vram: &mut [u16], x0: i16, y0: i16, x1: i16, y1: i16, color: u16) {
+        self.upscaled_pass(|r, target, s| {
+            let (x0, y0) = Self::scale_point((x0, y0), s);
+            let (x1, y1) = Self::scale_point((x1, y1), s);
+            r.trace_line(target, x0, y0, x1, y1, color);
+        });
+        self.trace_line(vram, x0, y0, x1, y1, color);
+    }
+
+    /// Body of [`Rasterizer::draw_line`] for a single pass
+    fn trace_line(&mut self, vram: &mut [u16], x0: i16, y0: i16, x1: i16, y1: i16, color: u16) {
         let dx = (x1 - x0).abs();
         let dy = -(y1 - y0).abs();
         let sx = if x0 < x1 { 1 } else { -1 };
@@ -666,7 +965,7 @@ impl Rasterizer {
             // Check clipping bounds before drawing
             let (clip_left, clip_top, clip_right, clip_bottom) = self.clip_rect;
             if x >= clip_left && x <= clip_right && y >= clip_top && y <= clip_bottom {
-                Self::write_pixel(vram, x, y, color);
+                self.write_pixel(vram, x, y, color);
             }
 
             if x == x1 && y == y1 {
@@ -745,12 +1044,13 @@ impl Rasterizer {
     /// # Algorithm
     ///
     /// Uses Bresenham's line algorithm with linear color interpolation:
-    /// 1. Calculate line parameters (dx, dy, steps)
+    /// 1. Calculate line parameters (dx, dy, steps along the major axis)
     /// 2. For each pixel along the line:
-    ///    - Calculate interpolation factor t = distance / total_length
-    ///    - Interpolate color: C = c0 * (1-t) + c1 * t
+    ///    - Interpolate color: C = c0 + (c1 - c0) * step / steps
     ///    - Convert to 15-bit and write pixel
     ///
+    /// This is the opaque, undithered form of `draw_shaded_line`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -773,44 +1073,98 @@ impl Rasterizer {
         y1: i16,
         c1: (u8, u8, u8),
     ) {
-        let dx = (x1 - x0).abs();
-        let dy = -(y1 - y0).abs();
+        self.draw_shaded_line(vram, ((x0, y0), c0), ((x1, y1), c1), None, false);
+    }
+
+    /// Draw a Gouraud-shaded line with optional semi-transparency and dithering
+    ///
+    /// Rasterizes the segment with Bresenham's algorithm. Colors are
+    /// interpolated linearly over the major axis, so both endpoints receive
+    /// exactly their vertex color.
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Mutable reference to VRAM buffer
+    /// * `p0` - Start point ((x, y), (r, g, b))
+    /// * `p1` - End point ((x, y), (r, g, b))
+    /// * `blend_mode` - Semi-transparency mode, or `None` for opaque
+    /// * `dither` - Apply the 4x4 dither pattern before truncating to 15-bit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::gpu::{BlendMode, Rasterizer};
+    ///
+    /// let mut vram = vec![0u16; 1024 * 512];
+    /// let mut rasterizer = Rasterizer::new();
+    ///
+    /// rasterizer.draw_shaded_line(
+    ///     &mut vram,
+    ///     ((0, 0), (255, 0, 0)),
+    ///     ((100, 50), (0, 0, 255)),
+    ///     Some(BlendMode::Average),
+    ///     true,
+    /// );
+    /// ```
+    pub fn draw_shaded_line(
+        &mut self,
+        vram: &mut [u16],
+        p0: ((i16, i16), (u8, u8, u8)),
+        p1: ((i16, i16), (u8, u8, u8)),
+        blend_mode: Option<crate::core::gpu::BlendMode>,
+        dither: bool,
+    ) {
+        self.upscaled_pass(|r, target, s| {
+            let scale = |p: ((i16, i16), (u8, u8, u8))| (Self::scale_point(p.0, s), p.1);
+            r.trace_shaded_line(target, scale(p0), scale(p1), blend_mode, dither);
+        });
+        self.trace_shaded_line(vram, p0, p1, blend_mode, dither);
+    }
+
+    /// Body of [`Rasterizer::draw_shaded_line`] for a single pass
+    fn trace_shaded_line(
+        &mut self,
+        vram: &mut [u16],
+        p0: ((i16, i16), (u8, u8, u8)),
+        p1: ((i16, i16), (u8, u8, u8)),
+        blend_mode: Option<crate::core::gpu::BlendMode>,
+        dither: bool,
+    ) {
+        let ((x0, y0), c0) = p0;
+        let ((x1, y1), c1) = p1;
+
+        let dx = (x1 as i32 - x0 as i32).abs();
+        let dy = -(y1 as i32 - y0 as i32).abs();
         let sx = if x0 < x1 { 1 } else { -1 };
         let sy = if y0 < y1 { 1 } else { -1 };
         let mut err = dx + dy;
 
-        // Calculate total distance for interpolation
-        let total_distance = ((dx * dx + dy * dy) as f32).sqrt();
-        if total_distance == 0.0 {
-            // Single point - just draw it with the start color
-            let (clip_left, clip_top, clip_right, clip_bottom) = self.clip_rect;
-            if x0 >= clip_left && x0 <= clip_right && y0 >= clip_top && y0 <= clip_bottom {
-                let color = Self::rgb_to_rgb15(c0.0, c0.1, c0.2);
-                Self::write_pixel(vram, x0, y0, color);
-            }
-            return;
-        }
+        // Number of steps along the major axis, used for color interpolation
+        let steps = dx.max(-dy).max(1);
+        let lerp =
+            |a: u8, b: u8, step: i32| (a as i32 + (b as i32 - a as i32) * step / steps) as u8;
 
+        let (clip_left, clip_top, clip_right, clip_bottom) = self.clip_rect;
         let mut x = x0;
         let mut y = y0;
+        let mut step = 0;
 
         loop {
-            // Check clipping bounds before drawing
-            let (clip_left, clip_top, clip_right, clip_bottom) = self.clip_rect;
             if x >= clip_left && x <= clip_right && y >= clip_top && y <= clip_bottom {
-                // Calculate interpolation factor (0.0 at start, 1.0 at end)
-                let dist_x = x - x0;
-                let dist_y = y - y0;
-                let current_distance = ((dist_x * dist_x + dist_y * dist_y) as f32).sqrt();
-                let t = current_distance / total_distance;
-
-                // Interpolate color
-                let r = (c0.0 as f32 * (1.0 - t) + c1.0 as f32 * t) as u8;
-                let g = (c0.1 as f32 * (1.0 - t) + c1.1 as f32 * t) as u8;
-                let b = (c0.2 as f32 * (1.0 - t) + c1.2 as f32 * t) as u8;
-
-                let color = Self::rgb_to_rgb15(r, g, b);
-                Self::write_pixel(vram, x, y, color);
+                let r = lerp(c0.0, c1.0, step);
+                let g = lerp(c0.1, c1.1, step);
+                let b = lerp(c0.2, c1.2, step);
+
+                let color = if dither {
+                    Self::dither_rgb15(x, y, r, g, b)
+                } else {
+                    Self::rgb_to_rgb15(r, g, b)
+                };
+
+                match blend_mode {
+                    Some(mode) => self.write_pixel_blended(vram, x, y, color, mode),
+                    None => self.write_pixel(vram, x, y, color),
+                }
             }
 
             if x == x1 && y == y1 {
@@ -826,6 +1180,7 @@ impl Rasterizer {
                 err += dx;
                 y += sy;
             }
+            step += 1;
         }
     }
 
@@ -941,9 +1296,53 @@ impl Rasterizer {
         c1: (u8, u8, u8),
         v2: (i16, i16),
         c2: (u8, u8, u8),
+    ) {
+        self.draw_shaded_triangle(vram, (v0, c0), (v1, c1), (v2, c2), None, false);
+    }
+
+    /// Draw a Gouraud-shaded triangle with optional semi-transparency and dithering
+    ///
+    /// Same rasterization as `draw_gradient_triangle`, but each interpolated
+    /// pixel is blended with the background when a blend mode is given, and
+    /// the interpolated color is dithered before truncation when requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Mutable reference to VRAM buffer
+    /// * `p0` - First vertex ((x, y), (r, g, b))
+    /// * `p1` - Second vertex ((x, y), (r, g, b))
+    /// * `p2` - Third vertex ((x, y), (r, g, b))
+    /// * `blend_mode` - Semi-transparency mode, or `None` for opaque
+    /// * `dither` - Apply the 4x4 dither pattern before truncating to 15-bit
+    pub fn draw_shaded_triangle(
+        &mut self,
+        vram: &mut [u16],
+        p0: ((i16, i16), (u8, u8, u8)),
+        p1: ((i16, i16), (u8, u8, u8)),
+        p2: ((i16, i16), (u8, u8, u8)),
+        blend_mode: Option<crate::core::gpu::BlendMode>,
+        dither: bool,
+    ) {
+        self.upscaled_pass(|r, target, s| {
+            let scale = |p: ((i16, i16), (u8, u8, u8))| (Self::scale_point(p.0, s), p.1);
+            r.fill_shaded_triangle(target, scale(p0), scale(p1), scale(p2), blend_mode, dither);
+        });
+        self.fill_shaded_triangle(vram, p0, p1, p2, blend_mode, dither);
+    }
+
+    /// Body of [`Rasterizer::draw_shaded_triangle`] for a single pass
+    fn fill_shaded_triangle(
+        &mut self,
+        vram: &mut [u16],
+        p0: ((i16, i16), (u8, u8, u8)),
+        p1: ((i16, i16), (u8, u8, u8)),
+        p2: ((i16, i16), (u8, u8, u8)),
+        blend_mode: Option<crate::core::gpu::BlendMode>,
+        dither: bool,
     ) {
         // Sort vertices by Y
-        let (v0, c0, v1, c1, v2, c2) = Self::sort_gradient_vertices(v0, c0, v1, c1, v2, c2);
+        let (v0, c0, v1, c1, v2, c2) =
+            Self::sort_gradient_vertices(p0.0, p0.1, p1.0, p1.1, p2.0, p2.1);
 
         if v0.1 == v2.1 {
             return; // Degenerate triangle
@@ -967,8 +1366,15 @@ impl Rasterizer {
                     let g = (c0.1 as f32 * w0 + c1.1 as f32 * w1 + c2.1 as f32 * w2) as u8;
                     let b = (c0.2 as f32 * w0 + c1.2 as f32 * w1 + c2.2 as f32 * w2) as u8;
 
-                    let color = Self::rgb_to_rgb15(r, g, b);
-                    Self::write_pixel(vram, x, y, color);
+                    let color = if dither {
+                        Self::dither_rgb15(x, y, r, g, b)
+                    } else {
+                        Self::rgb_to_rgb15(r, g, b)
+                    };
+                    match blend_mode {
+                        Some(mode) => self.write_pixel_blended(vram, x, y, color, mode),
+                        None => self.write_pixel(vram, x, y, color),
+                    }
                 }
             }
         }
@@ -1059,10 +1465,29 @@ impl Rasterizer {
     /// - Bits 10-14: Blue (5 bits)
     /// - Bit 15: Mask bit (always 0)
     fn rgb_to_rgb15(r: u8, g: u8, b: u8) -> u16 {
-        let r = ((r as u16) >> 3) & 0x1F;
-        let g = ((g as u16) >> 3) & 0x1F;
-        let b = ((b as u16) >> 3) & 0x1F;
-        (b << 10) | (g << 5) | r
+        rgb888_to_rgb555(r, g, b, ColorRounding::Truncate)
+    }
+
+    /// Convert 24-bit RGB to 15-bit RGB with dithering
+    ///
+    /// Adds the dither offset for the pixel position to each channel,
+    /// clamps to 0-255, then truncates to 5 bits.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Pixel X coordinate
+    /// * `y` - Pixel Y coordinate
+    /// * `r` - Red channel (0-255)
+    /// * `g` - Green channel (0-255)
+    /// * `b` - Blue channel (0-255)
+    ///
+    /// # Returns
+    ///
+    /// 16-bit color in 5-5-5 RGB format (bit 15 is 0)
+    fn dither_rgb15(x: i16, y: i16, r: u8, g: u8, b: u8) -> u16 {
+        let offset = DITHER_TABLE[(y & 3) as usize][(x & 3) as usize];
+        let apply = |c: u8| (c as i16 + offset).clamp(0, 255) as u8;
+        Self::rgb_to_rgb15(apply(r), apply(g), apply(b))
     }
 
     /// Convert 15-bit RGB to 24-bit RGB format
@@ -1088,10 +1513,7 @@ impl Rasterizer {
     /// // White: 0x7FFF -> (248, 248, 248)
     /// ```
     fn rgb15_to_rgb24(color: u16) -> (u8, u8, u8) {
-        let r = ((color & 0x1F) << 3) as u8;
-        let g = (((color >> 5) & 0x1F) << 3) as u8;
-        let b = (((color >> 10) & 0x1F) << 3) as u8;
-        (r, g, b)
+        rgb555_to_rgb888(color, ColorRounding::Truncate)
     }
 
     /// Read a pixel from VRAM safely
@@ -1197,34 +1619,147 @@ impl Rasterizer {
         texture_window: &crate::core::gpu::TextureWindow,
         tint_color: (u8, u8, u8),
     ) {
+        self.draw_shaded_textured_triangle(
+            vram,
+            (v0, t0, tint_color),
+            (v1, t1, tint_color),
+            (v2, t2, tint_color),
+            texture_info,
+            texture_window,
+            true,
+            None,
+            false,
+        );
+    }
+
+    /// Draw a textured triangle with per-vertex (Gouraud) modulation
+    ///
+    /// Each vertex carries a position, texture coordinate and modulation
+    /// color. Texture coordinates and colors are interpolated with barycentric
+    /// weights. Texels with the raw value 0x0000 are fully transparent and
+    /// are skipped. When a blend mode is given, only texels with bit 15 set
+    /// are blended with the background; other texels are drawn opaque.
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Mutable reference to VRAM buffer
+    /// * `p0` - First vertex: position, texture coordinate, color
+    /// * `p1` - Second vertex: position, texture coordinate, color
+    /// * `p2` - Third vertex: position, texture coordinate, color
+    /// * `texture_info` - Texture page and CLUT information
+    /// * `texture_window` - Texture window settings
+    /// * `modulated` - Multiply texels by the interpolated color (false = raw texture)
+    /// * `blend_mode` - Semi-transparency mode, or `None` for opaque drawing
+    /// * `dither` - Dither modulated texels (ignored for raw textures)
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    pub fn draw_shaded_textured_triangle(
+        &mut self,
+        vram: &mut [u16],
+        p0: ((i16, i16), (u8, u8), (u8, u8, u8)),
+        p1: ((i16, i16), (u8, u8), (u8, u8, u8)),
+        p2: ((i16, i16), (u8, u8), (u8, u8, u8)),
+        texture_info: &crate::core::gpu::TextureInfo,
+        texture_window: &crate::core::gpu::TextureWindow,
+        modulated: bool,
+        blend_mode: Option<crate::core::gpu::BlendMode>,
+        dither: bool,
+    ) {
+        self.prepare_clut(vram, texture_info);
+        self.upscaled_pass(|r, target, s| {
+            let scale =
+                |p: ((i16, i16), (u8, u8), (u8, u8, u8))| (Self::scale_point(p.0, s), p.1, p.2);
+            r.fill_textured_triangle(
+                target,
+                Some(vram),
+                [scale(p0), scale(p1), scale(p2)],
+                texture_info,
+                texture_window,
+                modulated,
+                blend_mode,
+                dither,
+            );
+        });
+        self.fill_textured_triangle(
+            vram,
+            None,
+            [p0, p1, p2],
+            texture_info,
+            texture_window,
+            modulated,
+            blend_mode,
+            dither,
+        );
+    }
+
+    /// Body of [`Rasterizer::draw_shaded_textured_triangle`] for a single pass
+    ///
+    /// Texels come from `textures` if given, otherwise from `vram` itself.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    fn fill_textured_triangle(
+        &mut self,
+        vram: &mut [u16],
+        textures: Option<&[u16]>,
+        points: [((i16, i16), (u8, u8), (u8, u8, u8)); 3],
+        texture_info: &crate::core::gpu::TextureInfo,
+        texture_window: &crate::core::gpu::TextureWindow,
+        modulated: bool,
+        blend_mode: Option<crate::core::gpu::BlendMode>,
+        dither: bool,
+    ) {
+        let [(v0, t0, c0), (v1, t1, c1), (v2, t2, c2)] = points;
+
         // Compute bounding box clipped to drawing area
         let min_x = v0.0.min(v1.0).min(v2.0).max(self.clip_rect.0);
         let max_x = v0.0.max(v1.0).max(v2.0).min(self.clip_rect.2);
         let min_y = v0.1.min(v1.1).min(v2.1).max(self.clip_rect.1);
         let max_y = v0.1.max(v1.1).max(v2.1).min(self.clip_rect.3);
 
+        let lerp = |a: u8, b: u8, c: u8, w: (f32, f32, f32)| -> u16 {
+            (a as f32 * w.0 + b as f32 * w.1 + c as f32 * w.2) as u16
+        };
+
         // Rasterize using barycentric coordinates
         for y in min_y..=max_y {
             for x in min_x..=max_x {
-                let (w0, w1, w2) = Self::barycentric(x, y, v0, v1, v2);
+                let w = Self::barycentric(x, y, v0, v1, v2);
 
                 // Check if inside triangle
-                if w0 >= 0.0 && w1 >= 0.0 && w2 >= 0.0 {
-                    // Interpolate texture coordinates
-                    let u = (t0.0 as f32 * w0 + t1.0 as f32 * w1 + t2.0 as f32 * w2) as u8;
-                    let v = (t0.1 as f32 * w0 + t1.1 as f32 * w1 + t2.1 as f32 * w2) as u8;
+                if w.0 < 0.0 || w.1 < 0.0 || w.2 < 0.0 {
+                    continue;
+                }
 
-                    // Sample texture with texture window
-                    let tex_color = self.sample_texture(vram, u, v, texture_info, texture_window);
+                // Interpolate texture coordinates and sample
+                let u = lerp(t0.0, t1.0, t2.0, w) as u8;
+                let v = lerp(t0.1, t1.1, t2.1, w) as u8;
+                let texels = textures.unwrap_or(vram);
+                let texel = self.fetch_texel(texels, u, v, texture_info, texture_window);
+
+                // Raw 0x0000 texels are transparent
+                if texel == 0x0000 {
+                    continue;
+                }
 
-                    // Apply tint (modulate)
-                    // Multiply by tint and divide by 128 (shift right by 7)
-                    let r = ((tex_color.0 as u16 * tint_color.0 as u16) >> 7) as u8;
-                    let g = ((tex_color.1 as u16 * tint_color.1 as u16) >> 7) as u8;
-                    let b = ((tex_color.2 as u16 * tint_color.2 as u16) >> 7) as u8;
+                let mut color = texel & 0x7FFF;
+                if modulated {
+                    // Multiply by the color and divide by 128, saturating
+                    let (r, g, b) = Self::rgb15_to_rgb24(texel);
+                    let modulate = |t: u8, c: u16| ((t as u16 * c) >> 7).min(255) as u8;
+                    let r = modulate(r, lerp(c0.0, c1.0, c2.0, w));
+                    let g = modulate(g, lerp(c0.1, c1.1, c2.1, w));
+                    let b = modulate(b, lerp(c0.2, c1.2, c2.2, w));
+                    color = if dither {
+                        Self::dither_rgb15(x, y, r, g, b)
+                    } else {
+                        Self::rgb_to_rgb15(r, g, b)
+                    };
+                }
+                color |= texel & 0x8000;
 
-                    let color = Self::rgb_to_rgb15(r, g, b);
-                    Self::write_pixel(vram, x, y, color);
+                match blend_mode {
+                    Some(mode) if texel & 0x8000 != 0 => {
+                        self.write_pixel_blended(vram, x, y, color, mode)
+                    }
+                    _ => self.write_pixel(vram, x, y, color),
                 }
             }
         }
@@ -1280,11 +1815,9 @@ impl Rasterizer {
 
         (u, v)
     }
-
-    /// Sample texture at given coordinates
+    /// Fetch the raw 16-bit texel at given coordinates
     ///
-    /// Dispatches to the appropriate texture sampling function based on
-    /// texture depth (4-bit, 8-bit, or 15-bit).
+    /// Applies the texture window and dispatches on texture depth.
     ///
     /// # Arguments
     ///
@@ -1296,23 +1829,22 @@ impl Rasterizer {
     ///
     /// # Returns
     ///
-    /// Tuple (r, g, b) with 8-bit RGB values
-    fn sample_texture(
+    /// Raw texel value (direct color or CLUT entry)
+    fn fetch_texel(
         &self,
         vram: &[u16],
         u: u8,
         v: u8,
         info: &crate::core::gpu::TextureInfo,
         window: &crate::core::gpu::TextureWindow,
-    ) -> (u8, u8, u8) {
+    ) -> u16 {
         // Apply texture window masking
         let (u, v) = self.apply_texture_window(u, v, window);
 
-        use crate::core::gpu::TextureDepth;
         match info.depth {
-            TextureDepth::T4Bit => self.sample_4bit_texture(vram, u, v, info),
-            TextureDepth::T8Bit => self.sample_8bit_texture(vram, u, v, info),
-            TextureDepth::T15Bit => self.sample_15bit_texture(vram, u, v, info),
+            TextureDepth::T4Bit => self.texel_4bit(vram, u, v, info),
+            TextureDepth::T8Bit => self.texel_8bit(vram, u, v, info),
+            TextureDepth::T15Bit => self.texel_15bit(vram, u, v, info),
         }
     }
 
@@ -1338,14 +1870,8 @@ impl Rasterizer {
     ///
     /// # Returns
     ///
-    /// Tuple (r, g, b) with 8-bit RGB values from CLUT
-    fn sample_4bit_texture(
-        &self,
-        vram: &[u16],
-        u: u8,
-        v: u8,
-        info: &crate::core::gpu::TextureInfo,
-    ) -> (u8, u8, u8) {
+    /// Raw 16-bit CLUT entry (including the semi-transparency bit)
+    fn texel_4bit(&self, vram: &[u16], u: u8, v: u8, info: &crate::core::gpu::TextureInfo) -> u16 {
         // Calculate texture page address
         // 4-bit textures: 4 pixels per 16-bit word, so divide U by 4
         let tex_x = (info.page_x + (u as u16 / 4)) & 0x3FF;
@@ -1359,11 +1885,7 @@ impl Rasterizer {
         let index = (index_word >> shift) & 0xF;
 
         // Look up color in CLUT
-        let clut_x = info.clut_x + index;
-        let clut_y = info.clut_y;
-        let color = Self::read_vram_pixel(vram, clut_x as i16, clut_y as i16);
-
-        Self::rgb15_to_rgb24(color)
+        self.clut_entry(vram, info, index)
     }
 
     /// Sample an 8-bit indexed color texture
@@ -1386,14 +1908,8 @@ impl Rasterizer {
     ///
     /// # Returns
     ///
-    /// Tuple (r, g, b) with 8-bit RGB values from CLUT
-    fn sample_8bit_texture(
-        &self,
-        vram: &[u16],
-        u: u8,
-        v: u8,
-        info: &crate::core::gpu::TextureInfo,
-    ) -> (u8, u8, u8) {
+    /// Raw 16-bit CLUT entry (including the semi-transparency bit)
+    fn texel_8bit(&self, vram: &[u16], u: u8, v: u8, info: &crate::core::gpu::TextureInfo) -> u16 {
         // Calculate texture page address
         // 8-bit textures: 2 pixels per 16-bit word, so divide U by 2
         let tex_x = (info.page_x + (u as u16 / 2)) & 0x3FF;
@@ -1410,11 +1926,7 @@ impl Rasterizer {
         };
 
         // Look up color in CLUT
-        let clut_x = info.clut_x + index;
-        let clut_y = info.clut_y;
-        let color = Self::read_vram_pixel(vram, clut_x as i16, clut_y as i16);
-
-        Self::rgb15_to_rgb24(color)
+        self.clut_entry(vram, info, index)
     }
 
     /// Sample a 15-bit direct color texture
@@ -1435,22 +1947,15 @@ impl Rasterizer {
     ///
     /// # Returns
     ///
-    /// Tuple (r, g, b) with 8-bit RGB values
-    fn sample_15bit_texture(
-        &self,
-        vram: &[u16],
-        u: u8,
-        v: u8,
-        info: &crate::core::gpu::TextureInfo,
-    ) -> (u8, u8, u8) {
+    /// Raw 16-bit texel (including the semi-transparency bit)
+    fn texel_15bit(&self, vram: &[u16], u: u8, v: u8, info: &crate::core::gpu::TextureInfo) -> u16 {
         // Calculate texture address
         // 15-bit textures: 1 pixel per 16-bit word
         let tex_x = (info.page_x + u as u16) & 0x3FF;
         let tex_y = (info.page_y + v as u16) & 0x1FF;
 
         // Read color directly
-        let color = Self::read_vram_pixel(vram, tex_x as i16, tex_y as i16);
-        Self::rgb15_to_rgb24(color)
+        Self::read_vram_pixel(vram, tex_x as i16, tex_y as i16)
     }
 
     /// Sort triangle vertices by Y coordinate, preserving associated colors
@@ -1525,20 +2030,44 @@ impl Rasterizer {
         let x = x.wrapping_add(draw_offset.0);
         let y = y.wrapping_add(draw_offset.1);
 
+        let rect = (x, y, width, height);
+        self.upscaled_pass(|r, target, _| {
+            r.fill_rectangle(target, draw_mode, draw_area, rect, color, semi_transparent);
+        });
+        self.fill_rectangle(vram, draw_mode, draw_area, rect, color, semi_transparent);
+    }
+
+    /// Body of [`Rasterizer::draw_rectangle`] for a single pass
+    ///
+    /// `rect` is (x, y, width, height) in native coordinates with the
+    /// drawing offset applied; it is scaled here by the pass scale.
+    fn fill_rectangle(
+        &mut self,
+        vram: &mut [u16],
+        draw_mode: &DrawMode,
+        draw_area: &DrawingArea,
+        rect: (i16, i16, u16, u16),
+        color: &Color,
+        semi_transparent: bool,
+    ) {
+        let (x, y, width, height) = rect;
+        let s = self.pass_scale;
+        let (vram_width, vram_height) = self.pass_size();
+
         // Convert color to 15-bit RGB
         let color15 = color.to_rgb15();
 
         // Calculate rectangle bounds
-        let x1 = x;
-        let y1 = y;
-        let x2 = x.saturating_add(width as i16);
-        let y2 = y.saturating_add(height as i16);
+        let x1 = x.saturating_mul(s);
+        let y1 = y.saturating_mul(s);
+        let x2 = x.saturating_add(width as i16).saturating_mul(s);
+        let y2 = y.saturating_add(height as i16).saturating_mul(s);
 
         // Clip to drawing area
-        let clip_x1 = x1.max(draw_area.left as i16);
-        let clip_y1 = y1.max(draw_area.top as i16);
-        let clip_x2 = x2.min((draw_area.right as i16) + 1);
-        let clip_y2 = y2.min((draw_area.bottom as i16) + 1);
+        let clip_x1 = x1.max(draw_area.left as i16 * s);
+        let clip_y1 = y1.max(draw_area.top as i16 * s);
+        let clip_x2 = x2.min((draw_area.right as i16 + 1) * s);
+        let clip_y2 = y2.min((draw_area.bottom as i16 + 1) * s);
 
         // Check if rectangle is completely outside drawing area
         if clip_x1 >= clip_x2 || clip_y1 >= clip_y2 {
@@ -1547,26 +2076,22 @@ impl Rasterizer {
 
         // Fill rectangle scanline by scanline
         for py in clip_y1..clip_y2 {
-            if !(0..512).contains(&py) {
+            if !(0..vram_height).contains(&py) {
                 continue;
             }
 
             for px in clip_x1..clip_x2 {
-                if !(0..1024).contains(&px) {
+                if !(0..vram_width).contains(&px) {
                     continue;
                 }
 
-                let vram_index = (py as usize) * 1024 + (px as usize);
-
                 if semi_transparent {
                     // Apply semi-transparency blending
-                    let bg_color = vram[vram_index];
                     let blend_mode =
                         crate::core::gpu::BlendMode::from_bits(draw_mode.semi_transparency);
-                    let blended = blend_mode.blend(bg_color, color15);
-                    vram[vram_index] = blended;
+                    self.write_pixel_blended(vram, px, py, color15, blend_mode);
                 } else {
-                    vram[vram_index] = color15;
+                    self.write_pixel(vram, px, py, color15);
                 }
             }
         }
@@ -1574,6 +2099,11 @@ impl Rasterizer {
 
     /// Draw a textured rectangle
     ///
+    /// U/V advance one texel per pixel from the top-left coordinate and wrap
+    /// within the texture window. Texels with the raw value 0x0000 are
+    /// transparent. With semi-transparency enabled, only texels with bit 15
+    /// set are blended.
+    ///
     /// # Arguments
     ///
     /// * `vram` - VRAM buffer
@@ -1587,6 +2117,7 @@ impl Rasterizer {
     /// * `tex_u` - Texture U coordinate (top-left)
     /// * `tex_v` - Texture V coordinate (top-left)
     /// * `texture_info` - Texture page and CLUT information
+    /// * `texture_window` - Texture window settings for UV wrapping
     /// * `color` - Modulation color (if modulated is true)
     /// * `semi_transparent` - Enable semi-transparency blending
     /// * `modulated` - Enable color modulation (multiply texture by color)
@@ -1604,6 +2135,7 @@ impl Rasterizer {
         tex_u: u8,
         tex_v: u8,
         texture_info: &TextureInfo,
+        texture_window: &crate::core::gpu::TextureWindow,
         color: &Color,
         semi_transparent: bool,
         modulated: bool,
@@ -1612,80 +2144,116 @@ impl Rasterizer {
         let x = x.wrapping_add(draw_offset.0);
         let y = y.wrapping_add(draw_offset.1);
 
+        let rect = (x, y, width, height);
+        let texture = (tex_u, tex_v, texture_info, texture_window);
+        let flags = (semi_transparent, modulated);
+        self.prepare_clut(vram, texture_info);
+        self.upscaled_pass(|r, target, _| {
+            r.fill_textured_rectangle(
+                target,
+                Some(vram),
+                draw_mode,
+                draw_area,
+                rect,
+                texture,
+                color,
+                flags,
+            );
+        });
+        self.fill_textured_rectangle(
+            vram, None, draw_mode, draw_area, rect, texture, color, flags,
+        );
+    }
+
+    /// Body of [`Rasterizer::draw_textured_rectangle`] for a single pass
+    ///
+    /// `rect` is (x, y, width, height) in native coordinates with the
+    /// drawing offset applied; it is scaled here by the pass scale, and each
+    /// texel covers a pass-scale square. Texels come from `textures` if
+    /// given, otherwise from `vram` itself.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    fn fill_textured_rectangle(
+        &mut self,
+        vram: &mut [u16],
+        textures: Option<&[u16]>,
+        draw_mode: &DrawMode,
+        draw_area: &DrawingArea,
+        rect: (i16, i16, u16, u16),
+        texture: (u8, u8, &TextureInfo, &crate::core::gpu::TextureWindow),
+        color: &Color,
+        flags: (bool, bool),
+    ) {
+        let (x, y, width, height) = rect;
+        let (tex_u, tex_v, texture_info, texture_window) = texture;
+        let (semi_transparent, modulated) = flags;
+        let s = self.pass_scale;
+        let (vram_width, vram_height) = self.pass_size();
+
         // Calculate rectangle bounds
-        let x1 = x;
-        let y1 = y;
-        let x2 = x.saturating_add(width as i16);
-        let y2 = y.saturating_add(height as i16);
+        let x1 = x.saturating_mul(s);
+        let y1 = y.saturating_mul(s);
+        let x2 = x.saturating_add(width as i16).saturating_mul(s);
+        let y2 = y.saturating_add(height as i16).saturating_mul(s);
 
         // Clip to drawing area
-        let clip_x1 = x1.max(draw_area.left as i16);
-        let clip_y1 = y1.max(draw_area.top as i16);
-        let clip_x2 = x2.min((draw_area.right as i16) + 1);
-        let clip_y2 = y2.min((draw_area.bottom as i16) + 1);
+        let clip_x1 = x1.max(draw_area.left as i16 * s);
+        let clip_y1 = y1.max(draw_area.top as i16 * s);
+        let clip_x2 = x2.min((draw_area.right as i16 + 1) * s);
+        let clip_y2 = y2.min((draw_area.bottom as i16 + 1) * s);
 
         // Check if rectangle is completely outside drawing area
         if clip_x1 >= clip_x2 || clip_y1 >= clip_y2 {
             return;
         }
 
+        let blend_mode = crate::core::gpu::BlendMode::from_bits(draw_mode.semi_transparency);
+
         // Render each pixel
         for py in clip_y1..clip_y2 {
-            if !(0..512).contains(&py) {
+            if !(0..vram_height).contains(&py) {
                 continue;
             }
 
             // Calculate texture V coordinate for this scanline
-            let v_offset = (py - y1) as u8;
+            let v_offset = ((py - y1) / s) as u8;
             let v = tex_v.wrapping_add(v_offset);
 
             for px in clip_x1..clip_x2 {
-                if !(0..1024).contains(&px) {
+                if !(0..vram_width).contains(&px) {
                     continue;
                 }
 
                 // Calculate texture U coordinate for this pixel
-                let u_offset = (px - x1) as u8;
+                let u_offset = ((px - x1) / s) as u8;
                 let u = tex_u.wrapping_add(u_offset);
 
-                // Sample texture
-                let tex_color = match texture_info.depth {
-                    TextureDepth::T4Bit => self.sample_4bit_texture(vram, u, v, texture_info),
-                    TextureDepth::T8Bit => self.sample_8bit_texture(vram, u, v, texture_info),
-                    TextureDepth::T15Bit => self.sample_15bit_texture(vram, u, v, texture_info),
-                };
-
-                // Check for transparent black (0x0000 in 15-bit texture)
-                let tex_color15 = ((tex_color.2 as u16 >> 3) << 10)
-                    | ((tex_color.1 as u16 >> 3) << 5)
-                    | (tex_color.0 as u16 >> 3);
-
-                if tex_color15 == 0x0000 && texture_info.depth != TextureDepth::T15Bit {
-                    // Skip transparent pixels in paletted textures
+                // Sample texture (raw 0x0000 texels are transparent)
+                let texels = textures.unwrap_or(vram);
+                let texel = self.fetch_texel(texels, u, v, texture_info, texture_window);
+                if texel == 0x0000 {
                     continue;
                 }
 
                 // Apply modulation if enabled
                 let final_color = if modulated {
-                    let r = ((tex_color.0 as u16 * color.r as u16) / 128) as u8;
-                    let g = ((tex_color.1 as u16 * color.g as u16) / 128) as u8;
-                    let b = ((tex_color.2 as u16 * color.b as u16) / 128) as u8;
-                    ((b as u16 >> 3) << 10) | ((g as u16 >> 3) << 5) | (r as u16 >> 3)
+                    let (r, g, b) = Self::rgb15_to_rgb24(texel);
+                    let modulate = |t: u8, c: u8| ((t as u16 * c as u16) >> 7).min(255) as u8;
+                    Self::rgb_to_rgb15(
+                        modulate(r, color.r),
+                        modulate(g, color.g),
+                        modulate(b, color.b),
+                    )
                 } else {
-                    tex_color15
+                    texel & 0x7FFF
                 };
 
-                let vram_index = (py as usize) * 1024 + (px as usize);
+                let final_color = final_color | (texel & 0x8000);
 
-                if semi_transparent {
+                if semi_transparent && texel & 0x8000 != 0 {
                     // Apply semi-transparency blending
-                    let bg_color = vram[vram_index];
-                    let blend_mode =
-                        crate::core::gpu::BlendMode::from_bits(draw_mode.semi_transparency);
-                    let blended = blend_mode.blend(bg_color, final_color);
-                    vram[vram_index] = blended;
+                    self.write_pixel_blended(vram, px, py, final_color, blend_mode);
                 } else {
-                    vram[vram_index] = final_color;
+                    self.write_pixel(vram, px, py, final_color);
                 }
             }
         }
@@ -2011,22 +2579,22 @@ mod tests {
         };
 
         // Sample pixel 0 (U=0) should get index 0
-        let color = rasterizer.sample_4bit_texture(&vram, 0, 0, &info);
+        let color = Rasterizer::rgb15_to_rgb24(rasterizer.texel_4bit(&vram, 0, 0, &info));
         let expected = Rasterizer::rgb15_to_rgb24(vram[0]);
         assert_eq!(color, expected);
 
         // Sample pixel 1 (U=1) should get index 1
-        let color = rasterizer.sample_4bit_texture(&vram, 1, 0, &info);
+        let color = Rasterizer::rgb15_to_rgb24(rasterizer.texel_4bit(&vram, 1, 0, &info));
         let expected = Rasterizer::rgb15_to_rgb24(vram[1]);
         assert_eq!(color, expected);
 
         // Sample pixel 2 (U=2) should get index 2
-        let color = rasterizer.sample_4bit_texture(&vram, 2, 0, &info);
+        let color = Rasterizer::rgb15_to_rgb24(rasterizer.texel_4bit(&vram, 2, 0, &info));
         let expected = Rasterizer::rgb15_to_rgb24(vram[2]);
         assert_eq!(color, expected);
 
         // Sample pixel 3 (U=3) should get index 3
-        let color = rasterizer.sample_4bit_texture(&vram, 3, 0, &info);
+        let color = Rasterizer::rgb15_to_rgb24(rasterizer.texel_4bit(&vram, 3, 0, &info));
         let expected = Rasterizer::rgb15_to_rgb24(vram[3]);
         assert_eq!(color, expected);
     }
@@ -2060,12 +2628,12 @@ mod tests {
         };
 
         // Sample pixel 0 (U=0, even) should get index 10
-        let color = rasterizer.sample_8bit_texture(&vram, 0, 0, &info);
+        let color = Rasterizer::rgb15_to_rgb24(rasterizer.texel_8bit(&vram, 0, 0, &info));
         let expected = Rasterizer::rgb15_to_rgb24(vram[10]);
         assert_eq!(color, expected);
 
         // Sample pixel 1 (U=1, odd) should get index 20
-        let color = rasterizer.sample_8bit_texture(&vram, 1, 0, &info);
+        let color = Rasterizer::rgb15_to_rgb24(rasterizer.texel_8bit(&vram, 1, 0, &info));
         let expected = Rasterizer::rgb15_to_rgb24(vram[20]);
         assert_eq!(color, expected);
     }
@@ -2091,15 +2659,15 @@ mod tests {
         };
 
         // Sample pixel 0 should get red
-        let color = rasterizer.sample_15bit_texture(&vram, 0, 0, &info);
+        let color = Rasterizer::rgb15_to_rgb24(rasterizer.texel_15bit(&vram, 0, 0, &info));
         assert_eq!(color, (248, 0, 0));
 
         // Sample pixel 1 should get green
-        let color = rasterizer.sample_15bit_texture(&vram, 1, 0, &info);
+        let color = Rasterizer::rgb15_to_rgb24(rasterizer.texel_15bit(&vram, 1, 0, &info));
         assert_eq!(color, (0, 248, 0));
 
         // Sample pixel 2 should get blue
-        let color = rasterizer.sample_15bit_texture(&vram, 2, 0, &info);
+        let color = Rasterizer::rgb15_to_rgb24(rasterizer.texel_15bit(&vram, 2, 0, &info));
         assert_eq!(color, (0, 0, 248));
     }
 
@@ -2690,11 +3258,66 @@ mod tests {
         };
 
         // Test that coordinates at 0 and 255 access different locations
-        let color_0 = rasterizer.sample_15bit_texture(&vram, 0, 0, &info);
-        let color_1 = rasterizer.sample_15bit_texture(&vram, 1, 0, &info);
+        let color_0 = Rasterizer::rgb15_to_rgb24(rasterizer.texel_15bit(&vram, 0, 0, &info));
+        let color_1 = Rasterizer::rgb15_to_rgb24(rasterizer.texel_15bit(&vram, 1, 0, &info));
         assert_ne!(color_0, color_1);
     }
 
+    #[test]
+    fn test_clut_cache_invalidated_by_write_into_palette() {
+        use crate::core::gpu::{TextureDepth, TextureInfo, TextureWindow};
+
+        let mut vram = vec![0u16; 1024 * 512];
+        let mut rasterizer = Rasterizer::new();
+
+        // 4-bit texture at (64, 0) using palette index 1 everywhere
+        vram[1] = 0x001F; // CLUT entry 1: red
+        vram[64] = 0x1111;
+        let info = TextureInfo {
+            page_x: 64,
+            page_y: 0,
+            clut_x: 0,
+            clut_y: 0,
+            depth: TextureDepth::T4Bit,
+        };
+
+        let draw = |rasterizer: &mut Rasterizer, vram: &mut [u16]| {
+            let p = |x, y| ((x, y), (0, 0), (128, 128, 128));
+            rasterizer.draw_shaded_textured_triangle(
+                vram,
+                p(100, 100),
+                p(120, 100),
+                p(100, 120),
+                &info,
+                &TextureWindow::default(),
+                false,
+                None,
+                false,
+            );
+            vram[105 * 1024 + 105]
+        };
+
+        assert_eq!(draw(&mut rasterizer, &mut vram), 0x001F);
+
+        // A write the rasterizer is not told about keeps the cached palette
+        vram[1] = 0x03E0;
+        assert_eq!(draw(&mut rasterizer, &mut vram), 0x001F);
+
+        // Reporting the write invalidates it
+        rasterizer.invalidate_clut_pixel(1, 0);
+        assert_eq!(draw(&mut rasterizer, &mut vram), 0x03E0);
+
+        // Drawing over the palette invalidates it as well
+        rasterizer.write_pixel(&mut vram, 1, 0, 0x7C00);
+        assert_eq!(draw(&mut rasterizer, &mut vram), 0x7C00);
+
+        // Writes next to the palette do not
+        vram[1] = 0x001F;
+        rasterizer.invalidate_clut_pixel(16, 0);
+        rasterizer.invalidate_clut_pixel(1, 1);
+        assert_eq!(draw(&mut rasterizer, &mut vram), 0x7C00);
+    }
+
     #[test]
     fn test_barycentric_edge_cases() {
         // Test with degenerate triangle (area = 0)
@@ -2854,6 +3477,23 @@ mod tests {
         assert_ne!(pixel2, 0);
     }
 
+    #[test]
+    fn test_dither_rgb15_offsets() {
+        // Row 0 offsets: -4, 0, -3, +1; row 1 starts at +2
+        assert_eq!(Rasterizer::dither_rgb15(0, 0, 8, 8, 8), 0x0000);
+        assert_eq!(Rasterizer::dither_rgb15(1, 0, 8, 8, 8), 0x0421);
+        assert_eq!(Rasterizer::dither_rgb15(3, 0, 7, 0, 0), 0x0001);
+        assert_eq!(Rasterizer::dither_rgb15(0, 1, 6, 0, 0), 0x0001);
+
+        // Pattern repeats every 4 pixels and clamps at the channel limits
+        assert_eq!(
+            Rasterizer::dither_rgb15(5, 4, 8, 8, 8),
+            Rasterizer::dither_rgb15(1, 0, 8, 8, 8)
+        );
+        assert_eq!(Rasterizer::dither_rgb15(0, 1, 255, 255, 255), 0x7FFF);
+        assert_eq!(Rasterizer::dither_rgb15(0, 0, 0, 0, 0), 0x0000);
+    }
+
     #[test]
     fn test_rgb_conversion_precision() {
         // Per PSX-SPX: 8-bit RGB (0-255) converts to 5-bit RGB (0-31)
@@ -2902,4 +3542,30 @@ mod tests {
         let center_pixel = vram[150 * 1024 + 150];
         assert!(center_pixel < 0x4000); // Less than 50% brightness
     }
+
+    #[test]
+    fn test_upscaled_triangle_fills_four_times_the_pixels() {
+        let mut vram = vec![0u16; 1024 * 512];
+        let mut rasterizer = Rasterizer::new();
+        assert_eq!(rasterizer.upscale_factor(), 1);
+        assert!(rasterizer.upscaled_vram().is_none());
+
+        rasterizer.set_upscale(2, &vram);
+        assert_eq!(rasterizer.upscale_factor(), 2);
+        rasterizer.draw_triangle(&mut vram, (100, 100), (228, 100), (100, 228), 0x7FFF);
+
+        let native = vram.iter().filter(|&&p| p == 0x7FFF).count();
+        let upscaled = rasterizer.upscaled_vram().unwrap();
+        let filled = upscaled.iter().filter(|&&p| p == 0x7FFF).count();
+
+        // 4× the area; edges are drawn inclusively at both resolutions, so
+        // the one-pixel border only scales by 2
+        let ratio = filled as f64 / native as f64;
+        assert!((ratio - 4.0).abs() < 0.08, "{} vs {}", filled, native);
+
+        // The upscaled copy lands at twice the coordinates
+        assert_eq!(upscaled[220 * 2048 + 220], 0x7FFF);
+        assert_eq!(upscaled[120 * 2048 + 120], 0x0000);
+        assert_eq!(upscaled[500 * 2048 + 220], 0x0000);
+    }
 }
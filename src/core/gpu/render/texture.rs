@@ -18,150 +18,139 @@
 //! Implements texture-mapped triangle and quadrilateral rasterization with support
 //! for 4-bit, 8-bit, and 15-bit texture formats.
 
-use super::super::primitives::{Color, TexCoord, TextureInfo, Vertex};
+use super::super::primitives::{BlendMode, Color, TexCoord, TextureInfo, Vertex};
 use super::super::GPU;
+use super::draw_time::{triangle_pixels, DrawCost};
 
 impl GPU {
     /// Render a textured triangle
     ///
     /// Applies the drawing offset to all vertices and rasterizes the triangle
-    /// with texture mapping using the software rasterizer.
+    /// with texture mapping using the software rasterizer. Covers the flat,
+    /// raw and Gouraud-shaded textured polygon commands.
+    /// Texels whose raw value is 0x0000 are transparent. With semi-transparency
+    /// enabled, only texels with bit 15 set are blended, using the mode from
+    /// the current draw mode (set by the polygon's texpage attribute).
     ///
     /// # Arguments
     ///
     /// * `vertices` - Array of 3 vertices defining the triangle
     /// * `texcoords` - Array of 3 texture coordinates corresponding to vertices
+    /// * `colors` - Array of 3 modulation colors, interpolated across the triangle
     /// * `texture_info` - Texture page and CLUT information
-    /// * `color` - Color tint to modulate with texture
     /// * `semi_transparent` - Whether semi-transparency is enabled
-    ///
-    /// # Texture Mapping
-    ///
-    /// The texture coordinates are interpolated across the triangle using
-    /// barycentric coordinates. The texture is sampled from VRAM at the
-    /// specified texture page with the given color depth (4-bit, 8-bit, or 15-bit).
+    /// * `modulated` - Whether to modulate texels by the colors (false = raw texture)
     ///
     /// # Color Modulation
     ///
-    /// The color parameter acts as a tint/modulation color that is multiplied
-    /// with the sampled texture color. For normal brightness, use (128, 128, 128).
-    ///
-    /// # Notes
-    ///
-    /// Semi-transparency is currently ignored (will be implemented in issue #36).
-    /// The drawing offset is applied to all vertices before rasterization.
+    /// Texels are multiplied by the color and divided by 128, saturating at
+    /// full brightness. For normal brightness, use (128, 128, 128).
+    /// Modulated texels are dithered when dithering is enabled in the draw mode.
     pub(crate) fn render_textured_triangle(
         &mut self,
         vertices: &[Vertex; 3],
         texcoords: &[TexCoord; 3],
+        colors: &[Color; 3],
         texture_info: &TextureInfo,
-        color: &Color,
         semi_transparent: bool,
+        modulated: bool,
     ) {
-        // Apply drawing offset
-        let v0 = (
-            vertices[0].x.wrapping_add(self.draw_offset.0),
-            vertices[0].y.wrapping_add(self.draw_offset.1),
-        );
-        let v1 = (
-            vertices[1].x.wrapping_add(self.draw_offset.0),
-            vertices[1].y.wrapping_add(self.draw_offset.1),
-        );
-        let v2 = (
-            vertices[2].x.wrapping_add(self.draw_offset.0),
-            vertices[2].y.wrapping_add(self.draw_offset.1),
-        );
-
-        let t0 = (texcoords[0].u, texcoords[0].v);
-        let t1 = (texcoords[1].u, texcoords[1].v);
-        let t2 = (texcoords[2].u, texcoords[2].v);
+        // Apply drawing offset and pair each vertex with its attributes
+        let point = |i: usize| {
+            (
+                (
+                    vertices[i].x.wrapping_add(self.draw_offset.0),
+                    vertices[i].y.wrapping_add(self.draw_offset.1),
+                ),
+                (texcoords[i].u, texcoords[i].v),
+                (colors[i].r, colors[i].g, colors[i].b),
+            )
+        };
+        let (p0, p1, p2) = (point(0), point(1), point(2));
 
         log::trace!(
-            "Rendering {}textured triangle: v=({},{}),({},{}),({},{}) t=({},{}),({},{}),({},{}) color=({},{},{})",
-            if semi_transparent { "semi-transparent " } else { "" },
-            v0.0, v0.1, v1.0, v1.1, v2.0, v2.1,
-            t0.0, t0.1, t1.0, t1.1, t2.0, t2.1,
-            color.r, color.g, color.b
+            "Rendering {}textured triangle: v={:?},{:?},{:?} t={:?},{:?},{:?} modulated={}",
+            if semi_transparent {
+                "semi-transparent "
+            } else {
+                ""
+            },
+            p0.0,
+            p1.0,
+            p2.0,
+            p0.1,
+            p1.1,
+            p2.1,
+            modulated
         );
 
-        // For now, ignore semi_transparent (will be implemented in #36)
-        let _ = semi_transparent;
+        let cost = DrawCost {
+            textured: true,
+            shaded: modulated && (colors[0] != colors[1] || colors[0] != colors[2]),
+            blended: semi_transparent,
+        };
+        self.add_draw_time(triangle_pixels(p0.0, p1.0, p2.0, &self.draw_area), cost);
+
+        let blend_mode =
+            semi_transparent.then(|| BlendMode::from_bits(self.draw_mode.semi_transparency));
 
         // Rasterize the textured triangle with texture window
-        self.rasterizer.draw_textured_triangle(
+        self.rasterizer.draw_shaded_textured_triangle(
             &mut self.vram,
-            v0,
-            t0,
-            v1,
-            t1,
-            v2,
-            t2,
+            p0,
+            p1,
+            p2,
             texture_info,
             &self.texture_window,
-            (color.r, color.g, color.b),
+            modulated,
+            blend_mode,
+            self.draw_mode.dithering,
         );
     }
 
     /// Render a textured quadrilateral
     ///
-    /// Splits the quad into two triangles and renders them as textured primitives.
-    /// The quad is split along the v0-v2 diagonal.
-    ///
-    /// # Arguments
-    ///
-    /// * `vertices` - Array of 4 vertices defining the quad (in order: v0, v1, v2, v3)
-    /// * `texcoords` - Array of 4 texture coordinates corresponding to vertices
-    /// * `texture_info` - Texture page and CLUT information
-    /// * `color` - Color tint to modulate with texture
-    /// * `semi_transparent` - Whether semi-transparency is enabled
-    ///
-    /// # Quad Splitting
-    ///
     /// The quad is split into two triangles:
     /// - Triangle 1: (v0, v1, v2)
     /// - Triangle 2: (v1, v2, v3)
     ///
     /// This matches the PlayStation GPU's quadrilateral rendering behavior.
     ///
-    /// # Notes
+    /// # Arguments
     ///
-    /// Semi-transparency is currently ignored (will be implemented in issue #36).
+    /// * `vertices` - Array of 4 vertices defining the quad
+    /// * `texcoords` - Array of 4 texture coordinates corresponding to vertices
+    /// * `colors` - Array of 4 modulation colors
+    /// * `texture_info` - Texture page and CLUT information
+    /// * `semi_transparent` - Whether semi-transparency is enabled
+    /// * `modulated` - Whether to modulate texels by the colors
     pub(crate) fn render_textured_quad(
         &mut self,
         vertices: &[Vertex; 4],
         texcoords: &[TexCoord; 4],
+        colors: &[Color; 4],
         texture_info: &TextureInfo,
-        color: &Color,
         semi_transparent: bool,
+        modulated: bool,
     ) {
-        // Split quad into two triangles: (v0,v1,v2) and (v1,v2,v3)
-        let tri1_verts = [vertices[0], vertices[1], vertices[2]];
-        let tri1_texcoords = [texcoords[0], texcoords[1], texcoords[2]];
-
-        let tri2_verts = [vertices[1], vertices[2], vertices[3]];
-        let tri2_texcoords = [texcoords[1], texcoords[2], texcoords[3]];
-
-        self.render_textured_triangle(
-            &tri1_verts,
-            &tri1_texcoords,
-            texture_info,
-            color,
-            semi_transparent,
-        );
-        self.render_textured_triangle(
-            &tri2_verts,
-            &tri2_texcoords,
-            texture_info,
-            color,
-            semi_transparent,
-        );
+        for [a, b, c] in [[0, 1, 2], [1, 2, 3]] {
+            self.render_textured_triangle(
+                &[vertices[a], vertices[b], vertices[c]],
+                &[texcoords[a], texcoords[b], texcoords[c]],
+                &[colors[a], colors[b], colors[c]],
+                texture_info,
+                semi_transparent,
+                modulated,
+            );
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::draw_time::SETUP_CYCLES;
     use super::*;
-    use crate::core::gpu::TextureDepth;
+    use crate::core::gpu::{TextureDepth, VideoMode};
 
     #[test]
     fn test_textured_triangle_basic() {
@@ -197,7 +186,14 @@ mod tests {
             b: 128,
         }; // Normal brightness
 
-        gpu.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_triangle(
+            &vertices,
+            &texcoords,
+            &[color; 3],
+            &texture_info,
+            false,
+            true,
+        );
 
         // Check center pixel has texture data
         let pixel = gpu.read_vram(150, 133);
@@ -240,7 +236,14 @@ mod tests {
             b: 128,
         };
 
-        gpu.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_triangle(
+            &vertices,
+            &texcoords,
+            &[color; 3],
+            &texture_info,
+            false,
+            true,
+        );
 
         // Center should be at (150+50, 133+30) = (200, 163)
         let pixel = gpu.read_vram(200, 163);
@@ -284,7 +287,14 @@ mod tests {
             b: 128,
         };
 
-        gpu.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_triangle(
+            &vertices,
+            &texcoords,
+            &[color; 3],
+            &texture_info,
+            false,
+            true,
+        );
 
         let pixel = gpu.read_vram(150, 133);
         // Modulation formula: (texel * color) / 128
@@ -330,7 +340,14 @@ mod tests {
             b: 128,
         };
 
-        gpu.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_triangle(
+            &vertices,
+            &texcoords,
+            &[color; 3],
+            &texture_info,
+            false,
+            true,
+        );
 
         // Should render with full U/V range
         let pixel = gpu.read_vram(150, 133);
@@ -373,7 +390,14 @@ mod tests {
             b: 128,
         };
 
-        gpu.render_textured_quad(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_quad(
+            &vertices,
+            &texcoords,
+            &[color; 4],
+            &texture_info,
+            false,
+            true,
+        );
 
         // Center should have green texture
         let pixel = gpu.read_vram(150, 150);
@@ -417,7 +441,14 @@ mod tests {
             b: 128,
         };
 
-        gpu.render_textured_quad(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_quad(
+            &vertices,
+            &texcoords,
+            &[color; 4],
+            &texture_info,
+            false,
+            true,
+        );
 
         // Check both triangle areas
         assert_ne!(gpu.read_vram(150, 120), 0x0000); // Upper triangle
@@ -459,7 +490,14 @@ mod tests {
             b: 128,
         };
 
-        gpu.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_triangle(
+            &vertices,
+            &texcoords,
+            &[color; 3],
+            &texture_info,
+            false,
+            true,
+        );
 
         let pixel = gpu.read_vram(150, 133);
         assert_ne!(pixel, 0x0000);
@@ -506,7 +544,14 @@ mod tests {
             b: 128,
         };
 
-        gpu.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_triangle(
+            &vertices,
+            &texcoords,
+            &[color; 3],
+            &texture_info,
+            false,
+            true,
+        );
     }
 
     #[test]
@@ -553,7 +598,14 @@ mod tests {
             b: 128,
         };
 
-        gpu.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_triangle(
+            &vertices,
+            &texcoords,
+            &[color; 3],
+            &texture_info,
+            false,
+            true,
+        );
     }
 
     #[test]
@@ -591,7 +643,14 @@ mod tests {
         };
 
         // Should handle wrapping
-        gpu.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_triangle(
+            &vertices,
+            &texcoords,
+            &[color; 3],
+            &texture_info,
+            false,
+            true,
+        );
     }
 
     #[test]
@@ -628,7 +687,14 @@ mod tests {
             b: 128,
         };
 
-        gpu.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_triangle(
+            &vertices,
+            &texcoords,
+            &[color; 3],
+            &texture_info,
+            false,
+            true,
+        );
     }
 
     #[test]
@@ -669,7 +735,14 @@ mod tests {
             b: 128,
         };
 
-        gpu.render_textured_quad(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_quad(
+            &vertices,
+            &texcoords,
+            &[color; 4],
+            &texture_info,
+            false,
+            true,
+        );
 
         // Center should be at (100+100, 100+50) = (200, 150)
         let pixel = gpu.read_vram(200, 150);
@@ -712,7 +785,14 @@ mod tests {
             b: 64,
         }; // Half brightness
 
-        gpu.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_triangle(
+            &vertices,
+            &texcoords,
+            &[color; 3],
+            &texture_info,
+            false,
+            true,
+        );
 
         let pixel = gpu.read_vram(150, 133);
         // Should be darker than original white texture
@@ -755,10 +835,54 @@ mod tests {
             b: 255,
         }; // Maximum brightness
 
-        gpu.render_textured_triangle(&vertices, &texcoords, &texture_info, &color, false);
+        gpu.render_textured_triangle(
+            &vertices,
+            &texcoords,
+            &[color; 3],
+            &texture_info,
+            false,
+            true,
+        );
 
         let pixel = gpu.read_vram(150, 133);
         // Should be brighter (clamped to max)
         assert_ne!(pixel, 0x0000);
     }
+
+    #[test]
+    fn test_large_textured_quad_keeps_gpu_busy() {
+        const READY_CMD: u32 = 1 << 26;
+
+        let mut gpu = GPU::new();
+        assert_ne!(gpu.status() & READY_CMD, 0);
+
+        gpu.write_gp0(0x2D000000); // Raw textured quad
+        gpu.write_gp0(0x00000000); // V1: (0, 0)
+        gpu.write_gp0(0x00000000); // CLUT + UV (0, 0)
+        gpu.write_gp0(0x000000FF); // V2: (255, 0)
+        gpu.write_gp0(0x010400FF); // 15-bit page X=256 + UV (255, 0)
+        gpu.write_gp0(0x00FF0000); // V3: (0, 255)
+        gpu.write_gp0(0x0000FF00); // UV (0, 255)
+        gpu.write_gp0(0x00FF00FF); // V4: (255, 255)
+        gpu.write_gp0(0x0000FFFF); // UV (255, 255)
+        assert!(gpu.command_fifo.is_empty());
+
+        // Two triangles of 255 × 255 / 2 pixels, two GPU cycles per texel
+        let per_triangle = SETUP_CYCLES + (255 * 255 / 2) * 2;
+        let gpu_cycles = 2 * per_triangle;
+
+        // Convert to CPU cycles, rounding up
+        let mode = VideoMode::NTSC;
+        let dots = mode.dots_per_scanline() as u32;
+        let line = mode.cycles_per_scanline();
+        let cpu_cycles = (gpu_cycles * line).div_ceil(dots);
+
+        gpu.tick_cycles(cpu_cycles - 1);
+        assert!(gpu.is_drawing());
+        assert_eq!(gpu.status() & READY_CMD, 0);
+
+        gpu.tick_cycles(1);
+        assert!(!gpu.is_drawing());
+        assert_ne!(gpu.status() & READY_CMD, 0);
+    }
 }
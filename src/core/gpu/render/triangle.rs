@@ -19,6 +19,7 @@
 
 use super::super::primitives::{BlendMode, Color, Vertex};
 use super::super::GPU;
+use super::draw_time::{triangle_pixels, DrawCost};
 
 impl GPU {
     /// Render a monochrome (flat-shaded) triangle
@@ -110,6 +111,12 @@ impl GPU {
             }
         );
 
+        let cost = DrawCost {
+            blended: semi_transparent,
+            ..DrawCost::default()
+        };
+        self.add_draw_time(triangle_pixels(v0, v1, v2, &self.draw_area), cost);
+
         // Convert color to 15-bit RGB format
         let color_15bit = color.to_rgb15();
 
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Oversized framebuffer for internal-resolution upscaling
+//!
+//! When upscaling is enabled the rasterizer draws every primitive twice:
+//! once into native VRAM, which stays authoritative for transfers, copies
+//! and texture sampling, and once at `factor`× resolution into this buffer.
+//! Writes that only happen at native resolution (uploads, fills, copies)
+//! are mirrored here as `factor`×`factor` blocks so the buffer never falls
+//! out of sync with VRAM.
+
+/// Largest supported upscale factor (4096×2048 buffer)
+pub const MAX_UPSCALE: u16 = 4;
+
+/// VRAM copy at `factor`× internal resolution
+pub(super) struct UpscaleBuffer {
+    /// Scale relative to the 1024×512 native VRAM
+    factor: u16,
+
+    /// Pixels, `1024 * factor` wide and `512 * factor` tall
+    pixels: Vec<u16>,
+}
+
+impl UpscaleBuffer {
+    /// Create a buffer seeded from native VRAM
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - Scale factor (2 to `MAX_UPSCALE`)
+    /// * `vram` - Native VRAM buffer (1024×512)
+    pub(super) fn new(factor: u16, vram: &[u16]) -> Self {
+        let factor = factor as usize;
+        let mut buffer = Self {
+            factor: factor as u16,
+            pixels: vec![0; 1024 * factor * 512 * factor],
+        };
+        buffer.reload(vram);
+        buffer
+    }
+
+    /// Scale factor
+    pub(super) fn factor(&self) -> u16 {
+        self.factor
+    }
+
+    /// Pixels, row-major with a stride of `1024 * factor`
+    pub(super) fn pixels(&self) -> &[u16] {
+        &self.pixels
+    }
+
+    /// Mutable pixels for drawing the upscaled pass
+    pub(super) fn pixels_mut(&mut self) -> &mut [u16] {
+        &mut self.pixels
+    }
+
+    /// Replace the whole buffer with a nearest-neighbour copy of VRAM
+    ///
+    /// # Arguments
+    ///
+    /// * `vram` - Native VRAM buffer (1024×512)
+    pub(super) fn reload(&mut self, vram: &[u16]) {
+        let factor = self.factor as usize;
+        let stride = 1024 * factor;
+        for (y, row) in self.pixels.chunks_exact_mut(stride).enumerate() {
+            let native = &vram[(y / factor) * 1024..(y / factor + 1) * 1024];
+            for (x, pixel) in row.iter_mut().enumerate() {
+                *pixel = native[x / factor];
+            }
+        }
+    }
+
+    /// Mirror a native VRAM write into the matching block
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Native X coordinate (0-1023)
+    /// * `y` - Native Y coordinate (0-511)
+    /// * `value` - Pixel value
+    #[inline]
+    pub(super) fn write_native_pixel(&mut self, x: u16, y: u16, value: u16) {
+        let factor = self.factor as usize;
+        let stride = 1024 * factor;
+        let left = x as usize * factor;
+        for row in 0..factor {
+            let start = (y as usize * factor + row) * stride + left;
+            self.pixels[start..start + factor].fill(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upscale_buffer_mirrors_native_writes_as_blocks() {
+        let mut vram = vec![0u16; 1024 * 512];
+        vram[3 * 1024 + 5] = 0x1234;
+        let mut buffer = UpscaleBuffer::new(2, &vram);
+
+        // Seeded from VRAM with nearest-neighbour
+        let stride = 2048;
+        for (x, y) in [(10, 6), (11, 6), (10, 7), (11, 7)] {
+            assert_eq!(buffer.pixels()[y * stride + x], 0x1234);
+        }
+        assert_eq!(buffer.pixels()[6 * stride + 12], 0);
+
+        buffer.write_native_pixel(1023, 511, 0x7FFF);
+        let filled = buffer.pixels().iter().filter(|&&p| p == 0x7FFF).count();
+        assert_eq!(filled, 4);
+        assert_eq!(buffer.pixels()[1023 * stride + 2047], 0x7FFF);
+    }
+}
@@ -48,10 +48,19 @@
 //! 11-15| -             | Not used
 //! ```
 //!
+//! ## Triggering
+//!
+//! Devices either pulse a source with [`InterruptController::request`] or
+//! drive its line level with [`InterruptController::set_line`]. Line-driven
+//! sources such as VBLANK only latch into I_STAT on a rising edge, so an
+//! acknowledged interrupt is not raised again while the line stays active.
+//!
 //! ## References
 //!
 //! - [PSX-SPX: Interrupt Control](http://problemkaputt.de/psx-spx.htm#interruptcontrol)
 
+use crate::core::save_state::{InterruptState, StateSave};
+
 /// Interrupt source bit flags
 ///
 /// These constants represent the bit positions in I_STAT and I_MASK registers
@@ -89,6 +98,9 @@ pub mod interrupts {
 
     /// Lightpen/IRQ10 (PIO) interrupt (bit 10)
     pub const LIGHTPEN: u16 = 1 << 10;
+
+    /// All eleven interrupt sources (bits 0-10)
+    pub const ALL: u16 = 0x07FF;
 }
 
 /// PlayStation Interrupt Controller
@@ -129,6 +141,11 @@ pub struct InterruptController {
     /// Each bit controls whether the corresponding interrupt can reach the CPU.
     /// 1 = interrupt enabled, 0 = interrupt masked (blocked).
     mask: u16,
+
+    /// Current level of each edge-triggered interrupt line
+    ///
+    /// Used by [`InterruptController::set_line`] to detect rising edges.
+    lines: u16,
 }
 
 impl InterruptController {
@@ -150,13 +167,21 @@ impl InterruptController {
     /// assert_eq!(ic.read_mask(), 0);
     /// ```
     pub fn new() -> Self {
-        Self { status: 0, mask: 0 }
+        Self {
+            status: 0,
+            mask: 0,
+            lines: 0,
+        }
     }
 
     /// Request an interrupt
     ///
     /// Sets the specified interrupt bit(s) in the status register.
     /// This is called by hardware components when they need to signal the CPU.
+    /// Each call is treated as a single pulse; devices that expose a level
+    /// instead should use [`InterruptController::set_line`].
+    ///
+    /// Bits 11-15 do not correspond to any source and are ignored.
     ///
     /// # Arguments
     ///
@@ -172,7 +197,7 @@ impl InterruptController {
     /// assert_eq!(ic.read_status(), interrupts::VBLANK as u32);
     /// ```
     pub fn request(&mut self, interrupt: u16) {
-        self.status |= interrupt;
+        self.status |= interrupt & interrupts::ALL;
         log::trace!(
             "IRQ requested: 0x{:04X}, status=0x{:04X}",
             interrupt,
@@ -180,6 +205,44 @@ impl InterruptController {
         );
     }
 
+    /// Update the level of edge-triggered interrupt line(s)
+    ///
+    /// I_STAT latches a source only when its line goes from inactive to
+    /// active. Holding a line active does not re-raise the interrupt after
+    /// it has been acknowledged; the line must drop first.
+    ///
+    /// # Arguments
+    ///
+    /// * `interrupt` - Interrupt bit(s) whose line is being driven
+    /// * `active` - New line level
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::interrupt::{InterruptController, interrupts};
+    ///
+    /// let mut ic = InterruptController::new();
+    /// ic.set_line(interrupts::VBLANK, true);
+    /// ic.acknowledge(!interrupts::VBLANK);
+    ///
+    /// // Still in VBlank: no new interrupt
+    /// ic.set_line(interrupts::VBLANK, true);
+    /// assert_eq!(ic.read_status(), 0);
+    /// ```
+    pub fn set_line(&mut self, interrupt: u16, active: bool) {
+        let interrupt = interrupt & interrupts::ALL;
+
+        if active {
+            let rising = interrupt & !self.lines;
+            self.lines |= interrupt;
+            if rising != 0 {
+                self.request(rising);
+            }
+        } else {
+            self.lines &= !interrupt;
+        }
+    }
+
     /// Acknowledge interrupt (write 0 to clear bits)
     ///
     /// Clears interrupt bits where the corresponding bit in `value` is 0.
@@ -330,6 +393,24 @@ impl Default for InterruptController {
     }
 }
 
+impl StateSave for InterruptController {
+    type State = InterruptState;
+
+    fn to_state(&self) -> InterruptState {
+        InterruptState {
+            i_stat: self.status as u32,
+            i_mask: self.mask as u32,
+            i_lines: self.lines as u32,
+        }
+    }
+
+    fn restore_from_state(&mut self, state: &InterruptState) {
+        self.status = state.i_stat as u16 & 0x7FF;
+        self.mask = state.i_mask as u16 & 0x7FF;
+        self.lines = state.i_lines as u16 & 0x7FF;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1073,4 +1154,97 @@ mod tests {
         let expected = interrupts::VBLANK | interrupts::GPU | interrupts::TIMER1;
         assert_eq!(ic.read_status(), expected as u32);
     }
+
+    #[test]
+    fn test_set_line_sets_only_on_rising_edge() {
+        let mut ic = InterruptController::new();
+
+        ic.set_line(interrupts::VBLANK, true);
+        assert_eq!(ic.read_status(), interrupts::VBLANK as u32);
+
+        // Acknowledged while the line is still high: stays clear
+        ic.write_status(!interrupts::VBLANK as u32);
+        ic.set_line(interrupts::VBLANK, true);
+        assert_eq!(ic.read_status(), 0);
+
+        // Line drops and rises again: new interrupt
+        ic.set_line(interrupts::VBLANK, false);
+        assert_eq!(ic.read_status(), 0);
+        ic.set_line(interrupts::VBLANK, true);
+        assert_eq!(ic.read_status(), interrupts::VBLANK as u32);
+    }
+
+    #[test]
+    fn test_set_line_low_does_not_clear_status() {
+        let mut ic = InterruptController::new();
+
+        ic.set_line(interrupts::CDROM, true);
+        ic.set_line(interrupts::CDROM, false);
+
+        assert_eq!(ic.read_status(), interrupts::CDROM as u32);
+    }
+
+    #[test]
+    fn test_every_source_raise_mask_acknowledge() {
+        let sources = [
+            interrupts::VBLANK,
+            interrupts::GPU,
+            interrupts::CDROM,
+            interrupts::DMA,
+            interrupts::TIMER0,
+            interrupts::TIMER1,
+            interrupts::TIMER2,
+            interrupts::CONTROLLER,
+            interrupts::SIO,
+            interrupts::SPU,
+            interrupts::LIGHTPEN,
+        ];
+        assert_eq!(sources.iter().fold(0, |acc, s| acc | s), interrupts::ALL);
+
+        for source in sources {
+            let mut ic = InterruptController::new();
+
+            // Raised but masked
+            ic.set_line(source, true);
+            assert_eq!(ic.read_status(), source as u32);
+            assert!(!ic.is_pending(), "0x{:03X} masked", source);
+
+            // Other sources in the mask do not let it through
+            ic.write_mask((interrupts::ALL & !source) as u32);
+            assert!(!ic.is_pending(), "0x{:03X} masked by others", source);
+
+            ic.write_mask(source as u32);
+            assert!(ic.is_pending(), "0x{:03X} unmasked", source);
+
+            // Writing 1 to every other bit only clears this source
+            ic.request(interrupts::ALL & !source);
+            ic.write_status(!source as u32);
+            assert_eq!(ic.read_status(), (interrupts::ALL & !source) as u32);
+            assert!(!ic.is_pending(), "0x{:03X} acknowledged", source);
+        }
+    }
+
+    #[test]
+    fn test_request_ignores_unused_bits() {
+        let mut ic = InterruptController::new();
+
+        ic.request(0xF800 | interrupts::SPU);
+        ic.set_line(0xF800, true);
+
+        assert_eq!(ic.read_status(), interrupts::SPU as u32);
+    }
+
+    #[test]
+    fn test_line_levels_saved_in_state() {
+        let mut ic = InterruptController::new();
+        ic.set_line(interrupts::VBLANK, true);
+        ic.acknowledge(0);
+
+        let mut restored = InterruptController::new();
+        restored.restore_from_state(&ic.to_state());
+
+        // VBlank still active after loading: no spurious interrupt
+        restored.set_line(interrupts::VBLANK, true);
+        assert_eq!(restored.read_status(), 0);
+    }
 }
@@ -185,6 +185,30 @@ impl SystemConfig {
             stack,
         })
     }
+
+    /// Get the path of the boot executable
+    ///
+    /// The BOOT line may carry arguments after the path
+    /// (e.g. `BOOT = cdrom:\SLUS_005.94;1 arg`); only the path is returned.
+    ///
+    /// # Returns
+    ///
+    /// Boot executable path as written in SYSTEM.CNF
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::loader::SystemConfig;
+    ///
+    /// let config = SystemConfig::parse("BOOT = cdrom:\\MAIN.EXE;1 debug").unwrap();
+    /// assert_eq!(config.boot_path(), "cdrom:\\MAIN.EXE;1");
+    /// ```
+    pub fn boot_path(&self) -> &str {
+        self.boot_file
+            .split_whitespace()
+            .next()
+            .unwrap_or(&self.boot_file)
+    }
 }
 
 /// PSX-EXE executable
@@ -300,6 +324,16 @@ impl PSXExecutable {
             data: exe_data,
         })
     }
+
+    /// Get the initial stack pointer from the header
+    ///
+    /// # Returns
+    ///
+    /// `Some(stack_base + stack_offset)`, or `None` if the header leaves the
+    /// stack base at 0 (the loader's default stack is used instead)
+    pub fn initial_sp(&self) -> Option<u32> {
+        (self.stack_base != 0).then(|| self.stack_base.wrapping_add(self.stack_offset))
+    }
 }
 
 #[cfg(test)]
@@ -422,4 +456,28 @@ mod tests {
         let result = PSXExecutable::load(&data);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_system_cnf_boot_path_strips_arguments() {
+        let config = SystemConfig::parse("BOOT=cdrom:\\SLUS_005.94;1 arg1 arg2").unwrap();
+
+        assert_eq!(config.boot_file, "cdrom:\\SLUS_005.94;1 arg1 arg2");
+        assert_eq!(config.boot_path(), "cdrom:\\SLUS_005.94;1");
+    }
+
+    #[test]
+    fn test_psx_exe_initial_sp() {
+        let mut data = vec![0u8; 0x800];
+        data[0..8].copy_from_slice(b"PS-X EXE");
+
+        // No stack base: the loader keeps its default
+        assert_eq!(PSXExecutable::load(&data).unwrap().initial_sp(), None);
+
+        data[0x30..0x34].copy_from_slice(&0x801FFF00u32.to_le_bytes());
+        data[0x34..0x38].copy_from_slice(&0xF0u32.to_le_bytes());
+        assert_eq!(
+            PSXExecutable::load(&data).unwrap().initial_sp(),
+            Some(0x801FFFF0)
+        );
+    }
 }
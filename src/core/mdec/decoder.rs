@@ -0,0 +1,263 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! MDEC macroblock decoding
+//!
+//! Implements the individual decoding stages of the MDEC:
+//! run-length decoding with dequantization, the inverse DCT and
+//! the YUV to RGB/monochrome conversion.
+//!
+//! # Block Format
+//!
+//! Each 8x8 block is a stream of 16-bit halfwords:
+//!
+//! ```text
+//! First halfword: Bit 15-10: Quantization scale, Bit 9-0: DC coefficient
+//! Next halfwords: Bit 15-10: Zero run length,   Bit 9-0: AC coefficient
+//! 0xFE00:         End of block (also used as padding between blocks)
+//! ```
+
+/// Decoded 8x8 block of coefficients/samples
+pub(super) type Block = [i16; 64];
+
+/// End-of-block marker
+const END_OF_BLOCK: u16 = 0xFE00;
+
+/// Matrix position of the n-th coefficient in zigzag order
+const ZAGZIG: [usize; 64] = [
+    0, 1, 8, 16, 9, 2, 3, 10, 17, 24, 32, 25, 18, 11, 4, 5, 12, 19, 26, 33, 40, 48, 41, 34, 27, 20,
+    13, 6, 7, 14, 21, 28, 35, 42, 49, 56, 57, 50, 43, 36, 29, 22, 15, 23, 30, 37, 44, 51, 58, 59,
+    52, 45, 38, 31, 39, 46, 53, 60, 61, 54, 47, 55, 62, 63,
+];
+
+/// Sign-extend a 10-bit coefficient
+#[inline(always)]
+fn signed10(value: u16) -> i32 {
+    (((value & 0x3FF) << 6) as i16 >> 6) as i32
+}
+
+/// Run-length decode and dequantize one block
+///
+/// Leading 0xFE00 padding halfwords are skipped.
+///
+/// # Arguments
+///
+/// * `data` - Input halfwords
+/// * `pos` - Read position in `data`, advanced past the block
+/// * `quant` - Quantization table (in zigzag order)
+///
+/// # Returns
+///
+/// Dequantized coefficients in matrix order, or `None` if the input
+/// ran out before the start of a block
+pub(super) fn rle_decode(data: &[u16], pos: &mut usize, quant: &[u8; 64]) -> Option<Block> {
+    while data.get(*pos) == Some(&END_OF_BLOCK) {
+        *pos += 1;
+    }
+
+    let first = *data.get(*pos)?;
+    *pos += 1;
+
+    let q_scale = (first >> 10) as i32;
+    let mut block = [0i16; 64];
+
+    let dc = if q_scale == 0 {
+        signed10(first) * 2
+    } else {
+        signed10(first) * quant[0] as i32
+    };
+    block[0] = dc.clamp(-0x400, 0x3FF) as i16;
+
+    let mut k = 0;
+    while let Some(&n) = data.get(*pos) {
+        *pos += 1;
+        if n == END_OF_BLOCK {
+            break;
+        }
+
+        k += ((n >> 10) as usize) + 1;
+        if k > 63 {
+            break;
+        }
+
+        let value = if q_scale == 0 {
+            signed10(n) * 2
+        } else {
+            (signed10(n) * quant[k] as i32 * q_scale + 4) / 8
+        };
+        block[ZAGZIG[k]] = value.clamp(-0x400, 0x3FF) as i16;
+    }
+
+    Some(block)
+}
+
+/// Apply the inverse DCT to a block in place
+///
+/// Uses the scale table uploaded by the game (the DCT basis matrix in
+/// 1.15 fixed point), applied in two one-dimensional passes.
+///
+/// # Arguments
+///
+/// * `block` - Coefficients in matrix order; replaced by the samples
+/// * `scale` - IDCT scale table
+pub(super) fn idct(block: &mut Block, scale: &[i16; 64]) {
+    let mut src = block.map(|v| v as i32);
+    let mut dst = [0i32; 64];
+
+    for _ in 0..2 {
+        for x in 0..8 {
+            for y in 0..8 {
+                let sum: i32 = (0..8)
+                    .map(|z| src[y + z * 8] * (scale[x + z * 8] as i32 / 8))
+                    .sum();
+                dst[x + y * 8] = (sum + 0xFFF) / 0x2000;
+            }
+        }
+        std::mem::swap(&mut src, &mut dst);
+    }
+
+    for (out, &value) in block.iter_mut().zip(src.iter()) {
+        *out = value as i16;
+    }
+}
+
+/// Convert a signed sample to an output byte
+#[inline(always)]
+fn to_pixel(value: i32, signed: bool) -> u8 {
+    let value = value.clamp(-128, 127) as u8;
+    if signed {
+        value
+    } else {
+        value ^ 0x80
+    }
+}
+
+/// Convert one luminance block plus chroma to RGB
+///
+/// # Arguments
+///
+/// * `out` - 16x16 RGB macroblock being assembled
+/// * `cr` - Cr (red difference) block, covering the whole macroblock
+/// * `cb` - Cb (blue difference) block, covering the whole macroblock
+/// * `luma` - Y block
+/// * `xx`, `yy` - Position of the Y block inside the macroblock (0 or 8)
+/// * `signed` - Output signed instead of unsigned components
+pub(super) fn yuv_to_rgb(
+    out: &mut [[u8; 3]; 256],
+    cr: &Block,
+    cb: &Block,
+    luma: &Block,
+    (xx, yy): (usize, usize),
+    signed: bool,
+) {
+    for y in 0..8 {
+        for x in 0..8 {
+            let chroma = (x + xx) / 2 + ((y + yy) / 2) * 8;
+            let r = cr[chroma] as i32;
+            let b = cb[chroma] as i32;
+
+            // 1.402 R, -0.3437 B - 0.7143 R, 1.772 B in 8.8 fixed point
+            let g = (-88 * b - 183 * r) >> 8;
+            let r = (359 * r) >> 8;
+            let b = (454 * b) >> 8;
+
+            let l = luma[x + y * 8] as i32;
+            out[(x + xx) + (y + yy) * 16] = [
+                to_pixel(l + r, signed),
+                to_pixel(l + g, signed),
+                to_pixel(l + b, signed),
+            ];
+        }
+    }
+}
+
+/// Convert a luminance block to monochrome pixels
+///
+/// # Arguments
+///
+/// * `luma` - Y block
+/// * `signed` - Output signed instead of unsigned samples
+///
+/// # Returns
+///
+/// 64 8-bit samples in row order
+pub(super) fn y_to_mono(luma: &Block, signed: bool) -> [u8; 64] {
+    luma.map(|l| to_pixel(l as i32, signed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed10_extension() {
+        assert_eq!(signed10(0x1FF), 511);
+        assert_eq!(signed10(0x200), -512);
+        assert_eq!(signed10(0xFFFF), -1);
+    }
+
+    #[test]
+    fn test_rle_decode_skips_padding_and_places_zigzag() {
+        let quant = [1u8; 64];
+        // Padding, DC = 5 (scale 0 doubles), run 1 then AC = 3
+        let data = [0xFE00, 0xFE00, 0x0005, 0x0403, 0xFE00];
+        let mut pos = 0;
+
+        let block = rle_decode(&data, &mut pos, &quant).unwrap();
+
+        assert_eq!(pos, 5);
+        assert_eq!(block[0], 10);
+        // k = 2 maps to matrix position 8 (row 1, column 0)
+        assert_eq!(block[8], 6);
+        assert_eq!(block.iter().filter(|&&v| v != 0).count(), 2);
+    }
+
+    #[test]
+    fn test_rle_decode_uses_quant_scale() {
+        let mut quant = [0u8; 64];
+        quant[0] = 2;
+        quant[1] = 16;
+        // Scale 2, DC = 10; AC at k=1 = 3 => (3 * 16 * 2 + 4) / 8 = 12
+        let data = [(2 << 10) | 10, 0x0003, 0xFE00];
+        let mut pos = 0;
+
+        let block = rle_decode(&data, &mut pos, &quant).unwrap();
+
+        assert_eq!(block[0], 20);
+        assert_eq!(block[1], 12);
+    }
+
+    #[test]
+    fn test_rle_decode_empty_input() {
+        let mut pos = 0;
+        assert!(rle_decode(&[0xFE00], &mut pos, &[1; 64]).is_none());
+    }
+
+    #[test]
+    fn test_y_to_mono_signedness() {
+        let mut luma = [0i16; 64];
+        luma[0] = 200;
+        luma[1] = -5;
+
+        let unsigned = y_to_mono(&luma, false);
+        assert_eq!(unsigned[0], 0xFF);
+        assert_eq!(unsigned[1], 0x7B);
+        assert_eq!(unsigned[2], 0x80);
+
+        let signed = y_to_mono(&luma, true);
+        assert_eq!(signed[0], 0x7F);
+        assert_eq!(signed[1], 0xFB);
+    }
+}
@@ -0,0 +1,529 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! MDEC (Macroblock Decoder) implementation
+//!
+//! The MDEC decompresses the JPEG-like image data used for FMV playback.
+//! Compressed data is usually fed in through DMA channel 0 (MDEC In) and the
+//! decoded pixels are read back through DMA channel 1 (MDEC Out).
+//!
+//! # Registers
+//!
+//! | Address    | Write                  | Read             |
+//! |------------|------------------------|------------------|
+//! | 0x1F801820 | Command/Parameter FIFO | Data/Response    |
+//! | 0x1F801824 | Control/Reset          | Status           |
+//!
+//! # Commands (bits 29-31)
+//!
+//! | Command | Description                                         |
+//! |---------|-----------------------------------------------------|
+//! | 1       | Decode macroblocks (bits 0-15: parameter words)     |
+//! | 2       | Set quant tables (16 words luma, +16 words chroma)  |
+//! | 3       | Set scale table (32 words)                          |
+//!
+//! # Output Depth (bits 27-28 of the decode command)
+//!
+//! - 0: 4-bit monochrome
+//! - 1: 8-bit monochrome
+//! - 2: 24-bit RGB
+//! - 3: 15-bit RGB
+//!
+//! # References
+//!
+//! - [PSX-SPX: Macroblock Decoder](http://problemkaputt.de/psx-spx.htm#macroblockdecodermdec)
+
+mod decoder;
+
+use decoder::Block;
+use std::collections::VecDeque;
+
+/// Macroblock Decoder
+///
+/// # Examples
+///
+/// ```
+/// use psrx::core::mdec::MDEC;
+///
+/// let mdec = MDEC::new();
+/// assert_eq!(mdec.read_status(), 0x8004_FFFF);
+/// ```
+pub struct MDEC {
+    /// Command currently being processed
+    command: u32,
+
+    /// Parameter words still expected for the current command
+    remaining: u32,
+
+    /// Parameter words received for the current command
+    params: Vec<u32>,
+
+    /// Decoded output words waiting to be read
+    output: VecDeque<u32>,
+
+    /// Luminance quantization table (zigzag order)
+    luma_quant: [u8; 64],
+
+    /// Chrominance quantization table (zigzag order)
+    chroma_quant: [u8; 64],
+
+    /// IDCT scale table
+    scale_table: [i16; 64],
+
+    /// Data-in request enable (control bit 30)
+    data_in_enable: bool,
+
+    /// Data-out request enable (control bit 29)
+    data_out_enable: bool,
+
+    /// Block currently being decoded (0-3: Y1-Y4, 4: Cr, 5: Cb)
+    current_block: u32,
+}
+
+impl MDEC {
+    /// Command: Decode macroblocks
+    const CMD_DECODE: u32 = 1;
+
+    /// Command: Set quant tables
+    const CMD_SET_QUANT: u32 = 2;
+
+    /// Command: Set scale table
+    const CMD_SET_SCALE: u32 = 3;
+
+    /// Create a new MDEC in its reset state
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use psrx::core::mdec::MDEC;
+    ///
+    /// let mdec = MDEC::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            command: 0,
+            remaining: 0,
+            params: Vec::new(),
+            output: VecDeque::new(),
+            luma_quant: [0; 64],
+            chroma_quant: [0; 64],
+            scale_table: [0; 64],
+            data_in_enable: false,
+            data_out_enable: false,
+            current_block: 4,
+        }
+    }
+
+    /// Reset the MDEC
+    ///
+    /// Aborts the current command and clears the FIFOs. Uploaded tables
+    /// are kept, as on hardware.
+    pub fn reset(&mut self) {
+        self.command = 0;
+        self.remaining = 0;
+        self.params.clear();
+        self.output.clear();
+        self.data_in_enable = false;
+        self.data_out_enable = false;
+        self.current_block = 4;
+    }
+
+    /// Write the command/parameter register (0x1F801820)
+    ///
+    /// The first word starts a command; following words are collected as
+    /// its parameters. The command executes once all parameters arrived.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Command or parameter word
+    pub fn write_command(&mut self, value: u32) {
+        if self.remaining > 0 {
+            self.params.push(value);
+            self.remaining -= 1;
+        } else {
+            self.command = value;
+            self.params.clear();
+            self.remaining = match value >> 29 {
+                Self::CMD_DECODE => value & 0xFFFF,
+                Self::CMD_SET_QUANT => {
+                    if value & 1 != 0 {
+                        32
+                    } else {
+                        16
+                    }
+                }
+                Self::CMD_SET_SCALE => 32,
+                _ => 0,
+            };
+            log::trace!(
+                "MDEC: Command 0x{:08X} ({} parameter words)",
+                value,
+                self.remaining
+            );
+        }
+
+        if self.remaining == 0 {
+            self.execute_command();
+        }
+    }
+
+    /// Read the data/response register (0x1F801820)
+    ///
+    /// # Returns
+    ///
+    /// The next decoded output word, or 0 if none is available
+    pub fn read_data(&mut self) -> u32 {
+        self.output.pop_front().unwrap_or(0)
+    }
+
+    /// Write the control register (0x1F801824)
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Bit 31: Reset, Bit 30: Enable data-in request,
+    ///   Bit 29: Enable data-out request
+    pub fn write_control(&mut self, value: u32) {
+        if value & (1 << 31) != 0 {
+            log::debug!("MDEC: Reset");
+            self.reset();
+        }
+
+        self.data_in_enable = value & (1 << 30) != 0;
+        self.data_out_enable = value & (1 << 29) != 0;
+    }
+
+    /// Read the status register (0x1F801824)
+    ///
+    /// # Returns
+    ///
+    /// Status register value
+    ///
+    /// # Status Register Format
+    ///
+    /// ```text
+    /// Bit 31:    Data-out FIFO empty
+    /// Bit 30:    Data-in FIFO full
+    /// Bit 29:    Command busy
+    /// Bit 28:    Data-in request
+    /// Bit 27:    Data-out request
+    /// Bit 25-26: Output depth
+    /// Bit 24:    Signed output
+    /// Bit 23:    Bit 15 of 15-bit output
+    /// Bit 16-18: Current block
+    /// Bit 0-15:  Remaining parameter words minus 1 (0xFFFF = none)
+    /// ```
+    pub fn read_status(&self) -> u32 {
+        let mut status = 0u32;
+
+        if self.output.is_empty() {
+            status |= 1 << 31;
+        }
+        if self.is_busy() {
+            status |= 1 << 29;
+        }
+        if self.data_in_enable {
+            status |= 1 << 28;
+        }
+        if self.data_out_enable && !self.output.is_empty() {
+            status |= 1 << 27;
+        }
+
+        // Depth, signed and bit 15 mirror bits 25-28 of the last command
+        status |= ((self.command >> 25) & 0xF) << 23;
+        status |= (self.current_block & 7) << 16;
+        status |= self.remaining.wrapping_sub(1) & 0xFFFF;
+
+        status
+    }
+
+    /// Check if the MDEC is still receiving parameters or has output pending
+    pub fn is_busy(&self) -> bool {
+        self.remaining > 0 || !self.output.is_empty()
+    }
+
+    /// Check if the current command still expects parameter words
+    pub fn awaiting_input(&self) -> bool {
+        self.remaining > 0
+    }
+
+    /// Get the number of decoded words waiting to be read
+    pub fn output_len(&self) -> usize {
+        self.output.len()
+    }
+
+    /// Execute the current command with its collected parameters
+    fn execute_command(&mut self) {
+        match self.command >> 29 {
+            Self::CMD_DECODE => self.decode_macroblocks(),
+            Self::CMD_SET_QUANT => {
+                let bytes: Vec<u8> = self.params.iter().flat_map(|w| w.to_le_bytes()).collect();
+                self.luma_quant.copy_from_slice(&bytes[..64]);
+                if bytes.len() >= 128 {
+                    self.chroma_quant.copy_from_slice(&bytes[64..128]);
+                }
+                log::debug!("MDEC: Quant tables set");
+            }
+            Self::CMD_SET_SCALE => {
+                for (i, word) in self.params.iter().enumerate() {
+                    self.scale_table[i * 2] = *word as i16;
+                    self.scale_table[i * 2 + 1] = (*word >> 16) as i16;
+                }
+                log::debug!("MDEC: Scale table set");
+            }
+            command => {
+                log::trace!("MDEC: Ignoring command {}", command);
+            }
+        }
+    }
+
+    /// Decode all macroblocks in the collected parameter words
+    fn decode_macroblocks(&mut self) {
+        let data: Vec<u16> = self
+            .params
+            .iter()
+            .flat_map(|&w| [w as u16, (w >> 16) as u16])
+            .collect();
+
+        let depth = (self.command >> 27) & 3;
+        let signed = self.command & (1 << 26) != 0;
+        let bit15 = self.command & (1 << 25) != 0;
+        let mut pos = 0;
+
+        match depth {
+            0 | 1 => {
+                while let Some(luma) = self.decode_block(&data, &mut pos, 0) {
+                    let pixels = decoder::y_to_mono(&luma, signed);
+                    let bytes: Vec<u8> = if depth == 0 {
+                        pixels
+                            .chunks(2)
+                            .map(|p| (p[0] >> 4) | (p[1] & 0xF0))
+                            .collect()
+                    } else {
+                        pixels.to_vec()
+                    };
+                    self.push_bytes(&bytes);
+                }
+            }
+            _ => {
+                while let Some(rgb) = self.decode_color_macroblock(&data, &mut pos, signed) {
+                    if depth == 2 {
+                        let bytes: Vec<u8> = rgb.iter().flatten().copied().collect();
+                        self.push_bytes(&bytes);
+                    } else {
+                        let bytes: Vec<u8> = rgb
+                            .iter()
+                            .flat_map(|&[r, g, b]| {
+                                let pixel = (r as u16 >> 3)
+                                    | ((g as u16 >> 3) << 5)
+                                    | ((b as u16 >> 3) << 10)
+                                    | ((bit15 as u16) << 15);
+                                pixel.to_le_bytes()
+                            })
+                            .collect();
+                        self.push_bytes(&bytes);
+                    }
+                }
+            }
+        }
+
+        log::trace!("MDEC: Decoded {} output words", self.output.len());
+    }
+
+    /// Decode one block with the IDCT applied
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Input halfwords
+    /// * `pos` - Read position in `data`
+    /// * `block` - Block index (0-3: Y, 4: Cr, 5: Cb), selects the quant table
+    fn decode_block(&mut self, data: &[u16], pos: &mut usize, block: u32) -> Option<Block> {
+        self.current_block = block;
+        let quant = if block >= 4 {
+            &self.chroma_quant
+        } else {
+            &self.luma_quant
+        };
+        let mut coefficients = decoder::rle_decode(data, pos, quant)?;
+        decoder::idct(&mut coefficients, &self.scale_table);
+        Some(coefficients)
+    }
+
+    /// Decode a 16x16 color macroblock (Cr, Cb, Y1, Y2, Y3, Y4)
+    fn decode_color_macroblock(
+        &mut self,
+        data: &[u16],
+        pos: &mut usize,
+        signed: bool,
+    ) -> Option<[[u8; 3]; 256]> {
+        let cr = self.decode_block(data, pos, 4)?;
+        let cb = self.decode_block(data, pos, 5)?;
+
+        let mut rgb = [[0u8; 3]; 256];
+        for (i, offset) in [(0, 0), (8, 0), (0, 8), (8, 8)].into_iter().enumerate() {
+            let luma = self.decode_block(data, pos, i as u32)?;
+            decoder::yuv_to_rgb(&mut rgb, &cr, &cb, &luma, offset, signed);
+        }
+
+        Some(rgb)
+    }
+
+    /// Pack output bytes into little-endian words
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(4) {
+            let mut word = [0u8; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.output.push_back(u32::from_le_bytes(word));
+        }
+    }
+}
+
+impl Default for MDEC {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Upload flat quant tables and the standard DCT scale table
+    fn setup_tables(mdec: &mut MDEC) {
+        mdec.write_command(0x4000_0001);
+        for _ in 0..32 {
+            mdec.write_command(0x0101_0101);
+        }
+
+        let mut scale = [0i16; 64];
+        for k in 0..8 {
+            for n in 0..8 {
+                let c = if k == 0 {
+                    std::f64::consts::FRAC_1_SQRT_2
+                } else {
+                    ((2 * n + 1) as f64 * k as f64 * std::f64::consts::PI / 16.0).cos()
+                };
+                scale[k * 8 + n] = (c * 32768.0).round().clamp(-32768.0, 32767.0) as i16;
+            }
+        }
+        mdec.write_command(0x6000_0000);
+        for pair in scale.chunks(2) {
+            mdec.write_command((pair[0] as u16 as u32) | ((pair[1] as u16 as u32) << 16));
+        }
+    }
+
+    /// DC-only block (scale 1, DC 400) which decodes to a flat Y of 50
+    const FLAT_BLOCK: u32 = 0xFE00_0590;
+
+    #[test]
+    fn test_mdec_reset_state() {
+        let mut mdec = MDEC::new();
+        mdec.write_command(0x2000_0002);
+        assert!(mdec.awaiting_input());
+
+        mdec.write_control(0x8000_0000);
+
+        assert!(!mdec.awaiting_input());
+        assert_eq!(mdec.read_status(), 0x8004_FFFF);
+    }
+
+    #[test]
+    fn test_mdec_status_remaining_words() {
+        let mut mdec = MDEC::new();
+        mdec.write_command(0x6000_0000);
+
+        let status = mdec.read_status();
+        assert_eq!(status & 0xFFFF, 31);
+        assert_ne!(status & (1 << 29), 0); // Busy
+    }
+
+    #[test]
+    fn test_mdec_set_tables() {
+        let mut mdec = MDEC::new();
+        setup_tables(&mut mdec);
+
+        assert_eq!(mdec.luma_quant, [1; 64]);
+        assert_eq!(mdec.chroma_quant, [1; 64]);
+        assert_eq!(mdec.scale_table[0], 0x5A82);
+        assert_eq!(mdec.scale_table[8], 0x7D8A);
+        assert!(!mdec.is_busy());
+    }
+
+    #[test]
+    fn test_mdec_decode_8bit_mono_block() {
+        let mut mdec = MDEC::new();
+        setup_tables(&mut mdec);
+
+        // Decode, 8-bit unsigned, one parameter word
+        mdec.write_command(0x2800_0001);
+        mdec.write_command(FLAT_BLOCK);
+
+        // 64 pixels of 50 ^ 0x80 = 0xB2
+        assert_eq!(mdec.output_len(), 16);
+        for _ in 0..16 {
+            assert_eq!(mdec.read_data(), 0xB2B2_B2B2);
+        }
+        assert_ne!(mdec.read_status() & (1 << 31), 0);
+    }
+
+    #[test]
+    fn test_mdec_decode_4bit_signed_block() {
+        let mut mdec = MDEC::new();
+        setup_tables(&mut mdec);
+
+        // Decode, 4-bit signed
+        mdec.write_command(0x2400_0001);
+        mdec.write_command(FLAT_BLOCK);
+
+        // 50 >> 4 = 3 in both nibbles
+        assert_eq!(mdec.output_len(), 8);
+        assert_eq!(mdec.read_data(), 0x3333_3333);
+    }
+
+    #[test]
+    fn test_mdec_decode_15bit_gray_macroblock() {
+        let mut mdec = MDEC::new();
+        setup_tables(&mut mdec);
+
+        // Cr = Cb = 0, four flat Y blocks
+        mdec.write_command(0x3A00_0006);
+        mdec.write_command(0xFE00_0400);
+        mdec.write_command(0xFE00_0400);
+        for _ in 0..4 {
+            mdec.write_command(FLAT_BLOCK);
+        }
+
+        // 256 pixels, two per word; 178 >> 3 = 22 per component, bit 15 set
+        assert_eq!(mdec.output_len(), 128);
+        let pixel = 22 | (22 << 5) | (22 << 10) | 0x8000;
+        assert_eq!(mdec.read_data(), pixel | (pixel << 16));
+    }
+
+    #[test]
+    fn test_mdec_decode_24bit_macroblock_size() {
+        let mut mdec = MDEC::new();
+        setup_tables(&mut mdec);
+
+        mdec.write_command(0x3000_0006);
+        mdec.write_command(0xFE00_0400);
+        mdec.write_command(0xFE00_0400);
+        for _ in 0..4 {
+            mdec.write_command(FLAT_BLOCK);
+        }
+
+        // 256 pixels * 3 bytes = 192 words
+        assert_eq!(mdec.output_len(), 192);
+        assert_eq!(mdec.read_data(), 0xB2B2_B2B2);
+    }
+}
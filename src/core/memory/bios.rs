@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! BIOS image validation
+//!
+//! Dumps found in the wild are not always exactly 512KB. Trimmed dumps
+//! (trailing padding removed) are padded back to full size; anything larger
+//! than the ROM or too small to hold the kernel is rejected. The first
+//! instruction at 0xBFC00000 is checked to catch byte-swapped dumps.
+
+use super::Bus;
+use crate::core::error::{EmulatorError, Result};
+
+/// Smallest image accepted as a trimmed dump (64KB)
+const MIN_BIOS_SIZE: usize = 64 * 1024;
+
+/// First instruction of every retail BIOS: `lui t0, 0x0013`
+const RESET_INSTRUCTION: u32 = 0x3C08_0013;
+
+/// Check a BIOS dump and bring it to the ROM size
+///
+/// # Arguments
+///
+/// * `data` - Raw file contents
+///
+/// # Returns
+///
+/// - `Ok(image)` of exactly 512KB, zero-padded if the dump was trimmed
+/// - `Err(EmulatorError::InvalidBiosSize)` if the size cannot be a BIOS dump
+/// - `Err(EmulatorError::InvalidBios)` if the dump is byte-swapped
+pub(super) fn prepare_image(mut data: Vec<u8>) -> Result<Vec<u8>> {
+    let size_error = |hint: &'static str| EmulatorError::InvalidBiosSize {
+        expected: Bus::BIOS_SIZE,
+        got: data.len(),
+        hint,
+    };
+
+    if data.len() > Bus::BIOS_SIZE {
+        return Err(size_error(
+            "the file is larger than the BIOS ROM; if it contains several images, extract the first 512KB",
+        ));
+    }
+    if data.len() < MIN_BIOS_SIZE {
+        return Err(size_error(
+            "the file is too small to be a BIOS dump; check that the correct file was selected",
+        ));
+    }
+    if !data.len().is_multiple_of(4) {
+        return Err(size_error(
+            "the size is not a multiple of 4 bytes; the dump is probably truncated mid-word",
+        ));
+    }
+
+    let first = u32::from_le_bytes([data[0], data[1], data[2], data[3]]);
+    if first != RESET_INSTRUCTION {
+        let swapped = [
+            first.swap_bytes(),
+            ((first & 0x00FF_00FF) << 8) | ((first >> 8) & 0x00FF_00FF),
+            first.rotate_left(16),
+        ];
+        if swapped.contains(&RESET_INSTRUCTION) {
+            return Err(EmulatorError::InvalidBios(format!(
+                "first word at 0xBFC00000 is 0x{:08X}, which looks byte-swapped; \
+                 convert the dump to little-endian byte order",
+                first
+            )));
+        }
+        log::warn!(
+            "BIOS: unrecognized reset vector 0x{:08X} (expected 0x{:08X}), loading anyway",
+            first,
+            RESET_INSTRUCTION
+        );
+    }
+
+    if data.len() < Bus::BIOS_SIZE {
+        log::warn!(
+            "BIOS: image is {} bytes, padding to {} bytes",
+            data.len(),
+            Bus::BIOS_SIZE
+        );
+        data.resize(Bus::BIOS_SIZE, 0);
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Image of `size` bytes starting with the reset instruction
+    fn image(size: usize) -> Vec<u8> {
+        let mut data = vec![0xAB; size];
+        data[..4].copy_from_slice(&RESET_INSTRUCTION.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_exact_size_loaded_as_is() {
+        let data = image(Bus::BIOS_SIZE);
+
+        assert_eq!(prepare_image(data.clone()).unwrap(), data);
+    }
+
+    #[test]
+    fn test_undersized_dump_padded() {
+        let prepared = prepare_image(image(256 * 1024)).unwrap();
+
+        assert_eq!(prepared.len(), Bus::BIOS_SIZE);
+        assert_eq!(prepared[256 * 1024 - 1], 0xAB);
+        assert!(prepared[256 * 1024..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_wrong_sizes_rejected_with_hint() {
+        for size in [Bus::BIOS_SIZE * 2, 1024, MIN_BIOS_SIZE + 2] {
+            match prepare_image(image(size)) {
+                Err(EmulatorError::InvalidBiosSize { got, hint, .. }) => {
+                    assert_eq!(got, size);
+                    assert!(!hint.is_empty());
+                }
+                other => panic!("size {} not rejected: {:?}", size, other.map(|d| d.len())),
+            }
+        }
+    }
+
+    #[test]
+    fn test_byte_swapped_dumps_rejected() {
+        let swapped_32 = image(Bus::BIOS_SIZE)
+            .chunks(4)
+            .flat_map(|w| [w[3], w[2], w[1], w[0]])
+            .collect::<Vec<_>>();
+        let swapped_16 = image(Bus::BIOS_SIZE)
+            .chunks(2)
+            .flat_map(|h| [h[1], h[0]])
+            .collect::<Vec<_>>();
+
+        for data in [swapped_32, swapped_16] {
+            let err = prepare_image(data).unwrap_err();
+            assert!(matches!(err, EmulatorError::InvalidBios(_)));
+            assert!(err.to_string().contains("byte-swapped"));
+        }
+    }
+
+    #[test]
+    fn test_unknown_reset_vector_accepted() {
+        let data = vec![0u8; Bus::BIOS_SIZE];
+
+        assert!(prepare_image(data).is_ok());
+    }
+}
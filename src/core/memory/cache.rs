@@ -60,6 +60,18 @@ impl Bus {
         self.icache_invalidate_range_queue.drain(..).collect()
     }
 
+    /// Check whether cache tag test mode is enabled (cache control bit 2)
+    ///
+    /// While the cache is isolated, stores in tag test mode write cache
+    /// tags (invalidating the line) instead of instruction words.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the TAG TEST bit of 0xFFFE0130 is set
+    pub fn cache_tag_test(&self) -> bool {
+        self.cache_control & Self::CACHE_CONTROL_TAG_TEST != 0
+    }
+
     /// Queue an instruction for ICache prefill
     ///
     /// Called when BIOS copies code to RAM. Queues both cached and uncached
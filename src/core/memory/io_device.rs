@@ -53,7 +53,7 @@
 //!         (self.base_addr, self.base_addr + 0x0F)
 //!     }
 //!
-//!     fn read_register(&self, offset: u32) -> Result<u32> {
+//!     fn read_register(&mut self, offset: u32) -> Result<u32> {
 //!         let index = (offset / 4) as usize;
 //!         Ok(self.registers.get(index).copied().unwrap_or(0))
 //!     }
@@ -94,8 +94,14 @@ use crate::core::error::Result;
 /// # Thread Safety
 ///
 /// IODevice implementations do not need to be `Send` or `Sync` as the Bus
-/// is not shared across threads. However, they should handle interior mutability
-/// properly when accessed through `&self` methods (e.g., using `RefCell`).
+/// is not shared across threads.
+///
+/// # Read Side Effects
+///
+/// Reads take `&mut self` because many PSX registers change state when
+/// read (e.g. timer mode flags, FIFOs). The default 8/16-bit write helpers
+/// perform a read-modify-write through `read_register`; devices with
+/// read side effects should override them.
 pub trait IODevice {
     /// Get the address range this device responds to
     ///
@@ -116,7 +122,7 @@ pub trait IODevice {
     /// // GPU registers: 0x1F801810 - 0x1F801817
     /// (0x1F801810, 0x1F801817)
     /// #     }
-    /// #     fn read_register(&self, offset: u32) -> psrx::core::error::Result<u32> { Ok(0) }
+    /// #     fn read_register(&mut self, offset: u32) -> psrx::core::error::Result<u32> { Ok(0) }
     /// #     fn write_register(&mut self, offset: u32, value: u32) -> psrx::core::error::Result<()> { Ok(()) }
     /// # }
     /// ```
@@ -159,7 +165,7 @@ pub trait IODevice {
     /// - The offset is out of range for this device
     /// - The offset is not properly aligned
     /// - The register is write-only
-    fn read_register(&self, offset: u32) -> Result<u32>;
+    fn read_register(&mut self, offset: u32) -> Result<u32>;
 
     /// Write a 32-bit value to a device register
     ///
@@ -190,7 +196,7 @@ pub trait IODevice {
     /// # Returns
     ///
     /// The 16-bit register value
-    fn read_register16(&self, offset: u32) -> Result<u16> {
+    fn read_register16(&mut self, offset: u32) -> Result<u16> {
         // Default: read 32-bit and mask
         let value = self.read_register(offset & !0x03)?;
         let shift = (offset & 0x02) * 8;
@@ -229,7 +235,7 @@ pub trait IODevice {
     /// # Returns
     ///
     /// The 8-bit register value
-    fn read_register8(&self, offset: u32) -> Result<u8> {
+    fn read_register8(&mut self, offset: u32) -> Result<u8> {
         // Default: read 32-bit and mask
         let value = self.read_register(offset & !0x03)?;
         let shift = (offset & 0x03) * 8;
@@ -292,7 +298,7 @@ mod tests {
             (self.base, self.base + self.size - 1)
         }
 
-        fn read_register(&self, offset: u32) -> Result<u32> {
+        fn read_register(&mut self, offset: u32) -> Result<u32> {
             let index = (offset / 4) as usize;
             self.registers
                 .get(index)
@@ -0,0 +1,397 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! I/O register access log for reverse-engineering
+//!
+//! When enabled, every read and write that lands in the I/O port region
+//! (0x1F801000-0x1F9FFFFF physical) is recorded with its decoded register
+//! name, size and value. The log keeps only the most recent accesses and
+//! can be restricted to address ranges, e.g. just the DMA registers.
+//!
+//! When the log is disabled, the only cost on the access path is a single
+//! `Option` check.
+//!
+//! # Example
+//!
+//! ```
+//! use psrx::core::memory::{Bus, WatchAccess};
+//!
+//! let mut bus = Bus::new();
+//! bus.enable_io_log(64);
+//!
+//! bus.write32(0x1F801074, 0x0000_0001).unwrap();
+//!
+//! let log = bus.io_log();
+//! assert_eq!(log[0].name, "I_MASK");
+//! assert_eq!(log[0].kind, WatchAccess::Write);
+//! ```
+
+use std::cell::RefCell;
+
+use super::{Bus, WatchAccess};
+use crate::core::cpu::TraceBuffer;
+
+/// A single logged I/O register access
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IoAccess {
+    /// Physical address of the access
+    pub address: u32,
+
+    /// Decoded register name (see [`io_register_name`])
+    pub name: &'static str,
+
+    /// Access size in bytes (1, 2 or 4)
+    pub size: u8,
+
+    /// Value read or written (zero-extended)
+    pub value: u32,
+
+    /// Whether the access was a read or a write
+    pub kind: WatchAccess,
+}
+
+/// Ring buffer of recent I/O accesses, filtered by physical address
+pub(super) type IoAccessLog = TraceBuffer<IoAccess>;
+
+impl Bus {
+    /// Start logging I/O register accesses
+    ///
+    /// Re-enabling discards the previous log and filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of most recent accesses to keep
+    pub fn enable_io_log(&mut self, capacity: usize) {
+        self.io_log = Some(RefCell::new(IoAccessLog::new(capacity)));
+    }
+
+    /// Stop logging I/O register accesses and discard the log
+    pub fn disable_io_log(&mut self) {
+        self.io_log = None;
+    }
+
+    /// Only log I/O accesses whose physical address is in `start..end`
+    ///
+    /// Multiple filters may be added; an access is logged if it matches
+    /// any of them. Has no effect while the log is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - First physical address to record
+    /// * `end` - Physical address one past the last to record
+    pub fn add_io_log_filter(&mut self, start: u32, end: u32) {
+        if let Some(log) = &mut self.io_log {
+            log.get_mut().add_filter(start..end);
+        }
+    }
+
+    /// Remove all I/O log filters (log every I/O access)
+    pub fn clear_io_log_filters(&mut self) {
+        if let Some(log) = &mut self.io_log {
+            log.get_mut().clear_filters();
+        }
+    }
+
+    /// Get the most recent I/O register accesses
+    ///
+    /// # Returns
+    ///
+    /// Logged accesses, oldest first (empty when the log is disabled)
+    pub fn io_log(&self) -> Vec<IoAccess> {
+        self.io_log
+            .as_ref()
+            .map(|log| log.borrow().entries().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Record an access if it targets the I/O region and passes the filters
+    ///
+    /// Callers check `self.io_log.is_some()` first to keep the common path
+    /// free of any work.
+    ///
+    /// # Arguments
+    ///
+    /// * `vaddr` - Virtual address of the access
+    /// * `size` - Access size in bytes
+    /// * `value` - Value read or written
+    /// * `kind` - Access kind
+    pub(super) fn log_io_access(&self, vaddr: u32, size: u8, value: u32, kind: WatchAccess) {
+        let Some(log) = &self.io_log else {
+            return;
+        };
+
+        let paddr = self.translate_address(vaddr);
+        if !(Self::IO_START..=Self::IO_END).contains(&paddr) {
+            return;
+        }
+
+        let mut log = log.borrow_mut();
+        if log.matches(paddr) {
+            log.push(IoAccess {
+                address: paddr,
+                name: io_register_name(paddr, kind),
+                size,
+                value,
+                kind,
+            });
+        }
+    }
+}
+
+/// DMA channel register names, indexed by channel then register
+const DMA_REGISTERS: [[&str; 3]; 7] = [
+    ["DMA0 MADR", "DMA0 BCR", "DMA0 CHCR"],
+    ["DMA1 MADR", "DMA1 BCR", "DMA1 CHCR"],
+    ["DMA2 MADR", "DMA2 BCR", "DMA2 CHCR"],
+    ["DMA3 MADR", "DMA3 BCR", "DMA3 CHCR"],
+    ["DMA4 MADR", "DMA4 BCR", "DMA4 CHCR"],
+    ["DMA5 MADR", "DMA5 BCR", "DMA5 CHCR"],
+    ["DMA6 MADR", "DMA6 BCR", "DMA6 CHCR"],
+];
+
+/// Timer register names, indexed by timer then register
+const TIMER_REGISTERS: [[&str; 3]; 3] = [
+    ["TIMER0 COUNT", "TIMER0 MODE", "TIMER0 TARGET"],
+    ["TIMER1 COUNT", "TIMER1 MODE", "TIMER1 TARGET"],
+    ["TIMER2 COUNT", "TIMER2 MODE", "TIMER2 TARGET"],
+];
+
+/// SPU per-voice register names, indexed by offset within the voice / 2
+const SPU_VOICE_REGISTERS: [&str; 8] = [
+    "SPU VOICE VOL_L",
+    "SPU VOICE VOL_R",
+    "SPU VOICE PITCH",
+    "SPU VOICE START",
+    "SPU VOICE ADSR_LO",
+    "SPU VOICE ADSR_HI",
+    "SPU VOICE ADSR_VOL",
+    "SPU VOICE REPEAT",
+];
+
+/// Decode the name of the I/O register at a physical address
+///
+/// Addresses inside a register (e.g. the upper half of a 32-bit register)
+/// decode to that register. Some registers have different meanings for
+/// reads and writes (GPU, MDEC), so the access kind is taken into account.
+/// SPU voice registers are named without the voice number; the address
+/// identifies the voice.
+///
+/// # Arguments
+///
+/// * `paddr` - Physical address in the I/O region
+/// * `kind` - Whether the access is a read or a write
+///
+/// # Returns
+///
+/// Register name, or `"UNKNOWN"` for unmapped addresses
+///
+/// # Example
+///
+/// ```
+/// use psrx::core::memory::{io_register_name, WatchAccess};
+///
+/// assert_eq!(io_register_name(0x1F8010A8, WatchAccess::Write), "DMA2 CHCR");
+/// assert_eq!(io_register_name(0x1F801814, WatchAccess::Write), "GPU GP1");
+/// assert_eq!(io_register_name(0x1F801814, WatchAccess::Read), "GPU GPUSTAT");
+/// ```
+pub fn io_register_name(paddr: u32, kind: WatchAccess) -> &'static str {
+    let write = kind == WatchAccess::Write;
+
+    match paddr {
+        // Memory control 1
+        0x1F801000..=0x1F801003 => "EXP1_BASE",
+        0x1F801004..=0x1F801007 => "EXP2_BASE",
+        0x1F801008..=0x1F80100B => "EXP1_DELAY",
+        0x1F80100C..=0x1F80100F => "EXP3_DELAY",
+        0x1F801010..=0x1F801013 => "BIOS_DELAY",
+        0x1F801014..=0x1F801017 => "SPU_DELAY",
+        0x1F801018..=0x1F80101B => "CDROM_DELAY",
+        0x1F80101C..=0x1F80101F => "EXP2_DELAY",
+        0x1F801020..=0x1F801023 => "COM_DELAY",
+
+        // Controller and memory card port
+        0x1F801040..=0x1F801043 => "JOY_DATA",
+        0x1F801044..=0x1F801047 => "JOY_STAT",
+        0x1F801048..=0x1F801049 => "JOY_MODE",
+        0x1F80104A..=0x1F80104B => "JOY_CTRL",
+        0x1F80104E..=0x1F80104F => "JOY_BAUD",
+
+        // Serial port
+        0x1F801050..=0x1F801053 => "SIO_DATA",
+        0x1F801054..=0x1F801057 => "SIO_STAT",
+        0x1F801058..=0x1F801059 => "SIO_MODE",
+        0x1F80105A..=0x1F80105B => "SIO_CTRL",
+        0x1F80105C..=0x1F80105D => "SIO_MISC",
+        0x1F80105E..=0x1F80105F => "SIO_BAUD",
+
+        // Memory control 2
+        0x1F801060..=0x1F801063 => "RAM_SIZE",
+
+        // Interrupt controller
+        0x1F801070..=0x1F801073 => "I_STAT",
+        0x1F801074..=0x1F801077 => "I_MASK",
+
+        // DMA
+        0x1F801080..=0x1F8010EF => {
+            let offset = paddr - 0x1F801080;
+            match (offset & 0xF) >> 2 {
+                reg @ 0..=2 => DMA_REGISTERS[(offset >> 4) as usize][reg as usize],
+                _ => "UNKNOWN",
+            }
+        }
+        0x1F8010F0..=0x1F8010F3 => "DPCR",
+        0x1F8010F4..=0x1F8010F7 => "DICR",
+
+        // Timers
+        0x1F801100..=0x1F80112F => {
+            let offset = paddr - 0x1F801100;
+            match (offset & 0xF) >> 2 {
+                reg @ 0..=2 => TIMER_REGISTERS[(offset >> 4) as usize][reg as usize],
+                _ => "UNKNOWN",
+            }
+        }
+
+        // CD-ROM (the meaning of registers 1-3 depends on the index)
+        0x1F801800 => "CDROM INDEX",
+        0x1F801801 => "CDROM REG1",
+        0x1F801802 => "CDROM REG2",
+        0x1F801803 => "CDROM REG3",
+
+        // GPU
+        0x1F801810..=0x1F801813 if write => "GPU GP0",
+        0x1F801810..=0x1F801813 => "GPU GPUREAD",
+        0x1F801814..=0x1F801817 if write => "GPU GP1",
+        0x1F801814..=0x1F801817 => "GPU GPUSTAT",
+
+        // MDEC
+        0x1F801820..=0x1F801823 if write => "MDEC COMMAND",
+        0x1F801820..=0x1F801823 => "MDEC DATA",
+        0x1F801824..=0x1F801827 if write => "MDEC CONTROL",
+        0x1F801824..=0x1F801827 => "MDEC STATUS",
+
+        // SPU
+        0x1F801C00..=0x1F801D7F => SPU_VOICE_REGISTERS[((paddr & 0xF) >> 1) as usize],
+        0x1F801D80..=0x1F801D81 => "SPU MAIN_VOL_L",
+        0x1F801D82..=0x1F801D83 => "SPU MAIN_VOL_R",
+        0x1F801D84..=0x1F801D85 => "SPU REVERB_VOL_L",
+        0x1F801D86..=0x1F801D87 => "SPU REVERB_VOL_R",
+        0x1F801D88..=0x1F801D8B => "SPU KON",
+        0x1F801D8C..=0x1F801D8F => "SPU KOFF",
+        0x1F801D90..=0x1F801D93 => "SPU PMON",
+        0x1F801D94..=0x1F801D97 => "SPU NON",
+        0x1F801D98..=0x1F801D9B => "SPU EON",
+        0x1F801D9C..=0x1F801D9F => "SPU ENDX",
+        0x1F801DA2..=0x1F801DA3 => "SPU REVERB_BASE",
+        0x1F801DA4..=0x1F801DA5 => "SPU IRQ_ADDR",
+        0x1F801DA6..=0x1F801DA7 => "SPU TRANSFER_ADDR",
+        0x1F801DA8..=0x1F801DA9 => "SPU TRANSFER_FIFO",
+        0x1F801DAA..=0x1F801DAB => "SPU SPUCNT",
+        0x1F801DAC..=0x1F801DAD => "SPU TRANSFER_CTRL",
+        0x1F801DAE..=0x1F801DAF => "SPU SPUSTAT",
+        0x1F801DB0..=0x1F801DB3 => "SPU CD_VOL",
+        0x1F801DB4..=0x1F801DB7 => "SPU EXT_VOL",
+        0x1F801DB8..=0x1F801DBB => "SPU CURRENT_VOL",
+        0x1F801DC0..=0x1F801DFF => "SPU REVERB",
+        0x1F801E00..=0x1F801FFF => "SPU VOICE_CURRENT_VOL",
+
+        // Expansion region 2
+        0x1F802041 => "EXP2 POST",
+        0x1F802000..=0x1F803FFF => "EXP2",
+
+        _ => "UNKNOWN",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_io_log_records_decoded_register_names() {
+        let mut bus = Bus::new();
+        bus.enable_io_log(16);
+
+        bus.write32(0x1F801074, 0x0000_000D).unwrap();
+        bus.write32(0xBF8010A8, 0x0100_0201).unwrap();
+        bus.write32(0x1F801814, 0x0300_0000).unwrap();
+        bus.read16(0x1F801D88).unwrap();
+        bus.write16(0x1F801C34, 0x1000).unwrap();
+        bus.read8(0x1F801800).unwrap();
+
+        // RAM accesses are not I/O
+        bus.write32(0x80000000, 0x1234).unwrap();
+
+        let log = bus.io_log();
+        let names: Vec<_> = log.iter().map(|access| access.name).collect();
+        assert_eq!(
+            names,
+            [
+                "I_MASK",
+                "DMA2 CHCR",
+                "GPU GP1",
+                "SPU KON",
+                "SPU VOICE PITCH",
+                "CDROM INDEX"
+            ]
+        );
+
+        assert_eq!(log[1].address, 0x1F8010A8);
+        assert_eq!(log[1].size, 4);
+        assert_eq!(log[1].value, 0x0100_0201);
+        assert_eq!(log[3].kind, WatchAccess::Read);
+        assert_eq!(log[3].size, 2);
+        assert_eq!(log[5].size, 1);
+    }
+
+    #[test]
+    fn test_io_log_filter_and_capacity() {
+        let mut bus = Bus::new();
+        bus.enable_io_log(2);
+        bus.add_io_log_filter(0x1F801080, 0x1F801100);
+
+        bus.write32(0x1F801074, 0).unwrap();
+        for channel in 0..4 {
+            bus.write32(0x1F801080 + channel * 0x10, channel).unwrap();
+        }
+
+        // Only DMA accesses pass the filter, and only the last two are kept
+        let log = bus.io_log();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[0].name, "DMA2 MADR");
+        assert_eq!(log[1].name, "DMA3 MADR");
+
+        bus.disable_io_log();
+        bus.write32(0x1F801080, 0).unwrap();
+        assert!(bus.io_log().is_empty());
+    }
+
+    #[test]
+    fn test_io_register_name_depends_on_direction() {
+        assert_eq!(io_register_name(0x1F801810, WatchAccess::Write), "GPU GP0");
+        assert_eq!(
+            io_register_name(0x1F801810, WatchAccess::Read),
+            "GPU GPUREAD"
+        );
+        assert_eq!(
+            io_register_name(0x1F801826, WatchAccess::Read),
+            "MDEC STATUS"
+        );
+        assert_eq!(
+            io_register_name(0x1F801128, WatchAccess::Read),
+            "TIMER2 TARGET"
+        );
+        assert_eq!(io_register_name(0x1F8010EC, WatchAccess::Read), "UNKNOWN");
+    }
+}
@@ -7,6 +7,7 @@
 //! It implements read and write operations for various hardware components including:
 //!
 //! - **GPU**: Graphics Processing Unit registers (GP0, GP1, GPUREAD, GPUSTAT)
+//! - **MDEC**: Macroblock decoder command/data and control/status registers
 //! - **Controller**: Joypad and memory card interface registers
 //! - **Timers**: Three root counter/timer channels (0-2)
 //! - **CD-ROM**: CD-ROM drive control and data registers
@@ -32,6 +33,12 @@ impl Bus {
     /// The 32-bit value read from the I/O port
     pub(super) fn read_io_port32(&self, paddr: u32) -> Result<u32> {
         match paddr {
+            // Memory Control registers (0x1F801000-0x1F801023)
+            Self::MEM_CONTROL_START..=Self::MEM_CONTROL_END => Ok(self.read_mem_control(paddr)),
+
+            // RAM_SIZE register (0x1F801060)
+            Self::RAM_SIZE_REG => Ok(self.read_ram_size()),
+
             // GPU GPUREAD register (0x1F801810)
             Self::GPU_GP0 => {
                 if let Some(gpu) = &self.gpu {
@@ -56,6 +63,30 @@ impl Bus {
                 }
             }
 
+            // MDEC data/response register (0x1F801820)
+            Self::MDEC_DATA => {
+                if let Some(mdec) = &self.mdec {
+                    let value = mdec.borrow_mut().read_data();
+                    log::trace!("MDEC_DATA (0x{:08X}) -> 0x{:08X}", paddr, value);
+                    Ok(value)
+                } else {
+                    log::warn!("MDEC_DATA access before MDEC initialized");
+                    Ok(0)
+                }
+            }
+
+            // MDEC status register (0x1F801824)
+            Self::MDEC_CTRL => {
+                if let Some(mdec) = &self.mdec {
+                    let value = mdec.borrow().read_status();
+                    log::trace!("MDEC_STATUS (0x{:08X}) -> 0x{:08X}", paddr, value);
+                    Ok(value)
+                } else {
+                    log::warn!("MDEC_STATUS access before MDEC initialized");
+                    Ok(0)
+                }
+            }
+
             // Controller JOY_RX_DATA register (0x1F801040)
             Self::JOY_DATA => {
                 if let Some(controller_ports) = &self.controller_ports {
@@ -192,7 +223,7 @@ impl Bus {
             // Timer 0 Counter (0x1F801100)
             Self::TIMER0_COUNTER => {
                 if let Some(timers) = &self.timers {
-                    let value = timers.borrow().channel(0).read_counter() as u32;
+                    let value = timers.borrow().read_counter(0) as u32;
                     log::trace!("TIMER0_COUNTER read at 0x{:08X} -> 0x{:04X}", paddr, value);
                     Ok(value)
                 } else {
@@ -228,7 +259,7 @@ impl Bus {
             // Timer 1 Counter (0x1F801110)
             Self::TIMER1_COUNTER => {
                 if let Some(timers) = &self.timers {
-                    let value = timers.borrow().channel(1).read_counter() as u32;
+                    let value = timers.borrow().read_counter(1) as u32;
                     log::trace!("TIMER1_COUNTER read at 0x{:08X} -> 0x{:04X}", paddr, value);
                     Ok(value)
                 } else {
@@ -264,7 +295,7 @@ impl Bus {
             // Timer 2 Counter (0x1F801120)
             Self::TIMER2_COUNTER => {
                 if let Some(timers) = &self.timers {
-                    let value = timers.borrow().channel(2).read_counter() as u32;
+                    let value = timers.borrow().read_counter(2) as u32;
                     log::trace!("TIMER2_COUNTER read at 0x{:08X} -> 0x{:04X}", paddr, value);
                     Ok(value)
                 } else {
@@ -319,6 +350,18 @@ impl Bus {
     /// Result indicating success or failure
     pub(super) fn write_io_port32(&mut self, paddr: u32, value: u32) -> Result<()> {
         match paddr {
+            // Memory Control registers (0x1F801000-0x1F801023)
+            Self::MEM_CONTROL_START..=Self::MEM_CONTROL_END => {
+                self.write_mem_control(paddr, value);
+                Ok(())
+            }
+
+            // RAM_SIZE register (0x1F801060)
+            Self::RAM_SIZE_REG => {
+                self.write_ram_size(value);
+                Ok(())
+            }
+
             // GPU GP0 register (0x1F801810) - commands and data
             Self::GPU_GP0 => {
                 log::info!("GP0 write = 0x{:08X}", value);
@@ -343,6 +386,30 @@ impl Bus {
                 }
             }
 
+            // MDEC command/parameter register (0x1F801820)
+            Self::MDEC_DATA => {
+                if let Some(mdec) = &self.mdec {
+                    mdec.borrow_mut().write_command(value);
+                    log::trace!("MDEC_CMD write at 0x{:08X} = 0x{:08X}", paddr, value);
+                    Ok(())
+                } else {
+                    log::warn!("MDEC_CMD write before MDEC initialized");
+                    Ok(())
+                }
+            }
+
+            // MDEC control register (0x1F801824)
+            Self::MDEC_CTRL => {
+                if let Some(mdec) = &self.mdec {
+                    mdec.borrow_mut().write_control(value);
+                    log::trace!("MDEC_CTRL write at 0x{:08X} = 0x{:08X}", paddr, value);
+                    Ok(())
+                } else {
+                    log::warn!("MDEC_CTRL write before MDEC initialized");
+                    Ok(())
+                }
+            }
+
             // Controller JOY_TX_DATA register (0x1F801040)
             Self::JOY_DATA => {
                 if let Some(controller_ports) = &self.controller_ports {
@@ -969,6 +1036,27 @@ mod tests {
         assert!(bus.write_io_port32(Bus::GPU_GP1, 0xABCDEF00).is_ok());
     }
 
+    #[test]
+    fn test_mdec_registers_routed() {
+        use crate::core::mdec::MDEC;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let mut bus = Bus::new();
+        let mdec = Rc::new(RefCell::new(MDEC::new()));
+        bus.set_mdec(mdec.clone());
+
+        // Set scale table command expects 32 parameter words
+        bus.write_io_port32(Bus::MDEC_DATA, 0x6000_0000).unwrap();
+        assert!(mdec.borrow().awaiting_input());
+        assert_eq!(bus.read_io_port32(Bus::MDEC_CTRL).unwrap() & 0xFFFF, 31);
+
+        // Reset through the control register
+        bus.write_io_port32(Bus::MDEC_CTRL, 0x8000_0000).unwrap();
+        assert!(!mdec.borrow().awaiting_input());
+        assert_eq!(bus.read_io_port32(Bus::MDEC_DATA).unwrap(), 0);
+    }
+
     #[test]
     fn test_uninitialized_controller_read32() {
         let bus = Bus::new();
@@ -0,0 +1,295 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Memory Control registers
+//!
+//! The BIOS programs these registers very early in boot to configure the
+//! expansion port windows and the access timings of each bus device.
+//! The emulator does not model bus timings, so the registers are plain
+//! storage that reads back what was written.
+//!
+//! # Register Map (0x1F801000-0x1F801023)
+//!
+//! ```text
+//! 0x1F801000: Expansion 1 Base Address (normally 0x1F000000)
+//! 0x1F801004: Expansion 2 Base Address (normally 0x1F802000)
+//! 0x1F801008: Expansion 1 Delay/Size
+//! 0x1F80100C: Expansion 3 Delay/Size
+//! 0x1F801010: BIOS ROM Delay/Size
+//! 0x1F801014: SPU Delay/Size
+//! 0x1F801018: CD-ROM Delay/Size
+//! 0x1F80101C: Expansion 2 Delay/Size
+//! 0x1F801020: COM_DELAY (common delay)
+//! 0x1F801060: RAM_SIZE (Memory Control 2)
+//! ```
+//!
+//! # RAM Window
+//!
+//! Bits 9-11 of RAM_SIZE select how much of the first 8MB of each segment
+//! decodes to RAM. The retail BIOS writes 0x00000B88, which mirrors the
+//! 2MB of RAM four times across 0x00000000-0x007FFFFF. Windows that lock
+//! part of the installed 2MB are not modelled; they behave like the
+//! plain 2MB mapping.
+
+use super::Bus;
+
+impl Bus {
+    /// Number of 32-bit Memory Control registers
+    pub(super) const MEM_CONTROL_COUNT: usize = 9;
+
+    /// Memory Control register block start (Expansion 1 Base Address)
+    pub(super) const MEM_CONTROL_START: u32 = 0x1F801000;
+
+    /// Memory Control register block end (last byte of COM_DELAY)
+    pub(super) const MEM_CONTROL_END: u32 = 0x1F801023;
+
+    /// Expected Expansion 1 base address
+    const EXP1_BASE: u32 = 0x1F000000;
+
+    /// Expected Expansion 2 base address
+    const EXP2_BASE: u32 = 0x1F802000;
+
+    /// Power-on values of the Memory Control registers
+    ///
+    /// These are the values the retail BIOS programs during boot.
+    pub(super) const MEM_CONTROL_DEFAULTS: [u32; Self::MEM_CONTROL_COUNT] = [
+        Self::EXP1_BASE,
+        Self::EXP2_BASE,
+        0x0013_243F,
+        0x0000_3022,
+        0x0013_243F,
+        0x2009_31E1,
+        0x0002_0843,
+        0x0007_0777,
+        0x0003_1125,
+    ];
+
+    /// RAM_SIZE register address
+    pub(super) const RAM_SIZE_REG: u32 = 0x1F801060;
+
+    /// End of the 8MB RAM window used when RAM is mirrored
+    const RAM_MIRROR_END: u32 = 0x007FFFFF;
+
+    /// End of the 4MB RAM window (upper 4MB of the 8MB window is HighZ)
+    const RAM_HALF_MIRROR_END: u32 = 0x003FFFFF;
+
+    /// Read a Memory Control register
+    ///
+    /// # Arguments
+    ///
+    /// * `paddr` - Physical address (0x1F801000-0x1F801023)
+    ///
+    /// # Returns
+    ///
+    /// The last value written to the register
+    pub(super) fn read_mem_control(&self, paddr: u32) -> u32 {
+        let index = ((paddr - Self::MEM_CONTROL_START) >> 2) as usize;
+        let value = self.mem_control[index];
+        log::trace!("MEM_CTRL read at 0x{:08X} -> 0x{:08X}", paddr, value);
+        value
+    }
+
+    /// Write a Memory Control register
+    ///
+    /// The base address registers have their upper 8 bits fixed to 0x1F.
+    /// Base addresses other than the ones the BIOS normally programs are
+    /// stored but logged, since the expansion windows are not relocated.
+    ///
+    /// # Arguments
+    ///
+    /// * `paddr` - Physical address (0x1F801000-0x1F801023)
+    /// * `value` - Value to write
+    pub(super) fn write_mem_control(&mut self, paddr: u32, value: u32) {
+        let index = ((paddr - Self::MEM_CONTROL_START) >> 2) as usize;
+
+        let value = match index {
+            0 | 1 => {
+                let base = 0x1F00_0000 | (value & 0x00FF_FFFF);
+                let expected = if index == 0 {
+                    Self::EXP1_BASE
+                } else {
+                    Self::EXP2_BASE
+                };
+                if base != expected {
+                    log::warn!(
+                        "Expansion {} base set to 0x{:08X} (expected 0x{:08X}), not relocated",
+                        index + 1,
+                        base,
+                        expected
+                    );
+                }
+                base
+            }
+            _ => value,
+        };
+
+        log::trace!("MEM_CTRL write at 0x{:08X} = 0x{:08X}", paddr, value);
+        self.mem_control[index] = value;
+    }
+
+    /// Last physical address that decodes to RAM
+    ///
+    /// # Returns
+    ///
+    /// 0x007FFFFF when RAM_SIZE selects an 8MB window, 0x003FFFFF for
+    /// 4MB of RAM followed by 4MB of HighZ (value 3), otherwise the end
+    /// of the physical 2MB
+    pub(super) fn ram_window_end(&self) -> u32 {
+        match (self.ram_size >> 9) & 0x7 {
+            5 | 7 => Self::RAM_MIRROR_END,
+            3 => Self::RAM_HALF_MIRROR_END,
+            _ => Self::RAM_END,
+        }
+    }
+
+    /// Translate a physical RAM address into an offset into the 2MB RAM
+    ///
+    /// # Arguments
+    ///
+    /// * `paddr` - Physical address inside the RAM window
+    ///
+    /// # Returns
+    ///
+    /// Offset into RAM, with mirrors folded onto the physical 2MB
+    #[inline(always)]
+    pub(super) fn ram_offset(paddr: u32) -> usize {
+        (paddr as usize) & (Self::RAM_SIZE - 1)
+    }
+
+    /// Read the RAM_SIZE register
+    ///
+    /// # Returns
+    ///
+    /// The last value written to 0x1F801060
+    pub(super) fn read_ram_size(&self) -> u32 {
+        log::trace!("RAM_SIZE read -> 0x{:08X}", self.ram_size);
+        self.ram_size
+    }
+
+    /// Write the RAM_SIZE register and reconfigure RAM mirroring
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to write
+    pub(super) fn write_ram_size(&mut self, value: u32) {
+        log::debug!("RAM_SIZE write = 0x{:08X}", value);
+        self.ram_size = value;
+        self.page_table.map_ram(self.ram_window_end());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::memory::MemoryRegion;
+
+    #[test]
+    fn test_mem_control_defaults() {
+        let bus = Bus::new();
+
+        assert_eq!(bus.read32(0x1F801000).unwrap(), 0x1F000000);
+        assert_eq!(bus.read32(0x1F801004).unwrap(), 0x1F802000);
+        assert_eq!(bus.read32(0x1F801020).unwrap(), 0x00031125);
+    }
+
+    #[test]
+    fn test_mem_control_round_trip() {
+        let mut bus = Bus::new();
+
+        for i in 2..Bus::MEM_CONTROL_COUNT as u32 {
+            let addr = Bus::MEM_CONTROL_START + i * 4;
+            let value = 0x1234_5600 | i;
+            bus.write32(addr, value).unwrap();
+            assert_eq!(bus.read32(addr).unwrap(), value, "register 0x{:08X}", addr);
+        }
+    }
+
+    #[test]
+    fn test_mem_control_expansion_bases() {
+        let mut bus = Bus::new();
+
+        // Values the BIOS writes are accepted unchanged
+        bus.write32(0xBF801000, 0x1F000000).unwrap();
+        bus.write32(0xBF801004, 0x1F802000).unwrap();
+        assert_eq!(bus.read32(0xBF801000).unwrap(), 0x1F000000);
+        assert_eq!(bus.read32(0xBF801004).unwrap(), 0x1F802000);
+
+        // The upper 8 bits are fixed to 0x1F
+        bus.write32(0x1F801000, 0x00123400).unwrap();
+        assert_eq!(bus.read32(0x1F801000).unwrap(), 0x1F123400);
+    }
+
+    #[test]
+    fn test_mem_control_reset_restores_defaults() {
+        let mut bus = Bus::new();
+        bus.write32(0x1F801014, 0xDEADBEEF).unwrap();
+
+        bus.reset();
+
+        assert_eq!(bus.read32(0x1F801014).unwrap(), 0x200931E1);
+    }
+
+    #[test]
+    fn test_ram_size_round_trip() {
+        let mut bus = Bus::new();
+
+        bus.write32(0x1F801060, 0x00000B88).unwrap();
+
+        assert_eq!(bus.read32(0x1F801060).unwrap(), 0x00000B88);
+    }
+
+    #[test]
+    fn test_ram_size_mirrors_ram_across_8mb_window() {
+        let mut bus = Bus::new();
+        bus.write32(0x1F801060, 0x00000B88).unwrap();
+
+        bus.write32(0x00000000, 0xCAFEBABE).unwrap();
+
+        assert_eq!(bus.identify_region(0x00200000), MemoryRegion::RAM);
+        assert_eq!(bus.read32(0x00200000).unwrap(), 0xCAFEBABE);
+        assert_eq!(bus.read32(0x80600000).unwrap(), 0xCAFEBABE);
+
+        // Writes through a mirror land in the same physical RAM
+        bus.write16(0xA07FFFFE, 0x1234).unwrap();
+        assert_eq!(bus.read16(0x001FFFFE).unwrap(), 0x1234);
+    }
+
+    #[test]
+    fn test_ram_size_4mb_window_leaves_upper_half_unmapped() {
+        let mut bus = Bus::new();
+        bus.write32(0x1F801060, 0x00000688).unwrap(); // Bits 9-11 = 3
+
+        bus.write32(0x00000000, 0xCAFEBABE).unwrap();
+
+        // 2MB of RAM mirrored once inside the 4MB window
+        assert_eq!(bus.identify_region(0x003FFFFC), MemoryRegion::RAM);
+        assert_eq!(bus.read32(0x80200000).unwrap(), 0xCAFEBABE);
+
+        // The upper 4MB is HighZ, not RAM
+        assert_eq!(bus.identify_region(0x00400000), MemoryRegion::Expansion);
+        assert_eq!(bus.identify_region(0x007FFFFC), MemoryRegion::Expansion);
+    }
+
+    #[test]
+    fn test_ram_size_default_has_no_mirror() {
+        let mut bus = Bus::new();
+        bus.write32(0x1F801060, 0x00000B88).unwrap();
+
+        bus.reset();
+
+        assert_eq!(bus.identify_region(0x00200000), MemoryRegion::Expansion);
+        assert_eq!(bus.identify_region(0x001FFFFC), MemoryRegion::RAM);
+    }
+}
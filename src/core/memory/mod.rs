@@ -55,6 +55,8 @@ use crate::core::dma::DMA;
 use crate::core::error::{EmulatorError, Result};
 use crate::core::gpu::GPU;
 use crate::core::interrupt::InterruptController;
+use crate::core::mdec::MDEC;
+use crate::core::save_state::{MemoryState, StateSave};
 use crate::core::spu::SPU;
 use crate::core::system::ControllerPorts;
 use crate::core::timer::Timers;
@@ -64,14 +66,24 @@ use std::io::Read;
 use std::rc::Rc;
 
 // Sub-modules
+mod bios;
 mod cache;
 mod io_device;
+mod io_log;
 mod io_ports;
+mod mem_control;
+mod page_table;
 mod region;
+mod watchpoint;
 
 // Re-export public types
 pub use io_device::IODevice;
+use io_log::IoAccessLog;
+pub use io_log::{io_register_name, IoAccess};
+use page_table::{Page, PageTable};
 pub use region::MemoryRegion;
+use watchpoint::Watchpoint;
+pub use watchpoint::{WatchAccess, WatchpointHit};
 
 /// Memory bus managing all memory accesses
 ///
@@ -129,6 +141,30 @@ pub struct Bus {
     /// Controls instruction cache, data cache, and scratchpad enable
     cache_control: u32,
 
+    /// Memory Control registers
+    ///
+    /// Physical address: 0x1F801000-0x1F801023
+    /// Expansion base addresses and per-device delay/size settings
+    mem_control: [u32; Self::MEM_CONTROL_COUNT],
+
+    /// RAM_SIZE register (Memory Control 2)
+    ///
+    /// Physical address: 0x1F801060
+    /// Bits 9-11 select the RAM window mapped at the start of each segment
+    ram_size: u32,
+
+    /// 64KB page table for direct RAM/BIOS access
+    page_table: PageTable,
+
+    /// Registered debug watchpoints
+    watchpoints: Vec<Watchpoint>,
+
+    /// Pending watchpoint hits, recorded from `&self` reads
+    watchpoint_hits: RefCell<Vec<WatchpointHit>>,
+
+    /// I/O register access log, recorded from `&self` reads (None = disabled)
+    io_log: Option<RefCell<IoAccessLog>>,
+
     /// GPU reference (shared via Rc<RefCell>)
     ///
     /// The GPU is shared between the System and Bus to allow memory-mapped
@@ -170,6 +206,12 @@ pub struct Bus {
     /// The SPU is shared between the System and Bus to allow
     /// memory-mapped register access while maintaining Rust's safety guarantees.
     spu: Option<Rc<RefCell<SPU>>>,
+
+    /// MDEC reference (shared via Rc<RefCell>)
+    ///
+    /// The MDEC is shared between the System and Bus to allow
+    /// memory-mapped register access while maintaining Rust's safety guarantees.
+    mdec: Option<Rc<RefCell<MDEC>>>,
 }
 
 impl Bus {
@@ -209,6 +251,9 @@ impl Bus {
     /// Cache Control register address
     const CACHE_CONTROL: u32 = 0x1FFE0130;
 
+    /// Cache control bit 2: cache tag test mode
+    const CACHE_CONTROL_TAG_TEST: u32 = 1 << 2;
+
     /// Expansion Region 1 physical address range (lower part)
     /// This is the main expansion area, typically unused on retail PSX
     const EXP1_LOW_START: u32 = 0x00200000;
@@ -228,6 +273,12 @@ impl Bus {
     /// GPU GP1/GPUSTAT register (control and status)
     const GPU_GP1: u32 = 0x1F801814;
 
+    /// MDEC command/parameter and data/response register
+    const MDEC_DATA: u32 = 0x1F801820;
+
+    /// MDEC control/reset and status register
+    const MDEC_CTRL: u32 = 0x1F801824;
+
     /// Controller JOY_TX_DATA / JOY_RX_DATA register
     const JOY_DATA: u32 = 0x1F801040;
 
@@ -308,6 +359,12 @@ impl Bus {
             scratchpad: [0u8; 1024],
             bios: vec![0u8; Self::BIOS_SIZE],
             cache_control: 0,
+            mem_control: Self::MEM_CONTROL_DEFAULTS,
+            ram_size: 0,
+            page_table: PageTable::new(Self::RAM_END),
+            watchpoints: Vec::new(),
+            watchpoint_hits: RefCell::new(Vec::new()),
+            io_log: None,
             gpu: None,
             controller_ports: None,
             timers: None,
@@ -315,6 +372,7 @@ impl Bus {
             cdrom: None,
             dma: None,
             spu: None,
+            mdec: None,
         }
     }
 
@@ -483,6 +541,18 @@ impl Bus {
         self.spu = Some(spu);
     }
 
+    /// Set MDEC reference for memory-mapped I/O
+    ///
+    /// Establishes the connection between the Bus and MDEC for handling
+    /// MDEC register accesses at memory-mapped addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `mdec` - Shared reference to the MDEC instance
+    pub fn set_mdec(&mut self, mdec: Rc<RefCell<MDEC>>) {
+        self.mdec = Some(mdec);
+    }
+
     /// Reset the bus to initial state
     ///
     /// Clears RAM and scratchpad to zero, simulating a power-cycle.
@@ -520,13 +590,17 @@ impl Bus {
         self.scratchpad.fill(0);
         // Reset cache control to default
         self.cache_control = 0;
+        // Reset memory control to power-on values
+        self.mem_control = Self::MEM_CONTROL_DEFAULTS;
+        self.ram_size = 0;
+        self.page_table.map_ram(self.ram_window_end());
         // BIOS is read-only ROM, so it is not cleared
     }
 
     /// Load BIOS from file
     ///
-    /// Loads a BIOS ROM file into the BIOS region. The file must be
-    /// exactly 512KB in size.
+    /// Loads a BIOS ROM file into the BIOS region. A 512KB image is loaded
+    /// as-is; a trimmed dump is zero-padded to 512KB with a warning.
     ///
     /// # Arguments
     ///
@@ -539,10 +613,11 @@ impl Bus {
     ///
     /// # Errors
     ///
-    /// Returns `EmulatorError::BiosError` if:
-    /// - File cannot be opened
-    /// - File size is not 512KB
-    /// - File cannot be read
+    /// - `EmulatorError::BiosNotFound` if the file cannot be opened
+    /// - `EmulatorError::InvalidBiosSize` if the file is larger than 512KB,
+    ///   smaller than 64KB or not a whole number of words
+    /// - `EmulatorError::InvalidBios` if the dump is byte-swapped
+    /// - `EmulatorError::Io` if the file cannot be read
     ///
     /// # Example
     ///
@@ -556,16 +631,10 @@ impl Bus {
         let mut file =
             File::open(path).map_err(|_| EmulatorError::BiosNotFound(path.to_string()))?;
 
-        let metadata = file.metadata()?;
-
-        if metadata.len() != Self::BIOS_SIZE as u64 {
-            return Err(EmulatorError::InvalidBiosSize {
-                expected: Self::BIOS_SIZE,
-                got: metadata.len() as usize,
-            });
-        }
+        let mut data = Vec::with_capacity(Self::BIOS_SIZE);
+        file.read_to_end(&mut data)?;
 
-        file.read_exact(&mut self.bios)?;
+        self.bios = bios::prepare_image(data)?;
 
         Ok(())
     }
@@ -594,11 +663,29 @@ impl Bus {
     /// assert_eq!(bus.read8(0x80000000).unwrap(), 0x42);
     /// ```
     pub fn read8(&self, vaddr: u32) -> Result<u8> {
+        let value = self.read8_direct(vaddr)?;
+        if !self.watchpoints.is_empty() {
+            self.check_watchpoints(vaddr, 1, value as u32, WatchAccess::Read);
+        }
+        if self.io_log.is_some() {
+            self.log_io_access(vaddr, 1, value as u32, WatchAccess::Read);
+        }
+        Ok(value)
+    }
+
+    /// Read 8-bit value without checking watchpoints
+    fn read8_direct(&self, vaddr: u32) -> Result<u8> {
         let paddr = self.translate_address(vaddr);
 
+        match self.page_table.lookup(paddr) {
+            Page::Ram(offset) => return Ok(self.ram[offset]),
+            Page::Bios(offset) => return Ok(self.bios[offset]),
+            Page::Slow => {}
+        }
+
         match self.identify_region(vaddr) {
             MemoryRegion::RAM => {
-                let offset = paddr as usize;
+                let offset = Self::ram_offset(paddr);
                 Ok(self.ram[offset])
             }
             MemoryRegion::Scratchpad => {
@@ -661,6 +748,18 @@ impl Bus {
     /// assert!(bus.read16(0x80000001).is_err());
     /// ```
     pub fn read16(&self, vaddr: u32) -> Result<u16> {
+        let value = self.read16_direct(vaddr)?;
+        if !self.watchpoints.is_empty() {
+            self.check_watchpoints(vaddr, 2, value as u32, WatchAccess::Read);
+        }
+        if self.io_log.is_some() {
+            self.log_io_access(vaddr, 2, value as u32, WatchAccess::Read);
+        }
+        Ok(value)
+    }
+
+    /// Read 16-bit value without checking watchpoints
+    fn read16_direct(&self, vaddr: u32) -> Result<u16> {
         // Check alignment
         if vaddr & 0x1 != 0 {
             return Err(EmulatorError::UnalignedAccess {
@@ -671,9 +770,22 @@ impl Bus {
 
         let paddr = self.translate_address(vaddr);
 
+        match self.page_table.lookup(paddr) {
+            Page::Ram(offset) => {
+                return Ok(u16::from_le_bytes([self.ram[offset], self.ram[offset + 1]]))
+            }
+            Page::Bios(offset) => {
+                return Ok(u16::from_le_bytes([
+                    self.bios[offset],
+                    self.bios[offset + 1],
+                ]))
+            }
+            Page::Slow => {}
+        }
+
         match self.identify_region(vaddr) {
             MemoryRegion::RAM => {
-                let offset = paddr as usize;
+                let offset = Self::ram_offset(paddr);
                 let bytes = [self.ram[offset], self.ram[offset + 1]];
                 Ok(u16::from_le_bytes(bytes))
             }
@@ -736,6 +848,45 @@ impl Bus {
     /// assert!(bus.read32(0x80000001).is_err());
     /// ```
     pub fn read32(&self, vaddr: u32) -> Result<u32> {
+        let value = self.read32_direct(vaddr)?;
+        if !self.watchpoints.is_empty() {
+            self.check_watchpoints(vaddr, 4, value, WatchAccess::Read);
+        }
+        if self.io_log.is_some() {
+            self.log_io_access(vaddr, 4, value, WatchAccess::Read);
+        }
+        Ok(value)
+    }
+
+    /// Read a 32-bit word from RAM or BIOS without any side effects
+    ///
+    /// Unlike [`Bus::read32`], this never triggers watchpoints, the I/O log
+    /// or device register reads, so the CPU can inspect code ahead of
+    /// execution.
+    ///
+    /// # Arguments
+    ///
+    /// * `vaddr` - Virtual address to peek (must be 4-byte aligned)
+    ///
+    /// # Returns
+    ///
+    /// The word, or `None` if the address is unaligned or not backed by
+    /// RAM or BIOS
+    pub(crate) fn peek32(&self, vaddr: u32) -> Option<u32> {
+        if vaddr & 0x3 != 0 {
+            return None;
+        }
+
+        let bytes = match self.page_table.lookup(self.translate_address(vaddr)) {
+            Page::Ram(offset) => &self.ram[offset..offset + 4],
+            Page::Bios(offset) => &self.bios[offset..offset + 4],
+            Page::Slow => return None,
+        };
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Read 32-bit value without checking watchpoints
+    fn read32_direct(&self, vaddr: u32) -> Result<u32> {
         // Check alignment
         if vaddr & 0x3 != 0 {
             return Err(EmulatorError::UnalignedAccess {
@@ -746,9 +897,29 @@ impl Bus {
 
         let paddr = self.translate_address(vaddr);
 
+        match self.page_table.lookup(paddr) {
+            Page::Ram(offset) => {
+                return Ok(u32::from_le_bytes([
+                    self.ram[offset],
+                    self.ram[offset + 1],
+                    self.ram[offset + 2],
+                    self.ram[offset + 3],
+                ]))
+            }
+            Page::Bios(offset) => {
+                return Ok(u32::from_le_bytes([
+                    self.bios[offset],
+                    self.bios[offset + 1],
+                    self.bios[offset + 2],
+                    self.bios[offset + 3],
+                ]))
+            }
+            Page::Slow => {}
+        }
+
         match self.identify_region(vaddr) {
             MemoryRegion::RAM => {
-                let offset = paddr as usize;
+                let offset = Self::ram_offset(paddr);
                 let bytes = [
                     self.ram[offset],
                     self.ram[offset + 1],
@@ -791,23 +962,8 @@ impl Bus {
                 Ok(self.cache_control)
             }
             MemoryRegion::Expansion => {
-                // Expansion regions: check for special addresses
                 let paddr = self.translate_address(vaddr);
-
-                // Expansion ROM entry points should return 0 (no ROM)
-                // BIOS checks these addresses and tries to call them as function pointers
-                // Returning 0 prevents invalid jumps to 0xFFFFFFFF
-                if (0x1F000000..=0x1F0000FF).contains(&paddr) {
-                    log::trace!(
-                        "Expansion ROM header read32 at 0x{:08X} -> 0x00000000 (no ROM)",
-                        vaddr
-                    );
-                    Ok(0x00000000)
-                } else {
-                    // Other expansion region addresses return 0xFFFFFFFF
-                    log::trace!("Expansion region read32 at 0x{:08X} -> 0xFFFFFFFF", vaddr);
-                    Ok(0xFFFFFFFF)
-                }
+                Ok(Self::read_expansion32(paddr))
             }
             MemoryRegion::Unmapped => Err(EmulatorError::InvalidMemoryAccess { address: vaddr }),
         }
@@ -838,11 +994,28 @@ impl Bus {
     /// assert_eq!(bus.read8(0x80000000).unwrap(), 0x42);
     /// ```
     pub fn write8(&mut self, vaddr: u32, value: u8) -> Result<()> {
+        self.write8_direct(vaddr, value)?;
+        if !self.watchpoints.is_empty() {
+            self.check_watchpoints(vaddr, 1, value as u32, WatchAccess::Write);
+        }
+        if self.io_log.is_some() {
+            self.log_io_access(vaddr, 1, value as u32, WatchAccess::Write);
+        }
+        Ok(())
+    }
+
+    /// Write 8-bit value without checking watchpoints
+    fn write8_direct(&mut self, vaddr: u32, value: u8) -> Result<()> {
         let paddr = self.translate_address(vaddr);
 
+        if let Page::Ram(offset) = self.page_table.lookup(paddr) {
+            self.ram[offset] = value;
+            return Ok(());
+        }
+
         match self.identify_region(vaddr) {
             MemoryRegion::RAM => {
-                let offset = paddr as usize;
+                let offset = Self::ram_offset(paddr);
                 self.ram[offset] = value;
                 Ok(())
             }
@@ -911,6 +1084,18 @@ impl Bus {
     /// assert!(bus.write16(0x80000001, 0x1234).is_err());
     /// ```
     pub fn write16(&mut self, vaddr: u32, value: u16) -> Result<()> {
+        self.write16_direct(vaddr, value)?;
+        if !self.watchpoints.is_empty() {
+            self.check_watchpoints(vaddr, 2, value as u32, WatchAccess::Write);
+        }
+        if self.io_log.is_some() {
+            self.log_io_access(vaddr, 2, value as u32, WatchAccess::Write);
+        }
+        Ok(())
+    }
+
+    /// Write 16-bit value without checking watchpoints
+    fn write16_direct(&mut self, vaddr: u32, value: u16) -> Result<()> {
         // Check alignment
         if vaddr & 0x1 != 0 {
             return Err(EmulatorError::UnalignedAccess {
@@ -920,11 +1105,16 @@ impl Bus {
         }
 
         let paddr = self.translate_address(vaddr);
+
+        if let Page::Ram(offset) = self.page_table.lookup(paddr) {
+            self.ram[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+            return Ok(());
+        }
         let bytes = value.to_le_bytes();
 
         match self.identify_region(vaddr) {
             MemoryRegion::RAM => {
-                let offset = paddr as usize;
+                let offset = Self::ram_offset(paddr);
                 self.ram[offset] = bytes[0];
                 self.ram[offset + 1] = bytes[1];
                 Ok(())
@@ -992,6 +1182,18 @@ impl Bus {
     /// assert!(bus.write32(0x80000001, 0x12345678).is_err());
     /// ```
     pub fn write32(&mut self, vaddr: u32, value: u32) -> Result<()> {
+        self.write32_direct(vaddr, value)?;
+        if !self.watchpoints.is_empty() {
+            self.check_watchpoints(vaddr, 4, value, WatchAccess::Write);
+        }
+        if self.io_log.is_some() {
+            self.log_io_access(vaddr, 4, value, WatchAccess::Write);
+        }
+        Ok(())
+    }
+
+    /// Write 32-bit value without checking watchpoints
+    fn write32_direct(&mut self, vaddr: u32, value: u32) -> Result<()> {
         // Check alignment
         if vaddr & 0x3 != 0 {
             return Err(EmulatorError::UnalignedAccess {
@@ -1001,21 +1203,28 @@ impl Bus {
         }
 
         let paddr = self.translate_address(vaddr);
+
+        if let Page::Ram(offset) = self.page_table.lookup(paddr) {
+            self.ram[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+            self.queue_icache_invalidation(offset as u32);
+            self.queue_icache_prefill(offset as u32, value);
+            return Ok(());
+        }
         let bytes = value.to_le_bytes();
 
         match self.identify_region(vaddr) {
             MemoryRegion::RAM => {
-                let offset = paddr as usize;
+                let offset = Self::ram_offset(paddr);
                 self.ram[offset] = bytes[0];
                 self.ram[offset + 1] = bytes[1];
                 self.ram[offset + 2] = bytes[2];
                 self.ram[offset + 3] = bytes[3];
 
                 // Queue for icache invalidation (all RAM writes)
-                self.queue_icache_invalidation(paddr);
+                self.queue_icache_invalidation(offset as u32);
 
                 // Prefill icache for BIOS code copy region
-                self.queue_icache_prefill(paddr, value);
+                self.queue_icache_prefill(offset as u32, value);
 
                 Ok(())
             }
@@ -1047,18 +1256,59 @@ impl Bus {
                 Ok(())
             }
             MemoryRegion::Expansion => {
-                // Expansion regions: ignore writes (no hardware present)
-                log::trace!(
-                    "Expansion region write32 at 0x{:08X} = 0x{:08X} (ignored)",
-                    vaddr,
-                    value
-                );
+                let paddr = self.translate_address(vaddr);
+                Self::write_expansion32(paddr, value);
                 Ok(())
             }
             MemoryRegion::Unmapped => Err(EmulatorError::InvalidMemoryAccess { address: vaddr }),
         }
     }
 
+    /// Read a 32-bit word from the expansion regions
+    ///
+    /// No expansion hardware is present, so the regions read as open bus,
+    /// except for the Expansion 1 ROM header.
+    ///
+    /// # Arguments
+    ///
+    /// * `paddr` - Physical address inside an expansion region
+    ///
+    /// # Returns
+    ///
+    /// 0 for the ROM header (0x1F000000-0x1F0000FF), 0xFFFFFFFF otherwise
+    pub fn read_expansion32(paddr: u32) -> u32 {
+        // Expansion ROM entry points should return 0 (no ROM)
+        // BIOS checks these addresses and tries to call them as function pointers
+        // Returning 0 prevents invalid jumps to 0xFFFFFFFF
+        if (0x1F000000..=0x1F0000FF).contains(&paddr) {
+            log::trace!(
+                "Expansion ROM header read32 at 0x{:08X} -> 0x00000000 (no ROM)",
+                paddr
+            );
+            0x00000000
+        } else {
+            // Other expansion region addresses return 0xFFFFFFFF
+            log::trace!("Expansion region read32 at 0x{:08X} -> 0xFFFFFFFF", paddr);
+            0xFFFFFFFF
+        }
+    }
+
+    /// Write a 32-bit word to the expansion regions
+    ///
+    /// Writes are ignored as no expansion hardware is present.
+    ///
+    /// # Arguments
+    ///
+    /// * `paddr` - Physical address inside an expansion region
+    /// * `value` - Value to write
+    pub fn write_expansion32(paddr: u32, value: u32) {
+        log::trace!(
+            "Expansion region write32 at 0x{:08X} = 0x{:08X} (ignored)",
+            paddr,
+            value
+        );
+    }
+
     /// Check if any interrupt is pending
     ///
     /// Returns true if the interrupt controller has any pending unmasked interrupts.
@@ -1161,6 +1411,41 @@ impl Bus {
     }
 }
 
+impl StateSave for Bus {
+    type State = MemoryState;
+
+    fn to_state(&self) -> MemoryState {
+        MemoryState {
+            ram: self.ram.clone(),
+            scratchpad: self.scratchpad.to_vec(),
+            cache_control: self.cache_control,
+            mem_control: self.mem_control.to_vec(),
+            ram_size: self.ram_size,
+        }
+    }
+
+    /// Restore RAM, scratchpad and memory control registers
+    ///
+    /// The BIOS is not part of the state and is left untouched. Buffers
+    /// with an unexpected size are copied as far as they fit.
+    fn restore_from_state(&mut self, state: &MemoryState) {
+        let len = self.ram.len().min(state.ram.len());
+        self.ram[..len].copy_from_slice(&state.ram[..len]);
+        let len = self.scratchpad.len().min(state.scratchpad.len());
+        self.scratchpad[..len].copy_from_slice(&state.scratchpad[..len]);
+
+        self.cache_control = state.cache_control;
+        for (reg, &value) in self.mem_control.iter_mut().zip(&state.mem_control) {
+            *reg = value;
+        }
+        self.write_ram_size(state.ram_size);
+
+        self.icache_prefill_queue.clear();
+        self.icache_invalidate_queue.clear();
+        self.icache_invalidate_range_queue.clear();
+    }
+}
+
 impl Default for Bus {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Page table fast path for RAM and BIOS accesses
+//!
+//! The physical address space (512MB) is split into 64KB pages. Pages that
+//! are entirely backed by RAM or BIOS ROM map directly to an offset into
+//! the backing buffer, so the common loads, stores and instruction fetches
+//! skip region identification. Every other page (scratchpad, I/O,
+//! expansion, cache control, unmapped) falls back to the region match.
+//!
+//! RAM pages follow the RAM_SIZE window, so the table is rebuilt whenever
+//! RAM mirroring changes.
+
+use super::Bus;
+
+/// Backing storage of a 64KB page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Page {
+    /// RAM page; carries the offset into RAM
+    Ram(usize),
+    /// BIOS ROM page; carries the offset into the BIOS image
+    Bios(usize),
+    /// Anything else; handled by the region match
+    Slow,
+}
+
+/// Physical page table with 64KB granularity
+pub(super) struct PageTable {
+    /// One entry per 64KB physical page
+    pages: Vec<Page>,
+}
+
+impl PageTable {
+    /// Page size shift (64KB pages)
+    const PAGE_SHIFT: u32 = 16;
+
+    /// Offset mask within a page
+    const PAGE_MASK: u32 = (1 << Self::PAGE_SHIFT) - 1;
+
+    /// Number of pages covering the 512MB physical address space
+    const PAGE_COUNT: usize = 0x2000_0000 >> Self::PAGE_SHIFT;
+
+    /// Build a page table with RAM mapped up to `ram_window_end`
+    ///
+    /// # Arguments
+    ///
+    /// * `ram_window_end` - Last physical address that decodes to RAM
+    pub(super) fn new(ram_window_end: u32) -> Self {
+        let mut table = Self {
+            pages: vec![Page::Slow; Self::PAGE_COUNT],
+        };
+
+        let bios_first = (Bus::BIOS_START >> Self::PAGE_SHIFT) as usize;
+        let bios_last = (Bus::BIOS_END >> Self::PAGE_SHIFT) as usize;
+        for page in bios_first..=bios_last {
+            table.pages[page] = Page::Bios((page - bios_first) << Self::PAGE_SHIFT);
+        }
+
+        table.map_ram(ram_window_end);
+        table
+    }
+
+    /// Remap the RAM window, folding mirrors onto the physical 2MB
+    ///
+    /// # Arguments
+    ///
+    /// * `ram_window_end` - Last physical address that decodes to RAM
+    pub(super) fn map_ram(&mut self, ram_window_end: u32) {
+        for (page, entry) in self.pages.iter_mut().enumerate() {
+            let paddr = (page as u32) << Self::PAGE_SHIFT;
+            if paddr <= ram_window_end {
+                *entry = Page::Ram(Bus::ram_offset(paddr));
+            } else if matches!(entry, Page::Ram(_)) {
+                *entry = Page::Slow;
+            }
+        }
+    }
+
+    /// Look up a physical address
+    ///
+    /// # Arguments
+    ///
+    /// * `paddr` - Physical address
+    ///
+    /// # Returns
+    ///
+    /// The backing buffer and the offset of `paddr` within it, or
+    /// `Page::Slow` if the address needs the region match
+    #[inline(always)]
+    pub(super) fn lookup(&self, paddr: u32) -> Page {
+        let offset = (paddr & Self::PAGE_MASK) as usize;
+        match self.pages[(paddr >> Self::PAGE_SHIFT) as usize] {
+            Page::Ram(base) => Page::Ram(base + offset),
+            Page::Bios(base) => Page::Bios(base + offset),
+            Page::Slow => Page::Slow,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_table_maps_ram_and_bios() {
+        let table = PageTable::new(Bus::RAM_END);
+
+        assert_eq!(table.lookup(0x00000000), Page::Ram(0));
+        assert_eq!(table.lookup(0x001FFFFC), Page::Ram(0x1FFFFC));
+        assert_eq!(table.lookup(0x1FC00100), Page::Bios(0x100));
+        assert_eq!(table.lookup(0x1FC7FFFF), Page::Bios(0x7FFFF));
+    }
+
+    #[test]
+    fn test_page_table_slow_regions() {
+        let table = PageTable::new(Bus::RAM_END);
+
+        assert_eq!(table.lookup(0x00200000), Page::Slow);
+        assert_eq!(table.lookup(0x1F800000), Page::Slow); // Scratchpad
+        assert_eq!(table.lookup(0x1F801810), Page::Slow); // I/O
+        assert_eq!(table.lookup(0x1FC80000), Page::Slow); // Past BIOS
+        assert_eq!(table.lookup(0x1FFE0130), Page::Slow); // Cache control
+    }
+
+    #[test]
+    fn test_page_table_ram_mirrors() {
+        let mut table = PageTable::new(Bus::RAM_END);
+
+        table.map_ram(0x007FFFFF);
+        assert_eq!(table.lookup(0x00200010), Page::Ram(0x10));
+        assert_eq!(table.lookup(0x007FFFFC), Page::Ram(0x1FFFFC));
+        assert_eq!(table.lookup(0x00800000), Page::Slow);
+
+        table.map_ram(Bus::RAM_END);
+        assert_eq!(table.lookup(0x00200010), Page::Slow);
+    }
+
+    #[test]
+    fn test_bus_fast_path_matches_slow_path() {
+        let mut bus = Bus::new();
+        bus.write_bios_for_test(0x10, &[0x11, 0x22, 0x33, 0x44]);
+
+        bus.write32(0x80001000, 0xA1B2C3D4).unwrap();
+        bus.write16(0xA0001004, 0x5566).unwrap();
+        bus.write8(0x00001006, 0x77).unwrap();
+
+        assert_eq!(bus.read32(0x00001000).unwrap(), 0xA1B2C3D4);
+        assert_eq!(bus.read16(0x80001004).unwrap(), 0x5566);
+        assert_eq!(bus.read8(0xA0001006).unwrap(), 0x77);
+        assert_eq!(bus.read32(0xBFC00010).unwrap(), 0x44332211);
+        assert_eq!(bus.read16(0x9FC00012).unwrap(), 0x4433);
+        assert_eq!(bus.read8(0x1FC00011).unwrap(), 0x22);
+
+        // BIOS stays read-only through the fast path
+        bus.write32(0xBFC00010, 0).unwrap();
+        assert_eq!(bus.read32(0xBFC00010).unwrap(), 0x44332211);
+    }
+}
@@ -91,7 +91,7 @@ impl Bus {
     pub fn identify_region(&self, vaddr: u32) -> MemoryRegion {
         let paddr = self.translate_address(vaddr);
 
-        if (Self::RAM_START..=Self::RAM_END).contains(&paddr) {
+        if (Self::RAM_START..=self.ram_window_end()).contains(&paddr) {
             MemoryRegion::RAM
         } else if (Self::EXP1_LOW_START..=Self::EXP1_LOW_END).contains(&paddr)
             || (Self::EXP2_START..=Self::EXP2_END).contains(&paddr)
@@ -110,12 +110,59 @@ impl Bus {
             MemoryRegion::Unmapped
         }
     }
+
+    /// Approximate CPU stall for a read from an address
+    ///
+    /// A coarse per-region hint based on the delays the retail BIOS
+    /// programs into the Memory Control registers. It is used by the CPU's
+    /// accurate clock mode; the programmed delay values themselves are not
+    /// decoded.
+    ///
+    /// # Arguments
+    ///
+    /// * `vaddr` - Virtual address
+    ///
+    /// # Returns
+    ///
+    /// Extra CPU cycles the access takes beyond the instruction's own cycle
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::memory::Bus;
+    ///
+    /// let bus = Bus::new();
+    ///
+    /// assert_eq!(bus.access_latency(0x1F800000), 0); // Scratchpad
+    /// assert!(bus.access_latency(0xBFC00000) > bus.access_latency(0x80000000));
+    /// ```
+    pub fn access_latency(&self, vaddr: u32) -> u32 {
+        match self.identify_region(vaddr) {
+            MemoryRegion::RAM => 4,
+            MemoryRegion::BIOS => 20,
+            MemoryRegion::IO => 2,
+            MemoryRegion::Expansion => 10,
+            MemoryRegion::Scratchpad | MemoryRegion::CacheControl | MemoryRegion::Unmapped => 0,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_access_latency_per_region() {
+        let bus = Bus::new();
+
+        assert_eq!(bus.access_latency(0x80000000), 4);
+        assert_eq!(bus.access_latency(0xA0000000), 4);
+        assert_eq!(bus.access_latency(0x1F800000), 0);
+        assert_eq!(bus.access_latency(0x1F801810), 2);
+        assert_eq!(bus.access_latency(0xBFC00000), 20);
+        assert_eq!(bus.access_latency(0xFFFE0130), 0);
+    }
+
     #[test]
     fn test_translate_address_kuseg() {
         let bus = Bus::new();
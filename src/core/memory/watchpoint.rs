@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Memory watchpoints for debugging
+//!
+//! Watchpoints are registered on physical addresses, so a watchpoint on
+//! 0x80010000 also catches accesses through 0x00010000 and 0xA0010000.
+//! Every matching access is recorded in a pending-hit queue that the
+//! caller polls with [`Bus::drain_watchpoint_hits`].
+//!
+//! When no watchpoints are registered, the only cost on the access path
+//! is a single emptiness check.
+//!
+//! # Example
+//!
+//! ```
+//! use psrx::core::memory::{Bus, WatchAccess};
+//!
+//! let mut bus = Bus::new();
+//! bus.add_watchpoint(0x80001000, false, true);
+//!
+//! bus.write32(0xA0001000, 0xDEADBEEF).unwrap();
+//!
+//! let hits = bus.drain_watchpoint_hits();
+//! assert_eq!(hits.len(), 1);
+//! assert_eq!(hits[0].kind, WatchAccess::Write);
+//! assert_eq!(hits[0].value, 0xDEADBEEF);
+//! ```
+
+use super::Bus;
+
+/// Kind of memory access that triggered a watchpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchAccess {
+    /// Load from memory
+    Read,
+    /// Store to memory
+    Write,
+}
+
+/// A single watchpoint hit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchpointHit {
+    /// Virtual address of the access
+    pub address: u32,
+
+    /// Access size in bytes (1, 2 or 4)
+    pub size: u8,
+
+    /// Value read or written (zero-extended)
+    pub value: u32,
+
+    /// Whether the access was a read or a write
+    pub kind: WatchAccess,
+}
+
+/// A registered watchpoint
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Watchpoint {
+    /// Watched physical address
+    paddr: u32,
+
+    /// Trigger on reads
+    on_read: bool,
+
+    /// Trigger on writes
+    on_write: bool,
+}
+
+impl Bus {
+    /// Maximum number of hits kept until the queue is drained
+    ///
+    /// Further hits are dropped so an undrained queue cannot grow without bound.
+    const MAX_WATCHPOINT_HITS: usize = 4096;
+
+    /// Register a watchpoint
+    ///
+    /// Registering the same address again replaces its read/write flags.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - Address to watch (any segment; matched physically)
+    /// * `on_read` - Trigger on reads touching the address
+    /// * `on_write` - Trigger on writes touching the address
+    pub fn add_watchpoint(&mut self, addr: u32, on_read: bool, on_write: bool) {
+        let paddr = self.translate_address(addr);
+        self.watchpoints.retain(|wp| wp.paddr != paddr);
+        self.watchpoints.push(Watchpoint {
+            paddr,
+            on_read,
+            on_write,
+        });
+    }
+
+    /// Remove the watchpoint on the given address
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - Previously watched address (any segment)
+    pub fn remove_watchpoint(&mut self, addr: u32) {
+        let paddr = self.translate_address(addr);
+        self.watchpoints.retain(|wp| wp.paddr != paddr);
+    }
+
+    /// Remove all watchpoints and discard pending hits
+    pub fn clear_watchpoints(&mut self) {
+        self.watchpoints.clear();
+        self.watchpoint_hits.borrow_mut().clear();
+    }
+
+    /// Drain the watchpoint hit queue
+    ///
+    /// Returns all hits recorded since the last drain, oldest first.
+    ///
+    /// # Returns
+    ///
+    /// Pending watchpoint hits
+    pub fn drain_watchpoint_hits(&mut self) -> Vec<WatchpointHit> {
+        std::mem::take(self.watchpoint_hits.get_mut())
+    }
+
+    /// Record a hit if the access touches a watched address
+    ///
+    /// Callers check `self.watchpoints.is_empty()` first to keep the
+    /// common path free of any work.
+    ///
+    /// # Arguments
+    ///
+    /// * `vaddr` - Virtual address of the access
+    /// * `size` - Access size in bytes
+    /// * `value` - Value read or written
+    /// * `kind` - Access kind
+    pub(super) fn check_watchpoints(&self, vaddr: u32, size: u8, value: u32, kind: WatchAccess) {
+        let start = self.translate_address(vaddr);
+        let end = start + size as u32;
+
+        let hit = self.watchpoints.iter().any(|wp| {
+            (start..end).contains(&wp.paddr)
+                && match kind {
+                    WatchAccess::Read => wp.on_read,
+                    WatchAccess::Write => wp.on_write,
+                }
+        });
+
+        if hit {
+            let mut hits = self.watchpoint_hits.borrow_mut();
+            if hits.len() < Self::MAX_WATCHPOINT_HITS {
+                hits.push(WatchpointHit {
+                    address: vaddr,
+                    size,
+                    value,
+                    kind,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watchpoint_no_hits_without_watchpoints() {
+        let mut bus = Bus::new();
+
+        bus.write32(0x80000000, 1).unwrap();
+        bus.read32(0x80000000).unwrap();
+
+        assert!(bus.drain_watchpoint_hits().is_empty());
+    }
+
+    #[test]
+    fn test_watchpoint_read_and_write() {
+        let mut bus = Bus::new();
+        bus.add_watchpoint(0x80000100, true, true);
+
+        bus.write16(0x80000100, 0xBEEF).unwrap();
+        bus.read8(0x00000100).unwrap();
+
+        let hits = bus.drain_watchpoint_hits();
+        assert_eq!(
+            hits,
+            vec![
+                WatchpointHit {
+                    address: 0x80000100,
+                    size: 2,
+                    value: 0xBEEF,
+                    kind: WatchAccess::Write,
+                },
+                WatchpointHit {
+                    address: 0x00000100,
+                    size: 1,
+                    value: 0xEF,
+                    kind: WatchAccess::Read,
+                },
+            ]
+        );
+        assert!(bus.drain_watchpoint_hits().is_empty());
+    }
+
+    #[test]
+    fn test_watchpoint_respects_access_kind() {
+        let mut bus = Bus::new();
+        bus.add_watchpoint(0x80000200, false, true);
+
+        bus.read32(0x80000200).unwrap();
+        assert!(bus.drain_watchpoint_hits().is_empty());
+
+        bus.write8(0x80000200, 0x12).unwrap();
+        assert_eq!(bus.drain_watchpoint_hits().len(), 1);
+    }
+
+    #[test]
+    fn test_watchpoint_matches_inside_wider_access() {
+        let mut bus = Bus::new();
+        bus.add_watchpoint(0x80000303, true, false);
+
+        // A word read covering 0x300-0x303 touches the watched byte
+        bus.read32(0x80000300).unwrap();
+        // A word read of the next word does not
+        bus.read32(0x80000304).unwrap();
+
+        let hits = bus.drain_watchpoint_hits();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].address, 0x80000300);
+        assert_eq!(hits[0].size, 4);
+    }
+
+    #[test]
+    fn test_watchpoint_remove_and_clear() {
+        let mut bus = Bus::new();
+        bus.add_watchpoint(0x80000400, true, true);
+        bus.add_watchpoint(0x80000500, true, true);
+
+        bus.remove_watchpoint(0xA0000400);
+        bus.read32(0x80000400).unwrap();
+        assert!(bus.drain_watchpoint_hits().is_empty());
+
+        bus.read32(0x80000500).unwrap();
+        bus.clear_watchpoints();
+        assert!(bus.drain_watchpoint_hits().is_empty());
+
+        bus.read32(0x80000500).unwrap();
+        assert!(bus.drain_watchpoint_hits().is_empty());
+    }
+}
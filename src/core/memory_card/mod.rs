@@ -0,0 +1,639 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! PlayStation Memory Card
+//!
+//! This module implements the 128KB memory card that shares the controller
+//! port serial bus. The console addresses the card by sending 0x81 as the
+//! first byte of a transfer (controllers answer to 0x01).
+//!
+//! # Card Layout
+//!
+//! - 1024 sectors (frames) of 128 bytes = 128KB
+//! - Block 0 (sectors 0-63) holds the header, directory and broken sector list
+//! - Blocks 1-15 hold save data
+//!
+//! # Commands
+//!
+//! | Command | Description |
+//! |---------|-------------|
+//! | 0x52 'R' | Read sector |
+//! | 0x53 'S' | Get card ID |
+//! | 0x57 'W' | Write sector |
+//!
+//! Every command reply starts with the FLAG byte followed by the card ID
+//! bytes 0x5A, 0x5D. FLAG bit 3 is set at power-on and cleared by the first
+//! successful write, which lets the BIOS detect card swaps.
+//!
+//! # Example
+//!
+//! ```
+//! use psrx::core::memory_card::MemoryCard;
+//!
+//! let mut card = MemoryCard::new();
+//! card.select();
+//! assert_eq!(card.transfer(0x81), 0xFF); // Address the card
+//! assert_eq!(card.transfer(0x53), 0x08); // Get ID: FLAG byte
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::error::{EmulatorError, Result};
+
+/// Memory card size in bytes (128KB)
+pub const CARD_SIZE: usize = 128 * 1024;
+
+/// Sector (frame) size in bytes
+pub const SECTOR_SIZE: usize = 128;
+
+/// Number of sectors on the card
+const SECTOR_COUNT: u16 = (CARD_SIZE / SECTOR_SIZE) as u16;
+
+/// FLAG bit 3: directory not read yet (set at power-on, cleared by a write)
+const FLAG_NEW_CARD: u8 = 0x08;
+
+/// FLAG bit 2: last write failed
+const FLAG_WRITE_ERROR: u8 = 0x04;
+
+/// Final status byte: command completed
+const STATUS_GOOD: u8 = 0x47;
+
+/// Final status byte: write checksum mismatch
+const STATUS_BAD_CHECKSUM: u8 = 0x4E;
+
+/// Final status byte: sector number out of range
+const STATUS_BAD_SECTOR: u8 = 0xFF;
+
+/// Command currently being processed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    /// Waiting for the command byte after 0x81
+    None,
+    /// Read sector (0x52)
+    Read,
+    /// Write sector (0x57)
+    Write,
+    /// Get card ID (0x53)
+    GetId,
+}
+
+/// PlayStation memory card
+///
+/// Emulates the card's side of the serial protocol. Each call to
+/// [`MemoryCard::transfer`] exchanges one byte; [`MemoryCard::ack`] reports
+/// whether the card pulled /ACK, i.e. expects another byte.
+///
+/// When opened from a file, written sectors are flushed back to it
+/// immediately.
+pub struct MemoryCard {
+    /// Card contents
+    data: Vec<u8>,
+
+    /// FLAG byte returned after the command byte
+    flag: u8,
+
+    /// Backing file, if any
+    path: Option<PathBuf>,
+
+    /// Card is addressed by the current transfer
+    selected: bool,
+
+    /// Command being processed
+    command: Command,
+
+    /// Byte index within the current transfer (0 = address byte)
+    step: usize,
+
+    /// Sector number being read or written
+    sector: u16,
+
+    /// Running XOR checksum of address and data bytes
+    checksum: u8,
+
+    /// Checksum sent with a write matched the received data
+    checksum_ok: bool,
+
+    /// Data received by a write command
+    write_buffer: Vec<u8>,
+
+    /// Previous byte received (echoed back during writes)
+    last_rx: u8,
+
+    /// /ACK asserted after the last transfer
+    ack: bool,
+}
+
+impl MemoryCard {
+    /// Create a freshly formatted card that is not backed by a file
+    ///
+    /// # Returns
+    ///
+    /// Formatted memory card
+    pub fn new() -> Self {
+        let mut card = Self {
+            data: vec![0; CARD_SIZE],
+            flag: FLAG_NEW_CARD,
+            path: None,
+            selected: false,
+            command: Command::None,
+            step: 0,
+            sector: 0,
+            checksum: 0,
+            checksum_ok: false,
+            write_buffer: Vec::with_capacity(SECTOR_SIZE),
+            last_rx: 0,
+            ack: false,
+        };
+        card.format();
+        card
+    }
+
+    /// Open a card image backed by a file
+    ///
+    /// A missing file is created with a freshly formatted card.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the 128KB card image
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(MemoryCard)` if the image was loaded or created
+    /// - `Err(EmulatorError)` if the file cannot be accessed or is not 128KB
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use psrx::core::memory_card::MemoryCard;
+    ///
+    /// let card = MemoryCard::open("card1.mcd").unwrap();
+    /// ```
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let mut card = Self::new();
+
+        if path.exists() {
+            let data = fs::read(path)?;
+            if data.len() != CARD_SIZE {
+                return Err(EmulatorError::MemoryCard(format!(
+                    "{}: expected {} bytes, got {}",
+                    path.display(),
+                    CARD_SIZE,
+                    data.len()
+                )));
+            }
+            card.data = data;
+        } else {
+            fs::write(path, &card.data)?;
+            log::info!("Memory card: created {}", path.display());
+        }
+
+        card.path = Some(path.to_path_buf());
+        Ok(card)
+    }
+
+    /// Erase the card and write an empty file system
+    ///
+    /// Writes the "MC" header, 15 free directory entries, an empty broken
+    /// sector list and the write-test frame, each with its checksum.
+    pub fn format(&mut self) {
+        self.data.fill(0);
+
+        // Header frame and write-test frame
+        for sector in [0, 63] {
+            let frame = self.frame_mut(sector);
+            frame[0] = b'M';
+            frame[1] = b'C';
+            Self::update_frame_checksum(frame);
+        }
+
+        // Directory frames: free block, no next block
+        for sector in 1..16 {
+            let frame = self.frame_mut(sector);
+            frame[0x00] = 0xA0;
+            frame[0x08] = 0xFF;
+            frame[0x09] = 0xFF;
+            Self::update_frame_checksum(frame);
+        }
+
+        // Broken sector list: no entries
+        for sector in 16..36 {
+            let frame = self.frame_mut(sector);
+            frame[0x00..0x04].fill(0xFF);
+            frame[0x08] = 0xFF;
+            frame[0x09] = 0xFF;
+            Self::update_frame_checksum(frame);
+        }
+    }
+
+    /// Get the raw card contents
+    ///
+    /// # Returns
+    ///
+    /// 128KB card image
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get the FLAG byte
+    ///
+    /// # Returns
+    ///
+    /// Current FLAG value (bit 3 = new card, bit 2 = write error)
+    pub fn flag(&self) -> u8 {
+        self.flag
+    }
+
+    /// Select the card (chip select asserted)
+    pub fn select(&mut self) {
+        self.selected = true;
+        self.command = Command::None;
+        self.step = 0;
+        self.ack = false;
+    }
+
+    /// Deselect the card (chip select released)
+    ///
+    /// Aborts any command in progress.
+    pub fn deselect(&mut self) {
+        self.selected = false;
+        self.command = Command::None;
+        self.step = 0;
+        self.ack = false;
+    }
+
+    /// Check whether the card acknowledged the last byte
+    ///
+    /// # Returns
+    ///
+    /// true if the card expects another byte
+    pub fn ack(&self) -> bool {
+        self.ack
+    }
+
+    /// Transfer a byte (simultaneous TX/RX)
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_byte` - Byte transmitted from console to card
+    ///
+    /// # Returns
+    ///
+    /// Byte transmitted from card to console
+    pub fn transfer(&mut self, tx_byte: u8) -> u8 {
+        if !self.selected {
+            self.ack = false;
+            return 0xFF;
+        }
+
+        let step = self.step;
+        self.step += 1;
+
+        let (rx_byte, ack) = match (self.command, step) {
+            (Command::None, 0) => (0xFF, tx_byte == 0x81),
+            (Command::None, _) => self.start_command(tx_byte),
+            (Command::Read, _) => self.read_step(step, tx_byte),
+            (Command::Write, _) => self.write_step(step, tx_byte),
+            (Command::GetId, _) => Self::get_id_step(step),
+        };
+
+        self.last_rx = tx_byte;
+        self.ack = ack;
+        if !ack {
+            self.command = Command::None;
+            self.selected = false;
+        }
+
+        log::trace!(
+            "Memory card transfer: TX=0x{:02X} RX=0x{:02X} (step {})",
+            tx_byte,
+            rx_byte,
+            step
+        );
+
+        rx_byte
+    }
+
+    /// Decode the command byte (step 1) and reply with FLAG
+    fn start_command(&mut self, tx_byte: u8) -> (u8, bool) {
+        self.command = match tx_byte {
+            0x52 => Command::Read,
+            0x57 => Command::Write,
+            0x53 => Command::GetId,
+            _ => {
+                log::warn!("Memory card: unknown command 0x{:02X}", tx_byte);
+                return (self.flag, false);
+            }
+        };
+        self.sector = 0;
+        self.checksum = 0;
+        self.write_buffer.clear();
+
+        (self.flag, true)
+    }
+
+    /// Read sector sequence
+    ///
+    /// ```text
+    /// step  2  3  4    5    6  7  8    9    10..137 138 139
+    /// TX    00 00 MSB  LSB  00 00 00   00   00      00  00
+    /// RX    5A 5D 00   MSB  5C 5D MSB  LSB  data    CHK 47
+    /// ```
+    fn read_step(&mut self, step: usize, tx_byte: u8) -> (u8, bool) {
+        match step {
+            2 => (0x5A, true),
+            3 => (0x5D, true),
+            4 => {
+                self.sector = (tx_byte as u16) << 8;
+                (0x00, true)
+            }
+            5 => {
+                self.sector |= tx_byte as u16;
+                (self.last_rx, true)
+            }
+            6 => (0x5C, true),
+            7 => (0x5D, true),
+            // An invalid sector answers 0xFFFF and ends the transfer there
+            8 if self.sector >= SECTOR_COUNT => (0xFF, true),
+            9 if self.sector >= SECTOR_COUNT => (0xFF, false),
+            8 => {
+                let msb = (self.sector >> 8) as u8;
+                self.checksum = msb;
+                (msb, true)
+            }
+            9 => {
+                let lsb = self.sector as u8;
+                self.checksum ^= lsb;
+                (lsb, true)
+            }
+            10..=137 => {
+                let byte = self.data[self.sector as usize * SECTOR_SIZE + step - 10];
+                self.checksum ^= byte;
+                (byte, true)
+            }
+            138 => (self.checksum, true),
+            _ => (STATUS_GOOD, false),
+        }
+    }
+
+    /// Write sector sequence
+    ///
+    /// ```text
+    /// step  2  3  4    5    6..133 134 135 136 137
+    /// TX    00 00 MSB  LSB  data   CHK 00  00  00
+    /// RX    5A 5D 00   MSB  (echo) ... 5C  5D  status
+    /// ```
+    fn write_step(&mut self, step: usize, tx_byte: u8) -> (u8, bool) {
+        match step {
+            2 => (0x5A, true),
+            3 => (0x5D, true),
+            4 => {
+                self.sector = (tx_byte as u16) << 8;
+                self.checksum = tx_byte;
+                (0x00, true)
+            }
+            5 => {
+                self.sector |= tx_byte as u16;
+                self.checksum ^= tx_byte;
+                (self.last_rx, true)
+            }
+            6..=133 => {
+                self.write_buffer.push(tx_byte);
+                self.checksum ^= tx_byte;
+                (self.last_rx, true)
+            }
+            134 => {
+                self.checksum_ok = tx_byte == self.checksum;
+                (self.last_rx, true)
+            }
+            135 => (0x5C, true),
+            136 => (0x5D, true),
+            _ => (self.finish_write(), false),
+        }
+    }
+
+    /// Commit the received sector and return the status byte
+    fn finish_write(&mut self) -> u8 {
+        if self.sector >= SECTOR_COUNT {
+            self.flag |= FLAG_WRITE_ERROR;
+            return STATUS_BAD_SECTOR;
+        }
+
+        if !self.checksum_ok {
+            log::warn!("Memory card: bad checksum writing sector {}", self.sector);
+            self.flag |= FLAG_WRITE_ERROR;
+            return STATUS_BAD_CHECKSUM;
+        }
+
+        let offset = self.sector as usize * SECTOR_SIZE;
+        self.data[offset..offset + SECTOR_SIZE].copy_from_slice(&self.write_buffer);
+        self.flag &= !(FLAG_NEW_CARD | FLAG_WRITE_ERROR);
+        self.flush();
+
+        STATUS_GOOD
+    }
+
+    /// Get ID sequence
+    ///
+    /// ```text
+    /// step  2  3  4  5  6  7  8  9
+    /// RX    5A 5D 5C 5D 04 00 00 80
+    /// ```
+    fn get_id_step(step: usize) -> (u8, bool) {
+        match step {
+            2 => (0x5A, true),
+            3 => (0x5D, true),
+            4 => (0x5C, true),
+            5 => (0x5D, true),
+            6 => (0x04, true),
+            7 => (0x00, true),
+            8 => (0x00, true),
+            _ => (0x80, false),
+        }
+    }
+
+    /// Write the card image back to its file
+    fn flush(&self) {
+        if let Some(path) = &self.path {
+            if let Err(e) = fs::write(path, &self.data) {
+                log::error!("Memory card: failed to write {}: {}", path.display(), e);
+            }
+        }
+    }
+
+    /// Get a mutable frame by sector number
+    fn frame_mut(&mut self, sector: usize) -> &mut [u8] {
+        &mut self.data[sector * SECTOR_SIZE..(sector + 1) * SECTOR_SIZE]
+    }
+
+    /// Store the XOR of bytes 0x00-0x7E in the last byte of a frame
+    fn update_frame_checksum(frame: &mut [u8]) {
+        frame[SECTOR_SIZE - 1] = frame[..SECTOR_SIZE - 1].iter().fold(0, |acc, &b| acc ^ b);
+    }
+}
+
+impl Default for MemoryCard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run a full transfer and collect the card's replies
+    fn exchange(card: &mut MemoryCard, tx: &[u8]) -> Vec<u8> {
+        card.select();
+        let rx = tx.iter().map(|&b| card.transfer(b)).collect();
+        card.deselect();
+        rx
+    }
+
+    /// Build a write-sector command with a valid checksum
+    fn write_command(sector: u16, data: &[u8; SECTOR_SIZE]) -> Vec<u8> {
+        let (msb, lsb) = ((sector >> 8) as u8, sector as u8);
+        let checksum = data.iter().fold(msb ^ lsb, |acc, &b| acc ^ b);
+
+        let mut tx = vec![0x81, 0x57, 0x00, 0x00, msb, lsb];
+        tx.extend_from_slice(data);
+        tx.extend_from_slice(&[checksum, 0x00, 0x00, 0x00]);
+        tx
+    }
+
+    /// Build a read-sector command
+    fn read_command(sector: u16) -> Vec<u8> {
+        let mut tx = vec![0x81, 0x52, 0x00, 0x00, (sector >> 8) as u8, sector as u8];
+        tx.resize(140, 0x00);
+        tx
+    }
+
+    #[test]
+    fn test_new_card_is_formatted() {
+        let card = MemoryCard::new();
+
+        assert_eq!(&card.data()[0..2], b"MC");
+        assert_eq!(card.data()[SECTOR_SIZE], 0xA0);
+        assert_eq!(card.flag(), FLAG_NEW_CARD);
+    }
+
+    #[test]
+    fn test_card_ignores_controller_address() {
+        let mut card = MemoryCard::new();
+        card.select();
+
+        assert_eq!(card.transfer(0x01), 0xFF);
+        assert!(!card.ack());
+    }
+
+    #[test]
+    fn test_get_id_sequence() {
+        let mut card = MemoryCard::new();
+
+        let rx = exchange(&mut card, &[0x81, 0x53, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        assert_eq!(
+            rx,
+            vec![0xFF, 0x08, 0x5A, 0x5D, 0x5C, 0x5D, 0x04, 0x00, 0x00, 0x80]
+        );
+    }
+
+    #[test]
+    fn test_sector_write_then_read() {
+        let mut card = MemoryCard::new();
+        let mut data = [0u8; SECTOR_SIZE];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = i as u8 ^ 0x5A;
+        }
+
+        let rx = exchange(&mut card, &write_command(0x0123, &data));
+        assert_eq!(&rx[1..4], &[FLAG_NEW_CARD, 0x5A, 0x5D]);
+        assert_eq!(&rx[rx.len() - 3..], &[0x5C, 0x5D, STATUS_GOOD]);
+        assert_eq!(card.flag(), 0, "write clears the new-card flag");
+
+        let rx = exchange(&mut card, &read_command(0x0123));
+        assert_eq!(&rx[1..4], &[0x00, 0x5A, 0x5D]);
+        assert_eq!(&rx[5..10], &[0x01, 0x5C, 0x5D, 0x01, 0x23]);
+        assert_eq!(&rx[10..138], &data[..]);
+        let checksum = data.iter().fold(0x01 ^ 0x23, |acc, &b| acc ^ b);
+        assert_eq!(rx[138], checksum);
+        assert_eq!(rx[139], STATUS_GOOD);
+    }
+
+    #[test]
+    fn test_ack_until_last_byte() {
+        let mut card = MemoryCard::new();
+        card.select();
+
+        let tx = read_command(0);
+        for (i, &byte) in tx.iter().enumerate() {
+            card.transfer(byte);
+            assert_eq!(card.ack(), i < tx.len() - 1, "ack after byte {}", i);
+        }
+
+        // Card stops responding once the command is complete
+        assert_eq!(card.transfer(0x00), 0xFF);
+        assert!(!card.ack());
+    }
+
+    #[test]
+    fn test_write_bad_checksum_rejected() {
+        let mut card = MemoryCard::new();
+        let mut tx = write_command(5, &[0xAA; SECTOR_SIZE]);
+        tx[134] ^= 0xFF;
+
+        let rx = exchange(&mut card, &tx);
+
+        assert_eq!(rx[137], STATUS_BAD_CHECKSUM);
+        assert_eq!(card.flag() & FLAG_WRITE_ERROR, FLAG_WRITE_ERROR);
+        assert_ne!(card.data()[5 * SECTOR_SIZE], 0xAA);
+    }
+
+    #[test]
+    fn test_read_invalid_sector() {
+        let mut card = MemoryCard::new();
+
+        let rx = exchange(&mut card, &read_command(0x0400));
+
+        assert_eq!(&rx[6..10], &[0x5C, 0x5D, 0xFF, 0xFF]);
+        assert!(rx[10..].iter().all(|&b| b == 0xFF));
+    }
+
+    #[test]
+    fn test_open_creates_and_persists_file() {
+        let path = std::env::temp_dir().join(format!("psrx_card_{}.mcd", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut card = MemoryCard::open(&path).unwrap();
+        exchange(&mut card, &write_command(64, &[0x11; SECTOR_SIZE]));
+
+        let reopened = MemoryCard::open(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(reopened.data()[64 * SECTOR_SIZE], 0x11);
+        assert_eq!(reopened.data().len(), CARD_SIZE);
+    }
+
+    #[test]
+    fn test_open_rejects_wrong_size() {
+        let path = std::env::temp_dir().join(format!("psrx_card_bad_{}.mcd", std::process::id()));
+        fs::write(&path, [0u8; 16]).unwrap();
+
+        let result = MemoryCard::open(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+}
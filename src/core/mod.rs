@@ -22,6 +22,7 @@
 //! - SPU (Sound Processing Unit)
 //! - CD-ROM (Disc drive)
 //! - DMA (Direct Memory Access)
+//! - MDEC (Macroblock Decoder)
 //! - Controller (Input devices)
 //! - Timer (3 timer/counter channels)
 //! - Interrupt Controller (IRQ management)
@@ -31,6 +32,7 @@
 #[cfg(feature = "audio")]
 pub mod audio;
 pub mod cdrom;
+pub mod cheat;
 pub mod controller;
 pub mod cpu;
 pub mod dma;
@@ -39,7 +41,9 @@ pub mod gpu;
 pub mod gte;
 pub mod interrupt;
 pub mod loader;
+pub mod mdec;
 pub mod memory;
+pub mod memory_card;
 pub mod save_state;
 pub mod spu;
 pub mod system;
@@ -58,7 +62,9 @@ pub use gpu::GPU;
 pub use gte::GTE;
 pub use interrupt::InterruptController;
 pub use loader::{PSXExecutable, SystemConfig};
+pub use mdec::MDEC;
 pub use memory::Bus;
+pub use memory_card::MemoryCard;
 pub use save_state::{SaveState, StateSave, SAVE_STATE_VERSION};
 pub use spu::SPU;
 pub use system::System;
@@ -32,6 +32,7 @@
 //! - Timer state (counters, targets, modes)
 //! - Controller state (button inputs)
 //! - Interrupt state (I_STAT, I_MASK)
+//! - Timing event schedule (matched by event name on load)
 //!
 //! # Version Compatibility
 //!
@@ -56,8 +57,11 @@
 //!
 //! // Later: load from file
 //! let loaded_state = SaveState::load_from_file("save.state").unwrap();
-//! // ... apply to system ...
+//! system.load_state(&loaded_state.to_bytes()).unwrap();
 //! ```
+//!
+//! `System::save_state()` and `System::load_state()` wrap the same format
+//! as an in-memory byte buffer.
 
 use bincode::{config, Decode, Encode};
 use chrono::{DateTime, Utc};
@@ -70,7 +74,7 @@ use std::path::Path;
 ///
 /// This version number should be incremented whenever the save state format changes
 /// in a way that breaks backward compatibility.
-pub const SAVE_STATE_VERSION: u32 = 1;
+pub const SAVE_STATE_VERSION: u32 = 12;
 
 /// Complete emulator save state
 ///
@@ -110,6 +114,9 @@ pub struct SaveState {
 
     /// Interrupt controller state
     pub interrupts: InterruptState,
+
+    /// Timing event schedule
+    pub timing: TimingState,
 }
 
 /// Save state metadata
@@ -142,7 +149,7 @@ pub struct SaveStateMetadata {
 ///
 /// Captures all CPU registers and internal state including
 /// delay slots, COP0, and GTE state.
-#[derive(Serialize, Deserialize, Encode, Decode)]
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct CPUState {
     /// General purpose registers (R0-R31)
     pub regs: [u32; 32],
@@ -174,6 +181,12 @@ pub struct CPUState {
     /// GTE (Geometry Transformation Engine) registers
     pub gte_data_regs: [i32; 32],
     pub gte_control_regs: [u32; 32],
+
+    /// GTE FLAG register
+    pub gte_flags: u32,
+
+    /// Instruction cache lines (tag, words, valid bits)
+    pub icache_lines: Vec<(u32, [u32; 4], u8)>,
 }
 
 /// Memory state (RAM and scratchpad)
@@ -181,19 +194,28 @@ pub struct CPUState {
 /// Contains main RAM and scratchpad data.
 /// BIOS is not saved as it doesn't change during execution and should
 /// be loaded from the user's BIOS file when restoring state.
-#[derive(Serialize, Deserialize, Encode, Decode)]
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct MemoryState {
     /// Main RAM (2MB)
     pub ram: Vec<u8>,
 
     /// Scratchpad (1KB fast RAM)
     pub scratchpad: Vec<u8>,
+
+    /// Cache control register (0xFFFE0130)
+    pub cache_control: u32,
+
+    /// Memory control registers (0x1F801000-0x1F801020)
+    pub mem_control: Vec<u32>,
+
+    /// RAM_SIZE register (0x1F801060)
+    pub ram_size: u32,
 }
 
 /// GPU state (Graphics Processing Unit)
 ///
 /// Captures VRAM contents, drawing settings, and display configuration.
-#[derive(Serialize, Deserialize, Encode, Decode)]
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct GPUState {
     /// VRAM (1MB - 1024x512 pixels, 16-bit per pixel)
     pub vram: Vec<u16>,
@@ -212,6 +234,10 @@ pub struct GPUState {
     pub display_area_x: u16,
     pub display_area_y: u16,
 
+    /// Display area size in pixels
+    pub display_width: u16,
+    pub display_height: u16,
+
     /// Display horizontal and vertical range
     pub display_horiz_start: u16,
     pub display_horiz_end: u16,
@@ -232,7 +258,7 @@ pub struct GPUState {
     pub texture_window_offset_x: u8,
     pub texture_window_offset_y: u8,
 
-    /// Drawing mode flags
+    /// Drawing mode flags (texture page in units of 64/256 pixels)
     pub draw_mode_texture_page_x: u8,
     pub draw_mode_texture_page_y: u8,
     pub draw_mode_semi_transparency: u8,
@@ -250,16 +276,56 @@ pub struct GPUState {
     /// GPU status register
     pub status: u32,
 
+    /// Current interlace field (GPUSTAT bit 13 reads 1 while interlace is off)
+    pub interlace_field: bool,
+
     /// Scanline and timing state
     pub scanline: u16,
     pub dots: u16,
     pub in_vblank: bool,
+    pub in_hblank: bool,
+
+    /// CPU cycles not yet converted to dots (scaled by dots per scanline)
+    pub dot_remainder: u64,
+
+    /// GPU clock cycles until the last primitive finishes drawing
+    pub draw_busy_cycles: u32,
+
+    /// Pending GP0 command words
+    pub command_fifo: Vec<u32>,
+
+    /// Active VRAM transfer, if any
+    pub vram_transfer: Option<VRAMTransferState>,
+
+    /// Last value presented on GPUREAD
+    pub gpuread_latch: u32,
+
+    /// A frame finished since it was last presented
+    pub frame_ready: bool,
+}
+
+/// In-progress VRAM transfer (GP0 A0h/C0h)
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
+pub struct VRAMTransferState {
+    /// Transfer rectangle
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+
+    /// Current position within the rectangle
+    pub current_x: u16,
+    pub current_y: u16,
+
+    /// Transfer direction (false = CPU→VRAM, true = VRAM→CPU)
+    pub vram_to_cpu: bool,
 }
 
 /// SPU state (Sound Processing Unit)
 ///
 /// Captures all audio processing state including voices, volumes, and sound RAM.
-#[derive(Serialize, Deserialize, Encode, Decode)]
+/// Samples already mixed into the host output buffer are not saved.
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct SPUState {
     /// Sound RAM (512KB)
     pub ram: Vec<u8>,
@@ -279,9 +345,17 @@ pub struct SPUState {
     pub cd_volume_left: i16,
     pub cd_volume_right: i16,
 
+    /// External audio volume
+    pub ext_volume_left: i16,
+    pub ext_volume_right: i16,
+
     /// Reverb state
     pub reverb: ReverbState,
 
+    /// Noise generator LFSR and frequency timer
+    pub noise_lfsr: u16,
+    pub noise_timer: i32,
+
     /// Control register
     pub control: u16,
 
@@ -290,10 +364,23 @@ pub struct SPUState {
 
     /// DMA transfer address
     pub transfer_addr: u32,
+
+    /// IRQ address and pending flag
+    pub irq_address: u16,
+    pub irq_pending: bool,
+
+    /// CPU cycles accumulated toward the next output sample
+    pub sample_counter: u32,
+
+    /// Capture buffer (left/right)
+    pub capture_buffer: [i16; 2],
+
+    /// Pending data transfer FIFO
+    pub dma_fifo: Vec<u16>,
 }
 
 /// Individual voice state
-#[derive(Serialize, Deserialize, Encode, Decode)]
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct VoiceState {
     /// Volume (left/right channels)
     pub volume_left: i16,
@@ -320,50 +407,80 @@ pub struct VoiceState {
     /// Current ADPCM decoder state
     pub adpcm_old: i16,
     pub adpcm_older: i16,
-}
+    pub adpcm_position: f32,
 
-/// ADSR (Attack Decay Sustain Release) envelope state
-#[derive(Serialize, Deserialize, Encode, Decode)]
-pub struct ADSRState {
-    /// Attack rate
-    pub attack_rate: u8,
+    /// Decoded samples of the current block
+    pub decoded_samples: Vec<i16>,
+
+    /// Key on/off flags
+    pub key_on: bool,
+    pub key_off: bool,
 
-    /// Decay rate
-    pub decay_rate: u8,
+    /// Block end flags
+    pub loop_flag: bool,
+    pub final_block: bool,
 
-    /// Sustain level
-    pub sustain_level: u8,
+    /// Noise, reverb (EON) and pitch modulation (PMON) routing
+    pub noise_enabled: bool,
+    pub reverb_enabled: bool,
+    pub pitch_modulation: bool,
+
+    /// Block decoded since the last IRQ check
+    pub decoded_block: Option<u32>,
+
+    /// Last envelope output (VxOUTX)
+    pub output: i16,
+}
 
-    /// Sustain rate
-    pub sustain_rate: u8,
+/// ADSR (Attack Decay Sustain Release) envelope state
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
+pub struct ADSRState {
+    /// ADSR register, lower half (attack/decay/sustain level)
+    pub word_1: u16,
 
-    /// Release rate
-    pub release_rate: u8,
+    /// ADSR register, upper half (sustain/release)
+    pub word_2: u16,
 
     /// Current envelope phase
     pub phase: u8,
 
     /// Current envelope level
     pub level: i16,
+
+    /// Samples left before the next envelope step
+    pub counter: u32,
 }
 
 /// Reverb configuration state
-#[derive(Serialize, Deserialize, Encode, Decode)]
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct ReverbState {
     /// Reverb enabled flag
     pub enabled: bool,
 
     /// Reverb current address
     pub reverb_current_addr: u32,
-    // Additional reverb parameters could be added here
+
+    /// Reverb work area start address (mBASE × 8)
+    pub reverb_start_addr: u32,
+
+    /// Reverb registers (0x1F801DC0-0x1F801DFF)
+    pub registers: Vec<u16>,
+
+    /// Whether the next sample is an odd (reverb-processing) sample
+    pub odd_sample: bool,
+
+    /// Last reverb output (left/right)
+    pub output: (i16, i16),
 }
 
 /// CD-ROM state
 ///
-/// Captures the state of the CD-ROM controller including FIFOs and command processing.
-#[derive(Serialize, Deserialize, Encode, Decode)]
+/// Captures the state of the CD-ROM controller including FIFOs and command
+/// processing. The disc image itself is not saved; the disc inserted when
+/// the state is loaded stays in the drive.
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct CDROMState {
-    /// Current status register
+    /// Status byte
     pub status: u8,
 
     /// Index register
@@ -375,18 +492,39 @@ pub struct CDROMState {
     /// Response FIFO
     pub response_fifo: Vec<u8>,
 
-    /// Data buffer
+    /// Data buffer and read index
     pub data_buffer: Vec<u8>,
+    pub data_index: u32,
+
+    /// Header and subheader of the last sector read
+    pub last_sector_header: Option<[u8; 8]>,
 
-    /// Current command being processed
-    pub current_command: u8,
+    /// Drive state (0=Idle, 1=Reading, 2=Seeking, 3=Playing)
+    pub drive_state: u8,
 
     /// Seek target (MSF format: minute, second, frame)
-    pub seek_target: (u8, u8, u8),
+    pub seek_target: Option<(u8, u8, u8)>,
+
+    /// Pending seek uses subchannel Q addressing (SeekP) instead of SeekL
+    pub seek_physical: bool,
 
     /// Current read position (MSF format)
     pub read_position: (u8, u8, u8),
 
+    /// Legacy read, seek and spin-up counters
+    pub read_ticks: u32,
+    pub seek_ticks: u32,
+    pub spin_up_ticks: u32,
+
+    /// Track being played
+    pub play_track: u8,
+
+    /// Next CD-DA report carries the right channel peak
+    pub report_peak_right: bool,
+
+    /// Audio volume values waiting to be latched
+    pub pending_volume: [u8; 4],
+
     /// Mode register
     pub mode: u8,
 
@@ -396,17 +534,70 @@ pub struct CDROMState {
     /// Interrupt flag register
     pub interrupt_flag: u8,
 
-    /// Reading flag
-    pub reading: bool,
+    /// XA filter file/channel
+    pub xa_filter_file: u8,
+    pub xa_filter_channel: u8,
+
+    /// XA-ADPCM decoder history (left, right)
+    pub xa_history: [(i32, i32); 2],
+
+    /// Command waiting for its ACK delay
+    pub pending_command: Option<u8>,
+
+    /// Second response waiting to be delivered
+    pub pending_second_response: Option<u8>,
+
+    /// Async interrupt waiting to be delivered
+    pub pending_async_interrupt: u8,
+
+    /// Last interrupt delivery time
+    pub last_interrupt_time: i32,
 
-    /// Seeking flag
-    pub seeking: bool,
+    /// Response bytes for the pending async interrupt
+    pub async_response_fifo: Vec<u8>,
+
+    /// Command waiting to be scheduled
+    pub command_to_schedule: Option<u8>,
+
+    /// CD-DA/XA audio playback state
+    pub audio: CDAudioState,
+}
+
+/// CD audio playback state
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
+pub struct CDAudioState {
+    /// Playback position and range (sectors)
+    pub current_sector: u32,
+    pub play_start: u32,
+    pub play_end: u32,
+
+    /// Playing and loop flags
+    pub playing: bool,
+    pub looping: bool,
+
+    /// Output volume and CD-to-SPU volume matrix
+    pub volume_left: i16,
+    pub volume_right: i16,
+    pub volume_matrix: [u8; 4],
+
+    /// Buffered CD-DA samples and read position
+    pub buffer: Vec<i16>,
+    pub buffer_position: u32,
+
+    /// Buffered XA samples and resampler state
+    pub xa_buffer: Vec<(i16, i16)>,
+    pub xa_sample_rate: u32,
+    pub xa_phase: u32,
+    pub xa_current: (i16, i16),
+
+    /// Peak CD-DA levels since the last report (left, right)
+    pub peak: (u16, u16),
 }
 
 /// DMA state (Direct Memory Access)
 ///
 /// Captures all 7 DMA channels and control registers.
-#[derive(Serialize, Deserialize, Encode, Decode)]
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct DMAState {
     /// DMA channel states (7 channels)
     pub channels: Vec<DMAChannelState>,
@@ -416,10 +607,13 @@ pub struct DMAState {
 
     /// DMA interrupt register (DICR)
     pub interrupt: u32,
+
+    /// CPU cycles available for block/linked-list transfers
+    pub cycle_budget: u32,
 }
 
 /// Individual DMA channel state
-#[derive(Serialize, Deserialize, Encode, Decode)]
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct DMAChannelState {
     /// Base address register (MADR)
     pub base_address: u32,
@@ -432,19 +626,49 @@ pub struct DMAChannelState {
 
     /// Channel ID (0-6)
     pub channel_id: u8,
+
+    /// In-progress transfer state
+    pub in_progress: bool,
+    pub current_address: u32,
+    pub words_remaining: u32,
+    pub chop_wait: u32,
+    pub nodes_visited: u32,
 }
 
 /// Timer state (3 timer channels)
 ///
-/// Captures all three timer/counter channels.
-#[derive(Serialize, Deserialize, Encode, Decode)]
+/// Captures all three timer/counter channels and the shared clock
+/// accumulators. Timing event handles are not saved; channels are marked
+/// for rescheduling on restore instead.
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct TimerState {
     /// Timer channel states
     pub timers: Vec<TimerChannelState>,
+
+    /// Timer 0 dot clock accumulator (video clock cycles)
+    pub timer0_dot_accum: u32,
+
+    /// Timer 1 hblank accumulator (CPU cycles)
+    pub timer1_hblank_accum: u32,
+
+    /// Timer 2 divide-by-8 accumulator (CPU cycles)
+    pub timer2_div_accum: u32,
+
+    /// GPU dot clock divider
+    pub dot_clock_divider: u32,
+
+    /// Video standard (false = NTSC, true = PAL)
+    pub video_mode: bool,
+
+    /// Latest global time reported by the system
+    pub current_time: u64,
+
+    /// Global time the counters were last advanced to
+    pub synced_time: u64,
 }
 
 /// Individual timer channel state
-#[derive(Serialize, Deserialize, Encode, Decode)]
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct TimerChannelState {
     /// Current counter value
     pub counter: u32,
@@ -452,36 +676,137 @@ pub struct TimerChannelState {
     /// Target value
     pub target: u32,
 
-    /// Mode register
+    /// Mode register (bits 0-9)
     pub mode: u32,
+
+    /// IRQ flag (mode bit 10)
+    pub irq_flag: bool,
+
+    /// Reached target flag (mode bit 11)
+    pub reached_target: bool,
+
+    /// Reached max flag (mode bit 12)
+    pub reached_max: bool,
+
+    /// Last sync signal state (for edge detection)
+    pub last_sync: bool,
+
+    /// Sync mode 3 latch
+    pub sync_latched: bool,
 }
 
 /// Controller state
 ///
-/// Captures the state of all controller ports.
-#[derive(Serialize, Deserialize, Encode, Decode)]
+/// Captures the state of all controller ports, including a serial
+/// transfer and /ACK pulse in flight.
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct ControllerState {
     /// Controller data for each port
     pub controllers: Vec<ControllerData>,
+
+    /// Last bytes written to JOY_TX_DATA and read from JOY_RX_DATA
+    pub tx_data: u8,
+    pub rx_data: u8,
+
+    /// Received bytes waiting to be read
+    pub rx_fifo: Vec<u8>,
+
+    /// Byte waiting in the TX FIFO
+    pub tx_pending: Option<u8>,
+
+    /// Byte being shifted out and the CPU cycles until it completes
+    pub transfer: Option<(u8, u32)>,
+
+    /// CPU cycles until the pending /ACK pulse
+    pub ack_ticks: Option<u32>,
+
+    /// JOY_STAT, JOY_MODE, JOY_CTRL and JOY_BAUD
+    pub stat: u32,
+    pub mode: u16,
+    pub ctrl: u16,
+    pub baud: u16,
+
+    /// Selected port (0 or 1)
+    pub selected_port: Option<u8>,
+
+    /// Device addressed by the current transfer (0=controller, 1=memory card)
+    pub active_device: Option<u8>,
 }
 
-/// Individual controller button state
-#[derive(Clone, Serialize, Deserialize, Encode, Decode)]
+/// Individual controller button and serial protocol state
+#[derive(Clone, Default, Serialize, Deserialize, Encode, Decode)]
 pub struct ControllerData {
     /// Button state (16-bit bitfield)
     pub buttons: u16,
+
+    /// Serial state (0=Idle, 1=Selected, 2=Transferring)
+    pub serial_state: u8,
+
+    /// Response bytes of the current poll and command bytes received so far
+    pub tx_buffer: Vec<u8>,
+    pub rx_buffer: Vec<u8>,
+
+    /// Index of the next response byte
+    pub transfer_index: u32,
 }
 
 /// Interrupt controller state
 ///
 /// Captures interrupt status and mask registers.
-#[derive(Serialize, Deserialize, Encode, Decode)]
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
 pub struct InterruptState {
     /// Interrupt status register (I_STAT)
     pub i_stat: u32,
 
     /// Interrupt mask register (I_MASK)
     pub i_mask: u32,
+
+    /// Levels of edge-triggered interrupt lines
+    pub i_lines: u32,
+}
+
+/// Timing event schedule
+///
+/// Event handles depend on registration order, so events are saved by
+/// name and matched against the events registered by the loading system.
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
+pub struct TimingState {
+    /// Global tick counter
+    pub global_tick_counter: u64,
+
+    /// Tick counter at the last event run
+    pub event_run_tick_counter: u64,
+
+    /// Ticks accumulated since the last event run
+    pub pending_ticks: i32,
+
+    /// Cycles until the next event
+    pub downcount: i32,
+
+    /// Events in scheduling order
+    pub events: Vec<TimingEventState>,
+
+    /// Total cycles reported by `System::cycles()`
+    pub cycles: u64,
+}
+
+/// Single scheduled timing event
+#[derive(Default, Serialize, Deserialize, Encode, Decode)]
+pub struct TimingEventState {
+    /// Event name (stable identifier)
+    pub name: String,
+
+    /// Next execution time (global ticks)
+    pub next_run_time: u64,
+
+    /// Last execution time (global ticks)
+    pub last_run_time: u64,
+
+    /// Interval for periodic events (0 = one-shot)
+    pub interval: i32,
+
+    /// Whether the event is scheduled
+    pub active: bool,
 }
 
 impl SaveState {
@@ -489,20 +814,12 @@ impl SaveState {
     ///
     /// # Arguments
     ///
-    /// * `_system` - Reference to the system to save
+    /// * `system` - Reference to the system to save
     ///
     /// # Returns
     ///
     /// SaveState containing complete system state
     ///
-    /// # Note
-    ///
-    /// This is currently a stub implementation that returns an empty state.
-    /// The full implementation requires each component (CPU, GPU, SPU, etc.) to
-    /// implement the `StateSave` trait with `to_state()` methods. Once those
-    /// trait implementations are complete, this method will call each component's
-    /// `to_state()` to capture the actual runtime state.
-    ///
     /// # Example
     ///
     /// ```no_run
@@ -510,120 +827,60 @@ impl SaveState {
     /// # let system = System::new();
     /// let state = SaveState::from_system(&system);
     /// ```
-    pub fn from_system(_system: &crate::core::System) -> Self {
-        Self {
-            version: SAVE_STATE_VERSION,
-            metadata: SaveStateMetadata {
-                timestamp: Utc::now(),
-                game_id: String::new(), // Will be populated by System method
-                game_title: String::new(),
-                frame_count: 0,
-                playtime: 0,
-                thumbnail: None,
-            },
-            cpu: CPUState {
-                regs: [0; 32],
-                pc: 0,
-                next_pc: 0,
-                hi: 0,
-                lo: 0,
-                cop0_regs: [0; 32],
-                load_delay: None,
-                in_branch_delay: false,
-                current_instruction: 0,
-                gte_data_regs: [0; 32],
-                gte_control_regs: [0; 32],
-            },
-            memory: MemoryState {
-                ram: Vec::new(),
-                scratchpad: Vec::new(),
-            },
-            gpu: GPUState {
-                vram: Vec::new(),
-                draw_area_left: 0,
-                draw_area_top: 0,
-                draw_area_right: 0,
-                draw_area_bottom: 0,
-                draw_offset_x: 0,
-                draw_offset_y: 0,
-                display_area_x: 0,
-                display_area_y: 0,
-                display_horiz_start: 0,
-                display_horiz_end: 0,
-                display_vert_start: 0,
-                display_vert_end: 0,
-                display_enabled: false,
-                display_depth_24bit: false,
-                vertical_interlace: false,
-                horizontal_res: 0,
-                vertical_res: false,
-                video_mode: false,
-                texture_window_mask_x: 0,
-                texture_window_mask_y: 0,
-                texture_window_offset_x: 0,
-                texture_window_offset_y: 0,
-                draw_mode_texture_page_x: 0,
-                draw_mode_texture_page_y: 0,
-                draw_mode_semi_transparency: 0,
-                draw_mode_texture_depth: 0,
-                draw_mode_dithering: false,
-                draw_mode_draw_to_display: false,
-                draw_mode_texture_disable: false,
-                draw_mode_rectangle_flip_x: false,
-                draw_mode_rectangle_flip_y: false,
-                mask_bit_force: false,
-                mask_bit_check: false,
-                status: 0,
-                scanline: 0,
-                dots: 0,
-                in_vblank: false,
-            },
-            spu: SPUState {
-                ram: Vec::new(),
-                voices: Vec::new(),
-                main_volume_left: 0,
-                main_volume_right: 0,
-                reverb_volume_left: 0,
-                reverb_volume_right: 0,
-                cd_volume_left: 0,
-                cd_volume_right: 0,
-                reverb: ReverbState {
-                    enabled: false,
-                    reverb_current_addr: 0,
-                },
-                control: 0,
-                status: 0,
-                transfer_addr: 0,
-            },
-            cdrom: CDROMState {
-                status: 0,
-                index: 0,
-                param_fifo: Vec::new(),
-                response_fifo: Vec::new(),
-                data_buffer: Vec::new(),
-                current_command: 0,
-                seek_target: (0, 0, 0),
-                read_position: (0, 0, 0),
-                mode: 0,
-                interrupt_enable: 0,
-                interrupt_flag: 0,
-                reading: false,
-                seeking: false,
-            },
-            dma: DMAState {
-                channels: Vec::new(),
-                control: 0,
-                interrupt: 0,
-            },
-            timers: TimerState { timers: Vec::new() },
-            controllers: ControllerState {
-                controllers: Vec::new(),
-            },
-            interrupts: InterruptState {
-                i_stat: 0,
-                i_mask: 0,
-            },
+    pub fn from_system(system: &crate::core::System) -> Self {
+        system.capture_state()
+    }
+
+    /// Serialize the save state to bytes
+    ///
+    /// # Returns
+    ///
+    /// bincode-encoded save state
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use psrx::core::save_state::SaveState;
+    /// # let state = SaveState::default();
+    /// let bytes = state.to_bytes();
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // Encoding into a Vec has no I/O and every field type is supported,
+        // so this cannot fail
+        bincode::encode_to_vec(self, config::standard()).expect("save state encoding failed")
+    }
+
+    /// Deserialize a save state from bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - bincode-encoded save state
+    ///
+    /// # Returns
+    ///
+    /// Result containing the decoded SaveState or error
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Deserialization fails
+    /// - Version is incompatible
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
+        // Set a 50MB limit to prevent unbounded allocation from corrupted/malicious files
+        // This is well above the expected ~3.6MB for a typical save state
+        let config = config::standard().with_limit::<{ 50 * 1024 * 1024 }>();
+        let (state, _): (SaveState, usize) = bincode::decode_from_slice(data, config)?;
+
+        // Version check
+        if state.version != SAVE_STATE_VERSION {
+            return Err(format!(
+                "Incompatible save state version: expected {}, got {}",
+                SAVE_STATE_VERSION, state.version
+            )
+            .into());
         }
+
+        Ok(state)
     }
 
     /// Save state to file
@@ -691,23 +948,8 @@ impl SaveState {
         let mut buffer = Vec::new();
         file.read_to_end(&mut buffer)?;
 
-        // Set a 50MB limit to prevent unbounded allocation from corrupted/malicious files
-        // This is well above the expected ~3.6MB for a typical save state
-        let config = config::standard().with_limit::<{ 50 * 1024 * 1024 }>();
-        let (state, _): (SaveState, usize) = bincode::decode_from_slice(&buffer, config)?;
-
-        // Version check
-        if state.version != SAVE_STATE_VERSION {
-            return Err(format!(
-                "Incompatible save state version: expected {}, got {}",
-                SAVE_STATE_VERSION, state.version
-            )
-            .into());
-        }
-
-        Ok(state)
+        Self::from_bytes(&buffer)
     }
-
     /// Get estimated file size for this save state
     ///
     /// Returns approximate size in bytes of the serialized save state.
@@ -751,144 +993,62 @@ impl Default for SaveState {
                 playtime: 0,
                 thumbnail: None,
             },
-            cpu: CPUState {
-                regs: [0; 32],
-                pc: 0,
-                next_pc: 0,
-                hi: 0,
-                lo: 0,
-                cop0_regs: [0; 32],
-                load_delay: None,
-                in_branch_delay: false,
-                current_instruction: 0,
-                gte_data_regs: [0; 32],
-                gte_control_regs: [0; 32],
-            },
+            cpu: CPUState::default(),
             memory: MemoryState {
                 ram: vec![0; 2 * 1024 * 1024],
                 scratchpad: vec![0; 1024],
+                ..Default::default()
             },
             gpu: GPUState {
                 vram: vec![0; 1024 * 512],
-                draw_area_left: 0,
-                draw_area_top: 0,
                 draw_area_right: 1023,
                 draw_area_bottom: 511,
-                draw_offset_x: 0,
-                draw_offset_y: 0,
-                display_area_x: 0,
-                display_area_y: 0,
+                display_width: 320,
+                display_height: 240,
                 display_horiz_start: 0x200,
                 display_horiz_end: 0xC00,
                 display_vert_start: 0x10,
                 display_vert_end: 0x100,
                 display_enabled: true,
-                display_depth_24bit: false,
-                vertical_interlace: false,
-                horizontal_res: 0,
-                vertical_res: false,
-                video_mode: false,
-                texture_window_mask_x: 0,
-                texture_window_mask_y: 0,
-                texture_window_offset_x: 0,
-                texture_window_offset_y: 0,
-                draw_mode_texture_page_x: 0,
-                draw_mode_texture_page_y: 0,
-                draw_mode_semi_transparency: 0,
-                draw_mode_texture_depth: 0,
-                draw_mode_dithering: false,
-                draw_mode_draw_to_display: false,
-                draw_mode_texture_disable: false,
-                draw_mode_rectangle_flip_x: false,
-                draw_mode_rectangle_flip_y: false,
-                mask_bit_force: false,
-                mask_bit_check: false,
                 status: 0x1C000000,
-                scanline: 0,
-                dots: 0,
-                in_vblank: false,
+                ..Default::default()
             },
             spu: SPUState {
                 ram: vec![0; 512 * 1024],
-                voices: (0..24)
-                    .map(|_| VoiceState {
-                        volume_left: 0,
-                        volume_right: 0,
-                        sample_rate: 0,
-                        start_address: 0,
-                        repeat_address: 0,
-                        current_address: 0,
-                        adsr: ADSRState {
-                            attack_rate: 0,
-                            decay_rate: 0,
-                            sustain_level: 0,
-                            sustain_rate: 0,
-                            release_rate: 0,
-                            phase: 0,
-                            level: 0,
-                        },
-                        enabled: false,
-                        adpcm_old: 0,
-                        adpcm_older: 0,
-                    })
-                    .collect(),
-                main_volume_left: 0,
-                main_volume_right: 0,
-                reverb_volume_left: 0,
-                reverb_volume_right: 0,
-                cd_volume_left: 0,
-                cd_volume_right: 0,
-                reverb: ReverbState {
-                    enabled: false,
-                    reverb_current_addr: 0,
-                },
-                control: 0,
-                status: 0,
-                transfer_addr: 0,
+                voices: (0..24).map(|_| VoiceState::default()).collect(),
+                ..Default::default()
             },
             cdrom: CDROMState {
-                status: 0,
-                index: 0,
-                param_fifo: Vec::new(),
-                response_fifo: Vec::new(),
-                data_buffer: Vec::new(),
-                current_command: 0,
-                seek_target: (0, 2, 0),
                 read_position: (0, 2, 0),
-                mode: 0,
-                interrupt_enable: 0,
-                interrupt_flag: 0,
-                reading: false,
-                seeking: false,
+                ..Default::default()
             },
             dma: DMAState {
                 channels: (0..7)
                     .map(|i| DMAChannelState {
-                        base_address: 0,
-                        block_control: 0,
-                        channel_control: 0,
                         channel_id: i,
+                        ..Default::default()
                     })
                     .collect(),
                 control: 0x07654321,
-                interrupt: 0,
+                ..Default::default()
             },
             timers: TimerState {
-                timers: (0..3)
-                    .map(|_| TimerChannelState {
-                        counter: 0,
-                        target: 0,
-                        mode: 0,
-                    })
-                    .collect(),
+                timers: (0..3).map(|_| TimerChannelState::default()).collect(),
+                dot_clock_divider: 8,
+                ..Default::default()
             },
             controllers: ControllerState {
-                controllers: vec![ControllerData { buttons: 0xFFFF }; 2],
-            },
-            interrupts: InterruptState {
-                i_stat: 0,
-                i_mask: 0,
+                controllers: vec![
+                    ControllerData {
+                        buttons: 0xFFFF,
+                        ..Default::default()
+                    };
+                    2
+                ],
+                ..Default::default()
             },
+            interrupts: InterruptState::default(),
+            timing: TimingState::default(),
         }
     }
 }
@@ -947,7 +1107,7 @@ mod tests {
 
     #[test]
     fn test_save_state_version() {
-        assert_eq!(SAVE_STATE_VERSION, 1);
+        assert_eq!(SAVE_STATE_VERSION, 12);
     }
 
     #[test]
@@ -966,7 +1126,6 @@ mod tests {
     #[test]
     fn test_save_state_serialization() {
         let state = SaveState {
-            version: SAVE_STATE_VERSION,
             metadata: SaveStateMetadata {
                 timestamp: Utc::now(),
                 game_id: "SCUS-94163".to_string(),
@@ -976,107 +1135,11 @@ mod tests {
                 thumbnail: None,
             },
             cpu: CPUState {
-                regs: [0; 32],
                 pc: 0xBFC00000,
                 next_pc: 0xBFC00004,
-                hi: 0,
-                lo: 0,
-                cop0_regs: [0; 32],
-                load_delay: None,
-                in_branch_delay: false,
-                current_instruction: 0,
-                gte_data_regs: [0; 32],
-                gte_control_regs: [0; 32],
-            },
-            memory: MemoryState {
-                ram: vec![0; 2 * 1024 * 1024],
-                scratchpad: vec![0; 1024],
-            },
-            gpu: GPUState {
-                vram: vec![0; 1024 * 512],
-                draw_area_left: 0,
-                draw_area_top: 0,
-                draw_area_right: 1023,
-                draw_area_bottom: 511,
-                draw_offset_x: 0,
-                draw_offset_y: 0,
-                display_area_x: 0,
-                display_area_y: 0,
-                display_horiz_start: 0,
-                display_horiz_end: 0,
-                display_vert_start: 0,
-                display_vert_end: 0,
-                display_enabled: false,
-                display_depth_24bit: false,
-                vertical_interlace: false,
-                horizontal_res: 0,
-                vertical_res: false,
-                video_mode: false,
-                texture_window_mask_x: 0,
-                texture_window_mask_y: 0,
-                texture_window_offset_x: 0,
-                texture_window_offset_y: 0,
-                draw_mode_texture_page_x: 0,
-                draw_mode_texture_page_y: 0,
-                draw_mode_semi_transparency: 0,
-                draw_mode_texture_depth: 0,
-                draw_mode_dithering: false,
-                draw_mode_draw_to_display: false,
-                draw_mode_texture_disable: false,
-                draw_mode_rectangle_flip_x: false,
-                draw_mode_rectangle_flip_y: false,
-                mask_bit_force: false,
-                mask_bit_check: false,
-                status: 0,
-                scanline: 0,
-                dots: 0,
-                in_vblank: false,
-            },
-            spu: SPUState {
-                ram: vec![0; 512 * 1024],
-                voices: Vec::new(),
-                main_volume_left: 0,
-                main_volume_right: 0,
-                reverb_volume_left: 0,
-                reverb_volume_right: 0,
-                cd_volume_left: 0,
-                cd_volume_right: 0,
-                reverb: ReverbState {
-                    enabled: false,
-                    reverb_current_addr: 0,
-                },
-                control: 0,
-                status: 0,
-                transfer_addr: 0,
-            },
-            cdrom: CDROMState {
-                status: 0,
-                index: 0,
-                param_fifo: Vec::new(),
-                response_fifo: Vec::new(),
-                data_buffer: Vec::new(),
-                current_command: 0,
-                seek_target: (0, 0, 0),
-                read_position: (0, 0, 0),
-                mode: 0,
-                interrupt_enable: 0,
-                interrupt_flag: 0,
-                reading: false,
-                seeking: false,
-            },
-            dma: DMAState {
-                channels: Vec::new(),
-                control: 0,
-                interrupt: 0,
-            },
-            timers: TimerState { timers: Vec::new() },
-            controllers: ControllerState {
-                controllers: Vec::new(),
-            },
-            interrupts: InterruptState {
-                i_stat: 0,
-                i_mask: 0,
+                ..Default::default()
             },
+            ..Default::default()
         };
 
         // Serialize
@@ -19,6 +19,12 @@
 //! ADPCM compresses 16-bit PCM audio to 4 bits per sample using
 //! adaptive prediction filters.
 
+/// Prediction filter coefficients applied to the previous sample (in 1/64ths)
+const POS_TABLE: [i32; 5] = [0, 60, 115, 98, 122];
+
+/// Prediction filter coefficients applied to the sample before that (in 1/64ths)
+const NEG_TABLE: [i32; 5] = [0, 0, -52, -55, -60];
+
 /// ADPCM decoder state
 ///
 /// Maintains state for ADPCM audio decompression including previous samples
@@ -57,10 +63,13 @@ impl ADPCMState {
     /// # ADPCM Block Format
     ///
     /// ```text
-    /// Byte 0: Shift (bits 0-3) | Filter (bits 4-7)
-    /// Byte 1: Flags (loop end, loop repeat, etc.)
-    /// Bytes 2-15: 14 bytes of nibble pairs (28 samples total)
+    /// Byte 0: Shift (bits 0-3) | Filter (bits 4-6)
+    /// Byte 1: Flags (bit 0: loop end, bit 1: loop repeat, bit 2: loop start)
+    /// Bytes 2-15: 14 bytes of nibble pairs (28 samples total, low nibble first)
     /// ```
+    ///
+    /// Shift values 13-15 behave like shift 9 and filter values 5-7 like
+    /// filter 4, matching the hardware.
     pub fn decode_block(&mut self, block: &[u8]) -> Vec<i16> {
         if block.len() < 16 {
             return Vec::new();
@@ -69,8 +78,11 @@ impl ADPCMState {
         let mut samples = Vec::with_capacity(28);
 
         // Block header
-        let shift = block[0] & 0xF;
-        let filter = (block[0] >> 4) & 0x3;
+        let shift = match block[0] & 0xF {
+            13..=15 => 9,
+            shift => shift,
+        };
+        let filter = ((block[0] >> 4) & 0x7).min(4);
         // Flags in block[1] are for loop control, handled elsewhere
 
         // Decode 28 samples from 14 bytes (2 samples per byte)
@@ -105,7 +117,7 @@ impl ADPCMState {
     /// # Arguments
     ///
     /// * `sample` - Input sample after shift
-    /// * `filter` - Filter mode (0-3)
+    /// * `filter` - Filter mode (0-4)
     ///
     /// # Returns
     ///
@@ -113,35 +125,21 @@ impl ADPCMState {
     ///
     /// # Filter Modes
     ///
-    /// - Filter 0: No filtering, pass through
-    /// - Filter 1: Simple first-order prediction
-    /// - Filter 2: Second-order prediction with both previous samples
-    /// - Filter 3: Alternative second-order prediction
+    /// The prediction is `(old * pos + older * neg + 32) >> 6` with:
+    ///
+    /// - Filter 0: pos=0, neg=0 (no prediction)
+    /// - Filter 1: pos=60, neg=0
+    /// - Filter 2: pos=115, neg=-52
+    /// - Filter 3: pos=98, neg=-55
+    /// - Filter 4: pos=122, neg=-60
     #[inline(always)]
     fn apply_filter(&mut self, sample: i16, filter: u8) -> i16 {
-        let result = match filter {
-            0 => sample as i32,
-            1 => {
-                // Filter 1: s + old[0] + (-old[0] >> 1)
-                sample as i32 + self.prev_samples[0] as i32 + (-(self.prev_samples[0] as i32) >> 1)
-            }
-            2 => {
-                // Filter 2: s + old[0]*2 + (-old[0]*3 >> 1) - old[1] + (old[1] >> 1)
-                sample as i32
-                    + (self.prev_samples[0] as i32 * 2)
-                    + ((-(self.prev_samples[0] as i32) * 3) >> 1)
-                    - self.prev_samples[1] as i32
-                    + (self.prev_samples[1] as i32 >> 1)
-            }
-            3 => {
-                // Filter 3: s + old[0]*2 - (old[0]*5 >> 2) + old[1]*2 - (old[1] >> 1)
-                sample as i32 + (self.prev_samples[0] as i32 * 2)
-                    - ((self.prev_samples[0] as i32 * 5) >> 2)
-                    + (self.prev_samples[1] as i32 * 2)
-                    - (self.prev_samples[1] as i32 >> 1)
-            }
-            _ => sample as i32,
-        };
+        let filter = (filter as usize).min(4);
+        let old = self.prev_samples[0] as i32;
+        let older = self.prev_samples[1] as i32;
+
+        let prediction = (old * POS_TABLE[filter] + older * NEG_TABLE[filter] + 32) >> 6;
+        let result = sample as i32 + prediction;
 
         // Clamp to i16 range
         let clamped = result.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
@@ -428,12 +426,12 @@ mod tests {
             ..Default::default()
         };
 
-        // Test filter 1: s + old[0] + (-old[0] >> 1)
+        // Test filter 1: s + (old[0] * 60 + 32) >> 6
         let sample = 0i16;
         let result = state.apply_filter(sample, 1);
 
-        // Expected: 0 + 8192 + (-8192 >> 1) = 0 + 8192 - 4096 = 4096
-        assert_eq!(result, 4096, "Filter 1 calculation should be correct");
+        // Expected: 0 + (8192 * 60 + 32) >> 6 = 7680
+        assert_eq!(result, 7680, "Filter 1 calculation should be correct");
         assert_eq!(
             state.prev_samples[0], result,
             "History should update with result"
@@ -459,8 +457,8 @@ mod tests {
 
         let samples = state.decode_block(&block);
 
-        // With shift=15, even max nibble should produce small values
-        // (7 << 12) >> 15 = 28672 >> 15 = 0
+        // Shift 15 behaves like shift 9, producing small values
+        // (7 << 12) >> 9 = 56
         for sample in samples {
             assert!(
                 sample.abs() < 100,
@@ -468,4 +466,37 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_decode_known_block_filter_2() {
+        let mut state = ADPCMState::default();
+
+        // Shift 8, filter 2; samples 4, -2, 0, 0, ...
+        let mut block = [0u8; 16];
+        block[0] = 0x28;
+        block[2] = 0xE4;
+
+        let samples = state.decode_block(&block);
+
+        // s0 = (4 << 12) >> 8 = 64
+        // s1 = (-2 << 12) >> 8 + (64 * 115 + 32) >> 6 = -32 + 115 = 83
+        // s2 = 0 + (83 * 115 - 64 * 52 + 32) >> 6 = 97
+        assert_eq!(&samples[..3], &[64, 83, 97]);
+    }
+
+    #[test]
+    fn test_decode_filter_4_and_clamped_filter_values() {
+        let mut block = [0u8; 16];
+        block[2] = 0x07; // First sample 7, rest 0
+
+        block[0] = 0x4C; // shift 12, filter 4
+        let filter4 = ADPCMState::default().decode_block(&block);
+
+        block[0] = 0x7C; // filter 7 behaves like filter 4
+        let filter7 = ADPCMState::default().decode_block(&block);
+
+        // s1 = (7 * 122 + 32) >> 6 = 13
+        assert_eq!(&filter4[..2], &[7, 13]);
+        assert_eq!(filter4, filter7);
+    }
 }
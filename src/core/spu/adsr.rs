@@ -19,14 +19,34 @@
 //! The envelope has four phases:
 //! - Attack: Volume rises from 0 to maximum
 //! - Decay: Volume falls from maximum to sustain level
-//! - Sustain: Volume holds at sustain level
+//! - Sustain: Volume rises or falls until key-off
 //! - Release: Volume falls from current level to 0
+//!
+//! # Envelope Steps
+//!
+//! Every phase is driven by a 7-bit rate plus a direction and a
+//! linear/exponential mode. The rate splits into a shift (bits 2-6) and a
+//! step (bits 0-1):
+//!
+//! ```text
+//! step   = 7 - (rate & 3)        (increase)
+//!        = -8 + (rate & 3)       (decrease)
+//! cycles = 1 << max(0, shift - 11)
+//! step   = step << max(0, 11 - shift)
+//!
+//! exponential increase above 0x6000: cycles *= 4
+//! exponential decrease:              step = step * level >> 15
+//! ```
+//!
+//! The level changes by `step` once every `cycles` samples. Lower rates are
+//! faster; decay and release rates are 4-/5-bit values scaled by 4.
+
+use crate::core::save_state::{ADSRState, StateSave};
 
 /// ADSR (Attack, Decay, Sustain, Release) envelope generator
 ///
 /// Controls the volume envelope for each voice over time.
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct ADSREnvelope {
     pub attack_rate: u8,
     pub attack_mode: AttackMode,
@@ -34,6 +54,7 @@ pub struct ADSREnvelope {
     pub sustain_level: u8,
     pub sustain_rate: u8,
     pub sustain_mode: SustainMode,
+    pub sustain_direction: SustainDirection,
     pub release_rate: u8,
     pub release_mode: ReleaseMode,
 
@@ -42,6 +63,9 @@ pub struct ADSREnvelope {
 
     /// Current envelope level (0-32767)
     pub level: i16,
+
+    /// Samples left before the next envelope step
+    pub counter: u32,
 }
 
 /// ADSR envelope phase
@@ -52,7 +76,7 @@ pub enum ADSRPhase {
     Attack,
     /// Decay phase: volume falling to sustain level
     Decay,
-    /// Sustain phase: volume held at sustain level
+    /// Sustain phase: volume rising or falling until key-off
     Sustain,
     /// Release phase: volume falling to zero
     Release,
@@ -74,6 +98,13 @@ pub enum SustainMode {
     Exponential,
 }
 
+/// Sustain direction (increase or decrease)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SustainDirection {
+    Increase,
+    Decrease,
+}
+
 /// Release mode (linear or exponential)
 #[derive(Debug, Clone, Copy)]
 pub enum ReleaseMode {
@@ -81,162 +112,119 @@ pub enum ReleaseMode {
     Exponential,
 }
 
-#[allow(dead_code)]
 impl ADSREnvelope {
+    /// Maximum envelope level
+    const MAX_LEVEL: i16 = 0x7FFF;
+
+    /// Start the envelope from silence (key-on)
+    pub fn key_on(&mut self) {
+        self.phase = ADSRPhase::Attack;
+        self.level = 0;
+        self.counter = 0;
+    }
+
+    /// Enter the release phase from the current level (key-off)
+    pub fn key_off(&mut self) {
+        if self.phase != ADSRPhase::Off {
+            self.phase = ADSRPhase::Release;
+            self.counter = 0;
+        }
+    }
+
     /// Advance the ADSR envelope by one sample
     ///
     /// Updates the current level based on the current phase and configured rates.
     /// Called once per audio sample (44100 Hz).
     pub fn tick(&mut self) {
         match self.phase {
-            ADSRPhase::Attack => self.tick_attack(),
-            ADSRPhase::Decay => self.tick_decay(),
-            ADSRPhase::Sustain => self.tick_sustain(),
-            ADSRPhase::Release => self.tick_release(),
-            ADSRPhase::Off => {}
-        }
-    }
+            ADSRPhase::Attack => {
+                let exponential = matches!(self.attack_mode, AttackMode::Exponential);
+                self.step(self.attack_rate, false, exponential);
 
-    /// Process attack phase
-    fn tick_attack(&mut self) {
-        let rate = self.attack_rate_to_step();
-
-        match self.attack_mode {
-            AttackMode::Linear => {
-                self.level = self.level.saturating_add(rate);
+                if self.level == Self::MAX_LEVEL {
+                    self.enter_phase(ADSRPhase::Decay);
+                }
             }
-            AttackMode::Exponential => {
-                // Exponential: increase rate scales with distance from max.
-                // When we're very close to max, the computed step can round to 0,
-                // which would otherwise leave us stuck in the attack phase.
-                let step = ((rate as i32 * (32767 - self.level as i32)) >> 15) as i16;
-                if step > 0 {
-                    self.level = self.level.saturating_add(step);
-                } else {
-                    self.level = 32767;
+            ADSRPhase::Decay => {
+                // Decay is always exponential in hardware
+                self.step(self.decay_rate << 2, true, true);
+
+                let sustain_level = self.sustain_target();
+                if self.level <= sustain_level {
+                    self.level = sustain_level;
+                    self.enter_phase(ADSRPhase::Sustain);
                 }
             }
-        }
-
-        if self.level == 32767 {
-            self.phase = ADSRPhase::Decay;
-        }
-    }
+            ADSRPhase::Sustain => {
+                let exponential = matches!(self.sustain_mode, SustainMode::Exponential);
+                let decrease = self.sustain_direction == SustainDirection::Decrease;
+                self.step(self.sustain_rate, decrease, exponential);
 
-    /// Process decay phase
-    fn tick_decay(&mut self) {
-        let rate = self.decay_rate_to_step();
-        let sustain_level = ((self.sustain_level as i32 + 1) << 11).min(32767) as i16;
-
-        // Decay is always exponential in hardware
-        let step = ((rate as i32 * self.level as i32) >> 15) as i16;
-        self.level = self.level.saturating_sub(step);
-
-        if self.level <= sustain_level {
-            self.level = sustain_level;
-            self.phase = ADSRPhase::Sustain;
-        }
-    }
-
-    /// Process sustain phase
-    fn tick_sustain(&mut self) {
-        let rate = self.sustain_rate_to_step();
-
-        match self.sustain_mode {
-            SustainMode::Linear => {
-                self.level = self.level.saturating_sub(rate);
-            }
-            SustainMode::Exponential => {
-                let step = ((rate as i32 * self.level as i32) >> 15) as i16;
-                self.level = self.level.saturating_sub(step);
+                if decrease && self.level == 0 {
+                    self.enter_phase(ADSRPhase::Off);
+                }
             }
-        }
-
-        if self.level <= 0 {
-            self.level = 0;
-            self.phase = ADSRPhase::Off;
-        }
-    }
-
-    /// Process release phase
-    fn tick_release(&mut self) {
-        let rate = self.release_rate_to_step();
+            ADSRPhase::Release => {
+                let exponential = matches!(self.release_mode, ReleaseMode::Exponential);
+                self.step(self.release_rate << 2, true, exponential);
 
-        match self.release_mode {
-            ReleaseMode::Linear => {
-                self.level = self.level.saturating_sub(rate);
-            }
-            ReleaseMode::Exponential => {
-                let step = ((rate as i32 * self.level as i32) >> 15) as i16;
-                self.level = self.level.saturating_sub(step);
+                if self.level == 0 {
+                    self.enter_phase(ADSRPhase::Off);
+                }
             }
-        }
-
-        if self.level <= 0 {
-            self.level = 0;
-            self.phase = ADSRPhase::Off;
+            ADSRPhase::Off => {}
         }
     }
 
-    /// Convert attack rate to step value
+    /// Level at which decay hands over to sustain
     ///
     /// # Returns
     ///
-    /// Step value to add per sample during attack phase
-    fn attack_rate_to_step(&self) -> i16 {
-        // PSX attack rate formula: simplified approximation
-        // Real hardware uses complex cycle counters
-        if self.attack_rate == 0 {
-            return 0;
-        }
-
-        // Higher rate = faster attack
-        // Rate 127 should reach max in ~1ms (44 samples)
-        // Rate 0 = infinite attack
-        let rate = self.attack_rate as i32;
-        ((32767 * rate) / (128 * 50)) as i16
+    /// `(sustain_level + 1) * 0x800`, capped at the maximum level
+    pub fn sustain_target(&self) -> i16 {
+        ((self.sustain_level as i32 + 1) << 11).min(Self::MAX_LEVEL as i32) as i16
     }
 
-    /// Convert decay rate to step value
-    ///
-    /// # Returns
-    ///
-    /// Step value for decay phase
-    fn decay_rate_to_step(&self) -> i16 {
-        if self.decay_rate == 0 {
-            return 0;
-        }
-
-        let rate = self.decay_rate as i32;
-        ((32767 * rate) / (16 * 200)) as i16
+    /// Switch to a new phase, restarting the step counter
+    fn enter_phase(&mut self, phase: ADSRPhase) {
+        self.phase = phase;
+        self.counter = 0;
     }
 
-    /// Convert sustain rate to step value
+    /// Apply one sample of envelope change for the given rate
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// Step value for sustain phase
-    fn sustain_rate_to_step(&self) -> i16 {
-        if self.sustain_rate == 0 {
-            return 0;
+    /// * `rate` - 7-bit envelope rate (lower is faster)
+    /// * `decrease` - Whether the level falls instead of rising
+    /// * `exponential` - Whether exponential mode is selected
+    fn step(&mut self, rate: u8, decrease: bool, exponential: bool) {
+        if self.counter > 0 {
+            self.counter -= 1;
+            return;
         }
 
-        let rate = self.sustain_rate as i32;
-        ((32767 * rate) / (128 * 200)) as i16
-    }
+        let rate = (rate & 0x7F) as i32;
+        let shift = rate >> 2;
+        let base = if decrease {
+            -8 + (rate & 3)
+        } else {
+            7 - (rate & 3)
+        };
 
-    /// Convert release rate to step value
-    ///
-    /// # Returns
-    ///
-    /// Step value for release phase
-    fn release_rate_to_step(&self) -> i16 {
-        if self.release_rate == 0 {
-            return 0;
+        let mut cycles = 1u32 << (shift - 11).max(0);
+        let mut step = base << (11 - shift).max(0);
+
+        if exponential {
+            if decrease {
+                step = (step * self.level as i32) >> 15;
+            } else if self.level > 0x6000 {
+                cycles *= 4;
+            }
         }
 
-        let rate = self.release_rate as i32;
-        ((32767 * rate) / (32 * 200)) as i16
+        self.level = (self.level as i32 + step).clamp(0, Self::MAX_LEVEL as i32) as i16;
+        self.counter = cycles - 1;
     }
 
     /// Convert ADSR configuration to register format (word 1)
@@ -295,6 +283,11 @@ impl ADSREnvelope {
             0
         };
         value |= ((self.sustain_rate as u16) & 0x7F) << 6;
+        value |= if self.sustain_direction == SustainDirection::Decrease {
+            1 << 14
+        } else {
+            0
+        };
         value |= if matches!(self.sustain_mode, SustainMode::Exponential) {
             1 << 15
         } else {
@@ -333,6 +326,11 @@ impl ADSREnvelope {
             ReleaseMode::Linear
         };
         self.sustain_rate = ((value >> 6) & 0x7F) as u8;
+        self.sustain_direction = if (value & (1 << 14)) != 0 {
+            SustainDirection::Decrease
+        } else {
+            SustainDirection::Increase
+        };
         self.sustain_mode = if (value & (1 << 15)) != 0 {
             SustainMode::Exponential
         } else {
@@ -341,6 +339,40 @@ impl ADSREnvelope {
     }
 }
 
+impl StateSave for ADSREnvelope {
+    type State = ADSRState;
+
+    fn to_state(&self) -> ADSRState {
+        ADSRState {
+            word_1: self.to_word_1(),
+            word_2: self.to_word_2(),
+            phase: match self.phase {
+                ADSRPhase::Attack => 0,
+                ADSRPhase::Decay => 1,
+                ADSRPhase::Sustain => 2,
+                ADSRPhase::Release => 3,
+                ADSRPhase::Off => 4,
+            },
+            level: self.level,
+            counter: self.counter,
+        }
+    }
+
+    fn restore_from_state(&mut self, state: &ADSRState) {
+        self.set_word_1(state.word_1);
+        self.set_word_2(state.word_2);
+        self.phase = match state.phase {
+            0 => ADSRPhase::Attack,
+            1 => ADSRPhase::Decay,
+            2 => ADSRPhase::Sustain,
+            3 => ADSRPhase::Release,
+            _ => ADSRPhase::Off,
+        };
+        self.level = state.level;
+        self.counter = state.counter;
+    }
+}
+
 impl Default for ADSREnvelope {
     fn default() -> Self {
         Self {
@@ -350,10 +382,12 @@ impl Default for ADSREnvelope {
             sustain_level: 0,
             sustain_rate: 0,
             sustain_mode: SustainMode::Linear,
+            sustain_direction: SustainDirection::Increase,
             release_rate: 0,
             release_mode: ReleaseMode::Linear,
             phase: ADSRPhase::Off,
             level: 0,
+            counter: 0,
         }
     }
 }
@@ -384,7 +418,7 @@ mod tests {
 
         // Transition to Attack
         env.phase = ADSRPhase::Attack;
-        env.attack_rate = 127;
+        env.attack_rate = 0; // Fastest rate
         env.attack_mode = AttackMode::Linear;
 
         // Tick until max level
@@ -444,7 +478,7 @@ mod tests {
     fn test_attack_reaches_maximum() {
         let mut env = ADSREnvelope::default();
         env.phase = ADSRPhase::Attack;
-        env.attack_rate = 127; // Maximum rate
+        env.attack_rate = 0; // Fastest rate
         env.attack_mode = AttackMode::Linear;
         env.level = 0;
 
@@ -521,6 +555,7 @@ mod tests {
         env.level = 10000;
         env.sustain_rate = 16;
         env.sustain_mode = SustainMode::Linear;
+        env.sustain_direction = SustainDirection::Decrease;
 
         let initial_level = env.level;
         env.tick();
@@ -539,6 +574,7 @@ mod tests {
         env.level = 10000;
         env.sustain_rate = 16;
         env.sustain_mode = SustainMode::Exponential;
+        env.sustain_direction = SustainDirection::Decrease;
 
         let initial_level = env.level;
         env.tick();
@@ -557,6 +593,7 @@ mod tests {
         env.level = 100;
         env.sustain_rate = 64;
         env.sustain_mode = SustainMode::Linear;
+        env.sustain_direction = SustainDirection::Decrease;
 
         // Tick until we reach zero or timeout
         for _ in 0..10000 {
@@ -612,7 +649,7 @@ mod tests {
         let mut env = ADSREnvelope::default();
         env.phase = ADSRPhase::Release;
         env.level = 100;
-        env.release_rate = 31; // Max rate
+        env.release_rate = 0; // Fastest rate
         env.release_mode = ReleaseMode::Linear;
 
         // Tick until we reach zero or timeout
@@ -642,17 +679,21 @@ mod tests {
     }
 
     #[test]
-    fn test_rate_zero_no_change() {
+    fn test_slowest_rate_waits_between_steps() {
         let mut env = ADSREnvelope::default();
         env.phase = ADSRPhase::Attack;
-        env.attack_rate = 0; // Zero rate
+        env.attack_rate = 0x7F; // Slowest rate: +4 every 2^20 samples
         env.attack_mode = AttackMode::Linear;
         env.level = 0;
 
         env.tick();
+        assert_eq!(env.level, 4);
 
-        // Zero rate should not change level
-        assert_eq!(env.level, 0, "Zero rate should not change level");
+        for _ in 0..10000 {
+            env.tick();
+        }
+
+        assert_eq!(env.level, 4, "Slowest rate should wait between steps");
         assert_eq!(env.phase, ADSRPhase::Attack, "Should stay in same phase");
     }
 
@@ -751,7 +792,7 @@ mod tests {
         let mut env = ADSREnvelope::default();
         env.phase = ADSRPhase::Decay;
         env.level = 32767;
-        env.decay_rate = 15;
+        env.decay_rate = 0; // Fastest decay
 
         // Test various sustain levels
         for sustain_level in 0..=15u8 {
@@ -780,7 +821,7 @@ mod tests {
     fn test_level_saturation() {
         let mut env = ADSREnvelope::default();
         env.phase = ADSRPhase::Attack;
-        env.attack_rate = 127;
+        env.attack_rate = 0;
         env.attack_mode = AttackMode::Linear;
         env.level = 32760;
 
@@ -816,4 +857,128 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_rate_table_steps() {
+        // Rate 0x2D: shift 11, step 7-1 = 6 every sample
+        let mut env = ADSREnvelope::default();
+        env.phase = ADSRPhase::Attack;
+        env.attack_rate = 0x2D;
+        env.tick();
+        env.tick();
+        assert_eq!(env.level, 12);
+
+        // Rate 0x34: shift 13, step 7 every 4 samples
+        env.attack_rate = 0x34;
+        env.level = 0;
+        env.counter = 0;
+        for _ in 0..8 {
+            env.tick();
+        }
+        assert_eq!(env.level, 14);
+
+        // Rate 0x20: shift 8, step 7 << 3 = 56 per sample
+        env.attack_rate = 0x20;
+        env.level = 0;
+        env.counter = 0;
+        env.tick();
+        assert_eq!(env.level, 56);
+    }
+
+    #[test]
+    fn test_exponential_attack_slows_above_0x6000() {
+        let mut env = ADSREnvelope::default();
+        env.phase = ADSRPhase::Attack;
+        env.attack_rate = 0x2C; // +7 every sample
+        env.attack_mode = AttackMode::Exponential;
+
+        env.level = 0x5000;
+        for _ in 0..8 {
+            env.tick();
+        }
+        assert_eq!(env.level, 0x5000 + 8 * 7);
+
+        // Above 0x6000 the same rate steps only every 4th sample
+        env.level = 0x6800;
+        env.counter = 0;
+        for _ in 0..8 {
+            env.tick();
+        }
+        assert_eq!(env.level, 0x6800 + 2 * 7);
+    }
+
+    #[test]
+    fn test_attack_then_release_curve() {
+        let mut env = ADSREnvelope::default();
+        env.set_word_1(0x0A8F); // Linear attack rate 0x0A, decay 8, sustain level 15
+        env.set_word_2(0x0006); // Linear release rate 6
+        env.key_on();
+
+        // Linear attack: constant increments until the maximum
+        let mut attack = vec![env.level];
+        while env.phase == ADSRPhase::Attack {
+            env.tick();
+            attack.push(env.level);
+        }
+        let first_step = attack[1] - attack[0];
+        assert!(first_step > 0);
+        assert!(attack
+            .windows(2)
+            .take(attack.len() - 2)
+            .all(|w| w[1] - w[0] == first_step));
+        assert_eq!(env.level, 0x7FFF);
+
+        // Sustain level 15 is the maximum, so decay ends immediately
+        env.tick();
+        assert_eq!(env.phase, ADSRPhase::Sustain);
+
+        // Linear release falls monotonically to silence
+        env.key_off();
+        let mut release = vec![env.level];
+        while env.phase == ADSRPhase::Release {
+            env.tick();
+            release.push(env.level);
+        }
+        assert!(release.windows(2).all(|w| w[1] <= w[0]));
+        assert_eq!(env.level, 0);
+        assert_eq!(env.phase, ADSRPhase::Off);
+    }
+
+    #[test]
+    fn test_exponential_release_slows_near_zero() {
+        let mut env = ADSREnvelope::default();
+        env.phase = ADSRPhase::Release;
+        env.release_rate = 0x08; // Rate 0x20: step -64, scaled by level
+        env.release_mode = ReleaseMode::Exponential;
+        env.level = 0x7FFF;
+
+        env.tick();
+        let high_drop = 0x7FFF - env.level;
+
+        env.level = 0x1000;
+        env.tick();
+        let low_drop = 0x1000 - env.level;
+
+        assert_eq!(high_drop, 64);
+        assert_eq!(low_drop, 8);
+    }
+
+    #[test]
+    fn test_key_off_when_silent_stays_off() {
+        let mut env = ADSREnvelope::default();
+        env.key_off();
+        assert_eq!(env.phase, ADSRPhase::Off);
+
+        env.key_on();
+        env.key_off();
+        assert_eq!(env.phase, ADSRPhase::Release);
+    }
+
+    #[test]
+    fn test_sustain_direction_round_trip() {
+        let mut env = ADSREnvelope::default();
+        env.set_word_2(1 << 14);
+        assert_eq!(env.sustain_direction, SustainDirection::Decrease);
+        assert_eq!(env.to_word_2(), 1 << 14);
+    }
 }
@@ -30,8 +30,20 @@
 //! | 0x1F801D80-0x1F801D83  | Main volume L/R        | R/W    |
 //! | 0x1F801D84-0x1F801D87  | Reverb volume L/R      | R/W    |
 //! | 0x1F801D88-0x1F801D8F  | Voice key on/off       | W      |
+//! | 0x1F801D90-0x1F801D93  | Pitch modulation (PMON)| R/W    |
+//! | 0x1F801D94-0x1F801D97  | Noise mode (NON)       | R/W    |
+//! | 0x1F801D98-0x1F801D9B  | Reverb mode (EON)      | R/W    |
+//! | 0x1F801DA2             | Reverb work area start | R/W    |
+//! | 0x1F801DA4             | IRQ address            | R/W    |
 //! | 0x1F801DAA             | Control register       | R/W    |
 //! | 0x1F801DAE             | Status register        | R      |
+//! | 0x1F801DC0-0x1F801DFF  | Reverb configuration   | R/W    |
+//!
+//! # Audio Output
+//!
+//! Mixed samples are produced at 44100 Hz stereo and queued in an internal
+//! ring buffer. A frontend audio thread pulls them with
+//! [`SPU::drain_samples`].
 //!
 //! # Voice Registers (per voice, 16 bytes each)
 //!
@@ -52,12 +64,15 @@ mod noise;
 mod registers;
 mod reverb;
 mod voice;
+mod wav;
 
+use crate::core::save_state::{SPUState, StateSave};
 use noise::NoiseGenerator;
 use registers::{SPUControl, SPUStatus, TransferMode};
 use reverb::ReverbConfig;
 use std::collections::VecDeque;
 use voice::Voice;
+pub use wav::WavWriter;
 
 /// SPU (Sound Processing Unit)
 ///
@@ -100,8 +115,7 @@ pub struct SPU {
     /// Status register
     status: SPUStatus,
 
-    /// Current sample position
-    #[allow(dead_code)]
+    /// CPU cycles accumulated toward the next output sample
     sample_counter: u32,
 
     /// Capture buffers
@@ -111,14 +125,38 @@ pub struct SPU {
     /// DMA transfer address (in 8-byte units)
     transfer_addr: u32,
 
+    /// IRQ address (in 8-byte units)
+    irq_address: u16,
+
+    /// SPU interrupt raised since the last poll
+    irq_pending: bool,
+
     /// DMA FIFO for buffered writes
     dma_fifo: VecDeque<u16>,
+
+    /// Mixed output waiting to be drained (interleaved left/right)
+    output_buffer: VecDeque<i16>,
+
+    /// Stereo frames discarded because the output buffer was full
+    overrun_frames: u64,
+
+    /// Stereo frames requested while the output buffer was empty
+    underrun_frames: u64,
 }
 
 impl SPU {
     /// SPU RAM size (512KB)
     const RAM_SIZE: usize = 512 * 1024;
 
+    /// CPU cycles per output sample (33.8688 MHz / 44.1 kHz)
+    const CYCLES_PER_SAMPLE: u32 = 768;
+
+    /// Output sample rate in Hz (stereo frames per second)
+    pub const SAMPLE_RATE: u32 = 44_100;
+
+    /// Output ring buffer capacity in stereo frames (~186 ms)
+    const OUTPUT_BUFFER_FRAMES: usize = 8192;
+
     /// Create a new SPU instance
     ///
     /// # Returns
@@ -143,7 +181,12 @@ impl SPU {
             sample_counter: 0,
             capture_buffer: [0; 2],
             transfer_addr: 0,
+            irq_address: 0,
+            irq_pending: false,
             dma_fifo: VecDeque::new(),
+            output_buffer: VecDeque::with_capacity(Self::OUTPUT_BUFFER_FRAMES * 2),
+            overrun_frames: 0,
+            underrun_frames: 0,
         }
     }
 
@@ -180,6 +223,20 @@ impl SPU {
             0x1F801D8C => 0, // VOICE_KEY_OFF (lower)
             0x1F801D8E => 0, // VOICE_KEY_OFF (upper)
 
+            // Pitch modulation (PMON), noise mode (NON) and reverb mode (EON)
+            0x1F801D90 => self.voice_flags(|v| v.pitch_modulation) as u16,
+            0x1F801D92 => (self.voice_flags(|v| v.pitch_modulation) >> 16) as u16,
+            0x1F801D94 => self.voice_flags(|v| v.noise_enabled) as u16,
+            0x1F801D96 => (self.voice_flags(|v| v.noise_enabled) >> 16) as u16,
+            0x1F801D98 => self.voice_flags(|v| v.reverb_enabled) as u16,
+            0x1F801D9A => (self.voice_flags(|v| v.reverb_enabled) >> 16) as u16,
+
+            // Reverb work area start (in 8-byte units)
+            0x1F801DA2 => (self.reverb.reverb_start_addr / 8) as u16,
+
+            // IRQ address (in 8-byte units)
+            0x1F801DA4 => self.irq_address,
+
             // Control/Status
             0x1F801DAA => self.read_control(),
             0x1F801DAE => self.read_status(),
@@ -191,6 +248,11 @@ impl SPU {
             // DMA Data Register (0x1F801DA8) - write-only, reads return 0
             0x1F801DA8 => 0,
 
+            // Reverb registers (0x1F801DC0-0x1F801DFF)
+            0x1F801DC0..=0x1F801DFF => self
+                .reverb
+                .read_register(((addr - 0x1F801DC0) / 2) as usize),
+
             _ => {
                 log::warn!("SPU read from unknown register: 0x{:08X}", addr);
                 0
@@ -231,6 +293,25 @@ impl SPU {
             // Voice key off (upper 8 voices, bits 16-23)
             0x1F801D8E => self.key_off_voices((value as u32) << 16),
 
+            // Pitch modulation (PMON, lower 16 / upper 8 voices)
+            // Voice 0 has no previous voice, so its PMON bit is ignored
+            0x1F801D90 => self.write_voice_flags(0, value & !1, |v| &mut v.pitch_modulation),
+            0x1F801D92 => self.write_voice_flags(16, value, |v| &mut v.pitch_modulation),
+
+            // Noise mode (NON, lower 16 / upper 8 voices)
+            0x1F801D94 => self.write_voice_flags(0, value, |v| &mut v.noise_enabled),
+            0x1F801D96 => self.write_voice_flags(16, value, |v| &mut v.noise_enabled),
+
+            // Reverb mode (EON, lower 16 / upper 8 voices)
+            0x1F801D98 => self.write_voice_flags(0, value, |v| &mut v.reverb_enabled),
+            0x1F801D9A => self.write_voice_flags(16, value, |v| &mut v.reverb_enabled),
+
+            // Reverb work area start (in 8-byte units)
+            0x1F801DA2 => self.reverb.set_base(value),
+
+            // IRQ address (in 8-byte units)
+            0x1F801DA4 => self.irq_address = value,
+
             // Control
             0x1F801DAA => self.write_control(value),
 
@@ -246,7 +327,9 @@ impl SPU {
             }
 
             // Reverb registers (0x1F801DC0-0x1F801DFF)
-            0x1F801DC0..=0x1F801DFF => self.write_reverb_register(addr, value),
+            0x1F801DC0..=0x1F801DFF => self
+                .reverb
+                .write_register(((addr - 0x1F801DC0) / 2) as usize, value),
 
             _ => {
                 log::warn!(
@@ -340,6 +423,36 @@ impl SPU {
         }
     }
 
+    /// Write one of the per-voice flag registers (PMON, NON, EON) for 16 voices
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - First voice covered by the register (0 or 16)
+    /// * `value` - One bit per voice
+    /// * `flag` - Selects the voice flag the register controls
+    fn write_voice_flags(&mut self, first: usize, value: u16, flag: fn(&mut Voice) -> &mut bool) {
+        for (bit, voice) in self.voices.iter_mut().skip(first).take(16).enumerate() {
+            *flag(voice) = (value & (1 << bit)) != 0;
+        }
+    }
+
+    /// Read one of the per-voice flag registers (PMON, NON, EON)
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Selects the voice flag to collect
+    ///
+    /// # Returns
+    ///
+    /// Bitmask with bit N set when the flag is set on voice N
+    fn voice_flags(&self, flag: fn(&Voice) -> bool) -> u32 {
+        self.voices
+            .iter()
+            .enumerate()
+            .filter(|(_, voice)| flag(voice))
+            .fold(0, |mask, (i, _)| mask | (1 << i))
+    }
+
     /// Read SPU control register
     ///
     /// # Returns
@@ -393,6 +506,10 @@ impl SPU {
         self.control.noise_step = ((value >> 8) & 0x3) as u8;
         self.control.reverb_enabled = (value & (1 << 7)) != 0;
         self.control.irq_enabled = (value & (1 << 6)) != 0;
+        // Clearing the IRQ enable bit acknowledges a pending IRQ
+        if !self.control.irq_enabled {
+            self.status.irq_flag = false;
+        }
         // Bits 5-4: transfer mode
         self.control.transfer_mode = match (value >> 4) & 0x3 {
             1 => TransferMode::ManualWrite,
@@ -420,41 +537,6 @@ impl SPU {
         );
     }
 
-    /// Write reverb configuration register
-    ///
-    /// # Arguments
-    ///
-    /// * `addr` - Register address
-    /// * `value` - 16-bit value to write
-    fn write_reverb_register(&mut self, addr: u32, value: u16) {
-        match addr {
-            0x1F801DC0 => self.reverb.apf_offset1 = value,
-            0x1F801DC2 => self.reverb.apf_offset2 = value,
-            0x1F801DC4 => self.reverb.reflect_volume1 = value as i16,
-            0x1F801DC6 => self.reverb.comb_volume1 = value as i16,
-            0x1F801DC8 => self.reverb.comb_volume2 = value as i16,
-            0x1F801DCA => self.reverb.comb_volume3 = value as i16,
-            0x1F801DCC => self.reverb.comb_volume4 = value as i16,
-            0x1F801DCE => self.reverb.apf_volume1 = value as i16,
-            0x1F801DD0 => self.reverb.apf_volume2 = value as i16,
-            0x1F801DD2 => self.reverb.input_volume_left = value as i16,
-            0x1F801DD4 => self.reverb.input_volume_right = value as i16,
-            0x1F801DD6 => self.reverb.reflect_volume2 = value as i16,
-            0x1F801DD8 => self.reverb.reflect_volume3 = value as i16,
-            0x1F801DDA => self.reverb.reflect_volume4 = value as i16,
-            // Reverb work area address
-            0x1F801DDC => self.reverb.reverb_start_addr = (value as u32) * 8,
-            0x1F801DDE => self.reverb.reverb_end_addr = (value as u32) * 8,
-            _ => {
-                log::debug!(
-                    "Unknown reverb register write: 0x{:08X} = 0x{:04X}",
-                    addr,
-                    value
-                );
-            }
-        }
-    }
-
     /// Read SPU status register
     ///
     /// # Returns
@@ -525,19 +607,13 @@ impl SPU {
             return Vec::new();
         }
 
-        // Calculate number of samples to generate
-        // CPU frequency: 33.8688 MHz (33_868_800 Hz)
-        // SPU frequency: 44.1 kHz (44_100 Hz)
-        // Ratio: 44100 / 33868800 ≈ 0.001302
-        const CPU_FREQ: f32 = 33_868_800.0;
-        const SPU_FREQ: f32 = 44_100.0;
-
-        let samples_to_generate = (cycles as f32 * SPU_FREQ / CPU_FREQ) as usize;
+        let samples_to_generate = self.samples_for_cycles(cycles);
 
         let mut output = Vec::with_capacity(samples_to_generate);
 
         for _ in 0..samples_to_generate {
             let sample = self.generate_sample();
+            self.push_output(sample);
             output.push(sample);
         }
 
@@ -578,42 +654,194 @@ impl SPU {
             return Vec::new();
         }
 
-        // Calculate number of samples to generate
-        const CPU_FREQ: f32 = 33_868_800.0;
-        const SPU_FREQ: f32 = 44_100.0;
-
-        let samples_to_generate = (cycles as f32 * SPU_FREQ / CPU_FREQ) as usize;
+        let samples_to_generate = self.samples_for_cycles(cycles);
 
         let mut output = Vec::with_capacity(samples_to_generate);
 
         for _ in 0..samples_to_generate {
             let sample = self.generate_sample_with_cd(cd_audio);
+            self.push_output(sample);
             output.push(sample);
         }
 
         output
     }
 
-    /// Generate a single stereo sample
+    /// Queue a mixed sample in the output ring buffer
     ///
-    /// Mixes all 24 voices, applies main volume, and processes reverb.
+    /// When the buffer is full the oldest frame is dropped, so a stalled
+    /// consumer hears the most recent audio once it resumes.
+    ///
+    /// # Arguments
+    ///
+    /// * `sample` - Stereo sample (left, right)
+    fn push_output(&mut self, (left, right): (i16, i16)) {
+        if self.output_buffer.len() >= Self::OUTPUT_BUFFER_FRAMES * 2 {
+            self.output_buffer.drain(..2);
+            self.overrun_frames += 1;
+        }
+
+        self.output_buffer.push_back(left);
+        self.output_buffer.push_back(right);
+    }
+
+    /// Pull mixed audio from the output ring buffer
+    ///
+    /// Fills `out` with interleaved stereo samples (left, right, left, ...)
+    /// at 44100 Hz. If fewer frames are buffered than requested, the rest of
+    /// `out` is filled with silence and counted as an underrun.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - Destination buffer of interleaved stereo samples
     ///
     /// # Returns
     ///
-    /// Stereo sample (left, right)
+    /// Number of stereo frames copied from the ring buffer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::SPU;
+    ///
+    /// let mut spu = SPU::new();
+    /// spu.write_register(0x1F801DAA, 0xC000); // Enable SPU
+    /// spu.tick(768 * 4); // Mix four frames
+    ///
+    /// let mut out = [0i16; 16];
+    /// assert_eq!(spu.drain_samples(&mut out), 4);
+    /// ```
+    pub fn drain_samples(&mut self, out: &mut [i16]) -> usize {
+        let requested = out.len() / 2;
+        let frames = requested.min(self.output_buffer.len() / 2);
+
+        for (dst, src) in out.iter_mut().zip(self.output_buffer.drain(..frames * 2)) {
+            *dst = src;
+        }
+        out[frames * 2..].fill(0);
+
+        self.underrun_frames += (requested - frames) as u64;
+        frames
+    }
+
+    /// Get the number of stereo frames waiting in the output buffer
+    ///
+    /// # Returns
+    ///
+    /// Buffered frame count
+    pub fn buffered_frames(&self) -> usize {
+        self.output_buffer.len() / 2
+    }
+
+    /// Get output buffer overrun and underrun counts
+    ///
+    /// # Returns
+    ///
+    /// Tuple of (frames dropped because the buffer was full,
+    /// frames of silence returned because the buffer was empty)
+    pub fn output_stats(&self) -> (u64, u64) {
+        (self.overrun_frames, self.underrun_frames)
+    }
+
+    /// Advance the output sample clock
+    ///
+    /// One 44.1 kHz sample is produced every 768 CPU cycles. Leftover cycles
+    /// carry over to the next call so short ticks don't lose samples.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycles` - Number of CPU cycles elapsed
+    ///
+    /// # Returns
+    ///
+    /// Number of samples to generate
+    fn samples_for_cycles(&mut self, cycles: u32) -> usize {
+        self.sample_counter += cycles;
+        let samples = self.sample_counter / Self::CYCLES_PER_SAMPLE;
+        self.sample_counter %= Self::CYCLES_PER_SAMPLE;
+        samples as usize
+    }
+
+    /// Mix all 24 voices and the reverb unit into one stereo sample
+    ///
+    /// Each voice renders its ADPCM (or noise) sample at its own pitch with
+    /// its ADSR envelope and left/right volume applied. A voice with PMON
+    /// set takes the previous voice's output as its pitch modulator. Voices with reverb
+    /// mode (EON) set also feed the reverb input, whose output is scaled by
+    /// the reverb output volume and added to the mix.
+    ///
+    /// # Arguments
+    ///
+    /// * `reverb_input` - Additional reverb input (CD audio routed to reverb)
+    ///
+    /// # Returns
+    ///
+    /// Sum of all voice and reverb outputs (left, right), before main volume
     #[inline(always)]
-    fn generate_sample(&mut self) -> (i16, i16) {
+    fn mix_voices(&mut self, reverb_input: (i64, i64)) -> (i64, i64) {
         // Use i64 to avoid overflow when mixing 24 voices at high volume
         let mut left: i64 = 0;
         let mut right: i64 = 0;
+        let (mut reverb_left, mut reverb_right) = reverb_input;
+        let irq_addr = self.irq_address as u32 * 8;
+        let mut irq_hit = false;
+
+        // The noise generator is shared by all voices and clocked once per sample
+        self.noise.tick();
+
+        // Each voice's output modulates the next voice's pitch when PMON is set
+        let mut modulator = 0;
 
-        // Mix all 24 voices
         for voice in &mut self.voices {
-            let (v_left, v_right) = voice.render_sample(&self.ram, &mut self.noise);
+            let (v_left, v_right) = voice.render_sample(&self.ram, &self.noise, modulator);
+            modulator = voice.output;
+
+            // Decoding a block that contains the IRQ address triggers IRQ9
+            if let Some(block_addr) = voice.decoded_block.take() {
+                irq_hit |= (block_addr..block_addr + 16).contains(&irq_addr);
+            }
+
             left += v_left as i64;
             right += v_right as i64;
+
+            if voice.reverb_enabled {
+                reverb_left += v_left as i64;
+                reverb_right += v_right as i64;
+            }
+        }
+
+        if irq_hit {
+            self.trigger_irq();
+        }
+
+        // Reverb keeps running (and writing its work area) even while muted
+        let (wet_left, wet_right) = self.reverb.process(
+            reverb_left.clamp(i16::MIN as i64, i16::MAX as i64) as i16,
+            reverb_right.clamp(i16::MIN as i64, i16::MAX as i64) as i16,
+            &mut self.ram,
+        );
+        left += (wet_left as i64 * self.reverb_volume_left as i64) >> 15;
+        right += (wet_right as i64 * self.reverb_volume_right as i64) >> 15;
+
+        // Voices keep running while muted, but produce no output
+        if !self.control.unmute {
+            return (0, 0);
         }
 
+        (left, right)
+    }
+
+    /// Generate a single stereo sample
+    ///
+    /// Mixes all 24 voices and reverb, then applies main volume.
+    ///
+    /// # Returns
+    ///
+    /// Stereo sample (left, right)
+    #[inline(always)]
+    fn generate_sample(&mut self) -> (i16, i16) {
+        let (mut left, mut right) = self.mix_voices((0, 0));
+
         // Apply main volume (fixed-point multiply with 15-bit fraction)
         left = (left * self.main_volume_left as i64) >> 15;
         right = (right * self.main_volume_right as i64) >> 15;
@@ -622,14 +850,14 @@ impl SPU {
         left = left.clamp(i16::MIN as i64, i16::MAX as i64);
         right = right.clamp(i16::MIN as i64, i16::MAX as i64);
 
-        // Apply reverb
-        self.reverb
-            .process(left as i16, right as i16, &mut self.ram)
+        (left as i16, right as i16)
     }
 
     /// Generate a single stereo sample with CD audio mixing
     ///
-    /// Mixes all 24 voices, CD audio, applies main volume, and processes reverb.
+    /// Mixes all 24 voices and reverb, applies main volume, and adds CD
+    /// audio. CD audio is also fed to the reverb input when CD reverb is
+    /// enabled in SPUCNT.
     ///
     /// # Arguments
     ///
@@ -643,40 +871,35 @@ impl SPU {
         &mut self,
         cd_audio: &mut crate::core::cdrom::CDAudio,
     ) -> (i16, i16) {
-        // Use i64 to avoid overflow when mixing 24 voices at high volume
-        let mut left: i64 = 0;
-        let mut right: i64 = 0;
-
-        // Mix all 24 voices
-        for voice in &mut self.voices {
-            let (v_left, v_right) = voice.render_sample(&self.ram, &mut self.noise);
-            left += v_left as i64;
-            right += v_right as i64;
-        }
-
-        // Apply main volume (fixed-point multiply with 15-bit fraction)
-        left = (left * self.main_volume_left as i64) >> 15;
-        right = (right * self.main_volume_right as i64) >> 15;
-
         // Mix CD audio if enabled
-        if self.control.cd_audio_enabled {
+        let (cd_left, cd_right) = if self.control.cd_audio_enabled {
             let (cd_left, cd_right) = cd_audio.get_sample();
 
             // Apply CD volume (fixed-point multiply with 15-bit fraction)
-            let cd_left = (cd_left as i64 * self.cd_volume_left as i64) >> 15;
-            let cd_right = (cd_right as i64 * self.cd_volume_right as i64) >> 15;
+            (
+                (cd_left as i64 * self.cd_volume_left as i64) >> 15,
+                (cd_right as i64 * self.cd_volume_right as i64) >> 15,
+            )
+        } else {
+            (0, 0)
+        };
 
-            left += cd_left;
-            right += cd_right;
-        }
+        let reverb_input = if self.control.cd_audio_reverb {
+            (cd_left, cd_right)
+        } else {
+            (0, 0)
+        };
+        let (mut left, mut right) = self.mix_voices(reverb_input);
+
+        // Apply main volume (fixed-point multiply with 15-bit fraction)
+        left = ((left * self.main_volume_left as i64) >> 15) + cd_left;
+        right = ((right * self.main_volume_right as i64) >> 15) + cd_right;
 
         // Clamp to i16 range
         left = left.clamp(i16::MIN as i64, i16::MAX as i64);
         right = right.clamp(i16::MIN as i64, i16::MAX as i64);
 
-        // Apply reverb
-        self.reverb
-            .process(left as i16, right as i16, &mut self.ram)
+        (left as i16, right as i16)
     }
 
     // DMA Interface Methods
@@ -756,9 +979,11 @@ impl SPU {
     /// let value = spu.dma_read();
     /// ```
     pub fn dma_read(&mut self) -> u32 {
+        self.check_transfer_irq(self.transfer_addr);
         let lo = self.read_ram_word(self.transfer_addr);
         self.transfer_addr = (self.transfer_addr + 2) & 0x7FFFE;
 
+        self.check_transfer_irq(self.transfer_addr);
         let hi = self.read_ram_word(self.transfer_addr);
         self.transfer_addr = (self.transfer_addr + 2) & 0x7FFFE;
 
@@ -805,6 +1030,7 @@ impl SPU {
     /// * `value` - 16-bit value to write
     #[inline(always)]
     pub(crate) fn write_ram_word(&mut self, addr: u32, value: u16) {
+        self.check_transfer_irq(addr);
         let addr = (addr as usize) & 0x7FFFE;
         self.ram[addr] = value as u8;
         self.ram[addr + 1] = (value >> 8) as u8;
@@ -830,6 +1056,41 @@ impl SPU {
         // SPU is always ready for DMA
         true
     }
+
+    /// Check a transfer access against the IRQ address
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - Byte address in SPU RAM being read or written
+    #[inline(always)]
+    fn check_transfer_irq(&mut self, addr: u32) {
+        if (addr & 0x7FFF8) == self.irq_address as u32 * 8 {
+            self.trigger_irq();
+        }
+    }
+
+    /// Raise the SPU interrupt (IRQ9) if enabled
+    ///
+    /// The status flag stays set until acknowledged by clearing SPUCNT
+    /// bit 6, so the interrupt fires only once per acknowledge.
+    fn trigger_irq(&mut self) {
+        if self.control.irq_enabled && !self.status.irq_flag {
+            self.status.irq_flag = true;
+            self.irq_pending = true;
+            log::trace!("SPU IRQ at address 0x{:05X}", self.irq_address as u32 * 8);
+        }
+    }
+
+    /// Poll the SPU interrupt
+    ///
+    /// Returns whether IRQ9 was raised since the last poll and clears it.
+    ///
+    /// # Returns
+    ///
+    /// true if the interrupt controller should be signalled
+    pub fn poll_interrupt(&mut self) -> bool {
+        std::mem::take(&mut self.irq_pending)
+    }
 }
 
 impl Default for SPU {
@@ -837,3 +1098,351 @@ impl Default for SPU {
         Self::new()
     }
 }
+
+impl StateSave for SPU {
+    type State = SPUState;
+
+    fn to_state(&self) -> SPUState {
+        SPUState {
+            ram: self.ram.clone(),
+            voices: self.voices.iter().map(|voice| voice.to_state()).collect(),
+            main_volume_left: self.main_volume_left,
+            main_volume_right: self.main_volume_right,
+            reverb_volume_left: self.reverb_volume_left,
+            reverb_volume_right: self.reverb_volume_right,
+            cd_volume_left: self.cd_volume_left,
+            cd_volume_right: self.cd_volume_right,
+            ext_volume_left: self.ext_volume_left,
+            ext_volume_right: self.ext_volume_right,
+            reverb: self.reverb.to_state(),
+            noise_lfsr: self.noise.lfsr,
+            noise_timer: self.noise.timer,
+            control: self.read_control(),
+            status: self.read_status(),
+            transfer_addr: self.transfer_addr,
+            irq_address: self.irq_address,
+            irq_pending: self.irq_pending,
+            sample_counter: self.sample_counter,
+            capture_buffer: self.capture_buffer,
+            dma_fifo: self.dma_fifo.iter().copied().collect(),
+        }
+    }
+
+    /// Restore the SPU from a saved state
+    ///
+    /// Samples already mixed into the output buffer belong to the host
+    /// audio stream and are dropped rather than restored.
+    fn restore_from_state(&mut self, state: &SPUState) {
+        let len = self.ram.len().min(state.ram.len());
+        self.ram[..len].copy_from_slice(&state.ram[..len]);
+        for (voice, saved) in self.voices.iter_mut().zip(&state.voices) {
+            voice.restore_from_state(saved);
+        }
+
+        self.main_volume_left = state.main_volume_left;
+        self.main_volume_right = state.main_volume_right;
+        self.reverb_volume_left = state.reverb_volume_left;
+        self.reverb_volume_right = state.reverb_volume_right;
+        self.cd_volume_left = state.cd_volume_left;
+        self.cd_volume_right = state.cd_volume_right;
+        self.ext_volume_left = state.ext_volume_left;
+        self.ext_volume_right = state.ext_volume_right;
+
+        // SPUCNT also sets the noise frequency and reverb enable
+        self.write_control(state.control);
+        self.reverb.restore_from_state(&state.reverb);
+        self.noise.lfsr = state.noise_lfsr;
+        self.noise.timer = state.noise_timer;
+
+        self.status.irq_flag = state.status & (1 << 6) != 0;
+        self.status.dma_busy = state.status & (1 << 10) != 0;
+        self.transfer_addr = state.transfer_addr;
+        self.irq_address = state.irq_address;
+        self.irq_pending = state.irq_pending;
+        self.sample_counter = state.sample_counter;
+        self.capture_buffer = state.capture_buffer;
+        self.dma_fifo = state.dma_fifo.iter().copied().collect();
+        self.output_buffer.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Write a looping ADPCM block (filter 0, shift 0) at the given address
+    fn write_looping_block(spu: &mut SPU, addr: usize, nibble: u8) {
+        spu.ram[addr + 1] = 0x07; // Loop start | repeat | end
+        spu.ram[addr + 2..addr + 16].fill(nibble * 0x11);
+    }
+
+    /// Key on a voice at full volume playing the block at `addr`
+    fn start_voice(spu: &mut SPU, voice: usize, addr: u32) {
+        let base = 0x1F801C00 + voice as u32 * 0x10;
+        spu.write_register(base, 0x3FFF); // Volume left
+        spu.write_register(base + 0x2, 0x3FFF); // Volume right
+        spu.write_register(base + 0x4, 0x1000); // 44.1 kHz
+        spu.write_register(base + 0x6, (addr / 8) as u16);
+        spu.key_on_voices(1 << voice);
+
+        // Hold the envelope at full level
+        let adsr = &mut spu.voices[voice].adsr;
+        adsr.phase = adsr::ADSRPhase::Sustain;
+        adsr.level = 0x7FFF;
+    }
+
+    #[test]
+    fn test_tick_generates_44100hz_samples() {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801DAA, 0xC000); // Enabled, unmuted
+
+        // One NTSC frame is exactly 735 samples
+        assert_eq!(spu.tick(564_480).len(), 735);
+
+        // Partial ticks accumulate instead of being dropped
+        let total: usize = (0..10).map(|_| spu.tick(400).len()).sum();
+        assert_eq!(total, 4000 / 768);
+    }
+
+    #[test]
+    fn test_mixer_sums_active_voices() {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801DAA, 0xC000);
+        spu.write_register(0x1F801D80, 0x7FFF);
+        spu.write_register(0x1F801D82, 0x7FFF);
+        write_looping_block(&mut spu, 0x1000, 1);
+
+        start_voice(&mut spu, 0, 0x1000);
+        let one = spu.tick(768 * 200);
+
+        let mut spu2 = SPU::new();
+        spu2.write_register(0x1F801DAA, 0xC000);
+        spu2.write_register(0x1F801D80, 0x7FFF);
+        spu2.write_register(0x1F801D82, 0x7FFF);
+        write_looping_block(&mut spu2, 0x1000, 1);
+        start_voice(&mut spu2, 0, 0x1000);
+        start_voice(&mut spu2, 5, 0x1000);
+        let two = spu2.tick(768 * 200);
+
+        let (l1, r1) = *one.last().unwrap();
+        let (l2, _) = *two.last().unwrap();
+        assert!(l1 > 0);
+        assert_eq!(l1, r1);
+        assert!((l2 as i32 - 2 * l1 as i32).abs() <= 2);
+    }
+
+    #[test]
+    fn test_mute_silences_output() {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801DAA, 0x8000); // Enabled, muted
+        spu.write_register(0x1F801D80, 0x7FFF);
+        spu.write_register(0x1F801D82, 0x7FFF);
+        write_looping_block(&mut spu, 0x1000, 1);
+        start_voice(&mut spu, 0, 0x1000);
+
+        assert!(spu.tick(768 * 100).iter().all(|&s| s == (0, 0)));
+    }
+
+    /// Run one voice through a minimal reverb network, with or without EON
+    fn render_with_reverb(eon: bool) -> Vec<(i16, i16)> {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801DAA, 0xC080); // Enabled, unmuted, reverb on
+        spu.write_register(0x1F801D80, 0x7FFF);
+        spu.write_register(0x1F801D82, 0x7FFF);
+        spu.write_register(0x1F801D84, 0x7FFF); // Reverb output volume
+        spu.write_register(0x1F801DA2, 0xE000); // Work area at 0x70000
+        spu.write_register(0x1F801DC4, 0x7FFF); // vIIR
+        spu.write_register(0x1F801DC6, 0x7FFF); // vCOMB1
+        spu.write_register(0x1F801DD4, 0x0010); // mLSAME
+        spu.write_register(0x1F801DD8, 0x0008); // mLCOMB1
+        spu.write_register(0x1F801DFC, 0x7FFF); // vLIN
+        spu.write_register(0x1F801D98, if eon { 0x0001 } else { 0 });
+        write_looping_block(&mut spu, 0x1000, 1);
+        start_voice(&mut spu, 0, 0x1000);
+
+        spu.tick(768 * 200)
+    }
+
+    #[test]
+    fn test_reverb_adds_delayed_echo_for_eon_voices() {
+        let dry = render_with_reverb(false);
+        let wet = render_with_reverb(true);
+
+        // The comb tap sits 0x40 bytes (32 reverb ticks) behind the input
+        assert_eq!(dry[..64], wet[..64]);
+        assert!(wet[70].0 > dry[70].0);
+
+        // Only the left channel is routed through this network
+        assert_eq!(wet[70].1, dry[70].1);
+    }
+
+    #[test]
+    fn test_reverb_registers_read_back() {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801D98, 0x8001);
+        spu.write_register(0x1F801D9A, 0x0080);
+        spu.write_register(0x1F801DA2, 0x1234);
+        spu.write_register(0x1F801DFE, 0x5678);
+
+        assert_eq!(spu.read_register(0x1F801D98), 0x8001);
+        assert_eq!(spu.read_register(0x1F801D9A), 0x0080);
+        assert!(spu.voices[23].reverb_enabled);
+        assert_eq!(spu.read_register(0x1F801DA2), 0x1234);
+        assert_eq!(spu.read_register(0x1F801DFE), 0x5678);
+    }
+
+    #[test]
+    fn test_irq_fires_once_when_voice_reaches_address() {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801DAA, 0xC040); // Enabled, unmuted, IRQ on
+        spu.write_register(0x1F801DA4, 0x1010 / 8);
+
+        // Two-block loop: 0x1000 (loop start) -> 0x1010 (loop end, repeat)
+        spu.ram[0x1001] = 0x04;
+        spu.ram[0x1011] = 0x03;
+        start_voice(&mut spu, 0, 0x1000);
+
+        // The first block is still playing
+        spu.tick(768 * 20);
+        assert!(!spu.poll_interrupt());
+        assert_eq!(spu.read_register(0x1F801DAE) & (1 << 6), 0);
+
+        // Crossing into the watched block raises IRQ9
+        spu.tick(768 * 20);
+        assert!(spu.poll_interrupt());
+        assert_ne!(spu.read_register(0x1F801DAE) & (1 << 6), 0);
+
+        // Looping past it again does not re-fire until acknowledged
+        spu.tick(768 * 200);
+        assert!(!spu.poll_interrupt());
+
+        spu.write_register(0x1F801DAA, 0xC000); // Acknowledge
+        assert_eq!(spu.read_register(0x1F801DAE) & (1 << 6), 0);
+        spu.write_register(0x1F801DAA, 0xC040);
+        spu.tick(768 * 60);
+        assert!(spu.poll_interrupt());
+    }
+
+    #[test]
+    fn test_irq_disabled_does_not_fire() {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801DAA, 0xC000);
+        spu.write_register(0x1F801DA4, 0x1000 / 8);
+        write_looping_block(&mut spu, 0x1000, 1);
+        start_voice(&mut spu, 0, 0x1000);
+
+        spu.tick(768 * 100);
+
+        assert!(!spu.poll_interrupt());
+        assert_eq!(spu.read_register(0x1F801DAE) & (1 << 6), 0);
+    }
+
+    #[test]
+    fn test_irq_on_transfer_write() {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801DAA, 0xC040);
+        spu.write_register(0x1F801DA4, 0x2008 / 8);
+        spu.set_transfer_address(0x2000 / 8);
+
+        // Two words cover 0x2000-0x2007, short of the IRQ address
+        spu.dma_write(0);
+        spu.dma_write(0);
+        spu.flush_dma_fifo();
+        assert!(!spu.poll_interrupt());
+
+        spu.dma_write(0);
+        spu.flush_dma_fifo();
+        assert!(spu.poll_interrupt());
+    }
+
+    #[test]
+    fn test_pitch_modulation_by_previous_voice() {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801DAA, 0xC000);
+
+        // Voice 0 outputs a constant +0x4000, i.e. a 1.5x pitch factor
+        write_looping_block(&mut spu, 0x1000, 4);
+        start_voice(&mut spu, 0, 0x1000);
+
+        // Voices 1 and 2 walk through silent blocks at the same base pitch
+        start_voice(&mut spu, 1, 0x4000);
+        start_voice(&mut spu, 2, 0x4000);
+        spu.write_register(0x1F801D90, 0x0003); // PMON on voices 0 and 1
+
+        spu.tick(768 * 280);
+
+        // Voice 0 cannot be modulated; voice 1 plays just under 1.5x as fast
+        // as voice 2: 280 * 0xBFFF / 0x8000 = 419.9 samples, 14 full blocks
+        assert_eq!(spu.read_register(0x1F801D90), 0x0002);
+        let modulated = spu.voices[1].current_address - 0x4000;
+        let plain = spu.voices[2].current_address - 0x4000;
+        assert_eq!(plain, 10 * 16);
+        assert_eq!(modulated, 14 * 16);
+    }
+
+    #[test]
+    fn test_noise_voice_plays_lfsr_level() {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801DAA, 0xC000 | (15 << 10) | (3 << 8)); // Fastest noise
+        spu.write_register(0x1F801D80, 0x7FFF);
+        spu.write_register(0x1F801D82, 0x7FFF);
+        start_voice(&mut spu, 3, 0x4000);
+        spu.write_register(0x1F801D94, 1 << 3);
+
+        let samples = spu.tick(768 * 64);
+
+        assert_eq!(spu.read_register(0x1F801D94), 1 << 3);
+        let level = ((spu.noise.output() as i32 * 0x7FFF) >> 15) as i16;
+        assert_eq!(spu.voices[3].output, level);
+        assert!(samples.windows(2).any(|w| w[0] != w[1]));
+    }
+
+    #[test]
+    fn test_drain_samples_returns_mixed_frames() {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801DAA, 0xC000);
+        spu.write_register(0x1F801D80, 0x7FFF);
+        spu.write_register(0x1F801D82, 0x7FFF);
+        write_looping_block(&mut spu, 0x1000, 1);
+        start_voice(&mut spu, 0, 0x1000);
+
+        let mixed = spu.tick(768 * 100);
+        assert_eq!(spu.buffered_frames(), 100);
+
+        let mut out = vec![0i16; 60 * 2];
+        assert_eq!(spu.drain_samples(&mut out), 60);
+        assert_eq!((out[0], out[1]), mixed[0]);
+        assert_eq!((out[118], out[119]), mixed[59]);
+        assert_eq!(spu.buffered_frames(), 40);
+        assert_eq!(spu.output_stats(), (0, 0));
+    }
+
+    #[test]
+    fn test_drain_samples_underrun_fills_silence() {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801DAA, 0xC000);
+        spu.write_register(0x1F801D80, 0x7FFF);
+        spu.write_register(0x1F801D82, 0x7FFF);
+        write_looping_block(&mut spu, 0x1000, 1);
+        start_voice(&mut spu, 0, 0x1000);
+        spu.tick(768 * 10);
+
+        let mut out = vec![1i16; 16 * 2];
+        assert_eq!(spu.drain_samples(&mut out), 10);
+
+        assert!(out[..20].iter().any(|&s| s != 0));
+        assert!(out[20..].iter().all(|&s| s == 0));
+        assert_eq!(spu.output_stats(), (0, 6));
+    }
+
+    #[test]
+    fn test_output_buffer_overrun_drops_oldest() {
+        let mut spu = SPU::new();
+        spu.write_register(0x1F801DAA, 0xC000);
+
+        spu.tick(768 * (SPU::OUTPUT_BUFFER_FRAMES as u32 + 5));
+
+        assert_eq!(spu.buffered_frames(), SPU::OUTPUT_BUFFER_FRAMES);
+        assert_eq!(spu.output_stats(), (5, 0));
+    }
+}
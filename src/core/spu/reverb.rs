@@ -18,45 +18,98 @@
 //! The PlayStation SPU includes hardware reverb effects that can be
 //! applied to audio output. This module implements the reverb
 //! configuration and processing logic using all-pass and comb filters.
+//!
+//! # Work Area
+//!
+//! The reverb network keeps all of its delay lines in a ring buffer in SPU
+//! RAM, from the work-area base (mBASE, 0x1F801DA2) up to the end of RAM.
+//! Every address register is an offset (in 8-byte units) relative to the
+//! current buffer position, which advances by one halfword per reverb tick.
+//!
+//! # Algorithm
+//!
+//! Reverb runs at 22.05 kHz (every other output sample) and processes both
+//! channels per tick:
+//!
+//! ```text
+//! [mLSAME] = (Lin + [dLSAME]*vWALL - [mLSAME-2])*vIIR + [mLSAME-2]
+//! [mLDIFF] = (Lin + [dRDIFF]*vWALL - [mLDIFF-2])*vIIR + [mLDIFF-2]
+//! Lout     = vCOMB1*[mLCOMB1] + vCOMB2*[mLCOMB2] + vCOMB3*[mLCOMB3] + vCOMB4*[mLCOMB4]
+//! [mLAPF1] = Lout - vAPF1*[mLAPF1-dAPF1], Lout = [mLAPF1-dAPF1] + [mLAPF1]*vAPF1
+//! [mLAPF2] = Lout - vAPF2*[mLAPF2-dAPF2], Lout = [mLAPF2-dAPF2] + [mLAPF2]*vAPF2
+//! ```
+//!
+//! The right channel mirrors this with L and R swapped.
+
+use crate::core::save_state::{ReverbState, StateSave};
+
+/// Number of reverb configuration registers (0x1F801DC0-0x1F801DFF)
+const REGISTER_COUNT: usize = 32;
+
+/// Left channel index into the per-channel register pairs
+const LEFT: usize = 0;
+
+/// Right channel index into the per-channel register pairs
+const RIGHT: usize = 1;
 
 /// Reverb configuration
 ///
 /// Hardware reverb effects configuration implementing the PSX SPU's
-/// reverb algorithm with all-pass and comb filters.
+/// reverb algorithm with all-pass and comb filters. Address registers are
+/// stored as written (8-byte units); per-channel registers are `[left, right]`.
 pub struct ReverbConfig {
-    /// Reverb enabled
+    /// Reverb master enable (SPUCNT bit 7); gates writes to the work area
     pub(crate) enabled: bool,
 
-    /// APF (All-Pass Filter) offsets
+    /// APF offsets (dAPF1, dAPF2)
     pub(crate) apf_offset1: u16,
     pub(crate) apf_offset2: u16,
 
-    /// Reflection volumes
-    pub(crate) reflect_volume1: i16,
-    pub(crate) reflect_volume2: i16,
-    pub(crate) reflect_volume3: i16,
-    pub(crate) reflect_volume4: i16,
+    /// Reflection volumes (vIIR, vWALL)
+    pub(crate) iir_volume: i16,
+    pub(crate) wall_volume: i16,
 
-    /// Comb filter volumes
-    pub(crate) comb_volume1: i16,
-    pub(crate) comb_volume2: i16,
-    pub(crate) comb_volume3: i16,
-    pub(crate) comb_volume4: i16,
+    /// Comb filter volumes (vCOMB1-4)
+    pub(crate) comb_volume: [i16; 4],
 
-    /// APF volumes
+    /// APF volumes (vAPF1, vAPF2)
     pub(crate) apf_volume1: i16,
     pub(crate) apf_volume2: i16,
 
-    /// Input volume
+    /// Same-side reflection addresses (mLSAME, mRSAME)
+    pub(crate) same_reflect_addr: [u16; 2],
+
+    /// Different-side reflection addresses (mLDIFF, mRDIFF)
+    pub(crate) diff_reflect_addr: [u16; 2],
+
+    /// Same-side reflection delay addresses (dLSAME, dRSAME)
+    pub(crate) same_reflect_delay: [u16; 2],
+
+    /// Different-side reflection delay addresses (dLDIFF, dRDIFF)
+    pub(crate) diff_reflect_delay: [u16; 2],
+
+    /// Comb filter tap addresses (mxCOMB1-4)
+    pub(crate) comb_addr: [[u16; 2]; 4],
+
+    /// APF addresses (mxAPF1, mxAPF2)
+    pub(crate) apf_addr1: [u16; 2],
+    pub(crate) apf_addr2: [u16; 2],
+
+    /// Input volume (vLIN, vRIN)
     pub(crate) input_volume_left: i16,
     pub(crate) input_volume_right: i16,
 
-    /// Reverb work area in SPU RAM
+    /// Reverb work area start in SPU RAM (byte address)
     pub(crate) reverb_start_addr: u32,
-    pub(crate) reverb_end_addr: u32,
 
-    /// Current reverb address
+    /// Current reverb address (byte offset within the work area)
     pub(crate) reverb_current_addr: u32,
+
+    /// Set on the 44.1 kHz samples where the 22.05 kHz network is clocked
+    odd_sample: bool,
+
+    /// Last computed output, held between reverb ticks
+    output: (i16, i16),
 }
 
 impl ReverbConfig {
@@ -70,167 +123,248 @@ impl ReverbConfig {
             enabled: false,
             apf_offset1: 0,
             apf_offset2: 0,
-            reflect_volume1: 0,
-            reflect_volume2: 0,
-            reflect_volume3: 0,
-            reflect_volume4: 0,
-            comb_volume1: 0,
-            comb_volume2: 0,
-            comb_volume3: 0,
-            comb_volume4: 0,
+            iir_volume: 0,
+            wall_volume: 0,
+            comb_volume: [0; 4],
             apf_volume1: 0,
             apf_volume2: 0,
+            same_reflect_addr: [0; 2],
+            diff_reflect_addr: [0; 2],
+            same_reflect_delay: [0; 2],
+            diff_reflect_delay: [0; 2],
+            comb_addr: [[0; 2]; 4],
+            apf_addr1: [0; 2],
+            apf_addr2: [0; 2],
             input_volume_left: 0,
             input_volume_right: 0,
             reverb_start_addr: 0,
-            reverb_end_addr: 0,
             reverb_current_addr: 0,
+            odd_sample: false,
+            output: (0, 0),
         }
     }
 
-    /// Apply reverb to a stereo sample
+    /// Set the work area base (mBASE)
+    ///
+    /// Writing mBASE also moves the current buffer position to the base.
     ///
-    /// Processes input samples through all-pass and comb filters
-    /// to create a reverb effect. The signal flow is:
-    /// input → APF1 → APF2 → comb filters → circular buffer → output
+    /// # Arguments
+    ///
+    /// * `value` - Base address in 8-byte units
+    pub fn set_base(&mut self, value: u16) {
+        self.reverb_start_addr = (value as u32) * 8;
+        self.reverb_current_addr = 0;
+    }
+
+    /// Read a reverb configuration register (0x1F801DC0-0x1F801DFF)
     ///
     /// # Arguments
     ///
-    /// * `left` - Left channel input sample
-    /// * `right` - Right channel input sample
-    /// * `spu_ram` - Mutable reference to SPU RAM for reverb buffer
+    /// * `index` - Register index (0-31, halfword offset from 0x1F801DC0)
     ///
     /// # Returns
     ///
-    /// Tuple of (left, right) samples with reverb applied
-    #[inline(always)]
-    pub fn process(&mut self, left: i16, right: i16, spu_ram: &mut [u8]) -> (i16, i16) {
-        if !self.enabled {
-            return (left, right);
+    /// 16-bit register value
+    pub fn read_register(&self, index: usize) -> u16 {
+        match index {
+            0x00 => self.apf_offset1,
+            0x01 => self.apf_offset2,
+            0x02 => self.iir_volume as u16,
+            0x03..=0x06 => self.comb_volume[index - 0x03] as u16,
+            0x07 => self.wall_volume as u16,
+            0x08 => self.apf_volume1 as u16,
+            0x09 => self.apf_volume2 as u16,
+            0x0A | 0x0B => self.same_reflect_addr[index & 1],
+            0x0C | 0x0D => self.comb_addr[0][index & 1],
+            0x0E | 0x0F => self.comb_addr[1][index & 1],
+            0x10 | 0x11 => self.same_reflect_delay[index & 1],
+            0x12 | 0x13 => self.diff_reflect_addr[index & 1],
+            0x14 | 0x15 => self.comb_addr[2][index & 1],
+            0x16 | 0x17 => self.comb_addr[3][index & 1],
+            0x18 | 0x19 => self.diff_reflect_delay[index & 1],
+            0x1A | 0x1B => self.apf_addr1[index & 1],
+            0x1C | 0x1D => self.apf_addr2[index & 1],
+            0x1E => self.input_volume_left as u16,
+            0x1F => self.input_volume_right as u16,
+            _ => 0,
         }
+    }
 
-        // If reverb work area is not configured, pass through
-        if self.reverb_start_addr == 0 || self.reverb_end_addr == 0 {
-            return (left, right);
+    /// Write a reverb configuration register (0x1F801DC0-0x1F801DFF)
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Register index (0-31, halfword offset from 0x1F801DC0)
+    /// * `value` - 16-bit value to write
+    pub fn write_register(&mut self, index: usize, value: u16) {
+        match index {
+            0x00 => self.apf_offset1 = value,
+            0x01 => self.apf_offset2 = value,
+            0x02 => self.iir_volume = value as i16,
+            0x03..=0x06 => self.comb_volume[index - 0x03] = value as i16,
+            0x07 => self.wall_volume = value as i16,
+            0x08 => self.apf_volume1 = value as i16,
+            0x09 => self.apf_volume2 = value as i16,
+            0x0A | 0x0B => self.same_reflect_addr[index & 1] = value,
+            0x0C | 0x0D => self.comb_addr[0][index & 1] = value,
+            0x0E | 0x0F => self.comb_addr[1][index & 1] = value,
+            0x10 | 0x11 => self.same_reflect_delay[index & 1] = value,
+            0x12 | 0x13 => self.diff_reflect_addr[index & 1] = value,
+            0x14 | 0x15 => self.comb_addr[2][index & 1] = value,
+            0x16 | 0x17 => self.comb_addr[3][index & 1] = value,
+            0x18 | 0x19 => self.diff_reflect_delay[index & 1] = value,
+            0x1A | 0x1B => self.apf_addr1[index & 1] = value,
+            0x1C | 0x1D => self.apf_addr2[index & 1] = value,
+            0x1E => self.input_volume_left = value as i16,
+            0x1F => self.input_volume_right = value as i16,
+            _ => {}
         }
+    }
 
-        // Input with volume
-        let input_left = ((left as i32) * (self.input_volume_left as i32)) >> 15;
-        let input_right = ((right as i32) * (self.input_volume_right as i32)) >> 15;
-
-        // Read feedback samples from reverb buffer at APF offsets
-        let apf1_fb_left = self.read_reverb_buffer(spu_ram, self.apf_offset1 as u32 * 2);
-        let apf1_fb_right = self.read_reverb_buffer(spu_ram, self.apf_offset1 as u32 * 2 + 2);
-
-        // Apply first all-pass filter
-        let apf1_left = self.apply_apf(input_left, apf1_fb_left, self.apf_volume1);
-        let apf1_right = self.apply_apf(input_right, apf1_fb_right, self.apf_volume1);
-
-        // Read feedback for second APF
-        let apf2_fb_left = self.read_reverb_buffer(spu_ram, self.apf_offset2 as u32 * 2);
-        let apf2_fb_right = self.read_reverb_buffer(spu_ram, self.apf_offset2 as u32 * 2 + 2);
-
-        // Apply second all-pass filter
-        let apf2_left = self.apply_apf(apf1_left, apf2_fb_left, self.apf_volume2);
-        let apf2_right = self.apply_apf(apf1_right, apf2_fb_right, self.apf_volume2);
+    /// Run the reverb network for one 44.1 kHz output sample
+    ///
+    /// The network is clocked on every other call (22.05 kHz); in between,
+    /// the previous output is held. Input is taken from the voices that have
+    /// reverb enabled (EON) plus CD audio when routed to reverb.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - Left channel reverb input
+    /// * `right` - Right channel reverb input
+    /// * `spu_ram` - Mutable reference to SPU RAM holding the work area
+    ///
+    /// # Returns
+    ///
+    /// Tuple of (left, right) reverb output, before the reverb output volume
+    #[inline(always)]
+    pub fn process(&mut self, left: i16, right: i16, spu_ram: &mut [u8]) -> (i16, i16) {
+        self.odd_sample = !self.odd_sample;
+        if !self.odd_sample {
+            return self.output;
+        }
 
-        // Apply comb filters (which incorporate the input signal)
-        let comb_left = self.apply_comb_filters(apf2_left, spu_ram, 0);
-        let comb_right = self.apply_comb_filters(apf2_right, spu_ram, 1);
+        let input = [
+            mul(left as i32, self.input_volume_left),
+            mul(right as i32, self.input_volume_right),
+        ];
 
-        // Write comb outputs to circular buffer at current position
-        self.write_reverb_buffer(spu_ram, 0, comb_left as i16);
-        self.write_reverb_buffer(spu_ram, 2, comb_right as i16);
+        // Same-side and different-side reflections
+        for ch in [LEFT, RIGHT] {
+            let same = addr(self.same_reflect_addr[ch]);
+            let same_prev = self.read_reverb_buffer(spu_ram, same - 2) as i32;
+            let same_delay = self.read_reverb_buffer(spu_ram, addr(self.same_reflect_delay[ch]));
+            let value = mul(
+                input[ch] + mul(same_delay as i32, self.wall_volume) - same_prev,
+                self.iir_volume,
+            ) + same_prev;
+            self.write_reverb_buffer(spu_ram, same, value);
+
+            let diff = addr(self.diff_reflect_addr[ch]);
+            let diff_prev = self.read_reverb_buffer(spu_ram, diff - 2) as i32;
+            let diff_delay =
+                self.read_reverb_buffer(spu_ram, addr(self.diff_reflect_delay[ch ^ 1]));
+            let value = mul(
+                input[ch] + mul(diff_delay as i32, self.wall_volume) - diff_prev,
+                self.iir_volume,
+            ) + diff_prev;
+            self.write_reverb_buffer(spu_ram, diff, value);
+        }
 
-        // Advance circular buffer pointer
-        self.advance_reverb_address();
+        let mut output = [0i16; 2];
+        for (ch, out) in output.iter_mut().enumerate() {
+            // Early echo (comb filter)
+            let mut sample: i32 = self
+                .comb_addr
+                .iter()
+                .zip(self.comb_volume)
+                .map(|(taps, volume)| {
+                    mul(
+                        self.read_reverb_buffer(spu_ram, addr(taps[ch])) as i32,
+                        volume,
+                    )
+                })
+                .sum();
+
+            // Late reverb (two all-pass filters)
+            sample = self.apply_apf(
+                spu_ram,
+                sample,
+                addr(self.apf_addr1[ch]),
+                addr(self.apf_offset1),
+                self.apf_volume1,
+            );
+            sample = self.apply_apf(
+                spu_ram,
+                sample,
+                addr(self.apf_addr2[ch]),
+                addr(self.apf_offset2),
+                self.apf_volume2,
+            );
+
+            *out = saturate(sample);
+        }
 
-        // Mix with original (dry signal + wet signal)
-        let out_left = ((left as i32) + comb_left).clamp(i16::MIN as i32, i16::MAX as i32);
-        let out_right = ((right as i32) + comb_right).clamp(i16::MIN as i32, i16::MAX as i32);
+        self.advance_reverb_address(spu_ram.len() as u32);
 
-        (out_left as i16, out_right as i16)
+        self.output = (output[LEFT], output[RIGHT]);
+        self.output
     }
 
     /// Apply all-pass filter
     ///
     /// # Arguments
     ///
+    /// * `spu_ram` - Mutable reference to SPU RAM
     /// * `input` - Input sample
-    /// * `feedback` - Feedback sample from reverb buffer
+    /// * `address` - Byte offset of the filter's write position (mxAPF)
+    /// * `delay` - Byte distance back to the delayed sample (dAPF)
     /// * `volume` - APF volume coefficient
     ///
     /// # Returns
     ///
     /// Filtered sample
     #[inline(always)]
-    fn apply_apf(&self, input: i32, feedback: i16, volume: i16) -> i32 {
-        let fb = (feedback as i32 * volume as i32) >> 15;
-        input - fb
+    fn apply_apf(
+        &self,
+        spu_ram: &mut [u8],
+        input: i32,
+        address: i32,
+        delay: i32,
+        volume: i16,
+    ) -> i32 {
+        let delayed = self.read_reverb_buffer(spu_ram, address - delay) as i32;
+        let stored = saturate(input - mul(delayed, volume));
+        self.write_reverb_buffer(spu_ram, address, stored as i32);
+        delayed + mul(stored as i32, volume)
     }
 
-    /// Apply comb filters
-    ///
-    /// Combines the input signal with delayed samples from the reverb buffer,
-    /// weighted by the comb filter volumes. This creates the characteristic
-    /// reverb effect with multiple delayed reflections.
+    /// Resolve a byte offset from the current position to a RAM address
     ///
     /// # Arguments
     ///
-    /// * `input` - Input sample (from APF chain)
-    /// * `spu_ram` - Reference to SPU RAM
-    /// * `channel` - Channel index (0=left, 1=right)
+    /// * `ram_size` - Size of SPU RAM (the work area ends at the end of RAM)
+    /// * `offset` - Byte offset from the current reverb address (may be negative)
     ///
     /// # Returns
     ///
-    /// Filtered sample with input incorporated, clamped to i32 range
+    /// Byte address in SPU RAM, or None if the work area is empty
     #[inline(always)]
-    fn apply_comb_filters(&self, input: i32, spu_ram: &[u8], channel: usize) -> i32 {
-        // Start with the input signal, accumulate in i64 to prevent overflow
-        // With 8 contributions (4 comb + 4 reflection) plus input, i32 could overflow
-        let mut output: i64 = input as i64;
-
-        // Apply 4 comb filters by reading delayed samples from buffer
-        let volumes = [
-            self.comb_volume1,
-            self.comb_volume2,
-            self.comb_volume3,
-            self.comb_volume4,
-        ];
-
-        // Read reflection volumes (same for both channels)
-        let reflection_volumes = [
-            self.reflect_volume1,
-            self.reflect_volume2,
-            self.reflect_volume3,
-            self.reflect_volume4,
-        ];
-
-        for (i, (&volume, &reflect_vol)) in
-            volumes.iter().zip(reflection_volumes.iter()).enumerate()
-        {
-            // Calculate offset for this comb filter tap
-            // Each channel has its own set of delay taps
-            let offset = ((channel * 8 + i * 2) * 2) as u32;
-            let sample = self.read_reverb_buffer(spu_ram, offset) as i64;
-
-            // Apply comb volume to delayed sample
-            let comb_contribution = (sample * volume as i64) >> 15;
-
-            // Apply reflection volume to input
-            let reflect_contribution = ((input as i64) * reflect_vol as i64) >> 15;
-
-            output += comb_contribution + reflect_contribution;
+    fn buffer_address(&self, ram_size: usize, offset: i32) -> Option<usize> {
+        let work_area_size = (ram_size as i64) - self.reverb_start_addr as i64;
+        if work_area_size <= 0 {
+            return None;
         }
 
-        // Clamp to i32 range before returning to prevent truncation issues
-        output.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+        // Calculate address within the circular buffer
+        let relative_addr =
+            (self.reverb_current_addr as i64 + offset as i64).rem_euclid(work_area_size);
+        Some((self.reverb_start_addr as i64 + relative_addr) as usize & !1)
     }
 
     /// Read from reverb buffer in SPU RAM
     ///
     /// Reads a sample from the circular reverb buffer, wrapping within the
-    /// configured work area defined by reverb_start_addr and reverb_end_addr.
+    /// work area between reverb_start_addr and the end of SPU RAM.
     ///
     /// # Arguments
     ///
@@ -241,67 +375,52 @@ impl ReverbConfig {
     ///
     /// 16-bit sample from reverb buffer (little-endian)
     #[inline(always)]
-    fn read_reverb_buffer(&self, spu_ram: &[u8], offset: u32) -> i16 {
-        let work_area_size = self.reverb_end_addr.saturating_sub(self.reverb_start_addr);
-        if work_area_size == 0 {
-            return 0;
-        }
-
-        // Calculate address within the circular buffer
-        let relative_addr = (self.reverb_current_addr + offset) % work_area_size;
-        let addr = (self.reverb_start_addr + relative_addr) as usize;
-
-        // Ensure we stay within SPU RAM bounds
-        if addr + 1 < spu_ram.len() {
-            let lo = spu_ram[addr] as u16;
-            let hi = spu_ram[addr + 1] as u16;
-            ((hi << 8) | lo) as i16
-        } else {
-            0
+    fn read_reverb_buffer(&self, spu_ram: &[u8], offset: i32) -> i16 {
+        match self.buffer_address(spu_ram.len(), offset) {
+            Some(addr) => i16::from_le_bytes([spu_ram[addr], spu_ram[addr + 1]]),
+            None => 0,
         }
     }
 
     /// Write to reverb buffer in SPU RAM
     ///
-    /// Writes a sample to the circular reverb buffer, wrapping within the
-    /// configured work area defined by reverb_start_addr and reverb_end_addr.
+    /// Writes a sample to the circular reverb buffer, saturated to 16 bits.
+    /// Writes are dropped while the reverb master enable is off.
     ///
     /// # Arguments
     ///
     /// * `spu_ram` - Mutable reference to SPU RAM
     /// * `offset` - Byte offset from current reverb address
-    /// * `value` - 16-bit sample to write (little-endian)
+    /// * `value` - Sample to write (little-endian)
     #[inline(always)]
-    fn write_reverb_buffer(&mut self, spu_ram: &mut [u8], offset: u32, value: i16) {
-        let work_area_size = self.reverb_end_addr.saturating_sub(self.reverb_start_addr);
-        if work_area_size == 0 {
+    fn write_reverb_buffer(&self, spu_ram: &mut [u8], offset: i32, value: i32) {
+        if !self.enabled {
             return;
         }
 
-        // Calculate address within the circular buffer
-        let relative_addr = (self.reverb_current_addr + offset) % work_area_size;
-        let addr = (self.reverb_start_addr + relative_addr) as usize;
-
-        // Ensure we stay within SPU RAM bounds
-        if addr + 1 < spu_ram.len() {
-            spu_ram[addr] = value as u8;
-            spu_ram[addr + 1] = (value >> 8) as u8;
+        if let Some(addr) = self.buffer_address(spu_ram.len(), offset) {
+            let bytes = saturate(value).to_le_bytes();
+            spu_ram[addr] = bytes[0];
+            spu_ram[addr + 1] = bytes[1];
         }
     }
 
     /// Advance reverb circular buffer address
     ///
-    /// Moves the current address forward by one stereo sample (4 bytes: 2 for left, 2 for right),
-    /// wrapping within the configured work area.
+    /// Moves the current address forward by one halfword, wrapping back to
+    /// the work area base at the end of SPU RAM.
+    ///
+    /// # Arguments
+    ///
+    /// * `ram_size` - Size of SPU RAM
     #[inline(always)]
-    fn advance_reverb_address(&mut self) {
-        let work_area_size = self.reverb_end_addr.saturating_sub(self.reverb_start_addr);
+    fn advance_reverb_address(&mut self, ram_size: u32) {
+        let work_area_size = ram_size.saturating_sub(self.reverb_start_addr);
         if work_area_size == 0 {
             return;
         }
 
-        // Advance by 4 bytes (one stereo sample)
-        self.reverb_current_addr = (self.reverb_current_addr + 4) % work_area_size;
+        self.reverb_current_addr = (self.reverb_current_addr + 2) % work_area_size;
     }
 }
 
@@ -311,11 +430,78 @@ impl Default for ReverbConfig {
     }
 }
 
+impl StateSave for ReverbConfig {
+    type State = ReverbState;
+
+    fn to_state(&self) -> ReverbState {
+        ReverbState {
+            enabled: self.enabled,
+            reverb_current_addr: self.reverb_current_addr,
+            reverb_start_addr: self.reverb_start_addr,
+            registers: (0..REGISTER_COUNT)
+                .map(|index| self.read_register(index))
+                .collect(),
+            odd_sample: self.odd_sample,
+            output: self.output,
+        }
+    }
+
+    fn restore_from_state(&mut self, state: &ReverbState) {
+        for (index, &value) in state.registers.iter().enumerate() {
+            self.write_register(index, value);
+        }
+        self.enabled = state.enabled;
+        self.reverb_start_addr = state.reverb_start_addr;
+        self.reverb_current_addr = state.reverb_current_addr;
+        self.odd_sample = state.odd_sample;
+        self.output = state.output;
+    }
+}
+
+/// Convert an address register (8-byte units) to a byte offset
+#[inline(always)]
+fn addr(value: u16) -> i32 {
+    (value as i32) * 8
+}
+
+/// Fixed-point multiply with a 15-bit fraction volume
+#[inline(always)]
+fn mul(sample: i32, volume: i16) -> i32 {
+    (sample * volume as i32) >> 15
+}
+
+/// Clamp a sample to the 16-bit range
+#[inline(always)]
+fn saturate(sample: i32) -> i16 {
+    sample.clamp(i16::MIN as i32, i16::MAX as i32) as i16
+}
+
 #[cfg(test)]
 #[allow(clippy::field_reassign_with_default)]
 mod tests {
     use super::*;
 
+    /// Size of the test SPU RAM
+    const RAM_SIZE: usize = 512 * 1024;
+
+    /// Configure a minimal network: one same-side reflection feeding comb 1,
+    /// then both all-pass filters, on the left channel only
+    fn simple_config() -> ReverbConfig {
+        let mut reverb = ReverbConfig::new();
+        reverb.enabled = true;
+        reverb.set_base(((RAM_SIZE - 0x1000) / 8) as u16);
+        reverb.input_volume_left = 0x7FFF;
+        reverb.iir_volume = 0x7FFF;
+        reverb.same_reflect_addr[LEFT] = 0x10;
+        reverb.comb_addr[0][LEFT] = 0x08;
+        reverb.comb_volume[0] = 0x7FFF;
+        reverb.apf_addr1[LEFT] = 0x20;
+        reverb.apf_offset1 = 0x02;
+        reverb.apf_addr2[LEFT] = 0x30;
+        reverb.apf_offset2 = 0x02;
+        reverb
+    }
+
     #[test]
     fn test_reverb_creation() {
         let reverb = ReverbConfig::new();
@@ -324,331 +510,207 @@ mod tests {
     }
 
     #[test]
-    fn test_reverb_disabled() {
-        let mut reverb = ReverbConfig::new();
-        reverb.enabled = false;
-
-        let mut spu_ram = vec![0u8; 512 * 1024];
-        let (left, right) = reverb.process(1000, 1000, &mut spu_ram);
-
-        // When disabled, reverb should pass through unchanged
-        assert_eq!(left, 1000);
-        assert_eq!(right, 1000);
-    }
-
-    #[test]
-    fn test_reverb_process() {
+    fn test_reverb_silent_without_configuration() {
         let mut reverb = ReverbConfig::new();
         reverb.enabled = true;
-        reverb.input_volume_left = 0x4000;
-        reverb.input_volume_right = 0x4000;
-
-        let mut spu_ram = vec![0u8; 512 * 1024];
 
-        let (_left, _right) = reverb.process(1000, 1000, &mut spu_ram);
-
-        // Reverb should process without crashing
-        // Output values are i16, so they're always in valid range
+        let mut spu_ram = vec![0u8; RAM_SIZE];
+        for _ in 0..100 {
+            assert_eq!(reverb.process(1000, 1000, &mut spu_ram), (0, 0));
+        }
     }
 
     #[test]
-    fn test_apf_filter() {
-        let reverb = ReverbConfig::new();
-        let input = 1000i32;
-        let feedback = 500i16;
-        let volume = 0x4000i16;
+    fn test_reverb_impulse_produces_delayed_echo() {
+        let mut reverb = simple_config();
+        let mut spu_ram = vec![0u8; RAM_SIZE];
 
-        let output = reverb.apply_apf(input, feedback, volume);
+        let mut output = vec![reverb.process(0x4000, 0, &mut spu_ram).0];
+        for _ in 0..300 {
+            output.push(reverb.process(0, 0, &mut spu_ram).0);
+        }
 
-        // APF should modify the input based on feedback
-        assert_ne!(output, input);
+        // Comb tap 0x40 bytes behind the reflection (32 ticks) plus two
+        // all-pass delays of 8 ticks each, at two output samples per tick
+        let first = output.iter().position(|&s| s != 0).unwrap();
+        assert_eq!(first, 2 * (32 + 8 + 8));
+        assert!(output[first] > 0x3F00);
     }
 
     #[test]
-    fn test_reverb_buffer_access() {
-        let mut reverb = ReverbConfig::new();
-        let mut spu_ram = vec![0u8; 512 * 1024];
+    fn test_reverb_output_held_between_ticks() {
+        let mut reverb = simple_config();
+        let mut spu_ram = vec![0u8; RAM_SIZE];
 
-        // Configure reverb work area
-        reverb.reverb_start_addr = 0x1000;
-        reverb.reverb_end_addr = 0x2000; // 4KB work area
-        reverb.reverb_current_addr = 0; // Offset within work area
-
-        // Write a value
-        reverb.write_reverb_buffer(&mut spu_ram, 0, 0x1234);
+        let output: Vec<_> = (0..300)
+            .map(|i| reverb.process(if i == 0 { 0x4000 } else { 0 }, 0, &mut spu_ram))
+            .collect();
 
-        // Read it back
-        let value = reverb.read_reverb_buffer(&spu_ram, 0);
-        assert_eq!(value, 0x1234);
+        // The network runs at 22.05 kHz, so samples come in equal pairs
+        for pair in output.chunks(2) {
+            assert_eq!(pair[0], pair[1]);
+        }
+        assert_eq!(reverb.reverb_current_addr, 300);
     }
 
     #[test]
-    fn test_reverb_buffer_wrapping() {
-        let mut reverb = ReverbConfig::new();
-        let mut spu_ram = vec![0u8; 512 * 1024];
-
-        // Configure small work area
-        reverb.reverb_start_addr = 0x1000;
-        reverb.reverb_end_addr = 0x1010; // 16 byte work area
-        reverb.reverb_current_addr = 0;
-
-        // Write at offset that will wrap
-        reverb.write_reverb_buffer(&mut spu_ram, 12, 0x1BCD);
+    fn test_reverb_master_disable_blocks_writes() {
+        let mut reverb = simple_config();
+        reverb.enabled = false;
+        let mut spu_ram = vec![0u8; RAM_SIZE];
 
-        // Read should wrap around
-        let value = reverb.read_reverb_buffer(&spu_ram, 12);
-        assert_eq!(value, 0x1BCD);
+        for _ in 0..300 {
+            assert_eq!(reverb.process(0x4000, 0x4000, &mut spu_ram), (0, 0));
+        }
+        assert!(spu_ram.iter().all(|&b| b == 0));
     }
 
     #[test]
-    fn test_reverb_address_advance() {
+    fn test_reverb_register_round_trip() {
         let mut reverb = ReverbConfig::new();
-        reverb.reverb_start_addr = 0x1000;
-        reverb.reverb_end_addr = 0x1100; // 256 bytes
-        reverb.reverb_current_addr = 0;
 
-        // Advance multiple times
-        for _ in 0..10 {
-            reverb.advance_reverb_address();
+        for index in 0..32 {
+            reverb.write_register(index, 0x1000 + index as u16);
         }
 
-        // Should advance by 4 bytes per call
-        assert_eq!(reverb.reverb_current_addr, 40);
+        assert_eq!(reverb.apf_offset1, 0x1000);
+        assert_eq!(reverb.comb_volume, [0x1003, 0x1004, 0x1005, 0x1006]);
+        assert_eq!(reverb.same_reflect_addr, [0x100A, 0x100B]);
+        assert_eq!(reverb.diff_reflect_delay, [0x1018, 0x1019]);
+        assert_eq!(reverb.input_volume_right, 0x101F);
+        for index in 0..32 {
+            assert_eq!(reverb.read_register(index), 0x1000 + index as u16);
+        }
     }
 
     #[test]
-    fn test_reverb_address_wrap_at_end() {
+    fn test_reverb_set_base_resets_position() {
         let mut reverb = ReverbConfig::new();
-        reverb.reverb_start_addr = 0x1000;
-        reverb.reverb_end_addr = 0x1010; // 16 bytes
-        reverb.reverb_current_addr = 12; // Near end
+        reverb.reverb_current_addr = 0x40;
 
-        reverb.advance_reverb_address();
+        reverb.set_base(0xE000);
 
-        // Should wrap to beginning
+        assert_eq!(reverb.reverb_start_addr, 0x70000);
         assert_eq!(reverb.reverb_current_addr, 0);
     }
 
     #[test]
-    fn test_reverb_zero_work_area() {
+    fn test_reverb_buffer_access() {
         let mut reverb = ReverbConfig::new();
-        let mut spu_ram = vec![0u8; 512 * 1024];
-
-        // Zero-size work area
-        reverb.reverb_start_addr = 0x1000;
-        reverb.reverb_end_addr = 0x1000;
         reverb.enabled = true;
+        reverb.reverb_start_addr = 0x1000;
+        let mut spu_ram = vec![0u8; RAM_SIZE];
 
-        // Should handle gracefully without crashing
         reverb.write_reverb_buffer(&mut spu_ram, 0, 0x1234);
-        let value = reverb.read_reverb_buffer(&spu_ram, 0);
-        assert_eq!(value, 0);
 
-        reverb.advance_reverb_address();
-        assert_eq!(reverb.reverb_current_addr, 0);
+        assert_eq!(reverb.read_reverb_buffer(&spu_ram, 0), 0x1234);
     }
 
     #[test]
-    fn test_reverb_out_of_bounds_protection() {
+    fn test_reverb_buffer_saturates_writes() {
         let mut reverb = ReverbConfig::new();
-        let spu_ram = vec![0u8; 512 * 1024];
-
-        // Configure work area near end of RAM
-        reverb.reverb_start_addr = 512 * 1024 - 10;
-        reverb.reverb_end_addr = 512 * 1024 + 100; // Beyond RAM size
-
-        // Should not crash
-        let value = reverb.read_reverb_buffer(&spu_ram, 0);
-        assert_eq!(value, 0);
-    }
-
-    #[test]
-    fn test_apf_filter_zero_volume() {
-        let reverb = ReverbConfig::new();
-        let input = 1000i32;
-        let feedback = 500i16;
-        let volume = 0i16;
-
-        let output = reverb.apply_apf(input, feedback, volume);
-
-        // Zero volume should pass input through unchanged
-        assert_eq!(output, input);
-    }
-
-    #[test]
-    fn test_apf_filter_positive_volume() {
-        let reverb = ReverbConfig::new();
-        let input = 1000i32;
-        let feedback = 500i16;
-        let volume = 0x4000i16; // 0.5 in fixed-point
+        reverb.enabled = true;
+        let mut spu_ram = vec![0u8; RAM_SIZE];
 
-        let output = reverb.apply_apf(input, feedback, volume);
+        reverb.write_reverb_buffer(&mut spu_ram, 0, 100_000);
+        reverb.write_reverb_buffer(&mut spu_ram, 2, -100_000);
 
-        // Should subtract scaled feedback from input
-        assert_ne!(output, input);
-        assert!(output < input);
+        assert_eq!(reverb.read_reverb_buffer(&spu_ram, 0), i16::MAX);
+        assert_eq!(reverb.read_reverb_buffer(&spu_ram, 2), i16::MIN);
     }
 
     #[test]
-    fn test_apf_filter_negative_feedback() {
-        let reverb = ReverbConfig::new();
-        let input = 1000i32;
-        let feedback = -500i16;
-        let volume = 0x4000i16;
+    fn test_reverb_buffer_wraps_at_end_of_ram() {
+        let mut reverb = ReverbConfig::new();
+        reverb.enabled = true;
+        reverb.reverb_start_addr = (RAM_SIZE - 0x10) as u32; // 16 byte work area
+        reverb.reverb_current_addr = 12;
+        let mut spu_ram = vec![0u8; RAM_SIZE];
 
-        let output = reverb.apply_apf(input, feedback, volume);
+        // 12 + 8 wraps to offset 4 of the work area
+        reverb.write_reverb_buffer(&mut spu_ram, 8, 0x1BCD);
+        assert_eq!(spu_ram[RAM_SIZE - 0x10 + 4], 0xCD);
 
-        // Negative feedback should increase output
-        assert!(output > input);
+        // Negative offsets wrap backwards from the base
+        reverb.reverb_current_addr = 0;
+        assert_eq!(reverb.read_reverb_buffer(&spu_ram, -12), 0x1BCD);
     }
 
     #[test]
-    fn test_reverb_little_endian_storage() {
+    fn test_reverb_address_advance() {
         let mut reverb = ReverbConfig::new();
-        let mut spu_ram = vec![0u8; 512 * 1024];
-
-        reverb.reverb_start_addr = 0x1000;
-        reverb.reverb_end_addr = 0x2000;
-        reverb.reverb_current_addr = 0;
+        reverb.reverb_start_addr = (RAM_SIZE - 0x100) as u32;
 
-        // Write value with distinct bytes
-        reverb.write_reverb_buffer(&mut spu_ram, 0, 0x1234);
+        for _ in 0..10 {
+            reverb.advance_reverb_address(RAM_SIZE as u32);
+        }
 
-        // Check little-endian storage
-        assert_eq!(spu_ram[0x1000], 0x34); // Low byte
-        assert_eq!(spu_ram[0x1001], 0x12); // High byte
+        // One halfword per reverb tick
+        assert_eq!(reverb.reverb_current_addr, 20);
     }
 
     #[test]
-    fn test_reverb_comb_filters_multiple_taps() {
+    fn test_reverb_address_wrap_at_end() {
         let mut reverb = ReverbConfig::new();
-        let spu_ram = vec![0u8; 512 * 1024];
-
-        reverb.reverb_start_addr = 0x1000;
-        reverb.reverb_end_addr = 0x2000;
+        reverb.reverb_start_addr = (RAM_SIZE - 0x10) as u32;
+        reverb.reverb_current_addr = 14;
 
-        // Set non-zero comb volumes
-        reverb.comb_volume1 = 0x1000;
-        reverb.comb_volume2 = 0x1000;
-        reverb.comb_volume3 = 0x1000;
-        reverb.comb_volume4 = 0x1000;
+        reverb.advance_reverb_address(RAM_SIZE as u32);
 
-        let input = 1000i32;
-        let output = reverb.apply_comb_filters(input, &spu_ram, 0);
-
-        // Output should include input contribution
-        // Exact value depends on buffer contents, but should be reasonable
-        assert!(output.abs() < 100000);
+        assert_eq!(reverb.reverb_current_addr, 0);
     }
 
     #[test]
-    fn test_reverb_process_with_configured_area() {
+    fn test_reverb_zero_work_area() {
         let mut reverb = ReverbConfig::new();
         reverb.enabled = true;
         reverb.reverb_start_addr = 0x1000;
-        reverb.reverb_end_addr = 0x2000;
-        reverb.input_volume_left = 0x4000;
-        reverb.input_volume_right = 0x4000;
-
-        let mut spu_ram = vec![0u8; 512 * 1024];
-
-        // Process multiple samples
-        for _ in 0..100 {
-            let (_left, _right) = reverb.process(1000, 1000, &mut spu_ram);
-
-            // Should produce valid output (always i16, so always valid)
-            // Just verify it doesn't crash
-        }
-    }
-
-    #[test]
-    fn test_reverb_parameters_independence() {
-        let mut reverb1 = ReverbConfig::new();
-        let reverb2 = ReverbConfig::new();
-
-        reverb1.apf_volume1 = 0x1000;
-        reverb1.comb_volume1 = 0x2000;
+        let mut spu_ram = vec![0u8; 0x1000];
 
-        // reverb2 should remain independent
-        assert_eq!(reverb2.apf_volume1, 0);
-        assert_eq!(reverb2.comb_volume1, 0);
-    }
-
-    #[test]
-    fn test_reverb_disabled_preserves_input() {
-        let mut reverb = ReverbConfig::new();
-        reverb.enabled = false;
-
-        let mut spu_ram = vec![0u8; 512 * 1024];
-
-        let input_left = 12345i16;
-        let input_right = 23456i16;
-
-        let (left, right) = reverb.process(input_left, input_right, &mut spu_ram);
+        reverb.write_reverb_buffer(&mut spu_ram, 0, 0x1234);
+        assert_eq!(reverb.read_reverb_buffer(&spu_ram, 0), 0);
 
-        // Disabled reverb should pass through unchanged
-        assert_eq!(left, input_left);
-        assert_eq!(right, input_right);
+        reverb.advance_reverb_address(spu_ram.len() as u32);
+        assert_eq!(reverb.reverb_current_addr, 0);
     }
 
     #[test]
-    fn test_reverb_volume_scaling() {
+    fn test_reverb_little_endian_storage() {
         let mut reverb = ReverbConfig::new();
         reverb.enabled = true;
         reverb.reverb_start_addr = 0x1000;
-        reverb.reverb_end_addr = 0x2000;
-
-        // Test different input volumes
-        let volumes = [0i16, 0x2000, 0x4000, 0x6000, 0x7FFF];
+        let mut spu_ram = vec![0u8; RAM_SIZE];
 
-        let mut spu_ram = vec![0u8; 512 * 1024];
-
-        for volume in &volumes {
-            reverb.input_volume_left = *volume;
-            reverb.input_volume_right = *volume;
-
-            let (_left, _right) = reverb.process(1000, 1000, &mut spu_ram);
+        reverb.write_reverb_buffer(&mut spu_ram, 0, 0x1234);
 
-            // Output should scale with volume (roughly)
-            // Values are always i16, so always valid - just verify no crash
-        }
+        assert_eq!(spu_ram[0x1000], 0x34); // Low byte
+        assert_eq!(spu_ram[0x1001], 0x12); // High byte
     }
 
     #[test]
-    fn test_reverb_buffer_offset_calculation() {
+    fn test_apf_zero_volume_is_pure_delay() {
         let mut reverb = ReverbConfig::new();
-        let mut spu_ram = vec![0u8; 512 * 1024];
+        reverb.enabled = true;
+        let mut spu_ram = vec![0u8; RAM_SIZE];
+        reverb.write_reverb_buffer(&mut spu_ram, 0x10, 500);
 
-        reverb.reverb_start_addr = 0x1000;
-        reverb.reverb_end_addr = 0x1100; // 256 bytes
-        reverb.reverb_current_addr = 100;
-
-        // Write at different offsets
-        reverb.write_reverb_buffer(&mut spu_ram, 0, 0x1111);
-        reverb.write_reverb_buffer(&mut spu_ram, 50, 0x2222);
-        reverb.write_reverb_buffer(&mut spu_ram, 200, 0x3333); // Will wrap
-
-        // Verify reads
-        assert_eq!(reverb.read_reverb_buffer(&spu_ram, 0), 0x1111);
-        assert_eq!(reverb.read_reverb_buffer(&spu_ram, 50), 0x2222);
-        assert_eq!(reverb.read_reverb_buffer(&spu_ram, 200), 0x3333);
+        let output = reverb.apply_apf(&mut spu_ram, 1000, 0x20, 0x10, 0);
+
+        assert_eq!(output, 500);
+        assert_eq!(reverb.read_reverb_buffer(&spu_ram, 0x20), 1000);
     }
 
     #[test]
-    fn test_reverb_stereo_independence() {
+    fn test_apf_feedback_and_feedforward() {
         let mut reverb = ReverbConfig::new();
         reverb.enabled = true;
-        reverb.reverb_start_addr = 0x1000;
-        reverb.reverb_end_addr = 0x2000;
-        reverb.input_volume_left = 0x7FFF;
-        reverb.input_volume_right = 0x0000;
-
-        let mut spu_ram = vec![0u8; 512 * 1024];
+        let mut spu_ram = vec![0u8; RAM_SIZE];
+        reverb.write_reverb_buffer(&mut spu_ram, 0x10, 1000);
 
-        let (left, right) = reverb.process(1000, 1000, &mut spu_ram);
+        // stored = 1000 - 0.5*1000 = 500, out = 1000 + 0.5*500 = 1250
+        let output = reverb.apply_apf(&mut spu_ram, 1000, 0x20, 0x10, 0x4000);
 
-        // With right volume at 0, right channel should be close to original
-        // (might have some reverb contribution)
-        assert_ne!(left, right);
+        assert_eq!(reverb.read_reverb_buffer(&spu_ram, 0x20), 500);
+        assert_eq!(output, 1250);
     }
 }
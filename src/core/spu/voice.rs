@@ -20,6 +20,7 @@
 
 use super::adpcm::ADPCMState;
 use super::adsr::{ADSREnvelope, ADSRPhase};
+use crate::core::save_state::{StateSave, VoiceState};
 
 /// Individual voice channel
 ///
@@ -73,6 +74,18 @@ pub struct Voice {
 
     /// Noise mode enabled
     pub(crate) noise_enabled: bool,
+
+    /// Voice output is also sent to the reverb unit (EON)
+    pub(crate) reverb_enabled: bool,
+
+    /// Byte address of the block decoded since the last IRQ check
+    pub(crate) decoded_block: Option<u32>,
+
+    /// Pitch modulated by the previous voice's output (PMON)
+    pub(crate) pitch_modulation: bool,
+
+    /// Last output sample after the envelope, before volume (VxOUTX)
+    pub(crate) output: i16,
 }
 
 #[allow(dead_code)]
@@ -104,6 +117,10 @@ impl Voice {
             loop_flag: false,
             final_block: false,
             noise_enabled: false,
+            reverb_enabled: false,
+            decoded_block: None,
+            pitch_modulation: false,
+            output: 0,
         }
     }
 
@@ -120,8 +137,7 @@ impl Voice {
         self.loop_flag = false;
         self.final_block = false;
         self.key_off = false;
-        self.adsr.phase = ADSRPhase::Attack;
-        self.adsr.level = 0;
+        self.adsr.key_on();
 
         log::trace!("Voice {} key on", self.id);
     }
@@ -131,17 +147,21 @@ impl Voice {
     /// Begins the release phase of the ADSR envelope.
     pub fn key_off(&mut self) {
         self.key_off = true;
-        self.adsr.phase = ADSRPhase::Release;
+        self.adsr.key_off();
 
         log::trace!("Voice {} key off", self.id);
     }
 
     /// Render a single stereo sample from this voice
     ///
+    /// Noise voices replace the ADPCM sample with the noise level but still
+    /// step through their ADPCM blocks, so loop/end flags keep applying.
+    ///
     /// # Arguments
     ///
     /// * `spu_ram` - Reference to SPU RAM for ADPCM data access
-    /// * `noise` - Mutable reference to noise generator
+    /// * `noise` - Reference to the shared noise generator
+    /// * `modulator` - Output of the previous voice, used when PMON is set
     ///
     /// # Returns
     ///
@@ -150,40 +170,64 @@ impl Voice {
     pub fn render_sample(
         &mut self,
         spu_ram: &[u8],
-        noise: &mut super::noise::NoiseGenerator,
+        noise: &super::noise::NoiseGenerator,
+        modulator: i16,
     ) -> (i16, i16) {
         if !self.enabled || self.adsr.phase == ADSRPhase::Off {
+            self.output = 0;
             return (0, 0);
         }
 
+        // Check if we need to decode a new ADPCM block
+        if self.needs_decode() {
+            self.decode_block(spu_ram);
+        }
+
         // Get sample (ADPCM or noise)
         let sample = if self.noise_enabled {
-            noise.generate()
+            noise.output()
         } else {
-            // Check if we need to decode a new ADPCM block
-            if self.needs_decode() {
-                self.decode_block(spu_ram);
-            }
-
             // Get interpolated sample at current position
             self.interpolate_sample()
         };
 
         // Apply ADSR envelope
         let enveloped = self.apply_envelope(sample);
+        self.output = enveloped;
 
         // Apply volume (fixed-point multiply with 15-bit fraction)
         let left = ((enveloped as i32 * self.volume_left as i32) >> 15) as i16;
         let right = ((enveloped as i32 * self.volume_right as i32) >> 15) as i16;
 
-        // Advance playback position (only for non-noise samples)
-        if !self.noise_enabled {
-            self.advance_position();
-        }
+        // Advance playback position
+        self.advance_position(self.pitch_step(modulator));
 
         (left, right)
     }
 
+    /// Compute the pitch step for this sample
+    ///
+    /// With pitch modulation enabled, the previous voice's output scales the
+    /// pitch by `(output + 0x8000) / 0x8000`, i.e. from 0 to nearly 2x.
+    /// Voice 0 has no previous voice and ignores PMON.
+    ///
+    /// # Arguments
+    ///
+    /// * `modulator` - Output of the previous voice (VxOUTX)
+    ///
+    /// # Returns
+    ///
+    /// Pitch step in 4.12 fixed point
+    #[inline(always)]
+    pub(crate) fn pitch_step(&self, modulator: i16) -> u16 {
+        if !self.pitch_modulation || self.id == 0 {
+            return self.sample_rate;
+        }
+
+        let factor = modulator as i32 + 0x8000;
+        (((self.sample_rate as i16 as i32) * factor) >> 15) as u16
+    }
+
     /// Check if a new ADPCM block needs to be decoded
     ///
     /// # Returns
@@ -222,11 +266,18 @@ impl Voice {
         }
 
         let block = &spu_ram[block_addr..block_addr + 16];
+        self.decoded_block = Some(block_addr as u32);
 
         // Check loop flags in block header
         let flags = block[1];
         let loop_end = (flags & 0x01) != 0;
         let loop_repeat = (flags & 0x02) != 0;
+        let loop_start = (flags & 0x04) != 0;
+
+        // A loop-start block becomes the new repeat address
+        if loop_start {
+            self.repeat_address = (block_addr / 8) as u16;
+        }
 
         // Decode the block
         self.decoded_samples = self.adpcm_state.decode_block(block);
@@ -251,8 +302,13 @@ impl Voice {
             self.loop_flag = false;
         }
 
-        // Reset position to start of new block
-        self.adpcm_state.position = 0.0;
+        // Move to the start of the new block, keeping any fractional
+        // overshoot from the previous one
+        if self.adpcm_state.position >= 28.0 {
+            self.adpcm_state.position -= 28.0;
+        } else {
+            self.adpcm_state.position = 0.0;
+        }
     }
 
     /// Get interpolated sample at current position
@@ -304,12 +360,14 @@ impl Voice {
 
     /// Advance the playback position
     ///
-    /// Updates position based on sample rate and handles block transitions.
-    pub(crate) fn advance_position(&mut self) {
-        // Calculate step based on sample rate
-        // Sample rate is in 4.12 fixed point format
-        // Base sample rate is 44100 Hz
-        let step = (self.sample_rate as f32) / 4096.0;
+    /// Updates position based on the pitch step and handles block transitions.
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - Pitch step in 4.12 fixed point (0x1000 = 44100 Hz)
+    pub(crate) fn advance_position(&mut self, pitch: u16) {
+        // Hardware limits the step to 0x4000 (four samples per output sample)
+        let step = (pitch.min(0x4000) as f32) / 4096.0;
 
         self.adpcm_state.position += step;
 
@@ -335,6 +393,59 @@ impl Voice {
     }
 }
 
+impl StateSave for Voice {
+    type State = VoiceState;
+
+    fn to_state(&self) -> VoiceState {
+        VoiceState {
+            volume_left: self.volume_left,
+            volume_right: self.volume_right,
+            sample_rate: self.sample_rate,
+            start_address: self.start_address,
+            repeat_address: self.repeat_address,
+            current_address: self.current_address,
+            adsr: self.adsr.to_state(),
+            enabled: self.enabled,
+            adpcm_old: self.adpcm_state.prev_samples[0],
+            adpcm_older: self.adpcm_state.prev_samples[1],
+            adpcm_position: self.adpcm_state.position,
+            decoded_samples: self.decoded_samples.clone(),
+            key_on: self.key_on,
+            key_off: self.key_off,
+            loop_flag: self.loop_flag,
+            final_block: self.final_block,
+            noise_enabled: self.noise_enabled,
+            reverb_enabled: self.reverb_enabled,
+            pitch_modulation: self.pitch_modulation,
+            decoded_block: self.decoded_block,
+            output: self.output,
+        }
+    }
+
+    fn restore_from_state(&mut self, state: &VoiceState) {
+        self.volume_left = state.volume_left;
+        self.volume_right = state.volume_right;
+        self.sample_rate = state.sample_rate;
+        self.start_address = state.start_address;
+        self.repeat_address = state.repeat_address;
+        self.current_address = state.current_address;
+        self.adsr.restore_from_state(&state.adsr);
+        self.enabled = state.enabled;
+        self.adpcm_state.prev_samples = [state.adpcm_old, state.adpcm_older];
+        self.adpcm_state.position = state.adpcm_position;
+        self.decoded_samples = state.decoded_samples.clone();
+        self.key_on = state.key_on;
+        self.key_off = state.key_off;
+        self.loop_flag = state.loop_flag;
+        self.final_block = state.final_block;
+        self.noise_enabled = state.noise_enabled;
+        self.reverb_enabled = state.reverb_enabled;
+        self.pitch_modulation = state.pitch_modulation;
+        self.decoded_block = state.decoded_block;
+        self.output = state.output;
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::field_reassign_with_default)]
 mod tests {
@@ -386,9 +497,9 @@ mod tests {
         voice.enabled = false;
 
         let spu_ram = vec![0u8; 512 * 1024];
-        let mut noise = NoiseGenerator::new();
+        let noise = NoiseGenerator::new();
 
-        let (left, right) = voice.render_sample(&spu_ram, &mut noise);
+        let (left, right) = voice.render_sample(&spu_ram, &noise, 0);
 
         assert_eq!(left, 0);
         assert_eq!(right, 0);
@@ -400,19 +511,45 @@ mod tests {
         voice.enabled = true;
         voice.noise_enabled = true;
         voice.adsr.phase = ADSRPhase::Sustain;
-        voice.adsr.level = 32767;
-        voice.volume_left = 0x4000;
-        voice.volume_right = 0x4000;
+        voice.adsr.level = 0x7FFF;
+        voice.volume_left = 0x7FFF;
+        voice.volume_right = 0x7FFF;
+        voice.sample_rate = 0x1000;
+        voice.decoded_samples = vec![1000i16; 28];
 
         let spu_ram = vec![0u8; 512 * 1024];
         let mut noise = NoiseGenerator::new();
-        noise.set_frequency(0, 1);
+        noise.set_frequency(15, 3);
+        for _ in 0..4 {
+            noise.tick();
+        }
+
+        voice.render_sample(&spu_ram, &noise, 0);
+
+        // The noise level replaces the ADPCM sample, but playback still advances
+        assert_eq!(noise.output(), 0x000F);
+        assert_eq!(voice.output, 14); // 0x000F scaled by 0x7FFF/0x8000
+        assert_eq!(voice.adpcm_state.position, 1.0);
+    }
 
-        let (_left, _right) = voice.render_sample(&spu_ram, &mut noise);
+    #[test]
+    fn test_voice_pitch_modulation_scales_step() {
+        let mut voice = Voice::new(1);
+        voice.sample_rate = 0x1000;
+
+        // PMON off: the modulator is ignored
+        assert_eq!(voice.pitch_step(0x4000), 0x1000);
+
+        voice.pitch_modulation = true;
+        assert_eq!(voice.pitch_step(0), 0x1000);
+        assert_eq!(voice.pitch_step(0x4000), 0x1800);
+        assert_eq!(voice.pitch_step(-0x8000), 0);
 
-        // Noise mode should produce output
-        // Output depends on noise state and is always valid i16
-        // Just verify it doesn't crash
+        // Voice 0 has no previous voice to modulate it
+        let mut voice0 = Voice::new(0);
+        voice0.sample_rate = 0x1000;
+        voice0.pitch_modulation = true;
+        assert_eq!(voice0.pitch_step(0x4000), 0x1000);
     }
 
     #[test]
@@ -543,7 +680,7 @@ mod tests {
         voice.adpcm_state.position = 10.0;
         voice.decoded_samples = vec![0i16; 28];
 
-        voice.advance_position();
+        voice.advance_position(voice.sample_rate);
 
         // Position should advance by 1.0
         assert_eq!(voice.adpcm_state.position, 11.0);
@@ -558,7 +695,7 @@ mod tests {
         voice.decoded_samples = vec![0i16; 28];
         voice.loop_flag = false;
 
-        voice.advance_position();
+        voice.advance_position(voice.sample_rate);
 
         // Should advance past block boundary
         assert!(voice.adpcm_state.position >= 28.0);
@@ -575,7 +712,7 @@ mod tests {
         voice.final_block = true;
         voice.decoded_samples = vec![0i16; 28];
 
-        voice.advance_position();
+        voice.advance_position(voice.sample_rate);
 
         // Should disable voice on final block completion
         assert!(!voice.enabled);
@@ -598,9 +735,9 @@ mod tests {
         voice.adpcm_state.position = 0.0;
 
         let spu_ram = vec![0u8; 512 * 1024];
-        let mut noise = NoiseGenerator::new();
+        let noise = NoiseGenerator::new();
 
-        let (left, right) = voice.render_sample(&spu_ram, &mut noise);
+        let (left, right) = voice.render_sample(&spu_ram, &noise, 0);
 
         // Left should be roughly half of right (due to volume difference)
         assert!(left.abs() > right.abs());
@@ -620,9 +757,9 @@ mod tests {
         voice.adpcm_state.position = 0.0;
 
         let spu_ram = vec![0u8; 512 * 1024];
-        let mut noise = NoiseGenerator::new();
+        let noise = NoiseGenerator::new();
 
-        let (left, _right) = voice.render_sample(&spu_ram, &mut noise);
+        let (left, _right) = voice.render_sample(&spu_ram, &noise, 0);
 
         // Output should be scaled by ADSR level
         // Expected: roughly 10000 * (16383 / 32767) ≈ 5000
@@ -662,7 +799,7 @@ mod tests {
             voice.sample_rate = *rate;
             let initial_pos = voice.adpcm_state.position;
 
-            voice.advance_position();
+            voice.advance_position(voice.sample_rate);
 
             if *rate == 0 {
                 // Zero rate should not advance
@@ -706,9 +843,9 @@ mod tests {
         voice.decoded_samples = vec![10000i16; 28];
 
         let spu_ram = vec![0u8; 512 * 1024];
-        let mut noise = NoiseGenerator::new();
+        let noise = NoiseGenerator::new();
 
-        let (left, right) = voice.render_sample(&spu_ram, &mut noise);
+        let (left, right) = voice.render_sample(&spu_ram, &noise, 0);
 
         // ADSR Off should produce silence
         assert_eq!(left, 0);
@@ -750,4 +887,53 @@ mod tests {
         assert!(!voice.final_block);
         assert_eq!(voice.adsr.phase, ADSRPhase::Attack);
     }
+
+    #[test]
+    fn test_voice_loop_start_block_sets_repeat_address() {
+        let mut voice = Voice::new(0);
+        let mut spu_ram = vec![0u8; 512 * 1024];
+
+        // Block 0x1000: loop start, samples = 1 << 12
+        spu_ram[0x1001] = 0x04;
+        spu_ram[0x1002..0x1010].fill(0x11);
+        // Block 0x1010: loop end + repeat, samples = 2 << 12
+        spu_ram[0x1011] = 0x03;
+        spu_ram[0x1012..0x1020].fill(0x22);
+
+        voice.start_address = 0x1000 / 8;
+        voice.sample_rate = 0x1000;
+        voice.key_on();
+
+        let noise = NoiseGenerator::new();
+        let mut played = Vec::new();
+        for _ in 0..(28 * 3) {
+            voice.render_sample(&spu_ram, &noise, 0);
+            played.push(voice.decoded_samples[0]);
+        }
+
+        // The loop-start block became the loop point and plays again after the end block
+        assert_eq!(voice.repeat_address, 0x1000 / 8);
+        assert_eq!(played[0], 4096);
+        assert_eq!(played[28], 8192);
+        assert_eq!(played[56], 4096);
+        assert!(voice.enabled);
+    }
+
+    #[test]
+    fn test_voice_pitch_step_carries_and_clamps() {
+        let mut voice = Voice::new(0);
+        let spu_ram = vec![0u8; 512 * 1024];
+
+        // 1.5 samples per step: 27.0 -> 28.5 carries 0.5 into the next block
+        voice.sample_rate = 0x1800;
+        voice.adpcm_state.position = 27.0;
+        voice.advance_position(voice.sample_rate);
+        voice.decode_block(&spu_ram);
+        assert_eq!(voice.adpcm_state.position, 0.5);
+
+        // Pitch above 0x4000 is limited to 4 samples per step
+        voice.sample_rate = 0xFFFF;
+        voice.advance_position(voice.sample_rate);
+        assert_eq!(voice.adpcm_state.position, 4.5);
+    }
 }
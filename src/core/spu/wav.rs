@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! WAV recording of SPU output
+//!
+//! Writes 16-bit stereo PCM at the SPU output rate. The RIFF and data chunk
+//! sizes are unknown while recording, so the header is written with zero
+//! sizes and patched by [`WavWriter::finish`].
+
+use std::fs::File;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use super::SPU;
+use crate::core::error::Result;
+
+/// Number of output channels
+const CHANNELS: u16 = 2;
+/// Bits per sample
+const BITS_PER_SAMPLE: u16 = 16;
+/// Bytes per stereo frame
+const BLOCK_ALIGN: u16 = CHANNELS * BITS_PER_SAMPLE / 8;
+/// Size of the RIFF/fmt/data headers
+const HEADER_SIZE: u32 = 44;
+
+/// Streaming 16-bit stereo WAV writer
+///
+/// # Example
+///
+/// ```
+/// use psrx::core::spu::WavWriter;
+/// use std::io::Cursor;
+///
+/// let mut wav = WavWriter::new(Cursor::new(Vec::new())).unwrap();
+/// wav.write_samples(&[(100, -100), (200, -200)]).unwrap();
+/// let data = wav.finish().unwrap().into_inner();
+/// assert_eq!(data.len(), 44 + 2 * 4);
+/// ```
+pub struct WavWriter<W: Write + Seek> {
+    /// Output stream
+    writer: W,
+
+    /// Stereo frames written so far
+    frames: u32,
+}
+
+impl WavWriter<BufWriter<File>> {
+    /// Create a WAV file and write its header
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - File to create (truncated if it exists)
+    ///
+    /// # Returns
+    ///
+    /// `Err(EmulatorError::Io)` if the file cannot be created
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W: Write + Seek> WavWriter<W> {
+    /// Start a WAV stream on `writer`
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Output positioned at the start of the file
+    pub fn new(mut writer: W) -> Result<Self> {
+        let byte_rate = SPU::SAMPLE_RATE * BLOCK_ALIGN as u32;
+
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&(HEADER_SIZE - 8).to_le_bytes())?;
+        writer.write_all(b"WAVE")?;
+
+        writer.write_all(b"fmt ")?;
+        writer.write_all(&16u32.to_le_bytes())?;
+        writer.write_all(&1u16.to_le_bytes())?; // PCM
+        writer.write_all(&CHANNELS.to_le_bytes())?;
+        writer.write_all(&SPU::SAMPLE_RATE.to_le_bytes())?;
+        writer.write_all(&byte_rate.to_le_bytes())?;
+        writer.write_all(&BLOCK_ALIGN.to_le_bytes())?;
+        writer.write_all(&BITS_PER_SAMPLE.to_le_bytes())?;
+
+        writer.write_all(b"data")?;
+        writer.write_all(&0u32.to_le_bytes())?;
+
+        Ok(Self { writer, frames: 0 })
+    }
+
+    /// Append stereo samples
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - (left, right) sample pairs
+    pub fn write_samples(&mut self, samples: &[(i16, i16)]) -> Result<()> {
+        for &(left, right) in samples {
+            self.writer.write_all(&left.to_le_bytes())?;
+            self.writer.write_all(&right.to_le_bytes())?;
+        }
+        self.frames += samples.len() as u32;
+        Ok(())
+    }
+
+    /// Number of stereo frames written so far
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// Patch the chunk sizes and flush the stream
+    ///
+    /// # Returns
+    ///
+    /// The underlying writer, positioned at the end of the data
+    pub fn finish(mut self) -> Result<W> {
+        let data_size = self.frames * BLOCK_ALIGN as u32;
+
+        self.writer.seek(SeekFrom::Start(4))?;
+        self.writer
+            .write_all(&(HEADER_SIZE - 8 + data_size).to_le_bytes())?;
+        self.writer.seek(SeekFrom::Start(40))?;
+        self.writer.write_all(&data_size.to_le_bytes())?;
+        self.writer.seek(SeekFrom::End(0))?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn u16_at(data: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes([data[offset], data[offset + 1]])
+    }
+
+    fn u32_at(data: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_wav_header_fields() {
+        let mut wav = WavWriter::new(Cursor::new(Vec::new())).unwrap();
+        wav.write_samples(&[(1, -1); 10]).unwrap();
+        let data = wav.finish().unwrap().into_inner();
+
+        assert_eq!(&data[0..4], b"RIFF");
+        assert_eq!(u32_at(&data, 4), 36 + 40);
+        assert_eq!(&data[8..16], b"WAVEfmt ");
+        assert_eq!(u16_at(&data, 20), 1, "PCM");
+        assert_eq!(u16_at(&data, 22), 2, "stereo");
+        assert_eq!(u32_at(&data, 24), 44_100);
+        assert_eq!(u32_at(&data, 28), 44_100 * 4);
+        assert_eq!(u16_at(&data, 32), 4);
+        assert_eq!(u16_at(&data, 34), 16);
+        assert_eq!(&data[36..40], b"data");
+        assert_eq!(u32_at(&data, 40), 40);
+        assert_eq!(data.len(), 44 + 40);
+    }
+
+    #[test]
+    fn test_wav_samples_little_endian_interleaved() {
+        let mut wav = WavWriter::new(Cursor::new(Vec::new())).unwrap();
+        wav.write_samples(&[(0x1234, -2)]).unwrap();
+        assert_eq!(wav.frames(), 1);
+        let data = wav.finish().unwrap().into_inner();
+
+        assert_eq!(&data[44..], &[0x34, 0x12, 0xFE, 0xFF]);
+    }
+}
@@ -18,6 +18,18 @@
 //! This module manages the memory-mapped I/O registers for controller communication.
 
 use super::super::controller::Controller;
+use super::super::memory_card::MemoryCard;
+use super::super::save_state::{ControllerData, ControllerState, StateSave};
+use std::collections::VecDeque;
+
+/// Device addressed by the first byte of a transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PortDevice {
+    /// Controller (address 0x01)
+    Controller,
+    /// Memory card (address 0x81)
+    MemoryCard,
+}
 
 /// PlayStation Controller Port Registers
 ///
@@ -36,13 +48,36 @@ use super::super::controller::Controller;
 /// 2. Write bytes to JOY_TX_DATA
 /// 3. Read responses from JOY_RX_DATA
 /// 4. Write to JOY_CTRL to deselect controller
+///
+/// The first byte of each transfer addresses either the controller (0x01)
+/// or the memory card (0x81) on the selected port.
+///
+/// # Timing
+/// Each byte takes `JOY_BAUD * 8` CPU cycles to shift out (a reload value of
+/// 0 completes immediately). One more byte can wait in the TX FIFO while
+/// another is shifting, and received bytes queue in an 8-byte RX FIFO. When
+/// the addressed device acknowledges a byte, its /ACK pulse arrives a short
+/// delay after the byte completes and raises IRQ7 if JOY_CTRL bit 12 is set;
+/// advance this with [`ControllerPorts::tick`].
 pub struct ControllerPorts {
     /// JOY_TX_DATA (0x1F801040) - Transmit data
     tx_data: u8,
 
-    /// JOY_RX_DATA (0x1F801040) - Receive data (same register)
+    /// JOY_RX_DATA (0x1F801040) - Last byte read from the RX FIFO
     rx_data: u8,
 
+    /// Received bytes waiting to be read (up to 8)
+    rx_fifo: VecDeque<u8>,
+
+    /// Byte waiting in the TX FIFO while another is shifting out
+    tx_pending: Option<u8>,
+
+    /// Byte being shifted out and the CPU cycles until it completes
+    transfer: Option<(u8, u32)>,
+
+    /// CPU cycles until the /ACK pulse of the last byte
+    ack_ticks: Option<u32>,
+
     /// JOY_STAT (0x1F801044) - Status register
     stat: u32,
 
@@ -58,8 +93,14 @@ pub struct ControllerPorts {
     /// Connected controllers (port 1 and 2)
     controllers: [Option<Controller>; 2],
 
+    /// Inserted memory cards (port 1 and 2)
+    memory_cards: [Option<MemoryCard>; 2],
+
     /// Currently selected port (0 or 1)
     selected_port: Option<usize>,
+
+    /// Device addressed by the current transfer
+    active_device: Option<PortDevice>,
 }
 
 impl ControllerPorts {
@@ -70,18 +111,35 @@ impl ControllerPorts {
         Self {
             tx_data: 0xFF,
             rx_data: 0xFF,
+            rx_fifo: VecDeque::new(),
+            tx_pending: None,
+            transfer: None,
+            ack_ticks: None,
             stat: 0x05, // TX ready (bit 0), RX ready (bit 2)
             mode: 0x000D,
             ctrl: 0,
             baud: 0,
             controllers: [Some(Controller::new()), None], // Port 1 has controller
+            memory_cards: [None, None],
             selected_port: None,
+            active_device: None,
         }
     }
 
+    /// Depth of the RX FIFO in bytes
+    const RX_FIFO_SIZE: usize = 8;
+
+    /// CPU cycles from the end of a byte to a controller's /ACK pulse
+    pub const CONTROLLER_ACK_DELAY: u32 = 450;
+
+    /// CPU cycles from the end of a byte to a memory card's /ACK pulse
+    pub const MEMORY_CARD_ACK_DELAY: u32 = 170;
+
     /// Write to TX_DATA register (0x1F801040)
     ///
-    /// Transmits a byte to the selected controller and receives a response byte.
+    /// Starts shifting a byte out to the selected port, or queues it in the
+    /// TX FIFO if a transfer is already in progress. The response byte
+    /// arrives in the RX FIFO when the transfer completes.
     ///
     /// # Arguments
     ///
@@ -89,31 +147,166 @@ impl ControllerPorts {
     pub fn write_tx_data(&mut self, value: u8) {
         self.tx_data = value;
 
-        // If controller is selected, perform transfer
+        if self.transfer.is_some() {
+            // TX ready (bit 0) drops while the FIFO is full
+            self.tx_pending = Some(value);
+            self.stat &= !0x01;
+        } else {
+            self.start_transfer(value);
+        }
+    }
+
+    /// Begin shifting a byte out at the current baud rate
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte to transmit
+    fn start_transfer(&mut self, value: u8) {
+        // TX finished (bit 2) stays clear until the byte is out, and the
+        // /ACK input returns high
+        self.stat &= !0x0084;
+
+        let ticks = self.baud as u32 * 8;
+        if ticks == 0 {
+            self.finish_transfer(value);
+        } else {
+            self.transfer = Some((value, ticks));
+        }
+    }
+
+    /// Exchange a fully shifted byte with the addressed device
+    ///
+    /// Queues the response in the RX FIFO, schedules the device's /ACK
+    /// pulse and starts the byte waiting in the TX FIFO, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte that was transmitted
+    fn finish_transfer(&mut self, value: u8) {
+        let mut rx = 0xFF;
+        let mut ack_delay = None;
+
         if let Some(port) = self.selected_port {
-            if let Some(controller) = &mut self.controllers[port] {
-                self.rx_data = controller.transfer(value);
-            } else {
-                self.rx_data = 0xFF; // No controller
+            // The first byte of a transfer addresses the device
+            if self.active_device.is_none() {
+                self.active_device = match value {
+                    0x01 => Some(PortDevice::Controller),
+                    0x81 => Some(PortDevice::MemoryCard),
+                    _ => None,
+                };
             }
-        } else {
-            self.rx_data = 0xFF;
+
+            match self.active_device {
+                Some(PortDevice::Controller) => {
+                    if let Some(controller) = &mut self.controllers[port] {
+                        rx = controller.transfer(value);
+                        if controller.ack() {
+                            ack_delay = Some(Self::CONTROLLER_ACK_DELAY);
+                        }
+                    }
+                }
+                Some(PortDevice::MemoryCard) => {
+                    if let Some(card) = &mut self.memory_cards[port] {
+                        rx = card.transfer(value);
+                        if card.ack() {
+                            ack_delay = Some(Self::MEMORY_CARD_ACK_DELAY);
+                        }
+                    }
+                }
+                None => {}
+            }
+        }
+
+        if self.rx_fifo.len() < Self::RX_FIFO_SIZE {
+            self.rx_fifo.push_back(rx);
+        }
+        self.ack_ticks = ack_delay;
+
+        // RX FIFO not empty (bit 1), TX finished (bit 2)
+        self.stat |= 0x06;
+
+        if let Some(next) = self.tx_pending.take() {
+            self.stat |= 0x01;
+            self.start_transfer(next);
+        }
+    }
+
+    /// Advance the serial transfer and /ACK timing
+    ///
+    /// # Arguments
+    ///
+    /// * `cycles` - Number of CPU cycles elapsed
+    ///
+    /// # Returns
+    ///
+    /// true if an /ACK pulse raised the controller interrupt (IRQ7)
+    pub fn tick(&mut self, cycles: u32) -> bool {
+        let mut irq = false;
+        let mut remaining = cycles;
+
+        // A queued byte starts as soon as the previous one completes, so
+        // the leftover cycles count towards it as well as towards /ACK
+        while let Some((value, ticks)) = self.transfer.take() {
+            if remaining < ticks {
+                self.transfer = Some((value, ticks - remaining));
+                break;
+            }
+            irq |= self.advance_ack(ticks);
+            remaining -= ticks;
+            self.finish_transfer(value);
+        }
+
+        irq | self.advance_ack(remaining)
+    }
+
+    /// Count down to a pending /ACK pulse
+    ///
+    /// # Arguments
+    ///
+    /// * `cycles` - Number of CPU cycles elapsed
+    ///
+    /// # Returns
+    ///
+    /// true if the pulse arrived with the ACK interrupt enabled
+    fn advance_ack(&mut self, cycles: u32) -> bool {
+        let Some(ticks) = self.ack_ticks else {
+            return false;
+        };
+
+        if cycles < ticks {
+            self.ack_ticks = Some(ticks - cycles);
+            return false;
         }
+        self.ack_ticks = None;
+
+        // /ACK input level (bit 7)
+        self.stat |= 0x0080;
 
-        // Set RX ready flag (bit 1)
-        self.stat |= 0x02;
+        // ACK interrupt enable (JOY_CTRL bit 12) sets the IRQ flag (bit 9)
+        if self.ctrl & 0x1000 != 0 {
+            self.stat |= 0x0200;
+            return true;
+        }
+        false
     }
 
     /// Read from RX_DATA register (0x1F801040)
     ///
-    /// Returns the last received byte from the controller.
+    /// Pops the next received byte. Reading an empty FIFO returns the last
+    /// byte again.
     ///
     /// # Returns
     ///
     /// Received byte
     pub fn read_rx_data(&mut self) -> u8 {
-        // Clear RX ready flag
-        self.stat &= !0x02;
+        if let Some(value) = self.rx_fifo.pop_front() {
+            self.rx_data = value;
+        }
+
+        // RX FIFO not empty flag
+        if self.rx_fifo.is_empty() {
+            self.stat &= !0x02;
+        }
         self.rx_data
     }
 
@@ -131,22 +324,36 @@ impl ControllerPorts {
         if (value & 0x0002) != 0 {
             // Determine which port based on DTR bits
             let port = if (value & 0x2000) != 0 { 1 } else { 0 };
-            self.selected_port = Some(port);
+            if self.selected_port != Some(port) {
+                if let Some(previous) = self.selected_port {
+                    self.deselect_devices(previous);
+                }
+                self.selected_port = Some(port);
+                self.active_device = None;
 
-            if let Some(controller) = &mut self.controllers[port] {
-                controller.select();
+                if let Some(controller) = &mut self.controllers[port] {
+                    controller.select();
+                }
+                if let Some(card) = &mut self.memory_cards[port] {
+                    card.select();
+                }
             }
 
             log::trace!("Controller port {} selected", port + 1);
         } else {
             // Deselect
             if let Some(port) = self.selected_port {
-                if let Some(controller) = &mut self.controllers[port] {
-                    controller.deselect();
-                }
+                self.deselect_devices(port);
                 log::trace!("Controller port {} deselected", port + 1);
             }
             self.selected_port = None;
+            self.active_device = None;
+
+            // Releasing the select line aborts any transfer in progress
+            self.transfer = None;
+            self.tx_pending = None;
+            self.ack_ticks = None;
+            self.stat = (self.stat & !0x0080) | 0x05;
         }
 
         // Acknowledge interrupt (bit 4)
@@ -155,6 +362,16 @@ impl ControllerPorts {
         }
     }
 
+    /// Release chip select for every device on a port
+    fn deselect_devices(&mut self, port: usize) {
+        if let Some(controller) = &mut self.controllers[port] {
+            controller.deselect();
+        }
+        if let Some(card) = &mut self.memory_cards[port] {
+            card.deselect();
+        }
+    }
+
     /// Read STAT register (0x1F801044)
     ///
     /// Returns the controller port status.
@@ -229,6 +446,106 @@ impl ControllerPorts {
     pub fn get_controller_mut(&mut self, port: usize) -> Option<&mut Controller> {
         self.controllers.get_mut(port).and_then(|c| c.as_mut())
     }
+
+    /// Set the digital button state of the controller at a port
+    ///
+    /// Takes effect from the next controller read (command 0x42).
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number (0 = port 1, 1 = port 2)
+    /// * `buttons` - 16-bit button state (active low: 0 = pressed)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::controller::buttons;
+    /// use psrx::core::system::ControllerPorts;
+    ///
+    /// let mut ports = ControllerPorts::new();
+    /// ports.set_digital(0, !buttons::START);
+    /// ```
+    pub fn set_digital(&mut self, port: usize, buttons: u16) {
+        if let Some(controller) = self.get_controller_mut(port) {
+            controller.set_buttons(buttons);
+        }
+    }
+
+    /// Set the analog stick positions of the controller at a port
+    ///
+    /// Sticks are only reported while the controller is in analog mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number (0 = port 1, 1 = port 2)
+    /// * `lx`, `ly` - Left stick X/Y (0x00-0xFF, 0x80 = center)
+    /// * `rx`, `ry` - Right stick X/Y (0x00-0xFF, 0x80 = center)
+    pub fn set_analog(&mut self, port: usize, lx: u8, ly: u8, rx: u8, ry: u8) {
+        if let Some(controller) = self.get_controller_mut(port) {
+            controller.set_sticks(lx, ly, rx, ry);
+        }
+    }
+
+    /// Switch the controller at a port between digital and analog mode
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number (0 = port 1, 1 = port 2)
+    /// * `enabled` - true for DualShock analog mode (ID 0x73)
+    pub fn set_analog_mode(&mut self, port: usize, enabled: bool) {
+        if let Some(controller) = self.get_controller_mut(port) {
+            controller.set_analog_mode(enabled);
+        }
+    }
+
+    /// Insert a memory card into a port
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number (0 = port 1, 1 = port 2)
+    /// * `card` - Memory card to insert
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::system::ControllerPorts;
+    /// use psrx::core::MemoryCard;
+    ///
+    /// let mut ports = ControllerPorts::new();
+    /// ports.insert_memory_card(0, MemoryCard::new());
+    /// assert!(ports.get_memory_card_mut(0).is_some());
+    /// ```
+    pub fn insert_memory_card(&mut self, port: usize, card: MemoryCard) {
+        if let Some(slot) = self.memory_cards.get_mut(port) {
+            *slot = Some(card);
+        }
+    }
+
+    /// Remove the memory card from a port
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number (0 = port 1, 1 = port 2)
+    ///
+    /// # Returns
+    ///
+    /// The removed card, if one was inserted
+    pub fn remove_memory_card(&mut self, port: usize) -> Option<MemoryCard> {
+        self.memory_cards.get_mut(port).and_then(|c| c.take())
+    }
+
+    /// Get mutable reference to memory card at port (0 or 1)
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number (0 = port 1, 1 = port 2)
+    ///
+    /// # Returns
+    ///
+    /// Optional mutable reference to memory card
+    pub fn get_memory_card_mut(&mut self, port: usize) -> Option<&mut MemoryCard> {
+        self.memory_cards.get_mut(port).and_then(|c| c.as_mut())
+    }
 }
 
 impl Default for ControllerPorts {
@@ -237,6 +554,73 @@ impl Default for ControllerPorts {
     }
 }
 
+impl StateSave for ControllerPorts {
+    type State = ControllerState;
+
+    fn to_state(&self) -> ControllerState {
+        ControllerState {
+            controllers: self
+                .controllers
+                .iter()
+                .map(|c| match c {
+                    Some(controller) => controller.save_data(),
+                    None => ControllerData {
+                        buttons: 0xFFFF,
+                        ..Default::default()
+                    },
+                })
+                .collect(),
+            tx_data: self.tx_data,
+            rx_data: self.rx_data,
+            rx_fifo: self.rx_fifo.iter().copied().collect(),
+            tx_pending: self.tx_pending,
+            transfer: self.transfer,
+            ack_ticks: self.ack_ticks,
+            stat: self.stat,
+            mode: self.mode,
+            ctrl: self.ctrl,
+            baud: self.baud,
+            selected_port: self.selected_port.map(|port| port as u8),
+            active_device: self.active_device.map(|device| match device {
+                PortDevice::Controller => 0,
+                PortDevice::MemoryCard => 1,
+            }),
+        }
+    }
+
+    /// Restore connected controllers and the port registers
+    ///
+    /// Ports without a controller ignore their saved entry.
+    fn restore_from_state(&mut self, state: &ControllerState) {
+        for (controller, data) in self.controllers.iter_mut().zip(&state.controllers) {
+            if let Some(controller) = controller {
+                controller.restore_data(data);
+            }
+        }
+
+        self.tx_data = state.tx_data;
+        self.rx_data = state.rx_data;
+        self.rx_fifo = state
+            .rx_fifo
+            .iter()
+            .copied()
+            .take(Self::RX_FIFO_SIZE)
+            .collect();
+        self.tx_pending = state.tx_pending;
+        self.transfer = state.transfer;
+        self.ack_ticks = state.ack_ticks;
+        self.stat = state.stat;
+        self.mode = state.mode;
+        self.ctrl = state.ctrl;
+        self.baud = state.baud;
+        self.selected_port = state.selected_port.map(|port| (port & 1) as usize);
+        self.active_device = state.active_device.map(|device| match device {
+            0 => PortDevice::Controller,
+            _ => PortDevice::MemoryCard,
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -544,6 +928,231 @@ mod tests {
         assert!(ports.selected_port.is_none());
     }
 
+    /// Run a controller read (0x01, 0x42, ...) on port 1
+    fn read_pad(ports: &mut ControllerPorts, len: usize) -> Vec<u8> {
+        ports.write_ctrl(0x0002);
+        let mut tx = vec![0x01, 0x42];
+        tx.resize(len, 0x00);
+        let rx = tx
+            .iter()
+            .map(|&b| {
+                ports.write_tx_data(b);
+                ports.read_rx_data()
+            })
+            .collect();
+        ports.write_ctrl(0x0000);
+        rx
+    }
+
+    #[test]
+    fn test_set_digital_reported_in_read_response() {
+        use crate::core::controller::buttons;
+
+        let mut ports = ControllerPorts::new();
+        ports.set_digital(0, !(buttons::CROSS | buttons::START));
+
+        let rx = read_pad(&mut ports, 5);
+
+        assert_eq!(rx, vec![0xFF, 0x41, 0x5A, 0xF7, 0xBF]);
+    }
+
+    #[test]
+    fn test_set_analog_reported_in_analog_mode() {
+        use crate::core::controller::buttons;
+
+        let mut ports = ControllerPorts::new();
+        ports.set_analog_mode(0, true);
+        ports.set_digital(0, !buttons::L3);
+        ports.set_analog(0, 0x00, 0xFF, 0x40, 0xC0);
+
+        let rx = read_pad(&mut ports, 9);
+
+        assert_eq!(
+            rx,
+            vec![0xFF, 0x73, 0x5A, 0xFD, 0xFF, 0x40, 0xC0, 0x00, 0xFF]
+        );
+        assert_eq!(ports.read_stat() & 0x0080, 0, "no ACK after last byte");
+    }
+
+    #[test]
+    fn test_analog_mode_toggle_back_to_digital() {
+        let mut ports = ControllerPorts::new();
+        ports.set_analog_mode(0, true);
+        ports.set_analog_mode(0, false);
+
+        let rx = read_pad(&mut ports, 5);
+
+        assert_eq!(rx[1], 0x41);
+    }
+
+    #[test]
+    fn test_memory_card_addressed_by_0x81() {
+        let mut ports = ControllerPorts::new();
+        ports.insert_memory_card(0, MemoryCard::new());
+        ports.write_ctrl(0x0002);
+
+        let rx: Vec<u8> = [0x81, 0x53, 0x00, 0x00]
+            .iter()
+            .map(|&b| {
+                ports.write_tx_data(b);
+                ports.read_rx_data()
+            })
+            .collect();
+
+        assert_eq!(rx, vec![0xFF, 0x08, 0x5A, 0x5D]);
+        ports.tick(ControllerPorts::MEMORY_CARD_ACK_DELAY);
+        assert_eq!(ports.read_stat() & 0x0080, 0x0080, "card should ACK");
+    }
+
+    #[test]
+    fn test_memory_card_absent_returns_0xff() {
+        let mut ports = ControllerPorts::new();
+        ports.write_ctrl(0x0002);
+
+        ports.write_tx_data(0x81);
+        ports.write_tx_data(0x52);
+
+        assert_eq!(ports.read_rx_data(), 0xFF);
+        assert_eq!(ports.read_stat() & 0x0080, 0, "no device should ACK");
+    }
+
+    #[test]
+    fn test_controller_not_addressed_by_memory_card_transfer() {
+        let mut ports = ControllerPorts::new();
+        ports.insert_memory_card(0, MemoryCard::new());
+        ports.write_ctrl(0x0002);
+
+        ports.write_tx_data(0x81);
+        ports.write_tx_data(0x42);
+
+        // FLAG byte from the card, not the controller ID (0x41)
+        assert_eq!(ports.read_rx_data(), 0xFF);
+        assert_eq!(ports.read_rx_data(), 0x08);
+    }
+
+    #[test]
+    fn test_digital_pad_read_with_transfer_and_ack_timing() {
+        use crate::core::controller::buttons;
+
+        let mut ports = ControllerPorts::new();
+        ports.set_digital(0, !buttons::CIRCLE);
+        ports.write_baud(0x88);
+        ports.write_ctrl(0x1003); // TX enable, select port 1, ACK IRQ enable
+
+        let byte_ticks = 0x88 * 8;
+        let tx = [0x01, 0x42, 0x00, 0x00, 0x00];
+        let expected = [0xFF, 0x41, 0x5A, 0xFF, 0xDF];
+
+        for (i, (&out, &response)) in tx.iter().zip(&expected).enumerate() {
+            ports.write_tx_data(out);
+            assert_eq!(ports.read_stat() & 0x06, 0, "byte {} shifting", i);
+
+            assert!(!ports.tick(byte_ticks - 1));
+            assert_eq!(ports.read_stat() & 0x02, 0, "byte {} not received yet", i);
+            assert!(!ports.tick(1));
+            assert_eq!(ports.read_stat() & 0x06, 0x06, "byte {} received", i);
+            assert_eq!(ports.read_rx_data(), response, "byte {}", i);
+
+            if i < tx.len() - 1 {
+                // /ACK pulse raises IRQ7 after the controller's delay
+                assert!(!ports.tick(ControllerPorts::CONTROLLER_ACK_DELAY - 1));
+                assert_eq!(ports.read_stat() & 0x0280, 0);
+                assert!(ports.tick(1), "byte {} ACK", i);
+                assert_eq!(ports.read_stat() & 0x0280, 0x0280);
+                ports.write_ctrl(0x1013); // Acknowledge the IRQ
+                assert_eq!(ports.read_stat() & 0x0200, 0);
+            } else {
+                assert!(!ports.tick(10_000), "no ACK after last byte");
+                assert_eq!(ports.read_stat() & 0x0080, 0);
+            }
+        }
+
+        ports.write_ctrl(0x0000);
+        assert!(ports.selected_port.is_none());
+    }
+
+    #[test]
+    fn test_tx_fifo_queues_byte_during_transfer() {
+        let mut ports = ControllerPorts::new();
+        ports.write_baud(0x88);
+        ports.write_ctrl(0x0003);
+
+        ports.write_tx_data(0x01);
+        ports.write_tx_data(0x42);
+        assert_eq!(ports.read_stat() & 0x01, 0, "TX FIFO full");
+
+        ports.tick(0x88 * 8);
+        assert_eq!(ports.read_stat() & 0x01, 0x01, "queued byte started");
+        assert_eq!(ports.read_stat() & 0x04, 0, "second byte shifting");
+        ports.tick(0x88 * 8);
+
+        assert_eq!(ports.read_rx_data(), 0xFF);
+        assert_eq!(ports.read_rx_data(), 0x41);
+        assert_eq!(ports.read_stat() & 0x02, 0, "RX FIFO drained");
+    }
+
+    #[test]
+    fn test_queued_byte_uses_leftover_cycles() {
+        let mut ports = ControllerPorts::new();
+        ports.write_baud(0x88);
+        ports.write_ctrl(0x0003);
+
+        ports.write_tx_data(0x01);
+        ports.write_tx_data(0x42);
+
+        // One tick covering both bytes completes both
+        ports.tick(0x88 * 8 * 2);
+
+        assert_eq!(ports.read_stat() & 0x05, 0x05, "both bytes out");
+        assert_eq!(ports.read_rx_data(), 0xFF);
+        assert_eq!(ports.read_rx_data(), 0x41);
+    }
+
+    #[test]
+    fn test_state_round_trip_keeps_transfer_in_flight() {
+        let mut original = ControllerPorts::new();
+        original.write_baud(0x88);
+        original.write_ctrl(0x1003);
+        original.write_tx_data(0x01);
+        original.tick(0x88 * 8); // First byte done, /ACK pending
+        original.write_tx_data(0x42);
+        original.write_tx_data(0x00); // Queued behind 0x42
+        original.tick(100);
+
+        let mut restored = ControllerPorts::new();
+        restored.restore_from_state(&original.to_state());
+
+        assert_eq!(restored.read_stat(), original.read_stat());
+        assert_eq!(restored.read_ctrl(), original.read_ctrl());
+        assert_eq!(restored.read_baud(), original.read_baud());
+        assert_eq!(restored.selected_port, Some(0));
+
+        // The pending /ACK and both bytes complete on the same cycles
+        let ack_ticks = ControllerPorts::CONTROLLER_ACK_DELAY - 100;
+        assert!(restored.tick(ack_ticks));
+        assert!(original.tick(ack_ticks));
+        restored.tick(0x88 * 8 * 2);
+        original.tick(0x88 * 8 * 2);
+
+        assert_eq!(restored.read_stat(), original.read_stat());
+        assert_eq!(restored.read_rx_data(), 0xFF);
+        assert_eq!(restored.read_rx_data(), 0x41);
+        assert_eq!(restored.read_rx_data(), 0x5A);
+    }
+
+    #[test]
+    fn test_deselect_aborts_transfer() {
+        let mut ports = ControllerPorts::new();
+        ports.write_baud(0x88);
+        ports.write_ctrl(0x0003);
+        ports.write_tx_data(0x01);
+
+        ports.write_ctrl(0x0000);
+        ports.tick(0x88 * 8);
+
+        assert_eq!(ports.read_stat() & 0x07, 0x05, "idle, nothing received");
+    }
+
     #[test]
     fn test_baud_rate_independence() {
         let mut ports = ControllerPorts::new();
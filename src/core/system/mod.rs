@@ -19,22 +19,29 @@
 //! and provides the main emulation loop.
 
 mod controller_ports;
+mod rewind;
 
 pub use controller_ports::ControllerPorts;
+use rewind::RewindBuffer;
 
 #[cfg(feature = "audio")]
 use super::audio::AudioBackend;
 use super::cdrom::CDROM;
-use super::cpu::{CpuTracer, CPU};
+use super::cheat::CheatEngine;
+use super::cpu::{ClockMode, CpuTracer, TraceEntry, CPU};
 use super::dma::DMA;
 use super::error::{EmulatorError, Result};
 use super::gpu::GPU;
 use super::interrupt::{interrupts, InterruptController};
-use super::memory::Bus;
-use super::spu::SPU;
+use super::mdec::MDEC;
+use super::memory::{Bus, IoAccess};
+use super::save_state::{SaveState, SaveStateMetadata, StateSave, SAVE_STATE_VERSION};
+use super::spu::{WavWriter, SPU};
 use super::timer::Timers;
-use super::timing::TimingEventManager;
+use super::timing::{EventTraceEntry, TickCount, TimingEventManager};
 use std::cell::RefCell;
+use std::fs::File;
+use std::io::BufWriter;
 use std::rc::Rc;
 
 /// PlayStation System
@@ -70,6 +77,8 @@ pub struct System {
     gpu: Rc<RefCell<GPU>>,
     /// SPU instance (shared via Rc<RefCell> for memory-mapped access)
     spu: Rc<RefCell<SPU>>,
+    /// MDEC instance (shared via Rc<RefCell> for memory-mapped access)
+    mdec: Rc<RefCell<MDEC>>,
     /// DMA controller (shared via Rc<RefCell> for memory-mapped access)
     dma: Rc<RefCell<DMA>>,
     /// CDROM drive (shared via Rc<RefCell> for memory-mapped access)
@@ -95,6 +104,12 @@ pub struct System {
     trace_count: usize,
     /// Cycles at last VBLANK
     last_vblank_cycles: u64,
+    /// Rewind snapshots (None when rewind is disabled)
+    rewind: Option<RewindBuffer>,
+    /// Active cheat codes, applied after each VBlank
+    cheats: CheatEngine,
+    /// WAV recording of the SPU output (None when not capturing)
+    audio_capture: Option<WavWriter<BufWriter<File>>>,
 }
 
 impl System {
@@ -128,6 +143,9 @@ impl System {
         // Create SPU wrapped in Rc<RefCell> for shared access
         let spu = Rc::new(RefCell::new(SPU::new()));
 
+        // Create MDEC wrapped in Rc<RefCell> for shared access
+        let mdec = Rc::new(RefCell::new(MDEC::new()));
+
         // Create bus and connect all peripherals for memory-mapped I/O
         let mut bus = Bus::new();
         bus.set_gpu(gpu.clone());
@@ -137,6 +155,7 @@ impl System {
         bus.set_timers(timers.clone());
         bus.set_interrupt_controller(interrupt_controller.clone());
         bus.set_spu(spu.clone());
+        bus.set_mdec(mdec.clone());
 
         // Create timing manager
         let mut timing = TimingEventManager::new();
@@ -144,10 +163,8 @@ impl System {
         // Register timing events for CD-ROM
         cdrom.borrow_mut().register_events(&mut timing);
 
-        // Register timing events for GPU
-        gpu.borrow_mut().register_events(&mut timing);
-
-        // Register timing events for Timers
+        // Register timing events for Timers (overflow sync points). The GPU
+        // scanline counter is advanced by the scheduler instead of events.
         timers.borrow_mut().register_events(&mut timing);
 
         log::info!("System: All components initialized and timing events registered");
@@ -172,6 +189,7 @@ impl System {
             timing,
             gpu,
             spu,
+            mdec,
             dma,
             cdrom,
             controller_ports,
@@ -185,6 +203,9 @@ impl System {
             trace_limit: 0,
             trace_count: 0,
             last_vblank_cycles: 0,
+            rewind: None,
+            cheats: CheatEngine::new(),
+            audio_capture: None,
         }
     }
 
@@ -224,6 +245,7 @@ impl System {
         // Reset SPU by creating a new instance and updating bus connection
         self.spu = Rc::new(RefCell::new(SPU::new()));
         self.bus.set_spu(self.spu.clone());
+        self.mdec.borrow_mut().reset();
         self.cycles = 0;
         self.running = true;
         self.trace_count = 0;
@@ -232,8 +254,8 @@ impl System {
 
     /// Execute one CPU instruction
     ///
-    /// Executes a single CPU instruction and ticks the GPU accordingly.
-    /// The GPU is synchronized with CPU cycles for accurate emulation.
+    /// Executes a single CPU instruction and advances every device by the
+    /// cycles it took, so devices are synchronized with each instruction.
     ///
     /// # Returns
     /// Number of cycles consumed
@@ -241,6 +263,86 @@ impl System {
     /// # Errors
     /// Returns error if instruction execution fails
     pub fn step(&mut self) -> Result<u32> {
+        let cpu_cycles = self.execute_instruction()?;
+        self.advance_devices(cpu_cycles);
+        Ok(cpu_cycles)
+    }
+
+    /// Run the CPU and devices in lockstep for a number of cycles
+    ///
+    /// The CPU runs in slices that end at the next scheduled timing event
+    /// (CD-ROM command responses, timer IRQs) or after at most
+    /// [`System::SLICE_CYCLES`] cycles. After each slice the GPU scanline
+    /// counter, timers, CD-ROM, SPU and DMA are advanced by the elapsed
+    /// cycles and the fired events are dispatched, so device timing lands on
+    /// the right cycle relative to CPU execution.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycles` - Minimum number of CPU cycles to run
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(elapsed)` with the cycles actually run, which may exceed
+    ///   `cycles` by the length of the last instruction
+    /// - `Err(EmulatorError)` if execution fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.reset();
+    /// let elapsed = system.run_cycles(10_000).unwrap();
+    /// assert!(elapsed >= 10_000);
+    /// ```
+    pub fn run_cycles(&mut self, cycles: u32) -> Result<u32> {
+        let mut elapsed = 0;
+        while elapsed < cycles {
+            let (ran, _) = self.run_slice(cycles - elapsed)?;
+            elapsed += ran;
+        }
+        Ok(elapsed)
+    }
+
+    /// Longest stretch of CPU cycles run before the devices catch up
+    ///
+    /// Bounds the latency of device interrupts that are not scheduled as
+    /// timing events, such as VBlank, DMA completion and SPU IRQs.
+    pub const SLICE_CYCLES: u32 = 128;
+
+    /// Run the CPU for one slice, then advance the devices by its length
+    ///
+    /// # Arguments
+    ///
+    /// * `max_cycles` - Upper bound on the slice length
+    ///
+    /// # Returns
+    ///
+    /// Tuple of (elapsed cycles, whether VBlank started during the slice)
+    fn run_slice(&mut self, max_cycles: u32) -> Result<(u32, bool)> {
+        let until_event = self.timing.downcount.max(1) as u32;
+        let slice = max_cycles.min(Self::SLICE_CYCLES).min(until_event);
+
+        // Timer counter reads interpolate up to the CPU's position in the slice
+        let slice_start = self.timing.get_current_time();
+        let mut elapsed = 0;
+        while elapsed < slice {
+            self.timers
+                .borrow_mut()
+                .set_current_time(slice_start + elapsed as u64);
+            elapsed += self.execute_instruction()?;
+        }
+
+        Ok((elapsed, self.advance_devices(elapsed)))
+    }
+
+    /// Trace and execute one CPU instruction without advancing the devices
+    ///
+    /// # Returns
+    /// Number of cycles consumed
+    fn execute_instruction(&mut self) -> Result<u32> {
         // Trace instruction if tracer is enabled
         if let Some(ref mut tracer) = self.tracer {
             // Check if we should still trace
@@ -274,107 +376,98 @@ impl System {
         }
 
         let cpu_cycles = self.cpu.step(&mut self.bus)?;
+        self.sync_icache();
+        Ok(cpu_cycles)
+    }
 
-        // Tick DMA controller to process active transfers
-        // DMA gets access to RAM, GPU, CD-ROM, and SPU for data transfers
-        let dma_irq = {
-            let ram = self.bus.ram_mut();
-            let mut gpu = self.gpu.borrow_mut();
-            let mut cdrom = self.cdrom.borrow_mut();
-            let mut spu = self.spu.borrow_mut();
-            self.dma
-                .borrow_mut()
-                .tick(ram, &mut gpu, &mut cdrom, &mut spu)
-        };
-
-        // Request DMA interrupt if any transfer completed
-        if dma_irq {
-            self.interrupt_controller
-                .borrow_mut()
-                .request(interrupts::DMA);
-        }
-
-        // Apply icache invalidation from memory writes (must come before prefill)
-        // This maintains cache coherency when memory is modified
+    /// Apply icache invalidations and prefills queued by memory writes
+    fn sync_icache(&mut self) {
+        // Invalidation must come before prefill to keep the cache coherent
         for addr in self.bus.drain_icache_invalidate_queue() {
             self.cpu.invalidate_icache(addr);
         }
 
-        // Apply icache range invalidation from bulk memory writes (e.g., executable loading)
-        // This efficiently invalidates large ranges without queueing individual addresses
+        // Range invalidation from bulk memory writes (e.g., executable loading)
+        // avoids queueing individual addresses
         for (start, end) in self.bus.drain_icache_invalidate_range_queue() {
             self.cpu.invalidate_icache_range(start, end);
         }
 
-        // Apply icache prefill from memory writes
-        // This ensures instructions are cached before execution
+        // Prefill ensures instructions are cached before execution
         for (addr, instruction) in self.bus.drain_icache_prefill_queue() {
             self.cpu.prefill_icache(addr, instruction);
         }
+    }
 
-        // Tick GPU (legacy timing for backward compatibility)
-        // Event-driven timing handles VBlank/HBlank via timing events
-        let (_vblank_irq_legacy, hblank_irq_legacy) = self.gpu.borrow_mut().tick(cpu_cycles);
+    /// Advance every device by the given number of CPU cycles
+    ///
+    /// Moves the timing event manager forward by the same amount and
+    /// dispatches the events that fired, then raises the resulting
+    /// interrupts.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycles` - CPU cycles elapsed since the last call
+    ///
+    /// # Returns
+    ///
+    /// true if VBlank started within `cycles`
+    fn advance_devices(&mut self, cycles: u32) -> bool {
+        self.timing.pending_ticks += cycles as TickCount;
 
-        // Tick timers with HBlank signal (legacy timing)
-        // For now, in_hblank is simplified (always false)
-        let timer_irqs_legacy = self
-            .timers
-            .borrow_mut()
-            .tick(cpu_cycles, false, hblank_irq_legacy);
-
-        // Run pending timing events to get list of triggered events
-        // Note: CPU::execute() also calls this, but we may need to run it here
-        // for events triggered during this step
-        let triggered_events = if self.timing.pending_ticks > 0 {
-            self.timing.run_events()
-        } else {
-            Vec::new()
+        // Tick DMA controller to process active transfers
+        // DMA gets access to RAM, MDEC, GPU, CD-ROM, and SPU for data transfers
+        let dma_irq = {
+            let ram = self.bus.ram_mut();
+            let mut mdec = self.mdec.borrow_mut();
+            let mut gpu = self.gpu.borrow_mut();
+            let mut cdrom = self.cdrom.borrow_mut();
+            let mut spu = self.spu.borrow_mut();
+            self.dma
+                .borrow_mut()
+                .tick(cycles, ram, &mut mdec, &mut gpu, &mut cdrom, &mut spu)
         };
 
-        // Process CD-ROM timing events
-        // This handles both command scheduling and event callbacks
-        self.cdrom
-            .borrow_mut()
-            .process_events(&mut self.timing, &triggered_events);
-
-        // Process GPU timing events (VBlank/HBlank)
-        self.gpu
-            .borrow_mut()
-            .process_events(&mut self.timing, &triggered_events);
-
-        // Poll GPU interrupts from event-driven timing
-        let (vblank_irq, hblank_irq) = self.gpu.borrow_mut().poll_interrupts();
-
-        // Request VBlank interrupt
-        if vblank_irq {
+        // Request DMA interrupt if any transfer completed
+        if dma_irq {
             self.interrupt_controller
                 .borrow_mut()
-                .request(interrupts::VBLANK);
+                .request(interrupts::DMA);
         }
 
-        // Process Timer timing events (overflow detection)
-        self.timers
-            .borrow_mut()
-            .process_events(&mut self.timing, &triggered_events);
-
-        // Poll timer interrupts from event-driven timing
-        let timer_irqs_event = self.timers.borrow_mut().poll_interrupts();
+        // DMA may have written code to RAM
+        self.sync_icache();
+
+        // Advance the GPU scanline counter, which drives the VBlank and HBlank signals
+        let (vblank_start, hblank) = self.gpu.borrow_mut().tick_cycles(cycles);
+
+        // Timers see each HBlank pulse (Timer 1 clock) and the VBlank level
+        // (Timer 1 sync input)
+        let (dot_clock_divider, video_mode, in_vblank) = {
+            let gpu = self.gpu.borrow();
+            (
+                gpu.dot_clock_divider(),
+                gpu.video_mode(),
+                gpu.is_in_vblank(),
+            )
+        };
+        let timer_irqs = {
+            let mut timers = self.timers.borrow_mut();
+            timers.set_current_time(self.timing.get_current_time());
+            timers.set_dot_clock_divider(dot_clock_divider);
+            timers.set_video_mode(video_mode);
+            timers.tick(cycles, hblank, in_vblank)
+        };
 
-        // Re-tick timers if event-driven HBlank occurred
-        // This ensures timers see the HBlank signal from timing events
-        if hblank_irq {
-            let _timer_irqs = self.timers.borrow_mut().tick(0, false, true);
+        // VBlank interrupt fires on the rising edge of the VBlank signal
+        self.interrupt_controller
+            .borrow_mut()
+            .set_line(interrupts::VBLANK, in_vblank);
+        if vblank_start {
+            self.cheats.apply(&mut self.bus);
         }
 
-        // Merge timer interrupts from both event-driven and legacy timing
-        let timer_irqs = [
-            timer_irqs_legacy[0] || timer_irqs_event[0],
-            timer_irqs_legacy[1] || timer_irqs_event[1],
-            timer_irqs_legacy[2] || timer_irqs_event[2],
-        ];
-
-        // Request timer interrupts (merged from both timing methods)
+        // Request timer interrupts
         if timer_irqs[0] {
             self.interrupt_controller
                 .borrow_mut()
@@ -391,46 +484,50 @@ impl System {
                 .request(interrupts::TIMER2);
         }
 
-        // Tick CD-ROM drive (synchronized with CPU cycles) - for legacy timing
-        // TODO: Remove this once all CD-ROM timing is event-driven
-        self.cdrom.borrow_mut().tick(cpu_cycles);
+        // Devices are now current, so the events due within `cycles` can run
+        let triggered_events = self.timing.run_events();
+
+        // Process CD-ROM timing events
+        // This handles both command scheduling and event callbacks
+        self.cdrom
+            .borrow_mut()
+            .process_events(&mut self.timing, &triggered_events);
+
+        // Timer overflow events only mark sync points; reschedule them
+        self.timers
+            .borrow_mut()
+            .process_events(&mut self.timing, &triggered_events);
 
-        // Request CD-ROM interrupt if flag is set
-        let cdrom_irq_flag = self.cdrom.borrow().interrupt_flag();
-        if cdrom_irq_flag != 0 {
+        // Controller/memory card serial transfers and their /ACK pulses
+        if self.controller_ports.borrow_mut().tick(cycles) {
             self.interrupt_controller
                 .borrow_mut()
-                .request(interrupts::CDROM);
+                .request(interrupts::CONTROLLER);
         }
 
-        // Tick SPU to generate audio samples with CD-DA mixing (only if audio feature is enabled)
-        #[cfg(feature = "audio")]
-        {
-            // Generate audio samples with CD audio mixed in
-            // We need to coordinate between CDROM (which owns cd_audio) and SPU
-            let audio_samples = {
-                let mut cdrom = self.cdrom.borrow_mut();
-                let mut spu = self.spu.borrow_mut();
-                spu.tick_with_cd(cpu_cycles, &mut cdrom.cd_audio)
-            };
-
-            // Queue samples to audio backend if available
-            if let Some(ref mut audio) = self.audio {
-                if !audio_samples.is_empty() {
-                    audio.queue_samples(&audio_samples);
-
-                    // Check buffer level and warn on underruns
-                    let buffer_level = audio.buffer_level();
-                    if buffer_level < 512 {
-                        log::warn!("Audio buffer underrun: {} samples queued", buffer_level);
-                    }
-                }
-            }
+        // Tick CD-ROM drive (spin-up and sector reads)
+        self.cdrom.borrow_mut().tick(cycles);
+
+        // CD-ROM interrupt fires each time an enabled flag is raised
+        if self.cdrom.borrow_mut().take_irq() {
+            self.interrupt_controller
+                .borrow_mut()
+                .request(interrupts::CDROM);
         }
 
-        self.cycles += cpu_cycles as u64;
+        // Tick SPU to generate audio samples with CD-DA mixing
+        // We need to coordinate between CDROM (which owns cd_audio) and SPU
+        let audio_samples = {
+            let mut cdrom = self.cdrom.borrow_mut();
+            let mut spu = self.spu.borrow_mut();
+            spu.tick_with_cd(cycles, &mut cdrom.cd_audio)
+        };
+        self.queue_audio(&audio_samples);
+        self.poll_spu_interrupt();
+
+        self.cycles += cycles as u64;
 
-        Ok(cpu_cycles)
+        vblank_start
     }
 
     /// Execute multiple instructions
@@ -466,8 +563,9 @@ impl System {
     /// The PlayStation CPU runs at approximately 33.8688 MHz.
     /// At 60 fps, one frame requires approximately 564,480 cycles.
     ///
-    /// This method uses event-driven execution through the timing system.
-    /// The CPU executes until the timing system signals the frame is complete.
+    /// The CPU and devices run in lockstep slices (see
+    /// [`System::run_cycles`]) until the GPU enters VBlank, so each call ends
+    /// right after the frame has been drawn and the VBlank IRQ raised.
     ///
     /// # Returns
     ///
@@ -484,43 +582,89 @@ impl System {
     /// system.run_frame().unwrap(); // Execute one frame
     /// ```
     pub fn run_frame(&mut self) -> Result<()> {
-        // PSX CPU runs at ~33.8688 MHz
-        // At 60 fps, one frame = 33868800 / 60 ≈ 564,480 cycles
-        const CYCLES_PER_FRAME: u64 = 564_480;
+        // One frame = 564,480 cycles at 60Hz (NTSC) or 677,376 cycles at 50Hz (PAL)
+        while !self.run_slice(u32::MAX)?.1 {}
+
+        if let Some(mut rewind) = self.rewind.take() {
+            if rewind.frame_completed() {
+                rewind.push(self.capture_state());
+            }
+            self.rewind = Some(rewind);
+        }
+
+        Ok(())
+    }
 
-        // Set frame target in timing system
-        self.timing.set_frame_target(CYCLES_PER_FRAME);
+    /// Run exactly `n` video frames and hash the resulting display
+    ///
+    /// Each frame runs up to the next VBlank (see [`System::run_frame`]) and
+    /// nothing depends on wall-clock time, so the same starting state always
+    /// produces the same hash. This makes it suitable for golden-frame tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of frames to run
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(hash)` of the displayed frame after the last frame
+    /// - `Err(EmulatorError)` if execution fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.load_bios("SCPH1001.BIN").unwrap();
+    /// system.reset();
+    /// let hash = system.run_frames(600).unwrap();
+    /// println!("BIOS logo hash: {:016X}", hash);
+    /// ```
+    pub fn run_frames(&mut self, n: u32) -> Result<u64> {
+        for _ in 0..n {
+            self.run_frame()?;
+        }
+        Ok(self.gpu.borrow().current_frame().hash())
+    }
 
-        // Execute CPU until timing system signals frame complete
-        self.cpu.execute(&mut self.bus, &mut self.timing)?;
+    /// Queue generated samples to the audio backend and WAV capture, if active
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - Stereo samples produced by the SPU
+    fn queue_audio(&mut self, samples: &[(i16, i16)]) {
+        if let Some(ref mut capture) = self.audio_capture {
+            if let Err(e) = capture.write_samples(samples) {
+                log::error!("Audio capture failed, stopping: {}", e);
+                self.audio_capture = None;
+            }
+        }
 
-        // Tick SPU for one frame worth of cycles and queue audio if available
         #[cfg(feature = "audio")]
-        {
-            // Generate audio samples with CD audio mixed in
-            let audio_samples = {
-                let mut cdrom = self.cdrom.borrow_mut();
-                let mut spu = self.spu.borrow_mut();
-                spu.tick_with_cd(CYCLES_PER_FRAME as u32, &mut cdrom.cd_audio)
-            };
-
-            if let Some(ref mut audio) = self.audio {
-                if !audio_samples.is_empty() {
-                    audio.queue_samples(&audio_samples);
-
-                    // Check buffer level and warn on underruns
-                    let buffer_level = audio.buffer_level();
-                    if buffer_level < 512 {
-                        log::warn!("Audio buffer underrun: {} samples queued", buffer_level);
-                    }
+        if let Some(ref mut audio) = self.audio {
+            if !samples.is_empty() {
+                audio.queue_samples(samples);
+
+                // Check buffer level and warn on underruns
+                let buffer_level = audio.buffer_level();
+                if buffer_level < 512 {
+                    log::warn!("Audio buffer underrun: {} samples queued", buffer_level);
                 }
             }
         }
 
-        // Update total cycles from timing system
-        self.cycles = self.timing.global_tick_counter;
+        #[cfg(not(feature = "audio"))]
+        let _ = samples;
+    }
 
-        Ok(())
+    /// Forward a pending SPU interrupt (IRQ9) to the interrupt controller
+    fn poll_spu_interrupt(&mut self) {
+        if self.spu.borrow_mut().poll_interrupt() {
+            self.interrupt_controller
+                .borrow_mut()
+                .request(interrupts::SPU);
+        }
     }
 
     /// Get current PC value
@@ -597,6 +741,14 @@ impl System {
         Rc::clone(&self.gpu)
     }
 
+    /// Get reference to Timers
+    ///
+    /// # Returns
+    /// Reference to Timers instance (wrapped in Rc<RefCell>)
+    pub fn timers(&self) -> Rc<RefCell<Timers>> {
+        Rc::clone(&self.timers)
+    }
+
     /// Get reference to Controller Ports
     ///
     /// # Returns
@@ -615,21 +767,14 @@ impl System {
 
     /// Load a game from CD-ROM and prepare for execution
     ///
-    /// **Current Implementation Status (Partial):**
-    ///
-    /// Currently implemented:
+    /// Boots the disc directly, bypassing the BIOS shell:
     /// 1. Load disc image from .cue file
-    /// 2. Read SYSTEM.CNF from disc (hard-coded filename: "SYSTEM.CNF;1")
-    /// 3. Parse SYSTEM.CNF to find boot executable path
-    ///
-    /// **Not yet implemented (TODO):**
-    /// 4. Full ISO9660 filesystem parsing to locate executable by path
-    /// 5. Load PSX-EXE file from disc
-    /// 6. Copy executable data to RAM
-    /// 7. Set CPU registers (PC, GP, SP, FP)
+    /// 2. Read and parse SYSTEM.CNF to find the boot executable
+    /// 3. Load the PSX-EXE named by the BOOT line from the disc
+    /// 4. Copy executable data to RAM and set PC, GP, SP and FP
     ///
-    /// This method will return an error until ISO9660 support is completed.
-    /// The full game boot sequence is planned for a future phase.
+    /// The stack pointer comes from the STACK line of SYSTEM.CNF (default
+    /// 0x801FFF00), as the BIOS does when it launches the boot executable.
     ///
     /// # Arguments
     ///
@@ -637,8 +782,8 @@ impl System {
     ///
     /// # Returns
     ///
-    /// - `Ok(())` if disc loads and SYSTEM.CNF is parsed successfully
-    /// - `Err(EmulatorError)` currently returns error for unimplemented executable loading
+    /// - `Ok(())` if the disc's boot executable was loaded
+    /// - `Err(EmulatorError)` if the disc, SYSTEM.CNF or executable is invalid
     ///
     /// # Example
     ///
@@ -647,19 +792,11 @@ impl System {
     ///
     /// let mut system = System::new();
     /// system.load_bios("SCPH1001.BIN").unwrap();
-    ///
-    /// // Currently only loads disc and parses SYSTEM.CNF
-    /// // Full executable loading not yet implemented
-    /// match system.load_game("game.cue") {
-    ///     Ok(_) => println!("Disc loaded, SYSTEM.CNF parsed"),
-    ///     Err(_) => println!("Executable loading not yet implemented"),
-    /// }
+    /// system.reset();
+    /// system.load_game("game.cue").unwrap();
     /// ```
     pub fn load_game(&mut self, cue_path: &str) -> Result<()> {
-        use super::loader::SystemConfig;
-        // PSXExecutable will be used when full ISO9660 parsing is implemented
-        #[allow(unused_imports)]
-        use super::loader::PSXExecutable;
+        use super::loader::{PSXExecutable, SystemConfig};
 
         log::info!("Loading game from: {}", cue_path);
 
@@ -683,46 +820,84 @@ impl System {
 
         // Step 3: Parse SYSTEM.CNF
         let config = SystemConfig::parse(&system_cnf_text)?;
-        log::info!("Boot file: {}", config.boot_file);
+        log::info!("Boot file: {}", config.boot_path());
         log::debug!("Stack: 0x{:08X}", config.stack);
 
-        // Step 4: Read executable from disc
-        // A full implementation would need ISO9660 parsing to locate the executable
-        // TODO: Implement full ISO9660 file system parsing
-        //
-        // When implemented, this would be:
-        // let exe_data = self.cdrom.borrow_mut().read_file(&config.boot_file)?;
-        // let exe = PSXExecutable::load(&exe_data)?;
-        //
-        // // Step 5: Load executable data into RAM
-        // self.bus.write_ram_slice(exe.load_address, &exe.data)?;
-        //
-        // // Step 6: Set CPU registers
-        // self.cpu.set_pc(exe.pc);
-        // self.cpu.set_reg(28, exe.gp);  // $gp (global pointer)
-        //
-        // // Setup stack
-        // let sp = if config.stack != 0x801FFF00 {
-        //     config.stack
-        // } else if exe.stack_base != 0 {
-        //     exe.stack_base + exe.stack_offset
-        // } else {
-        //     config.stack
-        // };
-        // self.cpu.set_reg(29, sp);  // $sp (stack pointer)
-        // self.cpu.set_reg(30, sp);  // $fp (frame pointer)
-        //
-        // log::info!("Game loaded successfully!");
-        // log::info!("Entry point: 0x{:08X}", exe.pc);
-        // log::info!("Global pointer: 0x{:08X}", exe.gp);
-        // log::info!("Stack pointer: 0x{:08X}", sp);
-
-        // For now, return error since executable loading is not implemented
-        Err(EmulatorError::LoaderError(format!(
-            "ISO9660 filesystem parsing not yet implemented. Cannot load executable: {}. \
-             Disc loaded successfully and SYSTEM.CNF parsed, but full boot sequence requires ISO9660 support.",
-            config.boot_file
-        )))
+        // Step 4: Read executable from disc and boot it
+        let exe_data = self
+            .cdrom
+            .borrow_mut()
+            .read_file(config.boot_path())
+            .map_err(EmulatorError::CdRom)?;
+        let exe = PSXExecutable::load(&exe_data)?;
+
+        self.boot_executable(&exe, config.stack)
+    }
+
+    /// Load a PSX-EXE file and jump straight to its entry point
+    ///
+    /// Bypasses the BIOS boot: the executable's text is copied into RAM at
+    /// its load address and PC, GP, SP and FP are set from the header. The
+    /// stack defaults to 0x801FFF00 when the header leaves it at 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the PSX-EXE file
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the executable was loaded
+    /// - `Err(EmulatorError)` if the file cannot be read or is not a PSX-EXE
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.reset();
+    /// system.load_exe("homebrew.exe").unwrap();
+    /// system.run_frame().unwrap();
+    /// ```
+    pub fn load_exe(&mut self, path: &str) -> Result<()> {
+        use super::loader::PSXExecutable;
+
+        log::info!("Loading executable from: {}", path);
+
+        let data = std::fs::read(path)?;
+        let exe = PSXExecutable::load(&data)?;
+        let stack = exe.initial_sp().unwrap_or(0x801FFF00);
+
+        self.boot_executable(&exe, stack)
+    }
+
+    /// Copy an executable into RAM and set the CPU up to run it
+    ///
+    /// # Arguments
+    ///
+    /// * `exe` - Parsed executable
+    /// * `stack` - Initial stack/frame pointer
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the executable fits in RAM
+    /// - `Err(EmulatorError)` if the load range is outside RAM
+    fn boot_executable(&mut self, exe: &super::loader::PSXExecutable, stack: u32) -> Result<()> {
+        self.bus.write_ram_slice(exe.load_address, &exe.data)?;
+
+        self.cpu.set_pc(exe.pc);
+        self.cpu.set_reg(28, exe.gp); // $gp (global pointer)
+        self.cpu.set_reg(29, stack); // $sp (stack pointer)
+        self.cpu.set_reg(30, stack); // $fp (frame pointer)
+
+        log::info!(
+            "Executable loaded: PC=0x{:08X}, GP=0x{:08X}, SP=0x{:08X}",
+            exe.pc,
+            exe.gp,
+            stack
+        );
+
+        Ok(())
     }
 
     /// Enable CPU execution tracing to a file
@@ -802,36 +977,518 @@ impl System {
     pub fn trace_count(&self) -> usize {
         self.trace_count
     }
-}
 
-impl Default for System {
-    fn default() -> Self {
-        Self::new()
+    /// Serialize the complete emulator state
+    ///
+    /// Captures the CPU, memory, every peripheral and the timing event
+    /// schedule. The BIOS image, the inserted disc and the audio backend
+    /// are not part of the state.
+    ///
+    /// # Returns
+    ///
+    /// Versioned binary save state, loadable with [`System::load_state`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::system::System;
+    ///
+    /// let system = System::new();
+    /// let state = system.save_state();
+    ///
+    /// let mut restored = System::new();
+    /// restored.load_state(&state).unwrap();
+    /// assert_eq!(restored.pc(), system.pc());
+    /// ```
+    pub fn save_state(&self) -> Vec<u8> {
+        SaveState::from_system(self).to_bytes()
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn test_system_creation() {
-        let system = System::new();
+    /// Restore the complete emulator state
+    ///
+    /// Timing events keep the handles registered by this system; their
+    /// schedule is matched to the saved one by event name.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Save state produced by [`System::save_state`]
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the state was restored
+    /// - `Err(EmulatorError::SaveState)` if the data is corrupt or from an
+    ///   incompatible version
+    pub fn load_state(&mut self, data: &[u8]) -> Result<()> {
+        let state =
+            SaveState::from_bytes(data).map_err(|e| EmulatorError::SaveState(e.to_string()))?;
 
-        assert_eq!(system.cycles, 0);
-        assert!(!system.running);
-        assert_eq!(system.pc(), 0xBFC00000); // BIOS entry point
-        assert!(system.tracer.is_none());
-        assert_eq!(system.trace_limit, 0);
-        assert_eq!(system.trace_count, 0);
+        self.restore_state(&state);
+        log::info!("System: Save state loaded (cycle {})", self.cycles);
+        Ok(())
     }
 
-    #[test]
-    fn test_system_default() {
-        let system1 = System::new();
-        let system2 = System::default();
-
-        assert_eq!(system1.cycles, system2.cycles);
+    /// Save the current display area to a PNG file
+    ///
+    /// The image uses the current display resolution (NTSC or PAL, with or
+    /// without interlace) and color depth; 15-bit pixels are expanded to
+    /// 8 bits per channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Output file path
+    ///
+    /// # Returns
+    ///
+    /// `Err(EmulatorError::Io)` if the file cannot be written
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use psrx::core::system::System;
+    ///
+    /// let system = System::new();
+    /// system.screenshot_png("screenshot.png").unwrap();
+    /// ```
+    pub fn screenshot_png<P: AsRef<std::path::Path>>(&self, path: P) -> Result<()> {
+        let frame = self.gpu.borrow().current_frame();
+        frame.save_png(path)?;
+        log::info!(
+            "System: Screenshot saved ({}x{})",
+            frame.width,
+            frame.height
+        );
+        Ok(())
+    }
+
+    /// Start recording the SPU output to a WAV file
+    ///
+    /// Every mixed sample the SPU produces from now on is written as 16-bit
+    /// stereo PCM at 44100 Hz. Capture reads the same samples that feed the
+    /// output ring buffer, so playback is unaffected. A capture already in
+    /// progress is finished first.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - WAV file to create
+    ///
+    /// # Returns
+    ///
+    /// `Err(EmulatorError::Io)` if the file cannot be created
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.start_audio_capture("capture.wav").unwrap();
+    /// system.run_frame().unwrap();
+    /// system.stop_audio_capture().unwrap();
+    /// ```
+    pub fn start_audio_capture<P: AsRef<std::path::Path>>(&mut self, path: P) -> Result<()> {
+        self.stop_audio_capture()?;
+        self.audio_capture = Some(WavWriter::create(path)?);
+        log::info!("System: Audio capture started");
+        Ok(())
+    }
+
+    /// Stop recording the SPU output and finalize the WAV file
+    ///
+    /// # Returns
+    ///
+    /// Number of stereo frames written (0 if no capture was active)
+    pub fn stop_audio_capture(&mut self) -> Result<u32> {
+        let Some(capture) = self.audio_capture.take() else {
+            return Ok(0);
+        };
+
+        let frames = capture.frames();
+        capture.finish()?;
+        log::info!("System: Audio capture stopped ({} frames)", frames);
+        Ok(frames)
+    }
+
+    /// Check whether the SPU output is being recorded
+    pub fn is_capturing_audio(&self) -> bool {
+        self.audio_capture.is_some()
+    }
+
+    /// Start recording recently executed instructions in memory
+    ///
+    /// Covers both [`System::step`] and [`System::run_frame`].
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of most recent instructions to keep
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.enable_trace_buffer(1024);
+    /// system.add_trace_filter(0x80010000, 0x80020000);
+    /// assert!(system.recent_trace().is_empty());
+    /// ```
+    pub fn enable_trace_buffer(&mut self, capacity: usize) {
+        self.cpu.enable_trace_buffer(capacity);
+    }
+
+    /// Stop recording recently executed instructions
+    pub fn disable_trace_buffer(&mut self) {
+        self.cpu.disable_trace_buffer();
+    }
+
+    /// Restrict the trace buffer to an address range
+    ///
+    /// Has no effect unless the trace buffer is enabled. Multiple ranges
+    /// may be added.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - First address to record
+    /// * `end` - Address one past the last to record
+    pub fn add_trace_filter(&mut self, start: u32, end: u32) {
+        if let Some(trace) = self.cpu.trace_buffer_mut() {
+            trace.add_filter(start..end);
+        }
+    }
+
+    /// Get the most recently executed instructions
+    ///
+    /// # Returns
+    ///
+    /// Trace entries, oldest first (empty when the trace buffer is disabled)
+    pub fn recent_trace(&self) -> &[TraceEntry] {
+        self.cpu.recent_trace()
+    }
+
+    /// Start recording timing event activity (schedule, deactivate, fire)
+    ///
+    /// Useful for tracking down events that fire late or never, e.g. in
+    /// the CD-ROM and timer code.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of most recent entries to keep
+    pub fn enable_event_trace(&mut self, capacity: usize) {
+        self.timing.enable_event_trace(capacity);
+    }
+
+    /// Stop recording timing event activity
+    pub fn disable_event_trace(&mut self) {
+        self.timing.disable_event_trace();
+    }
+
+    /// Get the most recent timing event activity
+    ///
+    /// # Returns
+    ///
+    /// Event trace entries, oldest first (empty when the trace is disabled)
+    pub fn recent_timing_events(&self) -> &[EventTraceEntry] {
+        self.timing.recent_events()
+    }
+
+    /// Start logging I/O register accesses with decoded register names
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of most recent accesses to keep
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.enable_io_log(256);
+    /// system.add_io_log_filter(0x1F801080, 0x1F801100); // DMA only
+    /// assert!(system.recent_io_accesses().is_empty());
+    /// ```
+    pub fn enable_io_log(&mut self, capacity: usize) {
+        self.bus.enable_io_log(capacity);
+    }
+
+    /// Stop logging I/O register accesses
+    pub fn disable_io_log(&mut self) {
+        self.bus.disable_io_log();
+    }
+
+    /// Only log I/O accesses in a physical address range
+    ///
+    /// Multiple filters may be added.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - First physical address to record
+    /// * `end` - Physical address one past the last to record
+    pub fn add_io_log_filter(&mut self, start: u32, end: u32) {
+        self.bus.add_io_log_filter(start, end);
+    }
+
+    /// Get the most recent I/O register accesses
+    ///
+    /// # Returns
+    ///
+    /// Logged accesses, oldest first (empty when the log is disabled)
+    pub fn recent_io_accesses(&self) -> Vec<IoAccess> {
+        self.bus.io_log()
+    }
+
+    /// Enable the BIOS call hooks (TTY console and call logging)
+    ///
+    /// Hooks are off by default; the BIOS always executes the calls itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.enable_bios_hle();
+    /// assert!(system.take_tty_output().is_empty());
+    /// ```
+    pub fn enable_bios_hle(&mut self) {
+        self.cpu.enable_bios_hle();
+    }
+
+    /// Disable the BIOS call hooks
+    pub fn disable_bios_hle(&mut self) {
+        self.cpu.disable_bios_hle();
+    }
+
+    /// Choose between cycle-accurate and fast CPU timing
+    ///
+    /// [`ClockMode::Accurate`] (the default) charges memory access delays
+    /// and GTE stalls; [`ClockMode::Fast`] counts one cycle per instruction,
+    /// trading timing accuracy for more CPU work per frame. See
+    /// [`ClockMode`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Cycle accounting mode
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::cpu::ClockMode;
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.set_clock_mode(ClockMode::Fast);
+    /// assert_eq!(system.clock_mode(), ClockMode::Fast);
+    /// ```
+    pub fn set_clock_mode(&mut self, mode: ClockMode) {
+        self.cpu.set_clock_mode(mode);
+    }
+
+    /// Get the CPU cycle accounting mode
+    ///
+    /// # Returns
+    ///
+    /// The current [`ClockMode`]
+    pub fn clock_mode(&self) -> ClockMode {
+        self.cpu.clock_mode()
+    }
+
+    /// Take the TTY output written through the BIOS since the last call
+    ///
+    /// # Returns
+    ///
+    /// Console text (empty when the hooks are disabled)
+    pub fn take_tty_output(&mut self) -> String {
+        self.cpu.take_tty_output()
+    }
+
+    /// Add a GameShark-style cheat
+    ///
+    /// The cheat is applied to RAM after every VBlank until removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - Code text, one `TTAAAAAA VVVV` line per code line
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the code was added
+    /// - `Err(EmulatorError::Parse)` if the code is invalid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.add_cheat("80012344 03E7").unwrap();
+    /// assert!(system.remove_cheat("80012344 03E7"));
+    /// ```
+    pub fn add_cheat(&mut self, code: &str) -> Result<()> {
+        self.cheats.add(code)
+    }
+
+    /// Remove a cheat added with [`System::add_cheat`]
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - Code text of the cheat
+    ///
+    /// # Returns
+    ///
+    /// true if a matching cheat was removed
+    pub fn remove_cheat(&mut self, code: &str) -> bool {
+        self.cheats.remove(code)
+    }
+
+    /// Start recording rewind snapshots
+    ///
+    /// A snapshot is taken at the end of every `interval`-th call to
+    /// [`System::run_frame`]. Older snapshots only keep the RAM, VRAM and
+    /// SPU RAM pages that changed, so a long history stays cheap.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - Frames between snapshots
+    /// * `capacity` - Maximum number of snapshots kept
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::system::System;
+    ///
+    /// let mut system = System::new();
+    /// system.enable_rewind(10, 60); // 10 seconds of history at 60 fps
+    /// ```
+    pub fn enable_rewind(&mut self, interval: u32, capacity: usize) {
+        self.rewind = Some(RewindBuffer::new(interval, capacity));
+    }
+
+    /// Stop recording rewind snapshots and free the history
+    pub fn disable_rewind(&mut self) {
+        self.rewind = None;
+    }
+
+    /// Get the number of rewind snapshots available
+    ///
+    /// # Returns
+    ///
+    /// Snapshot count (0 when rewind is disabled)
+    pub fn rewind_snapshots(&self) -> usize {
+        self.rewind.as_ref().map_or(0, |r| r.len())
+    }
+
+    /// Restore the previous rewind snapshot
+    ///
+    /// A snapshot taken at the current cycle is skipped, so each call
+    /// moves strictly backwards in time. The restored snapshot is removed
+    /// from the history.
+    ///
+    /// # Returns
+    ///
+    /// true if a snapshot was restored, false if the history is empty or
+    /// rewind is disabled
+    pub fn rewind_step(&mut self) -> bool {
+        let Some(rewind) = self.rewind.as_mut() else {
+            return false;
+        };
+
+        while let Some(state) = rewind.pop() {
+            if state.timing.cycles < self.cycles {
+                self.restore_state(&state);
+                log::debug!("System: Rewound to cycle {}", self.cycles);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Capture the state of every component
+    ///
+    /// # Returns
+    ///
+    /// SaveState for the current emulator state
+    pub(crate) fn capture_state(&self) -> SaveState {
+        let mut timing = self.timing.to_state();
+        timing.cycles = self.cycles;
+
+        SaveState {
+            version: SAVE_STATE_VERSION,
+            metadata: SaveStateMetadata {
+                timestamp: chrono::Utc::now(),
+                game_id: String::new(),
+                game_title: String::new(),
+                frame_count: 0,
+                playtime: 0,
+                thumbnail: None,
+            },
+            cpu: self.cpu.to_state(),
+            memory: self.bus.to_state(),
+            gpu: self.gpu.borrow().to_state(),
+            spu: self.spu.borrow().to_state(),
+            cdrom: self.cdrom.borrow().to_state(),
+            dma: self.dma.borrow().to_state(),
+            timers: self.timers.borrow().to_state(),
+            controllers: self.controller_ports.borrow().to_state(),
+            interrupts: self.interrupt_controller.borrow().to_state(),
+            timing,
+        }
+    }
+
+    /// Apply a decoded save state to every component
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - Save state to restore
+    fn restore_state(&mut self, state: &SaveState) {
+        self.cpu.restore_from_state(&state.cpu);
+        self.bus.restore_from_state(&state.memory);
+        self.gpu.borrow_mut().restore_from_state(&state.gpu);
+        self.spu.borrow_mut().restore_from_state(&state.spu);
+        self.cdrom.borrow_mut().restore_from_state(&state.cdrom);
+        self.dma.borrow_mut().restore_from_state(&state.dma);
+        self.timers.borrow_mut().restore_from_state(&state.timers);
+        self.controller_ports
+            .borrow_mut()
+            .restore_from_state(&state.controllers);
+        self.interrupt_controller
+            .borrow_mut()
+            .restore_from_state(&state.interrupts);
+        self.timing.restore_from_state(&state.timing);
+
+        self.cycles = state.timing.cycles;
+        self.last_vblank_cycles = self.cycles;
+    }
+}
+
+impl Default for System {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_creation() {
+        let system = System::new();
+
+        assert_eq!(system.cycles, 0);
+        assert!(!system.running);
+        assert_eq!(system.pc(), 0xBFC00000); // BIOS entry point
+        assert!(system.tracer.is_none());
+        assert_eq!(system.trace_limit, 0);
+        assert_eq!(system.trace_count, 0);
+    }
+
+    #[test]
+    fn test_system_default() {
+        let system1 = System::new();
+        let system2 = System::default();
+
+        assert_eq!(system1.cycles, system2.cycles);
         assert_eq!(system1.running, system2.running);
         assert_eq!(system1.pc(), system2.pc());
     }
@@ -1124,4 +1781,539 @@ mod tests {
 
         assert!(system.running);
     }
+
+    /// Build a PSX-EXE image with the given header values and text
+    fn make_exe(pc: u32, gp: u32, load: u32, stack: u32, text: &[u8]) -> Vec<u8> {
+        let mut data = vec![0u8; 0x800];
+        data[0..8].copy_from_slice(b"PS-X EXE");
+        data[0x10..0x14].copy_from_slice(&pc.to_le_bytes());
+        data[0x14..0x18].copy_from_slice(&gp.to_le_bytes());
+        data[0x18..0x1C].copy_from_slice(&load.to_le_bytes());
+        data[0x1C..0x20].copy_from_slice(&(text.len() as u32).to_le_bytes());
+        data[0x30..0x34].copy_from_slice(&stack.to_le_bytes());
+        data.extend_from_slice(text);
+        data
+    }
+
+    #[test]
+    fn test_load_exe_sets_registers_and_copies_text() {
+        let text: Vec<u8> = (0..0x40).collect();
+        let exe = make_exe(0x80010008, 0x8001F000, 0x80010000, 0x801FFF00, &text);
+        let path = std::env::temp_dir().join(format!("psrx_test_{}.exe", std::process::id()));
+        std::fs::write(&path, exe).unwrap();
+
+        let mut system = System::new();
+        let result = system.load_exe(path.to_str().unwrap());
+        std::fs::remove_file(&path).unwrap();
+        result.unwrap();
+
+        assert_eq!(system.pc(), 0x80010008);
+        assert_eq!(system.cpu().reg(28), 0x8001F000);
+        assert_eq!(system.cpu().reg(29), 0x801FFF00);
+        assert_eq!(system.cpu().reg(30), 0x801FFF00);
+        assert_eq!(system.bus().read32(0x80010000).unwrap(), 0x03020100);
+        assert_eq!(system.bus().read32(0x8001003C).unwrap(), 0x3F3E3D3C);
+    }
+
+    #[test]
+    fn test_load_exe_default_stack() {
+        let exe = make_exe(0x80010000, 0, 0x80010000, 0, &[0; 4]);
+        let path = std::env::temp_dir().join(format!("psrx_test_sp_{}.exe", std::process::id()));
+        std::fs::write(&path, exe).unwrap();
+
+        let mut system = System::new();
+        let result = system.load_exe(path.to_str().unwrap());
+        std::fs::remove_file(&path).unwrap();
+        result.unwrap();
+
+        assert_eq!(system.cpu().reg(29), 0x801FFF00);
+    }
+
+    #[test]
+    fn test_load_exe_rejects_missing_file() {
+        let mut system = System::new();
+        assert!(system.load_exe("/nonexistent/psrx.exe").is_err());
+    }
+
+    /// Build a system running a loop that counts in RAM and fills VRAM
+    fn make_looping_system() -> System {
+        let program: [u32; 13] = [
+            0x3C088002, // lui   t0, 0x8002
+            0x3C091F80, // lui   t1, 0x1F80
+            0x35291810, // ori   t1, t1, 0x1810 (GP0)
+            0x256B0001, // loop: addiu t3, t3, 1
+            0xAD0B0000, // sw    t3, 0(t0)
+            0x3C0C0200, // lui   t4, 0x0200 (GP0 fill)
+            0x018B6025, // or    t4, t4, t3
+            0xAD2C0000, // sw    t4, 0(t1)
+            0xAD200000, // sw    zero, 0(t1)
+            0x3C0D0010, // lui   t5, 0x0010
+            0x35AD0010, // ori   t5, t5, 0x0010 (16x16)
+            0x1000FFF7, // b     loop
+            0xAD2D0000, // sw    t5, 0(t1)
+        ];
+
+        let mut system = System::new();
+        for (i, word) in program.iter().enumerate() {
+            system
+                .bus_mut()
+                .write32(0x80010000 + i as u32 * 4, *word)
+                .unwrap();
+        }
+        system.cpu_mut().set_pc(0x80010000);
+        system
+    }
+
+    #[test]
+    fn test_accurate_clock_mode_counts_more_cycles_than_fast() {
+        let run = |mode| {
+            let mut system = make_looping_system();
+            system.set_clock_mode(mode);
+            system.step_n(1000).unwrap();
+            (system.pc(), system.cycles())
+        };
+
+        let (accurate_pc, accurate_cycles) = run(ClockMode::Accurate);
+        let (fast_pc, fast_cycles) = run(ClockMode::Fast);
+
+        assert_eq!(accurate_pc, fast_pc, "same instructions executed");
+        assert_eq!(fast_cycles, 1000);
+        assert!(accurate_cycles > fast_cycles);
+    }
+
+    #[test]
+    fn test_step_raises_one_vblank_irq_per_frame() {
+        let mut system = System::new();
+        system.bus_mut().write32(0x80010000, 0x1000FFFF).unwrap(); // b .
+        system.bus_mut().write32(0x80010004, 0x00000000).unwrap(); // nop
+        system.cpu_mut().set_pc(0x80010000);
+
+        let mode = system.gpu.borrow().video_mode();
+        let frame = (mode.cycles_per_scanline() * mode.scanlines_per_frame() as u32) as u64;
+
+        // Acknowledge each VBlank IRQ as soon as it is raised
+        let mut vblanks = Vec::new();
+        while system.cycles() < 3 * frame {
+            system.step().unwrap();
+            let mut irq = system.interrupt_controller.borrow_mut();
+            if irq.read_status() & interrupts::VBLANK as u32 != 0 {
+                irq.acknowledge(!interrupts::VBLANK);
+                vblanks.push(system.cycles());
+            }
+        }
+
+        // VBlank starts at scanline 243 of each frame
+        let first = (mode.vblank_start() as u32 * mode.cycles_per_scanline()) as u64;
+        assert_eq!(vblanks.len(), 3);
+        for (i, &cycle) in vblanks.iter().enumerate() {
+            let expected = first + i as u64 * frame;
+            assert!(
+                cycle.abs_diff(expected) <= 4,
+                "VBlank {} at cycle {}",
+                i,
+                cycle
+            );
+        }
+    }
+
+    /// Build a system counting loop iterations in t0 until Timer 2 reaches
+    /// `target` and its IRQ diverts the CPU to a `b .` handler
+    fn make_timer_irq_system(target: u16) -> System {
+        let program: [u32; 6] = [
+            0x24090401, // addiu t1, zero, 0x0401 (IM2 | IEc)
+            0x40896000, // mtc0  t1, SR
+            0x00000000, // nop
+            0x25080001, // loop: addiu t0, t0, 1
+            0x1000FFFE, // b     loop
+            0x00000000, // nop
+        ];
+
+        let mut system = System::new();
+        for (i, word) in program.iter().enumerate() {
+            system
+                .bus_mut()
+                .write32(0x80010000 + i as u32 * 4, *word)
+                .unwrap();
+        }
+        system.bus_mut().write32(0x80000080, 0x1000FFFF).unwrap(); // b .
+        system.bus_mut().write32(0x80000084, 0x00000000).unwrap(); // nop
+        system
+            .bus_mut()
+            .write32(0x1F801074, interrupts::TIMER2 as u32)
+            .unwrap();
+        {
+            let mut timers = system.timers.borrow_mut();
+            let timer2 = timers.channel_mut(2);
+            timer2.write_target(target);
+            timer2.write_mode(0x0018); // IRQ on target, reset on target
+        }
+        system.cpu_mut().set_pc(0x80010000);
+        system
+    }
+
+    #[test]
+    fn test_run_cycles_raises_timer_irq_at_expected_instruction() {
+        const TARGET: u16 = 3000;
+
+        // Reference: devices advanced after every instruction
+        let mut stepped = make_timer_irq_system(TARGET);
+        while stepped.pc() >= 0x80010000 {
+            stepped.step().unwrap();
+        }
+        let expected = stepped.cpu().reg(8);
+
+        // One loop iteration is three cached instructions, less the prologue
+        // and the initial icache fills
+        let iterations = TARGET as u32 / 3;
+        assert!(
+            expected <= iterations && expected + 16 >= iterations,
+            "t0 = {}",
+            expected
+        );
+
+        // Sliced execution ends a slice at the timer event, so the IRQ is
+        // taken after the same number of loop iterations
+        let mut system = make_timer_irq_system(TARGET);
+        system.run_cycles(TARGET as u32 * 2).unwrap();
+        assert!(system.pc() < 0x80010000, "IRQ taken");
+        assert_eq!(system.cpu().reg(8), expected);
+    }
+
+    #[test]
+    fn test_timer_counter_advances_within_slice() {
+        let program: [u32; 6] = [
+            0x3C091F80, // lui   t1, 0x1F80
+            0x8D2A1120, // lw    t2, 0x1120(t1) (Timer 2 counter)
+            0x00000000, // nop
+            0x8D2B1120, // lw    t3, 0x1120(t1)
+            0x1000FFFF, // b     .
+            0x00000000, // nop
+        ];
+
+        let mut system = System::new();
+        for (i, word) in program.iter().enumerate() {
+            system
+                .bus_mut()
+                .write32(0x80010000 + i as u32 * 4, *word)
+                .unwrap();
+        }
+        system.cpu_mut().set_pc(0x80010000);
+
+        // Both reads fall inside a single slice
+        system.run_cycles(64).unwrap();
+
+        let first = system.cpu().reg(10);
+        let second = system.cpu().reg(11);
+        assert!(first > 0, "first read = {}", first);
+        assert!(second > first, "reads {} then {}", first, second);
+    }
+
+    #[test]
+    fn test_run_frames_deterministic_from_reset() {
+        let run = || {
+            let mut system = System::new();
+            system.reset();
+            let hash = system.run_frames(3).unwrap();
+            (hash, system.cycles(), system.pc())
+        };
+
+        assert_eq!(run(), run());
+    }
+
+    #[test]
+    fn test_run_frames_hash_tracks_display() {
+        let mut blank = System::new();
+        let blank_hash = blank.run_frames(0).unwrap();
+
+        let mut a = make_looping_system();
+        let mut b = make_looping_system();
+        let hash = a.run_frames(2).unwrap();
+
+        assert_ne!(hash, blank_hash, "loop fills the displayed VRAM");
+
+        // Splitting the run does not change the result
+        b.run_frames(1).unwrap();
+        assert_eq!(b.run_frames(1).unwrap(), hash);
+    }
+
+    #[test]
+    fn test_save_state_round_trip_matches_original() {
+        let mut original = make_looping_system();
+        for _ in 0..3 {
+            original.run_frame().unwrap();
+        }
+
+        let state = original.save_state();
+        let mut restored = System::new();
+        restored.load_state(&state).unwrap();
+
+        assert_eq!(restored.pc(), original.pc());
+        assert_eq!(restored.cycles(), original.cycles());
+
+        for _ in 0..3 {
+            original.run_frame().unwrap();
+            restored.run_frame().unwrap();
+        }
+
+        assert_eq!(restored.pc(), original.pc());
+        assert_eq!(restored.cycles(), original.cycles());
+        assert_ne!(original.bus().read32(0x80020000).unwrap(), 0);
+
+        let expected = original.capture_state();
+        let mut actual = restored.capture_state();
+        actual.metadata.timestamp = expected.metadata.timestamp;
+        assert!(actual.to_bytes() == expected.to_bytes());
+    }
+
+    #[test]
+    fn test_save_state_round_trip_mid_draw() {
+        let mut original = make_looping_system();
+        original.run_frame().unwrap();
+
+        // Large flat rectangle inside a full-VRAM drawing area keeps the
+        // GPU busy for several scanlines
+        for word in [
+            0xE300_0000,
+            0xE400_0000 | (511 << 10) | 1023,
+            0x6000_00FF,
+            0x0000_0000,
+            0x00FF_00FF,
+        ] {
+            original.bus_mut().write32(0x1F801810, word).unwrap();
+        }
+        let gpustat = original.bus().read32(0x1F801814).unwrap();
+        assert_eq!(gpustat & (1 << 26), 0, "drawing");
+
+        let state = original.save_state();
+        let mut restored = System::new();
+        restored.load_state(&state).unwrap();
+
+        assert_eq!(restored.bus().read32(0x1F801814).unwrap(), gpustat);
+
+        original.run_frame().unwrap();
+        restored.run_frame().unwrap();
+
+        let expected = original.capture_state();
+        let mut actual = restored.capture_state();
+        actual.metadata.timestamp = expected.metadata.timestamp;
+        assert!(actual.to_bytes() == expected.to_bytes());
+    }
+
+    #[test]
+    fn test_save_state_keeps_gpu_sub_dot_timing() {
+        let mut original = GPU::new();
+        original.tick_cycles(1);
+
+        let mut restored = GPU::new();
+        restored.restore_from_state(&original.to_state());
+
+        // The carried cycle completes the first NTSC scanline (2172 cycles)
+        original.tick_cycles(2171);
+        restored.tick_cycles(2171);
+        assert_eq!(original.get_scanline(), 1);
+        assert_eq!(restored.get_scanline(), original.get_scanline());
+    }
+
+    #[test]
+    fn test_rewind_step_restores_earlier_frames() {
+        let mut system = make_looping_system();
+        system.enable_rewind(1, 16);
+
+        for _ in 0..3 {
+            system.run_frame().unwrap();
+        }
+        assert_eq!(system.rewind_snapshots(), 3);
+        let frame_3 = system.capture_state();
+        for _ in 0..2 {
+            system.run_frame().unwrap();
+        }
+        assert_ne!(system.cycles(), frame_3.timing.cycles);
+
+        // Frame 5 was captured at the current cycle; frames 4 then 3 follow
+        assert!(system.rewind_step());
+        assert!(system.rewind_step());
+
+        assert_eq!(system.cycles(), frame_3.timing.cycles);
+        let mut rewound = system.capture_state();
+        rewound.metadata.timestamp = frame_3.metadata.timestamp;
+        assert!(rewound.to_bytes() == frame_3.to_bytes());
+    }
+
+    #[test]
+    fn test_rewind_step_without_history() {
+        let mut system = make_looping_system();
+        assert!(!system.rewind_step(), "rewind disabled");
+
+        system.enable_rewind(1, 4);
+        system.run_frame().unwrap();
+        assert!(!system.rewind_step(), "only the current frame is recorded");
+    }
+
+    #[test]
+    fn test_cheat_applied_each_frame() {
+        let mut system = make_looping_system();
+        system.add_cheat("80020000 0000").unwrap();
+
+        system.run_frame().unwrap();
+
+        // The loop counter at 0x80020000 is reset by the cheat every frame
+        assert_eq!(system.bus().read16(0x80020000).unwrap(), 0);
+    }
+
+    /// Decode a PNG file into (width, height, RGB bytes)
+    #[test]
+    fn test_audio_capture_single_voice() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("capture.wav");
+        let mut system = make_looping_system();
+
+        {
+            let mut spu = system.spu.borrow_mut();
+            spu.write_register(0x1F801DAA, 0xC000); // Enabled, unmuted
+            spu.write_register(0x1F801D80, 0x3FFF); // Main volume left
+            spu.write_register(0x1F801D82, 0x3FFF); // Main volume right
+
+            // Looping ADPCM block at 0x1000
+            spu.write_ram(0x1001, 0x07);
+            for addr in 0x1002..0x1010 {
+                spu.write_ram(addr, 0x77);
+            }
+
+            spu.write_register(0x1F801C00, 0x3FFF); // Voice 0 volume left
+            spu.write_register(0x1F801C02, 0x3FFF); // Voice 0 volume right
+            spu.write_register(0x1F801C04, 0x1000); // 44.1 kHz
+            spu.write_register(0x1F801C06, 0x1000 / 8);
+            spu.write_register(0x1F801C08, 0x000F); // Fastest attack, full sustain
+            spu.write_register(0x1F801D88, 0x0001); // Key on voice 0
+        }
+
+        // The first frame after power-on ends at the first VBlank, part-way
+        // through the frame period
+        system.run_frame().unwrap();
+
+        system.start_audio_capture(&path).unwrap();
+        assert!(system.is_capturing_audio());
+        system.run_frame().unwrap();
+        let frames = system.stop_audio_capture().unwrap();
+        assert!(!system.is_capturing_audio());
+
+        // One NTSC frame of SPU output
+        assert_eq!(frames, 735);
+
+        let data = std::fs::read(&path).unwrap();
+        let u32_at =
+            |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        assert_eq!(&data[0..4], b"RIFF");
+        assert_eq!(&data[8..16], b"WAVEfmt ");
+        assert_eq!(u32_at(24), 44_100);
+        assert_eq!(&data[36..40], b"data");
+        assert_eq!(u32_at(40), 735 * 4);
+        assert_eq!(u32_at(4), 36 + 735 * 4);
+        assert_eq!(data.len(), 44 + 735 * 4);
+        assert!(
+            data[44..].iter().any(|&b| b != 0),
+            "voice output should be audible"
+        );
+    }
+
+    #[test]
+    fn test_stop_audio_capture_without_start() {
+        let mut system = System::new();
+        assert_eq!(system.stop_audio_capture().unwrap(), 0);
+    }
+
+    fn read_png(path: &std::path::Path) -> (u32, u32, Vec<u8>) {
+        let decoder =
+            png::Decoder::new(std::io::BufReader::new(std::fs::File::open(path).unwrap()));
+        let mut reader = decoder.read_info().unwrap();
+        let mut buf = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut buf).unwrap();
+        buf.truncate(info.buffer_size());
+        (info.width, info.height, buf)
+    }
+
+    #[test]
+    fn test_screenshot_png_ntsc_15bit() {
+        let system = System::new();
+        {
+            let mut gpu = system.gpu.borrow_mut();
+            gpu.write_vram(0, 0, 0x001F); // Red at the display origin
+            gpu.write_vram(5, 2, 0x7C00); // Blue at (5, 2)
+        }
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("shot.png");
+
+        system.screenshot_png(&path).unwrap();
+
+        let (width, height, rgb) = read_png(&path);
+        assert_eq!((width, height), (320, 240));
+        assert_eq!(&rgb[..3], &[0xF8, 0x00, 0x00]);
+        let offset = (2 * 320 + 5) * 3;
+        assert_eq!(&rgb[offset..offset + 3], &[0x00, 0x00, 0xF8]);
+    }
+
+    #[test]
+    fn test_screenshot_png_pal_24bit() {
+        let system = System::new();
+        {
+            let mut gpu = system.gpu.borrow_mut();
+            // 320 wide, PAL, 24-bit color; 288 visible lines
+            gpu.write_gp1(0x08000000 | (1 << 3) | (1 << 4) | 1);
+            gpu.write_gp1(0x07000000 | 0x23 | ((0x23 + 288) << 10));
+            // First pixel: R=0x12, G=0x34, B=0x56
+            gpu.write_vram(0, 0, 0x3412);
+            gpu.write_vram(1, 0, 0x0056);
+        }
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("shot.png");
+
+        system.screenshot_png(&path).unwrap();
+
+        let (width, height, rgb) = read_png(&path);
+        assert_eq!((width, height), (320, 288));
+        assert_eq!(&rgb[..3], &[0x12, 0x34, 0x56]);
+    }
+
+    #[test]
+    fn test_recent_trace_filters_loop_body() {
+        let mut system = make_looping_system();
+        system.enable_trace_buffer(16);
+        system.add_trace_filter(0x8001000C, 0x80010010); // addiu t3, t3, 1
+
+        system.run_frame().unwrap();
+
+        let trace = system.recent_trace();
+        assert_eq!(trace.len(), 16);
+        assert!(trace.iter().all(|e| e.pc == 0x8001000C));
+        assert!(trace
+            .windows(2)
+            .all(|w| w[1].changes[0].1 == w[0].changes[0].1 + 1));
+        assert_eq!(trace[0].changes[0].0, 11);
+        assert_eq!(trace[0].disassemble(), "addiu r11, r11, 1");
+    }
+
+    #[test]
+    fn test_io_log_names_device_registers() {
+        let mut system = System::new();
+        system.enable_io_log(8);
+
+        system.bus_mut().write32(0x1F801814, 0x0300_0001).unwrap(); // Display off
+        let status = system.bus().read32(0x1F801814).unwrap();
+        system.bus_mut().write16(0x1F801070, 0).unwrap();
+
+        let log = system.recent_io_accesses();
+        let names: Vec<_> = log.iter().map(|access| access.name).collect();
+        assert_eq!(names, ["GPU GP1", "GPU GPUSTAT", "I_STAT"]);
+        assert_eq!(log[1].value, status);
+        assert_ne!(status & (1 << 23), 0);
+    }
+
+    #[test]
+    fn test_load_state_rejects_corrupt_data() {
+        let mut system = System::new();
+
+        let result = system.load_state(&[0xFF; 16]);
+
+        assert!(matches!(result, Err(EmulatorError::SaveState(_))));
+    }
 }
@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 itsakeyfut
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rewind buffer
+//!
+//! Keeps a ring of periodic save states. Only the newest snapshot holds full
+//! copies of RAM, VRAM and SPU RAM; every older snapshot stores the pages
+//! that differ from its successor, so memory use grows with how much the
+//! game actually touches between snapshots.
+
+use std::collections::VecDeque;
+
+use crate::core::save_state::SaveState;
+
+/// Delta page size in elements
+const PAGE_SIZE: usize = 1024;
+
+/// Pages that differ between two memory images
+///
+/// Applying the delta to the newer image yields the older one.
+struct PageDelta<T> {
+    /// (page index, page contents of the older image)
+    pages: Vec<(usize, Vec<T>)>,
+}
+
+impl<T: Copy + PartialEq> PageDelta<T> {
+    /// Record the pages of `older` that differ from `newer`
+    fn between(older: &[T], newer: &[T]) -> Self {
+        let pages = older
+            .chunks(PAGE_SIZE)
+            .zip(newer.chunks(PAGE_SIZE))
+            .enumerate()
+            .filter(|(_, (old, new))| old != new)
+            .map(|(index, (old, _))| (index, old.to_vec()))
+            .collect();
+
+        Self { pages }
+    }
+
+    /// Turn the newer image back into the older one
+    fn apply(&self, image: &mut [T]) {
+        for (index, page) in &self.pages {
+            let start = index * PAGE_SIZE;
+            image[start..start + page.len()].copy_from_slice(page);
+        }
+    }
+}
+
+/// Snapshot without its large memory images
+struct Snapshot {
+    /// Save state with RAM, VRAM and SPU RAM taken out
+    state: SaveState,
+
+    /// Deltas turning the next snapshot's images into this one's
+    /// (None for the newest)
+    undo: Option<(PageDelta<u8>, PageDelta<u16>, PageDelta<u8>)>,
+}
+
+/// Ring buffer of periodic save states
+pub(crate) struct RewindBuffer {
+    /// Snapshots, oldest first
+    snapshots: VecDeque<Snapshot>,
+
+    /// Maximum number of snapshots kept
+    capacity: usize,
+
+    /// Frames between snapshots
+    interval: u32,
+
+    /// Frames since the last snapshot
+    frame_counter: u32,
+
+    /// Full memory images of the newest snapshot (RAM, VRAM, SPU RAM)
+    images: (Vec<u8>, Vec<u16>, Vec<u8>),
+}
+
+impl RewindBuffer {
+    /// Create an empty rewind buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - Frames between snapshots (at least 1)
+    /// * `capacity` - Maximum number of snapshots kept (at least 1)
+    pub(crate) fn new(interval: u32, capacity: usize) -> Self {
+        Self {
+            snapshots: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            interval: interval.max(1),
+            frame_counter: 0,
+            images: (Vec::new(), Vec::new(), Vec::new()),
+        }
+    }
+
+    /// Count a completed frame
+    ///
+    /// # Returns
+    ///
+    /// true if a snapshot is due
+    pub(crate) fn frame_completed(&mut self) -> bool {
+        self.frame_counter += 1;
+        if self.frame_counter >= self.interval {
+            self.frame_counter = 0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Number of snapshots available
+    pub(crate) fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Add a snapshot as the newest entry
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - Complete save state of the current frame
+    pub(crate) fn push(&mut self, mut state: SaveState) {
+        let ram = std::mem::take(&mut state.memory.ram);
+        let vram = std::mem::take(&mut state.gpu.vram);
+        let spu_ram = std::mem::take(&mut state.spu.ram);
+
+        if let Some(previous) = self.snapshots.back_mut() {
+            let (old_ram, old_vram, old_spu_ram) = &self.images;
+            previous.undo = Some((
+                PageDelta::between(old_ram, &ram),
+                PageDelta::between(old_vram, &vram),
+                PageDelta::between(old_spu_ram, &spu_ram),
+            ));
+        }
+
+        if self.snapshots.len() == self.capacity {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(Snapshot { state, undo: None });
+        self.images = (ram, vram, spu_ram);
+    }
+
+    /// Remove and return the newest snapshot
+    ///
+    /// # Returns
+    ///
+    /// Complete save state, or None if the buffer is empty
+    pub(crate) fn pop(&mut self) -> Option<SaveState> {
+        let Snapshot { mut state, .. } = self.snapshots.pop_back()?;
+
+        match self.snapshots.back_mut().and_then(|s| s.undo.take()) {
+            Some((ram_delta, vram_delta, spu_delta)) => {
+                let (ram, vram, spu_ram) = &self.images;
+                state.memory.ram = ram.clone();
+                state.gpu.vram = vram.clone();
+                state.spu.ram = spu_ram.clone();
+
+                ram_delta.apply(&mut self.images.0);
+                vram_delta.apply(&mut self.images.1);
+                spu_delta.apply(&mut self.images.2);
+            }
+            None => {
+                let (ram, vram, spu_ram) = std::mem::take(&mut self.images);
+                state.memory.ram = ram;
+                state.gpu.vram = vram;
+                state.spu.ram = spu_ram;
+            }
+        }
+
+        Some(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Save state with the given RAM contents
+    fn state_with_ram(ram: Vec<u8>) -> SaveState {
+        let mut state = SaveState::default();
+        state.memory.ram = ram;
+        state
+    }
+
+    #[test]
+    fn test_page_delta_restores_older_image() {
+        let older: Vec<u8> = (0..4096).map(|i| i as u8).collect();
+        let mut newer = older.clone();
+        newer[10] = 0xAA;
+        newer[3000] = 0xBB;
+
+        let delta = PageDelta::between(&older, &newer);
+        assert_eq!(delta.pages.len(), 2);
+
+        delta.apply(&mut newer);
+        assert_eq!(newer, older);
+    }
+
+    #[test]
+    fn test_pop_returns_snapshots_newest_first() {
+        let mut buffer = RewindBuffer::new(1, 8);
+        for value in 1..=3u8 {
+            buffer.push(state_with_ram(vec![value; 4096]));
+        }
+
+        for value in (1..=3u8).rev() {
+            let state = buffer.pop().unwrap();
+            assert_eq!(state.memory.ram, vec![value; 4096]);
+        }
+        assert!(buffer.pop().is_none());
+    }
+
+    #[test]
+    fn test_capacity_drops_oldest() {
+        let mut buffer = RewindBuffer::new(1, 2);
+        for value in 1..=3u8 {
+            buffer.push(state_with_ram(vec![value; 16]));
+        }
+
+        assert_eq!(buffer.len(), 2);
+        buffer.pop();
+        assert_eq!(buffer.pop().unwrap().memory.ram, vec![2; 16]);
+    }
+
+    #[test]
+    fn test_frame_completed_every_interval() {
+        let mut buffer = RewindBuffer::new(3, 8);
+
+        let due: Vec<bool> = (0..6).map(|_| buffer.frame_completed()).collect();
+
+        assert_eq!(due, vec![false, false, true, false, false, true]);
+    }
+}
@@ -53,7 +53,9 @@
 //!
 //! - [PSX-SPX: Timers](http://problemkaputt.de/psx-spx.htm#timers)
 
-use super::timing::EventHandle;
+use super::gpu::VideoMode;
+use super::save_state::{StateSave, TimerChannelState, TimerState};
+use super::timing::{EventHandle, GlobalTicks};
 
 /// Timer mode control register
 #[derive(Debug, Clone, Default)]
@@ -118,9 +120,6 @@ pub struct TimerChannel {
     /// Overflow timing event handle
     overflow_event: Option<EventHandle>,
 
-    /// Interrupt pending flag (for event-driven timing)
-    interrupt_pending: bool,
-
     /// Flag indicating that the timer needs rescheduling
     needs_reschedule: bool,
 }
@@ -152,7 +151,6 @@ impl TimerChannel {
             last_sync: false,
             sync_latched: false,
             overflow_event: None,
-            interrupt_pending: false,
             needs_reschedule: false,
         }
     }
@@ -182,6 +180,22 @@ impl TimerChannel {
     /// Returns the mode register value. Reading the mode register
     /// resets the IRQ flag, reached_target, and reached_max flags.
     pub fn read_mode(&mut self) -> u16 {
+        let mut value = self.mode_bits();
+
+        value |= (self.irq_flag as u16) << 10;
+        value |= (self.reached_target as u16) << 11;
+        value |= (self.reached_max as u16) << 12;
+
+        // Reading mode resets flags
+        self.reached_target = false;
+        self.reached_max = false;
+        self.irq_flag = false;
+
+        value
+    }
+
+    /// Get the writable mode register bits (0-9) without the status flags
+    fn mode_bits(&self) -> u16 {
         let mut value = 0u16;
 
         value |= self.mode.sync_enable as u16;
@@ -192,14 +206,6 @@ impl TimerChannel {
         value |= (self.mode.irq_repeat as u16) << 6;
         value |= (self.mode.irq_pulse_mode as u16) << 7;
         value |= (self.mode.clock_source as u16) << 8;
-        value |= (self.irq_flag as u16) << 10;
-        value |= (self.reached_target as u16) << 11;
-        value |= (self.reached_max as u16) << 12;
-
-        // Reading mode resets flags
-        self.reached_target = false;
-        self.reached_max = false;
-        self.irq_flag = false;
 
         value
     }
@@ -314,8 +320,7 @@ impl TimerChannel {
                 if self.counter == self.target {
                     self.reached_target = true;
 
-                    if self.mode.irq_on_target {
-                        self.trigger_irq();
+                    if self.mode.irq_on_target && self.trigger_irq() {
                         irq_triggered = true;
                     }
 
@@ -328,8 +333,7 @@ impl TimerChannel {
                 if self.counter == 0xFFFF {
                     self.reached_max = true;
 
-                    if self.mode.irq_on_max {
-                        self.trigger_irq();
+                    if self.mode.irq_on_max && self.trigger_irq() {
                         irq_triggered = true;
                     }
                 }
@@ -380,12 +384,31 @@ impl TimerChannel {
 
     /// Trigger an IRQ
     ///
-    /// Sets the IRQ flag if conditions are met (one-shot or repeat mode).
-    fn trigger_irq(&mut self) {
-        if !self.irq_flag || self.mode.irq_repeat {
+    /// In pulse mode (bit 7 = 0) the IRQ flag is set on every trigger. In
+    /// toggle mode (bit 7 = 1) the flag flips on every trigger, and only the
+    /// transition to set raises an interrupt. One-shot mode (bit 6 = 0)
+    /// ignores triggers while the flag is still set.
+    ///
+    /// # Returns
+    ///
+    /// `true` if an interrupt should be requested, `false` otherwise
+    fn trigger_irq(&mut self) -> bool {
+        if self.irq_flag && !self.mode.irq_repeat {
+            return false;
+        }
+
+        if self.mode.irq_pulse_mode {
+            self.irq_flag = !self.irq_flag;
+        } else {
             self.irq_flag = true;
-            log::trace!("Timer {} IRQ triggered", self.channel_id);
         }
+
+        log::trace!(
+            "Timer {} IRQ triggered (flag={})",
+            self.channel_id,
+            self.irq_flag
+        );
+        self.irq_flag
     }
 
     /// Check if IRQ is pending
@@ -413,6 +436,24 @@ pub struct Timers {
 
     /// Accumulator for Timer 2 divide-by-8 mode
     timer2_div_accum: u32,
+
+    /// GPU dot clock divider for the current horizontal resolution
+    dot_clock_divider: u32,
+
+    /// Accumulator for Timer 0 dot clock mode, in video clock cycles
+    timer0_dot_accum: u32,
+
+    /// Video standard, which sets the scanline length for Timer 1 hblank mode
+    video_mode: VideoMode,
+
+    /// Accumulator for Timer 1 hblank mode, in CPU cycles
+    timer1_hblank_accum: u32,
+
+    /// Latest global time reported by the system
+    current_time: GlobalTicks,
+
+    /// Global time up to which the counters have been advanced by `tick`
+    synced_time: GlobalTicks,
 }
 
 impl Timers {
@@ -435,6 +476,12 @@ impl Timers {
                 TimerChannel::new(2),
             ],
             timer2_div_accum: 0,
+            dot_clock_divider: 8,
+            timer0_dot_accum: 0,
+            video_mode: VideoMode::NTSC,
+            timer1_hblank_accum: 0,
+            current_time: 0,
+            synced_time: 0,
         }
     }
 
@@ -466,6 +513,106 @@ impl Timers {
         &mut self.channels[index]
     }
 
+    /// Video clock to CPU clock ratio (53.693175MHz / 33.8688MHz ≈ 11/7)
+    const VIDEO_CLOCK_NUM: u32 = 11;
+    const VIDEO_CLOCK_DEN: u32 = 7;
+
+    /// Set the GPU dot clock divider used by Timer 0's dot clock source
+    ///
+    /// # Arguments
+    ///
+    /// * `divider` - Video clock cycles per dot (see `GPU::dot_clock_divider`)
+    pub fn set_dot_clock_divider(&mut self, divider: u32) {
+        if divider != self.dot_clock_divider {
+            self.dot_clock_divider = divider;
+            self.channels[0].needs_reschedule = true;
+        }
+    }
+
+    /// Set the video standard used by Timer 1's hblank source
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - NTSC or PAL (see `GPU::video_mode`)
+    pub fn set_video_mode(&mut self, mode: VideoMode) {
+        if mode != self.video_mode {
+            self.video_mode = mode;
+            self.channels[1].needs_reschedule = true;
+        }
+    }
+
+    /// Set the current global time
+    ///
+    /// Counter reads are interpolated from the last `tick` up to this time.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - Current global time in CPU cycles
+    pub fn set_current_time(&mut self, now: GlobalTicks) {
+        self.current_time = now;
+    }
+
+    /// Read a channel's counter as of the current time
+    ///
+    /// Counters only advance in `tick`, so a read between ticks adds the
+    /// timer ticks for the cycles elapsed since the last `tick`.
+    /// Gated (sync-enabled) channels are returned as-is, since their counting
+    /// depends on blanking signals that are not known in advance.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Timer channel index (0-2)
+    ///
+    /// # Returns
+    ///
+    /// Interpolated 16-bit counter value
+    pub fn read_counter(&self, index: usize) -> u16 {
+        let ch = &self.channels[index];
+        let elapsed = self.current_time.saturating_sub(self.synced_time);
+        if elapsed == 0 || ch.mode.sync_enable {
+            return ch.counter;
+        }
+
+        let ticks = self.ticks_for_cycles(index, elapsed);
+
+        // Wrap the same way `TimerChannel::tick` does, so the value stays
+        // consistent with the next scheduled overflow
+        let period = if ch.mode.reset_on_target && ch.target > 0 && ch.counter < ch.target {
+            ch.target as u64
+        } else {
+            0x1_0000
+        };
+        ((ch.counter as u64 + ticks) % period) as u16
+    }
+
+    /// Convert elapsed CPU cycles into timer ticks without advancing state
+    ///
+    /// Mirrors the accumulator math in `tick`.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - Timer channel index (0-2)
+    /// * `cycles` - Elapsed CPU cycles
+    ///
+    /// # Returns
+    ///
+    /// Number of timer ticks in `cycles`
+    fn ticks_for_cycles(&self, channel: usize, cycles: u64) -> u64 {
+        let clock_source = self.channels[channel].mode.clock_source;
+        match channel {
+            0 if clock_source & 0x01 != 0 => {
+                let video = self.timer0_dot_accum as u64 + cycles * Self::VIDEO_CLOCK_NUM as u64;
+                video / (Self::VIDEO_CLOCK_DEN * self.dot_clock_divider) as u64
+            }
+            1 if clock_source & 0x01 != 0 => {
+                (self.timer1_hblank_accum as u64 + cycles)
+                    / self.video_mode.cycles_per_scanline() as u64
+            }
+            2 if clock_source & 0x02 != 0 => (self.timer2_div_accum as u64 + cycles) / 8,
+            _ => cycles,
+        }
+    }
+
     /// Tick all timers
     ///
     /// Advances all timer channels based on their clock sources.
@@ -482,16 +629,39 @@ impl Timers {
     pub fn tick(&mut self, cycles: u32, hblank: bool, vblank: bool) -> [bool; 3] {
         let mut irqs = [false; 3];
 
-        // Timer 0: System clock or pixel clock (simplified as system clock)
-        irqs[0] = self.channels[0].tick(cycles, false);
+        // Timer 0: System clock or dot clock
+        // Check low bit (bit 8): values 1 and 3 both select the dot clock
+        let timer0_cycles = if self.channels[0].mode.clock_source & 0x01 != 0 {
+            let cycles_per_dot = Self::VIDEO_CLOCK_DEN * self.dot_clock_divider;
+            self.timer0_dot_accum += cycles * Self::VIDEO_CLOCK_NUM;
+            let whole = self.timer0_dot_accum / cycles_per_dot;
+            self.timer0_dot_accum %= cycles_per_dot;
+            whole
+        } else {
+            self.timer0_dot_accum = 0;
+            cycles
+        };
+        irqs[0] = self.channels[0].tick(timer0_cycles, false);
 
         // Timer 1: System clock or hblank
         // Clock source determines pulse/count rate (HBlank vs system clock)
         // Sync signal is ALWAYS VBlank regardless of clock source
         // Check low bit (bit 8): values 1 and 3 both select HBlank mode
+        // An explicit hblank signal resynchronizes the scanline accumulator
         let (timer1_cycles, timer1_sync) = if self.channels[1].mode.clock_source & 0x01 != 0 {
-            (if hblank { 1 } else { 0 }, vblank)
+            let cycles_per_scanline = self.video_mode.cycles_per_scanline();
+            self.timer1_hblank_accum += cycles;
+            let mut whole = self.timer1_hblank_accum / cycles_per_scanline;
+            self.timer1_hblank_accum %= cycles_per_scanline;
+            if hblank {
+                // The pulse marks the end of the scanline the accumulator
+                // may already have counted
+                whole = whole.max(1);
+                self.timer1_hblank_accum = 0;
+            }
+            (whole, vblank)
         } else {
+            self.timer1_hblank_accum = 0;
             (cycles, vblank)
         };
         irqs[1] = self.channels[1].tick(timer1_cycles, timer1_sync);
@@ -510,13 +680,19 @@ impl Timers {
         };
         irqs[2] = self.channels[2].tick(timer2_cycles, false);
 
+        // Counters are now current
+        self.synced_time = self.current_time;
+
         irqs
     }
 
     /// Register timing events for timer overflow
     ///
     /// This should be called during system initialization to register timer
-    /// timing events with the timing manager.
+    /// timing events with the timing manager. The events do not touch the
+    /// counters, which only advance in `tick`; they mark the cycle at which a
+    /// channel next reaches its IRQ condition so the system scheduler can end
+    /// a CPU slice there.
     ///
     /// # Arguments
     ///
@@ -534,8 +710,10 @@ impl Timers {
 
     /// Process timer timing events
     ///
-    /// This should be called by System when timing events fire.
-    /// Also handles rescheduling when mode/target changes occur.
+    /// This should be called by System when timing events fire, after the
+    /// timers have been ticked up to the current time. Fired overflow events
+    /// are rescheduled from the updated counter. Also handles rescheduling
+    /// when mode/target changes occur.
     ///
     /// # Arguments
     ///
@@ -550,7 +728,7 @@ impl Timers {
         for i in 0..3 {
             if let Some(handle) = self.channels[i].overflow_event {
                 if triggered_events.contains(&handle) {
-                    self.timer_overflow_callback(i, timing);
+                    self.reschedule_timer(i, timing);
                 }
             }
         }
@@ -564,42 +742,6 @@ impl Timers {
         }
     }
 
-    /// Timer overflow callback (called when overflow_event fires)
-    ///
-    /// Handles timer overflow and reschedules the next overflow event.
-    ///
-    /// # Arguments
-    ///
-    /// * `channel` - Timer channel index (0-2)
-    /// * `timing` - Timing event manager
-    fn timer_overflow_callback(
-        &mut self,
-        channel: usize,
-        timing: &mut super::timing::TimingEventManager,
-    ) {
-        let ch = &mut self.channels[channel];
-
-        // Reset counter to 0 if reset_on_target is enabled
-        if ch.mode.reset_on_target {
-            ch.counter = 0;
-            ch.reached_target = true;
-        } else {
-            // Otherwise wrap around
-            ch.counter = ch.counter.wrapping_add(1);
-        }
-
-        // Set interrupt flags
-        if ch.mode.irq_on_target || ch.mode.irq_on_max {
-            ch.interrupt_pending = true;
-            ch.irq_flag = true;
-        }
-
-        log::trace!("Timer {}: Overflow event fired", channel);
-
-        // Reschedule for next overflow
-        self.reschedule_timer(channel, timing);
-    }
-
     /// Reschedule timer overflow event
     ///
     /// Calculates when the next overflow will occur and schedules the event.
@@ -627,32 +769,55 @@ impl Timers {
             return;
         };
 
-        // Calculate cycles until overflow
-        let remaining = target.saturating_sub(ch.counter) as i32;
-        if remaining <= 0 {
-            // Already at or past target, schedule immediately
-            timing.schedule(handle, 1);
-            return;
-        }
+        // Calculate timer ticks until the target is next reached, wrapping
+        // through 0xFFFF if the counter is already at or past it
+        let remaining = if target > ch.counter {
+            (target - ch.counter) as i32
+        } else {
+            0x1_0000 - ch.counter as i32 + target as i32
+        };
 
-        // Get clock divider based on clock source
-        let divider = self.get_clock_divider(channel);
-        let cycles_until_overflow = remaining * divider;
+        // Convert timer ticks to CPU cycles based on clock source
+        let cycles_until_overflow = self.cycles_for_ticks(channel, remaining);
 
         timing.schedule(handle, cycles_until_overflow);
         log::trace!(
-            "Timer {}: Scheduled overflow in {} cycles (counter={}, target={}, divider={})",
+            "Timer {}: Scheduled overflow in {} cycles (counter={}, target={})",
             channel,
             cycles_until_overflow,
             ch.counter,
-            target,
-            divider
+            target
         );
     }
 
+    /// Convert a number of timer ticks into CPU cycles
+    ///
+    /// The dot clock is not an integer fraction of the CPU clock, so Timer 0
+    /// in dot clock mode is converted with the video/CPU clock ratio and
+    /// rounded up.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - Timer channel index (0-2)
+    /// * `ticks` - Number of timer ticks
+    ///
+    /// # Returns
+    ///
+    /// Number of CPU cycles for `ticks` timer ticks
+    fn cycles_for_ticks(&self, channel: usize, ticks: i32) -> i32 {
+        if channel == 0 && self.channels[0].mode.clock_source & 1 != 0 {
+            let num = ticks as i64 * (Self::VIDEO_CLOCK_DEN * self.dot_clock_divider) as i64;
+            let den = Self::VIDEO_CLOCK_NUM as i64;
+            return ((num + den - 1) / den) as i32;
+        }
+
+        ticks * self.get_clock_divider(channel)
+    }
+
     /// Get clock divider for a timer channel
     ///
     /// Returns the number of CPU cycles per timer tick based on the clock source.
+    /// Timer 0's dot clock is not an integer divider; see `cycles_for_ticks`.
     ///
     /// # Arguments
     ///
@@ -666,9 +831,9 @@ impl Timers {
 
         match channel {
             0 => {
-                // Timer 0: system clock or pixel clock
+                // Timer 0: system clock or dot clock (rounded to whole cycles)
                 if ch.mode.clock_source & 1 != 0 {
-                    8 // Pixel clock (simplified, approximately 1/8 of CPU clock)
+                    (Self::VIDEO_CLOCK_DEN * self.dot_clock_divider / Self::VIDEO_CLOCK_NUM) as i32
                 } else {
                     1 // System clock
                 }
@@ -676,7 +841,7 @@ impl Timers {
             1 => {
                 // Timer 1: system clock or hblank
                 if ch.mode.clock_source & 1 != 0 {
-                    2146 // HBlank (cycles per scanline)
+                    self.video_mode.cycles_per_scanline() as i32 // HBlank
                 } else {
                     1 // System clock
                 }
@@ -692,25 +857,6 @@ impl Timers {
             _ => 1,
         }
     }
-
-    /// Poll timer interrupt flags
-    ///
-    /// Returns interrupt flags and clears them.
-    /// Replaces the return value of tick() for event-driven timing.
-    ///
-    /// # Returns
-    ///
-    /// Array of 3 booleans indicating interrupt status for each timer
-    pub fn poll_interrupts(&mut self) -> [bool; 3] {
-        let mut irqs = [false; 3];
-
-        for (i, irq) in irqs.iter_mut().enumerate() {
-            *irq = self.channels[i].interrupt_pending;
-            self.channels[i].interrupt_pending = false;
-        }
-
-        irqs
-    }
 }
 
 impl Default for Timers {
@@ -719,18 +865,72 @@ impl Default for Timers {
     }
 }
 
-/// ⚠️ **UNUSED PREPARATORY CODE - NOT CURRENTLY INVOKED** ⚠️
-///
-/// This IODevice trait implementation for Timers is **dead code** that exists only for
-/// future Phase 2 work. It is NOT used by any current code path.
-///
-/// **Current Implementation**: The Bus directly calls timer methods via
-/// `timers.borrow_mut().channel_mut(n).read_mode()`, etc. This provides proper mutable
-/// access and works correctly.
+impl StateSave for Timers {
+    type State = TimerState;
+
+    fn to_state(&self) -> TimerState {
+        TimerState {
+            timers: self
+                .channels
+                .iter()
+                .map(|ch| TimerChannelState {
+                    counter: ch.counter as u32,
+                    target: ch.target as u32,
+                    mode: ch.mode_bits() as u32,
+                    irq_flag: ch.irq_flag,
+                    reached_target: ch.reached_target,
+                    reached_max: ch.reached_max,
+                    last_sync: ch.last_sync,
+                    sync_latched: ch.sync_latched,
+                })
+                .collect(),
+            timer0_dot_accum: self.timer0_dot_accum,
+            timer1_hblank_accum: self.timer1_hblank_accum,
+            timer2_div_accum: self.timer2_div_accum,
+            dot_clock_divider: self.dot_clock_divider,
+            video_mode: self.video_mode == VideoMode::PAL,
+            current_time: self.current_time,
+            synced_time: self.synced_time,
+        }
+    }
+
+    /// Restore timers from a saved state
+    ///
+    /// Overflow event handles are kept from this instance; every channel is
+    /// marked for rescheduling so the next `process_events` call schedules
+    /// its overflow against the restored counter.
+    fn restore_from_state(&mut self, state: &TimerState) {
+        for (ch, saved) in self.channels.iter_mut().zip(&state.timers) {
+            ch.write_mode(saved.mode as u16);
+            ch.counter = saved.counter as u16;
+            ch.target = saved.target as u16;
+            ch.irq_flag = saved.irq_flag;
+            ch.reached_target = saved.reached_target;
+            ch.reached_max = saved.reached_max;
+            ch.last_sync = saved.last_sync;
+            ch.sync_latched = saved.sync_latched;
+            ch.needs_reschedule = true;
+        }
+
+        self.timer0_dot_accum = state.timer0_dot_accum;
+        self.timer1_hblank_accum = state.timer1_hblank_accum;
+        self.timer2_div_accum = state.timer2_div_accum;
+        self.dot_clock_divider = state.dot_clock_divider;
+        self.video_mode = if state.video_mode {
+            VideoMode::PAL
+        } else {
+            VideoMode::NTSC
+        };
+        self.current_time = state.current_time;
+        self.synced_time = state.synced_time;
+    }
+}
+
+/// IODevice trait implementation for Timers
 ///
-/// **Future Work (Phase 2+)**: When the Bus architecture is redesigned for trait-based
-/// device routing, this implementation will be activated and the limitations below will
-/// need to be addressed.
+/// The Bus currently routes timer registers directly through
+/// `timers.borrow_mut().channel_mut(n)`; this implementation provides the
+/// same behavior for trait-based device routing.
 ///
 /// ## Timer Register Layout
 ///
@@ -741,22 +941,15 @@ impl Default for Timers {
 ///
 /// Address range: 0x1F801100 - 0x1F80112F (48 bytes total)
 ///
-/// ## Known Limitations (To Be Fixed in Phase 2)
-///
-/// - **Mode register reads return 0**: The `read_mode()` method requires `&mut self` to
-///   clear status flags, but the IODevice trait's `read_register()` only provides `&self`.
-///   The current workaround returns 0 with a warning. This needs interior mutability
-///   (RefCell/atomic types) or trait redesign to fix properly.
-/// - **No runtime impact**: Since this implementation is unused, the limitation doesn't
-///   affect emulation accuracy or behavior.
-#[allow(dead_code)]
+/// Mode register reads return the live value and clear the reached
+/// target/max and IRQ flags, exactly like the direct Bus path.
 impl crate::core::memory::IODevice for Timers {
     fn address_range(&self) -> (u32, u32) {
         // Timer registers: 0x1F801100 - 0x1F80112F (3 timers × 16 bytes)
         (0x1F801100, 0x1F80112F)
     }
 
-    fn read_register(&self, offset: u32) -> crate::core::error::Result<u32> {
+    fn read_register(&mut self, offset: u32) -> crate::core::error::Result<u32> {
         // Calculate which timer and which register
         let timer_index = ((offset / 0x10) & 0x03) as usize;
         let reg_offset = offset % 0x10;
@@ -773,20 +966,16 @@ impl crate::core::memory::IODevice for Timers {
         match reg_offset {
             // Counter value (offset 0x00)
             0x00 => {
-                let value = self.channel(timer_index).read_counter() as u32;
+                let value = Timers::read_counter(self, timer_index) as u32;
                 log::trace!("TIMER{} counter read -> 0x{:04X}", timer_index, value);
                 Ok(value)
             }
 
             // Mode register (offset 0x04)
             0x04 => {
-                // Note: read_mode() requires &mut self due to flag clearing
-                // This is a limitation of the trait design - we'll log a warning
-                log::warn!(
-                    "TIMER{} mode read via IODevice (requires mutable access)",
-                    timer_index
-                );
-                Ok(0) // TODO: Need to handle this properly
+                let value = self.channel_mut(timer_index).read_mode() as u32;
+                log::trace!("TIMER{} mode read -> 0x{:04X}", timer_index, value);
+                Ok(value)
             }
 
             // Target value (offset 0x08)
@@ -854,6 +1043,22 @@ impl crate::core::memory::IODevice for Timers {
         }
     }
 
+    fn write_register16(&mut self, offset: u32, value: u16) -> crate::core::error::Result<()> {
+        // Timer registers are 16 bits wide; write directly instead of the
+        // default read-modify-write, which would clear the mode flags.
+        if offset & 0x02 != 0 {
+            return Ok(());
+        }
+        self.write_register(offset, value as u32)
+    }
+
+    fn write_register8(&mut self, offset: u32, value: u8) -> crate::core::error::Result<()> {
+        if offset & 0x03 != 0 {
+            return Ok(());
+        }
+        self.write_register(offset, value as u32)
+    }
+
     fn name(&self) -> &str {
         "Timers"
     }
@@ -1119,6 +1324,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_irq_pulse_mode_repeated_triggers() {
+        let mut timer = TimerChannel::new(0);
+        timer.write_target(10);
+        timer.write_mode(0x0058); // IRQ on target, repeat, pulse, reset on target
+
+        for _ in 0..3 {
+            assert!(timer.tick(10, false), "Pulse mode should raise every IRQ");
+            assert!(timer.irq_flag);
+        }
+        assert_eq!(timer.read_mode() & (1 << 10), 1 << 10);
+    }
+
+    #[test]
+    fn test_irq_toggle_mode_repeated_triggers() {
+        let mut timer = TimerChannel::new(0);
+        timer.write_target(10);
+        timer.write_mode(0x00D8); // IRQ on target, repeat, toggle, reset on target
+
+        // The flag flips on each trigger; only the set transition raises an IRQ
+        assert!(timer.tick(10, false));
+        assert!(timer.irq_flag);
+        assert!(!timer.tick(10, false));
+        assert!(!timer.irq_flag);
+        assert!(timer.tick(10, false));
+        assert!(timer.irq_flag);
+
+        // Bit 10 reflects the current toggle state
+        assert_eq!(timer.read_mode() & (1 << 10), 1 << 10);
+        timer.irq_flag = true;
+        timer.tick(10, false);
+        assert_eq!(timer.read_mode() & (1 << 10), 0);
+    }
+
+    #[test]
+    fn test_irq_toggle_one_shot_stops_after_first() {
+        let mut timer = TimerChannel::new(0);
+        timer.write_target(10);
+        timer.write_mode(0x0098); // IRQ on target, one-shot, toggle, reset on target
+
+        assert!(timer.tick(10, false));
+        assert!(!timer.tick(10, false));
+        assert!(timer.irq_flag, "One-shot toggle should not flip back");
+    }
+
     #[test]
     fn test_irq_repeat_mode() {
         let mut timer = TimerChannel::new(0);
@@ -1264,12 +1514,47 @@ mod tests {
         // Timer 0 source 0: system clock (divider = 1)
         assert_eq!(timers.get_clock_divider(0), 1);
 
-        // Timer 0 source 1: pixel clock (divider = 8, simplified)
+        // Timer 0 source 1: dot clock (320 wide: 8 video cycles ≈ 5.09 CPU cycles)
         let mut timer0 = TimerChannel::new(0);
         timer0.mode.clock_source = 1;
         let mut timers_with_pixel = Timers::new();
         timers_with_pixel.channels[0] = timer0;
-        assert_eq!(timers_with_pixel.get_clock_divider(0), 8);
+        assert_eq!(timers_with_pixel.get_clock_divider(0), 5);
+        assert_eq!(timers_with_pixel.cycles_for_ticks(0, 11), 56);
+    }
+
+    #[test]
+    fn test_timer0_dot_clock_follows_resolution() {
+        // One NTSC scanline is ~2146 CPU cycles
+        const SCANLINE: u32 = 2146;
+
+        let mut dots_320 = Timers::new();
+        dots_320.channel_mut(0).write_mode(0x0100);
+        dots_320.set_dot_clock_divider(8);
+        dots_320.tick(SCANLINE, false, false);
+
+        let mut dots_640 = Timers::new();
+        dots_640.channel_mut(0).write_mode(0x0100);
+        dots_640.set_dot_clock_divider(4);
+        dots_640.tick(SCANLINE, false, false);
+
+        // 2146 * 11 / (7 * divider)
+        assert_eq!(dots_320.channel(0).read_counter(), 421);
+        assert_eq!(dots_640.channel(0).read_counter(), 843);
+    }
+
+    #[test]
+    fn test_timer0_dot_clock_keeps_fractional_cycles() {
+        let mut timers = Timers::new();
+        timers.channel_mut(0).write_mode(0x0100);
+        timers.set_dot_clock_divider(8);
+
+        // 56 video-clock-scaled cycles make exactly 11 dots at 320 wide
+        for _ in 0..56 {
+            timers.tick(1, false, false);
+        }
+
+        assert_eq!(timers.channel(0).read_counter(), 11);
     }
 
     #[test]
@@ -1285,6 +1570,11 @@ mod tests {
         let mut timers_with_hblank = Timers::new();
         timers_with_hblank.channels[1] = timer1;
         assert_eq!(timers_with_hblank.get_clock_divider(1), 2146);
+
+        // PAL scanlines are longer
+        timers_with_hblank.set_video_mode(VideoMode::PAL);
+        assert_eq!(timers_with_hblank.get_clock_divider(1), 2168);
+        assert!(timers_with_hblank.channel(1).needs_reschedule);
     }
 
     #[test]
@@ -1302,6 +1592,98 @@ mod tests {
         assert_eq!(timers_with_div8.get_clock_divider(2), 8);
     }
 
+    #[test]
+    fn test_read_counter_interpolates_elapsed_cycles() {
+        let mut timers = Timers::new();
+        timers.channel_mut(0).write_mode(0x0000);
+        timers.channel_mut(2).write_mode(0x0200); // system clock / 8
+
+        timers.set_current_time(100);
+        timers.tick(100, false, false);
+        assert_eq!(timers.read_counter(0), 100);
+
+        // 50 cycles pass without a tick
+        timers.set_current_time(150);
+        assert_eq!(timers.read_counter(0), 150);
+        assert_eq!(timers.read_counter(2), 150 / 8);
+        assert_eq!(
+            timers.channel(0).read_counter(),
+            100,
+            "Read must not advance"
+        );
+
+        // Ticking the same cycles brings the stored counter to the same value
+        timers.tick(50, false, false);
+        assert_eq!(timers.channel(0).read_counter(), 150);
+        assert_eq!(timers.read_counter(0), 150);
+    }
+
+    #[test]
+    fn test_read_counter_interpolation_wraps_at_target() {
+        let mut timers = Timers::new();
+        timers.channel_mut(0).write_target(100);
+        timers.channel_mut(0).write_mode(0x0008); // reset on target
+
+        timers.set_current_time(250);
+        let interpolated = timers.read_counter(0);
+        timers.tick(250, false, false);
+
+        assert_eq!(interpolated, 50);
+        assert_eq!(timers.channel(0).read_counter(), interpolated);
+    }
+
+    #[test]
+    fn test_read_counter_sync_enabled_not_interpolated() {
+        let mut timers = Timers::new();
+        timers.channel_mut(0).write_mode(0x0001); // pause during hblank
+
+        timers.set_current_time(500);
+        assert_eq!(timers.read_counter(0), 0);
+    }
+
+    #[test]
+    fn test_state_round_trip_continues_identically() {
+        let mut timers = Timers::new();
+        timers.set_dot_clock_divider(5);
+        timers.set_video_mode(VideoMode::PAL);
+        timers.channel_mut(0).write_mode(0x0158); // dot clock, repeat IRQ on target
+        timers.channel_mut(0).write_target(300);
+        timers.channel_mut(1).write_mode(0x0100); // hblank
+        timers.channel_mut(2).write_mode(0x0230); // sys/8, IRQ on target and max
+        timers.channel_mut(2).write_target(50);
+        timers.tick(12_345, false, false);
+
+        // Encode and decode the state as a save file would
+        let config = bincode::config::standard();
+        let bytes = bincode::encode_to_vec(timers.to_state(), config).unwrap();
+        let (state, _): (TimerState, usize) = bincode::decode_from_slice(&bytes, config).unwrap();
+
+        let mut restored = Timers::new();
+        restored.restore_from_state(&state);
+
+        for i in 0..3 {
+            assert!(restored.channel(i).needs_reschedule);
+        }
+
+        for _ in 0..100 {
+            assert_eq!(
+                timers.tick(777, false, false),
+                restored.tick(777, false, false)
+            );
+        }
+        for i in 0..3 {
+            assert_eq!(
+                timers.channel_mut(i).read_mode(),
+                restored.channel_mut(i).read_mode()
+            );
+            assert_eq!(timers.read_counter(i), restored.read_counter(i));
+            assert_eq!(
+                timers.channel(i).read_target(),
+                restored.channel(i).read_target()
+            );
+        }
+    }
+
     #[test]
     fn test_timers_tick_all_channels() {
         let mut timers = Timers::new();
@@ -1334,6 +1716,44 @@ mod tests {
         assert_eq!(timers.channel(1).read_counter(), 1);
     }
 
+    #[test]
+    fn test_timer1_hblank_counts_scanlines_by_video_mode() {
+        let mut ntsc = Timers::new();
+        ntsc.channel_mut(1).write_mode(0x0100);
+
+        let mut pal = Timers::new();
+        pal.set_video_mode(VideoMode::PAL);
+        pal.channel_mut(1).write_mode(0x0100);
+
+        // 2146 cycles is one full NTSC scanline but not yet a PAL one
+        ntsc.tick(2146, false, false);
+        pal.tick(2146, false, false);
+        assert_eq!(ntsc.channel(1).read_counter(), 1);
+        assert_eq!(pal.channel(1).read_counter(), 0);
+
+        // 100 NTSC scanlines of cycles spread over small steps
+        for _ in 0..2146 {
+            ntsc.tick(99, false, false);
+            pal.tick(99, false, false);
+        }
+        assert_eq!(ntsc.channel(1).read_counter(), 1 + 99);
+        // 214600 / 2168 = 98 PAL scanlines
+        assert_eq!(pal.channel(1).read_counter(), 98);
+    }
+
+    #[test]
+    fn test_timer1_hblank_pulse_not_counted_twice() {
+        let mut timers = Timers::new();
+        timers.channel_mut(1).write_mode(0x0100);
+
+        // The GPU's HBlank pulse lands on the cycle that completes the line
+        for _ in 0..3 {
+            timers.tick(2145, false, false);
+            timers.tick(1, true, false);
+        }
+        assert_eq!(timers.channel(1).read_counter(), 3);
+    }
+
     #[test]
     fn test_timer1_vblank_sync_signal() {
         let mut timers = Timers::new();
@@ -1550,4 +1970,40 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_iodevice_mode_read_returns_value_and_clears_flags() {
+        use crate::core::memory::IODevice;
+
+        let mut timers = Timers::new();
+        // Timer 1: IRQ on target, repeat, target = 5
+        timers.write_register(0x14, 0x0050).unwrap();
+        timers.write_register(0x18, 5).unwrap();
+        timers.channel_mut(1).tick(5, false);
+
+        let mode = timers.read_register(0x14).unwrap();
+        assert_eq!(mode & 0x03FF, 0x0050, "Mode bits should read back");
+        assert_ne!(mode & (1 << 11), 0, "Reached target flag should be set");
+        assert_ne!(mode & (1 << 10), 0, "IRQ flag should be set");
+
+        // The read cleared the status flags
+        let mode = timers.read_register(0x14).unwrap();
+        assert_eq!(mode & 0x1C00, 0);
+    }
+
+    #[test]
+    fn test_iodevice_write16_does_not_clear_mode_flags() {
+        use crate::core::memory::IODevice;
+
+        let mut timers = Timers::new();
+        timers.write_register16(0x04, 0x0010).unwrap();
+        timers.write_register16(0x08, 3).unwrap();
+        timers.channel_mut(0).tick(3, false);
+
+        // Writing the target through the 16-bit path must not read the mode
+        timers.write_register16(0x08, 0x100).unwrap();
+
+        assert_eq!(timers.read_register16(0x08).unwrap(), 0x100);
+        assert_ne!(timers.read_register16(0x04).unwrap() & (1 << 11), 0);
+    }
 }
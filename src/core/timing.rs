@@ -46,6 +46,9 @@
 //! timing.run_events();
 //! ```
 
+use crate::core::cpu::TraceBuffer;
+use crate::core::save_state::{StateSave, TimingEventState, TimingState};
+
 /// Tick count type (relative time in CPU cycles)
 pub type TickCount = i32;
 
@@ -100,6 +103,42 @@ impl TimingEvent {
     }
 }
 
+/// What happened to an event in the event trace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventTraceKind {
+    /// `schedule()` was called
+    Scheduled,
+    /// `deactivate()` was called
+    Deactivated,
+    /// The event ran in `run_events()`
+    Fired,
+}
+
+/// One entry of the event trace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventTraceEntry {
+    /// What happened
+    pub kind: EventTraceKind,
+
+    /// Event handle
+    pub handle: EventHandle,
+
+    /// Event name
+    pub name: &'static str,
+
+    /// Global time when it happened
+    pub tick: GlobalTicks,
+
+    /// Time the event is due at; for `Fired`, the time it was due, so
+    /// `tick - run_time` is how late it ran
+    pub run_time: GlobalTicks,
+}
+
+/// Ring buffer of recent scheduling activity
+///
+/// Shares the CPU trace buffer's layout; address filters are not used.
+type EventTrace = TraceBuffer<EventTraceEntry>;
+
 /// Timing Event Manager
 ///
 /// Manages the global timing system and schedules events for execution.
@@ -142,8 +181,8 @@ pub struct TimingEventManager {
     /// Registered events
     events: Vec<TimingEvent>,
 
-    /// Frame target for execution control
-    frame_target: Option<GlobalTicks>,
+    /// Recent schedule/deactivate/fire activity, when enabled
+    trace: Option<EventTrace>,
 }
 
 impl TimingEventManager {
@@ -170,10 +209,75 @@ impl TimingEventManager {
             pending_ticks: 0,
             downcount: i32::MAX,
             events: Vec::new(),
-            frame_target: None,
+            trace: None,
+        }
+    }
+
+    /// Start recording event activity for debugging
+    ///
+    /// Every `schedule()`, `deactivate()` and fired event is recorded with
+    /// its name and tick. Replaces any existing trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of most recent entries to keep
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use psrx::core::timing::{EventTraceKind, TimingEventManager};
+    ///
+    /// let mut timing = TimingEventManager::new();
+    /// timing.enable_event_trace(256);
+    ///
+    /// let event = timing.register_event("CDROM");
+    /// timing.schedule(event, 100);
+    /// timing.pending_ticks = 100;
+    /// timing.run_events();
+    ///
+    /// let kinds: Vec<_> = timing.recent_events().iter().map(|e| e.kind).collect();
+    /// assert_eq!(kinds, [EventTraceKind::Scheduled, EventTraceKind::Fired]);
+    /// ```
+    pub fn enable_event_trace(&mut self, capacity: usize) {
+        self.trace = Some(EventTrace::new(capacity));
+    }
+
+    /// Stop recording event activity and drop the trace
+    pub fn disable_event_trace(&mut self) {
+        self.trace = None;
+    }
+
+    /// Get the most recent event activity
+    ///
+    /// # Returns
+    ///
+    /// Trace entries, oldest first (empty when the trace is disabled)
+    pub fn recent_events(&self) -> &[EventTraceEntry] {
+        self.trace.as_ref().map_or(&[], |t| t.entries())
+    }
+
+    /// Record activity of the event at `index` if tracing is enabled
+    fn record(&mut self, kind: EventTraceKind, index: usize, tick: GlobalTicks) {
+        if let Some(trace) = &mut self.trace {
+            let event = &self.events[index];
+            trace.push(EventTraceEntry {
+                kind,
+                handle: event.id,
+                name: event.name,
+                tick,
+                run_time: event.next_run_time,
+            });
         }
     }
 
+    /// Position of an event in the (sorted) event list
+    fn index_of(&self, handle: EventHandle) -> usize {
+        self.events
+            .iter()
+            .position(|event| event.id == handle)
+            .unwrap_or_else(|| panic!("invalid event handle {}", handle))
+    }
+
     /// Register a new timing event
     ///
     /// Creates a new event and returns its handle. The event is initially inactive
@@ -258,14 +362,12 @@ impl TimingEventManager {
         let current_time = self.get_current_time();
 
         // Find event by ID (not index) since sorting changes positions
-        let event = self
-            .events
-            .iter_mut()
-            .find(|event| event.id == handle)
-            .unwrap_or_else(|| panic!("invalid event handle {}", handle));
+        let index = self.index_of(handle);
+        let event = &mut self.events[index];
         event.next_run_time = current_time + ticks as GlobalTicks;
         event.last_run_time = current_time;
         event.active = true;
+        self.record(EventTraceKind::Scheduled, index, current_time);
 
         // Resort events by next_run_time
         self.sort_events();
@@ -274,21 +376,52 @@ impl TimingEventManager {
         self.update_downcount();
     }
 
-    /// Deactivate an event
+    /// Change the interval of a periodic event
     ///
-    /// Removes the event from the active event list.
+    /// Takes effect from the next time the event is rescheduled; call
+    /// `schedule()` to restart it with the new interval immediately.
     ///
     /// # Arguments
     ///
     /// * `handle` - Event handle
-    pub fn deactivate(&mut self, handle: EventHandle) {
-        // Find event by ID (not index) since sorting changes positions
+    /// * `interval` - New interval in CPU cycles (0 = one-shot)
+    pub fn set_interval(&mut self, handle: EventHandle, interval: TickCount) {
         let event = self
             .events
             .iter_mut()
             .find(|event| event.id == handle)
             .unwrap_or_else(|| panic!("invalid event handle {}", handle));
-        event.active = false;
+        event.interval = interval;
+    }
+
+    /// Check whether an event is scheduled
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - Event handle
+    ///
+    /// # Returns
+    ///
+    /// true if the event is active (waiting to run)
+    pub fn is_active(&self, handle: EventHandle) -> bool {
+        self.events
+            .iter()
+            .find(|event| event.id == handle)
+            .is_some_and(|event| event.active)
+    }
+
+    /// Deactivate an event
+    ///
+    /// Removes the event from the active event list.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - Event handle
+    pub fn deactivate(&mut self, handle: EventHandle) {
+        // Find event by ID (not index) since sorting changes positions
+        let index = self.index_of(handle);
+        self.events[index].active = false;
+        self.record(EventTraceKind::Deactivated, index, self.get_current_time());
         self.update_downcount();
     }
 
@@ -298,7 +431,7 @@ impl TimingEventManager {
     ///
     /// Current global time in CPU cycles
     #[inline]
-    fn get_current_time(&self) -> GlobalTicks {
+    pub fn get_current_time(&self) -> GlobalTicks {
         self.global_tick_counter + self.pending_ticks as GlobalTicks
     }
 
@@ -395,6 +528,7 @@ impl TimingEventManager {
 
         // Execute events
         for handle in &events_to_execute {
+            self.record(EventTraceKind::Fired, *handle, self.global_tick_counter);
             let event = &mut self.events[*handle];
             let ticks_late = (self.global_tick_counter - event.next_run_time) as TickCount;
             let event_id = event.id; // Save ID before mutable access
@@ -431,30 +565,6 @@ impl TimingEventManager {
         triggered_events
     }
 
-    /// Set frame target for execution control
-    ///
-    /// Used by the system to stop CPU execution after a specific number of cycles.
-    ///
-    /// # Arguments
-    ///
-    /// * `cycles` - Number of cycles for this frame
-    pub fn set_frame_target(&mut self, cycles: GlobalTicks) {
-        self.frame_target = Some(self.global_tick_counter + cycles);
-    }
-
-    /// Check if execution should exit (frame target reached)
-    ///
-    /// # Returns
-    ///
-    /// true if frame target has been reached
-    pub fn should_exit_loop(&self) -> bool {
-        if let Some(target) = self.frame_target {
-            self.global_tick_counter >= target
-        } else {
-            false
-        }
-    }
-
     /// Reset the timing system
     ///
     /// Clears all state and deactivates all events.
@@ -463,7 +573,6 @@ impl TimingEventManager {
         self.event_run_tick_counter = 0;
         self.pending_ticks = 0;
         self.downcount = i32::MAX;
-        self.frame_target = None;
 
         for event in &mut self.events {
             event.active = false;
@@ -479,6 +588,66 @@ impl Default for TimingEventManager {
     }
 }
 
+impl StateSave for TimingEventManager {
+    type State = TimingState;
+
+    fn to_state(&self) -> TimingState {
+        TimingState {
+            global_tick_counter: self.global_tick_counter,
+            event_run_tick_counter: self.event_run_tick_counter,
+            pending_ticks: self.pending_ticks,
+            downcount: self.downcount,
+            events: self
+                .events
+                .iter()
+                .map(|event| TimingEventState {
+                    name: event.name.to_string(),
+                    next_run_time: event.next_run_time,
+                    last_run_time: event.last_run_time,
+                    interval: event.interval,
+                    active: event.active,
+                })
+                .collect(),
+            cycles: 0,
+        }
+    }
+
+    /// Restore the event schedule from a saved state
+    ///
+    /// Event handles depend on registration order, so saved events are
+    /// matched by name against the events already registered on this
+    /// manager. Matched events keep their current handle and take the
+    /// saved schedule and position; events missing from the state are
+    /// left inactive.
+    fn restore_from_state(&mut self, state: &TimingState) {
+        self.global_tick_counter = state.global_tick_counter;
+        self.event_run_tick_counter = state.event_run_tick_counter;
+        self.pending_ticks = state.pending_ticks;
+        self.downcount = state.downcount;
+
+        let mut events = Vec::with_capacity(self.events.len());
+        for saved in &state.events {
+            let Some(index) = self.events.iter().position(|e| e.name == saved.name) else {
+                log::warn!("Timing: Saved event '{}' is not registered", saved.name);
+                continue;
+            };
+
+            let mut event = self.events.remove(index);
+            event.next_run_time = saved.next_run_time;
+            event.last_run_time = saved.last_run_time;
+            event.interval = saved.interval;
+            event.active = saved.active;
+            events.push(event);
+        }
+
+        for mut event in self.events.drain(..) {
+            event.active = false;
+            events.push(event);
+        }
+        self.events = events;
+    }
+}
+
 /// Timing Controller for Audio/Video Synchronization
 ///
 /// Manages frame timing and audio buffer levels to maintain smooth 60 FPS
@@ -792,15 +961,58 @@ mod tests {
     }
 
     #[test]
-    fn test_frame_target() {
+    fn test_event_trace_records_order_and_ticks() {
         let mut timing = TimingEventManager::new();
+        let cdrom = timing.register_event("CDROM");
+        let timer = timing.register_periodic_event("Timer", 300);
 
-        timing.set_frame_target(564_480);
-        assert!(!timing.should_exit_loop());
+        // Nothing is recorded until the trace is enabled
+        timing.schedule(cdrom, 50);
+        assert!(timing.recent_events().is_empty());
 
-        timing.pending_ticks = 564_480;
+        timing.enable_event_trace(16);
+        timing.schedule(cdrom, 1000);
+        timing.schedule(timer, 300);
+
+        // Fires late, at T=400
+        timing.pending_ticks = 400;
         timing.run_events();
-        assert!(timing.should_exit_loop());
+
+        timing.pending_ticks = 100;
+        timing.deactivate(cdrom);
+
+        let trace: Vec<_> = timing
+            .recent_events()
+            .iter()
+            .map(|e| (e.kind, e.name, e.tick, e.run_time))
+            .collect();
+        assert_eq!(
+            trace,
+            [
+                (EventTraceKind::Scheduled, "CDROM", 0, 1000),
+                (EventTraceKind::Scheduled, "Timer", 0, 300),
+                (EventTraceKind::Fired, "Timer", 400, 300),
+                (EventTraceKind::Deactivated, "CDROM", 500, 1000),
+            ]
+        );
+        assert_eq!(timing.recent_events()[2].handle, timer);
+    }
+
+    #[test]
+    fn test_event_trace_keeps_most_recent_entries() {
+        let mut timing = TimingEventManager::new();
+        let event = timing.register_event("Test");
+        timing.enable_event_trace(3);
+
+        for ticks in 1..=10 {
+            timing.schedule(event, ticks);
+        }
+
+        let run_times: Vec<_> = timing.recent_events().iter().map(|e| e.run_time).collect();
+        assert_eq!(run_times, [8, 9, 10]);
+
+        timing.disable_event_trace();
+        assert!(timing.recent_events().is_empty());
     }
 
     #[test]
@@ -17,6 +17,7 @@
 //!
 //! Displays CPU registers, PC, HI/LO, and disassembly of next instructions.
 
+use crate::core::cpu::Disassembler;
 use crate::core::system::System;
 
 /// Register names for MIPS R3000A
@@ -90,7 +91,6 @@ pub fn render_cpu_panel(ctx: &egui::Context, system: &System) {
 /// Render disassembly of next instructions
 ///
 /// Shows the next 10 instructions starting from the current PC.
-/// This is a simplified disassembly showing raw instruction words.
 fn render_disassembly(ui: &mut egui::Ui, system: &System) {
     let pc = system.cpu().pc();
     let bus = system.bus();
@@ -102,18 +102,17 @@ fn render_disassembly(ui: &mut egui::Ui, system: &System) {
         // Read instruction from memory
         match bus.read32(addr) {
             Ok(instruction) => {
+                let disasm = Disassembler::disassemble(instruction, addr);
+
                 // Highlight current PC
                 if i == 0 {
                     ui.colored_label(
                         egui::Color32::YELLOW,
-                        format!("→ 0x{:08X}: 0x{:08X}", addr, instruction),
+                        format!("→ 0x{:08X}: {:08X}  {}", addr, instruction, disasm),
                     );
                 } else {
-                    ui.label(format!("  0x{:08X}: 0x{:08X}", addr, instruction));
+                    ui.label(format!("  0x{:08X}: {:08X}  {}", addr, instruction, disasm));
                 }
-
-                // TODO: Add actual disassembly decoding in future phase
-                // For now, just show the raw instruction word
             }
             Err(_) => {
                 ui.colored_label(egui::Color32::RED, format!("  0x{:08X}: <invalid>", addr));